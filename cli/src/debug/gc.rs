@@ -1,5 +1,7 @@
 use boa_engine::{
-    Context, JsObject, JsResult, JsValue, NativeFunction, js_string, object::ObjectInitializer,
+    Context, JsArgs, JsNativeError, JsObject, JsResult, JsValue, NativeFunction, js_string,
+    object::{FunctionObjectBuilder, ObjectInitializer, builtins::JsArray},
+    property::Attribute,
 };
 
 /// Trigger garbage collection.
@@ -8,12 +10,127 @@ fn collect(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
     Ok(JsValue::undefined())
 }
 
+/// Returns statistics about the garbage collector.
+fn stats(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let stats = context.gc_statistics();
+    let object = ObjectInitializer::new(context)
+        .property(
+            js_string!("collections"),
+            stats.collections(),
+            Attribute::all(),
+        )
+        .property(
+            js_string!("bytesAllocated"),
+            stats.bytes_allocated(),
+            Attribute::all(),
+        )
+        .property(js_string!("threshold"), stats.threshold(), Attribute::all())
+        .property(
+            js_string!("objects"),
+            stats.object_count(),
+            Attribute::all(),
+        )
+        .property(
+            js_string!("ephemerons"),
+            stats.ephemeron_count(),
+            Attribute::all(),
+        )
+        .property(
+            js_string!("weakMaps"),
+            stats.weak_map_count(),
+            Attribute::all(),
+        )
+        .property(
+            js_string!("totalPause"),
+            stats.total_pause().as_secs_f64() * 1000.0,
+            Attribute::all(),
+        )
+        .property(
+            js_string!("lastPause"),
+            stats.last_pause().as_secs_f64() * 1000.0,
+            Attribute::all(),
+        )
+        .property(
+            js_string!("maxPause"),
+            stats.max_pause().as_secs_f64() * 1000.0,
+            Attribute::all(),
+        )
+        .build();
+
+    Ok(object.into())
+}
+
+/// Returns the number of values and bytes of each type in the heap, largest first.
+fn types(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let limit = args.get_or_undefined(0);
+    let limit = if limit.is_undefined() {
+        usize::MAX
+    } else {
+        usize::try_from(limit.to_length(context)?).unwrap_or(usize::MAX)
+    };
+
+    let types = boa_gc::type_statistics()
+        .into_iter()
+        .take(limit)
+        .map(|ty| {
+            ObjectInitializer::new(context)
+                .property(
+                    js_string!("name"),
+                    js_string!(ty.type_name()),
+                    Attribute::all(),
+                )
+                .property(js_string!("count"), ty.count(), Attribute::all())
+                .property(js_string!("bytes"), ty.bytes(), Attribute::all())
+                .build()
+                .into()
+        })
+        .collect::<Vec<JsValue>>();
+
+    Ok(JsArray::from_iter(types, context).into())
+}
+
+fn get_threshold(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+    Ok(JsValue::from(boa_gc::gc_config().threshold()))
+}
+
+fn set_threshold(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0).to_length(context)?;
+    let Ok(value) = value.try_into() else {
+        return Err(JsNativeError::range()
+            .with_message(format!("Argument {value} greater than usize::MAX"))
+            .into());
+    };
+    let mut config = boa_gc::gc_config();
+    config.set_threshold(value);
+    boa_gc::set_gc_config(config);
+    Ok(JsValue::undefined())
+}
+
 pub(super) fn create_object(context: &mut Context) -> JsObject {
+    let get_threshold =
+        FunctionObjectBuilder::new(context.realm(), NativeFunction::from_fn_ptr(get_threshold))
+            .name(js_string!("get threshold"))
+            .length(0)
+            .build();
+    let set_threshold =
+        FunctionObjectBuilder::new(context.realm(), NativeFunction::from_fn_ptr(set_threshold))
+            .name(js_string!("set threshold"))
+            .length(1)
+            .build();
+
     ObjectInitializer::new(context)
         .function(
             NativeFunction::from_fn_ptr(collect),
             js_string!("collect"),
             0,
         )
+        .function(NativeFunction::from_fn_ptr(stats), js_string!("stats"), 0)
+        .function(NativeFunction::from_fn_ptr(types), js_string!("types"), 0)
+        .accessor(
+            js_string!("threshold"),
+            Some(get_threshold),
+            Some(set_threshold),
+            Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
         .build()
 }
@@ -38,7 +38,7 @@ use debug::init_boa_debug_object;
 use rustyline::{EditMode, Editor, config::Config, error::ReadlineError};
 use std::time::{Duration, Instant};
 use std::{
-    fs::OpenOptions,
+    fs::{self, OpenOptions},
     io::{self, IsTerminal, Read, Write},
     path::{Path, PathBuf},
     rc::Rc,
@@ -426,6 +426,41 @@ fn evaluate_expr(
     Ok(())
 }
 
+/// Attaches the external source map referenced by a `//# sourceMappingURL=` comment at the end of
+/// `file` to its source.
+///
+/// Inline `data:` source maps are handled by the engine itself, so they are skipped here.
+fn with_source_map<'path, R>(source: Source<'path, R>, file: &Path) -> Source<'path, R> {
+    let Ok(code) = fs::read_to_string(file) else {
+        return source;
+    };
+    let url = code
+        .lines()
+        .rev()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map_while(|line| line.strip_prefix("//"))
+        .find_map(|comment| {
+            comment
+                .strip_prefix("# sourceMappingURL=")
+                .or_else(|| comment.strip_prefix("@ sourceMappingURL="))
+        })
+        .map(str::trim);
+
+    let Some(url) = url.filter(|url| !url.starts_with("data:")) else {
+        return source;
+    };
+    let url = url.strip_prefix("file://").unwrap_or(url);
+    let map_path = file
+        .parent()
+        .map_or_else(|| url.into(), |dir| dir.join(url));
+
+    match fs::read_to_string(map_path) {
+        Ok(source_map) => source.with_source_map(source_map),
+        Err(_) => source,
+    }
+}
+
 fn evaluate_file(
     file: &Path,
     args: &Opt,
@@ -451,7 +486,7 @@ fn evaluate_file(
     }
 
     if args.module {
-        let source = Source::from_filepath(file)?;
+        let source = with_source_map(Source::from_filepath(file)?, file);
         let mut counters = Counters::new(args.time);
         let module = {
             let _timer = counters.new_timer("Parsing");
@@ -482,7 +517,7 @@ fn evaluate_file(
         };
     }
 
-    let source = Source::from_filepath(file)?;
+    let source = with_source_map(Source::from_filepath(file)?, file);
     let mut counters = Counters::new(args.time);
     let script = {
         let _timer = counters.new_timer("Parsing");
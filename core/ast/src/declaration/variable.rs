@@ -126,10 +126,15 @@ impl LexicalDeclaration {
         }
     }
 
-    /// Returns `true` if the declaration is a `const` declaration.
+    /// Returns `true` if the declaration is a constant declaration.
+    ///
+    /// This is equivalent to the [`IsConstantDeclaration`][spec] syntax operation in the spec,
+    /// which is also `true` for `using` and `await using` declarations.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-isconstantdeclaration
     #[must_use]
     pub const fn is_const(&self) -> bool {
-        matches!(self, Self::Const(_))
+        matches!(self, Self::Const(_) | Self::Using(_) | Self::AwaitUsing(_))
     }
 }
 
@@ -28,6 +28,7 @@ pub struct ClassDeclaration {
     pub(crate) super_ref: Option<Expression>,
     pub(crate) constructor: Option<FunctionExpression>,
     pub(crate) elements: Box<[ClassElement]>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) name_scope: Scope,
@@ -42,12 +43,14 @@ impl ClassDeclaration {
         super_ref: Option<Expression>,
         constructor: Option<FunctionExpression>,
         elements: Box<[ClassElement]>,
+        decorators: Box<[Decorator]>,
     ) -> Self {
         Self {
             name,
             super_ref,
             constructor,
             elements,
+            decorators,
             name_scope: Scope::default(),
         }
     }
@@ -80,6 +83,13 @@ impl ClassDeclaration {
         &self.elements
     }
 
+    /// Gets the list of decorators applied to the class declaration.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scope containing the class name binding.
     #[inline]
     #[must_use]
@@ -90,7 +100,8 @@ impl ClassDeclaration {
 
 impl ToIndentedString for ClassDeclaration {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
-        let mut buf = format!("class {}", interner.resolve_expect(self.name.sym()));
+        let mut buf = decorators_to_string(&self.decorators, interner);
+        let _ = write!(buf, "class {}", interner.resolve_expect(self.name.sym()));
         if let Some(super_ref) = self.super_ref.as_ref() {
             let _ = write!(buf, " extends {}", super_ref.to_interned_string(interner));
         }
@@ -121,6 +132,9 @@ impl VisitWith for ClassDeclaration {
     where
         V: Visitor<'a>,
     {
+        for decorator in &*self.decorators {
+            visitor.visit_decorator(decorator)?;
+        }
         visitor.visit_identifier(&self.name)?;
         if let Some(expr) = &self.super_ref {
             visitor.visit_expression(expr)?;
@@ -138,6 +152,9 @@ impl VisitWith for ClassDeclaration {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in &mut *self.decorators {
+            visitor.visit_decorator_mut(decorator)?;
+        }
         visitor.visit_identifier_mut(&mut self.name)?;
         if let Some(expr) = &mut self.super_ref {
             visitor.visit_expression_mut(expr)?;
@@ -174,6 +191,7 @@ pub struct ClassExpression {
     pub(crate) super_ref: Option<Expression>,
     pub(crate) constructor: Option<FunctionExpression>,
     pub(crate) elements: Box<[ClassElement]>,
+    pub(crate) decorators: Box<[Decorator]>,
 
     span: Span,
 
@@ -190,6 +208,7 @@ impl ClassExpression {
         super_ref: Option<Expression>,
         constructor: Option<FunctionExpression>,
         elements: Box<[ClassElement]>,
+        decorators: Box<[Decorator]>,
         has_binding_identifier: bool,
         span: Span,
     ) -> Self {
@@ -203,6 +222,7 @@ impl ClassExpression {
             super_ref,
             constructor,
             elements,
+            decorators,
             span,
             name_scope,
         }
@@ -236,6 +256,13 @@ impl ClassExpression {
         &self.elements
     }
 
+    /// Gets the list of decorators applied to the class expression.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scope containing the class name binding if it exists.
     #[inline]
     #[must_use]
@@ -253,7 +280,8 @@ impl Spanned for ClassExpression {
 
 impl ToIndentedString for ClassExpression {
     fn to_indented_string(&self, interner: &Interner, indent_n: usize) -> String {
-        let mut buf = "class".to_string();
+        let mut buf = decorators_to_string(&self.decorators, interner);
+        buf.push_str("class");
         if self.name_scope.is_some()
             && let Some(name) = self.name
         {
@@ -295,6 +323,9 @@ impl VisitWith for ClassExpression {
     where
         V: Visitor<'a>,
     {
+        for decorator in &*self.decorators {
+            visitor.visit_decorator(decorator)?;
+        }
         if let Some(ident) = &self.name {
             visitor.visit_identifier(ident)?;
         }
@@ -314,6 +345,9 @@ impl VisitWith for ClassExpression {
     where
         V: VisitorMut<'a>,
     {
+        for decorator in &mut *self.decorators {
+            visitor.visit_decorator_mut(decorator)?;
+        }
         if let Some(ident) = &mut self.name {
             visitor.visit_identifier_mut(ident)?;
         }
@@ -401,6 +435,50 @@ pub enum ClassElement {
     StaticBlock(StaticBlockBody),
 }
 
+impl ClassElement {
+    /// Returns the decorators applied to the class element.
+    ///
+    /// Static blocks cannot be decorated, so they always return an empty list.
+    #[inline]
+    #[must_use]
+    pub fn decorators(&self) -> &[Decorator] {
+        match self {
+            Self::MethodDefinition(m) => &m.decorators,
+            Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => &field.decorators,
+            Self::PrivateFieldDefinition(field) | Self::PrivateStaticFieldDefinition(field) => {
+                &field.decorators
+            }
+            Self::StaticBlock(_) => &[],
+        }
+    }
+
+    /// Returns `true` if the class element is an auto-accessor declared with the `accessor` keyword.
+    #[inline]
+    #[must_use]
+    pub const fn is_accessor(&self) -> bool {
+        match self {
+            Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => field.is_accessor,
+            Self::PrivateFieldDefinition(field) | Self::PrivateStaticFieldDefinition(field) => {
+                field.is_accessor
+            }
+            Self::MethodDefinition(_) | Self::StaticBlock(_) => false,
+        }
+    }
+
+    pub(crate) fn decorators_mut(&mut self) -> &mut [Decorator] {
+        match self {
+            Self::MethodDefinition(m) => &mut m.decorators,
+            Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => {
+                &mut field.decorators
+            }
+            Self::PrivateFieldDefinition(field) | Self::PrivateStaticFieldDefinition(field) => {
+                &mut field.decorators
+            }
+            Self::StaticBlock(_) => &mut [],
+        }
+    }
+}
+
 /// A non-private class element field definition.
 ///
 /// More information:
@@ -413,6 +491,8 @@ pub enum ClassElement {
 pub struct ClassFieldDefinition {
     pub(crate) name: PropertyName,
     pub(crate) initializer: Option<Expression>,
+    pub(crate) is_accessor: bool,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
@@ -420,12 +500,22 @@ pub struct ClassFieldDefinition {
 
 impl ClassFieldDefinition {
     /// Creates a new class field definition.
+    ///
+    /// If `is_accessor` is `true`, the field is an auto-accessor declared with the `accessor`
+    /// keyword.
     #[inline]
     #[must_use]
-    pub fn new(name: PropertyName, initializer: Option<Expression>) -> Self {
+    pub fn new(
+        name: PropertyName,
+        initializer: Option<Expression>,
+        is_accessor: bool,
+        decorators: Box<[Decorator]>,
+    ) -> Self {
         Self {
             name,
             initializer,
+            is_accessor,
+            decorators,
             scope: Scope::default(),
         }
     }
@@ -444,6 +534,20 @@ impl ClassFieldDefinition {
         self.initializer.as_ref()
     }
 
+    /// Returns `true` if the class field definition is an auto-accessor.
+    #[inline]
+    #[must_use]
+    pub const fn is_accessor(&self) -> bool {
+        self.is_accessor
+    }
+
+    /// Returns the decorators applied to the class field definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Returns the scope of the class field definition.
     #[inline]
     #[must_use]
@@ -464,6 +568,8 @@ impl ClassFieldDefinition {
 pub struct PrivateFieldDefinition {
     pub(crate) name: PrivateName,
     pub(crate) initializer: Option<Expression>,
+    pub(crate) is_accessor: bool,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scope: Scope,
@@ -471,12 +577,22 @@ pub struct PrivateFieldDefinition {
 
 impl PrivateFieldDefinition {
     /// Creates a new private field definition.
+    ///
+    /// If `is_accessor` is `true`, the field is an auto-accessor declared with the `accessor`
+    /// keyword.
     #[inline]
     #[must_use]
-    pub fn new(name: PrivateName, initializer: Option<Expression>) -> Self {
+    pub fn new(
+        name: PrivateName,
+        initializer: Option<Expression>,
+        is_accessor: bool,
+        decorators: Box<[Decorator]>,
+    ) -> Self {
         Self {
             name,
             initializer,
+            is_accessor,
+            decorators,
             scope: Scope::default(),
         }
     }
@@ -495,6 +611,20 @@ impl PrivateFieldDefinition {
         self.initializer.as_ref()
     }
 
+    /// Returns `true` if the private field definition is an auto-accessor.
+    #[inline]
+    #[must_use]
+    pub const fn is_accessor(&self) -> bool {
+        self.is_accessor
+    }
+
+    /// Returns the decorators applied to the private field definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Returns the scope of the private field definition.
     #[inline]
     #[must_use]
@@ -508,72 +638,38 @@ impl ToIndentedString for ClassElement {
         let indentation = "    ".repeat(indent_n + 1);
         match self {
             Self::MethodDefinition(m) => m.to_indented_string(interner, indent_n),
-            Self::FieldDefinition(field) => match &field.initializer {
-                Some(expr) => {
-                    format!(
-                        "{indentation}{} = {};\n",
-                        field.name.to_interned_string(interner),
-                        expr.to_no_indent_string(interner, indent_n + 1)
-                    )
-                }
-                None => {
-                    format!(
-                        "{indentation}{};\n",
-                        field.name.to_interned_string(interner),
-                    )
-                }
-            },
-            Self::StaticFieldDefinition(field) => match &field.initializer {
-                Some(expr) => {
-                    format!(
-                        "{indentation}static {} = {};\n",
-                        field.name.to_interned_string(interner),
-                        expr.to_no_indent_string(interner, indent_n + 1)
-                    )
-                }
-                None => {
-                    format!(
-                        "{indentation}static {};\n",
-                        field.name.to_interned_string(interner),
-                    )
-                }
-            },
-            Self::PrivateFieldDefinition(PrivateFieldDefinition {
-                name, initializer, ..
-            }) => match initializer {
-                Some(expr) => {
-                    format!(
-                        "{indentation}#{} = {};\n",
-                        interner.resolve_expect(name.description()),
+            Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => {
+                let prefix = field_prefix(
+                    &field.decorators,
+                    matches!(self, Self::StaticFieldDefinition(_)),
+                    field.is_accessor,
+                    interner,
+                );
+                let name = field.name.to_interned_string(interner);
+                match &field.initializer {
+                    Some(expr) => format!(
+                        "{indentation}{prefix}{name} = {};\n",
                         expr.to_no_indent_string(interner, indent_n + 1)
-                    )
+                    ),
+                    None => format!("{indentation}{prefix}{name};\n"),
                 }
-                None => {
-                    format!(
-                        "{indentation}#{};\n",
-                        interner.resolve_expect(name.description()),
-                    )
-                }
-            },
-            Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
-                name,
-                initializer,
-                ..
-            }) => match initializer {
-                Some(expr) => {
-                    format!(
-                        "{indentation}static #{} = {};\n",
-                        interner.resolve_expect(name.description()),
+            }
+            Self::PrivateFieldDefinition(field) | Self::PrivateStaticFieldDefinition(field) => {
+                let prefix = field_prefix(
+                    &field.decorators,
+                    matches!(self, Self::PrivateStaticFieldDefinition(_)),
+                    field.is_accessor,
+                    interner,
+                );
+                let name = interner.resolve_expect(field.name.description());
+                match &field.initializer {
+                    Some(expr) => format!(
+                        "{indentation}{prefix}#{name} = {};\n",
                         expr.to_no_indent_string(interner, indent_n + 1)
-                    )
-                }
-                None => {
-                    format!(
-                        "{indentation}static #{};\n",
-                        interner.resolve_expect(name.description()),
-                    )
+                    ),
+                    None => format!("{indentation}{prefix}#{name};\n"),
                 }
-            },
+            }
             Self::StaticBlock(block) => {
                 format!(
                     "{indentation}static {}\n",
@@ -584,6 +680,32 @@ impl ToIndentedString for ClassElement {
     }
 }
 
+/// Builds the text preceding the name of a class field, including its decorators and modifiers.
+fn field_prefix(
+    decorators: &[Decorator],
+    is_static: bool,
+    is_accessor: bool,
+    interner: &Interner,
+) -> String {
+    let mut prefix = decorators_to_string(decorators, interner);
+    if is_static {
+        prefix.push_str("static ");
+    }
+    if is_accessor {
+        prefix.push_str("accessor ");
+    }
+    prefix
+}
+
+/// Converts a list of decorators to a string, with each decorator followed by a space.
+fn decorators_to_string(decorators: &[Decorator], interner: &Interner) -> String {
+    let mut buf = String::new();
+    for decorator in decorators {
+        let _ = write!(buf, "{} ", decorator.to_interned_string(interner));
+    }
+    buf
+}
+
 impl VisitWith for ClassElement {
     fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
@@ -591,6 +713,9 @@ impl VisitWith for ClassElement {
     {
         match self {
             Self::MethodDefinition(m) => {
+                for decorator in &*m.decorators {
+                    visitor.visit_decorator(decorator)?;
+                }
                 match &m.name {
                     ClassElementName::PropertyName(pn) => {
                         visitor.visit_property_name(pn)?;
@@ -603,6 +728,9 @@ impl VisitWith for ClassElement {
                 visitor.visit_function_body(&m.body)
             }
             Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => {
+                for decorator in &*field.decorators {
+                    visitor.visit_decorator(decorator)?;
+                }
                 visitor.visit_property_name(&field.name)?;
                 if let Some(expr) = &field.initializer {
                     visitor.visit_expression(expr)
@@ -611,13 +739,20 @@ impl VisitWith for ClassElement {
                 }
             }
             Self::PrivateFieldDefinition(PrivateFieldDefinition {
-                name, initializer, ..
+                name,
+                initializer,
+                decorators,
+                ..
             })
             | Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
                 name,
                 initializer,
+                decorators,
                 ..
             }) => {
+                for decorator in &**decorators {
+                    visitor.visit_decorator(decorator)?;
+                }
                 visitor.visit_private_name(name)?;
                 if let Some(expr) = initializer {
                     visitor.visit_expression(expr)
@@ -635,6 +770,9 @@ impl VisitWith for ClassElement {
     {
         match self {
             Self::MethodDefinition(m) => {
+                for decorator in &mut *m.decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 match m.name {
                     ClassElementName::PropertyName(ref mut pn) => {
                         visitor.visit_property_name_mut(pn)?;
@@ -647,6 +785,9 @@ impl VisitWith for ClassElement {
                 visitor.visit_function_body_mut(&mut m.body)
             }
             Self::FieldDefinition(field) | Self::StaticFieldDefinition(field) => {
+                for decorator in &mut *field.decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 visitor.visit_property_name_mut(&mut field.name)?;
                 if let Some(expr) = &mut field.initializer {
                     visitor.visit_expression_mut(expr)
@@ -655,13 +796,20 @@ impl VisitWith for ClassElement {
                 }
             }
             Self::PrivateFieldDefinition(PrivateFieldDefinition {
-                name, initializer, ..
+                name,
+                initializer,
+                decorators,
+                ..
             })
             | Self::PrivateStaticFieldDefinition(PrivateFieldDefinition {
                 name,
                 initializer,
+                decorators,
                 ..
             }) => {
+                for decorator in &mut **decorators {
+                    visitor.visit_decorator_mut(decorator)?;
+                }
                 visitor.visit_private_name_mut(name)?;
                 if let Some(expr) = initializer {
                     visitor.visit_expression_mut(expr)
@@ -693,6 +841,7 @@ pub struct ClassMethodDefinition {
     pub(crate) contains_direct_eval: bool,
     kind: MethodDefinitionKind,
     is_static: bool,
+    pub(crate) decorators: Box<[Decorator]>,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) scopes: FunctionScopes,
@@ -710,6 +859,7 @@ impl ClassMethodDefinition {
         kind: MethodDefinitionKind,
         is_static: bool,
         start_linear_pos: LinearPosition,
+        decorators: Box<[Decorator]>,
     ) -> Self {
         let contains_direct_eval = contains(&parameters, ContainsSymbol::DirectEval)
             || contains(&body, ContainsSymbol::DirectEval);
@@ -723,6 +873,7 @@ impl ClassMethodDefinition {
             contains_direct_eval,
             kind,
             is_static,
+            decorators,
             scopes: FunctionScopes::default(),
             linear_span: linear_span.into(),
         }
@@ -770,6 +921,13 @@ impl ClassMethodDefinition {
         self.name.is_private()
     }
 
+    /// Returns the decorators applied to the class method definition.
+    #[inline]
+    #[must_use]
+    pub const fn decorators(&self) -> &[Decorator] {
+        &self.decorators
+    }
+
     /// Gets the scopes of the class method definition.
     #[inline]
     #[must_use]
@@ -812,7 +970,59 @@ impl ToIndentedString for ClassMethodDefinition {
         let name = self.name.to_interned_string(interner);
         let parameters = join_nodes(interner, self.parameters.as_ref());
         let body = block_to_string(&self.body.statements, interner, indent_n + 1);
-        format!("{indentation}{prefix}{name}({parameters}) {body}\n")
+        let decorators = decorators_to_string(&self.decorators, interner);
+        format!("{indentation}{decorators}{prefix}{name}({parameters}) {body}\n")
+    }
+}
+
+/// A decorator applied to a class or to one of its elements.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-Decorator
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Decorator {
+    expression: Expression,
+}
+
+impl Decorator {
+    /// Creates a new decorator.
+    #[inline]
+    #[must_use]
+    pub const fn new(expression: Expression) -> Self {
+        Self { expression }
+    }
+
+    /// Gets the expression evaluated to get the decorator function.
+    #[inline]
+    #[must_use]
+    pub const fn expression(&self) -> &Expression {
+        &self.expression
+    }
+}
+
+impl ToInternedString for Decorator {
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        format!("@{}", self.expression.to_interned_string(interner))
+    }
+}
+
+impl VisitWith for Decorator {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        visitor.visit_expression(&self.expression)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        visitor.visit_expression_mut(&mut self.expression)
     }
 }
 
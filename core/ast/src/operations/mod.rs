@@ -220,6 +220,9 @@ where
 
         // `ComputedPropertyContains`: https://tc39.es/ecma262/#sec-static-semantics-computedpropertycontains
         fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
+            for decorator in node.decorators() {
+                decorator.visit_with(self)?;
+            }
             match node {
                 ClassElement::MethodDefinition(m) => {
                     if self.0 == ContainsSymbol::DirectEval {
@@ -329,6 +332,32 @@ where
             node.visit_with(self)
         }
 
+        fn visit_lexical_declaration(
+            &mut self,
+            node: &'ast LexicalDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            if self.0 == ContainsSymbol::AwaitExpression
+                && matches!(node, LexicalDeclaration::AwaitUsing(_))
+            {
+                return ControlFlow::Break(());
+            }
+
+            node.visit_with(self)
+        }
+
+        fn visit_iterable_loop_initializer(
+            &mut self,
+            node: &'ast IterableLoopInitializer,
+        ) -> ControlFlow<Self::BreakTy> {
+            if self.0 == ContainsSymbol::AwaitExpression
+                && matches!(node, IterableLoopInitializer::AwaitUsing(_))
+            {
+                return ControlFlow::Break(());
+            }
+
+            node.visit_with(self)
+        }
+
         fn visit_this(&mut self, _node: &'ast This) -> ControlFlow<Self::BreakTy> {
             if self.0 == ContainsSymbol::This {
                 return ControlFlow::Break(());
@@ -429,10 +458,14 @@ where
         }
 
         fn visit_class_element(&mut self, node: &'ast ClassElement) -> ControlFlow<Self::BreakTy> {
-            if let ClassElement::MethodDefinition(m) = node
-                && let ClassElementName::PropertyName(name) = m.name()
-            {
-                return name.visit_with(self);
+            if let ClassElement::MethodDefinition(m) = node {
+                for decorator in m.decorators() {
+                    decorator.visit_with(self)?;
+                }
+                if let ClassElementName::PropertyName(name) = m.name() {
+                    return name.visit_with(self);
+                }
+                return ControlFlow::Continue(());
             }
 
             node.visit_with(self)
@@ -1189,6 +1222,10 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         &mut self,
         node: &'ast ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators() {
+            self.visit(decorator)?;
+        }
+
         if let Some(node) = node.super_ref() {
             self.visit(node)?;
         }
@@ -1218,6 +1255,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         }
 
         for element in node.elements() {
+            for decorator in element.decorators() {
+                visitor.visit(decorator)?;
+            }
             match element {
                 ClassElement::MethodDefinition(m) => {
                     if let ClassElementName::PropertyName(name) = m.name() {
@@ -1258,6 +1298,10 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         &mut self,
         node: &'ast ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators() {
+            self.visit(decorator)?;
+        }
+
         if let Some(node) = node.super_ref() {
             self.visit(node)?;
         }
@@ -1287,6 +1331,9 @@ impl<'ast> Visitor<'ast> for AllPrivateIdentifiersValidVisitor {
         }
 
         for element in node.elements() {
+            for decorator in element.decorators() {
+                visitor.visit(decorator)?;
+            }
             match element {
                 ClassElement::MethodDefinition(m) => {
                     if let ClassElementName::PropertyName(name) = m.name() {
@@ -2484,7 +2531,10 @@ impl<'ast> Visitor<'ast> for AnnexBFunctionDeclarationNamesVisitor<'_> {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
-        if let IterableLoopInitializer::Const(node) = node.initializer() {
+        if let IterableLoopInitializer::Const(node)
+        | IterableLoopInitializer::Using(node)
+        | IterableLoopInitializer::AwaitUsing(node) = node.initializer()
+        {
             let bound_names = bound_names(node);
             self.0.retain(|name| !bound_names.contains(name));
         }
@@ -525,6 +525,145 @@ impl Scope {
     pub fn unique_id(&self) -> u32 {
         self.inner.unique_id
     }
+
+    /// Returns `true` if both scopes are the same scope, rather than just equal.
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Returns the state of this scope, excluding its outer scope.
+    #[must_use]
+    pub fn data(&self) -> ScopeData {
+        ScopeData {
+            index: self.inner.index.get(),
+            function: self.inner.function,
+            this_escaped: self.inner.this_escaped.get(),
+            bindings: self
+                .inner
+                .bindings
+                .borrow()
+                .iter()
+                .map(|binding| BindingData {
+                    name: binding.name.clone(),
+                    index: binding.index,
+                    flags: binding.flags,
+                })
+                .collect(),
+        }
+    }
+
+    /// Creates a new scope inside `outer` from the state returned by [`Scope::data`].
+    ///
+    /// The new scope gets a new unique ID from the scope tree of `outer`.
+    #[must_use]
+    pub fn from_data(outer: Self, data: ScopeData) -> Self {
+        Self {
+            inner: Rc::new(Inner {
+                unique_id: outer.inner.context.next_unique_id(),
+                index: Cell::new(data.index),
+                bindings: RefCell::new(
+                    data.bindings
+                        .into_iter()
+                        .map(|binding| Binding {
+                            name: binding.name,
+                            index: binding.index,
+                            flags: binding.flags,
+                        })
+                        .collect(),
+                ),
+                function: data.function,
+                this_escaped: Cell::new(data.this_escaped),
+                context: outer.inner.context.clone(),
+                outer: Some(outer),
+            }),
+        }
+    }
+
+    /// Returns the index of the binding with the same name as `binding`, creating it with the
+    /// flags of `binding` if this scope does not have it.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn get_or_create_binding(&self, binding: &BindingData) -> u32 {
+        let mut bindings = self.inner.bindings.borrow_mut();
+        if let Some(existing) = bindings.iter().find(|b| b.name == binding.name) {
+            return existing.index;
+        }
+        let index = bindings.len() as u32;
+        bindings.push(Binding {
+            name: binding.name.clone(),
+            index,
+            flags: binding.flags,
+        });
+        index
+    }
+}
+
+/// The state of a [`Scope`], excluding its outer scope.
+///
+/// This can be used to store the scopes referenced by compiled code and recreate them later
+/// with [`Scope::from_data`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScopeData {
+    /// The index of the scope in the scope chain.
+    pub index: u32,
+
+    /// If the scope is a function scope.
+    pub function: bool,
+
+    /// If `this` escaped the function environment of the scope.
+    pub this_escaped: bool,
+
+    /// The bindings of the scope, in declaration order.
+    pub bindings: Vec<BindingData>,
+}
+
+/// The state of a binding in a [`ScopeData`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BindingData {
+    name: JsString,
+    index: u32,
+    flags: BindingFlags,
+}
+
+impl BindingData {
+    /// Creates the state of a binding from its parts, as returned by [`BindingData::name`],
+    /// [`BindingData::index`] and [`BindingData::flags`].
+    ///
+    /// Returns `None` if `flags` contains unknown flags.
+    #[must_use]
+    pub fn from_parts(name: JsString, index: u32, flags: u8) -> Option<Self> {
+        Some(Self {
+            name,
+            index,
+            flags: BindingFlags::from_bits(flags)?,
+        })
+    }
+
+    /// Returns the name of the binding.
+    #[must_use]
+    pub const fn name(&self) -> &JsString {
+        &self.name
+    }
+
+    /// Returns the index of the binding in its scope.
+    #[must_use]
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the flags of the binding, as an opaque bitset.
+    #[must_use]
+    pub const fn flags(&self) -> u8 {
+        self.flags.bits()
+    }
+
+    /// Returns `true` if the binding is stored in the runtime environment of its scope, at
+    /// [`BindingData::index`], instead of a register.
+    #[must_use]
+    pub fn escapes(&self) -> bool {
+        self.flags.escapes()
+    }
 }
 
 /// Additional state that all Scopes of a single AST share for bookkeeping.
@@ -610,6 +749,21 @@ pub struct BindingLocator {
 }
 
 impl BindingLocator {
+    /// Creates a new binding locator from its parts.
+    #[must_use]
+    pub fn new(
+        name: JsString,
+        scope: BindingLocatorScope,
+        binding_index: u32,
+        unique_scope_id: u32,
+    ) -> Self {
+        let mut locator = Self::global(name);
+        locator.set_scope(scope);
+        locator.binding_index = binding_index;
+        locator.unique_scope_id = unique_scope_id;
+        locator
+    }
+
     /// Creates a new declarative binding locator that has knows indices.
     pub(crate) const fn declarative(
         name: JsString,
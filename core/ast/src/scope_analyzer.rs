@@ -7,7 +7,7 @@
 #[cfg(feature = "annex-b")]
 use crate::operations::annex_b_function_declarations_names;
 use crate::{
-    Declaration, Module, Script, StatementListItem, ToJsString,
+    Declaration, Module, Script, Statement, StatementListItem, ToJsString,
     declaration::{Binding, ExportDeclaration, LexicalDeclaration, VariableList},
     expression::{Identifier, literal::ObjectMethodDefinition},
     function::{
@@ -27,7 +27,7 @@ use crate::{
         Block, Catch, ForInLoop, ForLoop, ForOfLoop, Switch, With,
         iteration::{ForLoopInitializer, IterableLoopInitializer},
     },
-    visitor::{NodeRef, NodeRefMut, VisitorMut},
+    visitor::{NodeRef, NodeRefMut, VisitWith, VisitorMut},
 };
 use boa_interner::{Interner, Sym};
 use rustc_hash::FxHashMap;
@@ -103,6 +103,19 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         ControlFlow::Continue(())
     }
 
+    fn visit_statement_mut(&mut self, node: &'ast mut Statement) -> ControlFlow<Self::BreakTy> {
+        // The host can inspect the bindings of the scopes enclosing a `debugger` statement, so
+        // they must be stored in environments instead of registers.
+        if matches!(node, Statement::Debugger) {
+            let mut scope = Some(&self.scope);
+            while let Some(current) = scope {
+                current.escape_all_bindings();
+                scope = current.outer();
+            }
+        }
+        node.visit_with_mut(self)
+    }
+
     fn visit_block_mut(&mut self, node: &'ast mut Block) -> ControlFlow<Self::BreakTy> {
         let direct_eval_old = self.direct_eval;
         self.direct_eval = node.contains_direct_eval || self.direct_eval;
@@ -390,6 +403,9 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         node.name_scope.escape_all_bindings();
         std::mem::swap(&mut self.scope, &mut node.name_scope);
         if let Some(super_ref) = &mut node.super_ref {
@@ -410,6 +426,9 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         if let Some(name_scope) = &mut node.name_scope {
             if self.direct_eval {
                 name_scope.escape_all_bindings();
@@ -437,6 +456,9 @@ impl<'ast> VisitorMut<'ast> for BindingEscapeAnalyzer<'_> {
         &mut self,
         node: &'ast mut ClassElement,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators_mut() {
+            self.visit_decorator_mut(decorator)?;
+        }
         match node {
             ClassElement::MethodDefinition(node) => self.visit_function_like(
                 &mut node.parameters,
@@ -780,6 +802,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let mut name_scope = Scope::new(self.scope.clone(), false);
         let name = node.name().to_js_string(self.interner);
         name_scope.create_immutable_binding(name, true);
@@ -802,6 +827,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let mut name_scope = None;
         if let Some(name) = node.name
             && node.name_scope.is_some()
@@ -833,6 +861,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
         &mut self,
         node: &'ast mut ClassElement,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators_mut() {
+            self.visit_decorator_mut(decorator)?;
+        }
         match node {
             ClassElement::MethodDefinition(node) => {
                 let strict = node.body.strict();
@@ -1078,7 +1109,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
     fn visit_for_of_loop_mut(&mut self, node: &'ast mut ForOfLoop) -> ControlFlow<Self::BreakTy> {
         let initializer_bound_names = match node.initializer() {
             IterableLoopInitializer::Let(declaration)
-            | IterableLoopInitializer::Const(declaration) => bound_names(declaration),
+            | IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => bound_names(declaration),
             _ => Vec::new(),
         };
         if initializer_bound_names.is_empty() {
@@ -1111,7 +1144,9 @@ impl<'ast> VisitorMut<'ast> for BindingCollectorVisitor<'_> {
                 }
                 Some(scope)
             }
-            IterableLoopInitializer::Const(declaration) => {
+            IterableLoopInitializer::Const(declaration)
+            | IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
                 let scope = Scope::new(self.scope.clone(), false);
                 match declaration {
                     Binding::Identifier(ident) => {
@@ -1417,6 +1452,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
         &mut self,
         node: &'ast mut ClassDeclaration,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let index = self.index;
         if !node.name_scope.all_bindings_local() {
             self.index += 1;
@@ -1447,6 +1485,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
         &mut self,
         node: &'ast mut ClassExpression,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in &mut *node.decorators {
+            self.visit_decorator_mut(decorator)?;
+        }
         let index = self.index;
         if let Some(scope) = &node.name_scope {
             if !scope.all_bindings_local() {
@@ -1478,6 +1519,9 @@ impl<'ast> VisitorMut<'ast> for ScopeIndexVisitor {
         &mut self,
         node: &'ast mut ClassElement,
     ) -> ControlFlow<Self::BreakTy> {
+        for decorator in node.decorators_mut() {
+            self.visit_decorator_mut(decorator)?;
+        }
         match node {
             ClassElement::MethodDefinition(node) => {
                 let contains_direct_eval = node.contains_direct_eval();
@@ -1833,7 +1877,12 @@ where
     // 3. For each element d of declarations, do
     for d in &declarations {
         // i. If IsConstantDeclaration of d is true, then
-        if let LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Const(d)) = d {
+        if let LexicallyScopedDeclaration::LexicalDeclaration(
+            LexicalDeclaration::Const(d)
+            | LexicalDeclaration::Using(d)
+            | LexicalDeclaration::AwaitUsing(d),
+        ) = d
+        {
             // a. For each element dn of the BoundNames of d, do
             for dn in bound_names::<'_, VariableList>(d) {
                 // 1. Perform ! env.CreateImmutableBinding(dn, true).
@@ -2174,7 +2223,11 @@ fn function_declaration_instantiation(
                         drop(lex_env.create_mutable_binding(name, false));
                     }
                 }
-                Declaration::Lexical(LexicalDeclaration::Const(declaration)) => {
+                Declaration::Lexical(
+                    LexicalDeclaration::Const(declaration)
+                    | LexicalDeclaration::Using(declaration)
+                    | LexicalDeclaration::AwaitUsing(declaration),
+                ) => {
                     for name in bound_names(declaration) {
                         let name = name.to_js_string(interner);
                         lex_env.create_immutable_binding(name, true);
@@ -2255,16 +2308,12 @@ fn module_instantiation(module: &Module, env: &Scope, interner: &Interner) {
                     drop(env.create_mutable_binding(name, false));
                 }
             }
-            LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::Using(u)) => {
+            LexicallyScopedDeclaration::LexicalDeclaration(
+                LexicalDeclaration::Using(u) | LexicalDeclaration::AwaitUsing(u),
+            ) => {
                 for name in bound_names(u) {
                     let name = name.to_js_string(interner);
-                    drop(env.create_mutable_binding(name, false));
-                }
-            }
-            LexicallyScopedDeclaration::LexicalDeclaration(LexicalDeclaration::AwaitUsing(au)) => {
-                for name in bound_names(au) {
-                    let name = name.to_js_string(interner);
-                    drop(env.create_mutable_binding(name, false));
+                    env.create_immutable_binding(name, true);
                 }
             }
             LexicallyScopedDeclaration::AssignmentExpression(expr) => {
@@ -313,3 +313,26 @@ fn can_correlate_binding_to_scope() {
     // THEN
     assert_eq!(binding.locator().unique_scope_id(), child1.unique_id());
 }
+
+#[test]
+fn scope_can_be_recreated_from_data() {
+    let global = Scope::new_global();
+    let scope = Scope::new(global.clone(), true);
+    let _unused = scope.create_mutable_binding("x".into(), true);
+    let _unused = scope.create_mutable_binding("y".into(), false);
+    scope.escape_all_bindings();
+
+    let data = scope.data();
+    let recreated = Scope::from_data(global.clone(), data.clone());
+
+    assert_eq!(recreated.data(), data);
+    assert!(!recreated.ptr_eq(&scope));
+    assert_ne!(recreated.unique_id(), scope.unique_id());
+    assert!(recreated.has_lex_binding(&"y".into()));
+
+    // Existing bindings are reused, missing ones are appended.
+    let index = global.get_or_create_binding(&data.bindings[1]);
+    assert_eq!(index, 0);
+    assert_eq!(global.get_or_create_binding(&data.bindings[1]), index);
+    assert_eq!(global.get_or_create_binding(&data.bindings[0]), 1);
+}
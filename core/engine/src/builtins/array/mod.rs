@@ -291,6 +291,16 @@ impl BuiltInConstructor for Array {
 }
 
 impl Array {
+    /// Checks that a list of `len` elements of type `T` can be allocated without going over the
+    /// heap limit of the garbage collector.
+    pub(crate) fn check_list_allocation<T>(len: u64) -> JsResult<()> {
+        let bytes = usize::try_from(len)
+            .unwrap_or(usize::MAX)
+            .saturating_mul(size_of::<T>());
+        boa_gc::check_heap_limit(bytes)?;
+        Ok(())
+    }
+
     /// Optimized helper function, that sets the length of the array.
     fn set_length(o: &JsObject, len: u64, context: &mut Context) -> JsResult<()> {
         if o.is_array() && len < (2u64.pow(32) - 1) {
@@ -1002,6 +1012,7 @@ impl Array {
         };
 
         // 5. Let R be the empty String.
+        Self::check_list_allocation::<JsString>(len.saturating_mul(2))?;
         let mut r = Vec::with_capacity(len as usize + len.saturating_sub(1) as usize);
         // 6. Let k be 0.
         // 7. Repeat, while k < len,
@@ -2201,6 +2212,7 @@ impl Array {
         };
 
         // 4. Let R be the empty String.
+        Self::check_list_allocation::<JsString>(len.saturating_mul(2))?;
         let mut r = Vec::with_capacity(len as usize + len.saturating_sub(1) as usize);
 
         // 5. Let k be 0.
@@ -2640,6 +2652,7 @@ impl Array {
     {
         // 1. Let items be a new empty List.
         // doesn't matter if it clamps since it's just a best-effort optimization
+        Self::check_list_allocation::<JsValue>(len)?;
         let mut items = Vec::with_capacity(len as usize);
 
         // 2. Let k be 0.
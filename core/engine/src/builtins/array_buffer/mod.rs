@@ -34,7 +34,7 @@ use crate::{
     string::StaticJsStrings,
     symbol::JsSymbol,
 };
-use boa_gc::{Finalize, GcRef, GcRefMut, Trace};
+use boa_gc::{ExternalMemory, Finalize, GcRef, GcRefMut, Trace};
 
 use self::utils::{SliceRef, SliceRefMut};
 
@@ -209,17 +209,48 @@ pub struct ArrayBuffer {
 
     /// The `[[ArrayBufferDetachKey]]` internal slot.
     detach_key: JsValue,
+
+    /// The allocation of `data`, counted towards the heap limit.
+    memory: ExternalMemory,
 }
 
 impl ArrayBuffer {
-    pub(crate) fn from_data(data: AlignedVec<u8>, detach_key: JsValue) -> Self {
+    fn new(data: AlignedVec<u8>, max_byte_len: Option<u64>, detach_key: JsValue) -> Self {
+        Self::from_slots(Some(data), max_byte_len, detach_key)
+    }
+
+    /// Creates a buffer from the values of its internal slots, where `None` data means the
+    /// buffer is detached.
+    pub(crate) fn from_slots(
+        data: Option<AlignedVec<u8>>,
+        max_byte_len: Option<u64>,
+        detach_key: JsValue,
+    ) -> Self {
         Self {
-            data: Some(data),
-            max_byte_len: None,
+            memory: ExternalMemory::new(data.as_ref().map_or(0, AlignedVec::capacity)),
+            data,
+            max_byte_len,
             detach_key,
         }
     }
 
+    pub(crate) fn from_data(data: AlignedVec<u8>, detach_key: JsValue) -> Self {
+        Self::new(data, None, detach_key)
+    }
+
+    /// Takes the data out of the buffer, leaving it detached.
+    fn take_data(&mut self) -> Option<AlignedVec<u8>> {
+        self.memory.set_bytes(0);
+        self.data.take()
+    }
+
+    /// Puts back the data taken with [`Self::take_data`].
+    #[cfg(feature = "experimental")]
+    fn restore_data(&mut self, data: AlignedVec<u8>) {
+        self.memory.set_bytes(data.capacity());
+        self.data = Some(data);
+    }
+
     pub(crate) fn len(&self) -> usize {
         self.data.as_ref().map_or(0, AlignedVec::len)
     }
@@ -236,6 +267,21 @@ impl ArrayBuffer {
         self.data.as_mut()
     }
 
+    /// Gets the maximum byte length of the buffer, if it is resizable.
+    pub(crate) const fn max_byte_length(&self) -> Option<u64> {
+        self.max_byte_len
+    }
+
+    /// Gets the `[[ArrayBufferDetachKey]]` internal slot.
+    pub(crate) const fn detach_key(&self) -> &JsValue {
+        &self.detach_key
+    }
+
+    /// Sets the `[[ArrayBufferDetachKey]]` internal slot.
+    pub(crate) fn set_detach_key(&mut self, detach_key: JsValue) {
+        self.detach_key = detach_key;
+    }
+
     /// Sets the maximum byte length of the buffer, returning the previous value if present.
     pub(crate) fn set_max_byte_length(&mut self, max_byte_len: u64) -> Option<u64> {
         self.max_byte_len.replace(max_byte_len)
@@ -290,6 +336,8 @@ impl ArrayBuffer {
         }
 
         buf.resize(new_byte_length as usize, 0);
+        let capacity = buf.capacity();
+        self.memory.set_bytes(capacity);
         Ok(())
     }
 
@@ -306,7 +354,7 @@ impl ArrayBuffer {
                 .into());
         }
 
-        Ok(self.data.take())
+        Ok(self.take_data())
     }
 
     /// `IsDetachedBuffer ( arrayBuffer )`
@@ -788,7 +836,7 @@ impl ArrayBuffer {
         };
 
         // 5. If IsDetachedBuffer(arrayBuffer) is true, throw a TypeError exception.
-        let Some(mut bytes) = buf.borrow_mut().data_mut().data.take() else {
+        let Some(mut bytes) = buf.borrow_mut().data_mut().take_data() else {
             return Err(JsNativeError::typ()
                 .with_message("cannot transfer a detached buffer")
                 .into());
@@ -807,7 +855,7 @@ impl ArrayBuffer {
 
         // 8. If arrayBuffer.[[ArrayBufferDetachKey]] is not undefined, throw a TypeError exception.
         if !buf.borrow().data().detach_key.is_undefined() {
-            buf.borrow_mut().data_mut().data = Some(bytes);
+            buf.borrow_mut().data_mut().restore_data(bytes);
             return Err(JsNativeError::typ()
                 .with_message("cannot transfer a buffer with a detach key")
                 .into());
@@ -827,7 +875,7 @@ impl ArrayBuffer {
         // 16. Return newBuffer.
         if let Some(new_max_len) = new_max_len {
             if new_len > new_max_len {
-                buf.borrow_mut().data_mut().data = Some(bytes);
+                buf.borrow_mut().data_mut().restore_data(bytes);
                 return Err(JsNativeError::range()
                     .with_message("`length` cannot be bigger than `maxByteLength`")
                     .into());
@@ -850,11 +898,7 @@ impl ArrayBuffer {
         Ok(JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ArrayBuffer {
-                data: Some(bytes),
-                max_byte_len: new_max_len,
-                detach_key: JsValue::undefined(),
-            },
+            ArrayBuffer::new(bytes, new_max_len, JsValue::undefined()),
         )
         .into())
     }
@@ -901,15 +945,11 @@ impl ArrayBuffer {
         let obj = JsObject::new(
             context.root_shape(),
             prototype,
-            Self {
-                // 6. Set obj.[[ArrayBufferData]] to block.
-                // 7. Set obj.[[ArrayBufferByteLength]] to byteLength.
-                data: Some(block),
-                // 8. If allocatingResizableBuffer is true, then
-                //    c. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
-                max_byte_len,
-                detach_key: JsValue::undefined(),
-            },
+            // 6. Set obj.[[ArrayBufferData]] to block.
+            // 7. Set obj.[[ArrayBufferByteLength]] to byteLength.
+            // 8. If allocatingResizableBuffer is true, then
+            //    c. Set obj.[[ArrayBufferMaxByteLength]] to maxByteLength.
+            Self::new(block, max_byte_len, JsValue::undefined()),
         );
 
         // 9. Return obj.
@@ -965,6 +1005,9 @@ pub(crate) fn create_byte_data_block(
         JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
     })?;
 
+    // Buffers are not allocated by the garbage collector, but must still respect the heap limit.
+    boa_gc::check_heap_limit(alloc_size)?;
+
     let mut data_block = AlignedVec::<u8>::new(64);
     data_block.try_reserve_exact(alloc_size).map_err(|e| {
         let message = match e {
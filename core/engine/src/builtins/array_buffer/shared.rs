@@ -577,6 +577,9 @@ pub(crate) fn create_shared_byte_data_block(
         JsNativeError::range().with_message(format!("couldn't allocate the data block: {e}"))
     })?;
 
+    // Buffers are not allocated by the garbage collector, but must still respect the heap limit.
+    boa_gc::check_heap_limit(size)?;
+
     if size == 0 {
         // Must ensure we don't allocate a zero-sized buffer.
         return Ok(AlignedVec::new(0).into_boxed_slice());
@@ -0,0 +1,349 @@
+//! Boa's implementation of ECMAScript's `AsyncDisposableStack` object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncDisposableStack
+
+use boa_gc::{Finalize, Trace};
+
+use super::{DisposableResource, DisposeCapability, DisposeHint, adopt_closure};
+use crate::{
+    Context, JsArgs, JsData, JsExpect, JsObject, JsResult, JsString, JsSymbol, JsValue,
+    builtins::{
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+        promise::PromiseCapability,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_error, js_string,
+    object::internal_methods::get_prototype_from_constructor,
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+};
+
+/// The `AsyncDisposableStack` builtin object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct AsyncDisposableStack {
+    /// The `[[DisposeCapability]]` of the stack, or `None` if its
+    /// `[[AsyncDisposableState]]` is `disposed`.
+    capability: Option<DisposeCapability>,
+}
+
+impl IntrinsicObject for AsyncDisposableStack {
+    fn init(realm: &Realm) {
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose_async = BuiltInBuilder::callable(realm, Self::dispose_async)
+            .name(js_string!("disposeAsync"))
+            .length(0)
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("disposeAsync"),
+                dispose_async.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .property(
+                JsSymbol::async_dispose(),
+                dispose_async,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for AsyncDisposableStack {
+    const NAME: JsString = StaticJsStrings::ASYNC_DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for AsyncDisposableStack {
+    const CONSTRUCTOR_ARGUMENTS: usize = 0;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 9;
+    const CONSTRUCTOR_STORAGE_SLOTS: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_disposable_stack;
+
+    /// [`AsyncDisposableStack ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(js_error!(
+                TypeError: "AsyncDisposableStack: cannot call constructor without `new`"
+            ));
+        }
+
+        // 2. Let asyncDisposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_disposable_stack,
+            context,
+        )?;
+
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        let stack = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                capability: Some(DisposeCapability::new()),
+            },
+        );
+
+        // 5. Return asyncDisposableStack.
+        Ok(stack.upcast().into())
+    }
+}
+
+impl AsyncDisposableStack {
+    /// Returns the `AsyncDisposableStack` object in `this`, or throws a `TypeError` otherwise.
+    fn this_stack(this: &JsValue, method: &str) -> JsResult<JsObject<Self>> {
+        this.as_object()
+            .and_then(|o| o.downcast::<Self>().ok())
+            .ok_or_else(|| {
+                js_error!(
+                    TypeError: "AsyncDisposableStack.prototype.{}: invalid object type for `this`",
+                    method
+                )
+            })
+    }
+
+    /// Returns an error if the stack in `this` has already been disposed.
+    fn check_pending(stack: &JsObject<Self>, method: &str) -> JsResult<()> {
+        if stack.borrow().data().capability.is_none() {
+            return Err(js_error!(
+                ReferenceError: "AsyncDisposableStack.prototype.{}: the stack has already been disposed",
+                method
+            ));
+        }
+        Ok(())
+    }
+
+    /// Appends `resource` to the stack, if it has not been disposed in the meantime.
+    fn add_resource(stack: &JsObject<Self>, resource: DisposableResource) {
+        if let Some(capability) = stack.borrow_mut().data_mut().capability.as_mut() {
+            capability.add_resource(resource);
+        }
+    }
+
+    /// [`get AsyncDisposableStack.prototype.disposed`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "disposed")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        Ok(stack.borrow().data().capability.is_none().into())
+    }
+
+    /// [`AsyncDisposableStack.prototype.disposeAsync ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync
+    fn dispose_async(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .js_expect("cannot fail per spec")?;
+
+        // 3. If asyncDisposableStack does not have an [[AsyncDisposableState]] internal slot, then
+        let stack = match Self::this_stack(this, "disposeAsync") {
+            Ok(stack) => stack,
+            Err(err) => {
+                // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+                let err = err.into_opaque(context)?;
+                promise_capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[err], context)?;
+
+                // b. Return promiseCapability.[[Promise]].
+                return Ok(promise_capability.promise().clone().into());
+            }
+        };
+
+        // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, then
+        // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        let capability = stack.borrow_mut().data_mut().capability.take();
+        let Some(capability) = capability else {
+            // a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+            promise_capability.resolve().call(
+                &JsValue::undefined(),
+                &[JsValue::undefined()],
+                context,
+            )?;
+
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        };
+
+        // 6. Let result be Completion(DisposeResources(asyncDisposableStack.[[DisposeCapability]], NormalCompletion(undefined))).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        let promise = promise_capability.promise().clone();
+        capability.dispose_async(promise_capability, context)?;
+
+        // 9. Return promiseCapability.[[Promise]].
+        Ok(promise.into())
+    }
+
+    /// [`AsyncDisposableStack.prototype.use ( value )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "use")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], value, async-dispose).
+        let value = args.get_or_undefined(0);
+        if let Some(resource) = DisposableResource::from_value(value, DisposeHint::Async, context)?
+        {
+            Self::add_resource(&stack, resource);
+        }
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// [`AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "adopt")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "adopt")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0);
+        let on_dispose = args.get_or_undefined(1).as_callable().ok_or_else(|| {
+            js_error!(
+                TypeError: "AsyncDisposableStack.prototype.adopt: `onDisposeAsync` must be callable"
+            )
+        })?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDisposeAsync and performs the following steps when called:
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let f = adopt_closure(value.clone(), on_dispose, context);
+
+        // 7. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, F).
+        Self::add_resource(
+            &stack,
+            DisposableResource::from_method(f, DisposeHint::Async),
+        );
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// [`AsyncDisposableStack.prototype.defer ( onDisposeAsync )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "defer")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "defer")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let on_dispose = args.get_or_undefined(0).as_callable().ok_or_else(|| {
+            js_error!(
+                TypeError: "AsyncDisposableStack.prototype.defer: `onDisposeAsync` must be callable"
+            )
+        })?;
+
+        // 5. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, onDisposeAsync).
+        Self::add_resource(
+            &stack,
+            DisposableResource::from_method(on_dispose, DisposeHint::Async),
+        );
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`AsyncDisposableStack.prototype.move ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        let stack = Self::this_stack(this, "move")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        // 7. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        let capability = stack.borrow_mut().data_mut().capability.take();
+        let Some(capability) = capability else {
+            return Err(js_error!(
+                ReferenceError: "AsyncDisposableStack.prototype.move: the stack has already been disposed"
+            ));
+        };
+
+        // 4. Let newAsyncDisposableStack be ? OrdinaryCreateFromConstructor(%AsyncDisposableStack%, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        // 5. Set newAsyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 6. Set newAsyncDisposableStack.[[DisposeCapability]] to asyncDisposableStack.[[DisposeCapability]].
+        let new_stack = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .async_disposable_stack()
+                .prototype(),
+            Self {
+                capability: Some(capability),
+            },
+        );
+
+        // 9. Return newAsyncDisposableStack.
+        Ok(new_stack.upcast().into())
+    }
+}
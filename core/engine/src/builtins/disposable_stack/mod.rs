@@ -0,0 +1,790 @@
+//! Boa's implementation of ECMAScript's `DisposableStack` object, and of the
+//! abstract operations backing `using` and `await using` declarations.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DisposableStack
+
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+
+use crate::{
+    Context, JsArgs, JsData, JsError, JsExpect, JsObject, JsResult, JsString, JsSymbol, JsValue,
+    builtins::{
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, Promise,
+        error::SuppressedError, promise::PromiseCapability,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_error, js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, internal_methods::get_prototype_from_constructor},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+};
+
+mod async_disposable_stack;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use async_disposable_stack::AsyncDisposableStack;
+
+/// The hint of a [`DisposableResource`], indicating how it must be disposed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+pub(crate) enum DisposeHint {
+    /// The resource was added by a `using` declaration or a `DisposableStack`.
+    Sync,
+
+    /// The resource was added by an `await using` declaration or an `AsyncDisposableStack`.
+    Async,
+}
+
+/// A `DisposableResource` Record, per the [ECMAScript spec][spec].
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records
+#[derive(Debug, Clone, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub(crate) struct DisposableResource {
+    value: JsValue,
+    hint: DisposeHint,
+    method: Option<JsObject>,
+}
+
+impl DisposableResource {
+    /// Abstract operation [`CreateDisposableResource ( V, hint )`][spec], without a `method`.
+    ///
+    /// Returns `None` if `value` is `null` or `undefined` and `hint` is [`DisposeHint::Sync`],
+    /// since those resources don't need to be added to the disposable resource stack.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource
+    pub(crate) fn from_value(
+        value: &JsValue,
+        hint: DisposeHint,
+        context: &mut Context,
+    ) -> JsResult<Option<Self>> {
+        // 1. If method is not present, then
+        //    a. If V is either null or undefined, then
+        if value.is_null_or_undefined() {
+            // NOTE: From `AddDisposableResource`:
+            //    a. If V is either null or undefined and hint is sync-dispose, return unused.
+            //    b. NOTE: When V is either null or undefined and hint is async-dispose, we record
+            //       that a resource was added, so we'll still perform an Await when leaving the block.
+            if hint == DisposeHint::Sync {
+                return Ok(None);
+            }
+
+            // i. Set V to undefined.
+            // ii. Set method to undefined.
+            return Ok(Some(Self {
+                value: JsValue::undefined(),
+                hint,
+                method: None,
+            }));
+        }
+
+        // b. Else,
+        //    i. If V is not an Object, throw a TypeError exception.
+        if !value.is_object() {
+            return Err(js_error!(
+                TypeError: "cannot dispose a value that is not an object"
+            ));
+        }
+
+        //    ii. Set method to ? GetDisposeMethod(V, hint).
+        //    iii. If method is undefined, throw a TypeError exception.
+        let method = get_dispose_method(value, hint, context)?.ok_or_else(|| match hint {
+            DisposeHint::Sync => {
+                js_error!(TypeError: "value does not have a [Symbol.dispose] method")
+            }
+            DisposeHint::Async => js_error!(
+                TypeError: "value has neither a [Symbol.asyncDispose] nor a [Symbol.dispose] method"
+            ),
+        })?;
+
+        // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint, [[DisposeMethod]]: method }.
+        Ok(Some(Self {
+            value: value.clone(),
+            hint,
+            method: Some(method),
+        }))
+    }
+
+    /// Abstract operation [`CreateDisposableResource ( undefined, hint, method )`][spec].
+    ///
+    /// The caller must ensure that `method` is callable.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource
+    pub(crate) fn from_method(method: JsObject, hint: DisposeHint) -> Self {
+        debug_assert!(method.is_callable());
+        Self {
+            value: JsValue::undefined(),
+            hint,
+            method: Some(method),
+        }
+    }
+}
+
+/// Abstract operation [`GetDisposeMethod ( V, hint )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod
+fn get_dispose_method(
+    value: &JsValue,
+    hint: DisposeHint,
+    context: &mut Context,
+) -> JsResult<Option<JsObject>> {
+    // 1. If hint is async-dispose, then
+    if hint == DisposeHint::Async {
+        // a. Let method be ? GetMethod(V, @@asyncDispose).
+        if let Some(method) = value.get_method(JsSymbol::async_dispose(), context)? {
+            return Ok(Some(method));
+        }
+
+        // b. If method is undefined, then
+        //    i. Set method to ? GetMethod(V, @@dispose).
+        let Some(method) = value.get_method(JsSymbol::dispose(), context)? else {
+            return Ok(None);
+        };
+
+        //    ii. If method is not undefined, then
+        //        1. Let closure be a new Abstract Closure with no parameters that captures method
+        //           and performs the following steps when called:
+        //        2. NOTE: This function is not observable to user code. It is used to ensure that a
+        //           Promise returned from a synchronous @@dispose method will not be awaited and
+        //           that any exception thrown will not be thrown synchronously.
+        //        3. Return CreateBuiltinFunction(closure, 0, "", « »).
+        let closure = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, _, method, context| {
+                    // a. Let O be the this value.
+                    // b. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+                    let promise_capability = PromiseCapability::new(
+                        &context.intrinsics().constructors().promise().constructor(),
+                        context,
+                    )
+                    .js_expect("cannot fail per spec")?;
+
+                    // c. Let result be Completion(Call(method, O)).
+                    // d. IfAbruptRejectPromise(result, promiseCapability).
+                    if let Err(err) = method.call(this, &[], context) {
+                        let err = err.into_opaque(context)?;
+                        promise_capability
+                            .reject()
+                            .call(&JsValue::undefined(), &[err], context)?;
+                        return Ok(promise_capability.promise().clone().into());
+                    }
+
+                    // e. Perform ? Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                    promise_capability.resolve().call(
+                        &JsValue::undefined(),
+                        &[JsValue::undefined()],
+                        context,
+                    )?;
+
+                    // f. Return promiseCapability.[[Promise]].
+                    Ok(promise_capability.promise().clone().into())
+                },
+                method,
+            ),
+        )
+        .name(js_string!())
+        .length(0)
+        .build();
+
+        return Ok(Some(closure.into()));
+    }
+
+    // 2. Else,
+    //    a. Let method be ? GetMethod(V, @@dispose).
+    // 3. Return method.
+    value.get_method(JsSymbol::dispose(), context)
+}
+
+/// A `DisposeCapability` Record, per the [ECMAScript spec][spec].
+///
+/// Apart from the stack of disposable resources, this also keeps the state of an in-progress
+/// [`DisposeResources`][dispose] operation, which allows suspending it whenever a resource
+/// needs to be awaited.
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+/// [dispose]: DisposeCapability::dispose_resources
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub(crate) struct DisposeCapability {
+    resources: Vec<DisposableResource>,
+    completion: Option<JsValue>,
+    needs_await: bool,
+    has_awaited: bool,
+}
+
+impl DisposeCapability {
+    /// Creates a new, empty `DisposeCapability`.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Abstract operation [`AddDisposableResource ( disposeCapability, V, hint [ , method ] )`][spec].
+    ///
+    /// The resource must have been created with one of the [`DisposableResource`] constructors.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    pub(crate) fn add_resource(&mut self, resource: DisposableResource) {
+        // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+        self.resources.push(resource);
+    }
+
+    /// Records a throw completion for this dispose capability.
+    ///
+    /// If a throw completion was already recorded, it is replaced by a new `SuppressedError`
+    /// with `error` as its `error` and the previous completion as its `suppressed` value.
+    pub(crate) fn record_error(&mut self, error: JsValue, context: &mut Context) {
+        let error = match self.completion.take() {
+            // 1. If completion is a throw completion, then
+            //    a. Set result to result.[[Value]].
+            //    b. Let suppressed be completion.[[Value]].
+            //    c. Let error be a newly created SuppressedError object.
+            //    d. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+            //    e. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed", suppressed).
+            //    f. Set completion to ThrowCompletion(error).
+            Some(suppressed) => SuppressedError::create(error, suppressed, context).into(),
+            // 2. Else,
+            //    a. Set completion to result.
+            None => error,
+        };
+        self.completion = Some(error);
+    }
+
+    /// Abstract operation [`DisposeResources ( disposeCapability, completion )`][spec].
+    ///
+    /// Disposes the resources of this capability in reverse order until one of them must be
+    /// awaited, in which case this returns the value to await. When resumed, errors from the
+    /// awaited value must be reported with [`DisposeCapability::record_error`] before calling
+    /// this method again.
+    ///
+    /// Returns `None` once all resources have been disposed. The final completion can then be
+    /// retrieved with [`DisposeCapability::completion`].
+    ///
+    /// # Errors
+    ///
+    /// Only returns an error if a dispose method throws an uncatchable error.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn dispose_resources(&mut self, context: &mut Context) -> JsResult<Option<JsValue>> {
+        // 3. For each element resource of disposeCapability.[[DisposableResourceStack]], in reverse list order, do
+        while let Some(resource) = self.resources.pop() {
+            // a. Let value be resource.[[ResourceValue]].
+            // b. Let hint be resource.[[Hint]].
+            // c. Let method be resource.[[DisposeMethod]].
+            let DisposableResource {
+                value,
+                hint,
+                method,
+            } = resource;
+
+            // d. If hint is sync-dispose and needsAwait is true and hasAwaited is false, then
+            if hint == DisposeHint::Sync && self.needs_await && !self.has_awaited {
+                self.resources.push(DisposableResource {
+                    value,
+                    hint,
+                    method,
+                });
+
+                // i. Perform ! Await(undefined).
+                // ii. Set needsAwait to false.
+                self.needs_await = false;
+                return Ok(Some(JsValue::undefined()));
+            }
+
+            // e. If method is not undefined, then
+            if let Some(method) = method {
+                // i. Let result be Completion(Call(method, value)).
+                match method.call(&value, &[], context) {
+                    // ii. If result is a normal completion and hint is async-dispose, then
+                    Ok(result) if hint == DisposeHint::Async => {
+                        // 1. Set result to Completion(Await(result.[[Value]])).
+                        // 2. Set hasAwaited to true.
+                        self.has_awaited = true;
+                        return Ok(Some(result));
+                    }
+                    Ok(_) => {}
+                    // iii. If result is a throw completion, then
+                    Err(err) => {
+                        let err = err.into_opaque(context)?;
+                        self.record_error(err, context);
+                    }
+                }
+            } else {
+                // f. Else,
+                //    i. Assert: hint is async-dispose.
+                debug_assert_eq!(hint, DisposeHint::Async);
+
+                //    ii. Set needsAwait to true.
+                //    iii. NOTE: This can only indicate a case where either null or undefined was
+                //         the initialized value of an await using declaration.
+                self.needs_await = true;
+            }
+        }
+
+        // 4. If needsAwait is true and hasAwaited is false, then
+        if self.needs_await && !self.has_awaited {
+            // a. Perform ! Await(undefined).
+            self.needs_await = false;
+            return Ok(Some(JsValue::undefined()));
+        }
+
+        // 5. NOTE: After disposeCapability has been disposed, it will never be used again. The
+        //    contents of disposeCapability.[[DisposableResourceStack]] can be discarded in
+        //    implementations, such as by garbage collection, at this point.
+        // 6. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+        Ok(None)
+    }
+
+    /// Returns the thrown value of the completion recorded by this capability, if any.
+    pub(crate) fn completion(&self) -> Option<&JsValue> {
+        self.completion.as_ref()
+    }
+
+    /// Disposes all the resources of this capability, without awaiting any of them.
+    ///
+    /// This must only be used by capabilities that only contain [`DisposeHint::Sync`] resources.
+    fn dispose_sync(mut self, context: &mut Context) -> JsResult<()> {
+        let value = self.dispose_resources(context)?;
+        debug_assert!(value.is_none(), "sync resources must not be awaited");
+
+        // 7. Return ? completion.
+        match self.completion {
+            Some(error) => Err(JsError::from_opaque(error)),
+            None => Ok(()),
+        }
+    }
+
+    /// Disposes all the resources of this capability from native code, settling
+    /// `promise_capability` with the final completion.
+    fn dispose_async(
+        mut self,
+        promise_capability: PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        /// The state of a suspended asynchronous disposal.
+        #[derive(Trace, Finalize)]
+        #[boa_gc(unsafe_no_drop)]
+        struct AsyncDisposal {
+            capability: DisposeCapability,
+            promise_capability: PromiseCapability,
+        }
+
+        loop {
+            let Some(value) = self.dispose_resources(context)? else {
+                // 7. Return ? completion.
+                return match self.completion {
+                    Some(error) => promise_capability
+                        .reject()
+                        .call(&JsValue::undefined(), &[error], context)
+                        .map(|_| ()),
+                    None => promise_capability
+                        .resolve()
+                        .call(&JsValue::undefined(), &[JsValue::undefined()], context)
+                        .map(|_| ()),
+                };
+            };
+
+            // Await(value)
+            let promise = match Promise::promise_resolve(
+                &context.intrinsics().constructors().promise().constructor(),
+                value,
+                context,
+            ) {
+                Ok(promise) => promise
+                    .downcast::<Promise>()
+                    .expect("%Promise% constructor must return a `Promise` object"),
+                Err(err) => {
+                    let err = err.into_opaque(context)?;
+                    self.record_error(err, context);
+                    continue;
+                }
+            };
+
+            let state = Gc::new(GcRefCell::new(Some(AsyncDisposal {
+                capability: self,
+                promise_capability,
+            })));
+
+            let on_fulfilled = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, _, state: &Gc<GcRefCell<Option<AsyncDisposal>>>, context| {
+                        let AsyncDisposal {
+                            capability,
+                            promise_capability,
+                        } = state
+                            .borrow_mut()
+                            .take()
+                            .js_expect("should only run once")?;
+                        capability.dispose_async(promise_capability, context)?;
+                        Ok(JsValue::undefined())
+                    },
+                    state.clone(),
+                ),
+            )
+            .name(js_string!())
+            .length(1)
+            .build();
+
+            let on_rejected = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, state: &Gc<GcRefCell<Option<AsyncDisposal>>>, context| {
+                        let AsyncDisposal {
+                            mut capability,
+                            promise_capability,
+                        } = state
+                            .borrow_mut()
+                            .take()
+                            .js_expect("should only run once")?;
+                        capability.record_error(args.get_or_undefined(0).clone(), context);
+                        capability.dispose_async(promise_capability, context)?;
+                        Ok(JsValue::undefined())
+                    },
+                    state,
+                ),
+            )
+            .name(js_string!())
+            .length(1)
+            .build();
+
+            Promise::perform_promise_then(
+                &promise,
+                Some(on_fulfilled),
+                Some(on_rejected),
+                None,
+                context,
+            );
+
+            return Ok(());
+        }
+    }
+}
+
+/// The `DisposableStack` builtin object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct DisposableStack {
+    /// The `[[DisposeCapability]]` of the stack, or `None` if its
+    /// `[[DisposableState]]` is `disposed`.
+    capability: Option<DisposeCapability>,
+}
+
+impl IntrinsicObject for DisposableStack {
+    fn init(realm: &Realm) {
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose = BuiltInBuilder::callable(realm, Self::dispose)
+            .name(js_string!("dispose"))
+            .length(0)
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("dispose"),
+                dispose.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+            )
+            .property(
+                JsSymbol::dispose(),
+                dispose,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisposableStack {
+    const NAME: JsString = StaticJsStrings::DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for DisposableStack {
+    const CONSTRUCTOR_ARGUMENTS: usize = 0;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 9;
+    const CONSTRUCTOR_STORAGE_SLOTS: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::disposable_stack;
+
+    /// [`DisposableStack ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(js_error!(
+                TypeError: "DisposableStack: cannot call constructor without `new`"
+            ));
+        }
+
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::disposable_stack,
+            context,
+        )?;
+
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        let stack = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                capability: Some(DisposeCapability::new()),
+            },
+        );
+
+        // 5. Return disposableStack.
+        Ok(stack.upcast().into())
+    }
+}
+
+impl DisposableStack {
+    /// Returns the `DisposableStack` object in `this`, or throws a `TypeError` otherwise.
+    fn this_stack(this: &JsValue, method: &str) -> JsResult<JsObject<Self>> {
+        this.as_object()
+            .and_then(|o| o.downcast::<Self>().ok())
+            .ok_or_else(|| {
+                js_error!(
+                    TypeError: "DisposableStack.prototype.{}: invalid object type for `this`",
+                    method
+                )
+            })
+    }
+
+    /// Returns an error if the stack in `this` has already been disposed.
+    fn check_pending(stack: &JsObject<Self>, method: &str) -> JsResult<()> {
+        if stack.borrow().data().capability.is_none() {
+            return Err(js_error!(
+                ReferenceError: "DisposableStack.prototype.{}: the stack has already been disposed",
+                method
+            ));
+        }
+        Ok(())
+    }
+
+    /// Appends `resource` to the stack, if it has not been disposed in the meantime.
+    fn add_resource(stack: &JsObject<Self>, resource: DisposableResource) {
+        if let Some(capability) = stack.borrow_mut().data_mut().capability.as_mut() {
+            capability.add_resource(resource);
+        }
+    }
+
+    /// [`get DisposableStack.prototype.disposed`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "disposed")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        Ok(stack.borrow().data().capability.is_none().into())
+    }
+
+    /// [`DisposableStack.prototype.dispose ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose
+    fn dispose(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "dispose")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+        // 4. Set disposableStack.[[DisposableState]] to disposed.
+        let Some(capability) = stack.borrow_mut().data_mut().capability.take() else {
+            return Ok(JsValue::undefined());
+        };
+
+        // 5. Return ? DisposeResources(disposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        capability.dispose_sync(context)?;
+        Ok(JsValue::undefined())
+    }
+
+    /// [`DisposableStack.prototype.use ( value )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "use")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], value, sync-dispose).
+        let value = args.get_or_undefined(0);
+        if let Some(resource) = DisposableResource::from_value(value, DisposeHint::Sync, context)? {
+            Self::add_resource(&stack, resource);
+        }
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// [`DisposableStack.prototype.adopt ( value, onDispose )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "adopt")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "adopt")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let value = args.get_or_undefined(0);
+        let on_dispose = args
+            .get_or_undefined(1)
+            .as_callable()
+            .ok_or_else(|| {
+                js_error!(TypeError: "DisposableStack.prototype.adopt: `onDispose` must be callable")
+            })?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDispose and performs the following steps when called:
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let f = adopt_closure(value.clone(), on_dispose, context);
+
+        // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, F).
+        Self::add_resource(
+            &stack,
+            DisposableResource::from_method(f, DisposeHint::Sync),
+        );
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// [`DisposableStack.prototype.defer ( onDispose )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "defer")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        Self::check_pending(&stack, "defer")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = args
+            .get_or_undefined(0)
+            .as_callable()
+            .ok_or_else(|| {
+                js_error!(TypeError: "DisposableStack.prototype.defer: `onDispose` must be callable")
+            })?;
+
+        // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, onDispose).
+        Self::add_resource(
+            &stack,
+            DisposableResource::from_method(on_dispose, DisposeHint::Sync),
+        );
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`DisposableStack.prototype.move ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let stack = Self::this_stack(this, "move")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set disposableStack.[[DisposableState]] to disposed.
+        let Some(capability) = stack.borrow_mut().data_mut().capability.take() else {
+            return Err(js_error!(
+                ReferenceError: "DisposableStack.prototype.move: the stack has already been disposed"
+            ));
+        };
+
+        // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(%DisposableStack%, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        // 5. Set newDisposableStack.[[DisposableState]] to pending.
+        // 6. Set newDisposableStack.[[DisposeCapability]] to disposableStack.[[DisposeCapability]].
+        let new_stack = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .disposable_stack()
+                .prototype(),
+            Self {
+                capability: Some(capability),
+            },
+        );
+
+        // 9. Return newDisposableStack.
+        Ok(new_stack.upcast().into())
+    }
+}
+
+/// Creates the closure used by the `adopt` methods of `DisposableStack` and
+/// `AsyncDisposableStack`, which calls `on_dispose` with `value`.
+fn adopt_closure(value: JsValue, on_dispose: JsObject, context: &mut Context) -> JsObject {
+    #[derive(Trace, Finalize)]
+    struct Captures {
+        value: JsValue,
+        on_dispose: JsObject,
+    }
+
+    FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, _, captures, context| {
+                // a. Return ? Call(onDispose, undefined, « value »).
+                captures.on_dispose.call(
+                    &JsValue::undefined(),
+                    std::slice::from_ref(&captures.value),
+                    context,
+                )
+            },
+            Captures { value, on_dispose },
+        ),
+    )
+    .name(js_string!())
+    .length(0)
+    .build()
+    .into()
+}
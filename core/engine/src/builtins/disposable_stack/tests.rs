@@ -0,0 +1,198 @@
+use crate::{JsNativeErrorKind, TestAction, run_test_actions};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn using_disposes_in_reverse_order() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            function resource(name) {
+                return { [Symbol.dispose]() { log.push(name); } };
+            }
+
+            {
+                using a = resource("a");
+                using b = resource("b"), c = resource("c");
+                using d = null;
+                log.push("body");
+            }
+        "#}),
+        TestAction::assert_eq("log.join()", js_str!("body,c,b,a")),
+    ]);
+}
+
+#[test]
+fn using_disposes_on_abrupt_completions() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            function resource(name) {
+                return { [Symbol.dispose]() { log.push(name); } };
+            }
+
+            function f() {
+                using a = resource("return");
+                return "value";
+            }
+
+            for (let i = 0; i < 2; i++) {
+                using b = resource("loop" + i);
+                if (i === 0) continue;
+                break;
+            }
+
+            var result = f();
+
+            switch (1) {
+                case 0:
+                    using c = resource("never");
+                case 1:
+                    log.push("case");
+            }
+        "#}),
+        TestAction::assert_eq("result", js_str!("value")),
+        TestAction::assert_eq("log.join()", js_str!("loop0,loop1,return,case")),
+    ]);
+}
+
+#[test]
+fn using_suppressed_error() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var error;
+            try {
+                using a = { [Symbol.dispose]() { throw "first"; } };
+                using b = { [Symbol.dispose]() { throw "second"; } };
+                throw "body";
+            } catch (e) {
+                error = e;
+            }
+        "#}),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error", js_str!("first")),
+        TestAction::assert("error.suppressed instanceof SuppressedError"),
+        TestAction::assert_eq("error.suppressed.error", js_str!("second")),
+        TestAction::assert_eq("error.suppressed.suppressed", js_str!("body")),
+    ]);
+}
+
+#[test]
+fn using_invalid_value() {
+    run_test_actions([TestAction::assert_native_error(
+        "{ using x = {}; }",
+        JsNativeErrorKind::Type,
+        "value does not have a [Symbol.dispose] method",
+    )]);
+}
+
+#[test]
+fn using_for_of() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var resources = [1, 2].map(i => ({ [Symbol.dispose]() { log.push("dispose" + i); } }));
+            for (using r of resources) {
+                log.push("body");
+            }
+        "#}),
+        TestAction::assert_eq("log.join()", js_str!("body,dispose1,body,dispose2")),
+    ]);
+}
+
+#[test]
+fn await_using() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            async function f() {
+                {
+                    await using a = {
+                        async [Symbol.asyncDispose]() { log.push("async"); }
+                    };
+                    using b = { [Symbol.dispose]() { log.push("sync"); } };
+                    log.push("body");
+                }
+                log.push("after");
+            }
+            f();
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::assert_eq("log.join()", js_str!("body,sync,async,after")),
+    ]);
+}
+
+#[test]
+fn disposable_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var stack = new DisposableStack();
+            var value = stack.use({ [Symbol.dispose]() { log.push("use"); } });
+            stack.adopt(1, v => log.push("adopt" + v));
+            stack.defer(() => log.push("defer"));
+            var moved = stack.move();
+        "#}),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert("!moved.disposed"),
+        TestAction::assert("moved[Symbol.dispose] === moved.dispose"),
+        TestAction::run("moved.dispose(); moved.dispose();"),
+        TestAction::assert("moved.disposed"),
+        TestAction::assert_eq("log.join()", js_str!("defer,adopt1,use")),
+        TestAction::assert_native_error(
+            "stack.defer(() => {})",
+            JsNativeErrorKind::Reference,
+            "DisposableStack.prototype.defer: the stack has already been disposed",
+        ),
+    ]);
+}
+
+#[test]
+fn async_disposable_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var stack = new AsyncDisposableStack();
+            stack.use({ [Symbol.dispose]() { log.push("sync"); } });
+            stack.defer(async () => { log.push("async"); throw "error"; });
+            var error;
+            stack.disposeAsync().catch(e => { error = e; });
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs().unwrap()),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert_eq("log.join()", js_str!("async,sync")),
+        TestAction::assert_eq("error", js_str!("error")),
+    ]);
+}
+
+#[test]
+fn suppressed_error() {
+    run_test_actions([
+        TestAction::run("var e = new SuppressedError(1, 2, 'message');"),
+        TestAction::assert_eq("e.error", 1),
+        TestAction::assert_eq("e.suppressed", 2),
+        TestAction::assert_eq("e.message", js_str!("message")),
+        TestAction::assert("!Object.prototype.propertyIsEnumerable.call(e, 'error')"),
+    ]);
+}
+
+#[test]
+fn iterator_dispose() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var closed = false;
+            function* gen() {
+                try {
+                    yield 1;
+                } finally {
+                    closed = true;
+                }
+            }
+            {
+                using it = gen();
+                it.next();
+            }
+        "#}),
+        TestAction::assert("closed"),
+    ]);
+}
@@ -21,7 +21,7 @@ use crate::{
     string::StaticJsStrings,
 };
 
-use super::{Error, ErrorKind};
+use super::{Error, ErrorKind, stack};
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct AggregateError;
@@ -130,6 +130,8 @@ impl BuiltInConstructor for AggregateError {
         )
         .js_expect("should not fail according to spec")?;
 
+        stack::capture_header(&o, context)?;
+
         // 5. Return O.
         Ok(o.into())
     }
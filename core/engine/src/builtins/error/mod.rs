@@ -12,12 +12,12 @@
 
 use crate::{
     Context, JsArgs, JsData, JsResult, JsString, JsValue,
-    builtins::BuiltInObject,
+    builtins::{BuiltInObject, function::OrdinaryFunction},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::{IgnoreEq, JsNativeError},
     js_string,
     object::{JsObject, internal_methods::get_prototype_from_constructor},
-    property::Attribute,
+    property::{Attribute, PropertyDescriptor},
     realm::Realm,
     string::StaticJsStrings,
     vm::shadow_stack::{Backtrace, ShadowEntry},
@@ -29,6 +29,8 @@ pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
+pub(crate) mod stack;
+pub(crate) mod suppressed;
 pub(crate) mod syntax;
 pub(crate) mod r#type;
 pub(crate) mod uri;
@@ -40,6 +42,8 @@ pub(crate) use self::aggregate::AggregateError;
 pub(crate) use self::eval::EvalError;
 pub(crate) use self::range::RangeError;
 pub(crate) use self::reference::ReferenceError;
+pub use self::stack::StackFrame;
+pub(crate) use self::suppressed::SuppressedError;
 pub(crate) use self::syntax::SyntaxError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::uri::UriError;
@@ -116,6 +120,14 @@ pub enum ErrorKind {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-urierror
     Uri,
+
+    /// The `SuppressedError` type.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed,
 }
 
 /// A built-in `Error` object, per the [ECMAScript spec][spec].
@@ -143,6 +155,11 @@ pub struct Error {
     // rejection. Does not affect equality checks.
     #[unsafe_ignore_trace]
     pub(crate) backtrace: IgnoreEq<Option<Backtrace>>,
+
+    // The header of the `stack` string, captured along with the backtrace once the error
+    // is initialized. Does not affect equality checks.
+    #[unsafe_ignore_trace]
+    pub(crate) stack_header: IgnoreEq<Option<JsString>>,
 }
 
 impl Error {
@@ -154,6 +171,7 @@ impl Error {
             tag,
             position: IgnoreEq(None),
             backtrace: IgnoreEq(None),
+            stack_header: IgnoreEq(None),
         }
     }
 
@@ -163,15 +181,18 @@ impl Error {
             tag,
             position: IgnoreEq(entry),
             backtrace: IgnoreEq(None),
+            stack_header: IgnoreEq(None),
         }
     }
 
-    /// Get the position from the last called function.
+    /// Get the position from the last called function, and capture the backtrace of the
+    /// error.
     pub(crate) fn with_caller_position(tag: ErrorKind, context: &Context) -> Self {
         Self {
             tag,
             position: IgnoreEq(context.vm.shadow_stack.caller_position()),
-            backtrace: IgnoreEq(None),
+            backtrace: IgnoreEq(Some(stack::capture_backtrace(context))),
+            stack_header: IgnoreEq(None),
         }
     }
 }
@@ -179,10 +200,30 @@ impl Error {
 impl IntrinsicObject for Error {
     fn init(realm: &Realm) {
         let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+
+        let get_stack = BuiltInBuilder::callable(realm, Self::get_stack)
+            .name(js_string!("get stack"))
+            .build();
+        let set_stack = BuiltInBuilder::callable(realm, Self::set_stack)
+            .name(js_string!("set stack"))
+            .length(1)
+            .build();
+
         let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::capture_stack_trace,
+                js_string!("captureStackTrace"),
+                2,
+            )
             .property(js_string!("name"), Self::NAME, attribute)
             .property(js_string!("message"), js_string!(), attribute)
-            .method(Self::to_string, js_string!("toString"), 0);
+            .method(Self::to_string, js_string!("toString"), 0)
+            .accessor(
+                js_string!("stack"),
+                Some(get_stack),
+                Some(set_stack),
+                Attribute::CONFIGURABLE,
+            );
 
         #[cfg(feature = "experimental")]
         let builder = builder.static_method(Error::is_error, js_string!("isError"), 1);
@@ -201,8 +242,8 @@ impl BuiltInObject for Error {
 
 impl BuiltInConstructor for Error {
     const CONSTRUCTOR_ARGUMENTS: usize = 1;
-    const PROTOTYPE_STORAGE_SLOTS: usize = 3;
-    const CONSTRUCTOR_STORAGE_SLOTS: usize = 1;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 5;
+    const CONSTRUCTOR_STORAGE_SLOTS: usize = 2;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::error;
@@ -248,6 +289,8 @@ impl BuiltInConstructor for Error {
         // 4. Perform ? InstallErrorCause(O, options).
         Self::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        stack::capture_header(&o, context)?;
+
         // 5. Return O.
         Ok(o.into())
     }
@@ -329,6 +372,147 @@ impl Error {
         Ok(js_string!(&name, js_str!(": "), &msg).into())
     }
 
+    /// `get Error.prototype.stack`
+    ///
+    /// Returns the stack trace captured when the error was created, formatted by
+    /// [`HostHooks::format_error_stack`][crate::context::HostHooks::format_error_stack].
+    ///
+    /// More information:
+    ///  - [Error stacks proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-error-stacks/
+    pub(crate) fn get_stack(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let E be the this value.
+        // 2. If E is not an Object, throw a TypeError exception.
+        let Some(error) = this.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("Error.prototype.stack getter called on a non-object")
+                .into());
+        };
+
+        // 3. If E does not have an [[ErrorData]] internal slot, return undefined.
+        let (header, backtrace) = {
+            let Some(data) = error.downcast_ref::<Error>() else {
+                return Ok(JsValue::undefined());
+            };
+            (
+                data.stack_header.0.clone(),
+                data.backtrace.0.clone().unwrap_or_default(),
+            )
+        };
+        let header = match header {
+            Some(header) => header,
+            None => stack::header(&error, context)?,
+        };
+
+        // 4. Return GetStackString(E).
+        Ok(stack::stack_string(&error, &header, &backtrace, context)?.into())
+    }
+
+    /// `set Error.prototype.stack`
+    ///
+    /// Defines an own `stack` property on the this value, leaving `Error.prototype` untouched.
+    ///
+    /// More information:
+    ///  - [Error stacks proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-error-stacks/
+    pub(crate) fn set_stack(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let E be the this value.
+        // 2. If E is not an Object, throw a TypeError exception.
+        let Some(error) = this.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("Error.prototype.stack setter called on a non-object")
+                .into());
+        };
+
+        // 3. Let numberOfArgs be the number of arguments passed to this function call.
+        // 4. If numberOfArgs is 0, throw a TypeError exception.
+        let Some(value) = args.first() else {
+            return Err(JsNativeError::typ()
+                .with_message("Error.prototype.stack setter requires an argument")
+                .into());
+        };
+
+        // 5. Perform ? SetterThatIgnoresPrototypeProperties(E, %Error.prototype%, "stack", value).
+        let home = context.intrinsics().constructors().error().prototype();
+        if JsObject::equals(&error, &home) {
+            return Err(JsNativeError::typ()
+                .with_message("cannot set the stack of Error.prototype")
+                .into());
+        }
+        let key = js_string!("stack");
+        if error
+            .__get_own_property__(&key.clone().into(), &mut context.into())?
+            .is_none()
+        {
+            error.create_data_property_or_throw(key, value.clone(), context)?;
+        } else {
+            error.set(key, value.clone(), true, context)?;
+        }
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `Error.captureStackTrace( targetObject [ , constructorOpt ] )`
+    ///
+    /// Defines a `stack` property on `targetObject` with the current stack trace. If
+    /// `constructorOpt` is a function, its innermost call and all the frames above it are left
+    /// out of the trace.
+    ///
+    /// More information:
+    ///  - [V8 documentation][v8]
+    ///
+    /// [v8]: https://v8.dev/docs/stack-trace-api#stack-trace-collection-for-custom-exceptions
+    pub(crate) fn capture_stack_trace(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let Some(target) = args.get_or_undefined(0).as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("Error.captureStackTrace: target must be an object")
+                .into());
+        };
+
+        // Leave out the frame of `captureStackTrace` itself.
+        let mut backtrace = context
+            .vm
+            .shadow_stack
+            .take(usize::MAX, context.vm.frame().pc);
+        backtrace.pop_native();
+
+        if let Some(function) = args.get_or_undefined(1).as_object()
+            && let Some(function) = function.downcast_ref::<OrdinaryFunction>()
+        {
+            backtrace.truncate_before(function.code.source_info());
+        }
+        backtrace.truncate(context.vm.runtime_limits.backtrace_limit());
+
+        let header = stack::header(&target, context)?;
+        let stack = stack::stack_string(&target, &header, &backtrace, context)?;
+        target.define_property_or_throw(
+            js_string!("stack"),
+            PropertyDescriptor::builder()
+                .value(stack)
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+
+        Ok(JsValue::undefined())
+    }
+
     /// [`Error.isError`][spec].
     ///
     /// Returns a boolean indicating whether the argument is a built-in Error instance or not.
@@ -392,6 +576,8 @@ impl Error {
 
         Error::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        stack::capture_header(&o, context)?;
+
         Ok(o.into())
     }
 }
@@ -0,0 +1,214 @@
+//! Support for the non-standard `stack` property of error objects.
+//!
+//! The stack of an error is captured from the shadow stack of the VM when the error is created,
+//! along with its header, and formatted lazily, following the format used by V8.
+//!
+//! More information:
+//!  - [Error stacks proposal][proposal]
+//!  - [V8 documentation][v8]
+//!
+//! [proposal]: https://github.com/tc39/proposal-error-stacks
+//! [v8]: https://v8.dev/docs/stack-trace-api
+
+use std::fmt::{self, Display};
+
+use crate::{
+    Context, JsObject, JsResult, JsString,
+    error::IgnoreEq,
+    vm::{
+        SourcePath,
+        shadow_stack::{Backtrace, ShadowEntry},
+    },
+};
+
+use super::Error;
+
+/// A frame of the stack trace of an error object.
+///
+/// The frames of a stack trace are passed to [`HostHooks::format_error_stack`] to build the
+/// `stack` property of error objects.
+///
+/// [`HostHooks::format_error_stack`]: crate::context::HostHooks::format_error_stack
+#[derive(Debug, Clone)]
+pub struct StackFrame {
+    function_name: Option<JsString>,
+    path: Option<SourcePath>,
+    position: Option<(u32, u32)>,
+}
+
+impl StackFrame {
+    pub(crate) fn from_entry(entry: &ShadowEntry) -> Self {
+        match entry {
+            ShadowEntry::Native { function_name, .. } => Self {
+                function_name: function_name.clone(),
+                path: None,
+                position: None,
+            },
+            ShadowEntry::Bytecode { pc, source_info } => {
+                let function_name =
+                    Some(source_info.function_name().clone()).filter(|name| !name.is_empty());
+
+                if let Some(location) = source_info.original_location(*pc) {
+                    return Self {
+                        function_name,
+                        path: Some(location.path.into()),
+                        position: Some((
+                            location.position.line_number(),
+                            location.position.column_number(),
+                        )),
+                    };
+                }
+
+                Self {
+                    function_name,
+                    path: Some(source_info.map().path().clone()),
+                    position: source_info
+                        .map()
+                        .find(*pc)
+                        .map(|position| (position.line_number(), position.column_number())),
+                }
+            }
+        }
+    }
+
+    /// Gets the name of the function of this frame, if it has one.
+    #[inline]
+    #[must_use]
+    pub const fn function_name(&self) -> Option<&JsString> {
+        self.function_name.as_ref()
+    }
+
+    /// Returns `true` if this frame belongs to a native function.
+    #[inline]
+    #[must_use]
+    pub const fn is_native(&self) -> bool {
+        self.path.is_none()
+    }
+
+    /// Gets the path of the source of this frame, or `None` for native functions.
+    #[inline]
+    #[must_use]
+    pub const fn path(&self) -> Option<&SourcePath> {
+        self.path.as_ref()
+    }
+
+    /// Gets the line number of this frame, if it is known.
+    #[inline]
+    #[must_use]
+    pub fn line_number(&self) -> Option<u32> {
+        self.position.map(|(line, _)| line)
+    }
+
+    /// Gets the column number of this frame, if it is known.
+    #[inline]
+    #[must_use]
+    pub fn column_number(&self) -> Option<u32> {
+        self.position.map(|(_, column)| column)
+    }
+}
+
+impl Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(function_name) = &self.function_name {
+            write!(f, "{}", function_name.to_std_string_escaped())?;
+        } else {
+            f.write_str("<anonymous>")?;
+        }
+
+        let Some(path) = &self.path else {
+            return f.write_str(" (native)");
+        };
+
+        f.write_str(" (")?;
+        match path {
+            SourcePath::None => f.write_str("<anonymous>")?,
+            SourcePath::Eval => f.write_str("eval")?,
+            SourcePath::Json => f.write_str("json")?,
+            SourcePath::Path(path) => write!(f, "{}", path.display())?,
+        }
+        if let Some((line, column)) = self.position {
+            write!(f, ":{line}:{column}")?;
+        }
+        f.write_str(")")
+    }
+}
+
+/// Converts a backtrace into its stack frames, starting from the innermost one.
+///
+/// Positions are mapped back to the original sources of code that has a source map. Since source
+/// maps don't describe the names of functions, the original name of a function is taken from the
+/// mapping of its call site in the calling frame, if there is one.
+pub(crate) fn frames(backtrace: &Backtrace) -> Vec<StackFrame> {
+    let entries = backtrace.iter().rev().collect::<Vec<_>>();
+    entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let mut frame = StackFrame::from_entry(entry);
+            let caller_name = entries.get(i + 1).and_then(|caller| match caller {
+                ShadowEntry::Bytecode { pc, source_info } => {
+                    source_info.original_location(*pc)?.name
+                }
+                ShadowEntry::Native { .. } => None,
+            });
+            if !frame.is_native()
+                && let Some(name) = caller_name
+            {
+                frame.function_name = Some(name);
+            }
+            frame
+        })
+        .collect()
+}
+
+/// Captures the backtrace of an error object created by a native function, leaving out the frame
+/// of that function.
+pub(crate) fn capture_backtrace(context: &Context) -> Backtrace {
+    let limit = context.vm.runtime_limits.backtrace_limit();
+    let mut backtrace = context
+        .vm
+        .shadow_stack
+        .take(limit.saturating_add(1), context.vm.frame().pc);
+    backtrace.pop_native();
+    backtrace.truncate(limit);
+    backtrace
+}
+
+/// Computes the header of the `stack` string of `error`, which is the result of
+/// `Error.prototype.toString` on it.
+pub(crate) fn header(error: &JsObject, context: &mut Context) -> JsResult<JsString> {
+    Error::to_string(&error.clone().into(), &[], context)?.to_string(context)
+}
+
+/// Stores the header of the `stack` string of a newly created `error`, once its `message` has
+/// been installed.
+///
+/// As in V8, the header is captured along with the backtrace, so changing the `name` or the
+/// `message` of an error afterwards doesn't change its stack. If the header cannot be computed
+/// here, it is computed when the stack is read instead.
+pub(crate) fn capture_header(error: &JsObject, context: &mut Context) -> JsResult<()> {
+    match header(error, context) {
+        Ok(header) => {
+            if let Some(mut data) = error.downcast_mut::<Error>() {
+                data.stack_header = IgnoreEq(Some(header));
+            }
+            Ok(())
+        }
+        Err(err) if err.is_catchable() => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Builds the `stack` string of `error` from its `header` and its captured `backtrace`.
+pub(crate) fn stack_string(
+    error: &JsObject,
+    header: &JsString,
+    backtrace: &Backtrace,
+    context: &mut Context,
+) -> JsResult<JsString> {
+    let frames = frames(backtrace);
+
+    context
+        .host_hooks()
+        .format_error_stack(error, header, &frames, context)
+}
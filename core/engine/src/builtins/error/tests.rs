@@ -1,4 +1,4 @@
-use crate::{TestAction, run_test_actions};
+use crate::{JsNativeErrorKind, JsValue, TestAction, run_test_actions};
 use boa_macros::js_str;
 use indoc::indoc;
 
@@ -52,6 +52,7 @@ fn error_names() {
         TestAction::assert_eq("URIError.name", js_str!("URIError")),
         TestAction::assert_eq("TypeError.name", js_str!("TypeError")),
         TestAction::assert_eq("AggregateError.name", js_str!("AggregateError")),
+        TestAction::assert_eq("SuppressedError.name", js_str!("SuppressedError")),
     ]);
 }
 
@@ -66,5 +67,115 @@ fn error_lengths() {
         TestAction::assert_eq("URIError.length", 1),
         TestAction::assert_eq("TypeError.length", 1),
         TestAction::assert_eq("AggregateError.length", 2),
+        TestAction::assert_eq("SuppressedError.length", 3),
+    ]);
+}
+
+#[test]
+fn error_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function inner() {
+                return new TypeError("boom");
+            }
+            function outer() {
+                return inner();
+            }
+            var error = outer();
+        "#}),
+        TestAction::assert_eq(
+            "error.stack",
+            js_str!(
+                "TypeError: boom\n    at inner (<anonymous>:2:12)\n    at outer (<anonymous>:5:17)\n    at <main> (<anonymous>:7:18)"
+            ),
+        ),
+        TestAction::assert("!Object.hasOwn(error, 'stack')"),
+        TestAction::assert_eq(
+            "Object.getOwnPropertyDescriptor(Error.prototype, 'stack').get.call({})",
+            JsValue::undefined(),
+        ),
+        TestAction::assert_eq("error.stack = 'custom'; error.stack", js_str!("custom")),
+        TestAction::assert("Object.hasOwn(error, 'stack')"),
+        TestAction::assert_native_error(
+            "Error.prototype.stack = 'custom'",
+            JsNativeErrorKind::Type,
+            "cannot set the stack of Error.prototype",
+        ),
+    ]);
+}
+
+#[test]
+fn error_capture_stack_trace() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function MyError() {
+                Error.captureStackTrace(this, MyError);
+            }
+            function create() {
+                return new MyError();
+            }
+            var error = create();
+            var plain = {};
+            Error.captureStackTrace(plain);
+        "#}),
+        TestAction::assert_eq(
+            "error.stack",
+            js_str!("Error\n    at create (<anonymous>:5:12)\n    at <main> (<anonymous>:7:19)"),
+        ),
+        TestAction::assert_eq(
+            "plain.stack",
+            js_str!("Error\n    at <main> (<anonymous>:9:24)"),
+        ),
+        TestAction::assert_native_error(
+            "Error.captureStackTrace(1)",
+            JsNativeErrorKind::Type,
+            "Error.captureStackTrace: target must be an object",
+        ),
+    ]);
+}
+
+#[test]
+fn error_stack_backtrace_limit() {
+    run_test_actions([
+        TestAction::inspect_context(|ctx| {
+            ctx.runtime_limits_mut().set_backtrace_limit(1);
+        }),
+        TestAction::run(indoc! {r#"
+            function inner() {
+                return new Error("limited");
+            }
+            var error = inner();
+        "#}),
+        TestAction::assert_eq(
+            "error.stack",
+            js_str!("Error: limited\n    at inner (<anonymous>:2:12)"),
+        ),
+    ]);
+}
+
+#[test]
+fn error_stack_source_map() {
+    // Generated from:
+    //
+    // function inner() {
+    //     return new Error("boom");
+    // }
+    // function outer() {
+    //     return inner();
+    // }
+    // var error = outer();
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function a(){return new Error("boom")}
+            function b(){return a()}
+            var error=b();
+            //# sourceMappingURL=data:application/json;base64,eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbImFwcC50cyJdLCJzb3VyY2VSb290Ijoic3JjIiwibmFtZXMiOlsiaW5uZXIiLCJvdXRlciIsImVycm9yIl0sIm1hcHBpbmdzIjoiU0FBU0EsV0FDRTtTQUVGQyxXQUNFRDtJQUVQRSxNQUFRRCJ9
+        "#}),
+        TestAction::assert_eq(
+            "error.stack",
+            js_str!(
+                "Error: boom\n    at inner (src/app.ts:2:12)\n    at outer (src/app.ts:5:12)\n    at <main> (src/app.ts:7:13)"
+            ),
+        ),
     ]);
 }
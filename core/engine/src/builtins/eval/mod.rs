@@ -74,7 +74,7 @@ impl Eval {
         x: &JsValue,
         direct: bool,
         lexical_scope: Option<Scope>,
-        mut strict: bool,
+        strict: bool,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         bitflags::bitflags! {
@@ -88,13 +88,6 @@ impl Eval {
             }
         }
 
-        /// Possible actions that can be executed after exiting this function to restore the environment to its
-        /// original state.
-        enum EnvStackAction {
-            Truncate(usize),
-            Restore(SavedEnvironments),
-        }
-
         // 1. Assert: If direct is false, then strictCaller is also false.
         debug_assert!(direct || !strict);
 
@@ -129,7 +122,7 @@ impl Eval {
         if strict {
             parser.set_strict();
         }
-        let (mut body, source) = parser.parse_eval(direct, context.interner_mut())?;
+        let (body, source) = parser.parse_eval(direct, context.interner_mut())?;
 
         // 6. Let inFunction be false.
         // 7. Let inMethod be false.
@@ -201,6 +194,31 @@ impl Eval {
                 .into());
         }
 
+        Self::evaluate_body(body, source, direct, lexical_scope, strict, context)
+    }
+
+    /// Evaluates the parsed `body` of a script in the current realm, as if it was the code of
+    /// an `eval` call.
+    ///
+    /// This contains the steps of [`PerformEval`][spec] that come after parsing and validating
+    /// the early errors of the script, which allows reusing them for `ShadowRealm.prototype.evaluate`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn evaluate_body(
+        mut body: boa_ast::Script,
+        source: boa_ast::SourceText,
+        direct: bool,
+        lexical_scope: Option<Scope>,
+        mut strict: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        /// Possible actions that can be executed after exiting this function to restore the environment to its
+        /// original state.
+        enum EnvStackAction {
+            Truncate(usize),
+            Restore(SavedEnvironments),
+        }
+
         strict |= body.strict();
 
         // Because our environment model does not map directly to the spec, this section looks very different.
@@ -349,7 +367,7 @@ impl Eval {
             let global = frame.realm.environment();
             frame
                 .environments
-                .push_lexical(lexical_scope.num_bindings_non_local(), global);
+                .push_lexical(lexical_scope.clone(), global);
         }
 
         context
@@ -77,6 +77,15 @@ impl BoundFunction {
         .upcast())
     }
 
+    /// Creates the data of a bound function without checking its target.
+    pub(crate) fn new(target_function: JsObject, this: JsValue, args: Vec<JsValue>) -> Self {
+        Self {
+            target_function,
+            this,
+            args,
+        }
+    }
+
     /// Get a reference to the bound function's this.
     #[must_use]
     pub const fn this(&self) -> &JsValue {
@@ -94,6 +103,12 @@ impl BoundFunction {
     pub fn args(&self) -> &[JsValue] {
         self.args.as_slice()
     }
+
+    /// Replaces the bound `this` value and arguments of the bound function.
+    pub(crate) fn set_bound_values(&mut self, this: JsValue, args: Vec<JsValue>) {
+        self.this = this;
+        self.args = args;
+    }
 }
 
 /// Internal method `[[Call]]` for Bound Function Exotic Objects
@@ -0,0 +1,972 @@
+//! Runtime support for the decorators proposal.
+//!
+//! The bytecompiler records every decorated element of a class definition in a
+//! [`ClassDecoration`], which applies the decorators once all the elements of the class have been
+//! evaluated.
+//!
+//! More information:
+//!  - [Decorators proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-decorators/
+
+use bitflags::bitflags;
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+use boa_macros::js_str;
+use thin_vec::ThinVec;
+
+use crate::{
+    Context, JsArgs, JsData, JsExpect, JsObject, JsResult, JsString, JsSymbol, JsValue,
+    builtins::{
+        OrdinaryObject,
+        function::{ClassFieldDefinition, OrdinaryFunction, set_function_name},
+    },
+    js_error, js_string,
+    native_function::NativeFunction,
+    object::{
+        FunctionObjectBuilder, JsFunction, PrivateElement, PrivateName,
+        internal_methods::InternalMethodPropertyContext,
+    },
+    property::{PropertyDescriptor, PropertyKey},
+};
+
+bitflags! {
+    /// Flags describing an element recorded with [`ClassDecoration::push_element`].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub(crate) struct ClassElementFlags: u32 {
+        /// The element is a getter.
+        const GETTER = 0b0000_0001;
+
+        /// The element is a setter.
+        const SETTER = 0b0000_0010;
+
+        /// The element is a field.
+        const FIELD = 0b0000_0100;
+
+        /// The element is an auto-accessor declared with the `accessor` keyword.
+        const ACCESSOR = 0b0000_1000;
+
+        /// The element is static.
+        const STATIC = 0b0001_0000;
+
+        /// The element has a private name.
+        const PRIVATE = 0b0010_0000;
+
+        /// The initializer of the field is an anonymous function definition.
+        const ANONYMOUS_FUNCTION = 0b0100_0000;
+    }
+}
+
+/// The kind of a decorated class element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+enum ClassElementKind {
+    Method,
+    Getter,
+    Setter,
+    Field,
+    Accessor,
+}
+
+impl ClassElementKind {
+    fn from_flags(flags: ClassElementFlags) -> Self {
+        if flags.contains(ClassElementFlags::GETTER) {
+            Self::Getter
+        } else if flags.contains(ClassElementFlags::SETTER) {
+            Self::Setter
+        } else if flags.contains(ClassElementFlags::FIELD) {
+            Self::Field
+        } else if flags.contains(ClassElementFlags::ACCESSOR) {
+            Self::Accessor
+        } else {
+            Self::Method
+        }
+    }
+
+    /// The value of the `kind` property of the decorator context object.
+    fn name(self) -> JsString {
+        match self {
+            Self::Method => js_string!("method"),
+            Self::Getter => js_string!("getter"),
+            Self::Setter => js_string!("setter"),
+            Self::Field => js_string!("field"),
+            Self::Accessor => js_string!("accessor"),
+        }
+    }
+
+    /// Returns `true` if the element is initialized on each instance or on the class itself.
+    const fn has_storage(self) -> bool {
+        matches!(self, Self::Field | Self::Accessor)
+    }
+}
+
+/// The key of a decorated class element.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum ClassElementKey {
+    Public(#[unsafe_ignore_trace] PropertyKey),
+    Private(PrivateName, JsString),
+}
+
+impl ClassElementKey {
+    /// The key used to set the name of the functions of the element.
+    fn function_name(&self) -> PropertyKey {
+        match self {
+            Self::Public(key) => key.clone(),
+            Self::Private(_, description) => js_string!(js_str!("#"), description).into(),
+        }
+    }
+
+    /// The value of the `name` property of the decorator context object.
+    fn context_name(&self) -> JsValue {
+        match self {
+            Self::Public(key) => key.clone().into(),
+            Self::Private(_, description) => js_string!(js_str!("#"), description).into(),
+        }
+    }
+}
+
+/// A class element with decorators, or an auto-accessor.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct DecoratedElement {
+    kind: ClassElementKind,
+    is_static: bool,
+    key: ClassElementKey,
+
+    /// The method of the element, or the initializer of a field or an auto-accessor.
+    value: JsObject,
+
+    /// The getter and setter of an auto-accessor.
+    getter: Option<JsObject>,
+    setter: Option<JsObject>,
+
+    /// The name given to an anonymous function initializing a public field.
+    #[unsafe_ignore_trace]
+    function_name: Option<PropertyKey>,
+
+    /// The private name backing the value of an auto-accessor.
+    storage: Option<PrivateName>,
+
+    /// The decorators of the element, as pairs of `this` values and decorator functions.
+    decorators: Vec<(JsValue, JsObject)>,
+
+    /// The initializers returned by the decorators of a field or an auto-accessor.
+    initializers: ThinVec<JsFunction>,
+
+    /// The extra initializers added by the decorators of a field or an auto-accessor.
+    extra_initializers: ThinVec<JsFunction>,
+
+    /// The index of the field in the `[[Fields]]` slot of the class, for instance fields.
+    field_index: Option<usize>,
+}
+
+/// The state of the decoration of a class definition.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct ClassDecoration {
+    class: JsObject,
+    prototype: JsObject,
+    metadata: JsObject,
+    decorators: Vec<(JsValue, JsObject)>,
+    elements: Vec<DecoratedElement>,
+    class_extra_initializers: ThinVec<JsFunction>,
+}
+
+impl ClassDecoration {
+    /// Creates the decoration state of the class `class`, with the evaluated class decorators.
+    ///
+    /// The metadata object of the class inherits from the metadata of the parent class.
+    pub(crate) fn create(
+        class: JsObject,
+        prototype: JsObject,
+        decorators: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        let parent =
+            class.__get_prototype_of__(&mut InternalMethodPropertyContext::new(context))?;
+        let parent_metadata = match parent {
+            Some(parent) => parent.get(JsSymbol::metadata(), context)?.as_object(),
+            None => None,
+        };
+        let metadata = JsObject::from_proto_and_data(parent_metadata, OrdinaryObject);
+        let decorators = decorator_list(decorators, context)?;
+
+        let state = Self {
+            class,
+            prototype,
+            metadata,
+            decorators,
+            elements: Vec::new(),
+            class_extra_initializers: ThinVec::new(),
+        };
+
+        Ok(JsObject::from_proto_and_data(None, state).upcast())
+    }
+
+    /// Records a class element that must be decorated.
+    ///
+    /// `key` is the property key of a public element, or the description of the private name of a
+    /// private element, and `value` is the method of the element or the initializer of a field.
+    pub(crate) fn push_element(
+        state: &JsObject,
+        key: &JsValue,
+        value: &JsValue,
+        decorators: &JsValue,
+        storage: Option<JsString>,
+        flags: ClassElementFlags,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let (class, prototype) = {
+            let state = downcast_state(state)?;
+            let state = state.borrow();
+            (state.data().class.clone(), state.data().prototype.clone())
+        };
+
+        let kind = ClassElementKind::from_flags(flags);
+        let is_static = flags.contains(ClassElementFlags::STATIC);
+        let key = if flags.contains(ClassElementFlags::PRIVATE) {
+            let description = key
+                .as_string()
+                .js_expect("private element key must be a string")?;
+            ClassElementKey::Private(class.private_name(description.clone()), description)
+        } else {
+            ClassElementKey::Public(key.to_property_key(context)?)
+        };
+        let value = value
+            .as_object()
+            .js_expect("class element value must be a function object")?;
+
+        let mut function_name = None;
+        if kind.has_storage() {
+            value
+                .downcast_mut::<OrdinaryFunction>()
+                .js_expect("field initializer must be a function object")?
+                .set_home_object(class.clone());
+            if flags.contains(ClassElementFlags::ANONYMOUS_FUNCTION)
+                && let ClassElementKey::Public(key) = &key
+            {
+                function_name = Some(key.clone());
+            }
+        } else {
+            let prefix = match kind {
+                ClassElementKind::Getter => Some(js_str!("get")),
+                ClassElementKind::Setter => Some(js_str!("set")),
+                _ => None,
+            };
+            set_function_name(&value, &key.function_name(), prefix, context)?;
+            value
+                .downcast_mut::<OrdinaryFunction>()
+                .js_expect("method must be a function object")?
+                .set_home_object(if is_static {
+                    class.clone()
+                } else {
+                    prototype.clone()
+                });
+        }
+
+        let field_index = if kind.has_storage() && !is_static {
+            let function = class
+                .downcast_ref::<OrdinaryFunction>()
+                .js_expect("class must be a function object")?;
+            function.get_fields().len().checked_sub(1)
+        } else {
+            None
+        };
+
+        let storage = storage.map(|description| class.private_name(description));
+        let (getter, setter) = match &storage {
+            Some(storage) => {
+                let (getter, setter) = accessor_functions(storage, context);
+                let name = key.function_name();
+                set_function_name(&getter, &name, Some(js_str!("get")), context)?;
+                set_function_name(&setter, &name, Some(js_str!("set")), context)?;
+                (Some(getter), Some(setter))
+            }
+            None => (None, None),
+        };
+
+        let decorators = decorator_list(decorators, context)?;
+
+        downcast_state(state)?
+            .borrow_mut()
+            .data_mut()
+            .elements
+            .push(DecoratedElement {
+                kind,
+                is_static,
+                key,
+                value,
+                getter,
+                setter,
+                function_name,
+                storage,
+                decorators,
+                initializers: ThinVec::new(),
+                extra_initializers: ThinVec::new(),
+                field_index,
+            });
+
+        Ok(())
+    }
+
+    /// Applies the decorators of the elements and of the class, returning the decorated class.
+    ///
+    /// The elements are decorated in the following order: static methods and accessors, instance
+    /// methods and accessors, static fields and then instance fields. Then the class decorators are
+    /// applied and the extra initializers of the static methods are run.
+    pub(crate) fn apply(state: &JsObject, context: &mut Context) -> JsResult<JsValue> {
+        let (class, prototype, metadata, decorators, mut elements) = {
+            let state = downcast_state(state)?;
+            let mut state = state.borrow_mut();
+            let state = state.data_mut();
+            (
+                state.class.clone(),
+                state.prototype.clone(),
+                state.metadata.clone(),
+                state.decorators.clone(),
+                std::mem::take(&mut state.elements),
+            )
+        };
+
+        let has_decorators =
+            !decorators.is_empty() || elements.iter().any(|e| !e.decorators.is_empty());
+
+        let static_extra_initializers = Gc::new(GcRefCell::new(ThinVec::new()));
+        let instance_extra_initializers = Gc::new(GcRefCell::new(ThinVec::new()));
+
+        for (is_static, is_field) in [(true, false), (false, false), (true, true), (false, true)] {
+            for element in &mut elements {
+                if element.is_static != is_static
+                    || (element.kind == ClassElementKind::Field) != is_field
+                {
+                    continue;
+                }
+
+                let extra_initializers = if element.kind.has_storage() {
+                    Gc::new(GcRefCell::new(ThinVec::new()))
+                } else if is_static {
+                    static_extra_initializers.clone()
+                } else {
+                    instance_extra_initializers.clone()
+                };
+
+                decorate_element(element, &metadata, &extra_initializers, context)?;
+
+                if element.kind.has_storage() {
+                    element.extra_initializers = extra_initializers.borrow().clone();
+                }
+
+                define_element(&class, &prototype, element, context)?;
+            }
+        }
+
+        let instance_extra_initializers = instance_extra_initializers.borrow().clone();
+        if !instance_extra_initializers.is_empty() {
+            class
+                .downcast_mut::<OrdinaryFunction>()
+                .js_expect("class must be a function object")?
+                .fields_mut()
+                .insert(
+                    0,
+                    ClassFieldDefinition::Initializers(instance_extra_initializers),
+                );
+        }
+
+        if has_decorators {
+            class.define_property_or_throw(
+                JsSymbol::metadata(),
+                PropertyDescriptor::builder()
+                    .value(metadata.clone())
+                    .writable(true)
+                    .enumerable(false)
+                    .configurable(true),
+                context,
+            )?;
+        }
+
+        let name = class
+            .__get_own_property__(
+                &js_string!("name").into(),
+                &mut InternalMethodPropertyContext::new(context),
+            )?
+            .and_then(|desc| desc.value().cloned())
+            .filter(JsValue::is_string)
+            .unwrap_or_default();
+
+        let class_extra_initializers = Gc::new(GcRefCell::new(ThinVec::new()));
+        let mut new_class: JsValue = class.clone().into();
+        for (this, decorator) in decorators.iter().rev() {
+            let finished = Gc::new(GcRefCell::new(false));
+            let decorator_context = JsObject::with_object_proto(context.intrinsics());
+            decorator_context.create_data_property_or_throw(
+                js_string!("kind"),
+                js_string!("class"),
+                context,
+            )?;
+            decorator_context.create_data_property_or_throw(
+                js_string!("name"),
+                name.clone(),
+                context,
+            )?;
+            let add_initializer =
+                add_initializer_function(&class_extra_initializers, &finished, context);
+            decorator_context.create_data_property_or_throw(
+                js_string!("addInitializer"),
+                add_initializer,
+                context,
+            )?;
+            decorator_context.create_data_property_or_throw(
+                js_string!("metadata"),
+                metadata.clone(),
+                context,
+            )?;
+
+            let result = decorator.call(
+                this,
+                &[new_class.clone(), decorator_context.into()],
+                context,
+            );
+            *finished.borrow_mut() = true;
+            let result = result?;
+
+            if !result.is_undefined() {
+                if !result.is_callable() {
+                    return Err(js_error!(
+                        TypeError: "class decorators must return a function or undefined"
+                    ));
+                }
+                new_class = result;
+            }
+        }
+
+        {
+            let state = downcast_state(state)?;
+            let mut state = state.borrow_mut();
+            let state = state.data_mut();
+            state.elements = elements;
+            state.class_extra_initializers = class_extra_initializers.borrow().clone();
+        }
+
+        let static_extra_initializers = static_extra_initializers.borrow().clone();
+        for initializer in static_extra_initializers {
+            initializer.call(&class.clone().into(), &[], context)?;
+        }
+
+        Ok(new_class)
+    }
+
+    /// Initializes the static field or auto-accessor recorded at `index`.
+    pub(crate) fn initialize_static_element(
+        state: &JsObject,
+        index: usize,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let (class, element) = {
+            let state = downcast_state(state)?;
+            let state = state.borrow();
+            let state = state.data();
+            let element = state
+                .elements
+                .get(index)
+                .js_expect("decorated element index must be valid")?
+                .clone();
+            (state.class.clone(), element)
+        };
+
+        let initializer = JsFunction::from_object_unchecked(element.value.clone());
+        let field = match (&element.storage, &element.key) {
+            (Some(storage), _) => ClassFieldDefinition::Private(storage.clone(), initializer),
+            (None, ClassElementKey::Private(name, _)) => {
+                ClassFieldDefinition::Private(name.clone(), initializer)
+            }
+            (None, ClassElementKey::Public(key)) => ClassFieldDefinition::Public(
+                key.clone(),
+                initializer,
+                element.function_name.clone(),
+            ),
+        };
+
+        class.define_field(
+            &ClassFieldDefinition::Decorated {
+                field: Box::new(field),
+                initializers: element.initializers.clone(),
+                extra_initializers: element.extra_initializers.clone(),
+            },
+            context,
+        )
+    }
+
+    /// Runs the extra initializers added by the class decorators on the decorated class.
+    pub(crate) fn run_class_extra_initializers(
+        state: &JsObject,
+        class: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let initializers = std::mem::take(
+            &mut downcast_state(state)?
+                .borrow_mut()
+                .data_mut()
+                .class_extra_initializers,
+        );
+
+        for initializer in initializers {
+            initializer.call(class, &[], context)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn downcast_state(state: &JsObject) -> JsResult<JsObject<ClassDecoration>> {
+    state
+        .clone()
+        .downcast::<ClassDecoration>()
+        .ok()
+        .js_expect("class decoration state must be a `ClassDecoration` object")
+        .map_err(Into::into)
+}
+
+/// Reads a list of decorators stored as an array of `this` values and decorator functions.
+fn decorator_list(
+    decorators: &JsValue,
+    context: &mut Context,
+) -> JsResult<Vec<(JsValue, JsObject)>> {
+    let Some(decorators) = decorators.as_object() else {
+        return Ok(Vec::new());
+    };
+
+    let length = decorators.length_of_array_like(context)?;
+    let mut list = Vec::with_capacity((length / 2) as usize);
+    for index in (0..length).step_by(2) {
+        let this = decorators.get(index, context)?;
+        let decorator = decorators.get(index + 1, context)?;
+        let Some(decorator) = decorator.as_callable() else {
+            return Err(js_error!(TypeError: "decorators must be functions"));
+        };
+        list.push((this, decorator));
+    }
+
+    Ok(list)
+}
+
+/// Calls the decorators of `element` in reverse order, replacing its value or collecting the
+/// initializers returned by the decorators.
+fn decorate_element(
+    element: &mut DecoratedElement,
+    metadata: &JsObject,
+    extra_initializers: &Gc<GcRefCell<ThinVec<JsFunction>>>,
+    context: &mut Context,
+) -> JsResult<()> {
+    let decorators = std::mem::take(&mut element.decorators);
+    for (this, decorator) in decorators.iter().rev() {
+        let value: JsValue = match element.kind {
+            ClassElementKind::Method | ClassElementKind::Getter | ClassElementKind::Setter => {
+                element.value.clone().into()
+            }
+            ClassElementKind::Field => JsValue::undefined(),
+            ClassElementKind::Accessor => {
+                let value = JsObject::with_object_proto(context.intrinsics());
+                value.create_data_property_or_throw(
+                    js_string!("get"),
+                    element
+                        .getter
+                        .clone()
+                        .map(JsValue::from)
+                        .unwrap_or_default(),
+                    context,
+                )?;
+                value.create_data_property_or_throw(
+                    js_string!("set"),
+                    element
+                        .setter
+                        .clone()
+                        .map(JsValue::from)
+                        .unwrap_or_default(),
+                    context,
+                )?;
+                value.into()
+            }
+        };
+
+        let finished = Gc::new(GcRefCell::new(false));
+        let decorator_context =
+            element_context(element, metadata, extra_initializers, &finished, context)?;
+        let result = decorator.call(this, &[value, decorator_context.into()], context);
+        *finished.borrow_mut() = true;
+        let result = result?;
+
+        if result.is_undefined() {
+            continue;
+        }
+
+        match element.kind {
+            ClassElementKind::Method | ClassElementKind::Getter | ClassElementKind::Setter => {
+                let Some(method) = result.as_callable() else {
+                    return Err(js_error!(
+                        TypeError: "method decorators must return a function or undefined"
+                    ));
+                };
+                element.value = method;
+            }
+            ClassElementKind::Field => {
+                let Some(initializer) = result.as_callable() else {
+                    return Err(js_error!(
+                        TypeError: "field decorators must return a function or undefined"
+                    ));
+                };
+                element
+                    .initializers
+                    .push(JsFunction::from_object_unchecked(initializer));
+            }
+            ClassElementKind::Accessor => {
+                let Some(result) = result.as_object() else {
+                    return Err(js_error!(
+                        TypeError: "accessor decorators must return an object or undefined"
+                    ));
+                };
+                for (name, target) in [
+                    (js_string!("get"), &mut element.getter),
+                    (js_string!("set"), &mut element.setter),
+                ] {
+                    let function = result.get(name, context)?;
+                    if function.is_undefined() {
+                        continue;
+                    }
+                    let Some(function) = function.as_callable() else {
+                        return Err(js_error!(
+                            TypeError: "accessor decorators must return callable `get` and `set` properties"
+                        ));
+                    };
+                    *target = Some(function);
+                }
+                let initializer = result.get(js_string!("init"), context)?;
+                if !initializer.is_undefined() {
+                    let Some(initializer) = initializer.as_callable() else {
+                        return Err(js_error!(
+                            TypeError: "accessor decorators must return a callable `init` property"
+                        ));
+                    };
+                    element
+                        .initializers
+                        .push(JsFunction::from_object_unchecked(initializer));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Defines a decorated element on the class or its prototype.
+///
+/// Instance fields are already part of the `[[Fields]]` slot of the class, so only the
+/// initializers returned by their decorators are recorded.
+fn define_element(
+    class: &JsObject,
+    prototype: &JsObject,
+    element: &DecoratedElement,
+    context: &mut Context,
+) -> JsResult<()> {
+    let target = if element.is_static { class } else { prototype };
+
+    match (&element.key, element.kind) {
+        (ClassElementKey::Public(key), ClassElementKind::Method) => {
+            target.define_method_property(
+                key.clone(),
+                element.value.clone(),
+                &mut InternalMethodPropertyContext::new(context),
+            )?;
+        }
+        (
+            ClassElementKey::Public(key),
+            kind @ (ClassElementKind::Getter | ClassElementKind::Setter),
+        ) => {
+            let existing = target
+                .__get_own_property__(key, &mut InternalMethodPropertyContext::new(context))?;
+            let (getter, setter) = if kind == ClassElementKind::Getter {
+                (
+                    Some(element.value.clone().into()),
+                    existing.as_ref().and_then(PropertyDescriptor::set).cloned(),
+                )
+            } else {
+                (
+                    existing.as_ref().and_then(PropertyDescriptor::get).cloned(),
+                    Some(element.value.clone().into()),
+                )
+            };
+            target.__define_own_property__(
+                key,
+                PropertyDescriptor::builder()
+                    .maybe_get(getter)
+                    .maybe_set(setter)
+                    .enumerable(false)
+                    .configurable(true)
+                    .build(),
+                &mut InternalMethodPropertyContext::new(context),
+            )?;
+        }
+        (ClassElementKey::Public(key), ClassElementKind::Accessor) => {
+            target.__define_own_property__(
+                key,
+                PropertyDescriptor::builder()
+                    .maybe_get(element.getter.clone().map(JsValue::from))
+                    .maybe_set(element.setter.clone().map(JsValue::from))
+                    .enumerable(false)
+                    .configurable(true)
+                    .build(),
+                &mut InternalMethodPropertyContext::new(context),
+            )?;
+        }
+        (ClassElementKey::Private(name, _), kind) => {
+            let private_element = match kind {
+                ClassElementKind::Method => PrivateElement::Method(element.value.clone()),
+                ClassElementKind::Getter => PrivateElement::Accessor {
+                    getter: Some(element.value.clone()),
+                    setter: None,
+                },
+                ClassElementKind::Setter => PrivateElement::Accessor {
+                    getter: None,
+                    setter: Some(element.value.clone()),
+                },
+                ClassElementKind::Accessor => PrivateElement::Accessor {
+                    getter: element.getter.clone(),
+                    setter: element.setter.clone(),
+                },
+                ClassElementKind::Field => PrivateElement::Field(JsValue::undefined()),
+            };
+
+            if kind != ClassElementKind::Field {
+                if element.is_static {
+                    class
+                        .borrow_mut()
+                        .append_private_element(name.clone(), private_element);
+                } else {
+                    class
+                        .downcast_mut::<OrdinaryFunction>()
+                        .js_expect("class must be a function object")?
+                        .push_private_method(name.clone(), private_element);
+                }
+            }
+        }
+        (ClassElementKey::Public(_), ClassElementKind::Field) => {}
+    }
+
+    if let Some(index) = element.field_index
+        && (!element.initializers.is_empty() || !element.extra_initializers.is_empty())
+    {
+        let mut function = class
+            .downcast_mut::<OrdinaryFunction>()
+            .js_expect("class must be a function object")?;
+        let fields = function.fields_mut();
+        let field = fields
+            .get(index)
+            .js_expect("decorated field index must be valid")?
+            .clone();
+        fields[index] = ClassFieldDefinition::Decorated {
+            field: Box::new(field),
+            initializers: element.initializers.clone(),
+            extra_initializers: element.extra_initializers.clone(),
+        };
+    }
+
+    Ok(())
+}
+
+/// Captures of the `addInitializer` function of a decorator context object.
+#[derive(Trace, Finalize)]
+struct AddInitializerCaptures {
+    initializers: Gc<GcRefCell<ThinVec<JsFunction>>>,
+    finished: Gc<GcRefCell<bool>>,
+}
+
+/// Creates the `addInitializer` function of a decorator context object.
+fn add_initializer_function(
+    initializers: &Gc<GcRefCell<ThinVec<JsFunction>>>,
+    finished: &Gc<GcRefCell<bool>>,
+    context: &mut Context,
+) -> JsObject {
+    FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, captures, _| {
+                if *captures.finished.borrow() {
+                    return Err(js_error!(
+                        TypeError: "addInitializer: cannot add an initializer after decoration has finished"
+                    ));
+                }
+                let Some(initializer) = args.get_or_undefined(0).as_callable() else {
+                    return Err(js_error!(
+                        TypeError: "addInitializer: initializer must be a function"
+                    ));
+                };
+                captures
+                    .initializers
+                    .borrow_mut()
+                    .push(JsFunction::from_object_unchecked(initializer));
+                Ok(JsValue::undefined())
+            },
+            AddInitializerCaptures {
+                initializers: initializers.clone(),
+                finished: finished.clone(),
+            },
+        ),
+    )
+    .name(js_string!("addInitializer"))
+    .length(1)
+    .build()
+    .into()
+}
+
+/// Creates the decorator context object of a class element.
+fn element_context(
+    element: &DecoratedElement,
+    metadata: &JsObject,
+    extra_initializers: &Gc<GcRefCell<ThinVec<JsFunction>>>,
+    finished: &Gc<GcRefCell<bool>>,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    let decorator_context = JsObject::with_object_proto(context.intrinsics());
+    decorator_context.create_data_property_or_throw(
+        js_string!("kind"),
+        element.kind.name(),
+        context,
+    )?;
+    decorator_context.create_data_property_or_throw(
+        js_string!("name"),
+        element.key.context_name(),
+        context,
+    )?;
+    decorator_context.create_data_property_or_throw(
+        js_string!("static"),
+        element.is_static,
+        context,
+    )?;
+    decorator_context.create_data_property_or_throw(
+        js_string!("private"),
+        matches!(element.key, ClassElementKey::Private(..)),
+        context,
+    )?;
+
+    let access = JsObject::with_object_proto(context.intrinsics());
+    if element.kind != ClassElementKind::Setter {
+        let get = access_function(element.key.clone(), AccessKind::Get, context);
+        access.create_data_property_or_throw(js_string!("get"), get, context)?;
+    }
+    if matches!(
+        element.kind,
+        ClassElementKind::Setter | ClassElementKind::Field | ClassElementKind::Accessor
+    ) {
+        let set = access_function(element.key.clone(), AccessKind::Set, context);
+        access.create_data_property_or_throw(js_string!("set"), set, context)?;
+    }
+    let has = access_function(element.key.clone(), AccessKind::Has, context);
+    access.create_data_property_or_throw(js_string!("has"), has, context)?;
+    decorator_context.create_data_property_or_throw(js_string!("access"), access, context)?;
+
+    let add_initializer = add_initializer_function(extra_initializers, finished, context);
+    decorator_context.create_data_property_or_throw(
+        js_string!("addInitializer"),
+        add_initializer,
+        context,
+    )?;
+    decorator_context.create_data_property_or_throw(
+        js_string!("metadata"),
+        metadata.clone(),
+        context,
+    )?;
+
+    Ok(decorator_context)
+}
+
+/// The functions of the `access` object of a decorator context object.
+#[derive(Debug, Clone, Copy, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+enum AccessKind {
+    Get,
+    Set,
+    Has,
+}
+
+/// Creates the `get`, `set` or `has` function of the `access` object of a decorator context.
+fn access_function(key: ClassElementKey, kind: AccessKind, context: &mut Context) -> JsObject {
+    let (name, length) = match kind {
+        AccessKind::Get => (js_string!("get"), 1),
+        AccessKind::Set => (js_string!("set"), 2),
+        AccessKind::Has => (js_string!("has"), 1),
+    };
+
+    FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |_, args, (key, kind), context| {
+                let Some(object) = args.get_or_undefined(0).as_object() else {
+                    return Err(js_error!(
+                        TypeError: "decorator access functions require an object"
+                    ));
+                };
+                match (key, kind) {
+                    (ClassElementKey::Public(key), AccessKind::Get) => {
+                        object.get(key.clone(), context)
+                    }
+                    (ClassElementKey::Public(key), AccessKind::Set) => {
+                        object.set(key.clone(), args.get_or_undefined(1).clone(), true, context)?;
+                        Ok(JsValue::undefined())
+                    }
+                    (ClassElementKey::Public(key), AccessKind::Has) => {
+                        object.has_property(key.clone(), context).map(Into::into)
+                    }
+                    (ClassElementKey::Private(name, _), AccessKind::Get) => {
+                        object.private_get(name, context)
+                    }
+                    (ClassElementKey::Private(name, _), AccessKind::Set) => {
+                        object.private_set(name, args.get_or_undefined(1).clone(), context)?;
+                        Ok(JsValue::undefined())
+                    }
+                    (ClassElementKey::Private(name, _), AccessKind::Has) => Ok(object
+                        .private_element_find(name, true, true)
+                        .is_some()
+                        .into()),
+                }
+            },
+            (key, kind),
+        ),
+    )
+    .name(name)
+    .length(length)
+    .build()
+    .into()
+}
+
+/// Creates the getter and setter of an auto-accessor, which read and write its private storage.
+fn accessor_functions(storage: &PrivateName, context: &mut Context) -> (JsObject, JsObject) {
+    let getter = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |this, _, storage, context| {
+                let Some(object) = this.as_object() else {
+                    return Err(js_error!(TypeError: "accessor getter called on a non-object"));
+                };
+                object.private_get(storage, context)
+            },
+            storage.clone(),
+        ),
+    )
+    .build()
+    .into();
+
+    let setter = FunctionObjectBuilder::new(
+        context.realm(),
+        NativeFunction::from_copy_closure_with_captures(
+            |this, args, storage, context| {
+                let Some(object) = this.as_object() else {
+                    return Err(js_error!(TypeError: "accessor setter called on a non-object"));
+                };
+                object.private_set(storage, args.get_or_undefined(0).clone(), context)?;
+                Ok(JsValue::undefined())
+            },
+            storage.clone(),
+        ),
+    )
+    .length(1)
+    .build()
+    .into();
+
+    (getter, setter)
+}
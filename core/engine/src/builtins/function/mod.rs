@@ -15,6 +15,7 @@ use crate::{
     Context, JsArgs, JsExpect, JsResult, JsStr, JsString, JsValue, SpannedSourceText,
     builtins::{
         BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+        shadow_realm::WrappedFunction,
     },
     bytecompiler::FunctionCompiler,
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
@@ -55,6 +56,7 @@ use super::Proxy;
 
 pub(crate) mod arguments;
 mod bound;
+pub(crate) mod decorator;
 
 pub use bound::BoundFunction;
 
@@ -142,6 +144,21 @@ pub enum ClassFieldDefinition {
 
     /// A class field definition with a private name.
     Private(PrivateName, JsFunction),
+
+    /// A class field definition whose initial value is passed through the initializers returned
+    /// by its decorators, followed by the extra initializers added with `context.addInitializer`.
+    Decorated {
+        /// The decorated field definition.
+        field: Box<ClassFieldDefinition>,
+        /// The initializers returned by the decorators of the field.
+        initializers: ThinVec<JsFunction>,
+        /// The extra initializers added by the decorators of the field.
+        extra_initializers: ThinVec<JsFunction>,
+    },
+
+    /// The extra initializers added by the decorators of instance methods and accessors, which
+    /// run before any field of the instance is defined.
+    Initializers(ThinVec<JsFunction>),
 }
 
 unsafe impl Trace for ClassFieldDefinition {
@@ -153,6 +170,18 @@ unsafe impl Trace for ClassFieldDefinition {
             Self::Private(_, func) => {
                 mark(func);
             }
+            Self::Decorated {
+                field,
+                initializers,
+                extra_initializers,
+            } => {
+                mark(field);
+                mark(initializers);
+                mark(extra_initializers);
+            }
+            Self::Initializers(initializers) => {
+                mark(initializers);
+            }
         }
     }}
 }
@@ -278,6 +307,11 @@ impl OrdinaryFunction {
         self.fields.push(ClassFieldDefinition::Private(name, value));
     }
 
+    /// Returns a mutable reference to the values of the `[[Fields]]` internal slot.
+    pub(crate) fn fields_mut(&mut self) -> &mut ThinVec<ClassFieldDefinition> {
+        &mut self.fields
+    }
+
     /// Returns the values of the `[[PrivateMethods]]` internal slot.
     pub(crate) fn get_private_methods(&self) -> &[(PrivateName, PrivateElement)] {
         &self.private_methods
@@ -872,7 +906,10 @@ impl BuiltInFunctionObject {
             return Ok(
                 js_string!(js_str!("function "), &name, js_str!("() { [native code] }")).into(),
             );
-        } else if object.is::<Proxy>() || object.is::<BoundFunction>() {
+        } else if object.is::<Proxy>()
+            || object.is::<BoundFunction>()
+            || object.is::<WrappedFunction>()
+        {
             return Ok(js_string!("function () { [native code] }").into());
         }
 
@@ -1068,9 +1105,10 @@ pub(crate) fn function_call(
     let has_function_scope = context.vm.frame().code_block().has_function_scope();
 
     if has_binding_identifier {
+        let scope = context.vm.frame().code_block().constant_scope(0);
         let frame = context.vm.frame_mut();
         let global = frame.realm.environment();
-        let index = frame.environments.push_lexical(1, global);
+        let index = frame.environments.push_lexical(scope, global);
         frame.environments.put_lexical_value(
             BindingLocatorScope::Stack(index),
             0,
@@ -1176,9 +1214,10 @@ fn function_construct(
     let has_function_scope = context.vm.frame().code_block().has_function_scope();
 
     if has_binding_identifier {
+        let scope = context.vm.frame().code_block().constant_scope(0);
         let frame = context.vm.frame_mut();
         let global = frame.realm.environment();
-        let index = frame.environments.push_lexical(1, global);
+        let index = frame.environments.push_lexical(scope, global);
         frame.environments.put_lexical_value(
             BindingLocatorScope::Stack(index),
             0,
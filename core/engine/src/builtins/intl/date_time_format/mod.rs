@@ -10,20 +10,25 @@
 use crate::{
     Context, JsArgs, JsData, JsExpect, JsResult, JsString, JsValue, NativeFunction,
     builtins::{
-        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
         date::utils::{
             date_from_time, hour_from_time, min_from_time, month_from_time, ms_from_time,
             sec_from_time, time_clip, year_from_time,
         },
         intl::{
             Service,
-            date_time_format::options::{DateStyle, FormatMatcher, FormatOptions, TimeStyle},
+            date_time_format::options::{
+                DateStyle, FormatMatcher, FormatOptions, RequiredFields, TimeStyle,
+            },
             locale::{canonicalize_locale_list, filter_locales, resolve_locale},
             options::{IntlOptions, coerce_options_to_object},
         },
         options::get_option,
     },
-    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
     error::JsNativeError,
     js_error, js_string,
     object::{
@@ -38,25 +43,39 @@ use crate::{
 use boa_gc::{Finalize, Trace};
 use icu_calendar::{Iso, preferences::CalendarAlgorithm};
 use icu_datetime::{
-    DateTimeFormatter, DateTimeFormatterPreferences,
+    DateTimeFormatter, DateTimeFormatterPreferences, FormattedDateTime,
     fieldsets::{
-        builder::{DateFields, FieldSetBuilder},
+        builder::{DateFields, FieldSetBuilder, ZoneStyle},
         enums::CompositeFieldSet,
     },
     input::{Date, DateTime, Time, TimeZone, UtcOffset},
     options::{Length, TimePrecision},
     preferences::HourCycle as IcuHourCycle,
+    provider::{
+        DatetimePatternsTimeV1,
+        fields::{Field, FieldSymbol, Hour},
+        pattern::PatternItem,
+    },
 };
 use icu_decimal::preferences::NumberingSystem;
 use icu_decimal::provider::DecimalSymbolsV1;
-use icu_locale::{Locale, extensions::unicode::Value};
+use icu_locale::{Locale, extensions::unicode::Value, extensions_unicode_key as key};
+use icu_provider::{
+    DataIdentifierBorrowed, DataMarker, DataMarkerAttributes, DataProvider, DataRequest,
+};
 use icu_time::{
     TimeZoneInfo, ZonedDateTime,
-    zone::{IanaParser, models::Base},
+    zone::{
+        IanaParser,
+        models::{AtTime, Base},
+    },
 };
+use temporal_rs::{TimeZone as TemporalTimeZone, host::HostHooks};
 use timezone_provider::provider::TimeZoneId;
+use writeable::{Part, PartsWrite, Writeable};
 
 mod options;
+pub(in crate::builtins::intl) use options::supports_calendar;
 
 #[cfg(all(test, feature = "intl_bundled"))]
 mod tests;
@@ -68,9 +87,11 @@ pub(crate) enum FormatTimeZone {
 }
 
 impl FormatTimeZone {
-    pub(crate) fn to_time_zone_info(&self) -> TimeZoneInfo<Base> {
+    pub(crate) fn to_time_zone_info(&self, offset_seconds: i32) -> TimeZoneInfo<Base> {
         match self {
-            Self::Identifier((tz, _)) => tz.without_offset(),
+            Self::Identifier((tz, _)) => {
+                tz.with_offset(Some(UtcOffset::from_seconds_unchecked(offset_seconds)))
+            }
             Self::UtcOffset(utc_offset) => TimeZone::UNKNOWN.with_offset(Some(*utc_offset)),
         }
     }
@@ -88,6 +109,10 @@ pub(crate) struct DateTimeFormat {
     date_style: Option<DateStyle>,
     time_style: Option<TimeStyle>,
     time_zone: FormatTimeZone,
+    time_zone_identifier: JsString,
+    preferences: DateTimeFormatterPreferences,
+    requested_options: FormatOptions,
+    format_options: FormatOptions,
     fieldset: CompositeFieldSet,
     formatter: DateTimeFormatter<CompositeFieldSet>,
     bound_format: Option<JsFunction>,
@@ -119,6 +144,13 @@ impl IntrinsicObject for DateTimeFormat {
                 None,
                 Attribute::CONFIGURABLE,
             )
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::format_range, js_string!("formatRange"), 2)
+            .method(
+                Self::format_range_to_parts,
+                js_string!("formatRangeToParts"),
+                2,
+            )
             .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
             .property(
                 JsSymbol::to_string_tag(),
@@ -139,7 +171,7 @@ impl BuiltInObject for DateTimeFormat {
 
 impl BuiltInConstructor for DateTimeFormat {
     const CONSTRUCTOR_ARGUMENTS: usize = 0;
-    const PROTOTYPE_STORAGE_SLOTS: usize = 4;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 7;
     const CONSTRUCTOR_STORAGE_SLOTS: usize = 1;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -182,6 +214,7 @@ impl BuiltInConstructor for DateTimeFormat {
             options,
             FormatType::Any,
             FormatDefaults::Date,
+            None,
             context,
         )?;
         let prototype = get_prototype_from_constructor(
@@ -266,24 +299,16 @@ impl DateTimeFormat {
                         let date = args.get_or_undefined(0);
                         // 3. If date is not provided or is undefined, then
                         let x = if date.is_undefined() {
-                            // NOTE (nekevss) i64 should be sufficient for a millisecond
-                            // representation.
                             // a. Let x be ! Call(%Date.now%, undefined).
-                            context.clock().system_time_millis() as f64
+                            Formattable::TimeValue(context.clock().system_time_millis() as f64)
                         // 4. Else,
                         } else {
-                            // NOTE (nekevss) The i64 covers all MAX_SAFE_INTEGER values.
-                            // a. Let x be ? ToNumber(date).
-                            date.to_number(context)?
+                            // a. Let x be ? ToDateTimeFormattable(date).
+                            to_date_time_formattable(date, context)?
                         };
 
                         // 5. Return ? FormatDateTime(dtf, x).
-                        // A.O 11.5.6 PartitionDateTimePattern: 1. TimeClip(x). 2. If NaN throw. Then ToLocalTime and format.
-                        let x = time_clip(x);
-                        if x.is_nan() {
-                            return Err(js_error!(RangeError: "formatted date cannot be NaN"));
-                        }
-                        let result = format_timestamp_with_dtf(dtf.borrow().data(), x, context)?;
+                        let result = format_date_time(dtf.borrow().data(), &x, context)?;
                         Ok(JsValue::from(result))
                     },
                     dtf_clone,
@@ -326,6 +351,163 @@ impl DateTimeFormat {
         filter_locales::<Self>(requested_locales, options, context).map(JsValue::from)
     }
 
+    /// [`Intl.DateTimeFormat.prototype.formatToParts ( date )`][spec]
+    ///
+    /// Returns an array of objects representing the formatted date in parts that can be used
+    /// for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = this
+            .as_object()
+            .and_then(|o| o.downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`formatToParts` can only be called on a `DateTimeFormat` object")
+            })?;
+
+        let date = args.get_or_undefined(0);
+        // 3. If date is undefined, then
+        let x = if date.is_undefined() {
+            // a. Let x be ! Call(%Date.now%, undefined).
+            Formattable::TimeValue(context.clock().system_time_millis() as f64)
+        // 4. Else,
+        } else {
+            // a. Let x be ? ToDateTimeFormattable(date).
+            to_date_time_formattable(date, context)?
+        };
+
+        // 5. Return ? FormatDateTimeToParts(dtf, x).
+
+        // Abstract operation `FormatDateTimeToParts ( dateTimeFormat, x )`
+        // https://tc39.es/ecma402/#sec-formatdatetimetoparts
+
+        // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+        let parts = partition_date_time_pattern(dtf.borrow().data(), &x, context)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .js_expect("creating an empty array with default proto must not fail")?;
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part in parts, do
+        for (n, part) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            let o = part_to_object(part, None, context)?;
+
+            // d. Perform ! CreateDataProperty(result, ! ToString(n), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .js_expect("operation must not fail per the spec")?;
+
+            // e. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRange ( startDate, endDate )`][spec]
+    ///
+    /// Formats a date range in the most concise way based on the locale and options provided
+    /// when instantiating this `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatRange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToDateTimeFormattable(startDate).
+        // 5. Let y be ? ToDateTimeFormattable(endDate).
+        // 6. Return ? FormatDateTimeRange(dtf, x, y).
+
+        // Abstract operation `FormatDateTimeRange ( dateTimeFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatdatetimerange
+
+        // 1. Let parts be ? PartitionDateTimeRangePattern(dateTimeFormat, x, y).
+        let parts = date_time_range_parts(this, args, "formatRange", context)?;
+
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]], [[Source]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        let result = parts
+            .into_iter()
+            .map(|(part, _)| part.value)
+            .collect::<String>();
+
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRangeToParts ( startDate, endDate )`][spec]
+    ///
+    /// Returns an array of objects representing the formatted date range in parts that can be
+    /// used for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatRangeToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToDateTimeFormattable(startDate).
+        // 5. Let y be ? ToDateTimeFormattable(endDate).
+        // 6. Return ? FormatDateTimeRangeToParts(dtf, x, y).
+
+        // Abstract operation `FormatDateTimeRangeToParts ( dateTimeFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatdatetimerangetoparts
+
+        // 1. Let parts be ? PartitionDateTimeRangePattern(dateTimeFormat, x, y).
+        let parts = date_time_range_parts(this, args, "formatRangeToParts", context)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .js_expect("creating an empty array with default proto must not fail")?;
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Source]] } part in parts, do
+        for (n, (part, source)) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            // d. Perform ! CreateDataPropertyOrThrow(O, "source", part.[[Source]]).
+            let o = part_to_object(part, Some(source), context)?;
+
+            // e. Perform ! CreateDataProperty(result, ! ToString(n), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .js_expect("operation must not fail per the spec")?;
+
+            // f. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
     /// [`Intl.DateTimeFormat.prototype.resolvedOptions ( )`][spec]
     ///
     /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
@@ -389,18 +571,9 @@ impl DateTimeFormat {
                 );
             }
 
-            let time_zone_str = match &dtf.time_zone {
-                FormatTimeZone::UtcOffset(offset) => {
-                    let seconds = offset.to_seconds();
-                    let hours = seconds / 3600;
-                    let minutes = (seconds.abs() % 3600) / 60;
-                    format!("{hours:+03}:{minutes:02}")
-                }
-                FormatTimeZone::Identifier((tz, _id)) => tz.to_string(),
-            };
             options.property(
                 js_string!("timeZone"),
-                js_string!(time_zone_str),
+                dtf.time_zone_identifier.clone(),
                 Attribute::all(),
             );
 
@@ -415,6 +588,14 @@ impl DateTimeFormat {
                 options.property(js_string!("hour12"), hour12, Attribute::all());
             }
 
+            if dtf.date_style.is_none() && dtf.time_style.is_none() {
+                for (property, value) in dtf.format_options.components() {
+                    if let Some(value) = value {
+                        options.property(property, value, Attribute::all());
+                    }
+                }
+            }
+
             if let Some(ds) = dtf.date_style {
                 let ds_str = match ds {
                     DateStyle::Full => "full",
@@ -450,6 +631,38 @@ impl DateTimeFormat {
         dtf_object.borrow_mut().data_mut().resolved_options = Some(result.clone());
         Ok(result.into())
     }
+
+    /// Gets the identifier of the resolved calendar of this `DateTimeFormat`.
+    fn calendar(&self) -> &str {
+        self.calendar_algorithm
+            .as_ref()
+            .map_or("gregory", |ca| ca.as_str())
+    }
+
+    /// Gets the field set used to format a `Temporal` value with the given required fields.
+    ///
+    /// This corresponds to the `Temporal` formats of `CreateDateTimeFormat`, which are
+    /// computed on demand instead of being stored in the internal slots of the object.
+    /// Only the format of `Temporal.Instant` values inherits all options, including the time zone
+    /// name.
+    fn temporal_fieldset(
+        &self,
+        required: RequiredFields,
+        inherit_all: bool,
+    ) -> JsResult<CompositeFieldSet> {
+        if self.date_style.is_some() || self.time_style.is_some() {
+            return date_time_style_format(self.date_style, self.time_style, required, inherit_all);
+        }
+
+        let format_options = self
+            .requested_options
+            .get_date_time_format(required, inherit_all)
+            .ok_or_else(|| {
+                js_error!(TypeError: "the options of this DateTimeFormat cannot format the given value")
+            })?;
+
+        best_fit_date_time_format(&format_options)
+    }
 }
 
 // Represents a ISO8601 ToLocalTime Record
@@ -526,15 +739,19 @@ impl ToLocalTime {
 
 /// Creates a [`DateTimeFormat`] struct (internal slots only). The constructor wraps this in a
 /// `JsObject` with the correct prototype; Date.prototype.toLocaleString (and friends) use it
-/// directly with [`format_timestamp_with_dtf`] without allocating a JS object.
+/// directly with [`format_date_time`] without allocating a JS object.
+///
+/// `to_locale_string_time_zone` is the time zone of a `Temporal.ZonedDateTime` being formatted
+/// by its `toLocaleString` method, which cannot be overriden by the `timeZone` option.
 pub(crate) fn create_date_time_format(
     locales: &JsValue,
     options: &JsValue,
     date_time_format_type: FormatType,
     defaults: FormatDefaults,
+    to_locale_string_time_zone: Option<JsString>,
     context: &mut Context,
 ) -> JsResult<DateTimeFormat> {
-    // 2. Let hour12 be undefined. <- TODO
+    // 2. Let hour12 be undefined.
     // 3. Let modifyResolutionOptions be a new Abstract Closure with parameters (options) that captures hour12 and performs the following steps when called:
     //        a. Set hour12 to options.[[hour12]].
     //        b. Remove field [[hour12]] from options.
@@ -607,12 +824,29 @@ pub(crate) fn create_date_time_format(
 
     // ResolveOptions 8. Let resolution be ResolveLocale(constructor.[[AvailableLocales]], requestedLocales,
     // opt, constructor.[[RelevantExtensionKeys]], localeData).
-    let resolved_locale = resolve_locale::<DateTimeFormat>(
+    let mut resolved_locale = resolve_locale::<DateTimeFormat>(
         requested_locales,
         &mut intl_options,
         context.intl_provider(),
     )?;
 
+    // 3.c. If hour12 is not undefined, set options.[[hc]] to null.
+    //
+    // NOTE: A null option always takes precedence over the "hc" keyword of the requested locale,
+    // which means it must also be removed from the resolved locale.
+    if hour_12.is_some() {
+        intl_options.preferences.hour_cycle = None;
+        resolved_locale
+            .extensions
+            .unicode
+            .keywords
+            .remove(key!("hc"));
+    }
+
+    // The preferences of the formatter must only contain the values resolved from the locale and
+    // the options, letting ICU4X pick the locale defaults for everything else.
+    let mut formatter_preferences = intl_options.preferences;
+
     // TODO: The resolved calendar, numbering system, and hour cycle should come from
     // the ICU4X locale resolution result, not hardcoded defaults. However, ICU4X does
     // not yet expose getters for these computed values on DateTimeFormatter.
@@ -632,13 +866,6 @@ pub(crate) fn create_date_time_format(
         )
         .ok();
     }
-
-    if intl_options.preferences.hour_cycle.is_none() {
-        intl_options.preferences.hour_cycle = IcuHourCycle::try_from(
-            &Value::try_from_str("h12").expect("'h12' is a valid BCP 47 value"),
-        )
-        .ok();
-    }
     // 5. Set options to optionsResolution.[[Options]].
     // 6. Let r be optionsResolution.[[ResolvedLocale]].
     // 7. Set (deferred) dateTimeFormat.[[Locale]] to r.[[Locale]].
@@ -647,41 +874,64 @@ pub(crate) fn create_date_time_format(
     // 10. Set (deferred) dateTimeFormat.[[NumberingSystem]] to r.[[nu]].
     // 11. Let (deferred) resolvedLocaleData be r.[[LocaleData]].
 
-    // TODO: Handle hour12 and hc
-    // 12. If hour12 is true, then
-    // a. Let hc be resolvedLocaleData.[[hourCycle12]].
-    // 13. Else if hour12 is false, then
-    // a. Let hc be resolvedLocaleData.[[hourCycle24]].
-    // 14. Else,
-    // a. Assert: hour12 is undefined.
-    // b. Let hc be r.[[hc]].
-    // c. If hc is null, set hc to resolvedLocaleData.[[hourCycle]].
+    // NOTE: Steps 12-14 are deferred until the best format is known, since the locale data of the
+    // hour cycle is only needed if the format has an hour field.
 
     // 15. Let timeZone be ? Get(options, "timeZone").
     let time_zone = options.get(js_string!("timeZone"), context)?;
 
     // 16. If timeZone is undefined, then
     let time_zone = if time_zone.is_undefined() {
-        // TODO (nekevss): Resolve system time zone
-        // a. Set timeZone to SystemTimeZoneIdentifier().
-        JsString::from("Etc/UTC")
+        if let Some(time_zone) = to_locale_string_time_zone {
+            // NOTE: `Temporal.ZonedDateTime.prototype.toLocaleString` formats the value with its
+            // own time zone.
+            time_zone
+        } else {
+            // a. Set timeZone to SystemTimeZoneIdentifier().
+            let context: &Context = context;
+            let time_zone = context.get_system_time_zone(context.timezone_provider())?;
+            JsString::from(time_zone.identifier_with_provider(context.timezone_provider())?)
+        }
     // 17. Else,
     } else {
+        if to_locale_string_time_zone.is_some() {
+            return Err(js_error!(
+                TypeError: "the timeZone option cannot be used to format a Temporal.ZonedDateTime"
+            ));
+        }
         // a. Set timeZone to ? ToString(timeZone).
         time_zone.to_string(context)?
     };
     // 18. If IsTimeZoneOffsetString(timeZone) is true, then
     let time_zone_string = time_zone.to_std_string_escaped();
     // Note: Should a timezone enum be part of temporal_rs, icu_time, or an ECMA402 wrapper lib
-    let time_zone = if let Ok(utc_offset) = UtcOffset::try_from_str(&time_zone_string) {
+    let (time_zone, time_zone_identifier) = if let Ok(utc_offset) =
+        UtcOffset::try_from_str(&time_zone_string)
+    {
         //  a. Let parseResult be ParseText(StringToCodePoints(timeZone), UTCOffset).
         //  b. Assert: parseResult is a Parse Node.
         //  c. If parseResult contains more than one MinuteSecond Parse Node, throw a RangeError exception.
+        if utc_offset.to_seconds() % 60 != 0 {
+            return Err(js_error!(
+                RangeError: "{:?} was not a valid time zone offset.",
+                time_zone_string
+            ));
+        }
         //  d. Let offsetNanoseconds be ParseTimeZoneOffsetString(timeZone).
         //  e. Let offsetMinutes be offsetNanoseconds / (6 × 10**10).
         //  f. Assert: offsetMinutes is an integer.
+        let offset_minutes = utc_offset.to_seconds() / 60;
         //  g. Set timeZone to FormatOffsetTimeZoneIdentifier(offsetMinutes).
-        FormatTimeZone::UtcOffset(utc_offset)
+        let sign = if offset_minutes < 0 { '-' } else { '+' };
+        let identifier = format!(
+            "{sign}{:02}:{:02}",
+            offset_minutes.abs() / 60,
+            offset_minutes.abs() % 60
+        );
+        (
+            FormatTimeZone::UtcOffset(utc_offset),
+            JsString::from(identifier),
+        )
     } else {
         // 19. Else,
         //  a. Let timeZoneIdentifierRecord be GetAvailableNamedTimeZoneIdentifier(timeZone).
@@ -700,7 +950,15 @@ pub(crate) fn create_date_time_format(
                 JsNativeError::range()
                     .with_message(format!("{time_zone_string:#?} was not a valid time zone."))
             })?;
-        FormatTimeZone::Identifier((time_zone, time_zone_id))
+        let identifier = TemporalTimeZone::try_from_str_with_provider(
+            &time_zone_string,
+            context.timezone_provider(),
+        )?
+        .identifier_with_provider(context.timezone_provider())?;
+        (
+            FormatTimeZone::Identifier((time_zone, time_zone_id)),
+            JsString::from(identifier),
+        )
     };
     // 20. (deferred) Set dateTimeFormat.[[TimeZone]] to timeZone.
 
@@ -719,9 +977,10 @@ pub(crate) fn create_date_time_format(
     //         d. Set formatOptions.[[<prop>]] to value.
     //         e. If value is not undefined, then
     //                i. Set hasExplicitFormatComponents to true.
-    let mut format_options = FormatOptions::try_init(&options, preferences.hour_cycle, context)?;
+    let mut format_options = FormatOptions::try_init(&options, context)?;
+    // NOTE: The requested options are kept to create the formats of `Temporal` values.
+    let requested_options = format_options;
 
-    // TODO: how should formatMatcher be used?
     // 25. Let formatMatcher be ? GetOption(options, "formatMatcher", string, « "basic", "best fit" », "best fit").
     let format_matcher =
         get_option::<FormatMatcher>(&options, js_string!("formatMatcher"), context)?
@@ -755,11 +1014,9 @@ pub(crate) fn create_date_time_format(
                 js_error!(TypeError: "dateStyle cannot be defined for a time DateTimeFormat"),
             );
         }
-        // TODO (nekevss): implement d-e
-        // TODO (nekevss): Do we have access to the styles?
         // d. Let styles be resolvedLocaleData.[[styles]].[[<resolvedCalendar>]].
         // e. Let bestFormat be DateTimeStyleFormat(dateStyle, timeStyle, styles).
-        date_time_style_format(date_style, time_style)?
+        date_time_style_format(date_style, time_style, RequiredFields::Any, true)?
     // 31. Else,
     } else {
         // a. Let needDefaults be true.
@@ -792,19 +1049,59 @@ pub(crate) fn create_date_time_format(
         // i. Let bestFormat be BasicFormatMatcher(formatOptions, formats).
         // h. Else,
         // i. Let bestFormat be BestFitFormatMatcher(formatOptions, formats).
+        //
+        // NOTE: ICU4X doesn't expose the list of formats of the locale, and picks the pattern for
+        // a set of fields itself, so "basic" is treated as "best fit". The specification allows
+        // this, since the result of both matchers is implementation-defined.
         match format_matcher {
             FormatMatcher::Basic | FormatMatcher::BestFit => {
                 best_fit_date_time_format(&format_options)?
             }
         }
     };
+    let has_hour = if date_style.is_some() || time_style.is_some() {
+        time_style.is_some()
+    } else {
+        format_options.to_time_fields().is_some()
+    };
+
+    let hc = match hour_12 {
+        // 12. If hour12 is true, then
+        //     a. Let hc be resolvedLocaleData.[[hourCycle12]].
+        Some(true) => {
+            if locale_hour_cycle(formatter_preferences, context.intl_provider())?
+                == IcuHourCycle::H11
+            {
+                Some(IcuHourCycle::H11)
+            } else {
+                Some(IcuHourCycle::H12)
+            }
+        }
+        // 13. Else if hour12 is false, then
+        //     a. Let hc be resolvedLocaleData.[[hourCycle24]].
+        Some(false) => Some(IcuHourCycle::H23),
+        // 14. Else,
+        //     a. Assert: hour12 is undefined.
+        //     b. Let hc be r.[[hc]].
+        //     c. If hc is null, set hc to resolvedLocaleData.[[hourCycle]].
+        None => match intl_options.preferences.hour_cycle {
+            Some(hc) => Some(hc),
+            None if has_hour => Some(locale_hour_cycle(
+                formatter_preferences,
+                context.intl_provider(),
+            )?),
+            None => None,
+        },
+    };
+    formatter_preferences.hour_cycle = hc;
+
     // 32. Set dateTimeFormat.[[DateTimeFormat]] to bestFormat.
     // 33. If bestFormat has a field [[hour]], then
     // a. Set dateTimeFormat.[[HourCycle]] to hc.
     // 34. Return dateTimeFormat.
     let formatter = DateTimeFormatter::try_new_with_buffer_provider(
         context.intl_provider().erased_provider(),
-        resolved_locale.clone().into(),
+        formatter_preferences,
         fieldset,
     )
     .map_err(|e| JsNativeError::range().with_message(format!("failed to load formatter: {e}")))?;
@@ -813,10 +1110,14 @@ pub(crate) fn create_date_time_format(
         locale: resolved_locale,
         calendar_algorithm: intl_options.preferences.calendar_algorithm,
         numbering_system: intl_options.preferences.numbering_system,
-        hour_cycle: intl_options.preferences.hour_cycle,
+        hour_cycle: hc.filter(|_| has_hour),
         date_style,
         time_style,
         time_zone,
+        time_zone_identifier,
+        preferences: formatter_preferences,
+        requested_options,
+        format_options,
         fieldset,
         formatter,
         bound_format: None,
@@ -824,20 +1125,73 @@ pub(crate) fn create_date_time_format(
     })
 }
 
-/// Formats a timestamp (epoch milliseconds) using the given [`DateTimeFormat`] internals.
+/// Gets the default hour cycle of the locale of `preferences`.
 ///
-/// This is the shared implementation used by:
-/// - the bound `format` function created in `get_format`, and
-/// - [`format_date_time_locale`] used by `Date.prototype.toLocaleString` (and friends).
+/// This corresponds to the `[[hourCycle]]` field of the locale data of `Intl.DateTimeFormat`,
+/// which ICU4X stores as the hour field of the time patterns for the preferred hour cycle of the
+/// locale (the `j` skeleton of UTS 35).
+pub(in crate::builtins::intl) fn locale_hour_cycle(
+    preferences: DateTimeFormatterPreferences,
+    provider: &IntlProvider,
+) -> JsResult<IcuHourCycle> {
+    const PREFERRED_HOUR_CYCLE: &DataMarkerAttributes =
+        DataMarkerAttributes::from_str_or_panic("j");
+
+    let locale = DatetimePatternsTimeV1::INFO.make_locale(preferences.locale_preferences);
+    let patterns = DataProvider::<DatetimePatternsTimeV1>::load(
+        provider,
+        DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                PREFERRED_HOUR_CYCLE,
+                &locale,
+            ),
+            ..Default::default()
+        },
+    )
+    .map_err(|e| JsNativeError::range().with_message(format!("failed to load time patterns: {e}")))?
+    .payload;
+
+    let hour = patterns
+        .get()
+        .elements
+        .iter()
+        .flat_map(|pattern| pattern.get_default().1.iter())
+        .find_map(|item| match item {
+            PatternItem::Field(Field {
+                symbol: FieldSymbol::Hour(hour),
+                ..
+            }) => Some(hour),
+            _ => None,
+        });
+
+    Ok(match hour {
+        Some(Hour::H11) => IcuHourCycle::H11,
+        Some(Hour::H12) => IcuHourCycle::H12,
+        _ => IcuHourCycle::H23,
+    })
+}
+
+/// Creates a [`ZonedDateTime`] that can be formatted by ICU4X from a local date and time.
+fn zoned(
+    datetime: DateTime<Iso>,
+    zone: TimeZoneInfo<Base>,
+) -> ZonedDateTime<Iso, TimeZoneInfo<AtTime>> {
+    ZonedDateTime {
+        date: datetime.date,
+        time: datetime.time,
+        zone: zone.at_date_time_iso(datetime),
+    }
+}
+
+/// Converts a timestamp (epoch milliseconds) to the local time of the time zone of a
+/// [`DateTimeFormat`].
 ///
 /// It corresponds to the *post*-`TimeClip` portion of
-/// [`FormatDateTime(dtf, x)`](https://tc39.es/ecma402/#sec-formatdatetime),
-/// and the `ToLocalTime` / `PartitionDateTimePattern` logic from
-/// [11.5.6](https://tc39.es/ecma402/#sec-partitiondatetimepattern) and
-/// [11.5.12](https://tc39.es/ecma402/#sec-tolocaltime).
+/// [`PartitionDateTimePattern(dtf, x)`](https://tc39.es/ecma402/#sec-partitiondatetimepattern),
+/// and the `ToLocalTime` logic from [11.5.12](https://tc39.es/ecma402/#sec-tolocaltime).
 ///
 /// Callers must have already applied `TimeClip` and `NaN` check
-/// (`FormatDateTime` steps 1–2). This helper implements:
+/// (`PartitionDateTimePattern` steps 1–2). This helper implements:
 ///
 /// 11.5.6 `PartitionDateTimePattern` ( dtf, x )
 /// 1. Let x be TimeClip(x). (Done by caller)
@@ -847,13 +1201,12 @@ pub(crate) fn create_date_time_format(
 /// 5. Let offsetNs be GetOffsetNanosecondsFor(timeZone, epochNanoseconds).
 /// 6. Let tz be 𝔽(ℝ(x) + ℝ(offsetNs) / 10^6).
 ///
-/// Then calls `ToLocalTime::from_local_epoch_milliseconds` to obtain calendar fields,
-/// and formats the resulting `ZonedDateTime` with ICU4X.
-fn format_timestamp_with_dtf(
+/// Then calls `ToLocalTime::from_local_epoch_milliseconds` to obtain calendar fields.
+fn to_zoned_date_time(
     dtf: &DateTimeFormat,
     timestamp: f64,
     context: &mut Context,
-) -> JsResult<JsString> {
+) -> JsResult<ZonedDateTime<Iso, TimeZoneInfo<AtTime>>> {
     // PartitionDateTimePattern ( dtf, x ) step 3:
     // Let epochNanoseconds be ℤ(ℝ(x) × 10^6).
     //
@@ -887,21 +1240,515 @@ fn format_timestamp_with_dtf(
     let tz = timestamp + f64::from(time_zone_offset_seconds * 1_000);
     let fields = ToLocalTime::from_local_epoch_milliseconds(tz)?;
     let dt = fields.to_formattable_datetime()?;
-    let tz_info = time_zone.to_time_zone_info();
-    let tz_info_at_time = tz_info.at_date_time_iso(dt);
-    let zdt = ZonedDateTime {
-        date: dt.date,
-        time: dt.time,
-        zone: tz_info_at_time,
+    Ok(zoned(
+        dt,
+        time_zone.to_time_zone_info(time_zone_offset_seconds),
+    ))
+}
+
+/// The kind of a `Temporal` value that can be formatted by a [`DateTimeFormat`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlainKind {
+    Date,
+    Time,
+    DateTime,
+    YearMonth,
+    MonthDay,
+}
+
+impl PlainKind {
+    /// Gets the fields required to format a value of this kind.
+    fn required(self) -> RequiredFields {
+        match self {
+            Self::Date => RequiredFields::Date,
+            Self::Time => RequiredFields::Time,
+            Self::DateTime => RequiredFields::Any,
+            Self::YearMonth => RequiredFields::YearMonth,
+            Self::MonthDay => RequiredFields::MonthDay,
+        }
+    }
+}
+
+/// A `Temporal` value without a time zone, converted to the ISO calendar.
+#[derive(Debug, Clone)]
+struct PlainFormattable {
+    kind: PlainKind,
+    datetime: DateTime<Iso>,
+    /// The calendar of the original value, or `None` for `Temporal.PlainTime` values.
+    calendar: Option<String>,
+}
+
+impl PlainFormattable {
+    /// Checks that the calendar of this value can be used by `dtf`, as done by the abstract
+    /// operation [`HandleDateTimeValue ( dateTimeFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-handledatetimevalue
+    fn check_calendar(&self, dtf: &DateTimeFormat) -> JsResult<()> {
+        let Some(calendar) = self.calendar.as_deref() else {
+            return Ok(());
+        };
+        let dtf_calendar = dtf.calendar();
+        // Plain dates and date times with the ISO 8601 calendar can be formatted by any
+        // `DateTimeFormat`.
+        let iso_allowed = matches!(self.kind, PlainKind::Date | PlainKind::DateTime);
+        if (iso_allowed && calendar == "iso8601") || calendar == dtf_calendar {
+            return Ok(());
+        }
+        Err(js_error!(
+            RangeError: "the calendar `{}` does not match the calendar `{}` of the DateTimeFormat",
+            calendar,
+            dtf_calendar
+        ))
+    }
+}
+
+/// A value that can be formatted by a [`DateTimeFormat`], as returned by the abstract operation
+/// [`ToDateTimeFormattable ( value )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-todatetimeformattable
+#[derive(Debug, Clone)]
+enum Formattable {
+    /// A time value in epoch milliseconds.
+    TimeValue(f64),
+    /// The epoch milliseconds of a `Temporal.Instant`.
+    Instant(f64),
+    /// A `Temporal` value without a time zone.
+    Plain(PlainFormattable),
+}
+
+impl Formattable {
+    /// Abstract operation [`SameTemporalType ( x, y )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-sametemporaltype
+    fn same_type(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::TimeValue(_), Self::TimeValue(_)) | (Self::Instant(_), Self::Instant(_)) => true,
+            (Self::Plain(x), Self::Plain(y)) => x.kind == y.kind,
+            _ => false,
+        }
+    }
+
+    /// Converts a `Temporal` object to a `Formattable`, returning `None` if `object` is not a
+    /// `Temporal` object.
+    #[cfg(feature = "temporal")]
+    fn from_temporal_object(object: &JsObject) -> JsResult<Option<Self>> {
+        use crate::builtins::temporal::{
+            Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime, PlainYearMonth,
+            ZonedDateTime as TemporalZonedDateTime,
+        };
+        use temporal_rs::{Calendar, fields::CalendarFields};
+
+        let (kind, datetime, calendar) = if let Some(date) = object.downcast_ref::<PlainDate>() {
+            let calendar = date.inner.calendar().identifier().to_owned();
+            let date = date.inner.with_calendar(Calendar::ISO);
+            (
+                PlainKind::Date,
+                iso_date_time(date.year(), date.month(), date.day(), 0, 0, 0, 0)?,
+                Some(calendar),
+            )
+        } else if let Some(time) = object.downcast_ref::<PlainTime>() {
+            let time = &time.inner;
+            (
+                PlainKind::Time,
+                iso_date_time(
+                    1970,
+                    1,
+                    1,
+                    time.hour(),
+                    time.minute(),
+                    time.second(),
+                    subsecond_nanoseconds(
+                        time.millisecond(),
+                        time.microsecond(),
+                        time.nanosecond(),
+                    ),
+                )?,
+                None,
+            )
+        } else if let Some(datetime) = object.downcast_ref::<PlainDateTime>() {
+            let calendar = datetime.inner.calendar().identifier().to_owned();
+            let dt = datetime.inner.with_calendar(Calendar::ISO);
+            (
+                PlainKind::DateTime,
+                iso_date_time(
+                    dt.year(),
+                    dt.month(),
+                    dt.day(),
+                    dt.hour(),
+                    dt.minute(),
+                    dt.second(),
+                    subsecond_nanoseconds(dt.millisecond(), dt.microsecond(), dt.nanosecond()),
+                )?,
+                Some(calendar),
+            )
+        } else if let Some(year_month) = object.downcast_ref::<PlainYearMonth>() {
+            let calendar = year_month.inner.calendar().identifier().to_owned();
+            let date = year_month
+                .inner
+                .to_plain_date(Some(CalendarFields::new().with_optional_day(Some(1))))?
+                .with_calendar(Calendar::ISO);
+            (
+                PlainKind::YearMonth,
+                iso_date_time(date.year(), date.month(), date.day(), 0, 0, 0, 0)?,
+                Some(calendar),
+            )
+        } else if let Some(month_day) = object.downcast_ref::<PlainMonthDay>() {
+            let calendar = month_day.inner.calendar().identifier().to_owned();
+            // NOTE: 1972 is the reference year of month-days in the ISO 8601 calendar, which is
+            // a leap year.
+            let date = month_day
+                .inner
+                .to_plain_date(Some(CalendarFields::new().with_optional_year(Some(1972))))?
+                .with_calendar(Calendar::ISO);
+            (
+                PlainKind::MonthDay,
+                iso_date_time(date.year(), date.month(), date.day(), 0, 0, 0, 0)?,
+                Some(calendar),
+            )
+        } else if let Some(instant) = object.downcast_ref::<Instant>() {
+            return Ok(Some(Self::Instant(
+                instant.inner.epoch_milliseconds() as f64
+            )));
+        } else if object.is::<TemporalZonedDateTime>() {
+            return Err(js_error!(
+                TypeError: "Temporal.ZonedDateTime cannot be formatted by Intl.DateTimeFormat; use toLocaleString instead"
+            ));
+        } else {
+            return Ok(None);
+        };
+
+        Ok(Some(Self::Plain(PlainFormattable {
+            kind,
+            datetime,
+            calendar,
+        })))
+    }
+}
+
+/// Computes the subsecond nanoseconds of a time from its subsecond fields.
+#[cfg(feature = "temporal")]
+fn subsecond_nanoseconds(millisecond: u16, microsecond: u16, nanosecond: u16) -> u32 {
+    u32::from(millisecond) * 1_000_000 + u32::from(microsecond) * 1_000 + u32::from(nanosecond)
+}
+
+/// Creates an ISO date time that can be formatted by ICU4X.
+#[cfg(feature = "temporal")]
+fn iso_date_time(
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    subsecond: u32,
+) -> JsResult<DateTime<Iso>> {
+    Ok(DateTime {
+        date: Date::try_new_iso(year, month, day)
+            .map_err(|_| js_error!(RangeError: "date is outside of the formattable range"))?,
+        time: Time::try_new(hour, minute, second, subsecond)
+            .ok()
+            .js_expect("Temporal times are always valid")?,
+    })
+}
+
+/// Abstract operation [`ToDateTimeFormattable ( value )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-todatetimeformattable
+fn to_date_time_formattable(value: &JsValue, context: &mut Context) -> JsResult<Formattable> {
+    // 1. If IsTemporalObject(value) is true, return value.
+    #[cfg(feature = "temporal")]
+    if let Some(object) = value.as_object()
+        && let Some(formattable) = Formattable::from_temporal_object(&object)?
+    {
+        return Ok(formattable);
+    }
+
+    // 2. Return ? ToNumber(value).
+    Ok(Formattable::TimeValue(value.to_number(context)?))
+}
+
+/// Formats `x` with the format of `dtf` that corresponds to the type of `x`, calling `f` with the
+/// formatted value.
+///
+/// This handles the steps shared by [`PartitionDateTimePattern`][spec] and
+/// [`HandleDateTimeValue`][temporal].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimepattern
+/// [temporal]: https://tc39.es/proposal-temporal/#sec-temporal-handledatetimevalue
+fn with_formatted<R>(
+    dtf: &DateTimeFormat,
+    x: &Formattable,
+    context: &mut Context,
+    f: impl FnOnce(FormattedDateTime<'_>) -> R,
+) -> JsResult<R> {
+    let (time_value, required, inherit_all) = match x {
+        Formattable::TimeValue(t) => (*t, None, false),
+        Formattable::Instant(t) => (*t, Some(RequiredFields::Any), true),
+        Formattable::Plain(plain) => (0.0, Some(plain.kind.required()), false),
     };
-    let result = dtf.formatter.format(&zdt).to_string();
-    Ok(JsString::from(result))
+
+    let zdt = if let Formattable::Plain(plain) = x {
+        plain.check_calendar(dtf)?;
+        // NOTE: the formats of values without a time zone never include a time zone name.
+        zoned(
+            plain.datetime,
+            TimeZone::UNKNOWN.with_offset(Some(UtcOffset::zero())),
+        )
+    } else {
+        // 1. Let x be TimeClip(x).
+        let x = time_clip(time_value);
+        // 2. If x is NaN, throw a RangeError exception.
+        if x.is_nan() {
+            return Err(js_error!(RangeError: "formatted date cannot be NaN"));
+        }
+        to_zoned_date_time(dtf, x, context)?
+    };
+
+    let Some(required) = required else {
+        return Ok(f(dtf.formatter.format(&zdt)));
+    };
+
+    let fieldset = dtf.temporal_fieldset(required, inherit_all)?;
+    let formatter = DateTimeFormatter::try_new_with_buffer_provider(
+        context.intl_provider().erased_provider(),
+        dtf.preferences,
+        fieldset,
+    )
+    .map_err(|e| JsNativeError::range().with_message(format!("failed to load formatter: {e}")))?;
+
+    Ok(f(formatter.format(&zdt)))
+}
+
+/// Abstract operation [`FormatDateTime ( dateTimeFormat, x )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-formatdatetime
+fn format_date_time(
+    dtf: &DateTimeFormat,
+    x: &Formattable,
+    context: &mut Context,
+) -> JsResult<JsString> {
+    // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+    // 2. Let result be the empty String.
+    // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+    //     a. Set result to the string-concatenation of result and part.[[Value]].
+    // 4. Return result.
+    with_formatted(dtf, x, context, |formatted| {
+        JsString::from(formatted.write_to_string().as_ref())
+    })
+}
+
+/// A part of a formatted date, as returned by [`partition_date_time_pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DateTimePart {
+    typ: &'static str,
+    value: String,
 }
 
+/// Abstract operation [`PartitionDateTimePattern ( dateTimeFormat, x )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimepattern
+fn partition_date_time_pattern(
+    dtf: &DateTimeFormat,
+    x: &Formattable,
+    context: &mut Context,
+) -> JsResult<Vec<DateTimePart>> {
+    with_formatted(dtf, x, context, |formatted| {
+        let mut collector = PartsCollector(Vec::new());
+        formatted
+            .write_to_parts(&mut collector)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+        Ok(collector.into_parts())
+    })?
+}
+
+/// Collects the parts of a formatted date.
+///
+/// Text that isn't claimed by a part of the formatted date is stored as `None`, and becomes a
+/// `literal` part at the end.
+#[derive(Debug)]
+struct PartsCollector(Vec<(Option<&'static str>, String)>);
+
+impl PartsCollector {
+    fn into_parts(self) -> Vec<DateTimePart> {
+        let mut parts: Vec<DateTimePart> = Vec::with_capacity(self.0.len());
+        for (typ, value) in self.0 {
+            let typ = typ.unwrap_or("literal");
+            if typ == "literal"
+                && let Some(last) = parts.last_mut()
+                && last.typ == "literal"
+            {
+                last.value.push_str(&value);
+            } else {
+                parts.push(DateTimePart { typ, value });
+            }
+        }
+        parts
+    }
+}
+
+impl std::fmt::Write for PartsCollector {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        if let Some((None, value)) = self.0.last_mut() {
+            value.push_str(s);
+        } else {
+            self.0.push((None, s.to_owned()));
+        }
+        Ok(())
+    }
+}
+
+impl PartsWrite for PartsCollector {
+    type SubPartsWrite = Self;
+
+    fn with_part(
+        &mut self,
+        part: Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        let typ = match part.category {
+            // The names of the date time fields of ICU4X match the types of the parts of ECMA-402.
+            "datetime" => Some(part.value),
+            // Decimal parts are only nested inside of date time fields (e.g. the fractional digits
+            // of the seconds), where everything but the fraction and the separator belongs to
+            // the enclosing field.
+            "decimal" => match part.value {
+                "fraction" => Some("fractionalSecond"),
+                "decimal" => Some("literal"),
+                _ => None,
+            },
+            _ => return f(self),
+        };
+
+        let mut sub = PartsCollector(Vec::new());
+        f(&mut sub)?;
+        self.0.extend(
+            sub.0
+                .into_iter()
+                .map(|(inner, value)| (inner.or(typ), value)),
+        );
+        Ok(())
+    }
+}
+
+/// Abstract operation [`PartitionDateTimeRangePattern ( dateTimeFormat, x, y )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-partitiondatetimerangepattern
+fn partition_date_time_range_pattern(
+    dtf: &DateTimeFormat,
+    x: &Formattable,
+    y: &Formattable,
+    context: &mut Context,
+) -> JsResult<Vec<(DateTimePart, &'static str)>> {
+    // 1. If IsTemporalObject(x) is true or IsTemporalObject(y) is true, then
+    //     a. If SameTemporalType(x, y) is false, throw a TypeError exception.
+    if !x.same_type(y) {
+        return Err(js_error!(
+            TypeError: "the start and end of a date range must have the same type"
+        ));
+    }
+
+    let start = partition_date_time_pattern(dtf, x, context)?;
+    let end = partition_date_time_pattern(dtf, y, context)?;
+
+    // If the start and the end are equal in all the fields of the format, the range is
+    // formatted as a single date.
+    if start == end {
+        return Ok(start.into_iter().map(|part| (part, "shared")).collect());
+    }
+
+    // TODO: use the interval formats of the locale once ICU4X supports them. For now, this always
+    // uses the fallback range pattern.
+    let mut result = Vec::with_capacity(start.len() + end.len() + 1);
+    result.extend(start.into_iter().map(|part| (part, "startRange")));
+    result.push((
+        DateTimePart {
+            typ: "literal",
+            value: String::from("\u{2009}–\u{2009}"),
+        },
+        "shared",
+    ));
+    result.extend(end.into_iter().map(|part| (part, "endRange")));
+    Ok(result)
+}
+
+/// Shared steps of `formatRange` and `formatRangeToParts`.
+fn date_time_range_parts(
+    this: &JsValue,
+    args: &[JsValue],
+    method: &str,
+    context: &mut Context,
+) -> JsResult<Vec<(DateTimePart, &'static str)>> {
+    // 1. Let dtf be this value.
+    // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+    let dtf = this
+        .as_object()
+        .and_then(|o| o.downcast::<DateTimeFormat>().ok())
+        .ok_or_else(|| {
+            JsNativeError::typ().with_message(format!(
+                "`{method}` can only be called on a `DateTimeFormat` object"
+            ))
+        })?;
+
+    let start = args.get_or_undefined(0);
+    let end = args.get_or_undefined(1);
+
+    // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+    if start.is_undefined() || end.is_undefined() {
+        return Err(js_error!(TypeError: "`{}` requires both a start and an end date", method));
+    }
+
+    // 4. Let x be ? ToDateTimeFormattable(startDate).
+    let x = to_date_time_formattable(start, context)?;
+
+    // 5. Let y be ? ToDateTimeFormattable(endDate).
+    let y = to_date_time_formattable(end, context)?;
+
+    partition_date_time_range_pattern(dtf.borrow().data(), &x, &y, context)
+}
+
+/// Creates the object of a part returned by `formatToParts` and `formatRangeToParts`.
+fn part_to_object(
+    part: DateTimePart,
+    source: Option<&'static str>,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    let o = context
+        .intrinsics()
+        .templates()
+        .ordinary_object()
+        .create(OrdinaryObject, vec![]);
+
+    o.create_data_property_or_throw(js_string!("type"), js_string!(part.typ), context)
+        .js_expect("operation must not fail per the spec")?;
+    o.create_data_property_or_throw(js_string!("value"), js_string!(part.value), context)
+        .js_expect("operation must not fail per the spec")?;
+    if let Some(source) = source {
+        o.create_data_property_or_throw(js_string!("source"), js_string!(source), context)
+            .js_expect("operation must not fail per the spec")?;
+    }
+
+    Ok(o)
+}
+
+/// Abstract operation [`DateTimeStyleFormat ( dateStyle, timeStyle, styles )`][spec], restricted to
+/// the fields in `required`.
+///
+/// The time zone is only included in the format if `with_zone` is `true`.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-date-time-style-format
 fn date_time_style_format(
     date_style: Option<DateStyle>,
     time_style: Option<TimeStyle>,
+    required: RequiredFields,
+    with_zone: bool,
 ) -> JsResult<CompositeFieldSet> {
+    let date_style = date_style.filter(|_| required != RequiredFields::Time);
+    let time_style =
+        time_style.filter(|_| matches!(required, RequiredFields::Time | RequiredFields::Any));
+
     let mut builder = FieldSetBuilder::default();
     builder.length = match date_style {
         Some(DateStyle::Full | DateStyle::Long) => Some(Length::Long),
@@ -911,19 +1758,30 @@ fn date_time_style_format(
             Some(TimeStyle::Full | TimeStyle::Long) => Some(Length::Long),
             Some(TimeStyle::Medium) => Some(Length::Medium),
             Some(TimeStyle::Short) => Some(Length::Short),
-            None => return Err(js_error!(TypeError: "dateStyle or timeStyle must be defined")),
+            None => {
+                return Err(
+                    js_error!(TypeError: "the dateStyle or timeStyle of this DateTimeFormat cannot format the given value"),
+                );
+            }
         },
     };
-    builder.date_fields = match date_style {
-        Some(DateStyle::Full) => Some(DateFields::YMDE),
-        Some(DateStyle::Long | DateStyle::Medium | DateStyle::Short) => Some(DateFields::YMD),
-        None => None, // NOTE: timeStyle being undefined is checked when setting length
+    builder.date_fields = match (date_style, required) {
+        (None, _) => None, // NOTE: timeStyle being undefined is checked when setting length
+        (Some(_), RequiredFields::YearMonth) => Some(DateFields::YM),
+        (Some(_), RequiredFields::MonthDay) => Some(DateFields::MD),
+        (Some(DateStyle::Full), _) => Some(DateFields::YMDE),
+        (Some(DateStyle::Long | DateStyle::Medium | DateStyle::Short), _) => Some(DateFields::YMD),
     };
     builder.time_precision = match time_style {
         Some(TimeStyle::Full | TimeStyle::Long | TimeStyle::Medium) => Some(TimePrecision::Second),
         Some(TimeStyle::Short) => Some(TimePrecision::Minute),
         None => None, // NOTE: dateStyle being undefined is checked when setting length
     };
+    builder.zone_style = match time_style {
+        Some(TimeStyle::Full) if with_zone => Some(ZoneStyle::SpecificLong),
+        Some(TimeStyle::Long) if with_zone => Some(ZoneStyle::SpecificShort),
+        _ => None,
+    };
     builder
         .build_composite()
         .map_err(|e| JsNativeError::range().with_message(e.to_string()).into())
@@ -1014,7 +1872,7 @@ fn unwrap_date_time_format(
 /// Shared helper used by Date.prototype.toLocaleString,
 /// Date.prototype.toLocaleDateString, and Date.prototype.toLocaleTimeString.
 /// Applies `ToDateTimeOptions` defaults, calls [`create_date_time_format`], and formats
-/// the timestamp via [`format_timestamp_with_dtf`] without allocating a JS object.
+/// the timestamp via [`format_date_time`] without allocating a JS object.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn format_date_time_locale(
     locales: &JsValue,
@@ -1044,12 +1902,90 @@ pub(crate) fn format_date_time_locale(
         )?;
     }
     let options_value = options.into();
-    let dtf = create_date_time_format(locales, &options_value, format_type, defaults, context)?;
-    // FormatDateTime steps 1–2: TimeClip and NaN check (format_timestamp_with_dtf does ToLocalTime + format only).
-    let x = time_clip(timestamp);
-    if x.is_nan() {
-        return Err(js_error!(RangeError: "formatted date cannot be NaN"));
-    }
-    let result = format_timestamp_with_dtf(&dtf, x, context)?;
+    let dtf = create_date_time_format(
+        locales,
+        &options_value,
+        format_type,
+        defaults,
+        None,
+        context,
+    )?;
+    let result = format_date_time(&dtf, &Formattable::TimeValue(timestamp), context)?;
     Ok(JsValue::from(result))
 }
+
+/// Shared helper used by the `toLocaleString` methods of the `Temporal` builtins, except
+/// `Temporal.Duration`.
+///
+/// Creates a [`DateTimeFormat`] with the given `required` and `defaults` fields and formats
+/// `value` with it, as described by the `toLocaleString` methods of the
+/// [ECMAScript Internationalization API][spec].
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-temporal-intl
+#[cfg(feature = "temporal")]
+pub(crate) fn format_temporal_locale(
+    value: &JsValue,
+    locales: &JsValue,
+    options: &JsValue,
+    required: FormatType,
+    defaults: FormatDefaults,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    use crate::builtins::temporal::ZonedDateTime as TemporalZonedDateTime;
+
+    let zoned = value
+        .as_object()
+        .and_then(|o| o.downcast::<TemporalZonedDateTime>().ok());
+
+    let Some(zdt) = zoned else {
+        // 3. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options,
+        //    required, defaults).
+        let dtf = create_date_time_format(locales, options, required, defaults, None, context)?;
+        // 4. Return ? FormatDateTime(dateFormat, temporalValue).
+        let x = to_date_time_formattable(value, context)?;
+        return Ok(format_date_time(&dtf, &x, context)?.into());
+    };
+
+    // `Temporal.ZonedDateTime.prototype.toLocaleString ( [ locales [ , options ] ] )`
+    // https://tc39.es/proposal-temporal/#sup-temporal.zoneddatetime.prototype.tolocalestring
+    let (time_zone, calendar, epoch_milliseconds) = {
+        let zdt = zdt.borrow();
+        let inner = &zdt.data().inner;
+        (
+            // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+            inner
+                .time_zone()
+                .identifier_with_provider(context.timezone_provider())?,
+            // 4. Let calendar be zonedDateTime.[[Calendar]].
+            inner.calendar().identifier().to_owned(),
+            inner.epoch_milliseconds(),
+        )
+    };
+
+    // 5. Let dateTimeFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options,
+    //    any, all, timeZone).
+    let dtf = create_date_time_format(
+        locales,
+        options,
+        required,
+        defaults,
+        Some(JsString::from(time_zone)),
+        context,
+    )?;
+
+    // 6. If calendar is not "iso8601" and CalendarEquals(calendar, dateTimeFormat.[[Calendar]]) is
+    //    false, throw a RangeError exception.
+    let dtf_calendar = dtf.calendar();
+    if calendar != "iso8601" && calendar != dtf_calendar {
+        return Err(js_error!(
+            RangeError: "the calendar `{}` does not match the calendar `{}` of the DateTimeFormat",
+            calendar,
+            dtf_calendar
+        ));
+    }
+
+    // 7. Let instant be ! CreateTemporalInstant(zonedDateTime.[[EpochNanoseconds]]).
+    // 8. Return ? FormatDateTime(dateTimeFormat, instant).
+    let x = Formattable::Instant(epoch_milliseconds as f64);
+    Ok(format_date_time(&dtf, &x, context)?.into())
+}
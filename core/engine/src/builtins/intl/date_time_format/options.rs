@@ -1,7 +1,7 @@
 //! Intl.DateTimeFormat options module
 
 use crate::{
-    Context, JsError, JsNativeError, JsObject, JsResult, JsValue,
+    Context, JsError, JsNativeError, JsObject, JsResult, JsString, JsValue,
     builtins::{
         intl::{
             ServicePreferences, date_time_format::FormatType, locale::validate_extension,
@@ -154,8 +154,8 @@ impl OptionType for IcuHourCycle {
 // This section includes formatting options that act as an intermediary between
 // user space and ICU4X's datetimeformat composite fields.
 
+#[derive(Debug, Clone, Copy)]
 pub(super) struct FormatOptions {
-    _hour_cycle: Option<IcuHourCycle>,                 // -> ???
     week_day: Option<WeekDay>,                         // e -> Maps to DateField
     era: Option<Era>,                                  // G -> Maps to YearStyle
     year: Option<Year>,                                // Y -> Maps to DateField
@@ -170,11 +170,7 @@ pub(super) struct FormatOptions {
 }
 
 impl FormatOptions {
-    pub(super) fn try_init(
-        options: &JsObject,
-        hour_cycle: Option<IcuHourCycle>, // TODO: Is option correct?
-        context: &mut Context,
-    ) -> JsResult<Self> {
+    pub(super) fn try_init(options: &JsObject, context: &mut Context) -> JsResult<Self> {
         // Below is adapted and inlined from Step 24 of `CreateDateTimeFormat`
         let week_day = get_option::<WeekDay>(options, js_string!("weekday"), context)?;
         let era = get_option::<Era>(options, js_string!("era"), context)?;
@@ -192,7 +188,6 @@ impl FormatOptions {
             get_option::<TimeZoneName>(options, js_string!("timeZoneName"), context)?;
 
         Ok(Self {
-            _hour_cycle: hour_cycle,
             week_day,
             era,
             year,
@@ -318,6 +313,142 @@ impl FormatOptions {
     pub(super) fn to_zone_style(&self) -> Option<ZoneStyle> {
         self.time_zone_name.map(TimeZoneName::to_zone_style)
     }
+
+    /// Gets the components of these options that are relevant to a `Temporal` value.
+    ///
+    /// This is adapted from the abstract operation [`GetDateTimeFormat`][spec], returning `None`
+    /// if none of the requested components can be used to format the value.
+    ///
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-getdatetimeformat
+    pub(super) fn get_date_time_format(
+        &self,
+        required: RequiredFields,
+        inherit_all: bool,
+    ) -> Option<Self> {
+        let date = matches!(required, RequiredFields::Date | RequiredFields::Any);
+        let time = matches!(required, RequiredFields::Time | RequiredFields::Any);
+        let year = date || required == RequiredFields::YearMonth;
+        let day = date || required == RequiredFields::MonthDay;
+        let month = year || day;
+
+        // 5. Let anyPresent be false.
+        // 6. For each property name prop of « "weekday", "year", "month", "day", "dayPeriod",
+        //    "hour", "minute", "second", "fractionalSecondDigits" », do
+        //     a. If options.[[<prop>]] is not undefined, set anyPresent to true.
+        let any_present = self.has_date_defaults() || self.has_time_defaults();
+
+        // 7. Let formatOptions be a new Record.
+        // 8. Let needDefaults be true.
+        // 9. For each property name prop of requiredOptions, do
+        //     a. Let value be options.[[<prop>]].
+        //     b. If value is not undefined, then
+        //         i. Set formatOptions.[[<prop>]] to value.
+        //         ii. Set needDefaults to false.
+        let mut format = Self {
+            week_day: self.week_day.filter(|_| date),
+            era: None,
+            year: self.year.filter(|_| year),
+            month: self.month.filter(|_| month),
+            day: self.day.filter(|_| day),
+            day_period: self.day_period.filter(|_| time),
+            hour: self.hour.filter(|_| time),
+            minute: self.minute.filter(|_| time),
+            second: self.second.filter(|_| time),
+            fractional_second_digits: self.fractional_second_digits.filter(|_| time),
+            time_zone_name: None,
+        };
+
+        // 10. If needDefaults is true, then
+        if !format.has_date_defaults() && !format.has_time_defaults() {
+            // a. If anyPresent is true and inherit is relevant, return null.
+            if any_present && !inherit_all {
+                return None;
+            }
+            // b. For each property name prop of defaultOptions, do
+            //     i. Set formatOptions.[[<prop>]] to "numeric".
+            if year {
+                format.year = Some(Year::Numeric);
+            }
+            if month {
+                format.month = Some(Month::Numeric);
+            }
+            if day {
+                format.day = Some(Day::Numeric);
+            }
+            if time {
+                format.set_time_defaults();
+            }
+        }
+
+        // 11. If inherit is all, then
+        if inherit_all {
+            // a. Copy the era and timeZoneName fields of options to formatOptions.
+            format.era = self.era;
+            format.time_zone_name = self.time_zone_name;
+        // 12. Else,
+        } else if required != RequiredFields::Time {
+            // a. If required is date, year-month, or month-day, copy the era of options.
+            format.era = self.era;
+        }
+
+        Some(format)
+    }
+
+    /// Gets the properties of the components of these options, in the order in which they
+    /// are listed in the [`resolvedOptions` properties table][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#table-datetimeformat-resolvedoptions-properties
+    pub(super) fn components(&self) -> [(JsString, Option<JsValue>); 11] {
+        fn string(value: Option<&'static str>) -> Option<JsValue> {
+            value.map(|v| js_string!(v).into())
+        }
+
+        [
+            (
+                js_string!("weekday"),
+                string(self.week_day.map(WeekDay::as_str)),
+            ),
+            (js_string!("era"), string(self.era.map(Era::as_str))),
+            (js_string!("year"), string(self.year.map(Year::as_str))),
+            (js_string!("month"), string(self.month.map(Month::as_str))),
+            (js_string!("day"), string(self.day.map(Day::as_str))),
+            (
+                js_string!("dayPeriod"),
+                string(self.day_period.map(DayPeriod::as_str)),
+            ),
+            (js_string!("hour"), string(self.hour.map(Hour::as_str))),
+            (
+                js_string!("minute"),
+                string(self.minute.map(Minute::as_str)),
+            ),
+            (
+                js_string!("second"),
+                string(self.second.map(Second::as_str)),
+            ),
+            (
+                js_string!("fractionalSecondDigits"),
+                self.fractional_second_digits
+                    .map(|digits| digits.to_u8().into()),
+            ),
+            (
+                js_string!("timeZoneName"),
+                string(self.time_zone_name.map(TimeZoneName::as_str)),
+            ),
+        ]
+    }
+}
+
+/// The fields that must be present in the format of a value, as used by the abstract
+/// operation [`GetDateTimeFormat`][spec].
+///
+/// [spec]: https://tc39.es/proposal-temporal/#sec-getdatetimeformat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum RequiredFields {
+    Date,
+    Time,
+    YearMonth,
+    MonthDay,
+    Any,
 }
 
 // ==== Format Options ====
@@ -341,6 +472,14 @@ impl OptionType for WeekDay {
 }
 
 impl WeekDay {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+    }
+
     pub(crate) fn to_length(self) -> Length {
         match self {
             Self::Long => Length::Long,
@@ -358,6 +497,14 @@ pub(crate) enum Era {
 }
 
 impl Era {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+    }
+
     pub(crate) fn to_length(self) -> Length {
         match self {
             Self::Long => Length::Long,
@@ -384,6 +531,15 @@ pub(crate) enum Year {
     Numeric,
 }
 
+impl Year {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::TwoDigit => "2-digit",
+            Self::Numeric => "numeric",
+        }
+    }
+}
+
 impl OptionType for Year {
     fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
         match value.to_string(context)?.to_std_string_escaped().as_ref() {
@@ -404,6 +560,16 @@ pub(crate) enum Month {
 }
 
 impl Month {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::TwoDigit => "2-digit",
+            Self::Numeric => "numeric",
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+    }
+
     pub(crate) fn to_length(self) -> Length {
         // NOTE (nekevss): after a brief glance, narrow does not appear to be
         // currently supported by ICU4X ... TBD
@@ -434,6 +600,15 @@ pub(crate) enum Day {
     Numeric,
 }
 
+impl Day {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::TwoDigit => "2-digit",
+            Self::Numeric => "numeric",
+        }
+    }
+}
+
 impl OptionType for Day {
     fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
         match value.to_string(context)?.to_std_string_escaped().as_ref() {
@@ -452,6 +627,14 @@ pub(crate) enum DayPeriod {
 }
 
 impl DayPeriod {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Narrow => "narrow",
+            Self::Short => "short",
+            Self::Long => "long",
+        }
+    }
+
     pub(crate) fn to_length(self) -> Length {
         match self {
             Self::Long => Length::Long,
@@ -478,6 +661,15 @@ pub(crate) enum Hour {
     Numeric,
 }
 
+impl Hour {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::TwoDigit => "2-digit",
+            Self::Numeric => "numeric",
+        }
+    }
+}
+
 impl OptionType for Hour {
     fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
         match value.to_string(context)?.to_std_string_escaped().as_ref() {
@@ -494,6 +686,15 @@ pub(crate) enum Minute {
     Numeric,
 }
 
+impl Minute {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::TwoDigit => "2-digit",
+            Self::Numeric => "numeric",
+        }
+    }
+}
+
 impl OptionType for Minute {
     fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
         match value.to_string(context)?.to_std_string_escaped().as_ref() {
@@ -510,6 +711,15 @@ pub(crate) enum Second {
     Numeric,
 }
 
+impl Second {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::TwoDigit => "2-digit",
+            Self::Numeric => "numeric",
+        }
+    }
+}
+
 impl OptionType for Second {
     fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
         match value.to_string(context)?.to_std_string_escaped().as_ref() {
@@ -536,6 +746,14 @@ impl SubsecondDigits {
             _ => unreachable!("subSecondDigits must be previously constrained."),
         }
     }
+
+    pub(crate) fn to_u8(self) -> u8 {
+        match self {
+            SubsecondDigits::S1 => 1,
+            SubsecondDigits::S2 => 2,
+            SubsecondDigits::S3 => 3,
+        }
+    }
 }
 
 impl From<SubsecondDigits> for IcuSubsecondDigits {
@@ -573,6 +791,17 @@ impl OptionType for TimeZoneName {
 }
 
 impl TimeZoneName {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Short => "short",
+            Self::Long => "long",
+            Self::ShortOffset => "shortOffset",
+            Self::LongOffset => "longOffset",
+            Self::ShortGeneric => "shortGeneric",
+            Self::LongGeneric => "longGeneric",
+        }
+    }
+
     fn to_zone_style(self) -> ZoneStyle {
         match self {
             TimeZoneName::LongGeneric => ZoneStyle::GenericLong,
@@ -598,31 +827,10 @@ impl ServicePreferences for DateTimeFormatterPreferences {
         });
 
         // Handle LDML unicode key "ca", Calendar algorithm
-        self.calendar_algorithm = self.calendar_algorithm.take().filter(|ca| match ca {
-            CalendarAlgorithm::Buddhist => has_calendar_data_for_locale::<Buddhist>(id, provider),
-            CalendarAlgorithm::Chinese => {
-                has_calendar_data_for_locale::<ChineseTraditional>(id, provider)
-            }
-            CalendarAlgorithm::Coptic => has_calendar_data_for_locale::<Coptic>(id, provider),
-            CalendarAlgorithm::Dangi => {
-                has_calendar_data_for_locale::<KoreanTraditional>(id, provider)
-            }
-            CalendarAlgorithm::Ethiopic => has_calendar_data_for_locale::<Ethiopian>(id, provider),
-            CalendarAlgorithm::Gregory => has_calendar_data_for_locale::<Gregorian>(id, provider),
-            CalendarAlgorithm::Hebrew => has_calendar_data_for_locale::<Hebrew>(id, provider),
-            CalendarAlgorithm::Indian => has_calendar_data_for_locale::<Indian>(id, provider),
-            CalendarAlgorithm::Japanese => has_calendar_data_for_locale::<Japanese>(id, provider),
-            CalendarAlgorithm::Persian => has_calendar_data_for_locale::<Persian>(id, provider),
-            CalendarAlgorithm::Roc => has_calendar_data_for_locale::<Roc>(id, provider),
-            CalendarAlgorithm::Hijri(Some(
-                HijriCalendarAlgorithm::Civil | HijriCalendarAlgorithm::Tbla,
-            )) => has_calendar_data_for_locale::<Hijri<hijri::TabularAlgorithm>>(id, provider),
-            CalendarAlgorithm::Hijri(Some(HijriCalendarAlgorithm::Umalqura)) => {
-                has_calendar_data_for_locale::<Hijri<hijri::UmmAlQura>>(id, provider)
-            }
-            CalendarAlgorithm::Hijri(Some(HijriCalendarAlgorithm::Rgsa) | None) => true,
-            _ => false,
-        });
+        self.calendar_algorithm = self
+            .calendar_algorithm
+            .take()
+            .filter(|ca| supports_calendar(*ca, id, provider));
 
         // NOTE (nekevss): issue: this will not support `H24` as ICU4X does
         // not currently support it.
@@ -636,6 +844,38 @@ impl ServicePreferences for DateTimeFormatterPreferences {
     impl_service_preferences!(numbering_system, calendar_algorithm, hour_cycle);
 }
 
+/// Checks if the provider has the data required to format dates in the calendar `ca` for
+/// the locale `id`.
+pub(in crate::builtins::intl) fn supports_calendar(
+    ca: CalendarAlgorithm,
+    id: &LanguageIdentifier,
+    provider: &IntlProvider,
+) -> bool {
+    match ca {
+        CalendarAlgorithm::Buddhist => has_calendar_data_for_locale::<Buddhist>(id, provider),
+        CalendarAlgorithm::Chinese => {
+            has_calendar_data_for_locale::<ChineseTraditional>(id, provider)
+        }
+        CalendarAlgorithm::Coptic => has_calendar_data_for_locale::<Coptic>(id, provider),
+        CalendarAlgorithm::Dangi => has_calendar_data_for_locale::<KoreanTraditional>(id, provider),
+        CalendarAlgorithm::Ethiopic => has_calendar_data_for_locale::<Ethiopian>(id, provider),
+        CalendarAlgorithm::Gregory => has_calendar_data_for_locale::<Gregorian>(id, provider),
+        CalendarAlgorithm::Hebrew => has_calendar_data_for_locale::<Hebrew>(id, provider),
+        CalendarAlgorithm::Indian => has_calendar_data_for_locale::<Indian>(id, provider),
+        CalendarAlgorithm::Japanese => has_calendar_data_for_locale::<Japanese>(id, provider),
+        CalendarAlgorithm::Persian => has_calendar_data_for_locale::<Persian>(id, provider),
+        CalendarAlgorithm::Roc => has_calendar_data_for_locale::<Roc>(id, provider),
+        CalendarAlgorithm::Hijri(Some(
+            HijriCalendarAlgorithm::Civil | HijriCalendarAlgorithm::Tbla,
+        )) => has_calendar_data_for_locale::<Hijri<hijri::TabularAlgorithm>>(id, provider),
+        CalendarAlgorithm::Hijri(Some(HijriCalendarAlgorithm::Umalqura)) => {
+            has_calendar_data_for_locale::<Hijri<hijri::UmmAlQura>>(id, provider)
+        }
+        CalendarAlgorithm::Hijri(Some(HijriCalendarAlgorithm::Rgsa) | None) => true,
+        _ => false,
+    }
+}
+
 fn has_calendar_data_for_locale<C: CldrCalendar>(
     id: &LanguageIdentifier,
     provider: &IntlProvider,
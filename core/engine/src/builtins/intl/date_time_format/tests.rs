@@ -1,6 +1,8 @@
 use indoc::indoc;
 
-use crate::{TestAction, run_test_actions};
+use boa_macros::js_str;
+
+use crate::{JsNativeErrorKind, TestAction, run_test_actions};
 
 // Intl.DateTimeFormat tests
 
@@ -48,7 +50,10 @@ fn dtf_basic() {
             });
             let result = formatter.format(date);
         "}),
-        TestAction::assert_eq("result === 'Sunday, 20 December 2020 at 03:23:16'", true),
+        TestAction::assert_eq(
+            "result === 'Sunday, 20 December 2020 at 03:23:16 UTC'",
+            true,
+        ),
     ]);
     run_test_actions([
         TestAction::run(indoc! {"
@@ -62,6 +67,154 @@ fn dtf_basic() {
             });
             let result = formatter.format(date);
         "}),
-        TestAction::assert_eq("result === 'Sunday, 20 December 2020 at 14:23:16'", true),
+        TestAction::assert_eq(
+            "result === 'Sunday, 20 December 2020 at 14:23:16 GMT+11'",
+            true,
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn dtf_hour_cycle() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            const date = new Date(Date.UTC(2020, 11, 20, 15, 23, 16));
+            const h12 = new Intl.DateTimeFormat('en-US', { hour: 'numeric', hour12: true });
+            const h23 = new Intl.DateTimeFormat('en-US', { hour: 'numeric', hour12: false });
+        "}),
+        TestAction::assert_eq("h12.resolvedOptions().hourCycle", js_str!("h12")),
+        TestAction::assert_eq("h12.resolvedOptions().hour12", true),
+        TestAction::assert_eq("h23.resolvedOptions().hourCycle", js_str!("h23")),
+        TestAction::assert_eq("h23.resolvedOptions().hour12", false),
+        TestAction::assert_eq("h12.format(date)", js_str!("3 PM")),
+        TestAction::assert_eq("h23.format(date)", js_str!("15")),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en-US', { hour: 'numeric', hourCycle: 'h23' }).resolvedOptions().hourCycle",
+            js_str!("h23"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en-US-u-hc-h23', { hour: 'numeric', hour12: true }).resolvedOptions().locale",
+            js_str!("en-US"),
+        ),
+        TestAction::assert(
+            "!('hourCycle' in new Intl.DateTimeFormat('en-US', { dateStyle: 'short' }).resolvedOptions())",
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn dtf_resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            const options = new Intl.DateTimeFormat('en-US', {
+                timeZone: 'Australia/Sydney',
+                year: 'numeric',
+                month: 'long',
+            }).resolvedOptions();
+            const styled = new Intl.DateTimeFormat('en-US', {
+                dateStyle: 'medium',
+                timeZone: '+0530',
+            }).resolvedOptions();
+        "}),
+        TestAction::assert_eq("options.timeZone", js_str!("Australia/Sydney")),
+        TestAction::assert_eq("options.calendar", js_str!("gregory")),
+        TestAction::assert_eq("options.year", js_str!("numeric")),
+        TestAction::assert_eq("options.month", js_str!("long")),
+        TestAction::assert("!('day' in options)"),
+        TestAction::assert_eq("styled.timeZone", js_str!("+05:30")),
+        TestAction::assert_eq("styled.dateStyle", js_str!("medium")),
+        TestAction::assert("!('year' in styled)"),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn dtf_format_to_parts() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            const date = new Date(Date.UTC(2020, 11, 20, 3, 23, 16, 738));
+            const parts = new Intl.DateTimeFormat('en-GB', {
+                hour: '2-digit',
+                minute: '2-digit',
+                second: '2-digit',
+                fractionalSecondDigits: 3,
+                timeZone: 'UTC',
+            }).formatToParts(date);
+            const types = parts.map(part => part.type).join();
+        "}),
+        TestAction::assert_eq(
+            "types",
+            js_str!("hour,literal,minute,literal,second,literal,fractionalSecond"),
+        ),
+        TestAction::assert_eq("parts[0].value", js_str!("03")),
+        TestAction::assert_eq("parts[6].value", js_str!("738")),
+        TestAction::assert_eq(
+            "parts.map(part => part.value).join('')",
+            js_str!("03:23:16.738"),
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn dtf_format_range() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            const dtf = new Intl.DateTimeFormat('en-US', { timeZone: 'UTC' });
+            const start = Date.UTC(2020, 11, 20);
+            const end = Date.UTC(2020, 11, 24);
+            const parts = dtf.formatRangeToParts(start, end);
+        "}),
+        TestAction::assert_eq("dtf.formatRange(start, start)", js_str!("12/20/20")),
+        TestAction::assert_eq(
+            "dtf.formatRange(start, end)",
+            js_str!("12/20/20\u{2009}–\u{2009}12/24/20"),
+        ),
+        TestAction::assert_eq("parts[0].source", js_str!("startRange")),
+        TestAction::assert_eq("parts[parts.length - 1].source", js_str!("endRange")),
+        TestAction::assert_eq(
+            "dtf.formatRangeToParts(start, start)[0].source",
+            js_str!("shared"),
+        ),
+        TestAction::assert_native_error(
+            "dtf.formatRange(start)",
+            JsNativeErrorKind::Type,
+            "`formatRange` requires both a start and an end date",
+        ),
+    ]);
+}
+
+#[cfg(all(feature = "intl_bundled", feature = "temporal"))]
+#[test]
+fn dtf_format_temporal() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            const date = Temporal.PlainDate.from('2020-12-20');
+            const time = Temporal.PlainTime.from('15:23:16');
+        "}),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en-GB', { dateStyle: 'long' }).format(date)",
+            js_str!("20 December 2020"),
+        ),
+        TestAction::assert_eq(
+            "time.toLocaleString('en-US', { hour: 'numeric', minute: '2-digit', hour12: false })",
+            js_str!("15:23"),
+        ),
+        TestAction::assert_eq(
+            "Temporal.ZonedDateTime.from('2020-12-20T03:23:16+11:00[Australia/Sydney]').toLocaleString('en-GB', { timeStyle: 'short' })",
+            js_str!("03:23"),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DateTimeFormat('en-GB', { timeStyle: 'short' }).format(date)",
+            JsNativeErrorKind::Type,
+            "the dateStyle or timeStyle of this DateTimeFormat cannot format the given value",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DateTimeFormat('en-GB').formatRange(date, time)",
+            JsNativeErrorKind::Type,
+            "the start and end of a date range must have the same type",
+        ),
     ]);
 }
@@ -0,0 +1,573 @@
+use boa_gc::{Finalize, Trace};
+use boa_icu_provider::provider::{CalendarDisplayNamesV1, DateTimeFieldDisplayNamesV1};
+use cow_utils::CowUtils;
+use icu_experimental::{
+    dimension::provider::currency::displayname::CurrencyDisplaynameV1,
+    displaynames::{
+        DisplayNamesOptions, DisplayNamesPreferences, Fallback, LanguageDisplay,
+        LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames, Style,
+        provider::RegionDisplayNamesV1,
+    },
+};
+use icu_locale::{
+    LanguageIdentifier, Locale,
+    extensions::unicode::Value,
+    subtags::{Region, Script},
+};
+use icu_provider::{
+    DataIdentifierBorrowed, DataLocale, DataMarkerAttributes, DataPayload, DataProvider,
+    DataRequest, DataRequestMetadata,
+};
+
+use crate::{
+    Context, JsArgs, JsData, JsExpect, JsNativeError, JsResult, JsString, JsValue,
+    builtins::{
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+        options::{get_option, get_options_object},
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{JsObject, internal_methods::get_prototype_from_constructor},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+};
+
+use super::{
+    Service,
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    number_format::Currency,
+    options::{EmptyPreferences, IntlOptions},
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
+
+/// The ICU4X formatter used to get the display names of a specific type of code.
+enum DisplayNamesFormatter {
+    Language(Box<LocaleDisplayNamesFormatter>),
+    Region(RegionDisplayNames),
+    Script(ScriptDisplayNames),
+    /// Currency names are loaded from the provider on every call to `of`.
+    Currency,
+    /// ICU4X has no data for calendar and date-time field names, so they are loaded from the
+    /// markers provided by `boa_icu_provider`.
+    ///
+    /// Providers other than Boa's default one don't have these markers. The payload is `None`
+    /// in that case, and `of` falls back to the code or `undefined` according to the `fallback`
+    /// option, like it does for codes without a display name.
+    Calendar(Option<DataPayload<CalendarDisplayNamesV1>>),
+    DateTimeField(Option<DataPayload<DateTimeFieldDisplayNamesV1>>),
+}
+
+impl std::fmt::Debug for DisplayNamesFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The ICU4X formatters don't implement `Debug`, so only the variant is printed.
+        let variant = match self {
+            Self::Language(_) => "Language",
+            Self::Region(_) => "Region",
+            Self::Script(_) => "Script",
+            Self::Currency => "Currency",
+            Self::Calendar(_) => "Calendar",
+            Self::DateTimeField(_) => "DateTimeField",
+        };
+        f.debug_tuple(variant).finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DisplayNames` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DisplayNames {
+    locale: Locale,
+    style: Style,
+    typ: DisplayNamesType,
+    fallback: Fallback,
+    language_display: Option<LanguageDisplay>,
+    native: DisplayNamesFormatter,
+}
+
+impl Service for DisplayNames {
+    type LangMarker = RegionDisplayNamesV1;
+
+    type Preferences = EmptyPreferences;
+}
+
+impl IntrinsicObject for DisplayNames {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DisplayNames"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::of, js_string!("of"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisplayNames {
+    const NAME: JsString = StaticJsStrings::DISPLAY_NAMES;
+}
+
+impl BuiltInConstructor for DisplayNames {
+    const CONSTRUCTOR_ARGUMENTS: usize = 2;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 3;
+    const CONSTRUCTOR_STORAGE_SLOTS: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::display_names;
+
+    /// Constructor [`Intl.DisplayNames ( locales, options )`][spec].
+    ///
+    /// Constructor for `DisplayNames` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DisplayNames` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. If options is undefined, throw a TypeError exception.
+        if options.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("`Intl.DisplayNames` requires an options object")
+                .into());
+        }
+
+        // 5. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 6. Let opt be a new Record.
+        // 7. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 8. Set opt.[[localeMatcher]] to matcher.
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 9. Let r be ResolveLocale(%Intl.DisplayNames%.[[AvailableLocales]], requestedLocales, opt, %Intl.DisplayNames%.[[RelevantExtensionKeys]], %Intl.DisplayNames%.[[LocaleData]]).
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut IntlOptions {
+                matcher,
+                ..Default::default()
+            },
+            context.intl_provider(),
+        )?;
+
+        // 10. Let style be ? GetOption(options, "style", string, « "narrow", "short", "long" », "long").
+        // 11. Set displayNames.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or(Style::Long);
+
+        // 12. Let type be ? GetOption(options, "type", string, « "language", "region", "script", "currency", "calendar", "dateTimeField" », undefined).
+        // 13. If type is undefined, throw a TypeError exception.
+        // 14. Set displayNames.[[Type]] to type.
+        let typ: DisplayNamesType =
+            get_option(&options, js_string!("type"), context)?.ok_or_else(|| {
+                JsNativeError::typ().with_message("`Intl.DisplayNames` requires a `type` option")
+            })?;
+
+        // 15. Let fallback be ? GetOption(options, "fallback", string, « "code", "none" », "code").
+        // 16. Set displayNames.[[Fallback]] to fallback.
+        let fallback =
+            get_option(&options, js_string!("fallback"), context)?.unwrap_or(Fallback::Code);
+
+        // 17. Set displayNames.[[Locale]] to r.[[Locale]].
+        // 18. Let resolvedLocaleData be r.[[LocaleData]].
+        // 19. Let types be resolvedLocaleData.[[types]].
+        // 20. Assert: types is a Record (see 12.2.3).
+        // 21. Let languageDisplay be ? GetOption(options, "languageDisplay", string, « "dialect", "standard" », "dialect").
+        let language_display = get_option(&options, js_string!("languageDisplay"), context)?
+            .unwrap_or(LanguageDisplay::Dialect);
+
+        // 22. Let typeFields be types.[[<type>]].
+        // 23. Assert: typeFields is a Record (see 12.2.3).
+        // 24. If type is "language", then
+        //     a. Set displayNames.[[LanguageDisplay]] to languageDisplay.
+        //     b. Let typeFields be typeFields.[[<languageDisplay>]].
+        //     c. Assert: typeFields is a Record (see 12.2.3).
+        // 25. Let styleFields be typeFields.[[<style>]].
+        // 26. Assert: styleFields is a Record (see 12.2.3).
+        // 27. Set displayNames.[[Fields]] to styleFields.
+        let language_display = (typ == DisplayNamesType::Language).then_some(language_display);
+
+        let prefs = DisplayNamesPreferences::from(&locale);
+        let mut icu_options = DisplayNamesOptions::default();
+        icu_options.style = Some(style);
+        icu_options.fallback = fallback;
+        if let Some(language_display) = language_display {
+            icu_options.language_display = language_display;
+        }
+
+        let data_locale = DataLocale::from(&locale.id);
+        let req = DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&data_locale),
+            metadata: DataRequestMetadata::default(),
+        };
+        let provider = context.intl_provider().erased_provider();
+        let native = match typ {
+            DisplayNamesType::Language => {
+                LocaleDisplayNamesFormatter::try_new_with_buffer_provider(
+                    provider,
+                    prefs,
+                    icu_options,
+                )
+                .map(|formatter| DisplayNamesFormatter::Language(Box::new(formatter)))
+            }
+            DisplayNamesType::Region => {
+                RegionDisplayNames::try_new_with_buffer_provider(provider, prefs, icu_options)
+                    .map(DisplayNamesFormatter::Region)
+            }
+            DisplayNamesType::Script => {
+                ScriptDisplayNames::try_new_with_buffer_provider(provider, prefs, icu_options)
+                    .map(DisplayNamesFormatter::Script)
+            }
+            DisplayNamesType::Currency => Ok(DisplayNamesFormatter::Currency),
+            DisplayNamesType::Calendar => Ok(DisplayNamesFormatter::Calendar(
+                DataProvider::<CalendarDisplayNamesV1>::load(context.intl_provider(), req)
+                    .ok()
+                    .map(|response| response.payload),
+            )),
+            DisplayNamesType::DateTimeField => Ok(DisplayNamesFormatter::DateTimeField(
+                DataProvider::<DateTimeFieldDisplayNamesV1>::load(context.intl_provider(), req)
+                    .ok()
+                    .map(|response| response.payload),
+            )),
+        }
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 2. Let displayNames be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.DisplayNames.prototype%", « [[InitializedDisplayNames]], [[Locale]], [[Style]], [[Type]], [[Fallback]], [[LanguageDisplay]], [[Fields]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::display_names,
+            context,
+        )?;
+        let display_names = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                style,
+                typ,
+                fallback,
+                language_display,
+                native,
+            },
+        );
+
+        // 28. Return displayNames.
+        Ok(display_names.into())
+    }
+}
+
+impl DisplayNames {
+    /// [`Intl.DisplayNames.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in display
+    /// names without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.DisplayNames%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<Self>(requested_locales, options, context).map(JsValue::from)
+    }
+
+    /// [`Intl.DisplayNames.prototype.of ( code )`][spec].
+    ///
+    /// Returns the localized display name of the provided `code`.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of
+    fn of(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let object = this.as_object();
+        let dn = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`of` can only be called on a `DisplayNames` object")
+            })?;
+
+        // 3. Let code be ? ToString(code).
+        let code = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        // 4. Set code to ? CanonicalCodeForDisplayNames(displayNames.[[Type]], code).
+        // 5. Let fields be displayNames.[[Fields]].
+        // 6. If fields has a field [[<code>]], return fields.[[<code>]].
+        let (code, name) = dn.name_of(&code, context.intl_provider())?;
+        if let Some(name) = name {
+            return Ok(js_string!(name).into());
+        }
+
+        // 7. If displayNames.[[Fallback]] is "code", return code.
+        if dn.fallback == Fallback::Code {
+            return Ok(js_string!(code).into());
+        }
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// [`Intl.DisplayNames.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during
+    /// the construction of the current `Intl.DisplayNames` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let object = this.as_object();
+        let dn = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`resolvedOptions` can only be called on a `DisplayNames` object")
+            })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 19, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of displayNames's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                js_string!("locale"),
+                js_string!(dn.locale.to_string()),
+                context,
+            )
+            .js_expect("operation must not fail per the spec")?;
+        options
+            .create_data_property_or_throw(
+                js_string!("style"),
+                match dn.style {
+                    Style::Long => js_string!("long"),
+                    Style::Short => js_string!("short"),
+                    Style::Narrow => js_string!("narrow"),
+                    _ => unreachable!(),
+                },
+                context,
+            )
+            .js_expect("operation must not fail per the spec")?;
+        options
+            .create_data_property_or_throw(js_string!("type"), dn.typ.to_js_string(), context)
+            .js_expect("operation must not fail per the spec")?;
+        options
+            .create_data_property_or_throw(
+                js_string!("fallback"),
+                match dn.fallback {
+                    Fallback::Code => js_string!("code"),
+                    Fallback::None => js_string!("none"),
+                    _ => unreachable!(),
+                },
+                context,
+            )
+            .js_expect("operation must not fail per the spec")?;
+        if let Some(language_display) = dn.language_display {
+            options
+                .create_data_property_or_throw(
+                    js_string!("languageDisplay"),
+                    match language_display {
+                        LanguageDisplay::Dialect => js_string!("dialect"),
+                        LanguageDisplay::Standard => js_string!("standard"),
+                        _ => unreachable!(),
+                    },
+                    context,
+                )
+                .js_expect("operation must not fail per the spec")?;
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+
+    /// Canonicalizes `code` and gets its display name, if the data for it is available.
+    ///
+    /// This performs the abstract operation [`CanonicalCodeForDisplayNames ( type, code )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+    fn name_of(&self, code: &str, provider: &IntlProvider) -> JsResult<(String, Option<String>)> {
+        let invalid_code = || {
+            JsNativeError::range().with_message(format!(
+                "provided code `{code}` is not a valid {} code",
+                self.typ.to_js_string().to_std_string_escaped()
+            ))
+        };
+
+        match (self.typ, &self.native) {
+            // 1. If type is "language", then
+            (DisplayNamesType::Language, DisplayNamesFormatter::Language(formatter)) => {
+                // a. If code cannot be matched by the unicode_language_id Unicode locale nonterminal, throw a RangeError exception.
+                // b. If IsStructurallyValidLanguageTag(code) is false, throw a RangeError exception.
+                if code.contains('_') {
+                    return Err(invalid_code().into());
+                }
+                let mut locale = LanguageIdentifier::try_from_str(code)
+                    .map(Locale::from)
+                    .map_err(|_| invalid_code())?;
+
+                // c. Return CanonicalizeUnicodeLocaleId(code).
+                provider.locale_canonicalizer()?.canonicalize(&mut locale);
+                let code = locale.to_string();
+
+                // The formatter returns the code itself if it doesn't have a name for it.
+                let name = formatter.of(&locale);
+                let name = (name != code).then(|| name.into_owned());
+                Ok((code, name))
+            }
+            // 2. If type is "region", then
+            (DisplayNamesType::Region, DisplayNamesFormatter::Region(formatter)) => {
+                // a. If code cannot be matched by the unicode_region_subtag Unicode locale nonterminal, throw a RangeError exception.
+                // b. Return the ASCII-uppercase of code.
+                let region = Region::try_from_str(code).map_err(|_| invalid_code())?;
+                let name = formatter.of(region).map(str::to_owned);
+                Ok((region.to_string(), name))
+            }
+            // 3. If type is "script", then
+            (DisplayNamesType::Script, DisplayNamesFormatter::Script(formatter)) => {
+                // a. If code cannot be matched by the unicode_script_subtag Unicode locale nonterminal, throw a RangeError exception.
+                // b. Assert: The length of code is 4, and every code unit of code represents an ASCII letter (0x0041 through 0x005A and 0x0061 through 0x007A, both inclusive).
+                // c. Let first be the ASCII-uppercase of the substring of code from 0 to 1.
+                // d. Let rest be the ASCII-lowercase of the substring of code from 1.
+                // e. Return the string-concatenation of first and rest.
+                let script = Script::try_from_str(code).map_err(|_| invalid_code())?;
+                let name = formatter.of(script).map(str::to_owned);
+                Ok((script.to_string(), name))
+            }
+            // 4. If type is "calendar", then
+            (DisplayNamesType::Calendar, DisplayNamesFormatter::Calendar(payload)) => {
+                // a. If code cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+                // b. If code uses any of the backwards compatibility syntax described in Unicode Technical Standard #35 LDML § 3.3 BCP 47 Conformance, throw a RangeError exception.
+                if code.contains('_') {
+                    return Err(invalid_code().into());
+                }
+                Value::try_from_str(code).map_err(|_| invalid_code())?;
+
+                // c. Return the ASCII-lowercase of code.
+                let code = code.cow_to_ascii_lowercase().into_owned();
+                let name = payload
+                    .as_ref()
+                    .and_then(|payload| payload.get().names.get(&code))
+                    .map(str::to_owned);
+                Ok((code, name))
+            }
+            // 5. If type is "dateTimeField", then
+            (DisplayNamesType::DateTimeField, DisplayNamesFormatter::DateTimeField(payload)) => {
+                // a. If the result of IsValidDateTimeFieldCode(code) is false, throw a RangeError exception.
+                if !DATE_TIME_FIELDS.contains(&code) {
+                    return Err(invalid_code().into());
+                }
+
+                let name = payload
+                    .as_ref()
+                    .and_then(|payload| {
+                        let names = payload.get();
+                        match self.style {
+                            Style::Short => names.short_names.get(code),
+                            Style::Narrow => names.narrow_names.get(code),
+                            _ => names.names.get(code),
+                        }
+                    })
+                    .map(str::to_owned);
+
+                // b. Return code.
+                Ok((code.to_owned(), name))
+            }
+            // 6. Assert: type is "currency".
+            (DisplayNamesType::Currency, _) => {
+                // 7. If IsWellFormedCurrencyCode(code) is false, throw a RangeError exception.
+                let currency = code.parse::<Currency>().map_err(|_| invalid_code())?;
+
+                let name = DataMarkerAttributes::try_from_str(currency.as_str())
+                    .ok()
+                    .and_then(|attributes| {
+                        let locale = DataLocale::from(&self.locale.id);
+                        let req = DataRequest {
+                            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                                attributes, &locale,
+                            ),
+                            metadata: {
+                                let mut metadata = DataRequestMetadata::default();
+                                metadata.silent = true;
+                                metadata
+                            },
+                        };
+                        DataProvider::<CurrencyDisplaynameV1>::load(provider, req).ok()
+                    })
+                    .map(|response| response.payload.get().display_name.to_string());
+
+                // 8. Return the ASCII-uppercase of code.
+                Ok((currency.as_str().to_owned(), name))
+            }
+            _ => unreachable!("the formatter must always match the display names type"),
+        }
+    }
+}
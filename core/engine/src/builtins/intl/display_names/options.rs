@@ -0,0 +1,117 @@
+use std::str::FromStr;
+
+use icu_experimental::displaynames::{Fallback, LanguageDisplay, Style};
+
+use crate::{
+    Context, JsNativeError, JsResult, JsString, JsValue,
+    builtins::options::{OptionType, ParsableOptionType},
+    js_string,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisplayNamesType {
+    Language,
+    Region,
+    Script,
+    Currency,
+    Calendar,
+    DateTimeField,
+}
+
+impl DisplayNamesType {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Language => js_string!("language"),
+            Self::Region => js_string!("region"),
+            Self::Script => js_string!("script"),
+            Self::Currency => js_string!("currency"),
+            Self::Calendar => js_string!("calendar"),
+            Self::DateTimeField => js_string!("dateTimeField"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisplayNamesTypeError;
+
+impl std::fmt::Display for ParseDisplayNamesTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "provided string was not `language`, `region`, `script`, `currency`, `calendar` or `dateTimeField`",
+        )
+    }
+}
+
+impl FromStr for DisplayNamesType {
+    type Err = ParseDisplayNamesTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "language" => Ok(Self::Language),
+            "region" => Ok(Self::Region),
+            "script" => Ok(Self::Script),
+            "currency" => Ok(Self::Currency),
+            "calendar" => Ok(Self::Calendar),
+            "dateTimeField" => Ok(Self::DateTimeField),
+            _ => Err(ParseDisplayNamesTypeError),
+        }
+    }
+}
+
+impl ParsableOptionType for DisplayNamesType {}
+
+impl OptionType for Style {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `long`, `short` or `narrow`")
+                .into()),
+        }
+    }
+}
+
+impl OptionType for Fallback {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "code" => Ok(Self::Code),
+            "none" => Ok(Self::None),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `code` or `none`")
+                .into()),
+        }
+    }
+}
+
+impl OptionType for LanguageDisplay {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "dialect" => Ok(Self::Dialect),
+            "standard" => Ok(Self::Standard),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `dialect` or `standard`")
+                .into()),
+        }
+    }
+}
+
+/// The date-time fields accepted by [`CanonicalCodeForDisplayNames`][spec] when the type
+/// is `"dateTimeField"`.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+pub(crate) const DATE_TIME_FIELDS: [&str; 12] = [
+    "era",
+    "year",
+    "quarter",
+    "month",
+    "weekOfYear",
+    "weekday",
+    "day",
+    "dayPeriod",
+    "hour",
+    "minute",
+    "second",
+    "timeZoneName",
+];
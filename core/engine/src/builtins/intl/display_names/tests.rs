@@ -0,0 +1,187 @@
+use boa_macros::js_str;
+use icu_provider::prelude::*;
+use indoc::indoc;
+
+use crate::{Context, JsNativeErrorKind, TestAction, run_test_actions, run_test_actions_with};
+
+/// A provider without the markers of `boa_icu_provider`, like any provider generated with ICU4X.
+struct WithoutBoaMarkers<P>(P);
+
+impl<P: DynamicDataProvider<BufferMarker>> DynamicDataProvider<BufferMarker>
+    for WithoutBoaMarkers<P>
+{
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest<'_>,
+    ) -> Result<DataResponse<BufferMarker>, DataError> {
+        if boa_icu_provider::provider::MARKERS.contains(&marker) {
+            return Err(DataErrorKind::MarkerNotFound.with_marker(marker));
+        }
+        self.0.load_data(marker, req)
+    }
+}
+
+impl<P: DynamicDryDataProvider<BufferMarker>> DynamicDryDataProvider<BufferMarker>
+    for WithoutBoaMarkers<P>
+{
+    fn dry_load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest<'_>,
+    ) -> Result<DataResponseMetadata, DataError> {
+        if boa_icu_provider::provider::MARKERS.contains(&marker) {
+            return Err(DataErrorKind::MarkerNotFound.with_marker(marker));
+        }
+        self.0.dry_load_data(marker, req)
+    }
+}
+
+#[test]
+fn of() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'region' }).of('de')",
+            js_str!("Germany"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'language' }).of('fr')",
+            js_str!("French"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'script' }).of('latn')",
+            js_str!("Latin"),
+        ),
+    ]);
+}
+
+#[test]
+fn calendar() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'calendar' }).of('gregory')",
+            js_str!("Gregorian Calendar"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'calendar' }).of('ISO8601')",
+            js_str!("Gregorian Calendar (ISO 8601 Weeks)"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'calendar' }).of('unknown')",
+            js_str!("unknown"),
+        ),
+    ]);
+}
+
+#[test]
+fn date_time_field() {
+    run_test_actions([
+        TestAction::run("const dn = new Intl.DisplayNames('en', { type: 'dateTimeField' });"),
+        TestAction::assert_eq("dn.of('weekOfYear')", js_str!("week")),
+        TestAction::assert_eq("dn.of('dayPeriod')", js_str!("AM/PM")),
+        TestAction::assert_eq("dn.of('timeZoneName')", js_str!("time zone")),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'dateTimeField', style: 'short' }).of('month')",
+            js_str!("mo."),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'dateTimeField', style: 'narrow' }).of('weekOfYear')",
+            js_str!("wk"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('ja', { type: 'dateTimeField' }).of('weekOfYear')",
+            js_str!("週"),
+        ),
+        TestAction::assert_native_error(
+            "dn.of('week')",
+            JsNativeErrorKind::Range,
+            "provided code `week` is not a valid dateTimeField code",
+        ),
+    ]);
+}
+
+#[test]
+fn provider_without_boa_markers() {
+    let context = &mut Context::builder()
+        .icu_buffer_provider(WithoutBoaMarkers(boa_icu_provider::buffer()))
+        .unwrap()
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq(
+                "new Intl.DisplayNames('en', { type: 'calendar' }).of('gregory')",
+                js_str!("gregory"),
+            ),
+            TestAction::assert(
+                "new Intl.DisplayNames('en', { type: 'calendar', fallback: 'none' }).of('gregory') === undefined",
+            ),
+            TestAction::assert_eq(
+                "new Intl.DisplayNames('en', { type: 'dateTimeField' }).of('weekOfYear')",
+                js_str!("weekOfYear"),
+            ),
+            TestAction::assert(
+                "new Intl.DisplayNames('en', { type: 'dateTimeField', fallback: 'none' }).of('weekOfYear') === undefined",
+            ),
+            TestAction::assert_native_error(
+                "new Intl.DisplayNames('en', { type: 'dateTimeField' }).of('week')",
+                JsNativeErrorKind::Range,
+                "provided code `week` is not a valid dateTimeField code",
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn fallback() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'region' }).of('XZ')",
+            js_str!("XZ"),
+        ),
+        TestAction::assert(
+            "new Intl.DisplayNames('en', { type: 'region', fallback: 'none' }).of('XZ') === undefined",
+        ),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            const options = new Intl.DisplayNames('en', { type: 'language', style: 'short' })
+                .resolvedOptions();
+        "}),
+        TestAction::assert_eq("options.locale", js_str!("en")),
+        TestAction::assert_eq("options.style", js_str!("short")),
+        TestAction::assert_eq("options.type", js_str!("language")),
+        TestAction::assert_eq("options.fallback", js_str!("code")),
+        TestAction::assert_eq("options.languageDisplay", js_str!("dialect")),
+        TestAction::assert(
+            "!('languageDisplay' in new Intl.DisplayNames('en', { type: 'region' }).resolvedOptions())",
+        ),
+    ]);
+}
+
+#[test]
+fn invalid_arguments() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en')",
+            JsNativeErrorKind::Type,
+            "`Intl.DisplayNames` requires an options object",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', {})",
+            JsNativeErrorKind::Type,
+            "`Intl.DisplayNames` requires a `type` option",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', { type: 'region' }).of('12345')",
+            JsNativeErrorKind::Range,
+            "provided code `12345` is not a valid region code",
+        ),
+    ]);
+}
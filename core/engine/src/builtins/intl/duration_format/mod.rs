@@ -0,0 +1,1353 @@
+use std::fmt::Write;
+
+use boa_gc::{Finalize, Trace};
+use fixed_decimal::{Decimal, Sign, SignDisplay};
+use icu_decimal::{
+    DecimalFormatter, DecimalFormatterPreferences,
+    options::{DecimalFormatterOptions, GroupingStrategy},
+    preferences::NumberingSystem,
+    provider::DecimalSymbolsV1,
+};
+use icu_experimental::dimension::units::{
+    formatter::{UnitsFormatter, UnitsFormatterPreferences},
+    options::{UnitsFormatterOptions, Width},
+};
+use icu_list::{
+    ListFormatter, ListFormatterPreferences,
+    options::{ListFormatterOptions, ListLength},
+};
+use icu_locale::{Locale, extensions::unicode::Value};
+use icu_provider::{
+    DataIdentifierBorrowed, DataLocale, DataProvider, DataRequest, DataRequestMetadata,
+};
+use writeable::{PartsWrite, Writeable};
+
+use crate::{
+    Context, JsArgs, JsData, JsExpect, JsNativeError, JsResult, JsString, JsValue,
+    builtins::{
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+        options::{get_option, get_options_object},
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_error, js_string,
+    object::{JsObject, internal_methods::get_prototype_from_constructor},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+};
+
+use super::{
+    Service,
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    options::{IntlOptions, get_number_option},
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
+
+/// The resolved style and display of a unit of a `DurationFormat`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DurationUnitOptions {
+    style: UnitStyle,
+    display: Display,
+}
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DurationFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DurationFormat {
+    locale: Locale,
+    numbering_system: NumberingSystem,
+    style: BaseStyle,
+    units: [DurationUnitOptions; 10],
+    fractional_digits: Option<u8>,
+}
+
+impl Service for DurationFormat {
+    type LangMarker = DecimalSymbolsV1;
+
+    type Preferences = DecimalFormatterPreferences;
+}
+
+impl IntrinsicObject for DurationFormat {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DurationFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 1)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DurationFormat {
+    const NAME: JsString = StaticJsStrings::DURATION_FORMAT;
+}
+
+impl BuiltInConstructor for DurationFormat {
+    const CONSTRUCTOR_ARGUMENTS: usize = 0;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 4;
+    const CONSTRUCTOR_STORAGE_SLOTS: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::duration_format;
+
+    /// Constructor [`Intl.DurationFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `DurationFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/DurationFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DurationFormat` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 2. Let durationFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.DurationFormatPrototype%", « [[InitializedDurationFormat]], [[Locale]], [[DataLocale]], [[NumberingSystem]], [[Style]], [[YearsOptions]], [[MonthsOptions]], [[WeeksOptions]], [[DaysOptions]], [[HoursOptions]], [[MinutesOptions]], [[SecondsOptions]], [[MillisecondsOptions]], [[MicrosecondsOptions]], [[NanosecondsOptions]], [[HourMinuteSeparator]], [[MinuteSecondSeparator]], [[FractionalDigits]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::duration_format,
+            context,
+        )?;
+
+        let duration_format = Self::new(locales, options, context)?;
+
+        let duration_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            duration_format,
+        );
+
+        // 27. Return durationFormat.
+        Ok(duration_format.into())
+    }
+}
+
+impl DurationFormat {
+    /// Creates a new instance of `DurationFormat`.
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Let options be ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        let numbering_system =
+            get_option::<NumberingSystem>(&options, js_string!("numberingSystem"), context)?;
+
+        // 8. Let opt be the Record { [[localeMatcher]]: matcher, [[nu]]: numberingSystem }.
+        let mut intl_options = IntlOptions {
+            matcher,
+            preferences: {
+                let mut prefs = DecimalFormatterPreferences::default();
+                prefs.numbering_system = numbering_system;
+                prefs
+            },
+        };
+
+        // 9. Let r be ResolveLocale(%Intl.DurationFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.DurationFormat%.[[RelevantExtensionKeys]], %Intl.DurationFormat%.[[LocaleData]]).
+        // 10. Let locale be r.[[Locale]].
+        // 11. Set durationFormat.[[Locale]] to locale.
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // 12. Set durationFormat.[[NumberingSystem]] to r.[[nu]].
+        let numbering_system = match intl_options.preferences.numbering_system {
+            Some(nu) => nu,
+            None => default_numbering_system(&locale, context.intl_provider())?,
+        };
+
+        // 13. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow", "digital" », "short").
+        // 14. Set durationFormat.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 15. Let prevStyle be the empty String.
+        let mut prev_style = None;
+
+        // 16. For each row of Table 20, except the header row, in table order, do
+        let mut units = [DurationUnitOptions {
+            style: UnitStyle::Short,
+            display: Display::Auto,
+        }; 10];
+        for unit in DurationUnit::ALL {
+            // a. Let styleSlot be the Style Slot value of the current row.
+            // b. Let displaySlot be the Display Slot value of the current row.
+            // c. Let unit be the Unit value of the current row.
+            // d. Let valueList be the Values value of the current row.
+            // e. Let digitalBase be the Digital Default value of the current row.
+            // f. Let unitOptions be ? GetDurationUnitOptions(unit, options, style, valueList, digitalBase, prevStyle, digitalFormat.[[TwoDigitHours]]).
+            let unit_options =
+                get_duration_unit_options(unit, &options, style, prev_style, context)?;
+
+            // g. Set the value of durationFormat's internal slot whose name is styleSlot to unitOptions.[[Style]].
+            // h. Set the value of durationFormat's internal slot whose name is displaySlot to unitOptions.[[Display]].
+            units[unit as usize] = unit_options;
+
+            // i. If unit is one of "hours", "minutes", "seconds", "milliseconds", or "microseconds", then
+            if matches!(
+                unit,
+                DurationUnit::Hours
+                    | DurationUnit::Minutes
+                    | DurationUnit::Seconds
+                    | DurationUnit::Milliseconds
+                    | DurationUnit::Microseconds
+            ) {
+                // i. Set prevStyle to unitOptions.[[Style]].
+                prev_style = Some(unit_options.style);
+            }
+        }
+
+        // 17. Set durationFormat.[[FractionalDigits]] to ? GetNumberOption(options, "fractionalDigits", 0, 9, undefined).
+        let fractional_digits =
+            get_number_option(&options, js_string!("fractionalDigits"), 0, 9, context)?;
+
+        Ok(Self {
+            locale,
+            numbering_system,
+            style,
+            units,
+            fractional_digits,
+        })
+    }
+
+    /// [`Intl.DurationFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in duration
+    /// formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.DurationFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<Self>(requested_locales, options, context).map(JsValue::from)
+    }
+
+    /// [`Intl.DurationFormat.prototype.format ( duration )`][spec].
+    ///
+    /// Returns a language-specific formatted string representing the provided duration.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let object = this.as_object();
+        let df = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`format` can only be called on a `DurationFormat` object")
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = DurationRecord::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        // 5. Let result be the empty String.
+        // 6. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 7. Return result.
+        df.format_to_string(&record, context.intl_provider())
+            .map(|s| js_string!(s).into())
+    }
+
+    /// [`Intl.DurationFormat.prototype.formatToParts ( duration )`][spec].
+    ///
+    /// Returns an array of objects representing the formatted duration in parts that can be
+    /// used for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let object = this.as_object();
+        let df = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`formatToParts` can only be called on a `DurationFormat` object")
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = DurationRecord::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be PartitionDurationFormatPattern(df, record).
+        let parts = df.partition_duration_format_pattern(&record, context.intl_provider())?;
+
+        // 5. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .js_expect("creating an empty array with default proto must not fail")?;
+
+        // 6. Let n be 0.
+        // 7. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, part) in parts.into_iter().enumerate() {
+            // a. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+            let obj = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, "type", part.[[Type]]).
+            obj.create_data_property_or_throw(js_string!("type"), js_string!(part.typ), context)
+                .js_expect("operation must not fail per the spec")?;
+
+            // c. Perform ! CreateDataPropertyOrThrow(obj, "value", part.[[Value]]).
+            obj.create_data_property_or_throw(js_string!("value"), js_string!(part.value), context)
+                .js_expect("operation must not fail per the spec")?;
+
+            // d. If part.[[Unit]] is not empty, perform ! CreateDataPropertyOrThrow(obj, "unit", part.[[Unit]]).
+            if let Some(unit) = part.unit {
+                obj.create_data_property_or_throw(js_string!("unit"), js_string!(unit), context)
+                    .js_expect("operation must not fail per the spec")?;
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), obj).
+            result
+                .create_data_property_or_throw(n, obj, context)
+                .js_expect("operation must not fail per the spec")?;
+
+            // f. Set n to n + 1.
+        }
+
+        // 8. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.DurationFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and formatting options
+    /// computed during the construction of the current `Intl.DurationFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be the this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let object = this.as_object();
+        let df = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`resolvedOptions` can only be called on a `DurationFormat` object",
+                )
+            })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 21, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of df's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. If there is a Conversion value in the current row, let conversion be that value; else let conversion be empty.
+        //         ii. If conversion is number, then
+        //             1. Set v to 𝔽(v).
+        //         iii. Else if conversion is not empty, then
+        //             1. Assert: conversion is style+display and v is a Duration Unit Options Record.
+        //             2. NOTE: v.[[Style]] will be represented with a property named p (a plural Temporal unit), then v.[[Display]] will be represented with a property whose name suffixes p with "Display".
+        //             3. Let style be v.[[Style]].
+        //             4. If style is "fractional", then
+        //                 a. Assert: IsFractionalSecondUnitName(p) is true.
+        //                 b. Set style to "numeric".
+        //             5. Perform ! CreateDataPropertyOrThrow(options, p, style).
+        //             6. Set p to the string-concatenation of p and "Display".
+        //             7. Set v to v.[[Display]].
+        //         iv. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                js_string!("locale"),
+                js_string!(df.locale.to_string()),
+                context,
+            )
+            .js_expect("operation must not fail per the spec")?;
+        options
+            .create_data_property_or_throw(
+                js_string!("numberingSystem"),
+                js_string!(df.numbering_system.as_str()),
+                context,
+            )
+            .js_expect("operation must not fail per the spec")?;
+        options
+            .create_data_property_or_throw(js_string!("style"), df.style.to_js_string(), context)
+            .js_expect("operation must not fail per the spec")?;
+
+        for unit in DurationUnit::ALL {
+            let DurationUnitOptions { style, display } = df.units[unit as usize];
+            let style = if style == UnitStyle::Fractional {
+                UnitStyle::Numeric
+            } else {
+                style
+            };
+            options
+                .create_data_property_or_throw(
+                    js_string!(unit.name()),
+                    style.to_js_string(),
+                    context,
+                )
+                .js_expect("operation must not fail per the spec")?;
+            options
+                .create_data_property_or_throw(
+                    js_string!(unit.display_field()),
+                    display.to_js_string(),
+                    context,
+                )
+                .js_expect("operation must not fail per the spec")?;
+        }
+
+        if let Some(fractional_digits) = df.fractional_digits {
+            options
+                .create_data_property_or_throw(
+                    js_string!("fractionalDigits"),
+                    fractional_digits,
+                    context,
+                )
+                .js_expect("operation must not fail per the spec")?;
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+
+    /// Formats `duration` into a string, concatenating the values of all the parts returned by
+    /// [`PartitionDurationFormatPattern`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondurationformatpattern
+    pub(crate) fn format_to_string(
+        &self,
+        duration: &DurationRecord,
+        provider: &IntlProvider,
+    ) -> JsResult<String> {
+        Ok(self
+            .partition_duration_format_pattern(duration, provider)?
+            .into_iter()
+            .map(|part| part.value)
+            .collect())
+    }
+
+    /// Abstract operation [`PartitionDurationFormatPattern ( durationFormat, duration )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondurationformatpattern
+    fn partition_duration_format_pattern(
+        &self,
+        duration: &DurationRecord,
+        provider: &IntlProvider,
+    ) -> JsResult<Vec<DurationPart>> {
+        // 1. Let result be a new empty List.
+        let mut result = Vec::new();
+
+        // 2. Let signDisplayed be true.
+        let mut sign_displayed = true;
+
+        // 3. Let numericUnitFound be false.
+        // 4. While numericUnitFound is false, repeat for each row in Table 20 in table order, except the header row:
+        for unit in DurationUnit::ALL {
+            // a. Let value be the value of duration's field whose name is the Value Field value of the current row.
+            // b. Let style be the value of durationFormat's internal slot whose name is the Style Slot value of the current row.
+            // c. Let display be the value of durationFormat's internal slot whose name is the Display Slot value of the current row.
+            // d. Let unit be the Unit value of the current row.
+            let DurationUnitOptions { style, display } = self.units[unit as usize];
+
+            // e. If style is "numeric" or "2-digit", then
+            if matches!(style, UnitStyle::Numeric | UnitStyle::TwoDigit) {
+                // i. Let numericPartsList be FormatNumericUnits(durationFormat, duration, unit, signDisplayed).
+                let numeric_parts =
+                    self.format_numeric_units(duration, unit, sign_displayed, provider)?;
+
+                // ii. If numericPartsList is not empty, append numericPartsList to result.
+                if !numeric_parts.is_empty() {
+                    result.push(numeric_parts);
+                }
+
+                // iii. Set numericUnitFound to true.
+                break;
+            }
+
+            // f. Else,
+            //     i. Let nfOpts be OrdinaryObjectCreate(null).
+            //     ii. If unit is "seconds", "milliseconds", or "microseconds", then
+            //         1. If unit is "seconds", then
+            //             a. Let nextStyle be durationFormat.[[MillisecondsStyle]].
+            //         2. Else if unit is "milliseconds", then
+            //             a. Let nextStyle be durationFormat.[[MicrosecondsStyle]].
+            //         3. Else,
+            //             a. Let nextStyle be durationFormat.[[NanosecondsStyle]].
+            //         4. If nextStyle is "fractional", then
+            //             a. Set value to value + AddFractionalDigits(durationFormat, duration).
+            //             b. If durationFormat.[[FractionalDigits]] is undefined, then
+            //                 i. Let maximumFractionDigits be 9𝔽.
+            //                 ii. Let minimumFractionDigits be +0𝔽.
+            //             c. Else,
+            //                 i. Let maximumFractionDigits be durationFormat.[[FractionalDigits]].
+            //                 ii. Let minimumFractionDigits be durationFormat.[[FractionalDigits]].
+            //             d. Perform ! CreateDataPropertyOrThrow(nfOpts, "maximumFractionDigits", maximumFractionDigits).
+            //             e. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumFractionDigits", minimumFractionDigits).
+            //             f. Perform ! CreateDataPropertyOrThrow(nfOpts, "roundingMode", "trunc").
+            //             g. Set numericUnitFound to true.
+            let numeric_unit_found = matches!(
+                unit,
+                DurationUnit::Seconds | DurationUnit::Milliseconds | DurationUnit::Microseconds
+            ) && self.units[unit as usize + 1].style
+                == UnitStyle::Fractional;
+
+            let (mut value, is_zero) = if numeric_unit_found {
+                let (mut value, is_zero) = duration.fractional_value(unit);
+                self.round_fraction(&mut value);
+                (value, is_zero)
+            } else {
+                let value = duration.get(unit);
+                (Decimal::from(value), value == 0)
+            };
+
+            // iii. If display is "always" or value is not 0, then
+            if display == Display::Always || !is_zero {
+                // 1. Perform ! CreateDataPropertyOrThrow(nfOpts, "numberingSystem", durationFormat.[[NumberingSystem]]).
+                // 2. If signDisplayed is true, then
+                if sign_displayed {
+                    // a. Set signDisplayed to false.
+                    sign_displayed = false;
+
+                    // b. If value is 0 and DurationSign(duration) is -1, then
+                    if is_zero && duration.sign() == -1 {
+                        // i. Set value to negative-zero.
+                        value.sign = Sign::Negative;
+                    }
+                } else {
+                    // 3. Else,
+                    //     a. Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+                    value.apply_sign_display(SignDisplay::Never);
+                }
+
+                // 4. Let numberFormatUnit be the NumberFormat Unit value of the current row.
+                // 5. Perform ! CreateDataPropertyOrThrow(nfOpts, "style", "unit").
+                // 6. Perform ! CreateDataPropertyOrThrow(nfOpts, "unit", numberFormatUnit).
+                // 7. Perform ! CreateDataPropertyOrThrow(nfOpts, "unitDisplay", style).
+                // 8. Let nf be ! Construct(%Intl.NumberFormat%, « durationFormat.[[Locale]], nfOpts »).
+                let nf = self.units_formatter(unit, style, provider)?;
+
+                // 9. Let parts be PartitionNumberPattern(nf, value).
+                // 10. Let list be a new empty List.
+                // 11. For each Record { [[Type]], [[Value]] } part of parts, do
+                //     a. Append the Record { [[Type]]: part.[[Type]], [[Value]]: part.[[Value]], [[Unit]]: numberFormatUnit } to list.
+                // 12. Append list to result.
+                let parts = collect_parts(&nf.format_fixed_decimal(&value))?;
+                result.push(unit_parts(parts, unit));
+            }
+
+            if numeric_unit_found {
+                break;
+            }
+        }
+
+        // 5. Return ListFormatParts(durationFormat, result).
+        self.list_format_parts(result, provider)
+    }
+
+    /// Abstract operation [`FormatNumericUnits ( durationFormat, duration, firstNumericUnit, signDisplayed )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumericunits
+    fn format_numeric_units(
+        &self,
+        duration: &DurationRecord,
+        first_numeric_unit: DurationUnit,
+        mut sign_displayed: bool,
+        provider: &IntlProvider,
+    ) -> JsResult<Vec<DurationPart>> {
+        // 1. Assert: firstNumericUnit is "hours", "minutes", or "seconds".
+        debug_assert!(matches!(
+            first_numeric_unit,
+            DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds
+        ));
+
+        // 2. Let numericPartsList be a new empty List.
+        let mut numeric_parts = Vec::new();
+
+        // 3. Let hoursValue be duration.[[Hours]].
+        // 4. Let hoursDisplay be durationFormat.[[HoursDisplay]].
+        let hours_value = duration.get(DurationUnit::Hours);
+        let hours_display = self.units[DurationUnit::Hours as usize].display;
+
+        // 5. Let minutesValue be duration.[[Minutes]].
+        // 6. Let minutesDisplay be durationFormat.[[MinutesDisplay]].
+        let minutes_value = duration.get(DurationUnit::Minutes);
+        let minutes_display = self.units[DurationUnit::Minutes as usize].display;
+
+        // 7. Let secondsValue be duration.[[Seconds]].
+        // 8. If duration.[[Milliseconds]] is not 0 or duration.[[Microseconds]] is not 0 or duration.[[Nanoseconds]] is not 0, then
+        //     a. Set secondsValue to secondsValue + AddFractionalDigits(durationFormat, duration).
+        let (seconds_value, seconds_is_zero) =
+            if self.units[DurationUnit::Milliseconds as usize].style == UnitStyle::Fractional {
+                duration.fractional_value(DurationUnit::Seconds)
+            } else {
+                let value = duration.get(DurationUnit::Seconds);
+                (Decimal::from(value), value == 0)
+            };
+
+        // 9. Let secondsDisplay be durationFormat.[[SecondsDisplay]].
+        let seconds_display = self.units[DurationUnit::Seconds as usize].display;
+
+        // 10. Let hoursFormatted be false.
+        // 11. If firstNumericUnit is "hours", then
+        //     a. If hoursValue is not 0 or hoursDisplay is "always", then
+        //         i. Set hoursFormatted to true.
+        let hours_formatted = first_numeric_unit == DurationUnit::Hours
+            && (hours_value != 0 || hours_display == Display::Always);
+
+        // 12. If secondsValue is not 0 or secondsDisplay is "always", then
+        //     a. Let secondsFormatted be true.
+        // 13. Else,
+        //     a. Let secondsFormatted be false.
+        let seconds_formatted = !seconds_is_zero || seconds_display == Display::Always;
+
+        // 14. Let minutesFormatted be false.
+        // 15. If firstNumericUnit is "hours" or firstNumericUnit is "minutes", then
+        //     a. If hoursFormatted is true and secondsFormatted is true, then
+        //         i. Set minutesFormatted to true.
+        //     b. Else if minutesValue is not 0 or minutesDisplay is "always", then
+        //         i. Set minutesFormatted to true.
+        let minutes_formatted = matches!(
+            first_numeric_unit,
+            DurationUnit::Hours | DurationUnit::Minutes
+        ) && ((hours_formatted && seconds_formatted)
+            || minutes_value != 0
+            || minutes_display == Display::Always);
+
+        let formatter = self.digital_formatter(provider)?;
+        // TODO: use the separator of the locale data when ICU4X exposes it.
+        let separator = ":";
+
+        let mut format_numeric = |unit: DurationUnit, mut value: Decimal, is_zero: bool| {
+            // If signDisplayed is true, then
+            if sign_displayed {
+                // If value is 0 and DurationSign(duration) is -1, then
+                //     Set value to negative-zero.
+                if is_zero && duration.sign() == -1 {
+                    value.sign = Sign::Negative;
+                }
+            } else {
+                // Else,
+                //     Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+                value.apply_sign_display(SignDisplay::Never);
+            }
+            sign_displayed = false;
+
+            // If style is "2-digit", then
+            //     Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumIntegerDigits", 2𝔽).
+            if self.units[unit as usize].style == UnitStyle::TwoDigit {
+                value.pad_start(2);
+            }
+
+            collect_parts(&formatter.format(&value)).map(|parts| unit_parts(parts, unit))
+        };
+
+        // 16. If hoursFormatted is true, then
+        if hours_formatted {
+            // a. If signDisplayed is true, then
+            //     i. If hoursValue is 0 and DurationSign(duration) is -1, then
+            //         1. Set hoursValue to negative-zero.
+            // b. Let hoursParts be FormatNumericHours(durationFormat, hoursValue, signDisplayed).
+            // c. Set numericPartsList to the list-concatenation of numericPartsList and hoursParts.
+            // d. Set signDisplayed to false.
+            numeric_parts.extend(format_numeric(
+                DurationUnit::Hours,
+                Decimal::from(hours_value),
+                hours_value == 0,
+            )?);
+        }
+
+        // 17. If minutesFormatted is true, then
+        if minutes_formatted {
+            // a. If signDisplayed is true, then
+            //     i. If minutesValue is 0 and DurationSign(duration) is -1, then
+            //         1. Set minutesValue to negative-zero.
+            // b. Let minutesParts be FormatNumericMinutes(durationFormat, minutesValue, hoursFormatted, signDisplayed).
+            if hours_formatted {
+                numeric_parts.push(DurationPart::literal(separator));
+            }
+            numeric_parts.extend(format_numeric(
+                DurationUnit::Minutes,
+                Decimal::from(minutes_value),
+                minutes_value == 0,
+            )?);
+            // c. Set numericPartsList to the list-concatenation of numericPartsList and minutesParts.
+            // d. Set signDisplayed to false.
+        }
+
+        // 18. If secondsFormatted is true, then
+        if seconds_formatted {
+            // a. Let secondsParts be FormatNumericSeconds(durationFormat, secondsValue, minutesFormatted, signDisplayed).
+            if minutes_formatted {
+                numeric_parts.push(DurationPart::literal(separator));
+            }
+            let mut seconds_value = seconds_value;
+            self.round_fraction(&mut seconds_value);
+            numeric_parts.extend(format_numeric(
+                DurationUnit::Seconds,
+                seconds_value,
+                seconds_is_zero,
+            )?);
+            // b. Set numericPartsList to the list-concatenation of numericPartsList and secondsParts.
+        }
+
+        // 19. Return numericPartsList.
+        Ok(numeric_parts)
+    }
+
+    /// Abstract operation [`ListFormatParts ( durationFormat, partitionedPartsList )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-listformatparts
+    fn list_format_parts(
+        &self,
+        partitioned_parts: Vec<Vec<DurationPart>>,
+        provider: &IntlProvider,
+    ) -> JsResult<Vec<DurationPart>> {
+        // 1. Let lfOpts be OrdinaryObjectCreate(null).
+        // 2. Perform ! CreateDataPropertyOrThrow(lfOpts, "type", "unit").
+        // 3. Let listStyle be durationFormat.[[Style]].
+        // 4. If listStyle is "digital", then
+        //     a. Set listStyle to "short".
+        // 5. Perform ! CreateDataPropertyOrThrow(lfOpts, "style", listStyle).
+        let length = match self.style {
+            BaseStyle::Long => ListLength::Wide,
+            BaseStyle::Short | BaseStyle::Digital => ListLength::Short,
+            BaseStyle::Narrow => ListLength::Narrow,
+        };
+
+        // 6. Let lf be ! Construct(%Intl.ListFormat%, « durationFormat.[[Locale]], lfOpts »).
+        let lf = ListFormatter::try_new_unit_with_buffer_provider(
+            provider.erased_provider(),
+            ListFormatterPreferences::from(&self.locale),
+            ListFormatterOptions::default().with_length(length),
+        )
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 7. Let strings be a new empty List.
+        // 8. For each element parts of partitionedPartsList, do
+        //     a. Let string be the empty String.
+        //     b. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //         i. Set string to the string-concatenation of string and part.[[Value]].
+        //     c. Append string to strings.
+        let strings = partitioned_parts
+            .iter()
+            .map(|parts| {
+                parts
+                    .iter()
+                    .map(|part| part.value.as_str())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        // 9. Let formattedPartsList be CreatePartsFromList(lf, strings).
+        let formatted_parts = collect_parts_with_category(&lf.format(strings.iter()), "list")?;
+
+        // 10. Let partitionedPartsIndex be 0.
+        // 11. Let partitionedLength be the number of elements in partitionedPartsList.
+        // 12. Let flattenedPartsList be a new empty List.
+        let mut partitioned_parts = partitioned_parts.into_iter();
+        let mut flattened_parts = Vec::new();
+
+        // 13. For each Record { [[Type]], [[Value]] } listPart in formattedPartsList, do
+        for (typ, value) in formatted_parts {
+            // a. If listPart.[[Type]] is "element", then
+            if typ == "element" {
+                // i. Assert: partitionedPartsIndex < partitionedLength.
+                // ii. Let parts be partitionedPartsList[partitionedPartsIndex].
+                // iii. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+                //     1. Append part to flattenedPartsList.
+                // iv. Set partitionedPartsIndex to partitionedPartsIndex + 1.
+                flattened_parts.extend(
+                    partitioned_parts
+                        .next()
+                        .js_expect("list must have as many elements as partitioned parts")?,
+                );
+            } else {
+                // b. Else,
+                //     i. Assert: listPart.[[Type]] is "literal".
+                //     ii. Append the Record { [[Type]]: "literal", [[Value]]: listPart.[[Value]], [[Unit]]: empty } to flattenedPartsList.
+                flattened_parts.push(DurationPart::literal(&value));
+            }
+        }
+
+        // 14. Return flattenedPartsList.
+        Ok(flattened_parts)
+    }
+
+    /// Rounds the fractional digits of a fractional unit, using the `fractionalDigits` option.
+    fn round_fraction(&self, value: &mut Decimal) {
+        let (minimum, maximum) = self
+            .fractional_digits
+            .map_or((0, 9), |digits| (digits, digits));
+        value.trunc(-i16::from(maximum));
+        value.trim_end();
+        value.pad_end(-i16::from(minimum));
+    }
+
+    /// Creates the formatter of the numeric units of this `DurationFormat`.
+    fn digital_formatter(&self, provider: &IntlProvider) -> JsResult<DecimalFormatter> {
+        let mut prefs = DecimalFormatterPreferences::from(&self.locale);
+        prefs.numbering_system = Some(self.numbering_system);
+        let mut options = DecimalFormatterOptions::default();
+        options.grouping_strategy = Some(GroupingStrategy::Never);
+
+        DecimalFormatter::try_new_with_buffer_provider(provider.erased_provider(), prefs, options)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()).into())
+    }
+
+    /// Creates the formatter for `unit` with the provided `style`.
+    fn units_formatter(
+        &self,
+        unit: DurationUnit,
+        style: UnitStyle,
+        provider: &IntlProvider,
+    ) -> JsResult<UnitsFormatter> {
+        let mut prefs = UnitsFormatterPreferences::from(&self.locale);
+        prefs.numbering_system = Some(self.numbering_system);
+        let mut options = UnitsFormatterOptions::default();
+        options.width = match style {
+            UnitStyle::Long => Width::Long,
+            UnitStyle::Narrow => Width::Narrow,
+            _ => Width::Short,
+        };
+
+        UnitsFormatter::try_new_with_buffer_provider(
+            provider.erased_provider(),
+            prefs,
+            unit.singular(),
+            options,
+        )
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()).into())
+    }
+}
+
+/// Abstract operation [`GetDurationUnitOptions ( unit, options, baseStyle, stylesList, digitalBase, prevStyle, twoDigitHours )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getdurationunitoptions
+fn get_duration_unit_options(
+    unit: DurationUnit,
+    options: &JsObject,
+    base_style: BaseStyle,
+    prev_style: Option<UnitStyle>,
+    context: &mut Context,
+) -> JsResult<DurationUnitOptions> {
+    // 1. Let style be ? GetOption(options, unit, string, stylesList, undefined).
+    let style = get_option::<UnitStyle>(options, js_string!(unit.name()), context)?;
+    if let Some(style) = style
+        && !unit.accepts(style)
+    {
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "`{}` is not a valid style for the `{}` option",
+                style.to_js_string().to_std_string_escaped(),
+                unit.name()
+            ))
+            .into());
+    }
+
+    // 2. Let displayDefault be "always".
+    let mut display_default = Display::Always;
+
+    // 3. If style is undefined, then
+    let mut style = match style {
+        Some(style) => style,
+        // a. If baseStyle is "digital", then
+        None if base_style == BaseStyle::Digital => {
+            // ii. If unit is not one of "hours", "minutes", or "seconds", then
+            if !matches!(
+                unit,
+                DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds
+            ) {
+                // 1. Set displayDefault to "auto".
+                display_default = Display::Auto;
+            }
+
+            // i. Set style to digitalBase.
+            unit.digital_base()
+        }
+        // b. Else if prevStyle is "fractional", "numeric" or "2-digit", then
+        None if prev_style.is_some_and(UnitStyle::is_numeric) => {
+            // ii. If unit is not "minutes" or "seconds", then
+            if !matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds) {
+                // 1. Set displayDefault to "auto".
+                display_default = Display::Auto;
+            }
+
+            // i. Set style to "numeric".
+            UnitStyle::Numeric
+        }
+        // c. Else,
+        None => {
+            // ii. Set displayDefault to "auto".
+            display_default = Display::Auto;
+
+            // i. Set style to baseStyle.
+            UnitStyle::from(base_style)
+        }
+    };
+
+    // 4. If style is "numeric" and IsFractionalSecondUnitName(unit) is true, then
+    if style == UnitStyle::Numeric
+        && matches!(
+            unit,
+            DurationUnit::Milliseconds | DurationUnit::Microseconds | DurationUnit::Nanoseconds
+        )
+    {
+        // a. Set style to "fractional".
+        style = UnitStyle::Fractional;
+        // b. Set displayDefault to "auto".
+        display_default = Display::Auto;
+    }
+
+    // 5. Let displayField be the string-concatenation of unit and "Display".
+    // 6. Let display be ? GetOption(options, displayField, string, « "auto", "always" », displayDefault).
+    let display =
+        get_option(options, js_string!(unit.display_field()), context)?.unwrap_or(display_default);
+
+    // 7. Perform ? ValidateDurationUnitStyle(unit, style, display, prevStyle).
+
+    // `ValidateDurationUnitStyle ( unit, style, display, prevStyle )`
+    // https://tc39.es/ecma402/#sec-validatedurationunitstyle
+
+    // 1. If display is "always" and style is "fractional", throw a RangeError exception.
+    if display == Display::Always && style == UnitStyle::Fractional {
+        return Err(js_error!(
+            RangeError: "`{}Display` cannot be `always` if `{}` is a fractional unit",
+            unit.name(),
+            unit.name()
+        ));
+    }
+
+    // 2. If prevStyle is "fractional" and style is not "fractional", throw a RangeError exception.
+    if prev_style == Some(UnitStyle::Fractional) && style != UnitStyle::Fractional {
+        return Err(js_error!(
+            RangeError: "`{}` must be fractional if the previous unit is fractional",
+            unit.name()
+        ));
+    }
+
+    // 3. If prevStyle is "numeric" or "2-digit" and style is not one of "fractional", "numeric" or "2-digit", throw a RangeError exception.
+    if matches!(prev_style, Some(UnitStyle::Numeric | UnitStyle::TwoDigit)) && !style.is_numeric() {
+        return Err(js_error!(
+            RangeError: "`{}` must be numeric if the previous unit is numeric",
+            unit.name()
+        ));
+    }
+
+    // 4. Return unused.
+
+    // 8. If unit is "hours" and twoDigitHours is true, set style to "2-digit".
+    // TODO: use the `twoDigitHours` value of the locale data when ICU4X exposes it.
+
+    // 9. If unit is "minutes" or "seconds" and prevStyle is "numeric" or "2-digit", set style to "2-digit".
+    if matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds)
+        && matches!(prev_style, Some(UnitStyle::Numeric | UnitStyle::TwoDigit))
+    {
+        style = UnitStyle::TwoDigit;
+    }
+
+    // 10. Return the Duration Unit Options Record { [[Style]]: style, [[Display]]: display }.
+    Ok(DurationUnitOptions { style, display })
+}
+
+/// A [Duration Record][spec], with all its fields in the order of [`DurationUnit::ALL`].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-duration-records
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct DurationRecord([i128; 10]);
+
+impl DurationRecord {
+    /// Abstract operation [`ToDurationRecord ( input )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-todurationrecord
+    pub(crate) fn from_value(input: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. If input is not an Object, then
+        let Some(input) = input.as_object() else {
+            // a. If input is a String, throw a RangeError exception.
+            if input.is_string() {
+                return Err(js_error!(RangeError: "cannot format a string as a duration"));
+            }
+
+            // b. Throw a TypeError exception.
+            return Err(js_error!(TypeError: "a duration must be an object"));
+        };
+
+        // 2. Let result be a new Duration Record with each field set to 0.
+        let mut result = Self::default();
+        let mut any_defined = false;
+
+        // 3-22. For each field in alphabetical order, let value be ? Get(input, field) and,
+        //       if value is not undefined, set the field to ? ToIntegerIfIntegral(value).
+        for unit in [
+            DurationUnit::Days,
+            DurationUnit::Hours,
+            DurationUnit::Microseconds,
+            DurationUnit::Milliseconds,
+            DurationUnit::Minutes,
+            DurationUnit::Months,
+            DurationUnit::Nanoseconds,
+            DurationUnit::Seconds,
+            DurationUnit::Weeks,
+            DurationUnit::Years,
+        ] {
+            let value = input.get(js_string!(unit.name()), context)?;
+            if value.is_undefined() {
+                continue;
+            }
+            any_defined = true;
+
+            let value = value.to_number(context)?;
+            if !value.is_finite() || value.fract() != 0.0 {
+                return Err(js_error!(
+                    RangeError: "the `{}` field of a duration must be an integer",
+                    unit.name()
+                ));
+            }
+
+            // Out of range values saturate, and are rejected by the validation below.
+            #[allow(clippy::cast_possible_truncation)]
+            let value = value as i128;
+            result.0[unit as usize] = value;
+        }
+
+        // 23. If years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, and nanoseconds are all undefined, throw a TypeError exception.
+        if !any_defined {
+            return Err(js_error!(TypeError: "a duration must have at least one duration field"));
+        }
+
+        // 24. If IsValidDuration(result.[[Years]], result.[[Months]], result.[[Weeks]], result.[[Days]], result.[[Hours]], result.[[Minutes]], result.[[Seconds]], result.[[Milliseconds]], result.[[Microseconds]], result.[[Nanoseconds]]) is false, then
+        if !result.is_valid() {
+            // a. Throw a RangeError exception.
+            return Err(js_error!(RangeError: "the provided duration is invalid"));
+        }
+
+        // 25. Return result.
+        Ok(result)
+    }
+
+    /// Abstract operation [`IsValidDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-isvalidduration
+    fn is_valid(&self) -> bool {
+        const MAX_CALENDAR_UNIT: i128 = 1 << 32;
+        const MAX_NANOSECONDS: i128 = (1 << 53) * 1_000_000_000;
+
+        // 1. Let sign be 0.
+        // 2. For each value v of « years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds », do
+        //     a. If 𝔽(v) is not finite, return false.
+        //     b. If v < 0, then
+        //         i. If sign > 0, return false.
+        //         ii. Set sign to -1.
+        //     c. Else if v > 0, then
+        //         i. If sign < 0, return false.
+        //         ii. Set sign to 1.
+        if self.0.iter().any(|v| *v > 0) && self.0.iter().any(|v| *v < 0) {
+            return false;
+        }
+
+        // 3. If abs(years) ≥ 2**32, return false.
+        // 4. If abs(months) ≥ 2**32, return false.
+        // 5. If abs(weeks) ≥ 2**32, return false.
+        if self.0[..3].iter().any(|v| v.abs() >= MAX_CALENDAR_UNIT) {
+            return false;
+        }
+
+        // 6. Let normalizedSeconds be days × 86,400 + hours × 3600 + minutes × 60 + seconds + ℝ(𝔽(milliseconds)) × 10**-3 + ℝ(𝔽(microseconds)) × 10**-6 + ℝ(𝔽(nanoseconds)) × 10**-9.
+        // 7. NOTE: The above step cannot be implemented directly using floating-point arithmetic. ...
+        // 8. If abs(normalizedSeconds) ≥ 2**53, return false.
+        let [
+            ..,
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        ] = self.0;
+        let normalized_nanoseconds = [
+            (days, 86_400_000_000_000),
+            (hours, 3_600_000_000_000),
+            (minutes, 60_000_000_000),
+            (seconds, 1_000_000_000),
+            (milliseconds, 1_000_000),
+            (microseconds, 1_000),
+            (nanoseconds, 1),
+        ]
+        .into_iter()
+        .try_fold(0i128, |acc, (value, factor)| {
+            value.checked_mul(factor).and_then(|v| acc.checked_add(v))
+        });
+
+        // 9. Return true.
+        normalized_nanoseconds.is_some_and(|ns| ns.abs() < MAX_NANOSECONDS)
+    }
+
+    /// Gets the value of the field of `unit`.
+    pub(crate) fn get(&self, unit: DurationUnit) -> i128 {
+        self.0[unit as usize]
+    }
+
+    /// Abstract operation [`DurationSign ( duration )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-durationsign
+    fn sign(&self) -> i8 {
+        self.0
+            .iter()
+            .find(|v| **v != 0)
+            .map_or(0, |v| if *v < 0 { -1 } else { 1 })
+    }
+
+    /// Gets the value of `unit`, adding all smaller units as fractional digits
+    /// (see [`AddFractionalDigits`][spec]).
+    ///
+    /// Returns the value and whether it is zero.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-addfractionaldigits
+    fn fractional_value(&self, unit: DurationUnit) -> (Decimal, bool) {
+        let units = &self.0[unit as usize..];
+        let value = units.iter().fold(0i128, |acc, v| acc * 1000 + v);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let magnitude = -3 * (units.len() as i16 - 1);
+        let mut decimal = Decimal::from(value);
+        decimal.multiply_pow10(magnitude);
+        (decimal, value == 0)
+    }
+}
+
+#[cfg(feature = "temporal")]
+impl From<&temporal_rs::Duration> for DurationRecord {
+    fn from(duration: &temporal_rs::Duration) -> Self {
+        Self([
+            i128::from(duration.years()),
+            i128::from(duration.months()),
+            i128::from(duration.weeks()),
+            i128::from(duration.days()),
+            i128::from(duration.hours()),
+            i128::from(duration.minutes()),
+            i128::from(duration.seconds()),
+            i128::from(duration.milliseconds()),
+            duration.microseconds(),
+            duration.nanoseconds(),
+        ])
+    }
+}
+
+/// A part of a formatted duration.
+#[derive(Debug, Clone)]
+struct DurationPart {
+    typ: &'static str,
+    value: String,
+    unit: Option<&'static str>,
+}
+
+impl DurationPart {
+    fn literal(value: &str) -> Self {
+        Self {
+            typ: "literal",
+            value: value.to_owned(),
+            unit: None,
+        }
+    }
+}
+
+/// Collects the parts of a formatted value, merging all text outside of the parts of
+/// `category` into `literal` parts.
+#[derive(Debug)]
+struct PartsCollector {
+    category: &'static str,
+    parts: Vec<(&'static str, String)>,
+}
+
+impl Write for PartsCollector {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        if let Some((typ, value)) = self.parts.last_mut()
+            && *typ == "literal"
+        {
+            value.push_str(s);
+        } else {
+            self.parts.push(("literal", s.to_owned()));
+        }
+        Ok(())
+    }
+}
+
+impl PartsWrite for PartsCollector {
+    type SubPartsWrite = Self;
+
+    fn with_part(
+        &mut self,
+        part: writeable::Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        if part.category != self.category {
+            return f(self);
+        }
+
+        // Nested parts (e.g. the group separators of the integer part) are kept as separate parts.
+        let mut sub = PartsCollector {
+            category: self.category,
+            parts: Vec::new(),
+        };
+        f(&mut sub)?;
+        self.parts.extend(
+            sub.parts
+                .into_iter()
+                .map(|(typ, value)| (if typ == "literal" { part.value } else { typ }, value)),
+        );
+        Ok(())
+    }
+}
+
+/// Collects the parts of the category `category` of a formatted value.
+fn collect_parts_with_category(
+    formatted: &impl Writeable,
+    category: &'static str,
+) -> JsResult<Vec<(&'static str, String)>> {
+    let mut collector = PartsCollector {
+        category,
+        parts: Vec::new(),
+    };
+    formatted
+        .write_to_parts(&mut collector)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+    Ok(collector.parts)
+}
+
+/// Collects the parts of a formatted number.
+fn collect_parts(formatted: &impl Writeable) -> JsResult<Vec<(&'static str, String)>> {
+    collect_parts_with_category(formatted, "decimal")
+}
+
+/// Attaches `unit` to the parts of a formatted number, splitting the unit pattern from its
+/// surrounding whitespace.
+fn unit_parts(parts: Vec<(&'static str, String)>, unit: DurationUnit) -> Vec<DurationPart> {
+    let unit = Some(unit.singular());
+    let mut result = Vec::with_capacity(parts.len());
+    for (typ, value) in parts {
+        if typ != "literal" {
+            result.push(DurationPart { typ, value, unit });
+            continue;
+        }
+
+        let trimmed_start = value.trim_start();
+        let unit_text = trimmed_start.trim_end();
+        let leading = &value[..value.len() - trimmed_start.len()];
+        let trailing = &trimmed_start[unit_text.len()..];
+        for (typ, value) in [
+            ("literal", leading),
+            ("unit", unit_text),
+            ("literal", trailing),
+        ] {
+            if !value.is_empty() {
+                result.push(DurationPart {
+                    typ,
+                    value: value.to_owned(),
+                    unit,
+                });
+            }
+        }
+    }
+    result
+}
+
+/// Gets the default numbering system of `locale`.
+fn default_numbering_system(locale: &Locale, provider: &IntlProvider) -> JsResult<NumberingSystem> {
+    let data_locale = DataLocale::from(&locale.id);
+    let req = DataRequest {
+        id: DataIdentifierBorrowed::for_locale(&data_locale),
+        metadata: DataRequestMetadata::default(),
+    };
+    let data = DataProvider::<DecimalSymbolsV1>::load(provider, req)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    Value::try_from_str(data.payload.get().numsys())
+        .ok()
+        .and_then(|nu| NumberingSystem::try_from(nu).ok())
+        .ok_or_else(|| {
+            js_error!(TypeError: "could not obtain resolved numbering system from Intl provider")
+        })
+}
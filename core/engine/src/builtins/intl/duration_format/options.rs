@@ -0,0 +1,258 @@
+use std::str::FromStr;
+
+use crate::{JsString, builtins::options::ParsableOptionType, js_string};
+
+/// The base style of a `DurationFormat`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum BaseStyle {
+    Long,
+    #[default]
+    Short,
+    Narrow,
+    Digital,
+}
+
+impl BaseStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Digital => js_string!("digital"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseBaseStyleError;
+
+impl std::fmt::Display for ParseBaseStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow` or `digital`")
+    }
+}
+
+impl FromStr for BaseStyle {
+    type Err = ParseBaseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "digital" => Ok(Self::Digital),
+            _ => Err(ParseBaseStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for BaseStyle {}
+
+/// The style used to display a single unit of a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitStyle {
+    Long,
+    Short,
+    Narrow,
+    Numeric,
+    TwoDigit,
+    Fractional,
+}
+
+impl UnitStyle {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Long => js_string!("long"),
+            Self::Short => js_string!("short"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Numeric => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+            Self::Fractional => js_string!("fractional"),
+        }
+    }
+
+    /// Returns `true` if this is one of the styles of the digital section of a duration.
+    pub(crate) const fn is_numeric(self) -> bool {
+        matches!(self, Self::Numeric | Self::TwoDigit | Self::Fractional)
+    }
+}
+
+impl From<BaseStyle> for UnitStyle {
+    fn from(style: BaseStyle) -> Self {
+        match style {
+            BaseStyle::Long => Self::Long,
+            BaseStyle::Short => Self::Short,
+            BaseStyle::Narrow => Self::Narrow,
+            BaseStyle::Digital => Self::Numeric,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitStyleError;
+
+impl std::fmt::Display for ParseUnitStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not a valid duration unit style")
+    }
+}
+
+impl FromStr for UnitStyle {
+    type Err = ParseUnitStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(ParseUnitStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for UnitStyle {}
+
+/// Whether a unit of a duration should always be displayed, or only if it's nonzero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Display {
+    Auto,
+    Always,
+}
+
+impl Display {
+    pub(crate) fn to_js_string(self) -> JsString {
+        match self {
+            Self::Auto => js_string!("auto"),
+            Self::Always => js_string!("always"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisplayError;
+
+impl std::fmt::Display for ParseDisplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `auto` or `always`")
+    }
+}
+
+impl FromStr for Display {
+    type Err = ParseDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            _ => Err(ParseDisplayError),
+        }
+    }
+}
+
+impl ParsableOptionType for Display {}
+
+/// The units of a duration, in the order of the rows of the [`DurationFormat` units table][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#table-durationformat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum DurationUnit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl DurationUnit {
+    pub(crate) const ALL: [Self; 10] = [
+        Self::Years,
+        Self::Months,
+        Self::Weeks,
+        Self::Days,
+        Self::Hours,
+        Self::Minutes,
+        Self::Seconds,
+        Self::Milliseconds,
+        Self::Microseconds,
+        Self::Nanoseconds,
+    ];
+
+    /// Gets the name of the option and the duration field of this unit.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Years => "years",
+            Self::Months => "months",
+            Self::Weeks => "weeks",
+            Self::Days => "days",
+            Self::Hours => "hours",
+            Self::Minutes => "minutes",
+            Self::Seconds => "seconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Microseconds => "microseconds",
+            Self::Nanoseconds => "nanoseconds",
+        }
+    }
+
+    /// Gets the name of the display option of this unit.
+    pub(crate) const fn display_field(self) -> &'static str {
+        match self {
+            Self::Years => "yearsDisplay",
+            Self::Months => "monthsDisplay",
+            Self::Weeks => "weeksDisplay",
+            Self::Days => "daysDisplay",
+            Self::Hours => "hoursDisplay",
+            Self::Minutes => "minutesDisplay",
+            Self::Seconds => "secondsDisplay",
+            Self::Milliseconds => "millisecondsDisplay",
+            Self::Microseconds => "microsecondsDisplay",
+            Self::Nanoseconds => "nanosecondsDisplay",
+        }
+    }
+
+    /// Gets the singular, sanctioned unit identifier of this unit.
+    pub(crate) const fn singular(self) -> &'static str {
+        match self {
+            Self::Years => "year",
+            Self::Months => "month",
+            Self::Weeks => "week",
+            Self::Days => "day",
+            Self::Hours => "hour",
+            Self::Minutes => "minute",
+            Self::Seconds => "second",
+            Self::Milliseconds => "millisecond",
+            Self::Microseconds => "microsecond",
+            Self::Nanoseconds => "nanosecond",
+        }
+    }
+
+    /// Returns `true` if `style` is in the list of valid styles of this unit.
+    pub(crate) const fn accepts(self, style: UnitStyle) -> bool {
+        match self {
+            Self::Years | Self::Months | Self::Weeks | Self::Days => {
+                matches!(
+                    style,
+                    UnitStyle::Long | UnitStyle::Short | UnitStyle::Narrow
+                )
+            }
+            Self::Hours | Self::Minutes | Self::Seconds => !matches!(style, UnitStyle::Fractional),
+            Self::Milliseconds | Self::Microseconds | Self::Nanoseconds => {
+                !matches!(style, UnitStyle::TwoDigit | UnitStyle::Fractional)
+            }
+        }
+    }
+
+    /// Gets the default style of this unit when the base style is `"digital"`.
+    pub(crate) const fn digital_base(self) -> UnitStyle {
+        match self {
+            Self::Years | Self::Months | Self::Weeks | Self::Days => UnitStyle::Short,
+            _ => UnitStyle::Numeric,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use boa_macros::js_str;
+use indoc::indoc;
+
+use crate::{JsNativeErrorKind, TestAction, run_test_actions};
+
+#[test]
+fn format() {
+    run_test_actions([
+        TestAction::run("const duration = { hours: 1, minutes: 46, seconds: 40 };"),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en').format(duration)",
+            js_str!("1 hr, 46 min, 40 sec"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'long' }).format({ years: 1, days: 2 })",
+            js_str!("1 year, 2 days"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'narrow' }).format({ days: 3 })",
+            js_str!("3d"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital' }).format(duration)",
+            js_str!("1:46:40"),
+        ),
+        TestAction::assert_eq(
+            indoc! {"
+                new Intl.DurationFormat('en', { style: 'digital' })
+                    .format({ hours: -1, minutes: -2, milliseconds: -500 })
+            "},
+            js_str!("-1:02:00.5"),
+        ),
+        TestAction::assert_eq(
+            indoc! {"
+                new Intl.DurationFormat('en', { style: 'digital', fractionalDigits: 2 })
+                    .format({ minutes: 5, seconds: 3 })
+            "},
+            js_str!("0:05:03.00"),
+        ),
+    ]);
+}
+
+#[test]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            const parts = new Intl.DurationFormat('en', { style: 'long' })
+                .formatToParts({ hours: 2, minutes: 1 });
+        "}),
+        TestAction::assert_eq("parts.length", 7),
+        TestAction::assert_eq("parts[0].type", js_str!("integer")),
+        TestAction::assert_eq("parts[0].value", js_str!("2")),
+        TestAction::assert_eq("parts[0].unit", js_str!("hour")),
+        TestAction::assert_eq("parts[1].type", js_str!("literal")),
+        TestAction::assert_eq("parts[2].type", js_str!("unit")),
+        TestAction::assert_eq("parts[2].value", js_str!("hours")),
+        TestAction::assert_eq("parts[3].value", js_str!(", ")),
+        TestAction::assert("!('unit' in parts[3])"),
+        TestAction::assert_eq("parts[6].value", js_str!("minute")),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            const options = new Intl.DurationFormat('en', { style: 'digital' }).resolvedOptions();
+        "}),
+        TestAction::assert_eq("options.locale", js_str!("en")),
+        TestAction::assert_eq("options.numberingSystem", js_str!("latn")),
+        TestAction::assert_eq("options.style", js_str!("digital")),
+        TestAction::assert_eq("options.days", js_str!("short")),
+        TestAction::assert_eq("options.daysDisplay", js_str!("auto")),
+        TestAction::assert_eq("options.hours", js_str!("numeric")),
+        TestAction::assert_eq("options.hoursDisplay", js_str!("always")),
+        TestAction::assert_eq("options.minutes", js_str!("2-digit")),
+        TestAction::assert_eq("options.milliseconds", js_str!("numeric")),
+        TestAction::assert("!('fractionalDigits' in options)"),
+    ]);
+}
+
+#[test]
+fn invalid_arguments() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en', { hours: 'numeric', minutes: 'long' })",
+            JsNativeErrorKind::Range,
+            "`minutes` must be numeric if the previous unit is numeric",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en').format('PT1H')",
+            JsNativeErrorKind::Range,
+            "cannot format a string as a duration",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en').format({})",
+            JsNativeErrorKind::Type,
+            "a duration must have at least one duration field",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en').format({ hours: 1, minutes: -1 })",
+            JsNativeErrorKind::Range,
+            "the provided duration is invalid",
+        ),
+    ]);
+}
+
+#[cfg(feature = "temporal")]
+#[test]
+fn temporal_duration_to_locale_string() {
+    run_test_actions([TestAction::assert_eq(
+        "Temporal.Duration.from({ hours: 1, minutes: 30 }).toLocaleString('en', { style: 'long' })",
+        js_str!("1 hour, 30 minutes"),
+    )]);
+}
@@ -1,9 +1,25 @@
-use crate::{builtins::options::get_option, realm::Realm, string::StaticJsStrings};
+use crate::{
+    builtins::options::{OptionType, get_option},
+    realm::Realm,
+    string::StaticJsStrings,
+};
+use icu_calendar::{
+    AnyCalendarKind,
+    preferences::CalendarPreferences,
+    types::Weekday,
+    week::{WeekInformation, WeekPreferences},
+};
+use icu_datetime::DateTimeFormatterPreferences;
+use icu_decimal::provider::DecimalSymbolsV1;
 use icu_locale::{
+    Direction, LocaleDirectionality,
     extensions::unicode::Value,
     extensions_unicode_key as key, extensions_unicode_value as value,
     preferences::extensions::unicode::keywords::{CollationCaseFirst, HourCycle},
 };
+use icu_provider::{
+    DataIdentifierBorrowed, DataLocale, DataProvider, DataRequest, DataRequestMetadata,
+};
 
 #[cfg(all(test, feature = "intl_bundled"))]
 mod tests;
@@ -14,8 +30,10 @@ pub(crate) use utils::*;
 mod options;
 
 use crate::{
-    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
-    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    Context, JsArgs, JsExpect, JsNativeError, JsResult, JsString, JsValue,
+    builtins::{
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
     object::{JsObject, internal_methods::get_prototype_from_constructor},
@@ -23,7 +41,9 @@ use crate::{
     symbol::JsSymbol,
 };
 
-use super::options::coerce_options_to_object;
+use super::{
+    date_time_format::locale_hour_cycle, options::coerce_options_to_object, supported_values,
+};
 
 #[derive(Debug, Clone)]
 pub(crate) struct Locale;
@@ -46,6 +66,10 @@ impl IntrinsicObject for Locale {
             .name(js_string!("get collation"))
             .build();
 
+        let first_day_of_week = BuiltInBuilder::callable(realm, Self::first_day_of_week)
+            .name(js_string!("get firstDayOfWeek"))
+            .build();
+
         let hour_cycle = BuiltInBuilder::callable(realm, Self::hour_cycle)
             .name(js_string!("get hourCycle"))
             .build();
@@ -83,6 +107,17 @@ impl IntrinsicObject for Locale {
             .method(Self::maximize, js_string!("maximize"), 0)
             .method(Self::minimize, js_string!("minimize"), 0)
             .method(Self::to_string, js_string!("toString"), 0)
+            .method(Self::get_calendars, js_string!("getCalendars"), 0)
+            .method(Self::get_collations, js_string!("getCollations"), 0)
+            .method(Self::get_hour_cycles, js_string!("getHourCycles"), 0)
+            .method(
+                Self::get_numbering_systems,
+                js_string!("getNumberingSystems"),
+                0,
+            )
+            .method(Self::get_time_zones, js_string!("getTimeZones"), 0)
+            .method(Self::get_text_info, js_string!("getTextInfo"), 0)
+            .method(Self::get_week_info, js_string!("getWeekInfo"), 0)
             .accessor(
                 js_string!("baseName"),
                 Some(base_name),
@@ -107,6 +142,12 @@ impl IntrinsicObject for Locale {
                 None,
                 Attribute::CONFIGURABLE,
             )
+            .accessor(
+                js_string!("firstDayOfWeek"),
+                Some(first_day_of_week),
+                None,
+                Attribute::CONFIGURABLE,
+            )
             .accessor(
                 js_string!("hourCycle"),
                 Some(hour_cycle),
@@ -163,7 +204,7 @@ impl BuiltInObject for Locale {
 
 impl BuiltInConstructor for Locale {
     const CONSTRUCTOR_ARGUMENTS: usize = 1;
-    const PROTOTYPE_STORAGE_SLOTS: usize = 26;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 35;
     const CONSTRUCTOR_STORAGE_SLOTS: usize = 0;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -266,6 +307,21 @@ impl BuiltInConstructor for Locale {
         // 20. Set opt.[[co]] to collation.
         let co = get_option(options, js_string!("collation"), context)?;
 
+        // Steps added by the Intl Locale Info proposal:
+        // <https://tc39.es/proposal-intl-locale-info/#sec-intl-locale-constructor>
+        // a. Let fw be ? GetOption(options, "firstDayOfWeek", string, empty, undefined).
+        // b. If fw is not undefined, then
+        //     i. Set fw to ! WeekdayToString(fw).
+        //     ii. If fw cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // c. Set opt.[[fw]] to fw.
+        let fw = match get_option::<JsString>(options, js_string!("firstDayOfWeek"), context)? {
+            Some(fw) => {
+                let fw = weekday_to_string(fw);
+                Some(Value::from_value(fw.into(), context)?)
+            }
+            None => None,
+        };
+
         // 21. Let hc be ? GetOption(options, "hourCycle", string, « "h11", "h12", "h23", "h24" », undefined).
         // 22. Set opt.[[hc]] to hc.
         let hc = get_option::<HourCycle>(options, js_string!("hourCycle"), context)?;
@@ -295,6 +351,10 @@ impl BuiltInConstructor for Locale {
             // 34. Set locale.[[Collation]] to r.[[co]].
             tag.extensions.unicode.keywords.set(key!("co"), co);
         }
+        if let Some(fw) = fw {
+            // Set locale.[[FirstDayOfWeek]] to r.[[fw]].
+            tag.extensions.unicode.keywords.set(key!("fw"), fw);
+        }
         if let Some(hc) = hc {
             // 35. Set locale.[[HourCycle]] to r.[[hc]].
             tag.extensions.unicode.keywords.set(key!("hc"), hc.into());
@@ -550,6 +610,40 @@ impl Locale {
             .unwrap_or_default())
     }
 
+    /// [`get Intl.Locale.prototype.firstDayOfWeek`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/firstDayOfWeek
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.firstDayOfWeek
+    pub(crate) fn first_day_of_week(
+        this: &JsValue,
+        _: &[JsValue],
+        _: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let object = this.as_object();
+        let loc = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`get Locale.prototype.firstDayOfWeek` can only be called on a `Locale` object",
+                )
+            })?;
+
+        // 3. Return loc.[[FirstDayOfWeek]].
+        Ok(loc
+            .extensions
+            .unicode
+            .keywords
+            .get(&key!("fw"))
+            .map(|v| js_string!(v.to_string()).into())
+            .unwrap_or_default())
+    }
+
     /// [`get Intl.Locale.prototype.hourCycle`][spec].
     ///
     /// More information:
@@ -772,4 +866,476 @@ impl Locale {
         // 4. Return the substring of variants from 1.
         Ok(js_string!(loc.id.variants.to_string()).into())
     }
+
+    /// [`Intl.Locale.prototype.getCalendars ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getCalendars
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCalendars
+    pub(crate) fn get_calendars(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let object = this.as_object();
+        let mut loc = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getCalendars` can only be called on a `Locale` object",
+                )
+            })?
+            .clone();
+
+        // 3. Return CalendarsOfLocale(loc).
+
+        // Abstract operation `CalendarsOfLocale ( loc )`
+        // <https://tc39.es/proposal-intl-locale-info/#sec-calendars-of-locale>
+
+        // 1. If loc.[[Calendar]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[Calendar]] »).
+        let ca = if let Some(ca) = loc.extensions.unicode.keywords.get(&key!("ca")) {
+            js_string!(ca.to_string())
+        } else {
+            // 2. Let r be loc.[[Locale]] with likely subtags added.
+            context
+                .intl_provider()
+                .locale_expander()?
+                .maximize(&mut loc.id);
+
+            // 3. Let list be a List of one or more unique canonical calendar identifiers, which
+            //    must be lower case String values conforming to the type sequence from UTS 35
+            //    Unicode Locale Identifier, section 3.2, sorted in descending preference of those
+            //    in common use for date and time formatting in r.
+            let kind = AnyCalendarKind::new(CalendarPreferences::from(&loc));
+            js_string!(calendar_identifier(kind))
+        };
+
+        // 4. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list([ca.into()], context).into())
+    }
+
+    /// [`Intl.Locale.prototype.getCollations ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getCollations
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCollations
+    pub(crate) fn get_collations(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let object = this.as_object();
+        let loc = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getCollations` can only be called on a `Locale` object",
+                )
+            })?
+            .clone();
+
+        // 3. Return CollationsOfLocale(loc).
+
+        // Abstract operation `CollationsOfLocale ( loc )`
+        // <https://tc39.es/proposal-intl-locale-info/#sec-collations-of-locale>
+
+        // 1. If loc.[[Collation]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[Collation]] »).
+        let list: Vec<JsValue> = if let Some(co) = loc.extensions.unicode.keywords.get(&key!("co"))
+        {
+            vec![js_string!(co.to_string()).into()]
+        } else {
+            // 2. Let r be loc.[[Locale]].
+            // 3. Let list be a List of unique canonical collation types, which must be lower case
+            //    String values conforming to the type sequence from UTS 35 Unicode Locale
+            //    Identifier, section 3.2, sorted in lexicographic code unit order, of the
+            //    collation types commonly used for string comparison in r.
+            // 4. Remove any element from list which is "standard" or "search".
+            supported_values::collations_of_locale(&loc.id, context.intl_provider())
+                .into_iter()
+                .map(|co| js_string!(co).into())
+                .collect()
+        };
+
+        // 5. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list(list, context).into())
+    }
+
+    /// [`Intl.Locale.prototype.getHourCycles ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getHourCycles
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getHourCycles
+    pub(crate) fn get_hour_cycles(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let object = this.as_object();
+        let loc = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getHourCycles` can only be called on a `Locale` object",
+                )
+            })?
+            .clone();
+
+        // 3. Return HourCyclesOfLocale(loc).
+
+        // Abstract operation `HourCyclesOfLocale ( loc )`
+        // <https://tc39.es/proposal-intl-locale-info/#sec-hour-cycles-of-locale>
+
+        // 1. If loc.[[HourCycle]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[HourCycle]] »).
+        let hc = if let Some(hc) = loc.extensions.unicode.keywords.get(&key!("hc")) {
+            js_string!(hc.to_string())
+        } else {
+            // 2. Let r be loc.[[Locale]].
+            // 3. Let list be a List of one or more unique hour cycle identifiers, which must be
+            //    lower case String values indicating either the 12-hour format ("h11", "h12") or
+            //    the 24-hour format ("h23", "h24"), sorted in descending preference of those in
+            //    common use for date and time formatting in r.
+            let hc = locale_hour_cycle(
+                DateTimeFormatterPreferences::from(&loc),
+                context.intl_provider(),
+            )?;
+            js_string!(hc.as_str())
+        };
+
+        // 4. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list([hc.into()], context).into())
+    }
+
+    /// [`Intl.Locale.prototype.getNumberingSystems ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getNumberingSystems
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getNumberingSystems
+    pub(crate) fn get_numbering_systems(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let object = this.as_object();
+        let loc = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getNumberingSystems` can only be called on a `Locale` object",
+                )
+            })?
+            .clone();
+
+        // 3. Return NumberingSystemsOfLocale(loc).
+
+        // Abstract operation `NumberingSystemsOfLocale ( loc )`
+        // <https://tc39.es/proposal-intl-locale-info/#sec-numbering-systems-of-locale>
+
+        // 1. If loc.[[NumberingSystem]] is not undefined, then
+        //     a. Return CreateArrayFromList(« loc.[[NumberingSystem]] »).
+        let nu = if let Some(nu) = loc.extensions.unicode.keywords.get(&key!("nu")) {
+            js_string!(nu.to_string())
+        } else {
+            // 2. Let r be loc.[[Locale]].
+            // 3. Let list be a List of one or more unique canonical numbering system identifiers,
+            //    which must be lower case String values conforming to the type sequence from
+            //    UTS 35 Unicode Locale Identifier, section 3.2, sorted in descending preference
+            //    of those in common use for formatting numeric values in r.
+            let data_locale = DataLocale::from(&loc.id);
+            let req = DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&data_locale),
+                metadata: DataRequestMetadata::default(),
+            };
+            let data = DataProvider::<DecimalSymbolsV1>::load(context.intl_provider(), req)
+                .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+            js_string!(data.payload.get().numsys())
+        };
+
+        // 4. Return CreateArrayFromList( list ).
+        Ok(Array::create_array_from_list([nu.into()], context).into())
+    }
+
+    /// [`Intl.Locale.prototype.getTimeZones ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getTimeZones
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getTimeZones
+    pub(crate) fn get_time_zones(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let object = this.as_object();
+        let region = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getTimeZones` can only be called on a `Locale` object",
+                )
+            })?
+            .id
+            .region;
+
+        // 3. Let locale be loc.[[Locale]].
+        // 4. If locale does not contain the ["-" unicode_region_subtag] sequence, return undefined.
+        let Some(region) = region else {
+            return Ok(JsValue::undefined());
+        };
+
+        // 5. Return TimeZonesOfLocale(loc).
+
+        // Abstract operation `TimeZonesOfLocale ( loc )`
+        // <https://tc39.es/proposal-intl-locale-info/#sec-time-zones-of-locale>
+
+        // 1. Let locale be loc.[[Locale]].
+        // 2. Assert: locale matches the unicode_locale_id production.
+        // 3. Let region be the substring of locale corresponding to the unicode_region_subtag production.
+        // 4. Let list be a List of unique canonical time zone identifiers, which must be String
+        //    values indicating a canonical Zone name of the IANA Time Zone Database, sorted in
+        //    lexicographic code unit order, of those in common use in region.
+        let list = supported_values::available_time_zones(Some(region), context.intl_provider())?;
+
+        // 5. Return CreateArrayFromList( list ).
+        Ok(
+            Array::create_array_from_list(
+                list.into_iter().map(|tz| js_string!(tz).into()),
+                context,
+            )
+            .into(),
+        )
+    }
+
+    /// [`Intl.Locale.prototype.getTextInfo ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getTextInfo
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getTextInfo
+    pub(crate) fn get_text_info(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let object = this.as_object();
+        let id = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getTextInfo` can only be called on a `Locale` object",
+                )
+            })?
+            .id
+            .clone();
+
+        // 3. Let info be OrdinaryObjectCreate(%Object.prototype%).
+        let info = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. Let dir be TextDirectionOfLocale(loc).
+
+        // Abstract operation `TextDirectionOfLocale ( loc )`
+        // <https://tc39.es/proposal-intl-locale-info/#sec-text-direction-of-locale>
+
+        // 1. Let locale be loc.[[Locale]].
+        // 2. If the default general ordering of characters (characterOrder) within a line in
+        //    locale is right-to-left, return "rtl".
+        // 3. If the default general ordering of characters (characterOrder) within a line in
+        //    locale is left-to-right, return "ltr".
+        // 4. Return undefined.
+        let directionality = LocaleDirectionality::try_new_extended_with_buffer_provider(
+            context.intl_provider().erased_provider(),
+        )
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+        let dir = match directionality.get(&id) {
+            Some(Direction::RightToLeft) => js_string!("rtl").into(),
+            Some(Direction::LeftToRight) => js_string!("ltr").into(),
+            _ => JsValue::undefined(),
+        };
+
+        // 5. Perform ! CreateDataPropertyOrThrow(info, "direction", dir).
+        info.create_data_property_or_throw(js_string!("direction"), dir, context)
+            .js_expect("operation must not fail per the spec")?;
+
+        // 6. Return info.
+        Ok(info.into())
+    }
+
+    /// [`Intl.Locale.prototype.getWeekInfo ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getWeekInfo
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getWeekInfo
+    pub(crate) fn get_week_info(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let object = this.as_object();
+        let mut loc = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<icu_locale::Locale>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`Locale.prototype.getWeekInfo` can only be called on a `Locale` object",
+                )
+            })?
+            .clone();
+
+        // 3. Let info be OrdinaryObjectCreate(%Object.prototype%).
+        let info = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. Let wi be WeekInfoOfLocale(loc).
+
+        // Abstract operation `WeekInfoOfLocale ( loc )`
+        // <https://tc39.es/proposal-intl-locale-info/#sec-week-info-of-locale>
+
+        // 1. Let locale be loc.[[Locale]].
+        // 2. Let r be a record whose fields are defined by Table 1, with values based on locale.
+        // 3. Let fws be loc.[[FirstDayOfWeek]].
+        // 4. Let fw be StringToWeekday(fws).
+        // 5. If fw is not undefined, set r.[[FirstDay]] to fw.
+        // 6. Return r.
+        //
+        // NOTE: week data is keyed by region, so the likely region of the locale is added
+        // before querying the provider. ICU4X already applies the `fw` keyword of the locale,
+        // which covers steps 3 to 5.
+        context
+            .intl_provider()
+            .locale_expander()?
+            .maximize(&mut loc.id);
+        let wi = WeekInformation::try_new_with_buffer_provider(
+            context.intl_provider().erased_provider(),
+            WeekPreferences::from(&loc),
+        )
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 5. Let we be CreateArrayFromList(wi.[[Weekend]]).
+        let weekend = [
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+            Weekday::Saturday,
+            Weekday::Sunday,
+        ]
+        .into_iter()
+        .filter(|&day| wi.weekend.contains(day))
+        .map(|day| weekday_number(day).into());
+        let we = Array::create_array_from_list(weekend, context);
+
+        // 6. Perform ! CreateDataPropertyOrThrow(info, "firstDay", wi.[[FirstDay]]).
+        info.create_data_property_or_throw(
+            js_string!("firstDay"),
+            weekday_number(wi.first_weekday),
+            context,
+        )
+        .js_expect("operation must not fail per the spec")?;
+
+        // 7. Perform ! CreateDataPropertyOrThrow(info, "weekend", we).
+        info.create_data_property_or_throw(js_string!("weekend"), we, context)
+            .js_expect("operation must not fail per the spec")?;
+
+        // 8. Return info.
+        Ok(info.into())
+    }
+}
+
+/// Abstract operation [`WeekdayToString ( fw )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-weekday-to-string
+fn weekday_to_string(fw: JsString) -> JsString {
+    // 1. For each row of Table 2, except the header row, in table order, do
+    //     a. Let w be the name given in the Weekday Value column of the current row.
+    //     b. Let s be the name given in the String Value column of the current row.
+    //     c. If fw is equal to w, return s.
+    // 2. Return fw.
+    match fw.to_std_string_escaped().as_str() {
+        "0" | "7" => js_string!("sun"),
+        "1" => js_string!("mon"),
+        "2" => js_string!("tue"),
+        "3" => js_string!("wed"),
+        "4" => js_string!("thu"),
+        "5" => js_string!("fri"),
+        "6" => js_string!("sat"),
+        _ => fw,
+    }
+}
+
+/// Gets the canonical calendar identifier of a calendar kind.
+fn calendar_identifier(kind: AnyCalendarKind) -> &'static str {
+    match kind {
+        AnyCalendarKind::Buddhist => "buddhist",
+        AnyCalendarKind::Chinese => "chinese",
+        AnyCalendarKind::Coptic => "coptic",
+        AnyCalendarKind::Dangi => "dangi",
+        AnyCalendarKind::Ethiopian => "ethiopic",
+        AnyCalendarKind::EthiopianAmeteAlem => "ethioaa",
+        AnyCalendarKind::Hebrew => "hebrew",
+        AnyCalendarKind::Indian => "indian",
+        AnyCalendarKind::HijriTabularTypeIIFriday => "islamic-civil",
+        AnyCalendarKind::HijriSimulatedMecca => "islamic-rgsa",
+        AnyCalendarKind::HijriTabularTypeIIThursday => "islamic-tbla",
+        AnyCalendarKind::HijriUmmAlQura => "islamic-umalqura",
+        AnyCalendarKind::Iso => "iso8601",
+        AnyCalendarKind::Japanese | AnyCalendarKind::JapaneseExtended => "japanese",
+        AnyCalendarKind::Persian => "persian",
+        AnyCalendarKind::Roc => "roc",
+        _ => "gregory",
+    }
+}
+
+/// Gets the number of a day of the week, where 1 is Monday and 7 is Sunday.
+fn weekday_number(day: Weekday) -> u8 {
+    match day {
+        Weekday::Monday => 1,
+        Weekday::Tuesday => 2,
+        Weekday::Wednesday => 3,
+        Weekday::Thursday => 4,
+        Weekday::Friday => 5,
+        Weekday::Saturday => 6,
+        Weekday::Sunday => 7,
+    }
 }
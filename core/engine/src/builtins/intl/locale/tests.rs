@@ -1,3 +1,4 @@
+use boa_macros::js_str;
 use icu_decimal::provider::DecimalSymbolsV1;
 use icu_locale::{
     Locale, extensions_unicode_key as key, extensions_unicode_value as value, locale,
@@ -9,14 +10,17 @@ use icu_provider::{
     DryDataProvider,
     prelude::icu_locale_core::{LanguageIdentifier, extensions::unicode},
 };
+use indoc::indoc;
 
 use crate::{
+    JsNativeErrorKind, JsValue, TestAction,
     builtins::intl::{
         Service, ServicePreferences,
         locale::{default_locale, resolve_locale},
         options::{IntlOptions, LocaleMatcher},
     },
     context::icu::IntlProvider,
+    run_test_actions,
 };
 
 #[derive(Debug, Copy, Clone)]
@@ -144,3 +148,74 @@ fn locale_resolution() {
         resolve_locale::<TestService>([locale!("bn-Arab")], &mut options, &provider).unwrap();
     assert_eq!(locale, "bn-u-nu-beng".parse().unwrap());
 }
+
+#[test]
+fn locale_info() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.Locale('en-US').getCalendars().join()",
+            js_str!("gregory"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('th-TH').getCalendars().join()",
+            js_str!("buddhist"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('en-u-ca-hebrew').getCalendars().join()",
+            js_str!("hebrew"),
+        ),
+        TestAction::assert("new Intl.Locale('de').getCollations().includes('phonebk')"),
+        TestAction::assert("!new Intl.Locale('en').getCollations().includes('phonebk')"),
+        TestAction::assert_eq(
+            "new Intl.Locale('en-US').getHourCycles().join()",
+            js_str!("h12"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('en-GB').getHourCycles().join()",
+            js_str!("h23"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('ar-EG').getNumberingSystems().join()",
+            js_str!("arab"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('en', { numberingSystem: 'thai' }).getNumberingSystems().join()",
+            js_str!("thai"),
+        ),
+        TestAction::assert_eq("new Intl.Locale('en').getTimeZones()", JsValue::undefined()),
+        TestAction::assert_eq(
+            "new Intl.Locale('ja-JP').getTimeZones().join()",
+            js_str!("Asia/Tokyo"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('he').getTextInfo().direction",
+            js_str!("rtl"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('en').getTextInfo().direction",
+            js_str!("ltr"),
+        ),
+    ]);
+}
+
+#[test]
+fn week_info() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            const us = new Intl.Locale('en-US').getWeekInfo();
+            const gb = new Intl.Locale('en-GB').getWeekInfo();
+            const custom = new Intl.Locale('en-GB', { firstDayOfWeek: 3 });
+        "}),
+        TestAction::assert_eq("us.firstDay", 7),
+        TestAction::assert_eq("us.weekend.join()", js_str!("6,7")),
+        TestAction::assert_eq("gb.firstDay", 1),
+        TestAction::assert_eq("custom.firstDayOfWeek", js_str!("wed")),
+        TestAction::assert_eq("custom.toString()", js_str!("en-GB-u-fw-wed")),
+        TestAction::assert_eq("custom.getWeekInfo().firstDay", 3),
+        TestAction::assert_native_error(
+            "new Intl.Locale('en', { firstDayOfWeek: '' })",
+            JsNativeErrorKind::Range,
+            "nonterminal `type` must be at least 3 characters long",
+        ),
+    ]);
+}
@@ -17,7 +17,7 @@ use crate::{
     Context, JsArgs, JsData, JsResult, JsString, JsValue,
     builtins::{Array, BuiltInBuilder, BuiltInObject, IntrinsicObject},
     context::{icu::IntlProvider, intrinsics::Intrinsics},
-    js_string,
+    js_error, js_string,
     object::JsObject,
     property::Attribute,
     realm::Realm,
@@ -31,8 +31,10 @@ use icu_provider::{DataMarker, DataMarkerAttributes};
 use static_assertions::const_assert;
 
 pub(crate) use self::{
-    collator::Collator, date_time_format::DateTimeFormat, list_format::ListFormat, locale::Locale,
-    number_format::NumberFormat, plural_rules::PluralRules, segmenter::Segmenter,
+    collator::Collator, date_time_format::DateTimeFormat, display_names::DisplayNames,
+    duration_format::DurationFormat, list_format::ListFormat, locale::Locale,
+    number_format::NumberFormat, plural_rules::PluralRules,
+    relative_time_format::RelativeTimeFormat, segmenter::Segmenter,
 };
 
 /// Macro to easily implement `ServicePreferences`.
@@ -80,23 +82,33 @@ macro_rules! impl_service_preferences {
 
 pub(crate) mod collator;
 pub(crate) mod date_time_format;
+pub(crate) mod display_names;
+pub(crate) mod duration_format;
 pub(crate) mod list_format;
 pub(crate) mod locale;
 pub(crate) mod number_format;
 pub(crate) mod plural_rules;
+pub(crate) mod relative_time_format;
 pub(crate) mod segmenter;
 
 mod options;
+mod supported_values;
+
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
 
 // No singletons are allowed as lang markers.
 // Hopefully, we'll be able to migrate this to the definition of `Service` in the future
 // (https://github.com/rust-lang/rust/issues/76560)
 const_assert! {!<Collator as Service>::LangMarker::INFO.is_singleton}
+const_assert! {!<DisplayNames as Service>::LangMarker::INFO.is_singleton}
+const_assert! {!<DurationFormat as Service>::LangMarker::INFO.is_singleton}
 const_assert! {!<ListFormat as Service>::LangMarker::INFO.is_singleton}
 const_assert! {!<NumberFormat as Service>::LangMarker::INFO.is_singleton}
 const_assert! {!<PluralRules as Service>::LangMarker::INFO.is_singleton}
 const_assert! {!<Segmenter as Service>::LangMarker::INFO.is_singleton}
 const_assert! {!<DateTimeFormat as Service>::LangMarker::INFO.is_singleton}
+const_assert! {!<RelativeTimeFormat as Service>::LangMarker::INFO.is_singleton}
 
 /// JavaScript `Intl` object.
 #[derive(Debug, Clone, Trace, Finalize, JsData)]
@@ -131,6 +143,24 @@ impl IntrinsicObject for Intl {
                 realm.intrinsics().constructors().collator().constructor(),
                 Collator::ATTRIBUTE,
             )
+            .static_property(
+                DisplayNames::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .display_names()
+                    .constructor(),
+                DisplayNames::ATTRIBUTE,
+            )
+            .static_property(
+                DurationFormat::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .duration_format()
+                    .constructor(),
+                DurationFormat::ATTRIBUTE,
+            )
             .static_property(
                 ListFormat::NAME,
                 realm
@@ -177,11 +207,25 @@ impl IntrinsicObject for Intl {
                     .constructor(),
                 NumberFormat::ATTRIBUTE,
             )
+            .static_property(
+                RelativeTimeFormat::NAME,
+                realm
+                    .intrinsics()
+                    .constructors()
+                    .relative_time_format()
+                    .constructor(),
+                RelativeTimeFormat::ATTRIBUTE,
+            )
             .static_method(
                 Self::get_canonical_locales,
                 js_string!("getCanonicalLocales"),
                 1,
             )
+            .static_method(
+                Self::supported_values_of,
+                js_string!("supportedValuesOf"),
+                1,
+            )
             .build();
     }
 
@@ -221,6 +265,77 @@ impl Intl {
             context,
         )))
     }
+
+    /// `Intl.supportedValuesOf ( key )`
+    ///
+    /// Returns an array containing the calendars, collations, currencies, numbering systems,
+    /// time zones or units supported by the implementation.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN docs][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.supportedvaluesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/supportedValuesOf
+    pub(crate) fn supported_values_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let key be ? ToString(key).
+        let key = args.get_or_undefined(0).to_string(context)?;
+
+        let provider = context.intl_provider();
+        let list: Vec<JsValue> = match key.to_std_string_escaped().as_str() {
+            // 2. If key is "calendar", then
+            //     a. Let list be AvailableCanonicalCalendars().
+            "calendar" => supported_values::available_calendars(provider)
+                .into_iter()
+                .map(|ca| js_string!(ca).into())
+                .collect(),
+            // 3. Else if key is "collation", then
+            //     a. Let list be AvailableCanonicalCollations().
+            "collation" => supported_values::available_collations(provider)
+                .into_iter()
+                .map(|co| js_string!(co).into())
+                .collect(),
+            // 4. Else if key is "currency", then
+            //     a. Let list be AvailableCanonicalCurrencies().
+            "currency" => supported_values::available_currencies()
+                .iter()
+                .map(|&cu| js_string!(cu).into())
+                .collect(),
+            // 5. Else if key is "numberingSystem", then
+            //     a. Let list be AvailableCanonicalNumberingSystems().
+            "numberingSystem" => supported_values::available_numbering_systems(provider)
+                .into_iter()
+                .map(|nu| js_string!(nu).into())
+                .collect(),
+            // 6. Else if key is "timeZone", then
+            //     a. Let list be AvailablePrimaryTimeZoneIdentifiers().
+            "timeZone" => supported_values::available_time_zones(None, provider)?
+                .into_iter()
+                .map(|tz| js_string!(tz).into())
+                .collect(),
+            // 7. Else if key is "unit", then
+            //     a. Let list be AvailableCanonicalUnits().
+            "unit" => number_format::SANCTIONED_UNITS
+                .iter()
+                .map(|&unit| js_string!(unit).into())
+                .collect(),
+            // 8. Else,
+            //     a. Throw a RangeError exception.
+            key => {
+                return Err(js_error!(
+                    RangeError: "`{}` is not a supported key for `Intl.supportedValuesOf`",
+                    key
+                ));
+            }
+        };
+
+        // 9. Return CreateArrayFromList( list ).
+        Ok(JsValue::new(Array::create_array_from_list(list, context)))
+    }
 }
 
 fn get_kv_from_pref<T: icu_locale::preferences::PreferenceKey>(
@@ -1,20 +1,39 @@
 use std::cell::Cell;
 
 use boa_gc::{Finalize, Trace};
-use fixed_decimal::{Decimal, FloatPrecision, SignDisplay};
+use fixed_decimal::{Decimal, FloatPrecision, Sign, SignDisplay};
 use icu_decimal::{
-    DecimalFormatter, DecimalFormatterPreferences, FormattedDecimal,
+    DecimalFormatter, DecimalFormatterPreferences,
     options::{DecimalFormatterOptions, GroupingStrategy},
     preferences::NumberingSystem,
     provider::{DecimalDigitsV1, DecimalSymbolsV1},
 };
+use icu_experimental::{
+    compactdecimal::{CompactDecimalFormatter, CompactDecimalFormatterOptions},
+    dimension::{
+        currency::{
+            CurrencyCode,
+            formatter::CurrencyFormatter,
+            long_formatter::LongCurrencyFormatter,
+            options::{CurrencyFormatterOptions, Width as CurrencyWidth},
+        },
+        percent::{formatter::PercentFormatter, options::PercentFormatterOptions},
+        units::{
+            formatter::UnitsFormatter,
+            options::{UnitsFormatterOptions, Width as UnitsWidth},
+        },
+    },
+};
 
 mod options;
 use icu_locale::{Locale, extensions::unicode::Value};
-use icu_provider::{DataMarker, DataMarkerAttributes, DynamicDataProvider, buf::BufferMarker};
+use icu_provider::{
+    DataError, DataMarker, DataMarkerAttributes, DynamicDataProvider, buf::BufferMarker,
+};
 use num_bigint::BigInt;
 use num_traits::Num;
 pub(crate) use options::*;
+use writeable::{PartsWrite, Writeable};
 
 use super::{
     Service,
@@ -22,13 +41,16 @@ use super::{
     options::{IntlOptions, coerce_options_to_object},
 };
 use crate::{
-    Context, JsArgs, JsData, JsNativeError, JsObject, JsResult, JsString, JsSymbol, JsValue,
-    NativeFunction,
+    Context, JsArgs, JsData, JsExpect, JsNativeError, JsObject, JsResult, JsString, JsSymbol,
+    JsValue, NativeFunction,
     builtins::{
-        BuiltInConstructor, BuiltInObject, IntrinsicObject, builder::BuiltInBuilder,
-        options::get_option,
+        Array, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+        builder::BuiltInBuilder, options::get_option,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     },
-    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
     object::{
         FunctionObjectBuilder, JsFunction, ObjectInitializer,
@@ -50,6 +72,8 @@ mod tests;
 pub(crate) struct NumberFormat {
     locale: Locale,
     formatter: DecimalFormatter,
+    style_formatter: StyleFormatter,
+    compact_formatter: Option<CompactDecimalFormatter>,
     numbering_system: NumberingSystem,
     unit_options: UnitFormatOptions,
     digit_options: DigitFormatOptions,
@@ -59,22 +83,626 @@ pub(crate) struct NumberFormat {
     bound_format: Option<JsFunction>,
 }
 
+/// The formatter used to display the style of a `NumberFormat`.
+enum StyleFormatter {
+    /// Plain numbers, which are formatted by the base `DecimalFormatter`.
+    Decimal,
+    Percent(Box<PercentFormatter<DecimalFormatter>>),
+    Currency {
+        formatter: Box<CurrencyFormatter>,
+        code: CurrencyCode,
+    },
+    CurrencyName {
+        formatter: Box<LongCurrencyFormatter>,
+        code: CurrencyCode,
+    },
+    /// Currencies displayed with their ISO 4217 code, which are not supported by ICU4X. They
+    /// are formatted with the symbol pattern of the locale, replacing the symbol with the code.
+    CurrencyCode {
+        formatter: Box<CurrencyFormatter>,
+        code: CurrencyCode,
+    },
+    Unit(Box<UnitsFormatter>),
+}
+
+impl std::fmt::Debug for StyleFormatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The ICU4X formatters don't implement `Debug`, so only the variant is printed.
+        match self {
+            Self::Decimal => f.write_str("Decimal"),
+            Self::Percent(_) => f.debug_tuple("Percent").finish_non_exhaustive(),
+            Self::Currency { code, .. } => f
+                .debug_struct("Currency")
+                .field("code", &code.0)
+                .finish_non_exhaustive(),
+            Self::CurrencyName { code, .. } => f
+                .debug_struct("CurrencyName")
+                .field("code", &code.0)
+                .finish_non_exhaustive(),
+            Self::CurrencyCode { code, .. } => f
+                .debug_struct("CurrencyCode")
+                .field("code", &code.0)
+                .finish_non_exhaustive(),
+            Self::Unit(_) => f.debug_tuple("Unit").finish_non_exhaustive(),
+        }
+    }
+}
+
+impl StyleFormatter {
+    /// Creates the formatter for the style options of a `NumberFormat`.
+    fn new(
+        locale: &Locale,
+        unit_options: &UnitFormatOptions,
+        provider: &IntlProvider,
+    ) -> JsResult<Self> {
+        fn map_err(err: DataError) -> JsNativeError {
+            JsNativeError::typ().with_message(err.to_string())
+        }
+
+        let provider = provider.erased_provider();
+
+        let formatter = match unit_options {
+            UnitFormatOptions::Decimal => Self::Decimal,
+            UnitFormatOptions::Percent => Self::Percent(Box::new(
+                PercentFormatter::try_new_with_buffer_provider(
+                    provider,
+                    locale.into(),
+                    PercentFormatterOptions::default(),
+                )
+                .map_err(map_err)?,
+            )),
+            UnitFormatOptions::Currency {
+                currency, display, ..
+            } => match display {
+                CurrencyDisplay::Code => Self::CurrencyCode {
+                    formatter: Box::new(
+                        CurrencyFormatter::try_new_with_buffer_provider(
+                            provider,
+                            locale.into(),
+                            CurrencyFormatterOptions::default(),
+                        )
+                        .map_err(map_err)?,
+                    ),
+                    code: currency.code(),
+                },
+                CurrencyDisplay::Name => Self::CurrencyName {
+                    formatter: Box::new(
+                        LongCurrencyFormatter::try_new_with_buffer_provider(
+                            provider,
+                            locale.into(),
+                            &currency.code(),
+                        )
+                        .map_err(map_err)?,
+                    ),
+                    code: currency.code(),
+                },
+                CurrencyDisplay::Symbol | CurrencyDisplay::NarrowSymbol => {
+                    let mut options = CurrencyFormatterOptions::default();
+                    options.width = if *display == CurrencyDisplay::NarrowSymbol {
+                        CurrencyWidth::Narrow
+                    } else {
+                        CurrencyWidth::Short
+                    };
+
+                    Self::Currency {
+                        formatter: Box::new(
+                            CurrencyFormatter::try_new_with_buffer_provider(
+                                provider,
+                                locale.into(),
+                                options,
+                            )
+                            .map_err(map_err)?,
+                        ),
+                        code: currency.code(),
+                    }
+                }
+            },
+            UnitFormatOptions::Unit { unit, display } => {
+                let mut options = UnitsFormatterOptions::default();
+                options.width = match display {
+                    UnitDisplay::Short => UnitsWidth::Short,
+                    UnitDisplay::Narrow => UnitsWidth::Narrow,
+                    UnitDisplay::Long => UnitsWidth::Long,
+                };
+
+                Self::Unit(Box::new(
+                    UnitsFormatter::try_new_with_buffer_provider(
+                        provider,
+                        locale.into(),
+                        &unit.identifier(),
+                        options,
+                    )
+                    .map_err(map_err)?,
+                ))
+            }
+        };
+
+        Ok(formatter)
+    }
+}
+
+/// A part of a formatted number, as returned by [`NumberFormat::partition_number_pattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NumberPart {
+    pub(crate) typ: &'static str,
+    pub(crate) value: String,
+}
+
 impl NumberFormat {
-    /// [`FormatNumeric ( numberFormat, x )`][full] and [`FormatNumericToParts ( numberFormat, x )`][parts].
+    /// Abstract operation [`FormatNumeric ( numberFormat, x )`][spec].
     ///
-    /// The returned struct implements `Writable`, allowing to either write the number as a full
-    /// string or by parts.
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumber
+    pub(crate) fn format(&self, value: &mut Decimal) -> JsResult<String> {
+        // 1. Let parts be PartitionNumberPattern(numberFormat, x).
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        Ok(self
+            .partition_number_pattern(value)?
+            .into_iter()
+            .map(|part| part.value)
+            .collect())
+    }
+
+    /// Abstract operation [`PartitionNumberPattern ( numberFormat, x )`][spec].
     ///
-    /// [full]: https://tc39.es/ecma402/#sec-formatnumber
-    /// [parts]: https://tc39.es/ecma402/#sec-formatnumbertoparts
-    pub(crate) fn format<'a>(&'a self, value: &'a mut Decimal) -> FormattedDecimal<'a> {
-        // TODO: Missing support from ICU4X for Percent/Currency/Unit formatting.
-        // TODO: Missing support from ICU4X for Scientific/Engineering/Compact notation.
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberpattern
+    pub(crate) fn partition_number_pattern(
+        &self,
+        value: &mut Decimal,
+    ) -> JsResult<Vec<NumberPart>> {
+        // TODO: Add support in `Decimal` for infinity and NaN, which are handled
+        // in steps 1 to 3.
+
+        // 4. Else,
+        //     a. If x is not one of positive-infinity, negative-infinity, then
+        //         i. If numberFormat.[[Style]] is "percent", let x be 100 × x.
+        if self.unit_options.style() == Style::Percent {
+            value.multiply_pow10(2);
+        }
 
+        //         ii. Let exponent and magnitude be ComputeExponent(numberFormat, x).
+        let exponent = self.compute_exponent(value);
+
+        //         iii. Let x be x × 10**-exponent.
+        value.multiply_pow10(-exponent);
+
+        //     b. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+        //     c. Let n be formatNumberResult.[[FormattedString]].
+        //     d. Let x be formatNumberResult.[[RoundedNumber]].
         self.digit_options.format_fixed_decimal(value);
+
+        // 5. Let pattern be GetNumberFormatPattern(numberFormat, x).
         value.apply_sign_display(self.sign_display);
 
-        self.formatter.format(value)
+        // 6. Let result be a new empty List.
+        // 7. Let patternParts be PartitionPattern(pattern).
+        // 8. For each Record { [[Type]], [[Value]] } patternPart of patternParts, do
+        //     a-r. Append the parts of the pattern, the number and the notation to result.
+        let compact = match (self.notation, &self.compact_formatter) {
+            (Notation::Compact { .. }, Some(compact)) => {
+                value.multiply_pow10(exponent);
+                let parts = collect_parts(&compact.format_fixed_decimal(value), "compact")?;
+                if matches!(self.style_formatter, StyleFormatter::Decimal) {
+                    return Ok(parts);
+                }
+                Some(parts)
+            }
+            _ => None,
+        };
+
+        let mut parts = match &self.style_formatter {
+            StyleFormatter::Decimal => collect_parts(&self.formatter.format(value), "literal")?,
+            StyleFormatter::Percent(formatter) => self.collect_pattern_parts(
+                value,
+                |abs| formatter.format(abs).to_string(),
+                "percentSign",
+            )?,
+            StyleFormatter::Currency { formatter, code } => self.collect_pattern_parts(
+                value,
+                |abs| formatter.format_fixed_decimal(abs, *code).to_string(),
+                "currency",
+            )?,
+            StyleFormatter::CurrencyName { formatter, code } => self.collect_pattern_parts(
+                value,
+                |abs| formatter.format_fixed_decimal(abs, *code).to_string(),
+                "currency",
+            )?,
+            StyleFormatter::CurrencyCode { formatter, code } => {
+                let mut parts = self.collect_pattern_parts(
+                    value,
+                    |abs| formatter.format_fixed_decimal(abs, *code).to_string(),
+                    "currency",
+                )?;
+                replace_currency_symbol(&mut parts, *code);
+                parts
+            }
+            StyleFormatter::Unit(formatter) => {
+                collect_parts(&formatter.format_fixed_decimal(value), "unit")?
+            }
+        };
+
+        // ICU4X only supports compact notation for plain numbers, so the number of the pattern
+        // of the other styles is replaced with the compact number.
+        if let Some(compact) = compact {
+            replace_number(&mut parts, compact);
+        }
+
+        // ICU4X doesn't have the accounting patterns of the locales, so this uses the pattern
+        // of most CLDR locales, which wraps negative amounts in parentheses instead of showing
+        // a minus sign.
+        if let UnitFormatOptions::Currency {
+            display: CurrencyDisplay::Code | CurrencyDisplay::Symbol | CurrencyDisplay::NarrowSymbol,
+            sign: CurrencySign::Accounting,
+            ..
+        } = self.unit_options
+            && let Some(index) = parts.iter().position(|part| part.typ == "minusSign")
+        {
+            parts.remove(index);
+            parts.insert(
+                0,
+                NumberPart {
+                    typ: "literal",
+                    value: String::from("("),
+                },
+            );
+            parts.push(NumberPart {
+                typ: "literal",
+                value: String::from(")"),
+            });
+        }
+
+        if matches!(self.notation, Notation::Scientific | Notation::Engineering) {
+            // i. Else if p is equal to "scientificSeparator", then
+            //     1. Let scientificSeparator be the ILND String representing the exponent separator.
+            //     2. Append the Record { [[Type]]: "exponentSeparator", [[Value]]: scientificSeparator } to result.
+            // ii. Else if p is equal to "scientificExponent", then
+            //     1. If exponent < 0, then
+            //         a. Let minusSignSymbol be the ILD String representing the minus sign.
+            //         b. Append the Record { [[Type]]: "exponentMinusSign", [[Value]]: minusSignSymbol } to result.
+            //         c. Let exponent be -exponent.
+            //     2. Let exponentResult be ToRawFixed(exponent, 0, 0, 1, undefined).
+            //     3. Append the Record { [[Type]]: "exponentInteger", [[Value]]: exponentResult.[[FormattedString]] } to result.
+            // TODO: use the exponent separator of the locale.
+            let mut exponent_parts = vec![NumberPart {
+                typ: "exponentSeparator",
+                value: String::from("E"),
+            }];
+            if exponent < 0 {
+                exponent_parts.push(NumberPart {
+                    typ: "exponentMinusSign",
+                    value: String::from("-"),
+                });
+            }
+            exponent_parts.push(NumberPart {
+                typ: "exponentInteger",
+                value: self
+                    .formatter
+                    .format(&Decimal::from(exponent.unsigned_abs()))
+                    .to_string(),
+            });
+
+            let index = parts
+                .iter()
+                .rposition(|part| matches!(part.typ, "integer" | "fraction" | "decimal"))
+                .map_or(parts.len(), |i| i + 1);
+            let tail = parts.split_off(index);
+            parts.extend(exponent_parts);
+            parts.extend(tail);
+        }
+
+        // 9. Return result.
+        Ok(parts)
+    }
+
+    /// Collects the parts of `value` formatted with the pattern of a percent or currency style.
+    ///
+    /// The formatters of ICU4X for these styles only write plain text, and the currency
+    /// formatters put the sign of negative amounts between the symbol and the number. This
+    /// formats the absolute value with `format`, splits the text around the digits of the number,
+    /// and puts the sign in front of the pattern, like most CLDR locales do.
+    fn collect_pattern_parts(
+        &self,
+        value: &Decimal,
+        format: impl FnOnce(&Decimal) -> String,
+        affix_type: &'static str,
+    ) -> JsResult<Vec<NumberPart>> {
+        let mut parts = collect_parts(&self.formatter.format(value), "literal")?;
+        parts.retain(|part| matches!(part.typ, "minusSign" | "plusSign"));
+
+        let abs = value.clone().with_sign(Sign::None);
+        let number = self.formatter.format(&abs);
+        let number_text = number.to_string();
+        let text = format(&abs);
+        let Some(start) = text.find(&number_text) else {
+            push_affix(&mut parts, &text, affix_type);
+            return Ok(parts);
+        };
+
+        push_affix(&mut parts, &text[..start], affix_type);
+        parts.extend(collect_parts(&number, "literal")?);
+        push_affix(&mut parts, &text[start + number_text.len()..], affix_type);
+
+        Ok(parts)
+    }
+
+    /// Abstract operation [`ComputeExponent ( numberFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-computeexponent
+    fn compute_exponent(&self, value: &Decimal) -> i16 {
+        if self.notation == Notation::Standard {
+            return 0;
+        }
+
+        // 1. If x = 0, then
+        if value.absolute.is_zero() {
+            // a. Return [0, 0].
+            return 0;
+        }
+
+        // 2. If x < 0, then
+        //     a. Let x = -x.
+        // 3. Let magnitude be the base 10 logarithm of x rounded down to the nearest integer.
+        let magnitude = value.nonzero_magnitude_start();
+
+        // 4. Let exponent be ComputeExponentForMagnitude(numberFormat, magnitude).
+        let exponent = self.compute_exponent_for_magnitude(magnitude);
+
+        // 5. Let x be x × 10**-exponent.
+        let mut value = value.clone();
+        value.multiply_pow10(-exponent);
+
+        // 6. Let formatNumberResult be FormatNumericToString(numberFormat, x).
+        self.digit_options.format_fixed_decimal(&mut value);
+
+        // 7. If formatNumberResult.[[RoundedNumber]] = 0, then
+        //     a. Return [exponent, magnitude].
+        // 8. Let newMagnitude be the base 10 logarithm of formatNumberResult.[[RoundedNumber]] rounded down to the nearest integer.
+        // 9. If newMagnitude is magnitude - exponent, then
+        //     a. Return [exponent, magnitude].
+        if value.absolute.is_zero() || value.nonzero_magnitude_start() == magnitude - exponent {
+            return exponent;
+        }
+
+        // 10. Return [ComputeExponentForMagnitude(numberFormat, magnitude + 1), magnitude + 1].
+        self.compute_exponent_for_magnitude(magnitude + 1)
+    }
+
+    /// Abstract operation [`ComputeExponentForMagnitude ( numberFormat, magnitude )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-computeexponentformagnitude
+    fn compute_exponent_for_magnitude(&self, magnitude: i16) -> i16 {
+        match self.notation {
+            // 2. If notation is "scientific", then
+            //     a. Return magnitude.
+            Notation::Scientific => magnitude,
+            // 3. Else if notation is "engineering", then
+            //     a. Let thousands be the greatest integer that is not greater than magnitude / 3.
+            //     b. Return thousands × 3.
+            Notation::Engineering => magnitude.div_euclid(3) * 3,
+            // 4. Else if notation is "compact", then
+            //     a. Let exponent be an implementation- and locale-dependent (ILD) integer by which to scale a number of the given magnitude in compact notation for the current locale.
+            //     b. Return exponent.
+            Notation::Compact { .. } => self.compact_formatter.as_ref().map_or(0, |formatter| {
+                i16::from(formatter.compact_exponent_for_magnitude(magnitude))
+            }),
+            // 5. Else,
+            //     a. Assert: notation is "standard".
+            //     b. Return 0.
+            Notation::Standard => 0,
+        }
+    }
+
+    /// Abstract operation [`PartitionNumberRangePattern ( numberFormat, x, y )`][spec].
+    ///
+    /// Returns the parts of the formatted range, with the source of each part.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberrangepattern
+    fn partition_number_range_pattern(
+        &self,
+        x: &mut Decimal,
+        y: &mut Decimal,
+    ) -> JsResult<Vec<(NumberPart, &'static str)>> {
+        // 1. If x is NaN or y is NaN, throw a RangeError exception.
+        // `Decimal` cannot represent NaN, which is rejected by `ToIntlMathematicalValue`.
+
+        // 2. Let xResult be PartitionNumberPattern(numberFormat, x).
+        let x_result = self.partition_number_pattern(x)?;
+
+        // 3. Let yResult be PartitionNumberPattern(numberFormat, y).
+        let y_result = self.partition_number_pattern(y)?;
+
+        // 4. If FormatNumeric(numberFormat, x) is FormatNumeric(numberFormat, y), then
+        if x_result == y_result {
+            // a. Let appxResult be FormatApproximately(numberFormat, xResult).
+            // b. For each element r of appxResult, do
+            //     i. Set r.[[Source]] to "shared".
+            // c. Return appxResult.
+            return Ok(format_approximately(x_result)
+                .into_iter()
+                .map(|part| (part, "shared"))
+                .collect());
+        }
+
+        // 5. Let result be a new empty List.
+        // 6. For each element r of xResult, do
+        //     a. Append the Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "startRange" } to result.
+        let mut result = x_result
+            .into_iter()
+            .map(|part| (part, "startRange"))
+            .collect::<Vec<_>>();
+
+        // 7. Let rangeSeparator be an ILND String value used to separate two numbers.
+        // 8. Append the Record { [[Type]]: "literal", [[Value]]: rangeSeparator, [[Source]]: "shared" } to result.
+        // TODO: use the range pattern of the locale.
+        result.push((
+            NumberPart {
+                typ: "literal",
+                value: String::from("–"),
+            },
+            "shared",
+        ));
+
+        // 9. For each element r of yResult, do
+        //     a. Append the Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "endRange" } to result.
+        result.extend(y_result.into_iter().map(|part| (part, "endRange")));
+
+        // 10. Return CollapseNumberRange(numberFormat, result).
+        // The implementation of `CollapseNumberRange` is optional, and this returns `result` unmodified.
+        Ok(result)
+    }
+}
+
+/// Abstract operation [`FormatApproximately ( numberFormat, result )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-formatapproximately
+fn format_approximately(mut result: Vec<NumberPart>) -> Vec<NumberPart> {
+    // 1. Let approximatelySign be an ILND String value used to signify that a number is approximate.
+    // 2. If approximatelySign is not empty, insert the Record { [[Type]]: "approximatelySign", [[Value]]: approximatelySign } at an ILND index in result. For example, if numberFormat has [[Locale]] "en-US" and [[NumberingSystem]] "latn" and [[Style]] "decimal", the new Record might be inserted before the first element of result.
+    // TODO: use the approximately sign of the locale.
+    let index = result
+        .iter()
+        .position(|part| matches!(part.typ, "integer" | "minusSign" | "plusSign"))
+        .unwrap_or_default();
+    result.insert(
+        index,
+        NumberPart {
+            typ: "approximatelySign",
+            value: String::from("~"),
+        },
+    );
+
+    // 3. Return result.
+    result
+}
+
+/// Returns `true` if `part` is a part of the digits of a number, excluding its sign.
+fn is_number_part(part: &NumberPart) -> bool {
+    matches!(part.typ, "integer" | "group" | "decimal" | "fraction")
+}
+
+/// Replaces the digits of the number in `parts` with the digits and the compact affixes of
+/// `compact`, keeping the sign and the rest of the pattern of `parts`.
+fn replace_number(parts: &mut Vec<NumberPart>, mut compact: Vec<NumberPart>) {
+    compact.retain(|part| !matches!(part.typ, "minusSign" | "plusSign"));
+    let Some(start) = parts.iter().position(is_number_part) else {
+        return;
+    };
+    let end = parts
+        .iter()
+        .rposition(is_number_part)
+        .map_or(start, |end| end + 1);
+    parts.splice(start..end, compact);
+}
+
+/// Replaces the currency symbol in `parts` with the ISO 4217 `code` of the currency.
+///
+/// Like the currency spacing of CLDR, a no-break space separates the code from the number
+/// when the pattern puts them next to each other.
+fn replace_currency_symbol(parts: &mut Vec<NumberPart>, code: CurrencyCode) {
+    let Some(index) = parts.iter().position(|part| part.typ == "currency") else {
+        return;
+    };
+    parts[index].value = code.0.to_string();
+
+    let space = || NumberPart {
+        typ: "literal",
+        value: String::from('\u{a0}'),
+    };
+    if parts.get(index + 1).is_some_and(is_number_part) {
+        parts.insert(index + 1, space());
+    } else if index > 0 && is_number_part(&parts[index - 1]) {
+        parts.insert(index, space());
+    }
+}
+
+/// Collects the parts of a formatted number.
+///
+/// The text outside of the number is split into whitespace, which is returned as `literal`
+/// parts, and the remaining text, which is returned as parts of type `affix_type`.
+fn collect_parts(
+    formatted: &impl Writeable,
+    affix_type: &'static str,
+) -> JsResult<Vec<NumberPart>> {
+    let mut collector = PartsCollector(Vec::new());
+    formatted
+        .write_to_parts(&mut collector)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    let mut parts = Vec::with_capacity(collector.0.len());
+    for (typ, value) in collector.0 {
+        let Some(typ) = typ else {
+            push_affix(&mut parts, &value, affix_type);
+            continue;
+        };
+
+        parts.push(NumberPart { typ, value });
+    }
+
+    Ok(parts)
+}
+
+/// Pushes the text outside of a number to `parts`, splitting its whitespace into `literal`
+/// parts and the remaining text into a part of type `affix_type`.
+fn push_affix(parts: &mut Vec<NumberPart>, value: &str, affix_type: &'static str) {
+    let trimmed_start = value.trim_start();
+    let affix = trimmed_start.trim_end();
+    let leading = &value[..value.len() - trimmed_start.len()];
+    let trailing = &trimmed_start[affix.len()..];
+    for (typ, value) in [
+        ("literal", leading),
+        (affix_type, affix),
+        ("literal", trailing),
+    ] {
+        if !value.is_empty() {
+            parts.push(NumberPart {
+                typ,
+                value: value.to_owned(),
+            });
+        }
+    }
+}
+
+/// Collects the parts of the number and the surrounding text of a formatted number.
+#[derive(Debug)]
+struct PartsCollector(Vec<(Option<&'static str>, String)>);
+
+impl std::fmt::Write for PartsCollector {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        if let Some((None, value)) = self.0.last_mut() {
+            value.push_str(s);
+        } else {
+            self.0.push((None, s.to_owned()));
+        }
+        Ok(())
+    }
+}
+
+impl PartsWrite for PartsCollector {
+    type SubPartsWrite = Self;
+
+    fn with_part(
+        &mut self,
+        part: writeable::Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        if part.category != "decimal" {
+            return f(self);
+        }
+
+        // Nested parts (e.g. the group separators of the integer part) are kept as separate parts.
+        let mut sub = PartsCollector(Vec::new());
+        f(&mut sub)?;
+        self.0.extend(
+            sub.0
+                .into_iter()
+                .map(|(typ, value)| (Some(typ.unwrap_or(part.value)), value)),
+        );
+        Ok(())
     }
 }
 
@@ -107,6 +735,13 @@ impl IntrinsicObject for NumberFormat {
                 None,
                 Attribute::CONFIGURABLE,
             )
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::format_range, js_string!("formatRange"), 2)
+            .method(
+                Self::format_range_to_parts,
+                js_string!("formatRangeToParts"),
+                2,
+            )
             .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
             .build();
     }
@@ -122,7 +757,7 @@ impl BuiltInObject for NumberFormat {
 
 impl BuiltInConstructor for NumberFormat {
     const CONSTRUCTOR_ARGUMENTS: usize = 0;
-    const PROTOTYPE_STORAGE_SLOTS: usize = 4;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 7;
     const CONSTRUCTOR_STORAGE_SLOTS: usize = 1;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -277,29 +912,30 @@ impl NumberFormat {
 
         // 15. Let style be numberFormat.[[Style]].
         // 16. If style is "currency", then
-        let (min_fractional, max_fractional) = if unit_options.style() == Style::Currency {
-            // TODO: Missing support from ICU4X
-            // a. Let currency be numberFormat.[[Currency]].
-            // b. Let cDigits be CurrencyDigits(currency).
-            // c. Let mnfdDefault be cDigits.
-            // d. Let mxfdDefault be cDigits.
-            return Err(JsNativeError::typ().with_message("unimplemented").into());
-        } else {
-            // 17. Else,
-            (
-                // a. Let mnfdDefault be 0.
-                0,
-                // b. If style is "percent", then
-                if unit_options.style() == Style::Percent {
-                    // i. Let mxfdDefault be 0.
-                    0
-                } else {
-                    // c. Else,
-                    //    i. Let mxfdDefault be 3.
-                    3
-                },
-            )
-        };
+        let (min_fractional, max_fractional) =
+            if let UnitFormatOptions::Currency { currency, .. } = &unit_options {
+                // a. Let currency be numberFormat.[[Currency]].
+                // b. Let cDigits be CurrencyDigits(currency).
+                let c_digits = currency.digits();
+                // c. Let mnfdDefault be cDigits.
+                // d. Let mxfdDefault be cDigits.
+                (c_digits, c_digits)
+            } else {
+                // 17. Else,
+                (
+                    // a. Let mnfdDefault be 0.
+                    0,
+                    // b. If style is "percent", then
+                    if unit_options.style() == Style::Percent {
+                        // i. Let mxfdDefault be 0.
+                        0
+                    } else {
+                        // c. Else,
+                        //    i. Let mxfdDefault be 3.
+                        3
+                    },
+                )
+            };
 
         // 18. Let notation be ? GetOption(options, "notation", string, « "standard", "scientific", "engineering", "compact" », "standard").
         // 19. Set numberFormat.[[Notation]] to notation.
@@ -404,8 +1040,7 @@ impl NumberFormat {
                     &self,
                     marker: icu_provider::DataMarkerInfo,
                     req: icu_provider::DataRequest<'_>,
-                ) -> Result<icu_provider::DataResponse<BufferMarker>, icu_provider::DataError>
-                {
+                ) -> Result<icu_provider::DataResponse<BufferMarker>, DataError> {
                     if marker.id == DecimalDigitsV1::INFO.id {
                         self.nu.set(Some(req.id.marker_attributes.to_owned()));
                     }
@@ -438,10 +1073,37 @@ impl NumberFormat {
             (formatter, nu)
         };
 
+        let style_formatter = StyleFormatter::new(&locale, &unit_options, context.intl_provider())?;
+
+        let compact_formatter = match notation {
+            Notation::Compact { display } => {
+                let provider = context.intl_provider().erased_provider();
+                let prefs = (&locale).into();
+                let options = CompactDecimalFormatterOptions::default();
+                let formatter = match display {
+                    CompactDisplay::Short => {
+                        CompactDecimalFormatter::try_new_short_with_buffer_provider(
+                            provider, prefs, options,
+                        )
+                    }
+                    CompactDisplay::Long => {
+                        CompactDecimalFormatter::try_new_long_with_buffer_provider(
+                            provider, prefs, options,
+                        )
+                    }
+                }
+                .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+                Some(formatter)
+            }
+            _ => None,
+        };
+
         Ok(NumberFormat {
             locale,
             numbering_system,
             formatter,
+            style_formatter,
+            compact_formatter,
             unit_options,
             digit_options,
             notation,
@@ -512,7 +1174,7 @@ impl NumberFormat {
                         let mut x = to_intl_mathematical_value(value, context)?;
 
                         // 5. Return FormatNumeric(nf, x).
-                        Ok(js_string!(nf.borrow().data().format(&mut x).to_string()).into())
+                        Ok(js_string!(nf.borrow().data().format(&mut x)?).into())
                     },
                     nf_clone,
                 ),
@@ -528,6 +1190,154 @@ impl NumberFormat {
         Ok(bound_format.into())
     }
 
+    /// [`Intl.NumberFormat.prototype.formatToParts ( value )`][spec].
+    ///
+    /// Returns an array of objects representing the formatted number in parts that can be used
+    /// for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formattoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = this
+            .as_object()
+            .and_then(|o| o.downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`formatToParts` can only be called on a `NumberFormat` object")
+            })?;
+
+        // 3. Let x be ? ToIntlMathematicalValue(value).
+        let mut x = to_intl_mathematical_value(args.get_or_undefined(0), context)?;
+
+        // 4. Return FormatNumericToParts(nf, x).
+
+        // Abstract operation `FormatNumericToParts ( numberFormat, x )`
+        // https://tc39.es/ecma402/#sec-formatnumbertoparts
+
+        // 1. Let parts be PartitionNumberPattern(numberFormat, x).
+        let parts = nf.borrow().data().partition_number_pattern(&mut x)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .js_expect("creating an empty array with default proto must not fail")?;
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part in parts, do
+        for (n, part) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            let o = part_to_object(part, None, context)?;
+
+            // d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .js_expect("operation must not fail per the spec")?;
+
+            // e. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.NumberFormat.prototype.formatRange ( start, end )`][spec].
+    ///
+    /// Returns a string representing the range between two numbers.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        // 6. Return ? FormatNumericRange(nf, x, y).
+
+        // Abstract operation `FormatNumericRange ( numberFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatnumericrange
+
+        // 1. Let parts be ? PartitionNumberRangePattern(numberFormat, x, y).
+        let parts = number_range_parts(this, args, "formatRange", context)?;
+
+        // 2. Let result be the empty String.
+        // 3. For each part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        // 4. Return result.
+        let result = parts
+            .into_iter()
+            .map(|(part, _)| part.value)
+            .collect::<String>();
+
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.NumberFormat.prototype.formatRangeToParts ( start, end )`][spec].
+    ///
+    /// Returns an array of objects representing the formatted range in parts that can be used
+    /// for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrangetoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        // 6. Return ? FormatNumericRangeToParts(nf, x, y).
+
+        // Abstract operation `FormatNumericRangeToParts ( numberFormat, x, y )`
+        // https://tc39.es/ecma402/#sec-formatnumericrangetoparts
+
+        // 1. Let parts be ? PartitionNumberRangePattern(numberFormat, x, y).
+        let parts = number_range_parts(this, args, "formatRangeToParts", context)?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .js_expect("creating an empty array with default proto must not fail")?;
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Source]] } part in parts, do
+        for (n, (part, source)) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            // d. Perform ! CreateDataPropertyOrThrow(O, "source", part.[[Source]]).
+            let o = part_to_object(part, Some(source), context)?;
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .js_expect("operation must not fail per the spec")?;
+
+            // f. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
     /// [`Intl.NumberFormat.prototype.resolvedOptions ( )`][spec].
     ///
     /// Returns a new object with properties reflecting the locale and options computed during the
@@ -710,6 +1520,68 @@ impl NumberFormat {
     }
 }
 
+/// Gets the parts of the range between the `start` and `end` arguments, validating `this`
+/// and the arguments of `formatRange` and `formatRangeToParts`.
+fn number_range_parts(
+    this: &JsValue,
+    args: &[JsValue],
+    method: &str,
+    context: &mut Context,
+) -> JsResult<Vec<(NumberPart, &'static str)>> {
+    // 1. Let nf be the this value.
+    // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+    let nf = this
+        .as_object()
+        .and_then(|o| o.downcast::<NumberFormat>().ok())
+        .ok_or_else(|| {
+            JsNativeError::typ().with_message(format!(
+                "`{method}` can only be called on a `NumberFormat` object"
+            ))
+        })?;
+
+    let start = args.get_or_undefined(0);
+    let end = args.get_or_undefined(1);
+
+    // 3. If start is undefined or end is undefined, throw a TypeError exception.
+    if start.is_undefined() || end.is_undefined() {
+        return Err(js_error!(TypeError: "`{}` requires both a start and an end value", method));
+    }
+
+    // 4. Let x be ? ToIntlMathematicalValue(start).
+    let mut x = to_intl_mathematical_value(start, context)?;
+
+    // 5. Let y be ? ToIntlMathematicalValue(end).
+    let mut y = to_intl_mathematical_value(end, context)?;
+
+    nf.borrow()
+        .data()
+        .partition_number_range_pattern(&mut x, &mut y)
+}
+
+/// Creates the object of a part returned by `formatToParts` and `formatRangeToParts`.
+fn part_to_object(
+    part: NumberPart,
+    source: Option<&'static str>,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    let o = context
+        .intrinsics()
+        .templates()
+        .ordinary_object()
+        .create(OrdinaryObject, vec![]);
+
+    o.create_data_property_or_throw(js_string!("type"), js_string!(part.typ), context)
+        .js_expect("operation must not fail per the spec")?;
+    o.create_data_property_or_throw(js_string!("value"), js_string!(part.value), context)
+        .js_expect("operation must not fail per the spec")?;
+    if let Some(source) = source {
+        o.create_data_property_or_throw(js_string!("source"), js_string!(source), context)
+            .js_expect("operation must not fail per the spec")?;
+    }
+
+    Ok(o)
+}
+
 /// Abstract operation [`UnwrapNumberFormat ( nf )`][spec].
 ///
 /// This also checks that the returned object is a `NumberFormat`, which skips the
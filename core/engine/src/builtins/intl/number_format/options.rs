@@ -9,6 +9,7 @@ use boa_macros::js_str;
 use icu_decimal::{
     DecimalFormatterPreferences, preferences::NumberingSystem, provider::DecimalSymbolsV1,
 };
+use icu_experimental::dimension::currency::CurrencyCode;
 use icu_locale::extensions::unicode::Value;
 use icu_provider::{
     DataMarkerAttributes,
@@ -244,6 +245,31 @@ pub(crate) struct Currency {
 }
 
 impl Currency {
+    pub(crate) fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    /// Gets the ICU4X currency code of this currency.
+    pub(crate) fn code(self) -> CurrencyCode {
+        CurrencyCode(self.inner)
+    }
+
+    /// Abstract operation [`CurrencyDigits ( currency )`][spec].
+    ///
+    /// Returns the number of minor unit digits of this currency, as listed in the ISO 4217
+    /// standard, or 2 if the currency is not listed.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-currencydigits
+    pub(crate) fn digits(self) -> u8 {
+        match self.as_str() {
+            "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF"
+            | "UGX" | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+            "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+            "CLF" | "UYW" => 4,
+            _ => 2,
+        }
+    }
+
     pub(crate) fn to_js_string(self) -> JsString {
         let bytes = self.inner.as_bytes();
         js_string!(&[
@@ -295,6 +321,57 @@ impl std::str::FromStr for Currency {
 
 impl ParsableOptionType for Currency {}
 
+/// The simple unit identifiers sanctioned by the specification, sorted in lexicographic order.
+///
+/// See <https://tc39.es/ecma402/#table-sanctioned-single-unit-identifiers>.
+pub(crate) const SANCTIONED_UNITS: [&str; 45] = [
+    "acre",
+    "bit",
+    "byte",
+    "celsius",
+    "centimeter",
+    "day",
+    "degree",
+    "fahrenheit",
+    "fluid-ounce",
+    "foot",
+    "gallon",
+    "gigabit",
+    "gigabyte",
+    "gram",
+    "hectare",
+    "hour",
+    "inch",
+    "kilobit",
+    "kilobyte",
+    "kilogram",
+    "kilometer",
+    "liter",
+    "megabit",
+    "megabyte",
+    "meter",
+    "microsecond",
+    "mile",
+    "mile-scandinavian",
+    "milliliter",
+    "millimeter",
+    "millisecond",
+    "minute",
+    "month",
+    "nanosecond",
+    "ounce",
+    "percent",
+    "petabyte",
+    "pound",
+    "second",
+    "stone",
+    "terabit",
+    "terabyte",
+    "week",
+    "yard",
+    "year",
+];
+
 #[derive(Debug, Eq, PartialEq)]
 pub(crate) struct Unit {
     // INVARIANT: `numerator` must only contain ASCII lowercase alphabetic letters or `-`.
@@ -304,6 +381,11 @@ pub(crate) struct Unit {
 }
 
 impl Unit {
+    /// Gets the core unit identifier of this unit.
+    pub(crate) fn identifier(&self) -> String {
+        self.to_js_string().to_std_string_escaped()
+    }
+
     /// Gets the corresponding `JsString` of this unit.
     pub(crate) fn to_js_string(&self) -> JsString {
         if self.denominator.is_empty() {
@@ -332,54 +414,6 @@ impl std::str::FromStr for Unit {
     ///
     /// [spec]: https://tc39.es/ecma402/#sec-iswellformedunitidentifier
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        const SANCTIONED_UNITS: [&str; 45] = [
-            "acre",
-            "bit",
-            "byte",
-            "celsius",
-            "centimeter",
-            "day",
-            "degree",
-            "fahrenheit",
-            "fluid-ounce",
-            "foot",
-            "gallon",
-            "gigabit",
-            "gigabyte",
-            "gram",
-            "hectare",
-            "hour",
-            "inch",
-            "kilobit",
-            "kilobyte",
-            "kilogram",
-            "kilometer",
-            "liter",
-            "megabit",
-            "megabyte",
-            "meter",
-            "microsecond",
-            "mile",
-            "mile-scandinavian",
-            "milliliter",
-            "millimeter",
-            "millisecond",
-            "minute",
-            "month",
-            "nanosecond",
-            "ounce",
-            "percent",
-            "petabyte",
-            "pound",
-            "second",
-            "stone",
-            "terabit",
-            "terabyte",
-            "week",
-            "yard",
-            "year",
-        ];
-
         let (num, den) = s
             .split_once("-per-")
             .filter(|(_, den)| !den.is_empty())
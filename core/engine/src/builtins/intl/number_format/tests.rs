@@ -39,3 +39,128 @@ fn u16_to_rounding_increment_rainy_day() {
         assert!(RoundingIncrement::from_u16(num).is_none());
     }
 }
+
+#[cfg(feature = "intl_bundled")]
+mod format {
+    use boa_macros::js_str;
+    use indoc::indoc;
+
+    use crate::{JsNativeErrorKind, TestAction, run_test_actions};
+
+    #[test]
+    fn styles() {
+        run_test_actions([
+            TestAction::assert_eq(
+                "new Intl.NumberFormat('en', { style: 'percent' }).format(0.256)",
+                js_str!("26%"),
+            ),
+            TestAction::assert_eq(
+                "new Intl.NumberFormat('en', { style: 'currency', currency: 'USD' }).format(1234.5)",
+                js_str!("$1,234.50"),
+            ),
+            TestAction::assert_eq(
+                "new Intl.NumberFormat('en', { style: 'currency', currency: 'JPY' }).format(1234.5)",
+                js_str!("¥1,235"),
+            ),
+            TestAction::assert_eq(
+                indoc! {"
+                    new Intl.NumberFormat('en', {
+                        style: 'currency',
+                        currency: 'EUR',
+                        currencyDisplay: 'code',
+                    }).format(3)
+                "},
+                js_str!("EUR\u{a0}3.00"),
+            ),
+            TestAction::assert_eq(
+                indoc! {"
+                    new Intl.NumberFormat('en', {
+                        style: 'currency',
+                        currency: 'USD',
+                        currencySign: 'accounting',
+                    }).format(-3)
+                "},
+                js_str!("($3.00)"),
+            ),
+            TestAction::assert_eq(
+                "new Intl.NumberFormat('en', { style: 'unit', unit: 'kilometer' }).format(5)",
+                js_str!("5 km"),
+            ),
+            TestAction::assert_eq(
+                indoc! {"
+                    new Intl.NumberFormat('en', { style: 'unit', unit: 'kilometer', unitDisplay: 'long' })
+                        .format(5)
+                "},
+                js_str!("5 kilometers"),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn notations() {
+        run_test_actions([
+            TestAction::assert_eq(
+                "new Intl.NumberFormat('en', { notation: 'compact' }).format(1234567)",
+                js_str!("1.2M"),
+            ),
+            TestAction::assert_eq(
+                "new Intl.NumberFormat('en', { notation: 'compact', compactDisplay: 'long' }).format(1234567)",
+                js_str!("1.2 million"),
+            ),
+            TestAction::assert_eq(
+                indoc! {"
+                    new Intl.NumberFormat('en', {
+                        style: 'currency',
+                        currency: 'USD',
+                        notation: 'compact',
+                    }).format(1234567)
+                "},
+                js_str!("$1.2M"),
+            ),
+            TestAction::assert_eq(
+                "new Intl.NumberFormat('en', { notation: 'scientific' }).format(123456)",
+                js_str!("1.235E5"),
+            ),
+            TestAction::assert_eq(
+                "new Intl.NumberFormat('en', { notation: 'engineering' }).format(0.00123)",
+                js_str!("1.23E-3"),
+            ),
+        ]);
+    }
+
+    #[test]
+    fn format_to_parts() {
+        run_test_actions([
+            TestAction::run(indoc! {"
+                const parts = new Intl.NumberFormat('en', { style: 'currency', currency: 'USD' })
+                    .formatToParts(-1234.5);
+                const types = parts.map(part => part.type).join();
+                const values = parts.map(part => part.value).join('|');
+            "}),
+            TestAction::assert_eq(
+                "types",
+                js_str!("minusSign,currency,integer,group,integer,decimal,fraction"),
+            ),
+            TestAction::assert_eq("values", js_str!("-|$|1|,|234|.|50")),
+        ]);
+    }
+
+    #[test]
+    fn format_range() {
+        run_test_actions([
+            TestAction::run("const nf = new Intl.NumberFormat('en');"),
+            TestAction::assert_eq("nf.formatRange(3, 5)", js_str!("3–5")),
+            TestAction::assert_eq("nf.formatRange(2.9999, 3)", js_str!("~3")),
+            TestAction::run("const parts = nf.formatRangeToParts(3, 5);"),
+            TestAction::assert_eq("parts.length", 3),
+            TestAction::assert_eq("parts[0].source", js_str!("startRange")),
+            TestAction::assert_eq("parts[1].source", js_str!("shared")),
+            TestAction::assert_eq("parts[2].source", js_str!("endRange")),
+            TestAction::assert_native_error(
+                "nf.formatRange(3)",
+                JsNativeErrorKind::Type,
+                "`formatRange` requires both a start and an end value",
+            ),
+        ]);
+    }
+}
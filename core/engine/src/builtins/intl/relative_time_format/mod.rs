@@ -0,0 +1,578 @@
+use std::fmt::Write;
+
+use boa_gc::{Finalize, Trace};
+use fixed_decimal::{Decimal, FloatPrecision};
+use icu_decimal::{
+    DecimalFormatterPreferences, preferences::NumberingSystem, provider::DecimalSymbolsV1,
+};
+use icu_experimental::relativetime::{
+    RelativeTimeFormatter, RelativeTimeFormatterOptions, RelativeTimeFormatterPreferences,
+    options::Numeric, provider::LongSecondRelativeV1,
+};
+use icu_locale::{Locale, extensions::unicode::Value};
+use icu_provider::{
+    DataIdentifierBorrowed, DataLocale, DataProvider, DataRequest, DataRequestMetadata,
+};
+
+use crate::{
+    Context, JsArgs, JsData, JsExpect, JsNativeError, JsResult, JsString, JsValue,
+    builtins::{
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+        options::{OptionType, get_option},
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_error, js_string,
+    object::{JsObject, internal_methods::get_prototype_from_constructor},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+};
+
+use super::{
+    Service,
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    options::{IntlOptions, coerce_options_to_object},
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `RelativeTimeFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct RelativeTimeFormat {
+    locale: Locale,
+    numbering_system: NumberingSystem,
+    style: Style,
+    numeric: Numeric,
+    prefs: RelativeTimeFormatterPreferences,
+}
+
+impl Service for RelativeTimeFormat {
+    type LangMarker = LongSecondRelativeV1;
+
+    type Preferences = DecimalFormatterPreferences;
+}
+
+impl IntrinsicObject for RelativeTimeFormat {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.RelativeTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 2)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 2)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for RelativeTimeFormat {
+    const NAME: JsString = StaticJsStrings::RELATIVE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for RelativeTimeFormat {
+    const CONSTRUCTOR_ARGUMENTS: usize = 0;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 4;
+    const CONSTRUCTOR_STORAGE_SLOTS: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::relative_time_format;
+
+    /// Constructor [`Intl.RelativeTimeFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `RelativeTimeFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.RelativeTimeFormat` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 3. Return ? InitializeRelativeTimeFormat(relativeTimeFormat, locales, options).
+
+        // `InitializeRelativeTimeFormat ( relativeTimeFormat, locales, options )`
+        // https://tc39.es/ecma402/#sec-InitializeRelativeTimeFormat
+
+        // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 2. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 3. Let opt be a new Record.
+        // 4. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 5. Set opt.[[LocaleMatcher]] to matcher.
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 8. Set opt.[[nu]] to numberingSystem.
+        let numbering_system =
+            get_option::<NumberingSystem>(&options, js_string!("numberingSystem"), context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            preferences: {
+                let mut prefs = DecimalFormatterPreferences::default();
+                prefs.numbering_system = numbering_system;
+                prefs
+            },
+        };
+
+        // 9. Let r be ResolveLocale(%Intl.RelativeTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.RelativeTimeFormat%.[[RelevantExtensionKeys]], %Intl.RelativeTimeFormat%.[[LocaleData]]).
+        // 10. Let locale be r.[[Locale]].
+        // 11. Set relativeTimeFormat.[[Locale]] to locale.
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // 12. Set relativeTimeFormat.[[LocaleData]] to r.[[LocaleData]].
+        // 13. Set relativeTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let numbering_system = match intl_options.preferences.numbering_system {
+            Some(nu) => nu,
+            None => default_numbering_system(&locale, context.intl_provider())?,
+        };
+
+        // 14. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow" », "long").
+        // 15. Set relativeTimeFormat.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 16. Let numeric be ? GetOption(options, "numeric", string, « "always", "auto" », "always").
+        // 17. Set relativeTimeFormat.[[Numeric]] to numeric.
+        let numeric =
+            get_option(&options, js_string!("numeric"), context)?.unwrap_or(Numeric::Always);
+
+        // 18. Let relativeTimeFormat.[[NumberFormat]] be ! Construct(%Intl.NumberFormat%, « locale »).
+        // 19. Let relativeTimeFormat.[[PluralRules]] be ! Construct(%Intl.PluralRules%, « locale »).
+        // ICU4X handles both the number formatting and the plural rules selection internally.
+        let mut prefs = RelativeTimeFormatterPreferences::from(&locale);
+        prefs.numbering_system = Some(numbering_system);
+
+        // 2. Let relativeTimeFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.RelativeTimeFormat.prototype%", « [[InitializedRelativeTimeFormat]], [[Locale]], [[LocaleData]], [[Style]], [[Numeric]], [[NumberFormat]], [[NumberingSystem]], [[PluralRules]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::relative_time_format,
+            context,
+        )?;
+        let relative_time_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                numbering_system,
+                style,
+                numeric,
+                prefs,
+            },
+        );
+
+        // 20. Return relativeTimeFormat.
+        Ok(relative_time_format.into())
+    }
+}
+
+impl RelativeTimeFormat {
+    /// [`Intl.RelativeTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in relative
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.RelativeTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<Self>(requested_locales, options, context).map(JsValue::from)
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.format ( value, unit )`][spec].
+    ///
+    /// Returns a language-specific formatted string representing the given `value` in `unit`s,
+    /// relative to the present.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        use writeable::Writeable;
+
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let object = this.as_object();
+        let rtf = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`format` can only be called on a `RelativeTimeFormat` object")
+            })?;
+
+        // 3. Let value be ? ToNumber(value).
+        // 4. Let unit be ? ToString(unit).
+        let (value, unit) = to_value_and_unit(args, context)?;
+
+        // 5. Return ? FormatRelativeTime(relativeTimeFormat, value, unit).
+        let formatter = rtf.formatter(unit, context.intl_provider())?;
+        let parts = formatter.format(value);
+        let result = parts.write_to_string();
+
+        Ok(js_string!(result.as_ref()).into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.formatToParts ( value, unit )`][spec].
+    ///
+    /// Returns an array of objects representing the relative time format in parts that can be
+    /// used for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        use writeable::{PartsWrite, Writeable};
+
+        #[derive(Debug, Clone)]
+        struct Part {
+            typ: &'static str,
+            value: String,
+        }
+
+        /// Collects the parts of a formatted relative time, merging all text that is not
+        /// part of the formatted number into `literal` parts.
+        #[derive(Debug, Clone)]
+        struct PartsCollector(Vec<Part>);
+
+        impl Write for PartsCollector {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                if s.is_empty() {
+                    return Ok(());
+                }
+                if let Some(part) = self.0.last_mut()
+                    && part.typ == "literal"
+                {
+                    part.value.push_str(s);
+                } else {
+                    self.0.push(Part {
+                        typ: "literal",
+                        value: s.to_owned(),
+                    });
+                }
+                Ok(())
+            }
+        }
+
+        impl PartsWrite for PartsCollector {
+            type SubPartsWrite = Self;
+
+            fn with_part(
+                &mut self,
+                part: writeable::Part,
+                mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+            ) -> std::fmt::Result {
+                // Only the parts of the formatted number are exposed; everything else
+                // is a literal.
+                if part.category != "decimal" {
+                    return f(self);
+                }
+
+                // Nested parts (e.g. the group separators of the integer part) are kept
+                // as separate parts.
+                let mut sub = PartsCollector(Vec::new());
+                f(&mut sub)?;
+                self.0.extend(sub.0.into_iter().map(|p| Part {
+                    typ: if p.typ == "literal" {
+                        part.value
+                    } else {
+                        p.typ
+                    },
+                    value: p.value,
+                }));
+                Ok(())
+            }
+        }
+
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let object = this.as_object();
+        let rtf = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on a `RelativeTimeFormat` object",
+                )
+            })?;
+
+        // 3. Let value be ? ToNumber(value).
+        // 4. Let unit be ? ToString(unit).
+        let (value, unit) = to_value_and_unit(args, context)?;
+
+        // 5. Return ? FormatRelativeTimeToParts(relativeTimeFormat, value, unit).
+
+        // Abstract operation `FormatRelativeTimeToParts ( relativeTimeFormat, value, unit )`
+        // https://tc39.es/ecma402/#sec-FormatRelativeTimeToParts
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let formatter = rtf.formatter(unit, context.intl_provider())?;
+        let mut parts = PartsCollector(Vec::new());
+        formatter
+            .format(value)
+            .write_to_parts(&mut parts)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .js_expect("creating an empty array with default proto must not fail")?;
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, part) in parts.0.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw(js_string!("type"), js_string!(part.typ), context)
+                .js_expect("operation must not fail per the spec")?;
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw(
+                js_string!("value"),
+                js_string!(part.value.as_str()),
+                context,
+            )
+            .js_expect("operation must not fail per the spec")?;
+
+            // d. If part.[[Unit]] is not empty, then
+            if part.typ != "literal" {
+                // i. Perform ! CreateDataPropertyOrThrow(O, "unit", part.[[Unit]]).
+                o.create_data_property_or_throw(js_string!("unit"), unit.to_js_string(), context)
+                    .js_expect("operation must not fail per the spec")?;
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .js_expect("operation must not fail per the spec")?;
+
+            // f. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and formatting options
+    /// computed during the construction of the current `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let object = this.as_object();
+        let rtf = object
+            .as_ref()
+            .and_then(|o| o.downcast_ref::<Self>())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`resolvedOptions` can only be called on a `RelativeTimeFormat` object",
+                )
+            })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 16, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of relativeTimeFormat's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                js_string!("locale"),
+                js_string!(rtf.locale.to_string()),
+                context,
+            )
+            .js_expect("operation must not fail per the spec")?;
+        options
+            .create_data_property_or_throw(js_string!("style"), rtf.style.to_js_string(), context)
+            .js_expect("operation must not fail per the spec")?;
+        options
+            .create_data_property_or_throw(
+                js_string!("numeric"),
+                match rtf.numeric {
+                    Numeric::Always => js_string!("always"),
+                    Numeric::Auto => js_string!("auto"),
+                },
+                context,
+            )
+            .js_expect("operation must not fail per the spec")?;
+        options
+            .create_data_property_or_throw(
+                js_string!("numberingSystem"),
+                js_string!(rtf.numbering_system.as_str()),
+                context,
+            )
+            .js_expect("operation must not fail per the spec")?;
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+
+    /// Creates the ICU4X formatter for the provided `unit`, using the style and the
+    /// preferences of this `RelativeTimeFormat`.
+    fn formatter(&self, unit: Unit, provider: &IntlProvider) -> JsResult<RelativeTimeFormatter> {
+        let options = RelativeTimeFormatterOptions {
+            numeric: self.numeric,
+        };
+
+        macro_rules! try_new {
+            ($($style:ident, $unit:ident => $ctor:ident;)*) => {
+                match (self.style, unit) {
+                    $(
+                        (Style::$style, Unit::$unit) => RelativeTimeFormatter::$ctor(
+                            provider.erased_provider(),
+                            self.prefs,
+                            options,
+                        ),
+                    )*
+                }
+            };
+        }
+
+        try_new! {
+            Long, Second => try_new_long_second_with_buffer_provider;
+            Long, Minute => try_new_long_minute_with_buffer_provider;
+            Long, Hour => try_new_long_hour_with_buffer_provider;
+            Long, Day => try_new_long_day_with_buffer_provider;
+            Long, Week => try_new_long_week_with_buffer_provider;
+            Long, Month => try_new_long_month_with_buffer_provider;
+            Long, Quarter => try_new_long_quarter_with_buffer_provider;
+            Long, Year => try_new_long_year_with_buffer_provider;
+            Short, Second => try_new_short_second_with_buffer_provider;
+            Short, Minute => try_new_short_minute_with_buffer_provider;
+            Short, Hour => try_new_short_hour_with_buffer_provider;
+            Short, Day => try_new_short_day_with_buffer_provider;
+            Short, Week => try_new_short_week_with_buffer_provider;
+            Short, Month => try_new_short_month_with_buffer_provider;
+            Short, Quarter => try_new_short_quarter_with_buffer_provider;
+            Short, Year => try_new_short_year_with_buffer_provider;
+            Narrow, Second => try_new_narrow_second_with_buffer_provider;
+            Narrow, Minute => try_new_narrow_minute_with_buffer_provider;
+            Narrow, Hour => try_new_narrow_hour_with_buffer_provider;
+            Narrow, Day => try_new_narrow_day_with_buffer_provider;
+            Narrow, Week => try_new_narrow_week_with_buffer_provider;
+            Narrow, Month => try_new_narrow_month_with_buffer_provider;
+            Narrow, Quarter => try_new_narrow_quarter_with_buffer_provider;
+            Narrow, Year => try_new_narrow_year_with_buffer_provider;
+        }
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()).into())
+    }
+}
+
+/// Converts the `value` and `unit` arguments of `format` and `formatToParts`, performing the
+/// validation steps of the abstract operation [`PartitionRelativeTimePattern`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-PartitionRelativeTimePattern
+fn to_value_and_unit(args: &[JsValue], context: &mut Context) -> JsResult<(Decimal, Unit)> {
+    let value = args.get_or_undefined(0).to_number(context)?;
+    let unit = args.get_or_undefined(1).to_string(context)?;
+
+    // 1. If value is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+    let value = Decimal::try_from_f64(value, FloatPrecision::RoundTrip).map_err(|_| {
+        JsNativeError::range().with_message("relative time value must be a finite number")
+    })?;
+
+    // 2. Let unit be ? SingularRelativeTimeUnit(unit).
+    let unit = Unit::from_value(unit.into(), context)?;
+
+    Ok((value, unit))
+}
+
+/// Gets the default numbering system of `locale`.
+fn default_numbering_system(locale: &Locale, provider: &IntlProvider) -> JsResult<NumberingSystem> {
+    let data_locale = DataLocale::from(&locale.id);
+    let req = DataRequest {
+        id: DataIdentifierBorrowed::for_locale(&data_locale),
+        metadata: DataRequestMetadata::default(),
+    };
+    let data = DataProvider::<DecimalSymbolsV1>::load(provider, req)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    Value::try_from_str(data.payload.get().numsys())
+        .ok()
+        .and_then(|nu| NumberingSystem::try_from(nu).ok())
+        .ok_or_else(|| {
+            js_error!(TypeError: "could not obtain resolved numbering system from Intl provider")
+        })
+}
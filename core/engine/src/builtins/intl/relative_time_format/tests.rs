@@ -0,0 +1,73 @@
+use boa_macros::js_str;
+use indoc::indoc;
+
+use crate::{JsNativeErrorKind, TestAction, run_test_actions};
+
+#[test]
+fn format() {
+    run_test_actions([
+        TestAction::run("const rtf = new Intl.RelativeTimeFormat('en');"),
+        TestAction::assert_eq("rtf.format(-3, 'days')", js_str!("3 days ago")),
+        TestAction::assert_eq("rtf.format(1, 'hour')", js_str!("in 1 hour")),
+        TestAction::assert_eq("rtf.format(-1, 'day')", js_str!("1 day ago")),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en', { numeric: 'auto' }).format(-1, 'day')",
+            js_str!("yesterday"),
+        ),
+    ]);
+}
+
+#[test]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            const parts = new Intl.RelativeTimeFormat('en').formatToParts(100, 'day');
+        "}),
+        TestAction::assert_eq("parts.length", 3),
+        TestAction::assert_eq("parts[0].type", js_str!("literal")),
+        TestAction::assert_eq("parts[0].value", js_str!("in ")),
+        TestAction::assert_eq("parts[1].type", js_str!("integer")),
+        TestAction::assert_eq("parts[1].value", js_str!("100")),
+        TestAction::assert_eq("parts[1].unit", js_str!("day")),
+        TestAction::assert_eq("parts[2].value", js_str!(" days")),
+        TestAction::assert("!('unit' in parts[2])"),
+        TestAction::run(indoc! {"
+            const grouped = new Intl.RelativeTimeFormat('en').formatToParts(-1000, 'day');
+        "}),
+        TestAction::assert_eq(
+            "grouped.map(part => part.type).join()",
+            js_str!("integer,group,integer,literal"),
+        ),
+        TestAction::assert_eq("grouped[1].value", js_str!(",")),
+        TestAction::assert_eq("grouped[1].unit", js_str!("day")),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            const options = new Intl.RelativeTimeFormat('en', { style: 'short' }).resolvedOptions();
+        "}),
+        TestAction::assert_eq("options.locale", js_str!("en")),
+        TestAction::assert_eq("options.style", js_str!("short")),
+        TestAction::assert_eq("options.numeric", js_str!("always")),
+        TestAction::assert_eq("options.numberingSystem", js_str!("latn")),
+    ]);
+}
+
+#[test]
+fn invalid_arguments() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "new Intl.RelativeTimeFormat('en').format(1, 'decade')",
+            JsNativeErrorKind::Range,
+            "provided unit `decade` is not a valid relative time unit",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.RelativeTimeFormat('en').format(Infinity, 'day')",
+            JsNativeErrorKind::Range,
+            "relative time value must be a finite number",
+        ),
+    ]);
+}
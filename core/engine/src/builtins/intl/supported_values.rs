@@ -0,0 +1,223 @@
+//! Lists of the values supported by the `Intl` services of the engine.
+//!
+//! These are used by `Intl.supportedValuesOf` and the `Intl.Locale` info methods.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#sec-availablecanonicalcalendars
+
+use std::collections::BTreeSet;
+
+use icu_collator::provider::CollationMetadataV1;
+use icu_datetime::preferences::CalendarAlgorithm;
+use icu_decimal::provider::DecimalSymbolsV1;
+use icu_locale::{LanguageIdentifier, extensions::unicode::Value, langid, subtags::Region};
+use icu_provider::DataMarkerAttributes;
+use icu_time::{TimeZone, zone::iana::IanaParserExtended};
+
+use crate::{JsNativeError, JsResult, context::icu::IntlProvider};
+
+use super::{date_time_format::supports_calendar, locale::validate_extension};
+
+/// Every calendar type that could be supported by `Intl.DateTimeFormat`.
+const CALENDARS: [&str; 16] = [
+    "buddhist",
+    "chinese",
+    "coptic",
+    "dangi",
+    "ethioaa",
+    "ethiopic",
+    "gregory",
+    "hebrew",
+    "indian",
+    "islamic-civil",
+    "islamic-tbla",
+    "islamic-umalqura",
+    "iso8601",
+    "japanese",
+    "persian",
+    "roc",
+];
+
+/// Every collation type defined by CLDR, paired with a locale that tailors it.
+///
+/// Excludes the `standard` and `search` collations, as required by the specification.
+const COLLATIONS: [(&str, LanguageIdentifier); 13] = [
+    ("big5han", langid!("zh")),
+    ("compat", langid!("ar")),
+    ("dict", langid!("si")),
+    ("emoji", langid!("und")),
+    ("eor", langid!("und")),
+    ("gb2312", langid!("zh")),
+    ("phonebk", langid!("de")),
+    ("phonetic", langid!("ln")),
+    ("pinyin", langid!("zh")),
+    ("searchjl", langid!("ko")),
+    ("stroke", langid!("zh")),
+    ("trad", langid!("es")),
+    ("unihan", langid!("ko")),
+];
+
+/// The active currency codes of the ISO 4217 standard.
+///
+/// Excludes the codes reserved for testing (`XTS`) and for transactions where no currency is
+/// involved (`XXX`).
+const CURRENCIES: [&str; 180] = [
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT",
+    "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD",
+    "CAD", "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUC", "CUP",
+    "CVE", "CZK", "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP",
+    "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS",
+    "INR", "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW",
+    "KWD", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD",
+    "MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD", "NGN",
+    "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR",
+    "RON", "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SLL",
+    "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY",
+    "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES",
+    "VND", "VUV", "WST", "XAF", "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XCG", "XDR",
+    "XOF", "XPD", "XPF", "XPT", "XSU", "XUA", "YER", "ZAR", "ZMW", "ZWG", "ZWL",
+];
+
+/// The numbering systems with simple digit mappings.
+///
+/// See <https://tc39.es/ecma402/#table-numbering-system-digits>.
+const NUMBERING_SYSTEMS: [&str; 73] = [
+    "adlm", "ahom", "arab", "arabext", "bali", "beng", "bhks", "brah", "cakm", "cham", "deva",
+    "diak", "fullwide", "gara", "gong", "gonm", "gujr", "gukh", "guru", "hanidec", "hmng", "hmnp",
+    "java", "kali", "kawi", "khmr", "knda", "krai", "lana", "lanatham", "laoo", "latn", "lepc",
+    "limb", "mathbold", "mathdbl", "mathmono", "mathsanb", "mathsans", "mlym", "modi", "mong",
+    "mroo", "mtei", "mymr", "mymrepka", "mymrpao", "mymrshan", "mymrtlng", "nagm", "newa", "nkoo",
+    "olck", "onao", "orya", "osma", "outlined", "rohg", "saur", "segment", "shrd", "sind", "sinh",
+    "sora", "sund", "sunu", "takr", "talu", "tamldec", "telu", "thai", "tibt", "tirh",
+];
+
+/// Abstract operation [`AvailableCanonicalCalendars ( )`][spec].
+///
+/// Gets the calendar types supported by `Intl.DateTimeFormat`, sorted in lexicographic order.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalcalendars
+pub(in crate::builtins::intl) fn available_calendars(provider: &IntlProvider) -> Vec<&'static str> {
+    let id = langid!("en");
+    CALENDARS
+        .into_iter()
+        .filter(|ca| {
+            ca.parse::<Value>()
+                .ok()
+                .and_then(|ca| CalendarAlgorithm::try_from(&ca).ok())
+                .is_some_and(|ca| supports_calendar(ca, &id, provider))
+        })
+        .collect()
+}
+
+/// Abstract operation [`AvailableCanonicalCollations ( )`][spec].
+///
+/// Gets the collation types supported by `Intl.Collator` for at least one locale, sorted in
+/// lexicographic order.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalcollations
+pub(in crate::builtins::intl) fn available_collations(
+    provider: &IntlProvider,
+) -> Vec<&'static str> {
+    COLLATIONS
+        .iter()
+        .filter(|(co, id)| has_collation(co, id, provider))
+        .map(|(co, _)| *co)
+        .collect()
+}
+
+/// Gets the collation types supported by `Intl.Collator` for `id`, sorted in lexicographic order.
+pub(in crate::builtins::intl) fn collations_of_locale(
+    id: &LanguageIdentifier,
+    provider: &IntlProvider,
+) -> Vec<&'static str> {
+    COLLATIONS
+        .iter()
+        .map(|(co, _)| *co)
+        .filter(|co| has_collation(co, id, provider))
+        .collect()
+}
+
+fn has_collation(co: &str, id: &LanguageIdentifier, provider: &IntlProvider) -> bool {
+    validate_extension::<CollationMetadataV1>(
+        id,
+        DataMarkerAttributes::from_str_or_panic(co),
+        provider,
+    )
+}
+
+/// Abstract operation [`AvailableCanonicalCurrencies ( )`][spec].
+///
+/// Gets the currency codes supported by `Intl.NumberFormat`, sorted in lexicographic order.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalcurrencies
+pub(in crate::builtins::intl) fn available_currencies() -> &'static [&'static str] {
+    &CURRENCIES
+}
+
+/// Abstract operation [`AvailableCanonicalNumberingSystems ( )`][spec].
+///
+/// Gets the numbering systems supported by `Intl.NumberFormat`, sorted in lexicographic order.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalnumberingsystems
+pub(in crate::builtins::intl) fn available_numbering_systems(
+    provider: &IntlProvider,
+) -> Vec<&'static str> {
+    let id = LanguageIdentifier::UNKNOWN;
+    NUMBERING_SYSTEMS
+        .into_iter()
+        .filter(|nu| {
+            validate_extension::<DecimalSymbolsV1>(
+                &id,
+                DataMarkerAttributes::from_str_or_panic(nu),
+                provider,
+            )
+        })
+        .collect()
+}
+
+/// Abstract operation [`AvailablePrimaryTimeZoneIdentifiers ( )`][spec].
+///
+/// Gets the primary time zone identifiers supported by the engine, sorted in lexicographic order.
+/// If `region` is provided, only returns the time zones that are used in that region.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availableprimarytimezoneidentifiers
+pub(in crate::builtins::intl) fn available_time_zones(
+    region: Option<Region>,
+    provider: &IntlProvider,
+) -> JsResult<BTreeSet<String>> {
+    let parser = IanaParserExtended::try_new_with_buffer_provider(provider.erased_provider())
+        .map_err(|_| {
+            JsNativeError::error().with_message("Failed to init time zone data provider")
+        })?;
+
+    let mut time_zones = BTreeSet::new();
+    for tz in parser.as_borrowed().iter_all() {
+        if tz.time_zone == TimeZone::UNKNOWN {
+            continue;
+        }
+
+        // The BCP-47 identifier of a time zone starts with the code of the region that
+        // uses the time zone, except for the `Etc/*` zones and the legacy POSIX zones.
+        if let Some(region) = region {
+            let id = tz.time_zone.0.as_str();
+            if !tz.canonical.contains('/')
+                || tz.canonical.starts_with("Etc/")
+                || !id
+                    .get(..2)
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(region.as_str()))
+            {
+                continue;
+            }
+        }
+
+        let canonical = match tz.canonical {
+            "Etc/UTC" | "Etc/GMT" | "GMT" => "UTC",
+            canonical => canonical,
+        };
+        time_zones.insert(canonical.to_owned());
+    }
+
+    Ok(time_zones)
+}
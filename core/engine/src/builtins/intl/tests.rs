@@ -0,0 +1,34 @@
+use boa_macros::js_str;
+use indoc::indoc;
+
+use crate::{JsNativeErrorKind, TestAction, run_test_actions};
+
+#[test]
+fn supported_values_of() {
+    run_test_actions([
+        TestAction::run(indoc! {"
+            function isSorted(list) {
+                return list.every((value, i) => i === 0 || list[i - 1] < value);
+            }
+        "}),
+        TestAction::assert(indoc! {"
+            ['calendar', 'collation', 'currency', 'numberingSystem', 'timeZone', 'unit']
+                .every(key => isSorted(Intl.supportedValuesOf(key)))
+        "}),
+        TestAction::assert("Intl.supportedValuesOf('calendar').includes('gregory')"),
+        TestAction::assert("Intl.supportedValuesOf('collation').includes('phonebk')"),
+        TestAction::assert("!Intl.supportedValuesOf('collation').includes('standard')"),
+        TestAction::assert("Intl.supportedValuesOf('currency').includes('EUR')"),
+        TestAction::assert("Intl.supportedValuesOf('numberingSystem').includes('arab')"),
+        TestAction::assert("Intl.supportedValuesOf('timeZone').includes('America/New_York')"),
+        TestAction::assert("Intl.supportedValuesOf('timeZone').includes('UTC')"),
+        TestAction::assert("!Intl.supportedValuesOf('timeZone').includes('Etc/UTC')"),
+        TestAction::assert_eq("Intl.supportedValuesOf('unit').length", 45),
+        TestAction::assert_eq("Intl.supportedValuesOf('unit')[0]", js_str!("acre")),
+        TestAction::assert_native_error(
+            "Intl.supportedValuesOf('locale')",
+            JsNativeErrorKind::Range,
+            "`locale` is not a supported key for `Intl.supportedValuesOf`",
+        ),
+    ]);
+}
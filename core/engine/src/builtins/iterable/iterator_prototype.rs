@@ -52,6 +52,7 @@ impl IntrinsicObject for Iterator {
             .static_method(Self::some, js_string!("some"), 1)
             .static_method(Self::every, js_string!("every"), 1)
             .static_method(Self::find, js_string!("find"), 1)
+            .static_method(Self::dispose, JsSymbol::dispose(), 0)
             .static_accessor(
                 JsSymbol::to_string_tag(),
                 Some(get_to_string_tag),
@@ -73,6 +74,25 @@ impl IntrinsicObject for Iterator {
 }
 
 impl Iterator {
+    /// `Iterator.prototype [ @@dispose ] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-%iteratorprototype%-@@dispose
+    fn dispose(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Let return be ? GetMethod(O, "return").
+        // 3. If return is not undefined, then
+        if let Some(r#return) = this.get_method(js_string!("return"), context)? {
+            // a. Perform ? Call(return, O, « »).
+            r#return.call(this, &[], context)?;
+        }
+
+        // 4. Return NormalCompletion(undefined).
+        Ok(JsValue::undefined())
+    }
+
     /// `get Iterator.prototype.constructor`
     ///
     /// More information:
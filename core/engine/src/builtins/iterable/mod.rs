@@ -1,13 +1,16 @@
 //! Boa's implementation of ECMAScript's `IteratorRecord` and iterator prototype objects.
 
 use crate::{
-    Context, JsResult, JsValue,
-    builtins::{BuiltInBuilder, IntrinsicObject},
+    Context, JsExpect, JsResult, JsValue,
+    builtins::{
+        BuiltInBuilder, IntrinsicObject, Promise,
+        promise::{PromiseCapability, if_abrupt_reject_promise},
+    },
     context::intrinsics::Intrinsics,
     error::JsNativeError,
     js_string,
-    native_function::{CoroutineBranch, CoroutineState},
-    object::JsObject,
+    native_function::{CoroutineBranch, CoroutineState, NativeFunction},
+    object::{FunctionObjectBuilder, JsObject},
     realm::Realm,
     symbol::JsSymbol,
     vm::CompletionRecord,
@@ -109,6 +112,39 @@ impl Default for IteratorPrototypes {
 }
 
 impl IteratorPrototypes {
+    /// Appends every iterator prototype to `objects`.
+    pub(crate) fn collect_objects(&self, objects: &mut Vec<JsObject>) {
+        // Destructured exhaustively, so that new prototypes cannot be missed.
+        let Self {
+            iterator,
+            async_iterator,
+            async_from_sync_iterator,
+            array,
+            set,
+            string,
+            regexp_string,
+            map,
+            #[cfg(feature = "intl")]
+            segment,
+            iterator_helper,
+            wrap_for_valid_iterator,
+        } = self;
+        objects.extend([
+            iterator.clone(),
+            async_iterator.clone(),
+            async_from_sync_iterator.clone(),
+            array.clone(),
+            set.clone(),
+            string.clone(),
+            regexp_string.clone(),
+            map.clone(),
+            iterator_helper.clone(),
+            wrap_for_valid_iterator.clone(),
+        ]);
+        #[cfg(feature = "intl")]
+        objects.push(segment.clone());
+    }
+
     /// Returns the `ArrayIteratorPrototype` object.
     #[inline]
     #[must_use]
@@ -193,6 +229,7 @@ impl IntrinsicObject for AsyncIterator {
     fn init(realm: &Realm) {
         BuiltInBuilder::with_intrinsic::<Self>(realm)
             .static_method(|v, _, _| Ok(v.clone()), JsSymbol::async_iterator(), 0)
+            .static_method(Self::async_dispose, JsSymbol::async_dispose(), 0)
             .build();
     }
 
@@ -201,6 +238,85 @@ impl IntrinsicObject for AsyncIterator {
     }
 }
 
+impl AsyncIterator {
+    /// `AsyncIterator.prototype [ @@asyncDispose ] ( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-%asynciteratorprototype%-@@asyncdispose
+    fn async_dispose(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .js_expect("cannot fail per spec")?;
+
+        // 3. Let return be Completion(GetMethod(O, "return")).
+        let r#return = this.get_method(js_string!("return"), context);
+
+        // 4. IfAbruptRejectPromise(return, promiseCapability).
+        let r#return = if_abrupt_reject_promise!(r#return, promise_capability, context);
+
+        // 5. If return is undefined, then
+        let Some(r#return) = r#return else {
+            // a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+            promise_capability.resolve().call(
+                &JsValue::undefined(),
+                &[JsValue::undefined()],
+                context,
+            )?;
+
+            // 7. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        };
+
+        // 6. Else,
+        //    a. Let result be Completion(Call(return, O, « undefined »)).
+        let result = r#return.call(this, &[JsValue::undefined()], context);
+
+        //    b. IfAbruptRejectPromise(result, promiseCapability).
+        let result = if_abrupt_reject_promise!(result, promise_capability, context);
+
+        //    c. Let resultWrapper be Completion(PromiseResolve(%Promise%, result)).
+        let result_wrapper = Promise::promise_resolve(
+            &context.intrinsics().constructors().promise().constructor(),
+            result,
+            context,
+        );
+
+        //    d. IfAbruptRejectPromise(resultWrapper, promiseCapability).
+        let result_wrapper = if_abrupt_reject_promise!(result_wrapper, promise_capability, context)
+            .downcast::<Promise>()
+            .expect("%Promise% constructor must return a `Promise` object");
+
+        //    e. Let unwrap be a new Abstract Closure that performs the following steps when called:
+        //       i. Return undefined.
+        //    f. Let onFulfilled be CreateBuiltinFunction(unwrap, 1, "", « »).
+        let on_fulfilled = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::undefined())),
+        )
+        .name(js_string!())
+        .length(1)
+        .build();
+
+        //    g. Perform PerformPromiseThen(resultWrapper, onFulfilled, undefined, promiseCapability).
+        Promise::perform_promise_then(
+            &result_wrapper,
+            Some(on_fulfilled),
+            None,
+            Some(promise_capability.clone()),
+            context,
+        );
+
+        // 7. Return promiseCapability.[[Promise]].
+        Ok(promise_capability.promise().clone().into())
+    }
+}
+
 /// `CreateIterResultObject( value, done )`
 ///
 /// Generates an object supporting the `IteratorResult` interface.
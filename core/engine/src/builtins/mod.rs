@@ -10,6 +10,7 @@ pub mod bigint;
 pub mod boolean;
 pub mod dataview;
 pub mod date;
+pub mod disposable_stack;
 pub mod error;
 pub mod eval;
 pub mod finalization_registry;
@@ -29,6 +30,7 @@ pub mod proxy;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
+pub mod shadow_realm;
 pub mod string;
 pub mod symbol;
 pub mod typed_array;
@@ -63,8 +65,10 @@ pub(crate) use self::{
     boolean::Boolean,
     dataview::DataView,
     date::Date,
+    disposable_stack::{AsyncDisposableStack, DisposableStack},
     error::{
-        AggregateError, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, UriError,
+        AggregateError, EvalError, RangeError, ReferenceError, SuppressedError, SyntaxError,
+        TypeError, UriError,
     },
     eval::Eval,
     finalization_registry::FinalizationRegistry,
@@ -79,6 +83,7 @@ pub(crate) use self::{
     reflect::Reflect,
     regexp::RegExp,
     set::Set,
+    shadow_realm::ShadowRealm,
     string::String,
     symbol::Symbol,
     typed_array::{
@@ -300,6 +305,7 @@ impl Realm {
         EvalError::init(self);
         UriError::init(self);
         AggregateError::init(self);
+        SuppressedError::init(self);
         Reflect::init(self);
         Generator::init(self);
         GeneratorFunction::init(self);
@@ -316,6 +322,9 @@ impl Realm {
         WeakSet::init(self);
         Atomics::init(self);
         FinalizationRegistry::init(self);
+        DisposableStack::init(self);
+        AsyncDisposableStack::init(self);
+        ShadowRealm::init(self);
 
         #[cfg(feature = "annex-b")]
         {
@@ -327,6 +336,8 @@ impl Realm {
         {
             intl::Intl::init(self);
             intl::Collator::init(self);
+            intl::DisplayNames::init(self);
+            intl::DurationFormat::init(self);
             intl::ListFormat::init(self);
             intl::Locale::init(self);
             intl::DateTimeFormat::init(self);
@@ -335,6 +346,7 @@ impl Realm {
             intl::segmenter::SegmentIterator::init(self);
             intl::PluralRules::init(self);
             intl::NumberFormat::init(self);
+            intl::RelativeTimeFormat::init(self);
         }
 
         #[cfg(feature = "temporal")]
@@ -433,6 +445,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<EvalError>(context)?;
     global_binding::<UriError>(context)?;
     global_binding::<AggregateError>(context)?;
+    global_binding::<SuppressedError>(context)?;
     global_binding::<Reflect>(context)?;
     global_binding::<Promise>(context)?;
     global_binding::<EncodeUri>(context)?;
@@ -445,6 +458,9 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<IteratorConstructor>(context)?;
     global_binding::<Atomics>(context)?;
     global_binding::<FinalizationRegistry>(context)?;
+    global_binding::<DisposableStack>(context)?;
+    global_binding::<AsyncDisposableStack>(context)?;
+    global_binding::<ShadowRealm>(context)?;
 
     #[cfg(feature = "annex-b")]
     {
@@ -329,7 +329,7 @@ impl Number {
                 .map_err(|err| JsNativeError::range().with_message(err.to_string()))?;
 
             // 3. Return FormatNumeric(numberFormat, ! ToIntlMathematicalValue(x)).
-            Ok(js_string!(number_format.format(&mut x).to_string()).into())
+            Ok(js_string!(number_format.format(&mut x)?).into())
         }
 
         #[cfg(not(feature = "intl"))]
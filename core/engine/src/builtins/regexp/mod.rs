@@ -340,6 +340,11 @@ impl RegExp {
             flags.to_string(context)?
         };
 
+        Self::compile_pattern(p, f)
+    }
+
+    /// Compiles a `RegExp` from a pattern and flags that are already strings.
+    pub(crate) fn compile_pattern(p: JsString, f: JsString) -> JsResult<RegExp> {
         // 5. If F contains any code unit other than "g", "i", "m", "s", "u", "v", or "y"
         //    or if it contains the same code unit more than once, throw a SyntaxError exception.
         // TODO: Should directly parse the JsString instead of converting to String
@@ -1798,6 +1803,11 @@ impl RegExp {
                 //    In such cases, the corresponding substitution is ignored.
 
                 // ii. Set accumulatedResult to the string-concatenation of accumulatedResult, the substring of S from nextSourcePosition to position, and replacement.
+                string::String::check_allocation(
+                    accumulated_result.len()
+                        + (position - next_source_position)
+                        + replacement.len(),
+                )?;
                 accumulated_result.extend(s.get_expect(next_source_position..position).iter());
                 accumulated_result.extend(replacement.iter());
 
@@ -0,0 +1,418 @@
+//! Boa's implementation of ECMAScript's `ShadowRealm` object.
+//!
+//! A `ShadowRealm` evaluates code inside a new realm with its own set of intrinsics and global
+//! object. Only primitives and callables can cross the boundary between realms, the latter
+//! being wrapped into [`WrappedFunction`] objects.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm-objects
+
+use boa_ast::operations::{ContainsSymbol, contains};
+use boa_gc::{Finalize, Trace};
+use boa_parser::{Parser, Source};
+
+use crate::{
+    Context, JsArgs, JsData, JsError, JsObject, JsResult, JsString, JsSymbol, JsValue,
+    builtins::{
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, Eval, IntrinsicObject, Promise,
+        promise::PromiseCapability,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    job::NativeAsyncJob,
+    js_error, js_string,
+    module::{ModuleRequest, Referrer},
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, internal_methods::get_prototype_from_constructor},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    vm::opcode::load_dyn_import,
+};
+
+mod wrapped_function;
+
+#[cfg(test)]
+mod tests;
+
+pub use wrapped_function::WrappedFunction;
+pub(crate) use wrapped_function::get_wrapped_value;
+
+/// The `ShadowRealm` builtin object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct ShadowRealm {
+    /// The `[[ShadowRealm]]` internal slot, the realm where code is evaluated.
+    realm: Realm,
+}
+
+impl IntrinsicObject for ShadowRealm {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::evaluate, js_string!("evaluate"), 1)
+            .method(Self::import_value, js_string!("importValue"), 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for ShadowRealm {
+    const NAME: JsString = StaticJsStrings::SHADOW_REALM;
+}
+
+impl BuiltInConstructor for ShadowRealm {
+    const CONSTRUCTOR_ARGUMENTS: usize = 0;
+    const PROTOTYPE_STORAGE_SLOTS: usize = 3;
+    const CONSTRUCTOR_STORAGE_SLOTS: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::shadow_realm;
+
+    /// [`ShadowRealm ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm
+    fn constructor(
+        new_target: &JsValue,
+        _args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(js_error!(
+                TypeError: "ShadowRealm: cannot call constructor without `new`"
+            ));
+        }
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%ShadowRealm.prototype%", « [[ShadowRealm]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::shadow_realm,
+            context,
+        )?;
+
+        // 3. Let callerContext be the running execution context.
+        // 4. Perform ? InitializeHostDefinedRealm().
+        // 5. Let innerContext be the running execution context.
+        // 6. Remove innerContext from the execution context stack and restore callerContext as the running execution context.
+        // 7. Let realmRec be the Realm of innerContext.
+        let realm = context.create_realm()?;
+
+        // 8. Set O.[[ShadowRealm]] to realmRec.
+        // 9. Perform ? HostInitializeShadowRealm(realmRec).
+        context
+            .host_hooks()
+            .initialize_shadow_realm(&realm, context)?;
+
+        let shadow_realm = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { realm },
+        );
+
+        // 10. Return O.
+        Ok(shadow_realm.upcast().into())
+    }
+}
+
+impl ShadowRealm {
+    /// Abstract operation [`ValidateShadowRealmObject ( O )`][spec].
+    ///
+    /// Returns the realm of the `ShadowRealm` object in `this`, or throws a `TypeError` otherwise.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-validateshadowrealmobject
+    fn this_realm(this: &JsValue, method: &str) -> JsResult<Realm> {
+        this.as_object()
+            .and_then(|o| o.downcast_ref::<Self>().map(|o| o.realm.clone()))
+            .ok_or_else(|| {
+                js_error!(
+                    TypeError: "ShadowRealm.prototype.{}: invalid object type for `this`",
+                    method
+                )
+            })
+    }
+
+    /// [`ShadowRealm.prototype.evaluate ( sourceText )`][spec]
+    ///
+    /// Evaluates `sourceText` as a script inside the realm of the `ShadowRealm`.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.evaluate
+    fn evaluate(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::this_realm(this, "evaluate")?;
+
+        // 3. If Type(sourceText) is not String, throw a TypeError exception.
+        let Some(source_text) = args.get_or_undefined(0).as_string() else {
+            return Err(js_error!(
+                TypeError: "ShadowRealm.prototype.evaluate: source text must be a string"
+            ));
+        };
+
+        // 4. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 5. Let evalRealm be O.[[ShadowRealm]].
+        // 6. Return ? PerformShadowRealmEval(sourceText, callerRealm, evalRealm).
+        Self::perform_shadow_realm_eval(&source_text, &caller_realm, eval_realm, context)
+    }
+
+    /// Abstract operation [`PerformShadowRealmEval ( sourceText, callerRealm, evalRealm )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-performshadowrealmeval
+    fn perform_shadow_realm_eval(
+        source_text: &JsString,
+        caller_realm: &Realm,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? HostEnsureCanCompileStrings(evalRealm, « », sourceText, false).
+        context.host_hooks().ensure_can_compile_strings(
+            eval_realm.clone(),
+            &[],
+            source_text,
+            false,
+            context,
+        )?;
+
+        // 2. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+        //     a. Let script be ParseText(StringToCodePoints(sourceText), Script).
+        //     b. If script is a List of errors, throw a SyntaxError exception.
+        //     c. If script Contains ScriptBody is false, return undefined.
+        //     d. Let body be the ScriptBody of script.
+        let source_text = source_text.to_vec();
+        let mut parser = Parser::new(Source::from_utf16(&source_text));
+        parser.set_identifier(context.next_parser_identifier());
+        let (body, source) = parser.parse_eval(false, context.interner_mut())?;
+
+        //     e. If body Contains NewTarget, throw a SyntaxError exception.
+        if contains(&body, ContainsSymbol::NewTarget) {
+            return Err(js_error!(
+                SyntaxError: "invalid `new.target` expression inside ShadowRealm code"
+            ));
+        }
+        //     f. If body Contains SuperProperty, throw a SyntaxError exception.
+        if contains(&body, ContainsSymbol::SuperProperty) {
+            return Err(js_error!(
+                SyntaxError: "invalid `super` reference inside ShadowRealm code"
+            ));
+        }
+        //     g. If body Contains SuperCall, throw a SyntaxError exception.
+        if contains(&body, ContainsSymbol::SuperCall) {
+            return Err(js_error!(
+                SyntaxError: "invalid `super` call inside ShadowRealm code"
+            ));
+        }
+
+        // 3-14. Set up an execution context in evalRealm and evaluate body in its global
+        //       environment, as done by an indirect eval.
+        let old_realm = context.enter_realm(eval_realm);
+        let result = Eval::evaluate_body(body, source, false, None, false, context);
+        context.enter_realm(old_realm);
+
+        match result {
+            // 15. If result.[[Type]] is normal, then
+            //     a. Set result to Completion(GetWrappedValue(callerRealm, result.[[Value]])).
+            // 17. Return ? result.
+            Ok(value) => get_wrapped_value(caller_realm, &value, context),
+            // 16. If result.[[Type]] is not normal, then
+            //     a. Let copiedError be CreateTypeErrorCopy(callerRealm, result.[[Value]]).
+            //     b. Return ThrowCompletion(copiedError).
+            Err(err) => Err(create_type_error_copy(err, context)),
+        }
+    }
+
+    /// [`ShadowRealm.prototype.importValue ( specifier, exportName )`][spec]
+    ///
+    /// Imports the module `specifier` inside the realm of the `ShadowRealm`, returning a
+    /// promise for the export named `exportName`.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.importvalue
+    fn import_value(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::this_realm(this, "importValue")?;
+
+        // 3. Let specifierString be ? ToString(specifier).
+        let specifier = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. If Type(exportName) is not String, throw a TypeError exception.
+        let Some(export_name) = args.get_or_undefined(1).as_string() else {
+            return Err(js_error!(
+                TypeError: "ShadowRealm.prototype.importValue: export name must be a string"
+            ));
+        };
+
+        // 5. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 6. Let evalRealm be O.[[ShadowRealm]].
+        // 7. Return ShadowRealmImportValue(specifierString, exportName, callerRealm, evalRealm).
+        Ok(Self::shadow_realm_import_value(
+            specifier,
+            export_name,
+            &caller_realm,
+            eval_realm,
+            context,
+        )
+        .into())
+    }
+
+    /// Abstract operation [`ShadowRealmImportValue ( specifierString, exportNameString, callerRealm, evalRealm )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealmimportvalue
+    fn shadow_realm_import_value(
+        specifier: JsString,
+        export_name: JsString,
+        caller_realm: &Realm,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsObject {
+        // 1. Let evalContext be GetShadowRealmContext(evalRealm, true).
+        // 2. Let innerCapability be ! NewPromiseCapability(%Promise%).
+        let inner_capability = PromiseCapability::new(
+            &eval_realm
+                .intrinsics()
+                .constructors()
+                .promise()
+                .constructor(),
+            context,
+        )
+        .expect("operation cannot fail for the %Promise% intrinsic");
+        let inner_promise = inner_capability
+            .promise()
+            .clone()
+            .downcast::<Promise>()
+            .expect("%Promise% constructor must return a `Promise` object");
+
+        // 3. Let runningContext be the running execution context.
+        // 4. If runningContext is not already suspended, suspend runningContext.
+        // 5. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+        // 6. Let referrer be the Realm component of evalContext.
+        // 7. Perform HostLoadImportedModule(referrer, specifierString, empty, innerCapability).
+        // 8. Suspend evalContext and remove it from the execution context stack.
+        // 9. Resume the context that is now on the top of the execution context stack as the running execution context.
+        let referrer = Referrer::Realm(eval_realm.clone());
+        let request = ModuleRequest::from_specifier(specifier);
+        let job = NativeAsyncJob::with_realm(
+            async move |context| {
+                load_dyn_import(referrer, request, inner_capability, 0, context).await?;
+                Ok(JsValue::undefined())
+            },
+            eval_realm,
+        );
+        context.enqueue_job(job.into());
+
+        // 10. Let steps be the steps of an ExportGetter function as described below.
+        // 11. Let onFulfilled be CreateBuiltinFunction(steps, 1, "", « [[ExportNameString]] », callerRealm).
+        // 12. Set onFulfilled.[[ExportNameString]] to exportNameString.
+        let on_fulfilled = FunctionObjectBuilder::new(
+            caller_realm,
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, export_name: &JsString, context| {
+                    // 1. Assert: exports is a module namespace exotic object.
+                    let exports = args.get_or_undefined(0).as_object().ok_or_else(
+                        || js_error!(TypeError: "ShadowRealm: expected a module namespace object"),
+                    )?;
+
+                    // 2. Let f be the active function object.
+                    // 3. Let string be f.[[ExportNameString]].
+                    // 4. Assert: Type(string) is String.
+                    // 5. Let hasOwn be ? HasOwnProperty(exports, string).
+                    // 6. If hasOwn is false, throw a TypeError exception.
+                    if !exports.has_own_property(export_name.clone(), context)? {
+                        return Err(js_error!(
+                            TypeError: "ShadowRealm: module does not export `{}`",
+                            export_name.to_std_string_escaped()
+                        ));
+                    }
+
+                    // 7. Let value be ? Get(exports, string).
+                    let value = exports.get(export_name.clone(), context)?;
+
+                    // 8. Let realm be f.[[Realm]].
+                    let realm = context.realm().clone();
+
+                    // 9. Return ? GetWrappedValue(realm, value).
+                    get_wrapped_value(&realm, &value, context)
+                },
+                export_name,
+            ),
+        )
+        .name(js_string!())
+        .length(1)
+        .build();
+
+        // 13. Let errorSteps be the steps of an ImportValueError function as described below.
+        // 14. Let onRejected be CreateBuiltinFunction(errorSteps, 1, "", « », callerRealm).
+        let on_rejected = FunctionObjectBuilder::new(
+            caller_realm,
+            NativeFunction::from_fn_ptr(|_, args, context| {
+                // 1. Let realmRecord be the function's associated Realm Record.
+                // 2. Let copiedError be CreateTypeErrorCopy(realmRecord, error).
+                // 3. Return ThrowCompletion(copiedError).
+                let error = JsError::from_opaque(args.get_or_undefined(0).clone());
+                Err(create_type_error_copy(error, context))
+            }),
+        )
+        .name(js_string!())
+        .length(1)
+        .build();
+
+        // 15. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &caller_realm
+                .intrinsics()
+                .constructors()
+                .promise()
+                .constructor(),
+            context,
+        )
+        .expect("operation cannot fail for the %Promise% intrinsic");
+        let promise = promise_capability.promise().clone();
+
+        // 16. Return PerformPromiseThen(innerCapability.[[Promise]], onFulfilled, onRejected, promiseCapability).
+        Promise::perform_promise_then(
+            &inner_promise,
+            Some(on_fulfilled),
+            Some(on_rejected),
+            Some(promise_capability),
+            context,
+        );
+
+        promise
+    }
+}
+
+/// Abstract operation [`CreateTypeErrorCopy ( realmRecord, originalError )`][spec].
+///
+/// Creates a `TypeError` in the current realm with the same message as `error`, which avoids
+/// leaking objects of another realm. Uncatchable errors are returned unchanged.
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-create-type-error-copy
+pub(crate) fn create_type_error_copy(error: JsError, context: &mut Context) -> JsError {
+    if !error.is_catchable() {
+        return error;
+    }
+
+    match error.try_native(context) {
+        Ok(native) => js_error!(
+            TypeError: "an error was thrown inside a ShadowRealm: {}: {}",
+            native.kind(),
+            native.message()
+        ),
+        Err(_) => js_error!(TypeError: "an error was thrown inside a ShadowRealm"),
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{JsNativeErrorKind, TestAction, run_test_actions};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn evaluate_primitives() {
+    run_test_actions([
+        TestAction::run("var realm = new ShadowRealm();"),
+        TestAction::assert_eq("realm.evaluate('1 + 2')", 3),
+        TestAction::assert_eq("realm.evaluate('\"a\" + \"b\"')", js_str!("ab")),
+        TestAction::assert("realm.evaluate('undefined') === undefined"),
+        TestAction::assert_eq(
+            "realm.evaluate('typeof Symbol.iterator')",
+            js_str!("symbol"),
+        ),
+    ]);
+}
+
+#[test]
+fn evaluate_uses_a_separate_global() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var x = "outer";
+            var realm = new ShadowRealm();
+            realm.evaluate('var x = "inner"; globalThis.y = 1;');
+        "#}),
+        TestAction::assert_eq("x", js_str!("outer")),
+        TestAction::assert("typeof y === 'undefined'"),
+        TestAction::assert_eq("realm.evaluate('x')", js_str!("inner")),
+        TestAction::assert_eq("realm.evaluate('y')", 1),
+        TestAction::assert("realm.evaluate('Array') !== Array"),
+    ]);
+}
+
+#[test]
+fn wrapped_functions() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var realm = new ShadowRealm();
+            var add = realm.evaluate('(function add(a, b, c) { return a + b; })');
+            var apply = realm.evaluate('(f, x) => f(x)');
+        "#}),
+        TestAction::assert_eq("add(1, 2)", 3),
+        TestAction::assert_eq("add.name", js_str!("add")),
+        TestAction::assert_eq("add.length", 3),
+        TestAction::assert("Object.getPrototypeOf(add) === Function.prototype"),
+        TestAction::assert("!('prototype' in add)"),
+        TestAction::assert_eq("apply((x) => x * 2, 21)", 42),
+        TestAction::assert_native_error(
+            "new add(1, 2)",
+            JsNativeErrorKind::Type,
+            "not a constructor",
+        ),
+    ]);
+}
+
+#[test]
+fn objects_cannot_cross_the_boundary() {
+    run_test_actions([
+        TestAction::run("var realm = new ShadowRealm();"),
+        TestAction::assert_native_error(
+            "realm.evaluate('({})')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: only primitives and callables can cross the realm boundary",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('(x) => x')({})",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: only primitives and callables can cross the realm boundary",
+        ),
+    ]);
+}
+
+#[test]
+fn errors_are_copied_as_type_errors() {
+    run_test_actions([
+        TestAction::run("var realm = new ShadowRealm();"),
+        TestAction::assert_native_error(
+            "realm.evaluate('throw new RangeError(\"oops\")')",
+            JsNativeErrorKind::Type,
+            "an error was thrown inside a ShadowRealm: RangeError: oops",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('() => { throw 1; }')()",
+            JsNativeErrorKind::Type,
+            "an error was thrown inside a ShadowRealm",
+        ),
+        TestAction::assert(indoc! {r#"
+            try {
+                realm.evaluate('(')
+            } catch (e) {
+                e instanceof SyntaxError
+            }
+        "#}),
+        TestAction::assert(indoc! {r#"
+            try {
+                realm.evaluate('new.target')
+            } catch (e) {
+                e instanceof SyntaxError
+            }
+        "#}),
+    ]);
+}
+
+#[test]
+fn invalid_arguments() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "ShadowRealm()",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: cannot call constructor without `new`",
+        ),
+        TestAction::assert_native_error(
+            "ShadowRealm.prototype.evaluate.call({}, '1')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: invalid object type for `this`",
+        ),
+        TestAction::assert_native_error(
+            "new ShadowRealm().evaluate(1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: source text must be a string",
+        ),
+        TestAction::assert_native_error(
+            "new ShadowRealm().importValue('./module.js', 1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.importValue: export name must be a string",
+        ),
+    ]);
+}
@@ -0,0 +1,266 @@
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    Context, JsExpect, JsObject, JsResult, JsValue,
+    builtins::function::set_function_name,
+    js_error, js_string,
+    object::{
+        JsData,
+        internal_methods::{
+            CallValue, InternalMethodCallContext, InternalObjectMethods, ORDINARY_INTERNAL_METHODS,
+        },
+    },
+    property::PropertyDescriptor,
+    realm::Realm,
+    string::StaticJsStrings,
+    value::IntegerOrInfinity,
+};
+
+use super::create_type_error_copy;
+
+/// A function that wraps a callable of another realm, created when a callable crosses the
+/// boundary of a `ShadowRealm`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects
+#[derive(Debug, Trace, Finalize)]
+pub struct WrappedFunction {
+    target_function: JsObject,
+    realm: Realm,
+}
+
+impl JsData for WrappedFunction {
+    fn internal_methods(&self) -> &'static InternalObjectMethods {
+        static METHODS: InternalObjectMethods = InternalObjectMethods {
+            __call__: wrapped_function_exotic_call,
+            ..ORDINARY_INTERNAL_METHODS
+        };
+
+        &METHODS
+    }
+}
+
+impl WrappedFunction {
+    /// Abstract operation [`WrappedFunctionCreate ( callerRealm, Target )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrappedfunctioncreate
+    pub(crate) fn create(
+        caller_realm: Realm,
+        target_function: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let internalSlotsList be the internal slots listed in Table 2, plus [[Prototype]] and [[Extensible]].
+        // 2. Let wrapped be MakeBasicObject(internalSlotsList).
+        // 3. Set wrapped.[[Prototype]] to callerRealm.[[Intrinsics]].[[%Function.prototype%]].
+        // 4. Set wrapped.[[Call]] as described in 2.1.
+        // 5. Set wrapped.[[WrappedTargetFunction]] to Target.
+        // 6. Set wrapped.[[Realm]] to callerRealm.
+        let prototype = caller_realm
+            .intrinsics()
+            .constructors()
+            .function()
+            .prototype();
+        let wrapped = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                target_function: target_function.clone(),
+                realm: caller_realm,
+            },
+        )
+        .upcast();
+
+        // 7. Let result be Completion(CopyNameAndLength(wrapped, Target)).
+        // 8. If result is an abrupt completion, throw a TypeError exception.
+        if let Err(err) = copy_name_and_length(&wrapped, target_function, context) {
+            if !err.is_catchable() {
+                return Err(err);
+            }
+            return Err(js_error!(
+                TypeError: "ShadowRealm: could not copy the name and length of a wrapped function"
+            ));
+        }
+
+        // 9. Return wrapped.
+        Ok(wrapped)
+    }
+
+    /// Get a reference to the wrapped function's target function.
+    #[must_use]
+    pub const fn target_function(&self) -> &JsObject {
+        &self.target_function
+    }
+
+    /// Get a reference to the realm of the wrapped function.
+    #[must_use]
+    pub const fn realm(&self) -> &Realm {
+        &self.realm
+    }
+}
+
+/// Abstract operation [`CopyNameAndLength ( F, Target [ , prefix [ , argCount ] ] )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-copynameandlength
+fn copy_name_and_length(f: &JsObject, target: &JsObject, context: &mut Context) -> JsResult<()> {
+    // 1. If argCount is undefined, then set argCount to 0.
+    // 2. Let L be 0.
+    let mut l = JsValue::new(0);
+
+    // 3. Let targetHasLength be ? HasOwnProperty(Target, "length").
+    // 4. If targetHasLength is true, then
+    if target.has_own_property(StaticJsStrings::LENGTH, context)? {
+        // a. Let targetLen be ? Get(Target, "length").
+        let target_len = target.get(StaticJsStrings::LENGTH, context)?;
+        // b. If Type(targetLen) is Number, then
+        if target_len.is_number() {
+            match target_len
+                .to_integer_or_infinity(context)
+                .js_expect("to_integer_or_infinity cannot fail for a number")?
+            {
+                // i. If targetLen is +∞𝔽, set L to +∞.
+                IntegerOrInfinity::PositiveInfinity => l = f64::INFINITY.into(),
+                // ii. Else if targetLen is -∞𝔽, set L to 0.
+                IntegerOrInfinity::NegativeInfinity => {}
+                // iii. Else,
+                //     1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+                //     2. Assert: targetLenAsInt is finite.
+                //     3. Set L to max(targetLenAsInt - argCount, 0).
+                IntegerOrInfinity::Integer(target_len) => l = target_len.max(0).into(),
+            }
+        }
+    }
+
+    // 5. Perform SetFunctionLength(F, L).
+    f.define_property_or_throw(
+        StaticJsStrings::LENGTH,
+        PropertyDescriptor::builder()
+            .value(l)
+            .writable(false)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )
+    .js_expect("defining the `length` property for a new object should not fail")?;
+
+    // 6. Let targetName be ? Get(Target, "name").
+    let target_name = target.get(js_string!("name"), context)?;
+
+    // 7. If Type(targetName) is not String, set targetName to the empty String.
+    let target_name = target_name.as_string().unwrap_or_default();
+
+    // 8. Perform SetFunctionName(F, targetName, prefix).
+    set_function_name(f, &target_name.into(), None, context)?;
+
+    Ok(())
+}
+
+/// Internal method `[[Call]]` for Wrapped Function Exotic Objects
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects-call-thisargument-argumentslist
+fn wrapped_function_exotic_call(
+    obj: &JsObject,
+    argument_count: usize,
+    context: &mut InternalMethodCallContext<'_>,
+) -> JsResult<CallValue> {
+    let args = context
+        .vm
+        .stack
+        .calling_convention_pop_arguments(argument_count);
+    let _func = context.vm.stack.pop();
+    let this = context.vm.stack.pop();
+
+    let (target, mut realm) = {
+        let wrapped = obj.downcast_ref::<WrappedFunction>().js_expect(
+            "wrapped function exotic method should only be callable from wrapped function objects",
+        )?;
+        (wrapped.target_function.clone(), wrapped.realm.clone())
+    };
+
+    // 1. Let callerContext be the running execution context.
+    // 2. Let calleeContext be PrepareForWrappedFunctionCall(F).
+    context.swap_realm(&mut realm);
+
+    // 3. Let result be Completion(OrdinaryWrappedFunctionCall(F, thisArgument, argumentsList)).
+    let result = ordinary_wrapped_function_call(&target, &this, &args, context)
+        .map_err(|err| err.inject_realm(context.realm().clone()));
+
+    // 4. Remove calleeContext from the execution context stack and restore callerContext as the running execution context.
+    context.swap_realm(&mut realm);
+
+    // 5. Return ? result.
+    context.vm.stack.push(result?);
+
+    Ok(CallValue::Complete)
+}
+
+/// Abstract operation [`OrdinaryWrappedFunctionCall ( F, thisArgument, argumentsList )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-ordinary-wrapped-function-call
+fn ordinary_wrapped_function_call(
+    target: &JsObject,
+    this: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let target be F.[[WrappedTargetFunction]].
+    // 2. Assert: IsCallable(target) is true.
+    // 3. Let callerRealm be F.[[Realm]].
+    // 4. NOTE: Any exception objects produced after this point are associated with callerRealm.
+    let caller_realm = context.realm().clone();
+
+    // 5. Let targetRealm be ? GetFunctionRealm(target).
+    let target_realm = target.get_function_realm(context)?;
+
+    // 6. Let wrappedArgs be a new empty List.
+    // 7. For each element arg of argumentsList, do
+    //     a. Let wrappedValue be ? GetWrappedValue(targetRealm, arg).
+    //     b. Append wrappedValue to wrappedArgs.
+    let wrapped_args = args
+        .iter()
+        .map(|arg| get_wrapped_value(&target_realm, arg, context))
+        .collect::<JsResult<Vec<_>>>()?;
+
+    // 8. Let wrappedThisArgument be ? GetWrappedValue(targetRealm, thisArgument).
+    let wrapped_this = get_wrapped_value(&target_realm, this, context)?;
+
+    // 9. Let result be Completion(Call(target, wrappedThisArgument, wrappedArgs)).
+    match target.call(&wrapped_this, &wrapped_args, context) {
+        // 10. If result.[[Type]] is normal or result.[[Type]] is return, then
+        //     a. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+        Ok(result) => get_wrapped_value(&caller_realm, &result, context),
+        // 11. Else,
+        //     a. Let copiedError be CreateTypeErrorCopy(callerRealm, result.[[Value]]).
+        //     b. Return ThrowCompletion(copiedError).
+        Err(err) => Err(create_type_error_copy(err, context)),
+    }
+}
+
+/// Abstract operation [`GetWrappedValue ( callerRealm, value )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-getwrappedvalue
+pub(crate) fn get_wrapped_value(
+    caller_realm: &Realm,
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If Type(value) is Object, then
+    if let Some(object) = value.as_object() {
+        // a. If IsCallable(value) is false, throw a TypeError exception.
+        if !object.is_callable() {
+            return Err(js_error!(
+                TypeError: "ShadowRealm: only primitives and callables can cross the realm boundary"
+            ));
+        }
+
+        // b. Return ? WrappedFunctionCreate(callerRealm, value).
+        return WrappedFunction::create(caller_realm.clone(), &object, context).map(Into::into);
+    }
+
+    // 2. Return value.
+    Ok(value.clone())
+}
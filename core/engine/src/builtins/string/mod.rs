@@ -232,6 +232,16 @@ impl String {
     /// which can differ in JavaScript engines. In Boa it is `2^32 - 1`
     pub(crate) const MAX_STRING_LENGTH: usize = u32::MAX as usize;
 
+    /// Checks that a string of `len` code units can be allocated without going over the heap
+    /// limit of the garbage collector.
+    ///
+    /// Strings are not allocated by the garbage collector, so this must be called before
+    /// creating strings whose length depends on user input.
+    pub(crate) fn check_allocation(len: usize) -> JsResult<()> {
+        boa_gc::check_heap_limit(len.saturating_mul(size_of::<u16>()))?;
+        Ok(())
+    }
+
     /// Abstract function `StringCreate( value, prototype )`.
     ///
     /// Call this function if you want to create a `String` exotic object.
@@ -659,8 +669,11 @@ impl String {
         // 4. For each element next of args, do
         for arg in args {
             // a. Let nextString be ? ToString(next).
+            let next_string = arg.to_string(context)?;
+
             // b. Set R to the string-concatenation of R and nextString.
-            string = js_string!(&string, &arg.to_string(context)?);
+            Self::check_allocation(string.len() + next_string.len())?;
+            string = JsString::concat_rope(&string, &next_string);
         }
 
         // 5. Return R.
@@ -732,6 +745,7 @@ impl String {
         }
 
         let n = n as usize;
+        Self::check_allocation(n * len)?;
 
         // Charge each repetition against the VM loop-iteration limit.
         let mut result = Vec::with_capacity(n);
@@ -1098,6 +1112,9 @@ impl String {
         };
 
         // 13. Return the string-concatenation of preserved, replacement, and the substring of string from position + searchLength.
+        Self::check_allocation(
+            preserved.len() + replacement.len() + (string.len() - position - search_length),
+        )?;
         Ok(js_string!(
             &preserved,
             &replacement,
@@ -1244,6 +1261,7 @@ impl String {
             };
 
             // d. Set result to the string-concatenation of result, preserved, and replacement.
+            Self::check_allocation(result.len() + preserved.len() + replacement.len())?;
             result.extend(preserved.iter());
             result.extend(replacement.iter());
 
@@ -1565,6 +1583,7 @@ impl String {
                 ))
                 .into());
         }
+        Self::check_allocation(int_max_length as usize)?;
 
         let filler_len = filler.len() as u64;
 
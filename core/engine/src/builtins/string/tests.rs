@@ -66,6 +66,27 @@ fn concat() {
     ]);
 }
 
+#[test]
+fn concat_in_loop() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                var report = "";
+                for (let i = 0; i < 10000; i++) {
+                    report += `<tr><td>${i}</td></tr>`;
+                }
+                var other = "".concat(report, "\u{1F600}", report);
+            "#}),
+        TestAction::assert_eq("report.length", 218_890),
+        TestAction::assert_eq("report.slice(0, 23)", js_str!("<tr><td>0</td></tr><tr>")),
+        TestAction::assert_eq("report.at(-11)", js_str!("9")),
+        TestAction::assert_eq("report.endsWith('<td>9999</td></tr>')", true),
+        TestAction::assert_eq("other.length", 218_890 * 2 + 2),
+        TestAction::assert_eq("other.codePointAt(218_890)", 0x1F600),
+        TestAction::assert_eq("other === report + '\u{1F600}' + report", true),
+        TestAction::assert_eq("({ [report]: 1 })[report]", 1),
+    ]);
+}
+
 #[test]
 fn generic_concat() {
     run_test_actions([
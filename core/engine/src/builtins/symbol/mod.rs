@@ -88,6 +88,16 @@ impl GlobalSymbolRegistry {
     }
 }
 
+/// Returns the key of `sym` in the global symbol registry, if it was created by `Symbol.for`.
+pub(crate) fn registry_key(sym: &JsSymbol) -> Option<JsString> {
+    GLOBAL_SYMBOL_REGISTRY.get_key(sym)
+}
+
+/// Returns the symbol of the global symbol registry with the provided key, like `Symbol.for`.
+pub(crate) fn registry_symbol(key: &JsString) -> JsResult<JsSymbol> {
+    GLOBAL_SYMBOL_REGISTRY.get_or_create_symbol(key)
+}
+
 /// The internal representation of a `Symbol` object.
 #[derive(Debug, Clone, Copy)]
 pub struct Symbol;
@@ -109,6 +119,8 @@ impl IntrinsicObject for Symbol {
         let symbol_unscopables = JsSymbol::unscopables();
         let symbol_dispose = JsSymbol::dispose();
         let symbol_async_dispose = JsSymbol::async_dispose();
+        #[cfg(feature = "experimental")]
+        let symbol_metadata = JsSymbol::metadata();
 
         let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
 
@@ -121,7 +133,7 @@ impl IntrinsicObject for Symbol {
             .name(js_string!("get description"))
             .build();
 
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_method(Self::for_, js_string!("for"), 1)
             .static_method(Self::key_for, js_string!("keyFor"), 1)
             .static_property(
@@ -172,8 +184,12 @@ impl IntrinsicObject for Symbol {
                 symbol_to_primitive,
                 to_primitive,
                 Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-            )
-            .build();
+            );
+
+        #[cfg(feature = "experimental")]
+        let builder = builder.static_property(js_string!("metadata"), symbol_metadata, attribute);
+
+        builder.build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -188,7 +204,7 @@ impl BuiltInObject for Symbol {
 impl BuiltInConstructor for Symbol {
     const CONSTRUCTOR_ARGUMENTS: usize = 0;
     const PROTOTYPE_STORAGE_SLOTS: usize = 6;
-    const CONSTRUCTOR_STORAGE_SLOTS: usize = 17;
+    const CONSTRUCTOR_STORAGE_SLOTS: usize = 18;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::symbol;
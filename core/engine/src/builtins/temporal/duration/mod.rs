@@ -1115,21 +1115,24 @@ impl Duration {
         Ok(JsString::from(result).into())
     }
 
-    /// 7.3.24 `Temporal.Duration.prototype.toLocaleString ( )`
+    /// 7.3.24 `Temporal.Duration.prototype.toLocaleString ( [ locales [ , options ] ] )`
     ///
     /// More information:
     ///
     /// - [ECMAScript Temporal proposal][spec]
+    /// - [ECMA-402 reference][intl-spec]
     /// - [MDN reference][mdn]
     ///
     /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.duration.prototype.tolocalestring
+    /// [intl-spec]: https://tc39.es/ecma402/#sup-temporal.duration.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Duration/toLocaleString
     pub(crate) fn to_locale_string(
         this: &JsValue,
-        _: &[JsValue],
-        _: &mut Context,
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] args: &[JsValue],
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] context: &mut Context,
     ) -> JsResult<JsValue> {
-        // TODO: Update for ECMA-402 compliance
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
         let object = this.as_object();
         let duration = object
             .as_ref()
@@ -1138,11 +1141,34 @@ impl Duration {
                 JsNativeError::typ().with_message("this value must be a Duration object.")
             })?;
 
-        let result = duration
-            .inner
-            .as_temporal_string(ToStringRoundingOptions::default())?;
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::duration_format::{DurationFormat, DurationRecord};
 
-        Ok(JsString::from(result).into())
+            let record = DurationRecord::from(&*duration.inner);
+            drop(duration);
+
+            // 3. Let formatter be ? Construct(%Intl.DurationFormat%, « locales, options »).
+            let formatter =
+                DurationFormat::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+            // 4. Let parts be PartitionDurationFormatPattern(formatter, duration).
+            // 5. Let result be the empty String.
+            // 6. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+            //     a. Set result to the string-concatenation of result and part.[[Value]].
+            // 7. Return result.
+            let result = formatter.format_to_string(&record, context.intl_provider())?;
+            Ok(JsString::from(result).into())
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let result = duration
+                .inner
+                .as_temporal_string(ToStringRoundingOptions::default())?;
+
+            Ok(JsString::from(result).into())
+        }
     }
 
     /// 7.3.25 `Temporal.Duration.prototype.valueOf ( )`
@@ -660,12 +660,19 @@ impl Instant {
     /// More information:
     ///
     /// - [ECMAScript Temporal proposal][spec]
+    /// - [ECMA-402 reference][intl-spec]
     /// - [MDN reference][mdn]
     ///
     /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.instant.tolocalestring
+    /// [intl-spec]: https://tc39.es/proposal-temporal/#sup-temporal.instant.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/Instant/toLocaleString
-    fn to_locale_string(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        // TODO: Update for ECMA-402 compliance
+    fn to_locale_string(
+        this: &JsValue,
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let instant be the this value.
+        // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
         let object = this.as_object();
         let instant = object
             .as_ref()
@@ -675,12 +682,35 @@ impl Instant {
                     .with_message("the this object must be a Temporal.Instant object.")
             })?;
 
-        let ixdtf = instant.inner.to_ixdtf_string_with_provider(
-            None,
-            ToStringRoundingOptions::default(),
-            context.timezone_provider(),
-        )?;
-        Ok(JsString::from(ixdtf).into())
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{
+                FormatDefaults, FormatType, format_temporal_locale,
+            };
+
+            drop(instant);
+
+            // 3. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, any, all).
+            // 4. Return ? FormatDateTime(dateFormat, instant).
+            format_temporal_locale(
+                this,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                FormatType::Any,
+                FormatDefaults::All,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let ixdtf = instant.inner.to_ixdtf_string_with_provider(
+                None,
+                ToStringRoundingOptions::default(),
+                context.timezone_provider(),
+            )?;
+            Ok(JsString::from(ixdtf).into())
+        }
     }
 
     /// 8.3.13 `Temporal.Instant.prototype.toJSON ( )`
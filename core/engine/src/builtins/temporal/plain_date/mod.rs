@@ -1216,12 +1216,19 @@ impl PlainDate {
     /// More information:
     ///
     /// - [ECMAScript Temporal proposal][spec]
+    /// - [ECMA-402 reference][intl-spec]
     /// - [MDN reference][mdn]
     ///
     /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tolocalestring
+    /// [intl-spec]: https://tc39.es/proposal-temporal/#sup-temporal.plaindate.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDate/toLocaleString
-    fn to_locale_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
-        // TODO: Update for ECMA-402 compliance
+    fn to_locale_string(
+        this: &JsValue,
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] args: &[JsValue],
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
         let object = this.as_object();
         let date = object
             .as_ref()
@@ -1230,7 +1237,30 @@ impl PlainDate {
                 JsNativeError::typ().with_message("the this object must be a PlainDate object.")
             })?;
 
-        Ok(JsString::from(date.inner.to_string()).into())
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{
+                FormatDefaults, FormatType, format_temporal_locale,
+            };
+
+            drop(date);
+
+            // 3. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, date, date).
+            // 4. Return ? FormatDateTime(dateFormat, temporalDate).
+            format_temporal_locale(
+                this,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                FormatType::Date,
+                FormatDefaults::Date,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            Ok(JsString::from(date.inner.to_string()).into())
+        }
     }
 
     /// 3.3.32 `Temporal.PlainDate.prototype.toJSON ( )`
@@ -1454,12 +1454,19 @@ impl PlainDateTime {
     /// More information:
     ///
     /// - [ECMAScript Temporal proposal][spec]
+    /// - [ECMA-402 reference][intl-spec]
     /// - [MDN reference][mdn]
     ///
-    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.with
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/with
-    fn to_locale_string(this: &JsValue, _args: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
-        // TODO: Update for ECMA-402 compliance
+    /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tolocalestring
+    /// [intl-spec]: https://tc39.es/proposal-temporal/#sup-temporal.plaindatetime.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainDateTime/toLocaleString
+    fn to_locale_string(
+        this: &JsValue,
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] args: &[JsValue],
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(dateTime, [[InitializedTemporalDateTime]]).
         let object = this.as_object();
         let dt = object
             .as_ref()
@@ -1468,10 +1475,33 @@ impl PlainDateTime {
                 JsNativeError::typ().with_message("the this object must be a PlainDateTime object.")
             })?;
 
-        let ixdtf = dt
-            .inner
-            .to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto)?;
-        Ok(JsString::from(ixdtf).into())
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{
+                FormatDefaults, FormatType, format_temporal_locale,
+            };
+
+            drop(dt);
+
+            // 3. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, any, all).
+            // 4. Return ? FormatDateTime(dateFormat, dateTime).
+            format_temporal_locale(
+                this,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                FormatType::Any,
+                FormatDefaults::All,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let ixdtf = dt
+                .inner
+                .to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto)?;
+            Ok(JsString::from(ixdtf).into())
+        }
     }
 
     /// 5.3.36 `Temporal.PlainDateTime.prototype.toJSON ( )`
@@ -370,16 +370,19 @@ impl PlainMonthDay {
     /// More information:
     ///
     /// - [ECMAScript Temporal proposal][spec]
+    /// - [ECMA-402 reference][intl-spec]
     /// - [MDN reference][mdn]
     ///
     /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.tolocalestring
+    /// [intl-spec]: https://tc39.es/proposal-temporal/#sup-temporal.plainmonthday.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainMonthDay/toLocaleString
     pub(crate) fn to_locale_string(
         this: &JsValue,
-        _: &[JsValue],
-        _: &mut Context,
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] args: &[JsValue],
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] context: &mut Context,
     ) -> JsResult<JsValue> {
-        // TODO: Update for ECMA-402 compliance
+        // 1. Let monthDay be the this value.
+        // 2. Perform ? RequireInternalSlot(monthDay, [[InitializedTemporalMonthDay]]).
         let object = this.as_object();
         let month_day = object
             .as_ref()
@@ -388,7 +391,30 @@ impl PlainMonthDay {
                 JsNativeError::typ().with_message("this value must be a PlainMonthDay object.")
             })?;
 
-        Ok(JsString::from(month_day.inner.to_string()).into())
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{
+                FormatDefaults, FormatType, format_temporal_locale,
+            };
+
+            drop(month_day);
+
+            // 3. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, date, date).
+            // 4. Return ? FormatDateTime(dateFormat, monthDay).
+            format_temporal_locale(
+                this,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                FormatType::Date,
+                FormatDefaults::Date,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            Ok(JsString::from(month_day.inner.to_string()).into())
+        }
     }
 
     /// 10.3.10 `Temporal.PlainMonthDay.prototype.toJSON ( )`
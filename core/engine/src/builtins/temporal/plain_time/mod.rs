@@ -48,7 +48,7 @@ mod tests;
 #[derive(Debug, Clone, Copy, Trace, Finalize, JsData)]
 #[boa_gc(unsafe_empty_trace)] // Safety: PlainTimeInner does not contain any traceable types.
 pub struct PlainTime {
-    inner: PlainTimeInner,
+    pub(crate) inner: PlainTimeInner,
 }
 
 impl BuiltInObject for PlainTime {
@@ -776,12 +776,19 @@ impl PlainTime {
     /// More information:
     ///
     /// - [ECMAScript Temporal proposal][spec]
+    /// - [ECMA-402 reference][intl-spec]
     /// - [MDN reference][mdn]
     ///
     /// [spec]: https://tc39.es/proposal-temporal/#sec-temporal.plaintime.prototype.tolocalestring
+    /// [intl-spec]: https://tc39.es/proposal-temporal/#sup-temporal.plaintime.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Temporal/PlainTime/toLocaleString
-    fn to_locale_string(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
-        // TODO: Update for ECMA-402 compliance
+    fn to_locale_string(
+        this: &JsValue,
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] args: &[JsValue],
+        #[cfg_attr(not(feature = "intl"), allow(unused_variables))] context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let temporalTime be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalTime, [[InitializedTemporalTime]]).
         let object = this.as_object();
         let time = object
             .as_ref()
@@ -790,10 +797,33 @@ impl PlainTime {
                 JsNativeError::typ().with_message("the this object must be a PlainTime object.")
             })?;
 
-        let ixdtf = time
-            .inner
-            .to_ixdtf_string(ToStringRoundingOptions::default())?;
-        Ok(JsString::from(ixdtf).into())
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{
+                FormatDefaults, FormatType, format_temporal_locale,
+            };
+
+            drop(time);
+
+            // 3. Let timeFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, time, time).
+            // 4. Return ? FormatDateTime(timeFormat, temporalTime).
+            format_temporal_locale(
+                this,
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                FormatType::Time,
+                FormatDefaults::Time,
+                context,
+            )
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let ixdtf = time
+                .inner
+                .to_ixdtf_string(ToStringRoundingOptions::default())?;
+            Ok(JsString::from(ixdtf).into())
+        }
     }
 
     /// 4.3.18 `Temporal.PlainTime.prototype.toJSON ( )`
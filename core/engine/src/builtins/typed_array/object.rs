@@ -122,6 +122,12 @@ impl TypedArray {
         self.kind
     }
 
+    /// Gets the `[[ByteLength]]` and `[[ArrayLength]]` internal slots, which are `None` for
+    /// typed arrays that track the length of their buffer.
+    pub(crate) const fn length_slots(&self) -> (Option<u64>, Option<u64>) {
+        (self.byte_length, self.array_length)
+    }
+
     /// Get a reference to the `TypedArray` object's viewed array buffer.
     #[must_use]
     pub(crate) const fn viewed_array_buffer(&self) -> &BufferObject {
@@ -59,6 +59,25 @@ impl Default for UriFunctions {
 }
 
 impl UriFunctions {
+    /// Appends every URI function to `objects`.
+    pub(crate) fn collect_objects(&self, objects: &mut Vec<JsObject>) {
+        let Self {
+            decode_uri,
+            decode_uri_component,
+            encode_uri,
+            encode_uri_component,
+        } = self;
+        objects.extend(
+            [
+                decode_uri,
+                decode_uri_component,
+                encode_uri,
+                encode_uri_component,
+            ]
+            .map(|function| function.clone().into()),
+        );
+    }
+
     pub(crate) fn decode_uri(&self) -> JsFunction {
         self.decode_uri.clone()
     }
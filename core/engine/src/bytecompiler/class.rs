@@ -1,5 +1,7 @@
 use super::{BindingAccessOpcode, ByteCompiler, Literal, Register, ToJsString};
 use crate::{
+    JsString,
+    builtins::function::decorator::ClassElementFlags,
     js_string,
     vm::{CodeBlock, CodeBlockFlags, opcode::BindingOpcode},
 };
@@ -7,8 +9,8 @@ use boa_ast::{
     Expression,
     expression::Identifier,
     function::{
-        ClassDeclaration, ClassElement, ClassElementName, ClassExpression, FormalParameterList,
-        FunctionExpression,
+        ClassDeclaration, ClassElement, ClassElementName, ClassExpression, Decorator,
+        FormalParameterList, FunctionExpression,
     },
     property::{MethodDefinitionKind, PropertyName},
     scope::Scope,
@@ -28,6 +30,9 @@ enum StaticElement {
         name_index: StaticFieldName,
         is_anonymous_function: bool,
     },
+
+    // A decorated static field or a static auto-accessor, with its index in the class decoration state.
+    DecoratedField(u32),
 }
 
 enum StaticFieldName {
@@ -43,6 +48,7 @@ pub(crate) struct ClassSpec<'a> {
     super_ref: Option<&'a Expression>,
     constructor: Option<&'a FunctionExpression>,
     elements: &'a [ClassElement],
+    decorators: &'a [Decorator],
     has_binding_identifier: bool,
     name_scope: Option<&'a Scope>,
 }
@@ -54,6 +60,7 @@ impl<'a> From<&'a ClassDeclaration> for ClassSpec<'a> {
             super_ref: class.super_ref(),
             constructor: class.constructor(),
             elements: class.elements(),
+            decorators: class.decorators(),
             has_binding_identifier: true,
             name_scope: Some(class.name_scope()),
         }
@@ -67,12 +74,25 @@ impl<'a> From<&'a ClassExpression> for ClassSpec<'a> {
             super_ref: class.super_ref(),
             constructor: class.constructor(),
             elements: class.elements(),
+            decorators: class.decorators(),
             has_binding_identifier: class.name().is_some(),
             name_scope: class.name_scope(),
         }
     }
 }
 
+impl ClassSpec<'_> {
+    /// Returns `true` if the class, or any of its elements, is decorated or declares an
+    /// auto-accessor.
+    fn needs_decoration(&self) -> bool {
+        !self.decorators.is_empty()
+            || self
+                .elements
+                .iter()
+                .any(|element| !element.decorators().is_empty() || element.is_accessor())
+    }
+}
+
 impl ByteCompiler<'_> {
     /// This function compiles a class declaration or expression.
     ///
@@ -90,6 +110,14 @@ impl ByteCompiler<'_> {
             .map_or(Sym::EMPTY_STRING, Identifier::sym)
             .to_js_string(self.interner());
 
+        // Classes with decorators or auto-accessors are defined through a class decoration state.
+        // The class decorators are evaluated before the class binding is created.
+        let class_decorators = class.needs_decoration().then(|| {
+            let decorators = self.register_allocator.alloc();
+            self.compile_decorators(class.decorators, &decorators);
+            decorators
+        });
+
         let outer_scope = self.push_declarative_scope(class.name_scope);
 
         // The new span is not the same as the parent `ByteCompiler` have.
@@ -217,7 +245,35 @@ impl ByteCompiler<'_> {
             );
         }
 
+        let decoration = class_decorators.map(|decorators| {
+            let state = self.register_allocator.alloc();
+            self.bytecode.emit_create_class_decoration(
+                state.variable(),
+                class_register.variable(),
+                proto_register.variable(),
+                decorators.variable(),
+            );
+            self.register_allocator.dealloc(decorators);
+            state
+        });
+        let mut decorated_elements = 0;
+
         for element in class.elements {
+            if let Some(state) = &decoration
+                && (!element.decorators().is_empty() || element.is_accessor())
+            {
+                self.compile_decorated_class_element(
+                    element,
+                    &class_name,
+                    &class_register,
+                    state,
+                    decorated_elements,
+                    &mut static_elements,
+                );
+                decorated_elements += 1;
+                continue;
+            }
+
             match element {
                 ClassElement::MethodDefinition(m) => match m.name() {
                     ClassElementName::PropertyName(PropertyName::Literal(name)) => {
@@ -635,6 +691,23 @@ impl ByteCompiler<'_> {
             }
         }
 
+        // The class binding refers to the decorated class during the static initialization.
+        let decorated_class = decoration.as_ref().map(|state| {
+            let decorated_class = self.register_allocator.alloc();
+            self.bytecode
+                .emit_apply_class_decorators(decorated_class.variable(), state.variable());
+            if let Some(scope) = class.name_scope {
+                let binding = scope.get_identifier_reference(class_name.clone());
+                let index = self.insert_binding(binding);
+                self.emit_binding_access(
+                    BindingAccessOpcode::PutLexicalValue,
+                    &index,
+                    &decorated_class,
+                );
+            }
+            decorated_class
+        });
+
         for element in static_elements {
             match element {
                 StaticElement::StaticBlock(code) => {
@@ -701,9 +774,25 @@ impl ByteCompiler<'_> {
 
                     self.register_allocator.dealloc(value);
                 }
+                StaticElement::DecoratedField(index) => {
+                    let state = decoration
+                        .as_ref()
+                        .expect("decorated static elements require a class decoration state");
+                    self.bytecode
+                        .emit_initialize_decorated_static_element(state.variable(), index.into());
+                }
             }
         }
 
+        if let (Some(state), Some(decorated_class)) = (decoration, decorated_class) {
+            self.bytecode
+                .emit_run_class_extra_initializers(state.variable(), decorated_class.variable());
+            self.bytecode
+                .emit_move(class_register.variable(), decorated_class.variable());
+            self.register_allocator.dealloc(decorated_class);
+            self.register_allocator.dealloc(state);
+        }
+
         self.register_allocator.dealloc(proto_register);
 
         self.pop_declarative_scope(outer_scope);
@@ -721,4 +810,249 @@ impl ByteCompiler<'_> {
         // NOTE: Reset strict mode to before class declaration/expression evaluation.
         self.code_block_flags.set(CodeBlockFlags::STRICT, strict);
     }
+
+    /// Evaluates a list of decorators into an array of `this` and decorator function pairs.
+    ///
+    /// Decorators that are member expressions keep their base object as the `this` value of the
+    /// decorator call.
+    fn compile_decorators(&mut self, decorators: &[Decorator], dst: &Register) {
+        self.bytecode.emit_store_new_array(dst.variable());
+
+        let this = self.register_allocator.alloc();
+        let value = self.register_allocator.alloc();
+        for decorator in decorators {
+            match decorator.expression().flatten() {
+                Expression::PropertyAccess(access) => {
+                    self.compile_access_preserve_this(access, &this, &value);
+                }
+                expr => {
+                    self.bytecode.emit_store_undefined(this.variable());
+                    self.compile_expr(expr, &value);
+                }
+            }
+            self.bytecode
+                .emit_push_value_to_array(this.variable(), dst.variable());
+            self.bytecode
+                .emit_push_value_to_array(value.variable(), dst.variable());
+        }
+        self.register_allocator.dealloc(value);
+        self.register_allocator.dealloc(this);
+    }
+
+    /// Stores the property key of a class element into `dst`.
+    fn compile_class_element_key(&mut self, name: &PropertyName, dst: &Register) {
+        match name {
+            PropertyName::Literal(ident) => {
+                self.emit_store_literal(
+                    Literal::String(
+                        self.interner()
+                            .resolve_expect(ident.sym())
+                            .into_common(false),
+                    ),
+                    dst,
+                );
+            }
+            PropertyName::Computed(expr) => {
+                self.compile_expr(expr, dst);
+                self.bytecode
+                    .emit_to_property_key(dst.variable(), dst.variable());
+            }
+        }
+    }
+
+    /// Compiles the initializer function of a class field.
+    ///
+    /// Returns the function code and if the initializer is an anonymous function definition.
+    fn compile_field_initializer(
+        &mut self,
+        class_name: &JsString,
+        scope: &Scope,
+        initializer: Option<&Expression>,
+    ) -> (Gc<CodeBlock>, bool) {
+        let mut field_compiler = ByteCompiler::new(
+            class_name.clone(),
+            true,
+            self.json_parse,
+            self.variable_scope.clone(),
+            self.lexical_scope.clone(),
+            false,
+            false,
+            self.interner,
+            self.in_with,
+            self.spanned_source_text.clone_only_source(),
+            self.source_path.clone(),
+        );
+        field_compiler.code_block_flags |= CodeBlockFlags::HAS_FUNCTION_SCOPE;
+        let _ = field_compiler.push_scope(scope);
+        let value = field_compiler.register_allocator.alloc();
+        let is_anonymous_function = if let Some(node) = initializer {
+            field_compiler.compile_expr(node, &value);
+            node.is_anonymous_function_definition()
+        } else {
+            field_compiler
+                .bytecode
+                .emit_store_undefined(value.variable());
+            false
+        };
+        field_compiler
+            .bytecode
+            .emit_set_accumulator(value.variable());
+        field_compiler.register_allocator.dealloc(value);
+
+        field_compiler.code_block_flags |= CodeBlockFlags::IN_CLASS_FIELD_INITIALIZER;
+
+        (Gc::new(field_compiler.finish()), is_anonymous_function)
+    }
+
+    /// Compiles a decorated class element or an auto-accessor.
+    ///
+    /// Instead of being defined right away, the element is recorded in the class decoration
+    /// `state`, where it is decorated and defined once all the elements have been evaluated.
+    fn compile_decorated_class_element(
+        &mut self,
+        element: &ClassElement,
+        class_name: &JsString,
+        class_register: &Register,
+        state: &Register,
+        index: u32,
+        static_elements: &mut Vec<StaticElement>,
+    ) {
+        let decorators = self.register_allocator.alloc();
+        self.compile_decorators(element.decorators(), &decorators);
+
+        let key = self.register_allocator.alloc();
+        let value = self.register_allocator.alloc();
+        let storage = self.register_allocator.alloc();
+        self.bytecode.emit_store_undefined(storage.variable());
+
+        let mut flags = ClassElementFlags::empty();
+        match element {
+            ClassElement::MethodDefinition(m) => {
+                match m.name() {
+                    ClassElementName::PropertyName(name) => {
+                        self.compile_class_element_key(name, &key);
+                    }
+                    ClassElementName::PrivateName(name) => {
+                        flags |= ClassElementFlags::PRIVATE;
+                        self.emit_store_literal(
+                            Literal::String(name.description().to_js_string(self.interner())),
+                            &key,
+                        );
+                    }
+                }
+                match m.kind() {
+                    MethodDefinitionKind::Get => flags |= ClassElementFlags::GETTER,
+                    MethodDefinitionKind::Set => flags |= ClassElementFlags::SETTER,
+                    _ => {}
+                }
+                flags.set(ClassElementFlags::STATIC, m.is_static());
+
+                let method = self.method(m.into());
+                self.bytecode.emit_move(value.variable(), method.variable());
+                self.register_allocator.dealloc(method);
+            }
+            ClassElement::StaticBlock(_) => unreachable!("static blocks cannot be decorated"),
+            _ => {
+                let (scope, initializer, is_accessor, name_index) = match element {
+                    ClassElement::FieldDefinition(field)
+                    | ClassElement::StaticFieldDefinition(field) => {
+                        self.compile_class_element_key(field.name(), &key);
+                        (
+                            field.scope(),
+                            field.initializer(),
+                            field.is_accessor(),
+                            None,
+                        )
+                    }
+                    ClassElement::PrivateFieldDefinition(field)
+                    | ClassElement::PrivateStaticFieldDefinition(field) => {
+                        flags |= ClassElementFlags::PRIVATE;
+                        self.emit_store_literal(
+                            Literal::String(
+                                field.name().description().to_js_string(self.interner()),
+                            ),
+                            &key,
+                        );
+                        let name_index = self.get_or_insert_private_name(*field.name());
+                        (
+                            field.scope(),
+                            field.initializer(),
+                            field.is_accessor(),
+                            Some(name_index),
+                        )
+                    }
+                    _ => unreachable!("only fields remain"),
+                };
+                let is_static = matches!(
+                    element,
+                    ClassElement::StaticFieldDefinition(_)
+                        | ClassElement::PrivateStaticFieldDefinition(_)
+                );
+                flags.set(ClassElementFlags::STATIC, is_static);
+
+                let (code, is_anonymous_function) =
+                    self.compile_field_initializer(class_name, scope, initializer);
+                let function_index = self.push_function_to_constants(code);
+                self.emit_get_function(&value, function_index);
+
+                if is_accessor {
+                    // The value of an auto-accessor is kept in a private storage that is not
+                    // reachable from the class body.
+                    flags |= ClassElementFlags::ACCESSOR;
+                    let description = js_string!(format!("accessor storage {index}"));
+                    let storage_index = self.get_or_insert_string(description.clone());
+                    self.emit_store_literal(Literal::String(description), &storage);
+                    if !is_static {
+                        self.bytecode.emit_push_class_field_private(
+                            class_register.variable(),
+                            value.variable(),
+                            storage_index.into(),
+                        );
+                    }
+                } else {
+                    flags |= ClassElementFlags::FIELD;
+                    flags.set(
+                        ClassElementFlags::ANONYMOUS_FUNCTION,
+                        is_anonymous_function && name_index.is_none(),
+                    );
+                    match name_index {
+                        _ if is_static => {}
+                        Some(name_index) => {
+                            self.bytecode.emit_push_class_field_private(
+                                class_register.variable(),
+                                value.variable(),
+                                name_index.into(),
+                            );
+                        }
+                        None => {
+                            self.bytecode.emit_push_class_field(
+                                class_register.variable(),
+                                key.variable(),
+                                value.variable(),
+                                is_anonymous_function.into(),
+                            );
+                        }
+                    }
+                }
+
+                if is_static {
+                    static_elements.push(StaticElement::DecoratedField(index));
+                }
+            }
+        }
+
+        self.bytecode.emit_push_class_element_decorators(
+            state.variable(),
+            key.variable(),
+            value.variable(),
+            decorators.variable(),
+            storage.variable(),
+            flags.bits().into(),
+        );
+
+        self.register_allocator.dealloc(storage);
+        self.register_allocator.dealloc(value);
+        self.register_allocator.dealloc(key);
+        self.register_allocator.dealloc(decorators);
+    }
 }
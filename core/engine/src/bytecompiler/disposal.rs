@@ -0,0 +1,166 @@
+use super::{ByteCompiler, Register, jump_control::JumpControlInfoFlags};
+use boa_ast::{
+    StatementList, StatementListItem,
+    declaration::{Declaration, LexicalDeclaration},
+};
+
+/// The registers of a scope containing `using` or `await using` declarations.
+///
+/// See [`ByteCompiler::push_disposal_scope`].
+pub(crate) struct DisposalScope {
+    handler: u32,
+    re_throw: Register,
+    jump_index: Register,
+    re_throw_generator: Option<Register>,
+}
+
+/// Returns `true` if the statement list directly contains a `using` or `await using` declaration.
+pub(crate) fn has_using_declarations(list: &StatementList) -> bool {
+    list.statements().iter().any(|item| {
+        matches!(
+            item,
+            StatementListItem::Declaration(declaration)
+                if matches!(
+                    declaration.as_ref(),
+                    Declaration::Lexical(
+                        LexicalDeclaration::Using(_) | LexicalDeclaration::AwaitUsing(_)
+                    )
+                )
+        )
+    })
+}
+
+impl ByteCompiler<'_> {
+    /// Starts a scope that disposes the resources of its `using` and `await using`
+    /// declarations when it's exited.
+    ///
+    /// The scope behaves like a `try` statement with a `finally` block, which performs the
+    /// [`DisposeResources`][spec] operation on normal completions, thrown errors, and
+    /// `break`, `continue` or `return` statements.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn push_disposal_scope(&mut self, use_expr: bool) -> DisposalScope {
+        let re_throw = self.register_allocator.alloc();
+        let jump_index = self.register_allocator.alloc();
+
+        self.bytecode.emit_store_true(re_throw.variable());
+        self.bytecode.emit_store_zero(jump_index.variable());
+
+        self.push_try_with_finally_control_info(&re_throw, &jump_index, use_expr);
+
+        let re_throw_generator = self.is_generator().then(|| {
+            let re_throw_generator = self.register_allocator.alloc();
+            self.bytecode
+                .emit_store_false(re_throw_generator.variable());
+            re_throw_generator
+        });
+
+        self.bytecode.emit_push_dispose_scope();
+        let handler = self.push_handler();
+
+        DisposalScope {
+            handler,
+            re_throw,
+            jump_index,
+            re_throw_generator,
+        }
+    }
+
+    /// Ends a scope started by [`ByteCompiler::push_disposal_scope`], disposing all of its
+    /// resources.
+    pub(crate) fn pop_disposal_scope(&mut self, scope: DisposalScope) {
+        let DisposalScope {
+            handler,
+            re_throw,
+            jump_index,
+            re_throw_generator,
+        } = scope;
+
+        self.bytecode.emit_store_false(re_throw.variable());
+        let finally = self.jump();
+
+        self.patch_handler(handler);
+
+        let error = self.register_allocator.alloc();
+        if let Some(re_throw_generator) = &re_throw_generator {
+            let catch_handler = self.push_handler();
+            self.bytecode.emit_exception(error.variable());
+            self.bytecode.emit_dispose_error(error.variable());
+
+            // If the `Exception` opcode rethrows, this is a generator `return()` empty exception,
+            // which must continue propagating after the resources are disposed.
+            let no_throw = self.jump();
+            self.patch_handler(catch_handler);
+            self.bytecode.emit_store_true(re_throw_generator.variable());
+            self.patch_jump(no_throw);
+        } else {
+            self.bytecode.emit_exception(error.variable());
+            self.bytecode.emit_dispose_error(error.variable());
+        }
+
+        self.patch_jump(finally);
+
+        let finally_start = self.next_opcode_location();
+        self.jump_info
+            .last_mut()
+            .expect("there should be a disposal scope")
+            .flags |= JumpControlInfoFlags::IN_FINALLY;
+
+        let value = self.register_allocator.alloc();
+        self.bytecode
+            .emit_set_register_from_accumulator(value.variable());
+
+        let result = self.register_allocator.alloc();
+        let done = self.register_allocator.alloc();
+        if self.is_async() {
+            // Dispose the resources one by one, awaiting the values returned by
+            // `DisposeResources` until all resources have been disposed.
+            let start = self.next_opcode_location();
+            self.bytecode
+                .emit_dispose_resources(result.variable(), done.variable());
+            let exit = self.jump_if_true(&done);
+
+            let await_handler = self.push_handler();
+            self.bytecode.emit_await(result.variable());
+            let resume_kind = self.register_allocator.alloc();
+            self.pop_into_register(&resume_kind);
+            self.pop_into_register(&result);
+            self.generator_next(&result, &resume_kind);
+            self.register_allocator.dealloc(resume_kind);
+            self.bytecode.emit_jump(start);
+
+            self.patch_handler(await_handler);
+            self.bytecode.emit_exception(error.variable());
+            self.bytecode.emit_dispose_error(error.variable());
+            self.bytecode.emit_jump(start);
+
+            self.patch_jump(exit);
+        } else {
+            self.bytecode
+                .emit_dispose_resources(result.variable(), done.variable());
+        }
+        self.register_allocator.dealloc(result);
+
+        self.bytecode
+            .emit_pop_dispose_scope(done.variable(), error.variable());
+        self.bytecode.emit_set_accumulator(value.variable());
+        self.register_allocator.dealloc(value);
+
+        let no_error = self.jump_if_false(&done);
+        self.bytecode.emit_throw(error.variable());
+        self.patch_jump(no_error);
+        self.register_allocator.dealloc(done);
+        self.register_allocator.dealloc(error);
+
+        if let Some(re_throw_generator) = re_throw_generator {
+            let do_not_throw_exit = self.jump_if_false(&re_throw_generator);
+            self.bytecode.emit_re_throw();
+            self.patch_jump(do_not_throw_exit);
+            self.register_allocator.dealloc(re_throw_generator);
+        }
+
+        self.pop_try_with_finally_control_info(finally_start);
+        self.register_allocator.dealloc(re_throw);
+        self.register_allocator.dealloc(jump_index);
+    }
+}
@@ -11,6 +11,7 @@
 mod class;
 mod declaration;
 mod declarations;
+mod disposal;
 mod env;
 mod expression;
 mod function;
@@ -74,6 +75,7 @@ use thin_vec::ThinVec;
 pub(crate) use declarations::{
     global_declaration_instantiation_context, prepare_eval_declaration_instantiation,
 };
+use disposal::has_using_declarations;
 pub(crate) use function::FunctionCompiler;
 pub(crate) use jump_control::JumpControlInfo;
 pub(crate) use register::*;
@@ -1657,6 +1659,18 @@ impl<'ctx> ByteCompiler<'ctx> {
 
     /// Compile a [`StatementList`].
     pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool, block: bool) {
+        if has_using_declarations(list) {
+            let scope = self.push_disposal_scope(use_expr);
+            self.compile_statement_list_items(list, use_expr, block);
+            self.pop_disposal_scope(scope);
+        } else {
+            self.compile_statement_list_items(list, use_expr, block);
+        }
+    }
+
+    /// Compile the items of a [`StatementList`], without disposing the resources of
+    /// its `using` declarations.
+    fn compile_statement_list_items(&mut self, list: &StatementList, use_expr: bool, block: bool) {
         if use_expr || self.jump_control_info_has_use_expr() {
             let mut use_expr_index = 0;
             for (i, statement) in list.statements().iter().enumerate() {
@@ -2242,89 +2256,27 @@ impl<'ctx> ByteCompiler<'ctx> {
                     }
                 }
             }
-            LexicalDeclaration::Using(decls) => {
-                // For each using declaration, we need to:
-                // 1. Evaluate the initializer
-                // 2. Add the resource to the disposal stack
-                // 3. Bind the variable
+            LexicalDeclaration::Using(decls) | LexicalDeclaration::AwaitUsing(decls) => {
+                let r#async = matches!(decl, LexicalDeclaration::AwaitUsing(_));
                 for variable in decls.as_ref() {
-                    match variable.binding() {
-                        Binding::Identifier(ident) => {
-                            let ident = ident.to_js_string(self.interner());
-                            let value = self.register_allocator.alloc();
-
-                            if let Some(init) = variable.init() {
-                                self.compile_expr(init, &value);
-                            } else {
-                                self.bytecode.emit_store_undefined(value.variable());
-                            }
-
-                            // TODO(@abhinavs1920): Add resource to disposal stack
-                            // For now, we just bind the variable like a let declaration
-                            // Full implementation will add: AddDisposableResource opcode
-
-                            self.emit_binding(BindingOpcode::InitLexical, ident, &value);
-                            self.register_allocator.dealloc(value);
-                        }
-                        Binding::Pattern(pattern) => {
-                            let value = self.register_allocator.alloc();
-
-                            if let Some(init) = variable.init() {
-                                self.compile_expr(init, &value);
-                            } else {
-                                self.bytecode.emit_store_undefined(value.variable());
-                            }
-
-                            // TODO: Same as above
-
-                            self.compile_declaration_pattern(
-                                pattern,
-                                BindingOpcode::InitLexical,
-                                &value,
-                            );
-                            self.register_allocator.dealloc(value);
-                        }
-                    }
-                }
-            }
-            LexicalDeclaration::AwaitUsing(decls) => {
-                for variable in decls.as_ref() {
-                    match variable.binding() {
-                        Binding::Identifier(ident) => {
-                            let ident = ident.to_js_string(self.interner());
-                            let value = self.register_allocator.alloc();
-
-                            if let Some(init) = variable.init() {
-                                self.compile_expr(init, &value);
-                            } else {
-                                self.bytecode.emit_store_undefined(value.variable());
-                            }
-
-                            // TODO: Add resource to async disposal stack
-                            // For now, we just bind the variable like a let declaration
-                            // Full implementation will add: AddAsyncDisposableResource opcode
-
-                            self.emit_binding(BindingOpcode::InitLexical, ident, &value);
-                            self.register_allocator.dealloc(value);
-                        }
-                        Binding::Pattern(pattern) => {
-                            let value = self.register_allocator.alloc();
-
-                            if let Some(init) = variable.init() {
-                                self.compile_expr(init, &value);
-                            } else {
-                                self.bytecode.emit_store_undefined(value.variable());
-                            }
+                    let Binding::Identifier(ident) = variable.binding() else {
+                        unreachable!("using declarations cannot have binding patterns");
+                    };
+                    let ident = ident.to_js_string(self.interner());
+                    let init = variable
+                        .init()
+                        .expect("using declaration must have initializer");
 
-                            // TODO: SAME
-                            self.compile_declaration_pattern(
-                                pattern,
-                                BindingOpcode::InitLexical,
-                                &value,
-                            );
-                            self.register_allocator.dealloc(value);
-                        }
+                    let value = self.register_allocator.alloc();
+                    self.compile_expr(init, &value);
+                    if r#async {
+                        self.bytecode
+                            .emit_add_async_disposable_resource(value.variable());
+                    } else {
+                        self.bytecode.emit_add_disposable_resource(value.variable());
                     }
+                    self.emit_binding(BindingOpcode::InitLexical, ident, &value);
+                    self.register_allocator.dealloc(value);
                 }
             }
         }
@@ -1,15 +1,37 @@
 use super::{ByteCompiler, Literal, ToJsString};
 use crate::vm::opcode::BindingOpcode;
-use boa_ast::{ModuleItem, ModuleItemList, declaration::ExportDeclaration};
+use boa_ast::{
+    Declaration, ModuleItem, ModuleItemList, StatementListItem,
+    declaration::{ExportDeclaration, LexicalDeclaration},
+};
 use boa_interner::Sym;
 
 impl ByteCompiler<'_> {
     /// Compiles a [`ModuleItemList`].
     #[inline]
     pub fn compile_module_item_list(&mut self, list: &ModuleItemList) {
+        let has_using_declarations = list.items().iter().any(|item| {
+            matches!(
+                item,
+                ModuleItem::StatementListItem(StatementListItem::Declaration(declaration))
+                    if matches!(
+                        declaration.as_ref(),
+                        Declaration::Lexical(
+                            LexicalDeclaration::Using(_) | LexicalDeclaration::AwaitUsing(_)
+                        )
+                    )
+            )
+        });
+
+        let disposal_scope = has_using_declarations.then(|| self.push_disposal_scope(false));
+
         for node in list.items() {
             self.compile_module_item(node);
         }
+
+        if let Some(disposal_scope) = disposal_scope {
+            self.pop_disposal_scope(disposal_scope);
+        }
     }
 
     /// Compiles a [`ModuleItem`].
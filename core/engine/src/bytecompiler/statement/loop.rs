@@ -1,5 +1,5 @@
 use boa_ast::{
-    declaration::Binding,
+    declaration::{Binding, LexicalDeclaration},
     operations::bound_names,
     scope::BindingLocatorError,
     statement::{
@@ -24,6 +24,7 @@ impl ByteCompiler<'_> {
         let mut let_binding_indices = None;
         let mut outer_scope_local = None;
         let mut outer_scope = None;
+        let mut disposal_scope = None;
 
         if let Some(init) = for_loop.init() {
             match init {
@@ -62,6 +63,12 @@ impl ByteCompiler<'_> {
                         }
                         let_binding_indices = Some((indices, scope_index));
                     }
+                    if matches!(
+                        decl.declaration(),
+                        LexicalDeclaration::Using(_) | LexicalDeclaration::AwaitUsing(_)
+                    ) {
+                        disposal_scope = Some(self.push_disposal_scope(use_expr));
+                    }
                     self.compile_lexical_decl(decl.declaration());
                 }
             }
@@ -147,6 +154,10 @@ impl ByteCompiler<'_> {
             self.register_allocator.dealloc(hoisted.register);
         }
 
+        if let Some(disposal_scope) = disposal_scope {
+            self.pop_disposal_scope(disposal_scope);
+        }
+
         if let Some(outer_scope_local) = outer_scope_local {
             self.lexical_scope = outer_scope_local;
         }
@@ -243,6 +254,9 @@ impl ByteCompiler<'_> {
                 IterableLoopInitializer::Pattern(pattern) => {
                     self.compile_declaration_pattern(pattern, BindingOpcode::SetName, &value);
                 }
+                IterableLoopInitializer::Using(_) | IterableLoopInitializer::AwaitUsing(_) => {
+                    unreachable!("using declarations are not allowed in for-in loops")
+                }
             }
 
             self.register_allocator.dealloc(value);
@@ -309,6 +323,7 @@ impl ByteCompiler<'_> {
         self.register_allocator.dealloc(done_reg);
 
         let outer_scope = self.push_declarative_scope(for_of_loop.scope());
+        let mut disposal_scope = None;
 
         // For let/const with a local identifier binding, emit iterator_value
         // directly into the binding's persistent register to avoid a Move.
@@ -384,6 +399,26 @@ impl ByteCompiler<'_> {
                 IterableLoopInitializer::Pattern(pattern) => {
                     self.compile_declaration_pattern(pattern, BindingOpcode::SetName, &value);
                 }
+                IterableLoopInitializer::Using(declaration)
+                | IterableLoopInitializer::AwaitUsing(declaration) => {
+                    let Binding::Identifier(ident) = declaration else {
+                        unreachable!("using declarations cannot have binding patterns");
+                    };
+
+                    // The resources of each iteration are disposed at the end of the iteration.
+                    disposal_scope = Some(self.push_disposal_scope(use_expr));
+                    if matches!(
+                        for_of_loop.initializer(),
+                        IterableLoopInitializer::AwaitUsing(_)
+                    ) {
+                        self.bytecode
+                            .emit_add_async_disposable_resource(value.variable());
+                    } else {
+                        self.bytecode.emit_add_disposable_resource(value.variable());
+                    }
+                    let ident = ident.to_js_string(self.interner());
+                    self.emit_binding(BindingOpcode::InitLexical, ident, &value);
+                }
             }
 
             self.register_allocator.dealloc(value);
@@ -392,6 +427,10 @@ impl ByteCompiler<'_> {
 
         self.compile_stmt(for_of_loop.body(), use_expr, true);
 
+        if let Some(disposal_scope) = disposal_scope {
+            self.pop_disposal_scope(disposal_scope);
+        }
+
         {
             let exit = self.jump();
             self.patch_handler(handler_index);
@@ -1,4 +1,4 @@
-use crate::bytecompiler::ByteCompiler;
+use crate::bytecompiler::{ByteCompiler, disposal::has_using_declarations};
 use boa_ast::statement::Switch;
 
 impl ByteCompiler<'_> {
@@ -13,6 +13,14 @@ impl ByteCompiler<'_> {
         let start_address = self.next_opcode_location();
         self.push_switch_control_info(None, start_address, use_expr);
 
+        // The resources of `using` declarations in any of the cases are disposed
+        // when exiting the whole case block.
+        let disposal_scope = switch
+            .cases()
+            .iter()
+            .any(|case| has_using_declarations(case.body()))
+            .then(|| self.push_disposal_scope(use_expr));
+
         let mut labels = Vec::with_capacity(switch.cases().len());
 
         let condition = self.register_allocator.alloc();
@@ -45,13 +53,17 @@ impl ByteCompiler<'_> {
             };
             self.patch_jump(label);
 
-            self.compile_statement_list(case.body(), use_expr, true);
+            self.compile_statement_list_items(case.body(), use_expr, true);
         }
 
         if !default_label_set {
             self.patch_jump(default_label);
         }
 
+        if let Some(disposal_scope) = disposal_scope {
+            self.pop_disposal_scope(disposal_scope);
+        }
+
         self.pop_switch_control_info();
         self.pop_declarative_scope(outer_scope);
     }
@@ -1,11 +1,14 @@
 use crate::{
     Context, JsResult, JsString, JsValue,
-    builtins::{Promise, promise::OperationType},
+    builtins::{Promise, error::StackFrame, promise::OperationType},
     context::intrinsics::Intrinsics,
     job::JobCallback,
+    js_string,
     object::{JsFunction, JsObject},
     realm::Realm,
+    vm::CallFrame,
 };
+use std::fmt::Write;
 use time::{OffsetDateTime, UtcOffset};
 
 /// [`Host Hooks`] customizable by the host code or engine.
@@ -164,6 +167,52 @@ pub trait HostHooks {
         Ok(())
     }
 
+    /// [`HostInitializeShadowRealm ( realm )`][spec]
+    ///
+    /// Called after the realm of a new `ShadowRealm` object has been created and its default
+    /// global bindings have been defined, which allows hosts to add their own globals to it.
+    ///
+    /// # Requirements
+    ///
+    /// - If the returned Completion Record is a normal completion, it must be a normal completion
+    ///   containing unused. This is already ensured by the return type.
+    /// - Any property added to the global object of `realm` must be configurable.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-hostinitializeshadowrealm
+    fn initialize_shadow_realm(&self, _realm: &Realm, _context: &mut Context) -> JsResult<()> {
+        // The default implementation of HostInitializeShadowRealm is to return NormalCompletion(unused).
+        Ok(())
+    }
+
+    /// Formats the `stack` property of an error object.
+    ///
+    /// `header` is the result of calling `Error.prototype.toString` on `error`, and `frames` are
+    /// the frames of its stack trace, starting from the innermost one. The number of frames is
+    /// limited by [`RuntimeLimits::backtrace_limit`].
+    ///
+    /// The default implementation follows the format used by V8:
+    ///
+    /// ```text
+    /// Error: message
+    ///     at inner (script.js:2:11)
+    ///     at <main> (script.js:5:1)
+    /// ```
+    ///
+    /// [`RuntimeLimits::backtrace_limit`]: crate::vm::RuntimeLimits::backtrace_limit
+    fn format_error_stack(
+        &self,
+        _error: &JsObject,
+        header: &JsString,
+        frames: &[StackFrame],
+        _context: &mut Context,
+    ) -> JsResult<JsString> {
+        let mut stack = String::new();
+        for frame in frames {
+            let _ = write!(stack, "\n    at {frame}");
+        }
+        Ok(js_string!(header, &JsString::from(stack)))
+    }
+
     /// Creates the global object of a new [`Context`] from the initial intrinsics.
     ///
     /// Equivalent to the step 7 of [`InitializeHostDefinedRealm ( )`][ihdr].
@@ -223,6 +272,60 @@ pub trait HostHooks {
     fn max_buffer_size(&self, _context: &mut Context) -> u64 {
         1_610_612_736 // 1.5 GiB
     }
+
+    /// Called when the context has exhausted its instruction budget.
+    ///
+    /// This hook is called at the start of an instruction, which allows hosts to run code at regular
+    /// intervals of the execution, for example to check a deadline or to let other tasks progress.
+    ///
+    /// Returning `true` refuels the context with a new budget and resumes the execution.
+    /// Returning `false` terminates the execution with an uncatchable
+    /// [`EngineError::NoInstructionsRemain`] error. The context can then be refueled with
+    /// [`Context::refuel`] to run more code.
+    ///
+    /// By default, this always returns `false`.
+    ///
+    /// See [`RuntimeLimits::set_instruction_budget`].
+    ///
+    /// [`EngineError::NoInstructionsRemain`]: crate::error::EngineError::NoInstructionsRemain
+    /// [`RuntimeLimits::set_instruction_budget`]: crate::vm::RuntimeLimits::set_instruction_budget
+    fn instruction_budget_exhausted(&self, _context: &mut Context) -> bool {
+        false
+    }
+
+    /// Called at the next safe point of the execution after an interruption was requested with
+    /// [`InterruptHandle::interrupt`].
+    ///
+    /// The hook runs on the thread of the context, which allows hosts to run code that needs
+    /// access to the context, or to pause the execution by blocking until it should resume.
+    ///
+    /// Returning `true` resumes the execution. Returning `false` terminates it with an
+    /// uncatchable [`EngineError::Interrupted`] error.
+    ///
+    /// By default, this always returns `false`.
+    ///
+    /// [`InterruptHandle::interrupt`]: crate::context::InterruptHandle::interrupt
+    /// [`EngineError::Interrupted`]: crate::error::EngineError::Interrupted
+    fn interrupt_requested(&self, _context: &mut Context) -> bool {
+        false
+    }
+
+    /// Called when a `debugger` statement is executed.
+    ///
+    /// `frame` is the call frame running the statement, which gives access to its code block, its
+    /// position, its `this` value, its arguments and the bindings of its scopes (see
+    /// [`CallFrame::bindings`]). The rest of the call stack can be inspected
+    /// with [`Context::stack_trace`]. The hook runs on the thread of the context, so hosts can
+    /// pause the execution by blocking until it should resume.
+    ///
+    /// Returning an error throws it from the `debugger` statement.
+    ///
+    /// By default, this does nothing, as allowed by the [specification][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-debugger-statement
+    fn on_debugger_statement(&self, _frame: &CallFrame, _context: &mut Context) -> JsResult<()> {
+        Ok(())
+    }
 }
 
 /// Default implementation of [`HostHooks`], which doesn't carry any state.
@@ -0,0 +1,70 @@
+//! Asynchronous interruption of a running [`Context`].
+
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+#[cfg(doc)]
+use crate::{Context, context::HostHooks, error::EngineError};
+
+/// A thread-safe handle that can interrupt the execution of a [`Context`].
+///
+/// The handle is obtained with [`Context::interrupt_handle`], and can be sent to other threads,
+/// for example to a watchdog that stops scripts running for too long.
+///
+/// After calling [`InterruptHandle::interrupt`], the context calls the
+/// [`HostHooks::interrupt_requested`] hook the next time it reaches a safe point in the VM loop.
+/// By default, the hook terminates the execution with an uncatchable
+/// [`EngineError::Interrupted`] error.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_engine::{Context, Source, error::EngineError};
+/// let context = &mut Context::default();
+/// let handle = context.interrupt_handle();
+///
+/// std::thread::spawn(move || {
+///     std::thread::sleep(std::time::Duration::from_millis(10));
+///     handle.interrupt();
+/// });
+///
+/// let result = context.eval(Source::from_bytes("try { while (true) {} } catch {}"));
+/// assert_eq!(
+///     result.unwrap_err().as_engine(),
+///     Some(&EngineError::Interrupted)
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InterruptHandle {
+    requested: Arc<AtomicBool>,
+}
+
+impl InterruptHandle {
+    /// Requests the interruption of the context at its next safe point.
+    ///
+    /// If the context is not running, the interruption happens as soon as it starts executing
+    /// code again.
+    pub fn interrupt(&self) {
+        self.requested.store(true, Ordering::Release);
+    }
+
+    /// Cancels a requested interruption that the context has not handled yet.
+    pub fn cancel(&self) {
+        self.requested.store(false, Ordering::Release);
+    }
+
+    /// Returns `true` if an interruption was requested and the context has not handled it yet.
+    #[must_use]
+    pub fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` and clears the request if an interruption was requested.
+    #[inline]
+    pub(crate) fn take(&self) -> bool {
+        // Avoid the read-modify-write in the common case where no interruption was requested.
+        self.requested.load(Ordering::Relaxed) && self.requested.swap(false, Ordering::AcqRel)
+    }
+}
@@ -67,6 +67,14 @@ impl Intrinsics {
     pub(crate) const fn templates(&self) -> &ObjectTemplates {
         &self.templates
     }
+
+    /// Returns every intrinsic object and constructor, always in the same order.
+    pub(crate) fn all_objects(&self) -> Vec<JsObject> {
+        let mut objects = Vec::new();
+        self.constructors.collect_objects(&mut objects);
+        self.objects.collect_objects(&mut objects);
+        objects
+    }
 }
 
 /// Stores a constructor (such as `Object`) and its corresponding prototype.
@@ -146,6 +154,10 @@ pub struct StandardConstructors {
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
     aggregate_error: StandardConstructor,
+    suppressed_error: StandardConstructor,
+    disposable_stack: StandardConstructor,
+    async_disposable_stack: StandardConstructor,
+    shadow_realm: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
     typed_array: StandardConstructor,
@@ -175,6 +187,10 @@ pub struct StandardConstructors {
     #[cfg(feature = "intl")]
     collator: StandardConstructor,
     #[cfg(feature = "intl")]
+    display_names: StandardConstructor,
+    #[cfg(feature = "intl")]
+    duration_format: StandardConstructor,
+    #[cfg(feature = "intl")]
     list_format: StandardConstructor,
     #[cfg(feature = "intl")]
     locale: StandardConstructor,
@@ -184,6 +200,8 @@ pub struct StandardConstructors {
     plural_rules: StandardConstructor,
     #[cfg(feature = "intl")]
     number_format: StandardConstructor,
+    #[cfg(feature = "intl")]
+    relative_time_format: StandardConstructor,
     #[cfg(feature = "temporal")]
     instant: StandardConstructor,
     #[cfg(feature = "temporal")]
@@ -242,6 +260,10 @@ impl Default for StandardConstructors {
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
             aggregate_error: StandardConstructor::default(),
+            suppressed_error: StandardConstructor::default(),
+            disposable_stack: StandardConstructor::default(),
+            async_disposable_stack: StandardConstructor::default(),
+            shadow_realm: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
             typed_array: StandardConstructor::default(),
@@ -271,6 +293,10 @@ impl Default for StandardConstructors {
             #[cfg(feature = "intl")]
             collator: StandardConstructor::default(),
             #[cfg(feature = "intl")]
+            display_names: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            duration_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
             list_format: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             locale: StandardConstructor::default(),
@@ -280,6 +306,8 @@ impl Default for StandardConstructors {
             plural_rules: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             number_format: StandardConstructor::default(),
+            #[cfg(feature = "intl")]
+            relative_time_format: StandardConstructor::default(),
             #[cfg(feature = "temporal")]
             instant: StandardConstructor::default(),
             #[cfg(feature = "temporal")]
@@ -305,6 +333,197 @@ impl Default for StandardConstructors {
 }
 
 impl StandardConstructors {
+    /// Appends every constructor and prototype to `objects`.
+    pub(crate) fn collect_objects(&self, objects: &mut Vec<JsObject>) {
+        // Destructured exhaustively, so that new constructors cannot be missed.
+        let Self {
+            object,
+            proxy,
+            date,
+            function,
+            async_function,
+            generator_function,
+            async_generator_function,
+            array,
+            bigint,
+            number,
+            boolean,
+            string,
+            regexp,
+            symbol,
+            error,
+            type_error,
+            reference_error,
+            range_error,
+            syntax_error,
+            eval_error,
+            uri_error,
+            aggregate_error,
+            suppressed_error,
+            disposable_stack,
+            async_disposable_stack,
+            shadow_realm,
+            map,
+            set,
+            typed_array,
+            typed_int8_array,
+            typed_uint8_array,
+            typed_uint8clamped_array,
+            typed_int16_array,
+            typed_uint16_array,
+            typed_int32_array,
+            typed_uint32_array,
+            typed_bigint64_array,
+            typed_biguint64_array,
+            #[cfg(feature = "float16")]
+            typed_float16_array,
+            typed_float32_array,
+            typed_float64_array,
+            array_buffer,
+            shared_array_buffer,
+            data_view,
+            date_time_format,
+            promise,
+            weak_ref,
+            weak_map,
+            weak_set,
+            iterator,
+            finalization_registry,
+            #[cfg(feature = "intl")]
+            collator,
+            #[cfg(feature = "intl")]
+            display_names,
+            #[cfg(feature = "intl")]
+            duration_format,
+            #[cfg(feature = "intl")]
+            list_format,
+            #[cfg(feature = "intl")]
+            locale,
+            #[cfg(feature = "intl")]
+            segmenter,
+            #[cfg(feature = "intl")]
+            plural_rules,
+            #[cfg(feature = "intl")]
+            number_format,
+            #[cfg(feature = "intl")]
+            relative_time_format,
+            #[cfg(feature = "temporal")]
+            instant,
+            #[cfg(feature = "temporal")]
+            plain_date_time,
+            #[cfg(feature = "temporal")]
+            plain_date,
+            #[cfg(feature = "temporal")]
+            plain_time,
+            #[cfg(feature = "temporal")]
+            plain_year_month,
+            #[cfg(feature = "temporal")]
+            plain_month_day,
+            #[cfg(feature = "temporal")]
+            time_zone,
+            #[cfg(feature = "temporal")]
+            duration,
+            #[cfg(feature = "temporal")]
+            zoned_date_time,
+            #[cfg(feature = "temporal")]
+            calendar,
+        } = self;
+        let mut push = |constructor: &StandardConstructor| {
+            objects.push(constructor.constructor());
+            objects.push(constructor.prototype());
+        };
+        push(object);
+        push(proxy);
+        push(date);
+        push(function);
+        push(async_function);
+        push(generator_function);
+        push(async_generator_function);
+        push(array);
+        push(bigint);
+        push(number);
+        push(boolean);
+        push(string);
+        push(regexp);
+        push(symbol);
+        push(error);
+        push(type_error);
+        push(reference_error);
+        push(range_error);
+        push(syntax_error);
+        push(eval_error);
+        push(uri_error);
+        push(aggregate_error);
+        push(suppressed_error);
+        push(disposable_stack);
+        push(async_disposable_stack);
+        push(shadow_realm);
+        push(map);
+        push(set);
+        push(typed_array);
+        push(typed_int8_array);
+        push(typed_uint8_array);
+        push(typed_uint8clamped_array);
+        push(typed_int16_array);
+        push(typed_uint16_array);
+        push(typed_int32_array);
+        push(typed_uint32_array);
+        push(typed_bigint64_array);
+        push(typed_biguint64_array);
+        #[cfg(feature = "float16")]
+        push(typed_float16_array);
+        push(typed_float32_array);
+        push(typed_float64_array);
+        push(array_buffer);
+        push(shared_array_buffer);
+        push(data_view);
+        push(date_time_format);
+        push(promise);
+        push(weak_ref);
+        push(weak_map);
+        push(weak_set);
+        push(iterator);
+        push(finalization_registry);
+        #[cfg(feature = "intl")]
+        push(collator);
+        #[cfg(feature = "intl")]
+        push(display_names);
+        #[cfg(feature = "intl")]
+        push(duration_format);
+        #[cfg(feature = "intl")]
+        push(list_format);
+        #[cfg(feature = "intl")]
+        push(locale);
+        #[cfg(feature = "intl")]
+        push(segmenter);
+        #[cfg(feature = "intl")]
+        push(plural_rules);
+        #[cfg(feature = "intl")]
+        push(number_format);
+        #[cfg(feature = "intl")]
+        push(relative_time_format);
+        #[cfg(feature = "temporal")]
+        push(instant);
+        #[cfg(feature = "temporal")]
+        push(plain_date_time);
+        #[cfg(feature = "temporal")]
+        push(plain_date);
+        #[cfg(feature = "temporal")]
+        push(plain_time);
+        #[cfg(feature = "temporal")]
+        push(plain_year_month);
+        #[cfg(feature = "temporal")]
+        push(plain_month_day);
+        #[cfg(feature = "temporal")]
+        push(time_zone);
+        #[cfg(feature = "temporal")]
+        push(duration);
+        #[cfg(feature = "temporal")]
+        push(zoned_date_time);
+        #[cfg(feature = "temporal")]
+        push(calendar);
+    }
+
     /// Returns the `AsyncGeneratorFunction` constructor.
     ///
     /// More information:
@@ -569,6 +788,54 @@ impl StandardConstructors {
         &self.aggregate_error
     }
 
+    /// Returns the `SuppressedError` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-constructor
+    #[inline]
+    #[must_use]
+    pub const fn suppressed_error(&self) -> &StandardConstructor {
+        &self.suppressed_error
+    }
+
+    /// Returns the `DisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-constructor
+    #[inline]
+    #[must_use]
+    pub const fn disposable_stack(&self) -> &StandardConstructor {
+        &self.disposable_stack
+    }
+
+    /// Returns the `AsyncDisposableStack` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-constructor
+    #[inline]
+    #[must_use]
+    pub const fn async_disposable_stack(&self) -> &StandardConstructor {
+        &self.async_disposable_stack
+    }
+
+    /// Returns the `ShadowRealm` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm-constructor
+    #[inline]
+    #[must_use]
+    pub const fn shadow_realm(&self) -> &StandardConstructor {
+        &self.shadow_realm
+    }
+
     /// Returns the `Map` constructor.
     ///
     /// More information:
@@ -883,6 +1150,32 @@ impl StandardConstructors {
         &self.collator
     }
 
+    /// Returns the `Intl.DisplayNames` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-displaynames-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn display_names(&self) -> &StandardConstructor {
+        &self.display_names
+    }
+
+    /// Returns the `Intl.DurationFormat` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-durationformat-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn duration_format(&self) -> &StandardConstructor {
+        &self.duration_format
+    }
+
     /// Returns the `Intl.ListFormat` constructor.
     ///
     /// More information:
@@ -948,6 +1241,19 @@ impl StandardConstructors {
         &self.number_format
     }
 
+    /// Returns the `Intl.RelativeTimeFormat` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-relativetimeformat-constructor
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "intl")]
+    pub const fn relative_time_format(&self) -> &StandardConstructor {
+        &self.relative_time_format
+    }
+
     /// Returns the `Temporal.Instant` constructor.
     ///
     /// More information:
@@ -1156,6 +1462,69 @@ pub struct IntrinsicObjects {
 }
 
 impl IntrinsicObjects {
+    /// Appends every intrinsic object to `objects`.
+    pub(crate) fn collect_objects(&self, objects: &mut Vec<JsObject>) {
+        // Destructured exhaustively, so that new intrinsics cannot be missed.
+        let Self {
+            reflect,
+            math,
+            json,
+            throw_type_error,
+            array_prototype_values,
+            array_prototype_to_string,
+            iterator_prototypes,
+            generator,
+            async_generator,
+            atomics,
+            eval,
+            uri_functions,
+            is_finite,
+            is_nan,
+            parse_float,
+            parse_int,
+            #[cfg(feature = "annex-b")]
+            escape,
+            #[cfg(feature = "annex-b")]
+            unescape,
+            #[cfg(feature = "intl")]
+            intl,
+            #[cfg(feature = "intl")]
+            segments_prototype,
+            #[cfg(feature = "temporal")]
+            temporal,
+            #[cfg(feature = "temporal")]
+            now,
+        } = self;
+        objects.push(reflect.clone());
+        objects.push(math.clone());
+        objects.push(json.clone());
+        objects.push(throw_type_error.clone().into());
+        objects.push(array_prototype_values.clone().into());
+        objects.push(array_prototype_to_string.clone().into());
+        iterator_prototypes.collect_objects(objects);
+        objects.push(generator.clone());
+        objects.push(async_generator.clone());
+        objects.push(atomics.clone());
+        objects.push(eval.clone().into());
+        uri_functions.collect_objects(objects);
+        objects.push(is_finite.clone().into());
+        objects.push(is_nan.clone().into());
+        objects.push(parse_float.clone().into());
+        objects.push(parse_int.clone().into());
+        #[cfg(feature = "annex-b")]
+        objects.push(escape.clone().into());
+        #[cfg(feature = "annex-b")]
+        objects.push(unescape.clone().into());
+        #[cfg(feature = "intl")]
+        objects.push(intl.clone().upcast());
+        #[cfg(feature = "intl")]
+        objects.push(segments_prototype.clone());
+        #[cfg(feature = "temporal")]
+        objects.push(temporal.clone());
+        #[cfg(feature = "temporal")]
+        objects.push(now.clone());
+    }
+
     /// Creates a new set of uninitialized intrinsic objects.
     ///
     /// Creates all the required empty objects for every intrinsic object in this realm.
@@ -3,11 +3,13 @@
 use std::{cell::Cell, path::Path, rc::Rc};
 
 use boa_ast::StatementList;
+use boa_gc::{GcConfig, GcStatistics};
 use boa_interner::Interner;
 use boa_parser::source::ReadChar;
 pub use hooks::{DefaultHooks, HostHooks};
 #[cfg(feature = "intl")]
 pub use icu::IcuError;
+pub use interrupt::InterruptHandle;
 use intrinsics::Intrinsics;
 #[cfg(any(feature = "temporal", feature = "intl"))]
 use temporal_rs::provider::TimeZoneProvider;
@@ -30,6 +32,7 @@ use crate::{
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
     script::Script,
+    snapshot::{self, ExternalReferences, Snapshot, SnapshotCreator, SnapshotError},
     vm::{ActiveRunnable, CallFrame, Vm},
 };
 
@@ -42,6 +45,7 @@ pub use time::Clock;
 mod hooks;
 #[cfg(feature = "intl")]
 pub(crate) mod icu;
+mod interrupt;
 pub mod intrinsics;
 
 thread_local! {
@@ -98,10 +102,6 @@ pub struct Context {
     /// Execute in strict mode,
     strict: bool,
 
-    /// Number of instructions remaining before a forced exit
-    #[cfg(feature = "fuzz")]
-    pub(crate) instructions_remaining: usize,
-
     pub(crate) vm: Vm,
 
     pub(crate) kept_alive: Vec<JsObject>,
@@ -130,6 +130,8 @@ pub struct Context {
     parser_identifier: u32,
 
     data: HostDefined,
+
+    pub(crate) snapshot_creator: Option<Box<SnapshotCreator>>,
 }
 
 impl std::fmt::Debug for Context {
@@ -447,12 +449,54 @@ impl Context {
         self.vm.frame().realm.intrinsics()
     }
 
-    /// Returns the amount of remaining instructions to be executed
-    #[cfg(feature = "fuzz")]
+    /// Returns the number of instructions that can still be executed before the instruction
+    /// budget is exhausted.
+    ///
+    /// Returns [`u64::MAX`] if there is no instruction budget.
+    ///
+    /// See [`RuntimeLimits::set_instruction_budget`].
+    #[inline]
+    #[must_use]
+    pub const fn instructions_remaining(&self) -> u64 {
+        let budget = self.vm.runtime_limits.instruction_budget();
+        if budget == u64::MAX {
+            return u64::MAX;
+        }
+        budget.saturating_sub(self.vm.instructions_executed)
+    }
+
+    /// Returns a handle that can interrupt the execution of this context from any thread.
+    ///
+    /// All the handles returned by this method refer to the same context.
     #[inline]
     #[must_use]
-    pub fn instructions_remaining(&self) -> usize {
-        self.instructions_remaining
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.vm.interrupt_handle.clone()
+    }
+
+    /// Serializes the state of this context into a [`Snapshot`].
+    ///
+    /// New contexts can be created from the snapshot with [`ContextBuilder::snapshot`]. See the
+    /// [`snapshot`](crate::snapshot) module for what a snapshot stores.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the context was not built with [`ContextBuilder::snapshot_creator`],
+    /// or if it can reach a value that cannot be stored in a snapshot.
+    pub fn create_snapshot(&self) -> Result<Snapshot, SnapshotError> {
+        snapshot::create(self)
+    }
+
+    /// Refuels the context with a full instruction budget.
+    ///
+    /// This allows a context that was terminated with an
+    /// [`EngineError::NoInstructionsRemain`][crate::error::EngineError::NoInstructionsRemain]
+    /// error to resume executing code.
+    ///
+    /// See [`RuntimeLimits::set_instruction_budget`].
+    #[inline]
+    pub const fn refuel(&mut self) {
+        self.vm.instructions_executed = 0;
     }
 
     /// Returns the currently active realm.
@@ -594,6 +638,19 @@ impl Context {
         &mut self.vm.runtime_limits
     }
 
+    /// Returns statistics about the garbage collected heap.
+    ///
+    /// # Note
+    ///
+    /// The garbage collector is shared by all contexts of the current thread, so these statistics
+    /// also include the values allocated by any other context on the same thread.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn gc_statistics(&self) -> GcStatistics {
+        boa_gc::gc_statistics()
+    }
+
     /// Returns `true` if this context can be suspended by an `Atomics.wait` call.
     #[inline]
     #[must_use]
@@ -1005,12 +1062,14 @@ pub struct ContextBuilder {
     job_executor: Option<Rc<dyn JobExecutor>>,
     module_loader: Option<Rc<dyn DynModuleLoader>>,
     can_block: bool,
+    gc_config: Option<GcConfig>,
     #[cfg(feature = "intl")]
     icu: Option<icu::IntlProvider>,
     #[cfg(feature = "temporal")]
     timezone_provider: Option<Box<dyn TimeZoneProvider>>,
-    #[cfg(feature = "fuzz")]
-    instructions_remaining: usize,
+    instruction_budget: Option<u64>,
+    snapshot_creator: Option<ExternalReferences>,
+    snapshot: Option<(Snapshot, ExternalReferences)>,
 }
 
 impl std::fmt::Debug for ContextBuilder {
@@ -1037,7 +1096,11 @@ impl std::fmt::Debug for ContextBuilder {
                 "module_loader",
                 &self.module_loader.as_ref().map(|_| ModuleLoader),
             )
-            .field("can_block", &self.can_block);
+            .field("can_block", &self.can_block)
+            .field("gc_config", &self.gc_config)
+            .field("instruction_budget", &self.instruction_budget)
+            .field("snapshot_creator", &self.snapshot_creator)
+            .field("snapshot", &self.snapshot.as_ref().map(|_| "Snapshot"));
 
         #[cfg(feature = "intl")]
         out.field("icu", &self.icu);
@@ -1048,9 +1111,6 @@ impl std::fmt::Debug for ContextBuilder {
             &self.timezone_provider.as_ref().map(|_| "TimeZoneProvider"),
         );
 
-        #[cfg(feature = "fuzz")]
-        out.field("instructions_remaining", &self.instructions_remaining);
-
         out.finish()
     }
 }
@@ -1171,13 +1231,48 @@ impl ContextBuilder {
         self
     }
 
-    /// Specifies the number of instructions remaining to the [`Context`].
+    /// Sets the [`GcConfig`] of the garbage collector when the context is built.
+    ///
+    /// # Note
+    ///
+    /// The garbage collector is shared by all contexts of the current thread, so this
+    /// configuration also applies to any other context on the same thread.
+    #[must_use]
+    pub const fn gc_config(mut self, gc_config: GcConfig) -> Self {
+        self.gc_config = Some(gc_config);
+        self
+    }
+
+    /// Specifies the instruction budget of the [`Context`].
+    ///
+    /// See [`RuntimeLimits::set_instruction_budget`].
+    #[must_use]
+    pub const fn instruction_budget(mut self, instruction_budget: u64) -> Self {
+        self.instruction_budget = Some(instruction_budget);
+        self
+    }
+
+    /// Builds a [`Context`] that can be serialized with [`Context::create_snapshot`].
+    ///
+    /// `external_references` must contain every native function created by the host that the
+    /// context can reach when the snapshot is created.
+    #[must_use]
+    pub fn snapshot_creator(mut self, external_references: ExternalReferences) -> Self {
+        self.snapshot_creator = Some(external_references);
+        self
+    }
+
+    /// Restores the state of the built [`Context`] from a [`Snapshot`].
+    ///
+    /// `external_references` must contain the same native functions, in the same order, as the
+    /// table passed to [`ContextBuilder::snapshot_creator`] when the snapshot was created.
     ///
-    /// This function is only available if the `fuzz` feature is enabled.
-    #[cfg(feature = "fuzz")]
+    /// This is safe because a [`Snapshot`] can only be obtained from
+    /// [`Context::create_snapshot`], or from bytes whose origin the caller vouched for with the
+    /// unsafe [`Snapshot::from_bytes`].
     #[must_use]
-    pub const fn instructions_remaining(mut self, instructions_remaining: usize) -> Self {
-        self.instructions_remaining = instructions_remaining;
+    pub fn snapshot(mut self, snapshot: Snapshot, external_references: ExternalReferences) -> Self {
+        self.snapshot = Some((snapshot, external_references));
         self
     }
 
@@ -1198,12 +1293,19 @@ impl ContextBuilder {
             CANNOT_BLOCK_COUNTER.set(CANNOT_BLOCK_COUNTER.get() + 1);
         }
 
+        if let Some(gc_config) = self.gc_config {
+            boa_gc::set_gc_config(gc_config);
+        }
+
         let root_shape = RootShape::default();
 
         let host_hooks = self.host_hooks.unwrap_or(Rc::new(DefaultHooks));
         let clock = self.clock.unwrap_or_else(|| Rc::new(StdClock::new()));
         let realm = Realm::create(host_hooks.as_ref(), &root_shape)?;
-        let vm = Vm::new(realm);
+        let mut vm = Vm::new(realm);
+        if let Some(instruction_budget) = self.instruction_budget {
+            vm.runtime_limits.set_instruction_budget(instruction_budget);
+        }
 
         let module_loader: Rc<dyn DynModuleLoader> = if let Some(loader) = self.module_loader {
             loader
@@ -1242,8 +1344,6 @@ impl ContextBuilder {
                     }
                 }
             },
-            #[cfg(feature = "fuzz")]
-            instructions_remaining: self.instructions_remaining,
             kept_alive: Vec::new(),
             host_hooks,
             clock,
@@ -1254,10 +1354,21 @@ impl ContextBuilder {
             parser_identifier: 0,
             can_block: self.can_block,
             data: HostDefined::default(),
+            snapshot_creator: None,
         };
 
         builtins::set_default_global_bindings(&mut context)?;
 
+        if let Some(external_references) = self.snapshot_creator {
+            context.snapshot_creator = Some(Box::new(SnapshotCreator::new(
+                &context,
+                external_references,
+            )));
+        }
+        if let Some((snapshot, external_references)) = &self.snapshot {
+            snapshot::restore(&context, snapshot, external_references)?;
+        }
+
         Ok(context)
     }
 }
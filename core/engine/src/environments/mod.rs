@@ -27,8 +27,9 @@
 mod runtime;
 
 pub(crate) use runtime::{
-    DeclarativeEnvironment, Environment, EnvironmentStack, FunctionSlots, PrivateEnvironment,
-    SavedEnvironments, ThisBindingStatus,
+    DeclarativeEnvironment, DeclarativeEnvironmentKind, Environment, EnvironmentStack,
+    FunctionEnvironment, FunctionSlots, LexicalEnvironment, PrivateEnvironment, SavedEnvironments,
+    ThisBindingStatus,
 };
 
 #[cfg(test)]
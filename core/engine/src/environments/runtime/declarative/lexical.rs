@@ -1,3 +1,4 @@
+use boa_ast::scope::Scope;
 use boa_gc::{Finalize, GcRefCell, Trace};
 
 use crate::JsValue;
@@ -5,16 +6,26 @@ use crate::JsValue;
 #[derive(Debug, Trace, Finalize)]
 pub(crate) struct LexicalEnvironment {
     bindings: GcRefCell<Vec<Option<JsValue>>>,
+
+    // Safety: Nothing in `Scope` needs tracing.
+    #[unsafe_ignore_trace]
+    scope: Scope,
 }
 
 impl LexicalEnvironment {
     /// Creates a new `LexicalEnvironment`.
-    pub(crate) fn new(bindings: u32) -> Self {
+    pub(crate) fn new(bindings: u32, scope: Scope) -> Self {
         Self {
             bindings: GcRefCell::new(vec![None; bindings as usize]),
+            scope,
         }
     }
 
+    /// Gets the compile time environment of this lexical environment.
+    pub(crate) const fn compile(&self) -> &Scope {
+        &self.scope
+    }
+
     /// Gets the binding value from the environment by it's index.
     ///
     /// # Panics
@@ -36,7 +47,6 @@ impl LexicalEnvironment {
     }
 
     /// Gets the bindings of this poisonable environment.
-    #[expect(dead_code)]
     pub(crate) const fn bindings(&self) -> &GcRefCell<Vec<Option<JsValue>>> {
         &self.bindings
     }
@@ -9,6 +9,7 @@ pub(crate) use lexical::LexicalEnvironment;
 pub(crate) use module::ModuleEnvironment;
 
 use crate::{JsResult, JsValue};
+use boa_ast::scope::Scope;
 use boa_gc::{Finalize, Trace};
 use std::cell::Cell;
 
@@ -182,6 +183,17 @@ impl DeclarativeEnvironmentKind {
         }
     }
 
+    /// Returns the compile time environment of this environment, which holds the names of its
+    /// bindings. Returns `None` for the global environment, whose scope is stored in its realm.
+    pub(crate) const fn compile(&self) -> Option<&Scope> {
+        match self {
+            Self::Lexical(inner) => Some(inner.compile()),
+            Self::Function(inner) => Some(inner.compile()),
+            Self::Module(inner) => Some(inner.compile()),
+            Self::Global(_) => None,
+        }
+    }
+
     /// Get the binding value from the environment by it's index.
     ///
     /// # Panics
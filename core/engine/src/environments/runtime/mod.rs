@@ -85,6 +85,66 @@ impl EnvironmentStack {
         }
     }
 
+    /// Creates an environment stack from its environments, ordered from the outermost to the
+    /// innermost, and its private environments.
+    pub(crate) fn from_environments(
+        environments: Vec<Environment>,
+        private_stack: ThinVec<Gc<PrivateEnvironment>>,
+    ) -> Self {
+        let mut stack = Self::new();
+        for env in environments {
+            stack.push_env(env);
+        }
+        stack.private_stack = private_stack;
+        stack
+    }
+
+    /// Returns the environments of the stack, ordered from the outermost to the innermost.
+    pub(crate) fn environments(&self) -> Vec<Environment> {
+        let mut environments = self
+            .iter_from_tip()
+            .map(|(env, _)| env.clone())
+            .collect::<Vec<_>>();
+        environments.reverse();
+        environments
+    }
+
+    /// Returns the private environments of the stack, ordered from the outermost to the innermost.
+    pub(crate) fn private_environments(&self) -> &[Gc<PrivateEnvironment>] {
+        &self.private_stack
+    }
+
+    /// Returns the names and values of the bindings of the declarative environments of the stack,
+    /// ordered from the innermost environment to the global one.
+    ///
+    /// Bindings that are not initialized have no value.
+    pub(crate) fn bindings(
+        &self,
+        global: &Gc<DeclarativeEnvironment>,
+        global_scope: &Scope,
+    ) -> Vec<(JsString, Option<JsValue>)> {
+        let mut bindings = Vec::new();
+        let environments = self
+            .iter_from_tip()
+            .filter_map(|(env, _)| env.as_declarative())
+            .chain(std::iter::once(global));
+        for env in environments {
+            let kind = env.kind();
+            let len = kind
+                .as_global()
+                .map_or(usize::MAX, |global| global.bindings().borrow().len());
+            let scope = kind.compile().unwrap_or(global_scope);
+            for binding in scope.data().bindings {
+                // The global scope can contain the bindings of scripts that were parsed but not
+                // evaluated yet.
+                if binding.escapes() && (binding.index() as usize) < len {
+                    bindings.push((binding.name().clone(), kind.get(binding.index())));
+                }
+            }
+        }
+        bindings
+    }
+
     /// Gets the next outer function environment.
     pub(crate) fn outer_function_environment(&self) -> Option<(Gc<DeclarativeEnvironment>, Scope)> {
         for (env, _) in self.iter_from_tip() {
@@ -210,16 +270,18 @@ impl EnvironmentStack {
     /// Push a lexical environment on the environments stack and return it's index.
     pub(crate) fn push_lexical(
         &mut self,
-        bindings_count: u32,
+        scope: Scope,
         global: &Gc<DeclarativeEnvironment>,
     ) -> u32 {
+        let num_bindings = scope.num_bindings_non_local();
+
         let (poisoned, with) = self.compute_poisoned_with(global);
 
         let index = self.depth;
 
         self.push_env(Environment::Declarative(Gc::new(
             DeclarativeEnvironment::new(
-                DeclarativeEnvironmentKind::Lexical(LexicalEnvironment::new(bindings_count)),
+                DeclarativeEnvironmentKind::Lexical(LexicalEnvironment::new(num_bindings, scope)),
                 poisoned,
                 with,
             ),
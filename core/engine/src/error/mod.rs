@@ -7,7 +7,7 @@ use crate::{
     Context, JsResult, JsString, JsValue,
     builtins::{
         Array,
-        error::{Error, ErrorKind},
+        error::{Error, ErrorKind, stack},
     },
     js_string,
     object::JsObject,
@@ -366,11 +366,17 @@ impl From<PanicError> for JsError {
 #[boa_gc(unsafe_no_drop)]
 #[allow(variant_size_differences)]
 pub enum EngineError {
-    /// Error thrown when no instructions remain. Only used in a fuzzing context.
-    #[cfg(feature = "fuzz")]
+    /// Error thrown when the instruction budget of the context is exhausted.
+    ///
+    /// See [`RuntimeLimits::set_instruction_budget`][crate::vm::RuntimeLimits::set_instruction_budget].
     #[error("NoInstructionsRemainError: instruction budget was exhausted")]
     NoInstructionsRemain,
 
+    /// Error thrown when the execution is interrupted through an
+    /// [`InterruptHandle`][crate::context::InterruptHandle].
+    #[error("InterruptedError: execution was interrupted by the host")]
+    Interrupted,
+
     /// Error thrown when a runtime limit is exceeded.
     #[error("RuntimeLimitError: {0}")]
     RuntimeLimit(#[from] RuntimeLimitError),
@@ -388,8 +394,8 @@ impl EngineError {
     /// error reporting frameworks such as `anyhow`, `eyre` or `miette`.
     fn into_erased(self, context: &mut Context) -> ErasedEngineError {
         match self {
-            #[cfg(feature = "fuzz")]
             EngineError::NoInstructionsRemain => ErasedEngineError::NoInstructionsRemain,
+            EngineError::Interrupted => ErasedEngineError::Interrupted,
             EngineError::RuntimeLimit(err) => ErasedEngineError::RuntimeLimit(err),
             EngineError::Panic(err) => ErasedEngineError::Panic(ErasedPanicError {
                 message: err.message,
@@ -639,6 +645,17 @@ impl JsError {
 
                         JsNativeErrorKind::Aggregate(error_list)
                     }
+                    ErrorKind::Suppressed => {
+                        let error = try_get_property(js_string!("error"), "error", context)?
+                            .unwrap_or_default();
+                        let suppressed =
+                            try_get_property(js_string!("suppressed"), "suppressed", context)?
+                                .unwrap_or_default();
+                        JsNativeErrorKind::Suppressed {
+                            error: Box::new(Self::from_opaque(error)),
+                            suppressed: Box::new(Self::from_opaque(suppressed)),
+                        }
+                    }
                 };
 
                 let realm = try_get_property(js_string!("constructor"), "constructor", context)?
@@ -779,6 +796,12 @@ impl JsError {
                     .map(|err| err.into_erased(context))
                     .collect(),
             ),
+            JsNativeErrorKind::Suppressed { error, suppressed } => {
+                JsErasedNativeErrorKind::Suppressed {
+                    error: Box::new(error.into_erased(context)),
+                    suppressed: Box::new(suppressed.into_erased(context)),
+                }
+            }
             JsNativeErrorKind::Error => JsErasedNativeErrorKind::Error,
             JsNativeErrorKind::Eval => JsErasedNativeErrorKind::Eval,
             JsNativeErrorKind::Range => JsErasedNativeErrorKind::Range,
@@ -834,6 +857,13 @@ impl From<JsNativeError> for JsError {
     }
 }
 
+impl From<boa_gc::HeapLimitExceeded> for JsError {
+    #[cfg_attr(feature = "native-backtrace", track_caller)]
+    fn from(err: boa_gc::HeapLimitExceeded) -> Self {
+        Self::from(JsNativeError::from(err))
+    }
+}
+
 impl From<EngineError> for JsError {
     fn from(value: EngineError) -> Self {
         Self {
@@ -858,19 +888,17 @@ impl fmt::Display for JsError {
         }
 
         if let Some(shadow_stack) = &self.backtrace {
-            for entry in shadow_stack.iter().rev() {
+            let frames = stack::frames(shadow_stack);
+            for (entry, frame) in shadow_stack.iter().rev().zip(&frames) {
                 write!(f, "\n    at ")?;
-                match entry {
-                    ShadowEntry::Native {
-                        function_name,
-                        source_info,
-                    } => {
-                        if let Some(function_name) = function_name {
-                            write!(f, "{}", function_name.to_std_string_escaped())?;
-                        } else {
-                            f.write_str("<anonymous>")?;
-                        }
+                if let Some(function_name) = frame.function_name() {
+                    write!(f, "{}", function_name.to_std_string_escaped())?;
+                } else {
+                    f.write_str("<anonymous>")?;
+                }
 
+                match entry {
+                    ShadowEntry::Native { source_info, .. } => {
                         if let Some(loc) = source_info.as_location() {
                             write!(
                                 f,
@@ -883,24 +911,16 @@ impl fmt::Display for JsError {
                             f.write_str(" (native)")?;
                         }
                     }
-                    ShadowEntry::Bytecode { pc, source_info } => {
-                        let has_function_name = !source_info.function_name().is_empty();
-                        if has_function_name {
-                            write!(f, "{}", source_info.function_name().to_std_string_escaped(),)?;
-                        } else {
-                            f.write_str("<anonymous>")?;
-                        }
-
+                    ShadowEntry::Bytecode { .. } => {
                         f.write_str(" (")?;
-                        source_info.map().path().fmt(f)?;
+                        if let Some(path) = frame.path() {
+                            path.fmt(f)?;
+                        }
 
-                        if let Some(position) = source_info.map().find(*pc) {
-                            write!(
-                                f,
-                                ":{}:{}",
-                                position.line_number(),
-                                position.column_number()
-                            )?;
+                        if let (Some(line), Some(column)) =
+                            (frame.line_number(), frame.column_number())
+                        {
+                            write!(f, ":{line}:{column}")?;
                         } else {
                             f.write_str(":?:?")?;
                         }
@@ -1090,6 +1110,45 @@ impl JsNativeError {
         matches!(self.kind, JsNativeErrorKind::Aggregate(_))
     }
 
+    /// Creates a new `JsNativeError` of kind `SuppressedError` from the `error` that was thrown
+    /// while disposing a resource and the `suppressed` error it replaced, with empty `message`
+    /// and undefined `cause`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+    /// let error = JsNativeError::suppressed(
+    ///     JsNativeError::typ().into(),
+    ///     JsNativeError::range().into(),
+    /// );
+    ///
+    /// assert!(matches!(
+    ///     error.kind(),
+    ///     JsNativeErrorKind::Suppressed { .. }
+    /// ));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[cfg_attr(feature = "native-backtrace", track_caller)]
+    pub fn suppressed(error: JsError, suppressed: JsError) -> Self {
+        Self::new(
+            JsNativeErrorKind::Suppressed {
+                error: Box::new(error),
+                suppressed: Box::new(suppressed),
+            },
+            Cow::Borrowed(""),
+            None,
+        )
+    }
+
+    /// Check if it's a [`JsNativeErrorKind::Suppressed`].
+    #[must_use]
+    #[inline]
+    pub const fn is_suppressed(&self) -> bool {
+        matches!(self.kind, JsNativeErrorKind::Suppressed { .. })
+    }
+
     /// Creates a new `JsNativeError` of kind `Error`, with empty `message` and undefined `cause`.
     ///
     /// # Examples
@@ -1388,6 +1447,10 @@ impl JsNativeError {
                 constructors.aggregate_error().prototype(),
                 ErrorKind::Aggregate,
             ),
+            JsNativeErrorKind::Suppressed { .. } => (
+                constructors.suppressed_error().prototype(),
+                ErrorKind::Suppressed,
+            ),
             JsNativeErrorKind::Error => (constructors.error().prototype(), ErrorKind::Error),
             JsNativeErrorKind::Eval => (constructors.eval_error().prototype(), ErrorKind::Eval),
             JsNativeErrorKind::Range => (constructors.range_error().prototype(), ErrorKind::Range),
@@ -1425,26 +1488,48 @@ impl JsNativeError {
             );
         }
 
-        if let JsNativeErrorKind::Aggregate(errors) = kind {
-            let errors = errors
-                .into_iter()
-                .map(|e| {
-                    e.into_opaque(context)
-                        .expect("engine errors cannot be the cause of another error")
-                })
-                .collect::<Vec<_>>();
-            let errors = Array::create_array_from_list(errors, context);
-            o.define_property_or_throw(
-                js_string!("errors"),
-                PropertyDescriptor::builder()
-                    .configurable(true)
-                    .enumerable(false)
-                    .writable(true)
-                    .value(errors),
-                context,
-            )
-            .expect("The spec guarantees this succeeds for a newly created object ");
+        match kind {
+            JsNativeErrorKind::Aggregate(errors) => {
+                let errors = errors
+                    .into_iter()
+                    .map(|e| {
+                        e.into_opaque(context)
+                            .expect("engine errors cannot be the cause of another error")
+                    })
+                    .collect::<Vec<_>>();
+                let errors = Array::create_array_from_list(errors, context);
+                o.define_property_or_throw(
+                    js_string!("errors"),
+                    PropertyDescriptor::builder()
+                        .configurable(true)
+                        .enumerable(false)
+                        .writable(true)
+                        .value(errors),
+                    context,
+                )
+                .expect("The spec guarantees this succeeds for a newly created object ");
+            }
+            JsNativeErrorKind::Suppressed { error, suppressed } => {
+                for (key, value) in [
+                    (js_string!("error"), error),
+                    (js_string!("suppressed"), suppressed),
+                ] {
+                    o.create_non_enumerable_data_property_or_throw(
+                        key,
+                        value
+                            .into_opaque(context)
+                            .expect("engine errors cannot be suppressed by another error"),
+                        context,
+                    );
+                }
+            }
+            _ => {}
         }
+
+        // Creating the error cannot fail, so the header is computed when the stack is read if
+        // capturing it throws.
+        stack::capture_header(&o, context).ok();
+
         o
     }
 
@@ -1462,6 +1547,13 @@ impl From<boa_parser::Error> for JsNativeError {
     }
 }
 
+impl From<boa_gc::HeapLimitExceeded> for JsNativeError {
+    #[cfg_attr(feature = "native-backtrace", track_caller)]
+    fn from(err: boa_gc::HeapLimitExceeded) -> Self {
+        Self::range().with_message(format!("out of memory: {err}"))
+    }
+}
+
 /// The list of possible error types a [`JsNativeError`] can be.
 ///
 /// More information:
@@ -1482,6 +1574,20 @@ pub enum JsNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
     Aggregate(Vec<JsError>),
+    /// An error thrown while disposing a resource, which suppressed a previous error.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error thrown during disposal.
+        error: Box<JsError>,
+        /// The error that was suppressed by `error`.
+        suppressed: Box<JsError>,
+    },
     /// A generic error. Commonly used as the base for custom exceptions.
     ///
     /// More information:
@@ -1558,6 +1664,10 @@ unsafe impl Trace for JsNativeErrorKind {
         mark,
         match &this {
             Self::Aggregate(errors) => mark(errors),
+            Self::Suppressed { error, suppressed } => {
+                mark(error);
+                mark(suppressed);
+            }
             Self::Error
             | Self::Eval
             | Self::Range
@@ -1574,6 +1684,7 @@ impl PartialEq<ErrorKind> for JsNativeErrorKind {
         matches!(
             (self, other),
             (Self::Aggregate(_), ErrorKind::Aggregate)
+                | (Self::Suppressed { .. }, ErrorKind::Suppressed)
                 | (Self::Error, ErrorKind::Error)
                 | (Self::Eval, ErrorKind::Eval)
                 | (Self::Range, ErrorKind::Range)
@@ -1589,6 +1700,7 @@ impl fmt::Display for JsNativeErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Aggregate(_) => "AggregateError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Error => "Error",
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
@@ -1638,11 +1750,14 @@ impl ErasedPanicError {
 #[derive(Debug, Clone, Error, Eq, PartialEq, Trace, Finalize)]
 #[allow(variant_size_differences)]
 pub enum ErasedEngineError {
-    /// Error thrown when no instructions remain. Only used in a fuzzing context.
-    #[cfg(feature = "fuzz")]
+    /// Error thrown when the instruction budget of the context is exhausted.
     #[error("NoInstructionsRemainError: instruction budget was exhausted")]
     NoInstructionsRemain,
 
+    /// Error thrown when the execution is interrupted by the host.
+    #[error("InterruptedError: execution was interrupted by the host")]
+    Interrupted,
+
     /// Error thrown when a runtime limit is exceeded.
     #[error("RuntimeLimitError: {0}")]
     RuntimeLimit(#[from] RuntimeLimitError),
@@ -1761,6 +1876,20 @@ pub enum JsErasedNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
     Aggregate(Vec<JsErasedError>),
+    /// An error thrown while disposing a resource, which suppressed a previous error.
+    ///
+    /// More information:
+    /// - [ECMAScript reference][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error thrown during disposal.
+        error: Box<JsErasedError>,
+        /// The error that was suppressed by `error`.
+        suppressed: Box<JsErasedError>,
+    },
     /// A generic error. Commonly used as the base for custom exceptions.
     ///
     /// More information:
@@ -1839,6 +1968,7 @@ impl fmt::Display for JsErasedNativeErrorKind {
             Self::Aggregate(errors) => {
                 return write!(f, "AggregateError(error count: {})", errors.len());
             }
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Error => "Error",
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
@@ -1,7 +1,7 @@
 use std::path::Path;
 
 use crate::{
-    Context, JsError, Source,
+    Context, JsError, Script, Source,
     builtins::promise::PromiseState,
     module::Module,
     vm::{shadow_stack::ShadowEntry, source_info::SourcePath},
@@ -176,3 +176,57 @@ fn eval_error_has_backtrace() {
         }
     }
 }
+
+/// Test that the backtrace of a `JsError` is mapped back to the original sources when the script
+/// has a source map.
+#[test]
+fn backtrace_with_source_map() {
+    let context = &mut Context::default();
+
+    let code = indoc! {r#"
+        function a(){throw new Error("boom")}
+        a();
+    "#};
+    let source_map = r#"{
+        "version": 3,
+        "sources": ["../src/app.ts"],
+        "names": ["fail"],
+        "mappings": "SAASA,IACL;AAEJA"
+    }"#;
+    let source = Source::from_bytes(code)
+        .with_path(Path::new("dist/app.js"))
+        .with_source_map(source_map);
+    let script = Script::parse(source, None, context).unwrap();
+    let error = script.evaluate(context).unwrap_err();
+
+    let path = Path::new("dist").join("../src/app.ts");
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "Error: boom ({0}:2:5)\n    at fail ({0}:2:5)\n    at <main> ({0}:4:1)",
+            path.display()
+        )
+    );
+}
+
+/// Test that an invalid source map supplied on a `Source` is reported as a `SyntaxError`.
+#[test]
+fn invalid_source_map() {
+    let context = &mut Context::default();
+
+    for source_map in [
+        "not json",
+        r#"{ "version": 2, "sources": [], "mappings": "" }"#,
+        r#"{ "version": 3, "sources": [], "mappings": "AAAA" }"#,
+        r#"{ "version": 3, "sources": ["a.js"], "mappings": "A!" }"#,
+    ] {
+        let source = Source::from_bytes("1").with_source_map(source_map);
+        let error = Script::parse(source, None, context).unwrap_err();
+        assert!(
+            error
+                .as_native()
+                .is_some_and(|error| error.kind == crate::JsNativeErrorKind::Syntax),
+            "{source_map} should be rejected"
+        );
+    }
+}
@@ -99,6 +99,7 @@ pub mod optimizer;
 pub mod property;
 pub mod realm;
 pub mod script;
+pub mod snapshot;
 pub mod string;
 pub mod symbol;
 pub mod value;
@@ -348,6 +349,14 @@ impl TestAction {
         })
     }
 
+    /// Asserts that evaluating `source` throws an engine error.
+    fn assert_engine_error(source: impl Into<Cow<'static, str>>, error: EngineError) -> Self {
+        Self(Inner::AssertEngineError {
+            source: source.into(),
+            error,
+        })
+    }
+
     /// Asserts that calling `op` with the currently executing context returns `true`.
     fn assert_context(op: fn(&mut Context) -> bool) -> Self {
         Self(Inner::AssertContext { op })
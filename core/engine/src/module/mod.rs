@@ -29,6 +29,7 @@ use std::rc::Rc;
 
 use rustc_hash::FxHashSet;
 
+use boa_ast::LinearPosition;
 use boa_ast::declaration::ImportAttribute as AstImportAttribute;
 use boa_engine::js_string;
 use boa_engine::property::PropertyKey;
@@ -41,6 +42,7 @@ use boa_parser::{Parser, Source};
 pub use loader::*;
 pub use namespace::ModuleNamespace;
 use source::SourceTextModule;
+pub(crate) use source::{CompiledModule, ModuleCode};
 pub use synthetic::{SyntheticModule, SyntheticModuleInitializer};
 
 use crate::bytecompiler::ToJsString;
@@ -51,8 +53,10 @@ use crate::{
     builtins,
     builtins::promise::{PromiseCapability, PromiseState},
     environments::DeclarativeEnvironment,
+    js_error,
     object::{JsObject, JsPromise},
     realm::Realm,
+    vm::{bytecode_cache, source_info::SourceMapV3},
 };
 
 mod loader;
@@ -275,6 +279,10 @@ impl Module {
         context: &mut Context,
     ) -> JsResult<Self> {
         let path = src.path().map(Path::to_path_buf);
+        let source_map = src
+            .source_map()
+            .map(|source_map| SourceMapV3::parse(source_map, path.as_deref()))
+            .transpose()?;
         let realm = realm.unwrap_or_else(|| context.realm().clone());
 
         let mut parser = Parser::new(src);
@@ -282,7 +290,14 @@ impl Module {
         let (module, source) =
             parser.parse_module_with_source(realm.scope(), context.interner_mut())?;
 
-        let source_text = SourceText::new(source);
+        // A source map supplied on the `Source` takes precedence over an inline one.
+        let source_map = source_map.or_else(|| {
+            SourceMapV3::from_inline_comment(
+                source.get_code_points_from_pos(LinearPosition::default()),
+                path.as_deref(),
+            )
+        });
+        let source_text = SourceText::with_source_map(source, source_map);
         let src = SourceTextModule::new(module, context.interner(), source_text, path.clone());
 
         Ok(Self {
@@ -296,6 +311,75 @@ impl Module {
         })
     }
 
+    /// Compiles this module and serializes its bytecode into a bytecode cache.
+    ///
+    /// The returned bytes can be loaded with [`Module::from_bytecode_cache`] to load the module
+    /// later without parsing and compiling it again. The cache can only be loaded by the same
+    /// build of the engine. This can be called at any point of the lifecycle of the module.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if this is not a source text module, since only those are
+    /// compiled.
+    pub fn to_bytecode_cache(&self, context: &mut Context) -> JsResult<Vec<u8>> {
+        let ModuleKind::SourceText(src) = self.kind() else {
+            return Err(js_error!(
+                TypeError: "only source text modules can be serialized into a bytecode cache"
+            ));
+        };
+        let compiled = src.compile(context)?;
+
+        Ok(bytecode_cache::encode_module(
+            &compiled,
+            src.code(),
+            context.interner(),
+            self.realm().scope(),
+        ))
+    }
+
+    /// Loads a module from a bytecode cache created with [`Module::to_bytecode_cache`].
+    ///
+    /// The returned module is unlinked, like the modules returned by [`Module::parse`], and
+    /// has to be loaded, linked and evaluated in `realm` (or the current realm if `None`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not a valid module bytecode cache, or if it was created
+    /// by an incompatible build of the engine. In that case, the module should be parsed and
+    /// compiled again from its source.
+    ///
+    /// The cache errors are thrown as [`JsNativeError`]s with the message of the corresponding
+    /// [`BytecodeCacheError`].
+    ///
+    /// # Safety
+    ///
+    /// The bytecode in the cache is run without being verified, so `bytes` must not have been
+    /// crafted or tampered with. Caches created by [`Module::to_bytecode_cache`] are safe to
+    /// load, and so are truncated or accidentally corrupted ones, since the checksum rejects
+    /// them. A crafted cache can make the VM access registers out of bounds.
+    ///
+    /// [`BytecodeCacheError`]: crate::vm::BytecodeCacheError
+    pub unsafe fn from_bytecode_cache(
+        bytes: &[u8],
+        realm: Option<Realm>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let realm = realm.unwrap_or_else(|| context.realm().clone());
+        let cached = bytecode_cache::decode_module(bytes, realm.scope(), context.interner_mut())?;
+        let path = cached.code.path.clone();
+        let src = SourceTextModule::from_compiled(cached.compiled, cached.code);
+
+        Ok(Self {
+            inner: Gc::new(ModuleRepr {
+                realm,
+                namespace: GcRefCell::default(),
+                kind: ModuleKind::SourceText(Box::new(src)),
+                host_defined: HostDefined::default(),
+                path,
+            }),
+        })
+    }
+
     /// Abstract operation [`CreateSyntheticModule ( exportNames, evaluationSteps, realm )`][spec].
     ///
     /// Creates a new Synthetic Module from its list of exported names, its evaluation steps and
@@ -16,7 +16,7 @@ use boa_ast::{
         ContainsSymbol, LexicallyScopedDeclaration, bound_names, contains,
         lexically_scoped_declarations, var_scoped_declarations,
     },
-    scope::BindingLocator,
+    scope::{BindingLocator, Scope},
 };
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_interner::Interner;
@@ -248,6 +248,11 @@ impl std::fmt::Debug for SourceTextContext {
 #[derive(Trace, Finalize)]
 pub(crate) struct SourceTextModule {
     status: GcRefCell<ModuleStatus>,
+    /// The compiled code of the module, set when the module is first linked or serialized.
+    ///
+    /// Modules loaded from a bytecode cache start with their compiled code, and an empty
+    /// source in their unlinked status.
+    compiled: GcRefCell<Option<CompiledModule>>,
     loaded_modules: GcRefCell<FxHashMap<super::ModuleRequest, Module>>,
     async_parent_modules: GcRefCell<Vec<Module>>,
     import_meta: GcRefCell<Option<JsObject>>,
@@ -259,6 +264,7 @@ impl std::fmt::Debug for SourceTextModule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SourceTextModule")
             .field("status", &self.status)
+            .field("compiled", &self.compiled)
             .field("loaded_modules", &self.loaded_modules)
             .field("async_parent_modules", &self.async_parent_modules)
             .field("import_meta", &self.import_meta)
@@ -266,15 +272,39 @@ impl std::fmt::Debug for SourceTextModule {
     }
 }
 
+/// The static records of a [`SourceTextModule`], created when it is parsed.
 #[derive(Debug)]
-struct ModuleCode {
-    has_tla: bool,
-    requested_modules: IndexSet<super::ModuleRequest, BuildHasherDefault<FxHasher>>,
-    path: Option<PathBuf>,
-    import_entries: Vec<ImportEntry>,
-    local_export_entries: Vec<LocalExportEntry>,
-    indirect_export_entries: Vec<IndirectExportEntry>,
-    star_export_entries: Vec<super::ModuleRequest>,
+pub(crate) struct ModuleCode {
+    pub(crate) has_tla: bool,
+    pub(crate) requested_modules: IndexSet<super::ModuleRequest, BuildHasherDefault<FxHasher>>,
+    pub(crate) path: Option<PathBuf>,
+    pub(crate) import_entries: Vec<ImportEntry>,
+    pub(crate) local_export_entries: Vec<LocalExportEntry>,
+    pub(crate) indirect_export_entries: Vec<IndirectExportEntry>,
+    pub(crate) star_export_entries: Vec<super::ModuleRequest>,
+}
+
+/// The compiled code of a [`SourceTextModule`].
+#[derive(Clone, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub(crate) struct CompiledModule {
+    pub(crate) codeblock: Gc<CodeBlock>,
+    /// The scope of the module environment.
+    #[unsafe_ignore_trace]
+    pub(crate) scope: Scope,
+    /// The function declarations of the module, as the indices of their code in the constants
+    /// of `codeblock` and the bindings they initialize.
+    #[unsafe_ignore_trace]
+    pub(crate) functions: Vec<(u32, BindingLocator)>,
+}
+
+impl std::fmt::Debug for CompiledModule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CompiledModule")
+            .field("codeblock", &self.codeblock)
+            .field("functions", &self.functions)
+            .finish_non_exhaustive()
+    }
 }
 
 struct ModuleRequestsVisitor<'a> {
@@ -439,6 +469,7 @@ impl SourceTextModule {
                 source,
                 source_text,
             }),
+            compiled: GcRefCell::default(),
             loaded_modules: GcRefCell::default(),
             async_parent_modules: GcRefCell::default(),
             import_meta: GcRefCell::default(),
@@ -454,6 +485,26 @@ impl SourceTextModule {
         }
     }
 
+    /// Creates a new `SourceTextModule` from the code and records loaded from a bytecode cache.
+    pub(super) fn from_compiled(compiled: CompiledModule, code: ModuleCode) -> Self {
+        Self {
+            status: GcRefCell::new(ModuleStatus::Unlinked {
+                source: boa_ast::Module::default(),
+                source_text: SourceText::default(),
+            }),
+            compiled: GcRefCell::new(Some(compiled)),
+            loaded_modules: GcRefCell::default(),
+            async_parent_modules: GcRefCell::default(),
+            import_meta: GcRefCell::default(),
+            code,
+        }
+    }
+
+    /// Gets the static records of this module.
+    pub(super) const fn code(&self) -> &ModuleCode {
+        &self.code
+    }
+
     /// Abstract operation [`InnerModuleLoading`][spec].
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-InnerModuleLoading
@@ -1610,201 +1661,87 @@ impl SourceTextModule {
         // 4. Assert: realm is not undefined.
         let realm = module_self.realm().clone();
 
-        let status = self.status.borrow();
-        let (source, source_text) = status
-            .source()
-            .js_expect("module can only initialize its environment in the linking phase")?;
-
         // 5. Let env be NewModuleEnvironment(realm.[[GlobalEnv]]).
         // 6. Set module.[[Environment]] to env.
-        let env = source.scope().clone();
-
-        let spanned_source_text = SpannedSourceText::new_source_only(source_text.clone());
-        let mut compiler = ByteCompiler::new(
-            js_string!("<main>"),
-            true,
-            false,
-            source.scope().clone(),
-            source.scope().clone(),
-            self.code.has_tla,
-            false,
-            context.interner_mut(),
-            false,
-            spanned_source_text,
-            self.code.path.clone().into(),
-        );
-
-        compiler.async_handler = self.code.has_tla.then(|| compiler.push_handler());
+        let CompiledModule {
+            codeblock,
+            scope: env,
+            functions,
+        } = self.compile(context)?;
 
         let mut imports = Vec::new();
 
-        let (codeblock, functions) = {
-            // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
-            for entry in &self.code.import_entries {
-                // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
-                let module_request = super::ModuleRequest::from_ast(
-                    entry.module_request().to_js_string(compiler.interner()),
-                    entry.attributes(),
-                    compiler.interner(),
-                );
-                let imported_module = self.loaded_modules.borrow()[&module_request].clone();
-
-                if let ImportName::Name(name) = entry.import_name() {
-                    let name = name.to_js_string(compiler.interner());
-                    // c. Else,
-                    //    i. Let resolution be importedModule.ResolveExport(in.[[ImportName]]).
-                    let resolution = imported_module
-                        .resolve_export(&name, &mut HashSet::default(), compiler.interner())
-                        // ii. If resolution is either null or ambiguous, throw a SyntaxError exception.
-                        .map_err(|err| match err {
-                            ResolveExportError::NotFound => JsNativeError::syntax().with_message(
-                                format!("could not find export `{}`", name.to_std_string_escaped()),
-                            ),
-                            ResolveExportError::Ambiguous => {
-                                JsNativeError::syntax().with_message(format!(
-                                    "could not resolve ambiguous export `{}`",
-                                    name.to_std_string_escaped()
-                                ))
-                            }
-                        })?;
+        // 7. For each ImportEntry Record in of module.[[ImportEntries]], do
+        for entry in &self.code.import_entries {
+            // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
+            let module_request = super::ModuleRequest::from_ast(
+                entry.module_request().to_js_string(context.interner()),
+                entry.attributes(),
+                context.interner(),
+            );
+            let imported_module = self.loaded_modules.borrow()[&module_request].clone();
 
-                    // 2. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
-                    // 3. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
-                    let local_name = entry.local_name().to_js_string(compiler.interner());
-                    let locator = env
-                        .get_binding(&local_name)
-                        .js_expect("binding must exist")?;
+            if let ImportName::Name(name) = entry.import_name() {
+                let name = name.to_js_string(context.interner());
+                // c. Else,
+                //    i. Let resolution be importedModule.ResolveExport(in.[[ImportName]]).
+                let resolution = imported_module
+                    .resolve_export(&name, &mut HashSet::default(), context.interner())
+                    // ii. If resolution is either null or ambiguous, throw a SyntaxError exception.
+                    .map_err(|err| match err {
+                        ResolveExportError::NotFound => JsNativeError::syntax().with_message(
+                            format!("could not find export `{}`", name.to_std_string_escaped()),
+                        ),
+                        ResolveExportError::Ambiguous => {
+                            JsNativeError::syntax().with_message(format!(
+                                "could not resolve ambiguous export `{}`",
+                                name.to_std_string_escaped()
+                            ))
+                        }
+                    })?;
 
-                    if let BindingName::Name(_) = resolution.binding_name() {
-                        // 1. Perform env.CreateImportBinding(in.[[LocalName]], resolution.[[Module]],
-                        //    resolution.[[BindingName]]).
-                        //    deferred to initialization below
-                        imports.push(ImportBinding::Single {
-                            locator,
-                            export_locator: resolution,
-                        });
-                    } else {
-                        // 1. Let namespace be GetModuleNamespace(resolution.[[Module]]).
-                        // deferred to initialization below
-                        imports.push(ImportBinding::Namespace {
-                            locator,
-                            module: resolution.into_module(),
-                        });
-                    }
+                // 2. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                // 3. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+                let local_name = entry.local_name().to_js_string(context.interner());
+                let locator = env
+                    .get_binding(&local_name)
+                    .js_expect("binding must exist")?;
+
+                if let BindingName::Name(_) = resolution.binding_name() {
+                    // 1. Perform env.CreateImportBinding(in.[[LocalName]], resolution.[[Module]],
+                    //    resolution.[[BindingName]]).
+                    //    deferred to initialization below
+                    imports.push(ImportBinding::Single {
+                        locator,
+                        export_locator: resolution,
+                    });
                 } else {
-                    // b. If in.[[ImportName]] is namespace-object, then
-                    //    ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
-                    //    iii. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
-                    let name = entry.local_name().to_js_string(compiler.interner());
-                    let locator = env.get_binding(&name).js_expect("binding must exist")?;
-
-                    //    i. Let namespace be GetModuleNamespace(importedModule).
-                    //       deferred to initialization below
+                    // 1. Let namespace be GetModuleNamespace(resolution.[[Module]]).
+                    // deferred to initialization below
                     imports.push(ImportBinding::Namespace {
                         locator,
-                        module: imported_module.clone(),
+                        module: resolution.into_module(),
                     });
                 }
+            } else {
+                // b. If in.[[ImportName]] is namespace-object, then
+                //    ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
+                //    iii. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
+                let name = entry.local_name().to_js_string(context.interner());
+                let locator = env.get_binding(&name).js_expect("binding must exist")?;
+
+                //    i. Let namespace be GetModuleNamespace(importedModule).
+                //       deferred to initialization below
+                imports.push(ImportBinding::Namespace {
+                    locator,
+                    module: imported_module.clone(),
+                });
             }
-
-            // 18. Let code be module.[[ECMAScriptCode]].
-            // 19. Let varDeclarations be the VarScopedDeclarations of code.
-            let var_declarations = var_scoped_declarations(source);
-            // 20. Let declaredVarNames be a new empty List.
-            let mut declared_var_names = Vec::new();
-            // 21. For each element d of varDeclarations, do
-            for var in var_declarations {
-                // a. For each element dn of the BoundNames of d, do
-                for name in var.bound_names() {
-                    let name = name.to_js_string(compiler.interner());
-
-                    // i. If declaredVarNames does not contain dn, then
-                    if !declared_var_names.contains(&name) {
-                        // 1. Perform ! env.CreateMutableBinding(dn, false).
-                        // 2. Perform ! env.InitializeBinding(dn, undefined).
-                        let binding = env
-                            .get_binding_reference(&name)
-                            .js_expect("binding must exist")?;
-                        let index = compiler.insert_binding(binding);
-                        compiler.emit_binding_access(
-                            BindingAccessOpcode::DefInitVar,
-                            &index,
-                            &CallFrame::undefined_register(),
-                        );
-
-                        // 3. Append dn to declaredVarNames.
-                        declared_var_names.push(name);
-                    }
-                }
-            }
-
-            // 22. Let lexDeclarations be the LexicallyScopedDeclarations of code.
-            // 23. Let privateEnv be null.
-            let lex_declarations = lexically_scoped_declarations(source);
-            let mut functions = Vec::new();
-            // 24. For each element d of lexDeclarations, do
-            for declaration in lex_declarations {
-                // ii. Else,
-                // a. For each element dn of the BoundNames of d, do
-                // 1. Perform ! env.CreateMutableBinding(dn, false).
-                //
-                // iii. If d is either a FunctionDeclaration, a GeneratorDeclaration, an
-                //      AsyncFunctionDeclaration, or an AsyncGeneratorDeclaration, then
-                // 1. Let fo be InstantiateFunctionObject of d with arguments env and privateEnv.
-                // 2. Perform ! env.InitializeBinding(dn, fo).
-                //
-                // deferred to below.
-                let (spec, locator): (FunctionSpec<'_>, _) = match declaration {
-                    LexicallyScopedDeclaration::FunctionDeclaration(f) => {
-                        let name = bound_names(f)[0].to_js_string(compiler.interner());
-                        let locator = env.get_binding(&name).js_expect("binding must exist")?;
-
-                        (f.into(), locator)
-                    }
-                    LexicallyScopedDeclaration::GeneratorDeclaration(g) => {
-                        let name = bound_names(g)[0].to_js_string(compiler.interner());
-                        let locator = env.get_binding(&name).js_expect("binding must exist")?;
-
-                        (g.into(), locator)
-                    }
-                    LexicallyScopedDeclaration::AsyncFunctionDeclaration(af) => {
-                        let name = bound_names(af)[0].to_js_string(compiler.interner());
-                        let locator = env.get_binding(&name).js_expect("binding must exist")?;
-
-                        (af.into(), locator)
-                    }
-                    LexicallyScopedDeclaration::AsyncGeneratorDeclaration(ag) => {
-                        let name = bound_names(ag)[0].to_js_string(compiler.interner());
-                        let locator = env.get_binding(&name).js_expect("binding must exist")?;
-
-                        (ag.into(), locator)
-                    }
-                    LexicallyScopedDeclaration::ClassDeclaration(_)
-                    | LexicallyScopedDeclaration::LexicalDeclaration(_)
-                    | LexicallyScopedDeclaration::AssignmentExpression(_) => {
-                        continue;
-                    }
-                };
-
-                functions.push((spec, locator));
-            }
-
-            // Should compile after initializing bindings first to ensure inner calls
-            // are correctly resolved to the outer functions instead of as global bindings.
-            let functions = functions
-                .into_iter()
-                .map(|(spec, locator)| (compiler.function(spec), locator))
-                .collect::<Vec<_>>();
-
-            compiler.compile_module_item_list(source.items());
-
-            (Gc::new(compiler.finish()), functions)
-        };
+        }
 
         // 8. Let moduleContext be a new ECMAScript code execution context.
         let mut envs = EnvironmentStack::new();
-        envs.push_module(source.scope().clone());
-        drop(status);
+        envs.push_module(env);
 
         // 9. Set the Function of moduleContext to null.
         // 10. Assert: module.[[Realm]] is not undefined.
@@ -1926,6 +1863,142 @@ impl SourceTextModule {
         Ok(())
     }
 
+    /// Compiles the code of the module, or returns the code compiled by a previous call or
+    /// loaded from a bytecode cache.
+    ///
+    /// Contains the steps of [`InitializeEnvironment ( )`][spec] that declare the bindings of
+    /// the module, which are emitted as bytecode.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-initialize-environment
+    pub(super) fn compile(&self, context: &mut Context) -> JsResult<CompiledModule> {
+        if let Some(compiled) = &*self.compiled.borrow() {
+            return Ok(compiled.clone());
+        }
+
+        let status = self.status.borrow();
+        let (source, source_text) = status
+            .source()
+            .js_expect("module can only be compiled before it is linked")?;
+        let env = source.scope().clone();
+
+        let spanned_source_text = SpannedSourceText::new_source_only(source_text.clone());
+        let mut compiler = ByteCompiler::new(
+            js_string!("<main>"),
+            true,
+            false,
+            source.scope().clone(),
+            source.scope().clone(),
+            self.code.has_tla,
+            false,
+            context.interner_mut(),
+            false,
+            spanned_source_text,
+            self.code.path.clone().into(),
+        );
+
+        compiler.async_handler = self.code.has_tla.then(|| compiler.push_handler());
+
+        // 18. Let code be module.[[ECMAScriptCode]].
+        // 19. Let varDeclarations be the VarScopedDeclarations of code.
+        let var_declarations = var_scoped_declarations(source);
+        // 20. Let declaredVarNames be a new empty List.
+        let mut declared_var_names = Vec::new();
+        // 21. For each element d of varDeclarations, do
+        for var in var_declarations {
+            // a. For each element dn of the BoundNames of d, do
+            for name in var.bound_names() {
+                let name = name.to_js_string(compiler.interner());
+
+                // i. If declaredVarNames does not contain dn, then
+                if !declared_var_names.contains(&name) {
+                    // 1. Perform ! env.CreateMutableBinding(dn, false).
+                    // 2. Perform ! env.InitializeBinding(dn, undefined).
+                    let binding = env
+                        .get_binding_reference(&name)
+                        .js_expect("binding must exist")?;
+                    let index = compiler.insert_binding(binding);
+                    compiler.emit_binding_access(
+                        BindingAccessOpcode::DefInitVar,
+                        &index,
+                        &CallFrame::undefined_register(),
+                    );
+
+                    // 3. Append dn to declaredVarNames.
+                    declared_var_names.push(name);
+                }
+            }
+        }
+
+        // 22. Let lexDeclarations be the LexicallyScopedDeclarations of code.
+        // 23. Let privateEnv be null.
+        let lex_declarations = lexically_scoped_declarations(source);
+        let mut functions = Vec::new();
+        // 24. For each element d of lexDeclarations, do
+        for declaration in lex_declarations {
+            // ii. Else,
+            // a. For each element dn of the BoundNames of d, do
+            // 1. Perform ! env.CreateMutableBinding(dn, false).
+            //
+            // iii. If d is either a FunctionDeclaration, a GeneratorDeclaration, an
+            //      AsyncFunctionDeclaration, or an AsyncGeneratorDeclaration, then
+            // 1. Let fo be InstantiateFunctionObject of d with arguments env and privateEnv.
+            // 2. Perform ! env.InitializeBinding(dn, fo).
+            //
+            // deferred to below.
+            let (spec, locator): (FunctionSpec<'_>, _) = match declaration {
+                LexicallyScopedDeclaration::FunctionDeclaration(f) => {
+                    let name = bound_names(f)[0].to_js_string(compiler.interner());
+                    let locator = env.get_binding(&name).js_expect("binding must exist")?;
+
+                    (f.into(), locator)
+                }
+                LexicallyScopedDeclaration::GeneratorDeclaration(g) => {
+                    let name = bound_names(g)[0].to_js_string(compiler.interner());
+                    let locator = env.get_binding(&name).js_expect("binding must exist")?;
+
+                    (g.into(), locator)
+                }
+                LexicallyScopedDeclaration::AsyncFunctionDeclaration(af) => {
+                    let name = bound_names(af)[0].to_js_string(compiler.interner());
+                    let locator = env.get_binding(&name).js_expect("binding must exist")?;
+
+                    (af.into(), locator)
+                }
+                LexicallyScopedDeclaration::AsyncGeneratorDeclaration(ag) => {
+                    let name = bound_names(ag)[0].to_js_string(compiler.interner());
+                    let locator = env.get_binding(&name).js_expect("binding must exist")?;
+
+                    (ag.into(), locator)
+                }
+                LexicallyScopedDeclaration::ClassDeclaration(_)
+                | LexicallyScopedDeclaration::LexicalDeclaration(_)
+                | LexicallyScopedDeclaration::AssignmentExpression(_) => {
+                    continue;
+                }
+            };
+
+            functions.push((spec, locator));
+        }
+
+        // Should compile after initializing bindings first to ensure inner calls
+        // are correctly resolved to the outer functions instead of as global bindings.
+        let functions = functions
+            .into_iter()
+            .map(|(spec, locator)| (compiler.function(spec), locator))
+            .collect::<Vec<_>>();
+
+        compiler.compile_module_item_list(source.items());
+
+        let module = CompiledModule {
+            codeblock: Gc::new(compiler.finish()),
+            scope: env,
+            functions,
+        };
+        drop(status);
+        *self.compiled.borrow_mut() = Some(module.clone());
+        Ok(module)
+    }
+
     /// Abstract operation [`ExecuteModule ( [ capability ] )`][spec].
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-source-text-module-record-execute-module
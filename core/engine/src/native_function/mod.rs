@@ -162,6 +162,14 @@ impl NativeFunction {
         }
     }
 
+    /// Returns the function pointer of this `NativeFunction`, or `None` if it is a closure.
+    pub(crate) fn as_fn_ptr(&self) -> Option<NativeFunctionPointer> {
+        match self.inner {
+            Inner::PointerFn(f) => Some(f),
+            Inner::Closure(_) => None,
+        }
+    }
+
     /// Creates a `NativeFunction` from a function returning a [`Future`]-like.
     ///
     /// The returned `NativeFunction` will return an ECMAScript `Promise` that will be fulfilled
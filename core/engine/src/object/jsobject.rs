@@ -1048,8 +1048,13 @@ impl<T: NativeObject> JsObject<T> {
 
     /// Create a new private name with this object as the unique identifier.
     pub(crate) fn private_name(&self, description: JsString) -> PrivateName {
+        PrivateName::new(description, self.private_name_id())
+    }
+
+    /// Returns the unique identifier of the private names created by this object.
+    pub(crate) fn private_name_id(&self) -> usize {
         let ptr: *const _ = self.as_ref();
-        PrivateName::new(description, ptr.cast::<()>() as usize)
+        ptr.cast::<()>() as usize
     }
 }
 
@@ -207,6 +207,16 @@ impl PrivateName {
     pub(crate) const fn new(description: JsString, id: usize) -> Self {
         Self { description, id }
     }
+
+    /// Returns the `[[Description]]` of the private name.
+    pub(crate) const fn description(&self) -> &JsString {
+        &self.description
+    }
+
+    /// Returns the unique identifier of the private name.
+    pub(crate) const fn id(&self) -> usize {
+        self.id
+    }
 }
 
 /// The representation of private object elements.
@@ -306,6 +316,11 @@ impl<T: ?Sized> Object<T> {
         self.properties.remove(key)
     }
 
+    /// Returns the `[[PrivateElements]]` of the object.
+    pub(crate) fn private_elements(&self) -> &[(PrivateName, PrivateElement)] {
+        &self.private_elements
+    }
+
     /// Append a private element to an object.
     pub(crate) fn append_private_element(&mut self, name: PrivateName, element: PrivateElement) {
         if let PrivateElement::Accessor { getter, setter } = &element {
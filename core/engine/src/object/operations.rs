@@ -6,6 +6,7 @@ use crate::{
     builtins::{
         Array, Proxy,
         function::{BoundFunction, ClassFieldDefinition, OrdinaryFunction, set_function_name},
+        shadow_realm::WrappedFunction,
     },
     context::intrinsics::{StandardConstructor, StandardConstructors},
     error::JsNativeError,
@@ -876,6 +877,10 @@ impl JsObject {
             return fun.get_function_realm(context);
         }
 
+        if let Some(wrapped) = self.downcast_ref::<WrappedFunction>() {
+            return Ok(wrapped.realm().clone());
+        }
+
         Ok(context.realm().clone())
     }
 
@@ -1216,16 +1221,50 @@ impl JsObject {
         field_record: &ClassFieldDefinition,
         context: &mut Context,
     ) -> JsResult<()> {
+        let (field_record, initializers, extra_initializers) = match field_record {
+            ClassFieldDefinition::Initializers(initializers) => {
+                for initializer in initializers {
+                    initializer.call(&self.clone().into(), &[], context)?;
+                }
+                return Ok(());
+            }
+            ClassFieldDefinition::Decorated {
+                field,
+                initializers,
+                extra_initializers,
+            } => (&**field, &initializers[..], &extra_initializers[..]),
+            field_record => (field_record, &[][..], &[][..]),
+        };
+
         // 2. Let initializer be fieldRecord.[[Initializer]].
         let initializer = match field_record {
             ClassFieldDefinition::Public(_, function, _)
             | ClassFieldDefinition::Private(_, function) => function,
+            ClassFieldDefinition::Decorated { .. } | ClassFieldDefinition::Initializers(_) => {
+                return Err(js_error!(TypeError: "invalid nested class field definition"));
+            }
         };
 
         // 3. If initializer is not empty, then
         // a. Let initValue be ? Call(initializer, receiver).
         // 4. Else, let initValue be undefined.
-        let init_value = initializer.call(&self.clone().into(), &[], context)?;
+        let mut init_value = initializer.call(&self.clone().into(), &[], context)?;
+
+        if let ClassFieldDefinition::Public(_, _, Some(function_name)) = field_record {
+            set_function_name(
+                &init_value
+                    .as_object()
+                    .js_expect("init value must be a function object")?,
+                function_name,
+                None,
+                context,
+            )?;
+        }
+
+        // NOTE: The initializers returned by the decorators of the field are applied in order.
+        for initializer in initializers {
+            init_value = initializer.call(&self.clone().into(), &[init_value], context)?;
+        }
 
         match field_record {
             // 1. Let fieldName be fieldRecord.[[Name]].
@@ -1236,22 +1275,17 @@ impl JsObject {
             }
             // 1. Let fieldName be fieldRecord.[[Name]].
             // 6. Else,
-            ClassFieldDefinition::Public(field_name, _, function_name) => {
-                if let Some(function_name) = function_name {
-                    set_function_name(
-                        &init_value
-                            .as_object()
-                            .js_expect("init value must be a function object")?,
-                        function_name,
-                        None,
-                        context,
-                    )?;
-                }
-
+            ClassFieldDefinition::Public(field_name, _, _) => {
                 // a. Assert: IsPropertyKey(fieldName) is true.
                 // b. Perform ? CreateDataPropertyOrThrow(receiver, fieldName, initValue).
                 self.create_data_property_or_throw(field_name.clone(), init_value, context)?;
             }
+            ClassFieldDefinition::Decorated { .. } | ClassFieldDefinition::Initializers(_) => {}
+        }
+
+        // NOTE: The extra initializers added by the decorators run once the field is defined.
+        for initializer in extra_initializers {
+            initializer.call(&self.clone().into(), &[], context)?;
         }
 
         // 7. Return unused.
@@ -1418,6 +1452,7 @@ impl JsValue {
         let len = obj.length_of_array_like(context)?;
 
         // 4. Let list be a new empty List.
+        Array::check_list_allocation::<JsValue>(len)?;
         let mut list = Vec::with_capacity(len as usize);
 
         // 5. Let index be 0.
@@ -12,17 +12,21 @@ use std::path::{Path, PathBuf};
 
 use rustc_hash::FxHashMap;
 
+use boa_ast::LinearPosition;
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_parser::{Parser, Source, source::ReadChar};
 
 use crate::{
     Context, HostDefined, JsResult, JsString, JsValue, Module, SpannedSourceText,
-    bytecompiler::{ByteCompiler, global_declaration_instantiation_context},
+    bytecompiler::{ByteCompiler, ToJsString, global_declaration_instantiation_context},
     environments::EnvironmentStack,
     js_string,
     realm::Realm,
     spanned_source_text::SourceText,
-    vm::{ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock},
+    vm::{
+        ActiveRunnable, CallFrame, CallFrameFlags, CodeBlock, bytecode_cache,
+        source_info::SourceMapV3,
+    },
 };
 
 /// ECMAScript's [**Script Record**][spec].
@@ -46,7 +50,10 @@ impl std::fmt::Debug for Script {
 #[derive(Trace, Debug, Finalize)]
 enum ScriptPhase {
     Ast(#[unsafe_ignore_trace] boa_ast::Script),
-    Codeblock(Gc<CodeBlock>),
+    /// Code loaded from a bytecode cache, whose compile time global declarations were not
+    /// instantiated yet.
+    Cached(Gc<CodeBlock>, #[unsafe_ignore_trace] Box<[JsString]>),
+    Codeblock(Gc<CodeBlock>, #[unsafe_ignore_trace] Box<[JsString]>),
 }
 
 #[derive(Trace, Finalize)]
@@ -90,6 +97,10 @@ impl Script {
         context: &mut Context,
     ) -> JsResult<Self> {
         let path = src.path().map(Path::to_path_buf);
+        let source_map = src
+            .source_map()
+            .map(|source_map| SourceMapV3::parse(source_map, path.as_deref()))
+            .transpose()?;
         let mut parser = Parser::new(src);
         parser.set_identifier(context.next_parser_identifier());
         if context.is_strict() {
@@ -101,7 +112,14 @@ impl Script {
             context.optimize_statement_list(code.statements_mut());
         }
 
-        let source_text = SourceText::new(source);
+        // A source map supplied on the `Source` takes precedence over an inline one.
+        let source_map = source_map.or_else(|| {
+            SourceMapV3::from_inline_comment(
+                source.get_code_points_from_pos(LinearPosition::default()),
+                path.as_deref(),
+            )
+        });
+        let source_text = SourceText::with_source_map(source, source_map);
 
         Ok(Self {
             inner: Gc::new(Inner {
@@ -119,10 +137,30 @@ impl Script {
     ///
     /// This is a no-op if this has been called previously.
     pub fn codeblock(&self, context: &mut Context) -> JsResult<Gc<CodeBlock>> {
-        let cb = {
+        let (cb, annex_b_function_names) = {
             let phase = self.inner.phase.borrow();
             let source = match &*phase {
-                ScriptPhase::Codeblock(codeblock) => return Ok(codeblock.clone()),
+                ScriptPhase::Codeblock(codeblock, _) => return Ok(codeblock.clone()),
+                ScriptPhase::Cached(codeblock, annex_b_function_names) => {
+                    let codeblock = codeblock.clone();
+                    let annex_b_function_names = annex_b_function_names.clone();
+                    drop(phase);
+
+                    #[cfg(feature = "annex-b")]
+                    for name in &annex_b_function_names {
+                        // Mirrors the bindings created by `global_declaration_instantiation_context`
+                        // when the script was compiled.
+                        if !self.inner.realm.scope().has_lex_binding(name)
+                            && context.can_declare_global_function(name)?
+                        {
+                            context.create_global_var_binding(name.clone(), false)?;
+                        }
+                    }
+
+                    *self.inner.phase.borrow_mut() =
+                        ScriptPhase::Codeblock(codeblock.clone(), annex_b_function_names);
+                    return Ok(codeblock);
+                }
                 ScriptPhase::Ast(source) => source,
             };
 
@@ -135,6 +173,11 @@ impl Script {
                 context,
             )?;
 
+            let cached_function_names = annex_b_function_names
+                .iter()
+                .map(|name| name.to_js_string(context.interner()))
+                .collect();
+
             let spanned_source_text = SpannedSourceText::new_source_only(self.get_source());
 
             let mut compiler = ByteCompiler::new(
@@ -159,14 +202,88 @@ impl Script {
             compiler.global_declaration_instantiation(source);
             compiler.compile_statement_list(source.statements(), true, false);
 
-            Gc::new(compiler.finish())
+            (Gc::new(compiler.finish()), cached_function_names)
         };
 
-        *self.inner.phase.borrow_mut() = ScriptPhase::Codeblock(cb.clone());
+        *self.inner.phase.borrow_mut() = ScriptPhase::Codeblock(cb.clone(), annex_b_function_names);
 
         Ok(cb)
     }
 
+    /// Compiles this script and serializes its bytecode into a bytecode cache.
+    ///
+    /// The returned bytes can be loaded with [`Script::from_bytecode_cache`] to run the script
+    /// later without parsing and compiling it again. The cache can only be loaded by the same
+    /// build of the engine.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the script cannot be compiled.
+    pub fn to_bytecode_cache(&self, context: &mut Context) -> JsResult<Vec<u8>> {
+        let codeblock = self.codeblock(context)?;
+        let phase = self.inner.phase.borrow();
+        let (ScriptPhase::Codeblock(_, annex_b_function_names)
+        | ScriptPhase::Cached(_, annex_b_function_names)) = &*phase
+        else {
+            unreachable!("the script was compiled above");
+        };
+
+        Ok(bytecode_cache::encode_script(
+            &codeblock,
+            &self.inner.source_text,
+            self.path(),
+            annex_b_function_names,
+            self.inner.realm.scope(),
+        ))
+    }
+
+    /// Loads a script from a bytecode cache created with [`Script::to_bytecode_cache`].
+    ///
+    /// The global lexical declarations of the script are added to the scope of `realm` (or the
+    /// current realm if `None`), as if the script was parsed in it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not a valid bytecode cache, or if it was created by
+    /// an incompatible build of the engine. In that case, the script should be parsed and
+    /// compiled again from its source. Like [`Script::parse`], this also throws a `SyntaxError`
+    /// if the script redeclares an existing global lexical binding.
+    ///
+    /// The cache errors are thrown as [`JsNativeError`]s with the message of the corresponding
+    /// [`BytecodeCacheError`].
+    ///
+    /// # Safety
+    ///
+    /// The bytecode in the cache is run without being verified, so `bytes` must not have been
+    /// crafted or tampered with. Caches created by [`Script::to_bytecode_cache`] are safe to
+    /// load, and so are truncated or accidentally corrupted ones, since the checksum rejects
+    /// them. A crafted cache can make the VM access registers out of bounds.
+    ///
+    /// [`JsNativeError`]: crate::JsNativeError
+    /// [`BytecodeCacheError`]: crate::vm::BytecodeCacheError
+    pub unsafe fn from_bytecode_cache(
+        bytes: &[u8],
+        realm: Option<Realm>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let realm = realm.unwrap_or_else(|| context.realm().clone());
+        let cached = bytecode_cache::decode_script(bytes, realm.scope())?;
+
+        Ok(Self {
+            inner: Gc::new(Inner {
+                realm,
+                phase: GcRefCell::new(ScriptPhase::Cached(
+                    cached.codeblock,
+                    cached.annex_b_function_names,
+                )),
+                source_text: cached.source_text,
+                loaded_modules: GcRefCell::default(),
+                host_defined: HostDefined::default(),
+                path: cached.path.map(|path| path.to_path_buf()),
+            }),
+        })
+    }
+
     /// Evaluates this script and returns its result.
     ///
     /// Note that this won't run any scheduled promise jobs; you need to call [`Context::run_jobs`]
@@ -250,4 +367,39 @@ impl Script {
     pub(super) fn get_source(&self) -> SourceText {
         self.inner.source_text.clone()
     }
+
+    /// Returns the code of this script, or `None` if it was not compiled yet.
+    pub(super) fn compiled_codeblock(&self) -> Option<Gc<CodeBlock>> {
+        match &*self.inner.phase.borrow() {
+            ScriptPhase::Ast(_) => None,
+            ScriptPhase::Cached(codeblock, _) | ScriptPhase::Codeblock(codeblock, _) => {
+                Some(codeblock.clone())
+            }
+        }
+    }
+
+    /// Returns `true` if both scripts are the same script.
+    pub(super) fn ptr_eq(&self, other: &Self) -> bool {
+        Gc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Creates a script from its compiled code, assuming its global declarations are already
+    /// instantiated in `realm`.
+    pub(super) fn from_compiled(
+        realm: Realm,
+        codeblock: Gc<CodeBlock>,
+        source_text: SourceText,
+        path: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            inner: Gc::new(Inner {
+                realm,
+                phase: GcRefCell::new(ScriptPhase::Codeblock(codeblock, Box::default())),
+                source_text,
+                loaded_modules: GcRefCell::default(),
+                host_defined: HostDefined::default(),
+                path,
+            }),
+        }
+    }
 }
@@ -0,0 +1,657 @@
+//! Restoration of a [`Snapshot`] into a newly built context.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use boa_ast::scope::BindingData;
+use boa_gc::Gc;
+
+use crate::{
+    Context, JsBigInt, JsObject, JsSymbol, JsValue,
+    builtins::{
+        Array, Date, OrdinaryObject,
+        array_buffer::{AlignedVec, ArrayBuffer, BufferObject},
+        error::{Error, ErrorKind},
+        function::{BoundFunction, ClassFieldDefinition, ConstructorKind, OrdinaryFunction},
+        map::ordered_map::OrderedMap,
+        regexp::RegExp,
+        set::ordered_set::OrderedSet,
+        symbol::registry_symbol,
+        typed_array::{TypedArray, TypedArrayKind},
+    },
+    environments::{
+        DeclarativeEnvironment, DeclarativeEnvironmentKind, Environment, EnvironmentStack,
+        FunctionEnvironment, FunctionSlots, LexicalEnvironment, PrivateEnvironment,
+        ThisBindingStatus,
+    },
+    native_function::{NativeFunction, NativeFunctionObject},
+    object::{NativeObject, PrivateElement, PrivateName, builtins::JsFunction, shape::RootShape},
+    property::{PropertyDescriptor, PropertyKey},
+    realm::Realm,
+    script::Script,
+    vm::{
+        ActiveRunnable,
+        bytecode_cache::{Reader, check_header},
+    },
+};
+
+use super::{
+    ExternalReferences, MAGIC, Snapshot, SnapshotError,
+    encode::{error_kind_tag, typed_array_kind_tag},
+    intrinsics::{IntrinsicTable, own_properties},
+};
+
+type DecodeResult<T = ()> = Result<T, SnapshotError>;
+
+/// The next identifier given to the private names of classes that were not stored.
+///
+/// Private name identifiers are otherwise the address of their class, which is always aligned,
+/// so odd identifiers never collide with them.
+static NEXT_PRIVATE_ID: AtomicUsize = AtomicUsize::new(1);
+
+/// Restores `snapshot` into the newly built `context`.
+pub(crate) fn restore(
+    context: &Context,
+    snapshot: &Snapshot,
+    external_references: &ExternalReferences,
+) -> Result<(), SnapshotError> {
+    let payload = check_header(snapshot.as_bytes(), MAGIC)?;
+    let realm = context.realm().clone();
+    let intrinsics = IntrinsicTable::new(&realm);
+
+    let mut reader = Reader::new(payload, realm.scope().clone());
+    reader.strings()?;
+    if reader.u32()? != intrinsics.len() {
+        return Err(SnapshotError::EngineMismatch);
+    }
+    reader.tables()?;
+
+    let mut decoder = Decoder {
+        reader,
+        intrinsics,
+        external_references,
+        realm,
+        root_shape: context.root_shape(),
+        symbols: Vec::new(),
+        scripts: Vec::new(),
+        objects: Vec::new(),
+        private_ids: Vec::new(),
+        environments: Vec::new(),
+    };
+
+    // Every object and environment is created before any of them is filled, since they can
+    // reference each other in any order.
+    decoder.symbols()?;
+    decoder.scripts()?;
+    let objects = decoder.objects()?;
+    decoder.private_ids()?;
+    let environments = decoder.environments()?;
+    for (object, body) in decoder.objects.clone().iter().zip(objects) {
+        decoder.body(body, |d| {
+            d.object_state(object)?;
+            d.object_internals(object)
+        })?;
+    }
+    for (environment, body) in decoder.environments.clone().iter().zip(environments) {
+        decoder.body(body, |d| d.environment_bindings(environment))?;
+    }
+    decoder.roots()?;
+
+    if !decoder.reader.is_empty() {
+        return Err(SnapshotError::Corrupted);
+    }
+    Ok(())
+}
+
+/// Decodes the values encoded by the `Encoder`, recreating them in a new context.
+struct Decoder<'a> {
+    reader: Reader<'a>,
+    intrinsics: IntrinsicTable,
+    external_references: &'a ExternalReferences,
+    realm: Realm,
+    root_shape: &'a RootShape,
+    symbols: Vec<JsSymbol>,
+    scripts: Vec<Script>,
+    objects: Vec<JsObject>,
+    private_ids: Vec<usize>,
+    environments: Vec<Gc<DeclarativeEnvironment>>,
+}
+
+impl<'a> Decoder<'a> {
+    /// Decodes the section `bytes` with `f`, which must consume all of it.
+    fn body(&mut self, bytes: &'a [u8], f: impl FnOnce(&mut Self) -> DecodeResult) -> DecodeResult {
+        let outer = self.reader.swap_bytes(bytes);
+        let result = f(self);
+        let rest = self.reader.swap_bytes(outer);
+        result?;
+        if !rest.is_empty() {
+            return Err(SnapshotError::Corrupted);
+        }
+        Ok(())
+    }
+
+    fn option<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> DecodeResult<T>,
+    ) -> DecodeResult<Option<T>> {
+        if self.reader.bool()? {
+            f(self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn list<T>(&mut self, mut f: impl FnMut(&mut Self) -> DecodeResult<T>) -> DecodeResult<Vec<T>> {
+        let len = self.reader.len()?;
+        let mut values = Vec::with_capacity(len);
+        for _ in 0..len {
+            values.push(f(self)?);
+        }
+        Ok(values)
+    }
+
+    fn index<T: Clone>(&mut self, values: &[T]) -> DecodeResult<T> {
+        let index = self.reader.u32()? as usize;
+        values.get(index).cloned().ok_or(SnapshotError::Corrupted)
+    }
+
+    fn value(&mut self) -> DecodeResult<JsValue> {
+        Ok(match self.reader.u8()? {
+            0 => JsValue::undefined(),
+            1 => JsValue::null(),
+            2 => self.reader.bool()?.into(),
+            3 => i32::from_le_bytes(self.reader.u32()?.to_le_bytes()).into(),
+            4 => f64::from_bits(self.reader.u64()?).into(),
+            5 => self.reader.string()?.into(),
+            6 => self.symbol()?.into(),
+            7 => self.bigint()?.into(),
+            8 => self.object()?.into(),
+            _ => return Err(SnapshotError::Corrupted),
+        })
+    }
+
+    fn bigint(&mut self) -> DecodeResult<JsBigInt> {
+        JsBigInt::from_string_radix(self.reader.str()?, 16).ok_or(SnapshotError::Corrupted)
+    }
+
+    fn symbol(&mut self) -> DecodeResult<JsSymbol> {
+        let index = self.reader.u32()? as usize;
+        self.symbols
+            .get(index)
+            .cloned()
+            .ok_or(SnapshotError::Corrupted)
+    }
+
+    fn symbols(&mut self) -> DecodeResult {
+        self.symbols = self.list(|d| {
+            let symbol = match d.reader.u8()? {
+                0 => JsSymbol::well_known(d.reader.u64()?),
+                1 => registry_symbol(&d.reader.string()?).ok(),
+                2 => JsSymbol::new(d.reader.option(Reader::string)?),
+                _ => None,
+            };
+            symbol.ok_or(SnapshotError::Corrupted)
+        })?;
+        Ok(())
+    }
+
+    fn scripts(&mut self) -> DecodeResult {
+        self.scripts = self.list(|d| {
+            let codeblock = d.reader.code_block()?;
+            let source = d.reader.source()?;
+            let path = d.reader.option(Reader::path)?;
+            Ok(Script::from_compiled(
+                d.realm.clone(),
+                codeblock,
+                source,
+                path.map(|path| path.to_path_buf()),
+            ))
+        })?;
+        Ok(())
+    }
+
+    fn object(&mut self) -> DecodeResult<JsObject> {
+        let index = self.reader.u32()?;
+        if let Some(object) = self.intrinsics.get(index) {
+            return Ok(object.clone());
+        }
+        self.objects
+            .get((index - self.intrinsics.len()) as usize)
+            .cloned()
+            .ok_or(SnapshotError::Corrupted)
+    }
+
+    fn create<T: NativeObject>(&self, data: T) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(self.root_shape, None, data).upcast()
+    }
+
+    /// Creates the objects of the snapshot, returning the sections that hold their state.
+    fn objects(&mut self) -> DecodeResult<Vec<&'a [u8]>> {
+        let len = self.reader.len()?;
+        let mut bodies = Vec::with_capacity(len);
+        for _ in 0..len {
+            let object = self.object_kind()?;
+            self.objects.push(object);
+            bodies.push(self.reader.bytes()?);
+        }
+        Ok(bodies)
+    }
+
+    fn object_kind(&mut self) -> DecodeResult<JsObject> {
+        Ok(match self.reader.u8()? {
+            0 => self.create(OrdinaryObject),
+            1 => self.create(Array),
+            2 => {
+                let code = self.reader.code_block()?;
+                self.create(OrdinaryFunction::new(
+                    code,
+                    EnvironmentStack::new(),
+                    None,
+                    self.realm.clone(),
+                ))
+            }
+            3 => {
+                let target = self.object()?;
+                self.create(BoundFunction::new(target, JsValue::undefined(), Vec::new()))
+            }
+            4 => {
+                let index = self.reader.u32()?;
+                let f = self
+                    .external_references
+                    .get(index)
+                    .ok_or(SnapshotError::MissingExternalReference(index))?;
+                let name = self.reader.string()?;
+                let constructor = self.reader.option(|r| {
+                    Ok(if r.bool()? {
+                        ConstructorKind::Base
+                    } else {
+                        ConstructorKind::Derived
+                    })
+                })?;
+                self.create(NativeFunctionObject {
+                    f: NativeFunction::from_fn_ptr(f),
+                    name,
+                    constructor,
+                    realm: Some(self.realm.clone()),
+                })
+            }
+            5 => {
+                let tag = self.reader.u8()?;
+                let kind = [
+                    ErrorKind::Aggregate,
+                    ErrorKind::Error,
+                    ErrorKind::Eval,
+                    ErrorKind::Type,
+                    ErrorKind::Range,
+                    ErrorKind::Reference,
+                    ErrorKind::Syntax,
+                    ErrorKind::Uri,
+                    ErrorKind::Suppressed,
+                ]
+                .into_iter()
+                .find(|&kind| error_kind_tag(kind) == tag)
+                .ok_or(SnapshotError::Corrupted)?;
+                self.create(Error::new(kind))
+            }
+            6 => {
+                let value = self.reader.bool()?;
+                self.create(value)
+            }
+            7 => {
+                let value = f64::from_bits(self.reader.u64()?);
+                self.create(value)
+            }
+            8 => {
+                let string = self.reader.string()?;
+                self.create(string)
+            }
+            9 => {
+                let symbol = self.symbol()?;
+                self.create(symbol)
+            }
+            10 => {
+                let bigint = self.bigint()?;
+                self.create(bigint)
+            }
+            11 => self.create(OrderedMap::<JsValue>::new()),
+            12 => self.create(OrderedSet::new()),
+            13 => {
+                let time = f64::from_bits(self.reader.u64()?);
+                self.create(Date::new(time))
+            }
+            14 => {
+                let source = self.reader.string()?;
+                let flags = self.reader.string()?;
+                let regexp =
+                    RegExp::compile_pattern(source, flags).map_err(|_| SnapshotError::Corrupted)?;
+                self.create(regexp)
+            }
+            15 => {
+                let data = self.reader.option(|r| {
+                    let bytes = r.bytes()?;
+                    let mut data = AlignedVec::<u8>::new(64);
+                    data.extend_from_slice(bytes);
+                    Ok(data)
+                })?;
+                let max_byte_len = self.reader.option(Reader::u64)?;
+                self.create(ArrayBuffer::from_slots(
+                    data,
+                    max_byte_len,
+                    JsValue::undefined(),
+                ))
+            }
+            16 => {
+                let tag = self.reader.u8()?;
+                let kind = [
+                    TypedArrayKind::Int8,
+                    TypedArrayKind::Uint8,
+                    TypedArrayKind::Uint8Clamped,
+                    TypedArrayKind::Int16,
+                    TypedArrayKind::Uint16,
+                    TypedArrayKind::Int32,
+                    TypedArrayKind::Uint32,
+                    TypedArrayKind::BigInt64,
+                    TypedArrayKind::BigUint64,
+                    #[cfg(feature = "float16")]
+                    TypedArrayKind::Float16,
+                    TypedArrayKind::Float32,
+                    TypedArrayKind::Float64,
+                ]
+                .into_iter()
+                .find(|&kind| typed_array_kind_tag(kind) == tag)
+                .ok_or(SnapshotError::Corrupted)?;
+                let byte_offset = self.reader.u64()?;
+                let byte_length = self.reader.option(Reader::u64)?;
+                let array_length = self.reader.option(Reader::u64)?;
+                let buffer = self
+                    .object()?
+                    .downcast::<ArrayBuffer>()
+                    .map_err(|_| SnapshotError::Corrupted)?;
+                self.create(TypedArray::new(
+                    BufferObject::Buffer(buffer),
+                    kind,
+                    byte_offset,
+                    byte_length,
+                    array_length,
+                ))
+            }
+            _ => return Err(SnapshotError::Corrupted),
+        })
+    }
+
+    fn object_state(&mut self, object: &JsObject) -> DecodeResult {
+        let prototype = self.option(Self::object)?;
+        let extensible = self.reader.bool()?;
+        let properties = self.list(|d| Ok((d.property_key()?, d.descriptor()?)))?;
+        let private_elements = self.list(|d| Ok((d.private_name()?, d.private_element()?)))?;
+
+        let mut object = object.borrow_mut();
+        object.extensible = true;
+        object.set_prototype(prototype);
+        for (key, descriptor) in properties {
+            object.insert(key, descriptor);
+        }
+        for (name, element) in private_elements {
+            object.append_private_element(name, element);
+        }
+        object.extensible = extensible;
+        Ok(())
+    }
+
+    fn object_internals(&mut self, object: &JsObject) -> DecodeResult {
+        if object.is::<OrdinaryFunction>() {
+            let environments = self.environment_stack()?;
+            let home_object = self.option(Self::object)?;
+            let script_or_module = self.option(|d| {
+                let script = d.index(&d.scripts.clone())?;
+                Ok(ActiveRunnable::Script(script))
+            })?;
+            let fields = self.list(Self::class_field)?;
+            let private_methods = self.list(|d| Ok((d.private_name()?, d.private_element()?)))?;
+
+            let mut function = object
+                .downcast_mut::<OrdinaryFunction>()
+                .expect("checked that the object is a function");
+            function.environments = environments;
+            function.home_object = home_object;
+            function.script_or_module = script_or_module;
+            function.fields_mut().extend(fields);
+            for (name, method) in private_methods {
+                function.push_private_method(name, method);
+            }
+        } else if object.is::<BoundFunction>() {
+            let this = self.value()?;
+            let args = self.list(Self::value)?;
+            object
+                .downcast_mut::<BoundFunction>()
+                .expect("checked that the object is a bound function")
+                .set_bound_values(this, args);
+        } else if object.is::<OrderedMap<JsValue>>() {
+            let entries = self.list(|d| Ok((d.value()?, d.value()?)))?;
+            let mut map = object
+                .downcast_mut::<OrderedMap<JsValue>>()
+                .expect("checked that the object is a map");
+            for (key, value) in entries {
+                map.insert(key, value);
+            }
+        } else if object.is::<OrderedSet>() {
+            let values = self.list(Self::value)?;
+            let mut set = object
+                .downcast_mut::<OrderedSet>()
+                .expect("checked that the object is a set");
+            for value in values {
+                set.add(value);
+            }
+        } else if object.is::<ArrayBuffer>() {
+            let detach_key = self.value()?;
+            object
+                .downcast_mut::<ArrayBuffer>()
+                .expect("checked that the object is an array buffer")
+                .set_detach_key(detach_key);
+        }
+        Ok(())
+    }
+
+    fn property_key(&mut self) -> DecodeResult<PropertyKey> {
+        Ok(match self.reader.u8()? {
+            0 => self.reader.string()?.into(),
+            1 => self.symbol()?.into(),
+            2 => self.reader.u32()?.into(),
+            _ => return Err(SnapshotError::Corrupted),
+        })
+    }
+
+    fn descriptor(&mut self) -> DecodeResult<PropertyDescriptor> {
+        let builder = match self.reader.u8()? {
+            0 => PropertyDescriptor::builder()
+                .value(self.value()?)
+                .writable(self.reader.bool()?),
+            1 => PropertyDescriptor::builder()
+                .get(self.value()?)
+                .set(self.value()?),
+            _ => return Err(SnapshotError::Corrupted),
+        };
+        Ok(builder
+            .enumerable(self.reader.bool()?)
+            .configurable(self.reader.bool()?)
+            .build())
+    }
+
+    fn private_name(&mut self) -> DecodeResult<PrivateName> {
+        let description = self.reader.string()?;
+        let id = self.private_id()?;
+        Ok(PrivateName::new(description, id))
+    }
+
+    fn private_id(&mut self) -> DecodeResult<usize> {
+        let index = self.reader.u32()? as usize;
+        self.private_ids
+            .get(index)
+            .copied()
+            .ok_or(SnapshotError::Corrupted)
+    }
+
+    fn private_ids(&mut self) -> DecodeResult {
+        self.private_ids = self.list(|d| {
+            Ok(d.option(Self::object)?.map_or_else(
+                || NEXT_PRIVATE_ID.fetch_add(2, Ordering::Relaxed),
+                |owner| owner.private_name_id(),
+            ))
+        })?;
+        Ok(())
+    }
+
+    fn private_element(&mut self) -> DecodeResult<PrivateElement> {
+        Ok(match self.reader.u8()? {
+            0 => PrivateElement::Field(self.value()?),
+            1 => PrivateElement::Method(self.object()?),
+            2 => PrivateElement::Accessor {
+                getter: self.option(Self::object)?,
+                setter: self.option(Self::object)?,
+            },
+            _ => return Err(SnapshotError::Corrupted),
+        })
+    }
+
+    fn class_field(&mut self) -> DecodeResult<ClassFieldDefinition> {
+        Ok(match self.reader.u8()? {
+            0 => {
+                let key = self.property_key()?;
+                let function = self.function()?;
+                let name = self.option(Self::property_key)?;
+                ClassFieldDefinition::Public(key, function, name)
+            }
+            1 => {
+                let name = self.private_name()?;
+                let function = self.function()?;
+                ClassFieldDefinition::Private(name, function)
+            }
+            2 => ClassFieldDefinition::Decorated {
+                field: Box::new(self.class_field()?),
+                initializers: self.list(Self::function)?.into(),
+                extra_initializers: self.list(Self::function)?.into(),
+            },
+            3 => ClassFieldDefinition::Initializers(self.list(Self::function)?.into()),
+            _ => return Err(SnapshotError::Corrupted),
+        })
+    }
+
+    fn function(&mut self) -> DecodeResult<JsFunction> {
+        JsFunction::from_object(self.object()?).ok_or(SnapshotError::Corrupted)
+    }
+
+    fn environment_stack(&mut self) -> DecodeResult<EnvironmentStack> {
+        let environments = self.list(|d| {
+            Ok(match d.reader.u8()? {
+                0 => Environment::Declarative(d.index(&d.environments.clone())?),
+                1 => Environment::Object(d.object()?),
+                _ => return Err(SnapshotError::Corrupted),
+            })
+        })?;
+        let private_environments = self.list(|d| {
+            let id = d.private_id()?;
+            let descriptions = d.reader.list(Reader::string)?;
+            Ok(Gc::new(PrivateEnvironment::new(id, descriptions)))
+        })?;
+        Ok(EnvironmentStack::from_environments(
+            environments,
+            private_environments.into(),
+        ))
+    }
+
+    /// Creates the environments of the snapshot, returning the sections that hold their
+    /// bindings.
+    fn environments(&mut self) -> DecodeResult<Vec<&'a [u8]>> {
+        let len = self.reader.len()?;
+        let mut bodies = Vec::with_capacity(len);
+        for _ in 0..len {
+            let poisoned = self.reader.bool()?;
+            let with = self.reader.bool()?;
+            let kind = match self.reader.u8()? {
+                0 => {
+                    let scope = self.reader.scope()?;
+                    DeclarativeEnvironmentKind::Lexical(LexicalEnvironment::new(
+                        self.reader.u32()?,
+                        scope,
+                    ))
+                }
+                1 => {
+                    let scope = self.reader.scope()?;
+                    let this = match self.reader.u8()? {
+                        0 => ThisBindingStatus::Lexical,
+                        1 => ThisBindingStatus::Uninitialized,
+                        2 => ThisBindingStatus::Initialized(self.value()?),
+                        _ => return Err(SnapshotError::Corrupted),
+                    };
+                    let function_object = self.object()?;
+                    let new_target = self.option(Self::object)?;
+                    let slots = FunctionSlots::new(this, function_object, new_target);
+                    DeclarativeEnvironmentKind::Function(FunctionEnvironment::new(
+                        self.reader.u32()?,
+                        slots,
+                        scope,
+                    ))
+                }
+                _ => return Err(SnapshotError::Corrupted),
+            };
+            self.environments
+                .push(Gc::new(DeclarativeEnvironment::new(kind, poisoned, with)));
+            bodies.push(self.reader.bytes()?);
+        }
+        Ok(bodies)
+    }
+
+    fn environment_bindings(&mut self, environment: &DeclarativeEnvironment) -> DecodeResult {
+        let values = self.list(|d| d.option(Self::value))?;
+        let mut bindings = match environment.kind() {
+            DeclarativeEnvironmentKind::Lexical(lexical) => lexical.bindings().borrow_mut(),
+            DeclarativeEnvironmentKind::Function(function) => function.bindings().borrow_mut(),
+            DeclarativeEnvironmentKind::Global(_) | DeclarativeEnvironmentKind::Module(_) => {
+                unreachable!("only lexical and function environments are restored")
+            }
+        };
+        if values.len() != bindings.len() {
+            return Err(SnapshotError::Corrupted);
+        }
+        *bindings = values;
+        Ok(())
+    }
+
+    /// Restores the global declarative bindings and the modified intrinsics.
+    fn roots(&mut self) -> DecodeResult {
+        let bindings = self.list(|d| {
+            let name = d.reader.string()?;
+            let flags = d.reader.u8()?;
+            let value = d.option(Self::value)?;
+            Ok((name, flags, value))
+        })?;
+        let global_scope = self.realm.scope().clone();
+        let mut values = Vec::with_capacity(bindings.len());
+        for (name, flags, value) in bindings {
+            let binding =
+                BindingData::from_parts(name, 0, flags).ok_or(SnapshotError::Corrupted)?;
+            values.push((global_scope.get_or_create_binding(&binding), value));
+        }
+        self.realm.resize_global_env();
+        let environment = self.realm.environment();
+        for (index, value) in values {
+            if let Some(value) = value {
+                environment.set(index, value);
+            }
+        }
+
+        let len = self.reader.len()?;
+        for _ in 0..len {
+            let index = self.reader.u32()?;
+            let object = self
+                .intrinsics
+                .get(index)
+                .cloned()
+                .ok_or(SnapshotError::Corrupted)?;
+            for (key, _) in own_properties(&object) {
+                object.borrow_mut().remove(&key);
+            }
+            self.object_state(&object)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,711 @@
+//! Serialization of the state of a context into a [`Snapshot`].
+
+use boa_gc::Gc;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    Context, JsObject, JsString, JsSymbol, JsValue,
+    builtins::{
+        Array, Date, OrdinaryObject,
+        array_buffer::ArrayBuffer,
+        error::{Error, ErrorKind},
+        function::{BoundFunction, ClassFieldDefinition, OrdinaryFunction},
+        map::ordered_map::OrderedMap,
+        regexp::RegExp,
+        set::ordered_set::OrderedSet,
+        symbol::registry_key,
+        typed_array::{TypedArray, TypedArrayKind},
+    },
+    environments::{
+        DeclarativeEnvironment, DeclarativeEnvironmentKind, Environment, EnvironmentStack,
+        ThisBindingStatus,
+    },
+    native_function::NativeFunctionObject,
+    object::{NativeObject, PrivateElement, PrivateName},
+    property::{PropertyDescriptor, PropertyKey},
+    realm::Realm,
+    script::Script,
+    value::JsVariant,
+    vm::{
+        ActiveRunnable,
+        bytecode_cache::{Writer, with_header},
+    },
+};
+
+use super::{MAGIC, Snapshot, SnapshotCreator, SnapshotError, intrinsics::own_properties};
+
+type EncodeResult<T = ()> = Result<T, SnapshotError>;
+
+/// Serializes the state of `context`, which must be a snapshot creator.
+pub(crate) fn create(context: &Context) -> Result<Snapshot, SnapshotError> {
+    let Some(creator) = context.snapshot_creator.as_deref() else {
+        return Err(SnapshotError::NotASnapshotCreator);
+    };
+    let realm = context.realm().clone();
+    if !realm.loaded_modules().borrow().is_empty() {
+        return Err(SnapshotError::Unsupported(
+            "contexts with loaded modules".into(),
+        ));
+    }
+
+    let mut encoder = Encoder {
+        writer: Writer::new(realm.scope()),
+        creator,
+        realm,
+        objects: Vec::new(),
+        object_indices: FxHashMap::default(),
+        environments: Vec::new(),
+        environment_indices: FxHashMap::default(),
+        symbols: Vec::new(),
+        symbol_indices: FxHashMap::default(),
+        scripts: Vec::new(),
+        private_ids: Vec::new(),
+        private_id_indices: FxHashMap::default(),
+    };
+
+    // Encoding the roots and the records discovers the objects and environments they reference,
+    // which are encoded until every reachable object is.
+    let roots = encoder.section(Encoder::roots)?;
+    let mut objects = Vec::new();
+    let mut environments = Vec::new();
+    while objects.len() < encoder.objects.len() || environments.len() < encoder.environments.len() {
+        if let Some(object) = encoder.objects.get(objects.len()).cloned() {
+            objects.push(encoder.section(|e| e.object_record(&object))?);
+        } else {
+            let environment = encoder.environments[environments.len()].clone();
+            environments.push(encoder.section(|e| e.environment_record(&environment))?);
+        }
+    }
+    let objects = encoder.records(&objects)?;
+    let environments = encoder.records(&environments)?;
+    let private_ids = encoder.section(Encoder::private_ids)?;
+    let scripts = encoder.section(Encoder::scripts)?;
+    let symbols = encoder.section(Encoder::symbols)?;
+    let declarations = encoder.section(|e| {
+        e.writer.u32(e.creator.intrinsics.len());
+        Ok(())
+    })?;
+
+    let body = [symbols, scripts, objects, private_ids, environments, roots].concat();
+    let payload = encoder.writer.finish(declarations, body);
+    Ok(Snapshot {
+        bytes: with_header(MAGIC, &payload),
+    })
+}
+
+/// Returns the tag used to encode an [`ErrorKind`].
+pub(super) const fn error_kind_tag(kind: ErrorKind) -> u8 {
+    match kind {
+        ErrorKind::Aggregate => 0,
+        ErrorKind::Error => 1,
+        ErrorKind::Eval => 2,
+        ErrorKind::Type => 3,
+        ErrorKind::Range => 4,
+        ErrorKind::Reference => 5,
+        ErrorKind::Syntax => 6,
+        ErrorKind::Uri => 7,
+        ErrorKind::Suppressed => 8,
+    }
+}
+
+/// Returns the tag used to encode a [`TypedArrayKind`].
+pub(super) const fn typed_array_kind_tag(kind: TypedArrayKind) -> u8 {
+    match kind {
+        TypedArrayKind::Int8 => 0,
+        TypedArrayKind::Uint8 => 1,
+        TypedArrayKind::Uint8Clamped => 2,
+        TypedArrayKind::Int16 => 3,
+        TypedArrayKind::Uint16 => 4,
+        TypedArrayKind::Int32 => 5,
+        TypedArrayKind::Uint32 => 6,
+        TypedArrayKind::BigInt64 => 7,
+        TypedArrayKind::BigUint64 => 8,
+        #[cfg(feature = "float16")]
+        TypedArrayKind::Float16 => 9,
+        TypedArrayKind::Float32 => 10,
+        TypedArrayKind::Float64 => 11,
+    }
+}
+
+/// Encodes the objects, environments and other values reachable from a context.
+///
+/// Objects are written as indices into a combined table, where the first indices refer to the
+/// [`IntrinsicTable`] and the rest to the objects stored in the snapshot.
+///
+/// [`IntrinsicTable`]: super::intrinsics::IntrinsicTable
+struct Encoder<'a> {
+    writer: Writer,
+    creator: &'a SnapshotCreator,
+    realm: Realm,
+    objects: Vec<JsObject>,
+    object_indices: FxHashMap<JsObject, u32>,
+    environments: Vec<Gc<DeclarativeEnvironment>>,
+    environment_indices: FxHashMap<*const DeclarativeEnvironment, u32>,
+    symbols: Vec<JsSymbol>,
+    symbol_indices: FxHashMap<u64, u32>,
+    scripts: Vec<Script>,
+    private_ids: Vec<usize>,
+    private_id_indices: FxHashMap<usize, u32>,
+}
+
+impl Encoder<'_> {
+    /// Encodes a section with `f`, returning its bytes.
+    fn section(&mut self, f: impl FnOnce(&mut Self) -> EncodeResult) -> EncodeResult<Vec<u8>> {
+        let outer = self.writer.swap_bytes(Vec::new());
+        let result = f(self);
+        let bytes = self.writer.swap_bytes(outer);
+        result.map(|()| bytes)
+    }
+
+    /// Encodes a list of already encoded records.
+    fn records(&mut self, records: &[Vec<u8>]) -> EncodeResult<Vec<u8>> {
+        self.section(|e| {
+            e.writer.u32_len(records.len());
+            for record in records {
+                e.writer.raw(record);
+            }
+            Ok(())
+        })
+    }
+
+    fn option<T>(
+        &mut self,
+        value: Option<T>,
+        f: impl FnOnce(&mut Self, T) -> EncodeResult,
+    ) -> EncodeResult {
+        self.writer.bool(value.is_some());
+        value.map_or(Ok(()), |value| f(self, value))
+    }
+
+    fn list<T>(
+        &mut self,
+        values: &[T],
+        mut f: impl FnMut(&mut Self, &T) -> EncodeResult,
+    ) -> EncodeResult {
+        self.writer.u32_len(values.len());
+        values.iter().try_for_each(|value| f(self, value))
+    }
+
+    /// Encodes the global declarative bindings and the modified intrinsics.
+    fn roots(&mut self) -> EncodeResult {
+        let bindings = self.realm.scope().data().bindings;
+        let values = self
+            .realm
+            .environment()
+            .kind()
+            .as_global()
+            .expect("realms only store global environments")
+            .bindings()
+            .borrow()
+            .clone();
+        self.list(&bindings, |e, binding| {
+            e.writer.string(binding.name());
+            e.writer.u8(binding.flags());
+            let value = values.get(binding.index() as usize).cloned().flatten();
+            e.option(value.as_ref(), Self::value)
+        })?;
+
+        let creator = self.creator;
+        let modified = creator
+            .pristine
+            .iter()
+            .zip(creator.intrinsics.objects())
+            .zip(0..)
+            .filter(|((state, object), _)| state.is_modified(object))
+            .map(|((_, object), index)| (index, object.clone()))
+            .collect::<Vec<_>>();
+        self.list(&modified, |e, (index, object)| {
+            e.writer.u32(*index);
+            e.object_state(object)
+        })
+    }
+
+    fn value(&mut self, value: &JsValue) -> EncodeResult {
+        match value.variant() {
+            JsVariant::Undefined => self.writer.u8(0),
+            JsVariant::Null => self.writer.u8(1),
+            JsVariant::Boolean(value) => {
+                self.writer.u8(2);
+                self.writer.bool(value);
+            }
+            JsVariant::Integer32(value) => {
+                self.writer.u8(3);
+                self.writer.u32(u32::from_le_bytes(value.to_le_bytes()));
+            }
+            JsVariant::Float64(value) => {
+                self.writer.u8(4);
+                self.writer.u64(value.to_bits());
+            }
+            JsVariant::String(string) => {
+                self.writer.u8(5);
+                self.writer.string(&string);
+            }
+            JsVariant::Symbol(symbol) => {
+                self.writer.u8(6);
+                self.symbol(&symbol);
+            }
+            JsVariant::BigInt(bigint) => {
+                self.writer.u8(7);
+                self.writer.str(&bigint.to_string_radix(16));
+            }
+            JsVariant::Object(object) => {
+                self.writer.u8(8);
+                return self.object(&object);
+            }
+        }
+        Ok(())
+    }
+
+    fn symbol(&mut self, symbol: &JsSymbol) {
+        let index = if let Some(&index) = self.symbol_indices.get(&symbol.hash()) {
+            index
+        } else {
+            let index = u32::try_from(self.symbols.len()).expect("too many symbols");
+            self.symbol_indices.insert(symbol.hash(), index);
+            self.symbols.push(symbol.clone());
+            index
+        };
+        self.writer.u32(index);
+    }
+
+    fn symbols(&mut self) -> EncodeResult {
+        let symbols = std::mem::take(&mut self.symbols);
+        self.list(&symbols, |e, symbol| {
+            if JsSymbol::well_known(symbol.hash()).is_some() {
+                e.writer.u8(0);
+                e.writer.u64(symbol.hash());
+            } else if let Some(key) = registry_key(symbol) {
+                e.writer.u8(1);
+                e.writer.string(&key);
+            } else {
+                e.writer.u8(2);
+                e.writer.option(symbol.description(), |w, description| {
+                    w.string(&description);
+                });
+            }
+            Ok(())
+        })
+    }
+
+    /// Encodes a reference to an object, adding it to the objects to encode if needed.
+    fn object(&mut self, object: &JsObject) -> EncodeResult {
+        let index = self.object_index(object)?;
+        self.writer.u32(index);
+        Ok(())
+    }
+
+    fn object_index(&mut self, object: &JsObject) -> EncodeResult<u32> {
+        if let Some(index) = self.creator.intrinsics.index_of(object) {
+            return Ok(index);
+        }
+        if let Some(&index) = self.object_indices.get(object) {
+            return Ok(index);
+        }
+        // Bound functions must be created after their target, since their internal methods
+        // depend on it, and typed arrays after their buffer.
+        let target = object
+            .downcast_ref::<BoundFunction>()
+            .map(|bound| bound.target_function().clone())
+            .or_else(|| {
+                object
+                    .downcast_ref::<TypedArray>()
+                    .map(|array| array.viewed_array_buffer().clone().into())
+            });
+        if let Some(target) = target {
+            self.object_index(&target)?;
+        }
+        let index = u32::try_from(self.objects.len())
+            .ok()
+            .and_then(|index| index.checked_add(self.creator.intrinsics.len()))
+            .ok_or_else(|| SnapshotError::Unsupported("more than `u32::MAX` objects".into()))?;
+        self.object_indices.insert(object.clone(), index);
+        self.objects.push(object.clone());
+        Ok(index)
+    }
+
+    /// Encodes the kind of an object and the data needed to create it, followed by its state.
+    fn object_record(&mut self, object: &JsObject) -> EncodeResult {
+        self.object_kind(object)?;
+        let body = self.section(|e| {
+            e.object_state(object)?;
+            e.object_internals(object)
+        })?;
+        self.writer.bytes(&body);
+        Ok(())
+    }
+
+    fn object_kind(&mut self, object: &JsObject) -> EncodeResult {
+        if object.is::<OrdinaryObject>() {
+            self.writer.u8(0);
+        } else if object.is::<Array>() {
+            self.writer.u8(1);
+        } else if let Some(function) = object.downcast_ref::<OrdinaryFunction>() {
+            if function.realm() != &self.realm {
+                return Err(SnapshotError::Unsupported(
+                    "functions of other realms".into(),
+                ));
+            }
+            self.writer.u8(2);
+            self.writer.code_block(&function.code);
+        } else if let Some(bound) = object.downcast_ref::<BoundFunction>() {
+            let target = bound.target_function().clone();
+            drop(bound);
+            self.writer.u8(3);
+            self.object(&target)?;
+        } else if let Some(native) = object.downcast_ref::<NativeFunctionObject>() {
+            let index = native
+                .f
+                .as_fn_ptr()
+                .and_then(|f| self.creator.external_references.index_of(f))
+                .ok_or_else(|| SnapshotError::UnknownNativeFunction(native.name.clone()))?;
+            if native.realm.as_ref() != Some(&self.realm) {
+                return Err(SnapshotError::Unsupported(
+                    "functions of other realms".into(),
+                ));
+            }
+            self.writer.u8(4);
+            self.writer.u32(index);
+            self.writer.string(&native.name);
+            self.writer
+                .option(native.constructor, |w, kind| w.bool(kind.is_base()));
+        } else if let Some(error) = object.downcast_ref::<Error>() {
+            self.writer.u8(5);
+            self.writer.u8(error_kind_tag(error.tag));
+        } else if let Some(&value) = object.downcast_ref::<bool>().as_deref() {
+            self.writer.u8(6);
+            self.writer.bool(value);
+        } else if let Some(&value) = object.downcast_ref::<f64>().as_deref() {
+            self.writer.u8(7);
+            self.writer.u64(value.to_bits());
+        } else if let Some(string) = object.downcast_ref::<JsString>() {
+            self.writer.u8(8);
+            self.writer.string(&string);
+        } else if let Some(symbol) = object.downcast_ref::<JsSymbol>() {
+            let symbol = symbol.clone();
+            self.writer.u8(9);
+            self.symbol(&symbol);
+        } else if let Some(bigint) = object.downcast_ref::<crate::JsBigInt>() {
+            self.writer.u8(10);
+            self.writer.str(&bigint.to_string_radix(16));
+        } else if object.is::<OrderedMap<JsValue>>() {
+            self.writer.u8(11);
+        } else if object.is::<OrderedSet>() {
+            self.writer.u8(12);
+        } else if let Some(&date) = object.downcast_ref::<Date>().as_deref() {
+            self.writer.u8(13);
+            self.writer.u64(date.time_value().to_bits());
+        } else if let Some(regexp) = object.downcast_ref::<RegExp>() {
+            self.writer.u8(14);
+            self.writer.string(regexp.original_source());
+            self.writer.string(regexp.original_flags());
+        } else if let Some(buffer) = object.downcast_ref::<ArrayBuffer>() {
+            self.writer.u8(15);
+            self.writer.option(buffer.data(), Writer::bytes);
+            self.writer.option(buffer.max_byte_length(), Writer::u64);
+        } else if let Some(array) = object.downcast_ref::<TypedArray>() {
+            let buffer = JsObject::from(array.viewed_array_buffer().clone());
+            let (byte_length, array_length) = array.length_slots();
+            self.writer.u8(16);
+            self.writer.u8(typed_array_kind_tag(array.kind()));
+            self.writer.u64(array.byte_offset());
+            self.writer.option(byte_length, Writer::u64);
+            self.writer.option(array_length, Writer::u64);
+            drop(array);
+            self.object(&buffer)?;
+        } else {
+            return Err(SnapshotError::Unsupported(format!(
+                "objects of type `{}`",
+                object.borrow().data().type_name_of_value()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Encodes the prototype, extensibility, properties and private elements of an object.
+    fn object_state(&mut self, object: &JsObject) -> EncodeResult {
+        let prototype = object.prototype();
+        self.option(prototype.as_ref(), Self::object)?;
+        let (extensible, private_elements) = {
+            let object = object.borrow();
+            (object.extensible, object.private_elements().to_vec())
+        };
+        self.writer.bool(extensible);
+        let properties = own_properties(object);
+        self.list(&properties, |e, (key, descriptor)| {
+            e.property_key(key);
+            e.descriptor(descriptor)
+        })?;
+        self.list(&private_elements, |e, (name, element)| {
+            e.private_name(name);
+            e.private_element(element)
+        })
+    }
+
+    /// Encodes the internal slots of the objects that have mutable internal state.
+    fn object_internals(&mut self, object: &JsObject) -> EncodeResult {
+        if let Some(function) = object.downcast_ref::<OrdinaryFunction>() {
+            let environments = function.environments.clone();
+            let home_object = function.get_home_object().cloned();
+            let script_or_module = function.script_or_module.clone();
+            let fields = function.get_fields().to_vec();
+            let private_methods = function.get_private_methods().to_vec();
+            drop(function);
+
+            self.environment_stack(&environments)?;
+            self.option(home_object.as_ref(), Self::object)?;
+            self.option(script_or_module.as_ref(), |e, runnable| match runnable {
+                ActiveRunnable::Script(script) => e.script(script),
+                ActiveRunnable::Module(_) => Err(SnapshotError::Unsupported(
+                    "functions defined in modules".into(),
+                )),
+            })?;
+            self.list(&fields, Self::class_field)?;
+            self.list(&private_methods, |e, (name, element)| {
+                e.private_name(name);
+                e.private_element(element)
+            })
+        } else if let Some(bound) = object.downcast_ref::<BoundFunction>() {
+            let this = bound.this().clone();
+            let args = bound.args().to_vec();
+            drop(bound);
+            self.value(&this)?;
+            self.list(&args, Self::value)
+        } else if let Some(map) = object.downcast_ref::<OrderedMap<JsValue>>() {
+            let entries = map
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect::<Vec<_>>();
+            drop(map);
+            self.list(&entries, |e, (key, value)| {
+                e.value(key)?;
+                e.value(value)
+            })
+        } else if let Some(set) = object.downcast_ref::<OrderedSet>() {
+            let values = set.iter().cloned().collect::<Vec<_>>();
+            drop(set);
+            self.list(&values, Self::value)
+        } else if let Some(buffer) = object.downcast_ref::<ArrayBuffer>() {
+            let detach_key = buffer.detach_key().clone();
+            drop(buffer);
+            self.value(&detach_key)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn property_key(&mut self, key: &PropertyKey) {
+        match key {
+            PropertyKey::String(string) => {
+                self.writer.u8(0);
+                self.writer.string(string);
+            }
+            PropertyKey::Symbol(symbol) => {
+                self.writer.u8(1);
+                self.symbol(symbol);
+            }
+            PropertyKey::Index(index) => {
+                self.writer.u8(2);
+                self.writer.u32(index.get());
+            }
+        }
+    }
+
+    fn descriptor(&mut self, descriptor: &PropertyDescriptor) -> EncodeResult {
+        let undefined = JsValue::undefined();
+        if descriptor.is_accessor_descriptor() {
+            self.writer.u8(1);
+            self.value(descriptor.get().unwrap_or(&undefined))?;
+            self.value(descriptor.set().unwrap_or(&undefined))?;
+        } else {
+            self.writer.u8(0);
+            self.value(descriptor.value().unwrap_or(&undefined))?;
+            self.writer.bool(descriptor.writable().unwrap_or_default());
+        }
+        self.writer
+            .bool(descriptor.enumerable().unwrap_or_default());
+        self.writer
+            .bool(descriptor.configurable().unwrap_or_default());
+        Ok(())
+    }
+
+    fn private_name(&mut self, name: &PrivateName) {
+        self.writer.string(name.description());
+        self.private_id(name.id());
+    }
+
+    /// Encodes the unique identifier of a private name as an index into the private id table.
+    fn private_id(&mut self, id: usize) {
+        let index = if let Some(&index) = self.private_id_indices.get(&id) {
+            index
+        } else {
+            let index = u32::try_from(self.private_ids.len()).expect("too many private names");
+            self.private_id_indices.insert(id, index);
+            self.private_ids.push(id);
+            index
+        };
+        self.writer.u32(index);
+    }
+
+    fn private_ids(&mut self) -> EncodeResult {
+        // Private names are identified by the address of the class that created them, so they
+        // are encoded as a reference to the class. The classes that are no longer reachable
+        // get a new identifier when restored.
+        let owners = self
+            .creator
+            .intrinsics
+            .objects()
+            .iter()
+            .chain(&self.objects)
+            .map(|object| (object.private_name_id(), object.clone()))
+            .collect::<FxHashMap<_, _>>();
+        let ids = std::mem::take(&mut self.private_ids);
+        self.list(&ids, |e, id| e.option(owners.get(id), Self::object))
+    }
+
+    fn private_element(&mut self, element: &PrivateElement) -> EncodeResult {
+        match element {
+            PrivateElement::Field(value) => {
+                self.writer.u8(0);
+                self.value(value)
+            }
+            PrivateElement::Method(method) => {
+                self.writer.u8(1);
+                self.object(method)
+            }
+            PrivateElement::Accessor { getter, setter } => {
+                self.writer.u8(2);
+                self.option(getter.as_ref(), Self::object)?;
+                self.option(setter.as_ref(), Self::object)
+            }
+        }
+    }
+
+    fn class_field(&mut self, field: &ClassFieldDefinition) -> EncodeResult {
+        match field {
+            ClassFieldDefinition::Public(key, function, name) => {
+                self.writer.u8(0);
+                self.property_key(key);
+                self.object(function)?;
+                self.option(name.as_ref(), |e, name| {
+                    e.property_key(name);
+                    Ok(())
+                })
+            }
+            ClassFieldDefinition::Private(name, function) => {
+                self.writer.u8(1);
+                self.private_name(name);
+                self.object(function)
+            }
+            ClassFieldDefinition::Decorated {
+                field,
+                initializers,
+                extra_initializers,
+            } => {
+                self.writer.u8(2);
+                self.class_field(field)?;
+                self.list(initializers, |e, function| e.object(function))?;
+                self.list(extra_initializers, |e, function| e.object(function))
+            }
+            ClassFieldDefinition::Initializers(initializers) => {
+                self.writer.u8(3);
+                self.list(initializers, |e, function| e.object(function))
+            }
+        }
+    }
+
+    fn script(&mut self, script: &Script) -> EncodeResult {
+        if script.realm() != &self.realm {
+            return Err(SnapshotError::Unsupported("scripts of other realms".into()));
+        }
+        let index = if let Some(index) = self.scripts.iter().position(|s| s.ptr_eq(script)) {
+            index
+        } else {
+            self.scripts.push(script.clone());
+            self.scripts.len() - 1
+        };
+        self.writer.u32_len(index);
+        Ok(())
+    }
+
+    fn scripts(&mut self) -> EncodeResult {
+        let scripts = std::mem::take(&mut self.scripts);
+        self.list(&scripts, |e, script| {
+            let codeblock = script
+                .compiled_codeblock()
+                .expect("scripts that defined functions must be compiled");
+            e.writer.code_block(&codeblock);
+            e.writer.source(&script.get_source());
+            e.writer.option(script.path(), Writer::path);
+            Ok(())
+        })
+    }
+
+    fn environment_stack(&mut self, stack: &EnvironmentStack) -> EncodeResult {
+        self.list(&stack.environments(), |e, environment| match environment {
+            Environment::Declarative(environment) => {
+                e.writer.u8(0);
+                let index = e.environment_index(environment);
+                e.writer.u32(index);
+                Ok(())
+            }
+            Environment::Object(object) => {
+                e.writer.u8(1);
+                e.object(object)
+            }
+        })?;
+        self.list(stack.private_environments(), |e, environment| {
+            e.private_id(environment.id());
+            e.writer.list(environment.descriptions(), Writer::string);
+            Ok(())
+        })
+    }
+
+    fn environment_index(&mut self, environment: &Gc<DeclarativeEnvironment>) -> u32 {
+        let key = std::ptr::from_ref::<DeclarativeEnvironment>(environment);
+        if let Some(&index) = self.environment_indices.get(&key) {
+            return index;
+        }
+        let index = u32::try_from(self.environments.len()).expect("too many environments");
+        self.environment_indices.insert(key, index);
+        self.environments.push(environment.clone());
+        index
+    }
+
+    /// Encodes the kind of an environment and the data needed to create it, followed by the
+    /// values of its bindings.
+    fn environment_record(&mut self, environment: &Gc<DeclarativeEnvironment>) -> EncodeResult {
+        self.writer.bool(environment.poisoned());
+        self.writer.bool(environment.with());
+        let bindings = match environment.kind() {
+            DeclarativeEnvironmentKind::Lexical(lexical) => {
+                self.writer.u8(0);
+                self.writer.scope(lexical.compile());
+                lexical.bindings().borrow().clone()
+            }
+            DeclarativeEnvironmentKind::Function(function) => {
+                self.writer.u8(1);
+                self.writer.scope(function.compile());
+                let slots = function.slots();
+                match slots.this() {
+                    ThisBindingStatus::Lexical => self.writer.u8(0),
+                    ThisBindingStatus::Uninitialized => self.writer.u8(1),
+                    ThisBindingStatus::Initialized(this) => {
+                        self.writer.u8(2);
+                        self.value(&this)?;
+                    }
+                }
+                self.object(slots.function_object())?;
+                self.option(slots.new_target(), Self::object)?;
+                function.bindings().borrow().clone()
+            }
+            DeclarativeEnvironmentKind::Global(_) | DeclarativeEnvironmentKind::Module(_) => {
+                return Err(SnapshotError::Unsupported("module environments".into()));
+            }
+        };
+        self.writer.u32_len(bindings.len());
+        let body =
+            self.section(|e| e.list(&bindings, |e, value| e.option(value.as_ref(), Self::value)))?;
+        self.writer.bytes(&body);
+        Ok(())
+    }
+}
@@ -0,0 +1,154 @@
+//! The table of objects that every newly created context starts with.
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    JsObject, JsValue,
+    property::{PropertyDescriptor, PropertyKey},
+    realm::Realm,
+};
+
+/// The objects created by the initialization of a realm, in a deterministic order.
+///
+/// The table contains the global object, every intrinsic and every object reachable from them
+/// through properties and prototypes. Since building two contexts always creates the same
+/// objects in the same order, a snapshot can refer to these objects by their index in the table
+/// instead of storing them.
+pub(crate) struct IntrinsicTable {
+    objects: Vec<JsObject>,
+    indices: FxHashMap<JsObject, u32>,
+}
+
+impl IntrinsicTable {
+    /// Builds the table of the objects of a newly initialized `realm`.
+    pub(crate) fn new(realm: &Realm) -> Self {
+        let mut table = Self {
+            objects: Vec::new(),
+            indices: FxHashMap::default(),
+        };
+        table.visit(realm.global_object());
+        table.visit(realm.global_this());
+        for object in realm.intrinsics().all_objects() {
+            table.visit(&object);
+        }
+
+        let mut next = 0;
+        while let Some(object) = table.objects.get(next).cloned() {
+            next += 1;
+            if let Some(prototype) = object.prototype() {
+                table.visit(&prototype);
+            }
+            for (_, descriptor) in own_properties(&object) {
+                for value in [descriptor.value(), descriptor.get(), descriptor.set()]
+                    .into_iter()
+                    .flatten()
+                {
+                    if let Some(object) = value.as_object() {
+                        table.visit(&object);
+                    }
+                }
+            }
+        }
+        table
+    }
+
+    fn visit(&mut self, object: &JsObject) {
+        if !self.indices.contains_key(object) {
+            let index = u32::try_from(self.objects.len()).expect("too many intrinsics");
+            self.indices.insert(object.clone(), index);
+            self.objects.push(object.clone());
+        }
+    }
+
+    /// Returns the number of objects in the table.
+    pub(crate) fn len(&self) -> u32 {
+        u32::try_from(self.objects.len()).expect("too many intrinsics")
+    }
+
+    /// Returns the object at `index`.
+    pub(crate) fn get(&self, index: u32) -> Option<&JsObject> {
+        self.objects.get(index as usize)
+    }
+
+    /// Returns the index of `object`, if it is in the table.
+    pub(crate) fn index_of(&self, object: &JsObject) -> Option<u32> {
+        self.indices.get(object).copied()
+    }
+
+    /// Returns the objects of the table.
+    pub(crate) fn objects(&self) -> &[JsObject] {
+        &self.objects
+    }
+}
+
+/// The state of an object of the [`IntrinsicTable`] right after the initialization of its realm.
+pub(crate) struct PristineState {
+    prototype: Option<JsObject>,
+    extensible: bool,
+    properties: Vec<(PropertyKey, PropertyDescriptor)>,
+}
+
+impl PristineState {
+    /// Records the current state of `object`.
+    pub(crate) fn new(object: &JsObject) -> Self {
+        Self {
+            prototype: object.prototype(),
+            extensible: object.borrow().extensible,
+            properties: own_properties(object),
+        }
+    }
+
+    /// Returns `true` if the prototype, extensibility, properties or private elements of `object`
+    /// changed since this state was recorded.
+    pub(crate) fn is_modified(&self, object: &JsObject) -> bool {
+        if self.prototype != object.prototype()
+            || self.extensible != object.borrow().extensible
+            || !object.borrow().private_elements().is_empty()
+        {
+            return true;
+        }
+        let properties = own_properties(object);
+        properties.len() != self.properties.len()
+            || properties.iter().zip(&self.properties).any(
+                |((key, descriptor), (old_key, old_descriptor))| {
+                    key != old_key || !same_descriptor(descriptor, old_descriptor)
+                },
+            )
+    }
+}
+
+/// Returns `true` if both descriptors have the same fields, comparing values with `SameValue`.
+fn same_descriptor(a: &PropertyDescriptor, b: &PropertyDescriptor) -> bool {
+    let same_value = |a: Option<&JsValue>, b: Option<&JsValue>| match (a, b) {
+        (Some(a), Some(b)) => JsValue::same_value(a, b),
+        (None, None) => true,
+        _ => false,
+    };
+    a.enumerable() == b.enumerable()
+        && a.configurable() == b.configurable()
+        && a.writable() == b.writable()
+        && same_value(a.value(), b.value())
+        && same_value(a.get(), b.get())
+        && same_value(a.set(), b.set())
+}
+
+/// Returns the own properties stored in `object`, with the indexed properties sorted first and
+/// the other properties in creation order.
+///
+/// This reads the property storage directly, so it never runs user code and skips the
+/// properties that exotic objects compute on the fly.
+pub(crate) fn own_properties(object: &JsObject) -> Vec<(PropertyKey, PropertyDescriptor)> {
+    let object = object.borrow();
+    let properties = object.properties();
+    let mut indices = properties.index_property_keys().collect::<Vec<_>>();
+    indices.sort_unstable();
+    indices
+        .into_iter()
+        .map(PropertyKey::from)
+        .chain(properties.shape.keys())
+        .filter_map(|key| {
+            let descriptor = properties.get(&key)?;
+            Some((key, descriptor))
+        })
+        .collect()
+}
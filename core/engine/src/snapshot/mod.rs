@@ -0,0 +1,325 @@
+//! Snapshots of the state of a [`Context`], used to create new contexts quickly.
+//!
+//! A context that runs a lot of setup code (polyfills, bundled libraries, configuration) before
+//! it is ready can be snapshotted once that setup is done. Creating new contexts from the
+//! [`Snapshot`] then skips the setup code entirely: the objects, functions and global bindings
+//! it created are restored directly.
+//!
+//! Snapshots work as a difference against a newly built context. The builtin objects are not
+//! stored, only the changes made to them and the objects created afterwards, so contexts
+//! restored from a snapshot must be built with the same [`HostHooks`] as the context that
+//! created it.
+//!
+//! # Native functions
+//!
+//! Native functions cannot be serialized, so the native functions created by the host must be
+//! registered in an [`ExternalReferences`] table that is passed both when creating the
+//! snapshot and when restoring it. Only functions created from function pointers can be
+//! registered; snapshotting a context that can reach a native closure fails with
+//! [`SnapshotError::UnknownNativeFunction`].
+//!
+//! # Limitations
+//!
+//! Only ordinary objects, arrays, functions (including classes, closures and bound functions),
+//! errors, primitive wrappers, dates, regular expressions, maps, sets, `ArrayBuffer`s and the
+//! typed arrays viewing them can be stored. Reaching any other object, including a
+//! `SharedArrayBuffer`, fails with [`SnapshotError::Unsupported`]. Pending jobs, loaded
+//! modules, cached template objects, the host defined data and the objects kept alive for
+//! `WeakRef`s are not stored either, and source positions of the restored `Error` objects are
+//! lost.
+//!
+//! # Examples
+//!
+//! ```
+//! use boa_engine::{
+//!     Context, JsResult, JsValue, NativeFunction, Source, js_string,
+//!     property::Attribute,
+//!     snapshot::{ExternalReferences, Snapshot},
+//! };
+//!
+//! fn host_add(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+//!     let a = args.first().cloned().unwrap_or_default().to_number(context)?;
+//!     let b = args.get(1).cloned().unwrap_or_default().to_number(context)?;
+//!     Ok(JsValue::new(a + b))
+//! }
+//!
+//! let references = ExternalReferences::new().function(host_add);
+//!
+//! let mut context = Context::builder()
+//!     .snapshot_creator(references.clone())
+//!     .build()
+//!     .unwrap();
+//! context
+//!     .register_global_builtin_callable(
+//!         js_string!("hostAdd"),
+//!         2,
+//!         NativeFunction::from_fn_ptr(host_add),
+//!     )
+//!     .unwrap();
+//! context
+//!     .eval(Source::from_bytes("const sum = (...n) => n.reduce(hostAdd, 0);"))
+//!     .unwrap();
+//! let bytes = context.create_snapshot().unwrap().into_bytes();
+//!
+//! // SAFETY: The bytes were created by `Context::create_snapshot` just above.
+//! let snapshot = unsafe { Snapshot::from_bytes(bytes) }.unwrap();
+//! let mut context = Context::builder()
+//!     .snapshot(snapshot, references)
+//!     .build()
+//!     .unwrap();
+//! let result = context.eval(Source::from_bytes("sum(1, 2, 3)")).unwrap();
+//! assert_eq!(result, JsValue::new(6));
+//! ```
+//!
+//! [`Context`]: crate::Context
+//! [`HostHooks`]: crate::context::HostHooks
+
+use std::{error::Error, fmt};
+
+use crate::{
+    Context, JsError, JsNativeError, JsString,
+    native_function::NativeFunctionPointer,
+    vm::{
+        BytecodeCacheError,
+        bytecode_cache::{HeaderError, check_header},
+    },
+};
+
+use self::intrinsics::{IntrinsicTable, PristineState};
+
+mod decode;
+mod encode;
+mod intrinsics;
+
+#[cfg(test)]
+mod tests;
+
+/// The bytes every snapshot starts with.
+const MAGIC: [u8; 8] = *b"BOASNAP\0";
+
+/// The native functions that can be stored in a [`Snapshot`].
+///
+/// Snapshots store native functions by their index in this table, so the table used to
+/// restore a snapshot must contain the same functions in the same order as the table used to
+/// create it.
+#[derive(Debug, Clone, Default)]
+pub struct ExternalReferences {
+    functions: Vec<NativeFunctionPointer>,
+}
+
+impl ExternalReferences {
+    /// Creates an empty table of external references.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a native function in the table.
+    #[must_use]
+    pub fn function(mut self, function: NativeFunctionPointer) -> Self {
+        self.functions.push(function);
+        self
+    }
+
+    /// Returns the number of registered functions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.functions.len()
+    }
+
+    /// Returns `true` if no function is registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+
+    fn index_of(&self, function: NativeFunctionPointer) -> Option<u32> {
+        let index = self
+            .functions
+            .iter()
+            .position(|&registered| std::ptr::fn_addr_eq(registered, function))?;
+        u32::try_from(index).ok()
+    }
+
+    fn get(&self, index: u32) -> Option<NativeFunctionPointer> {
+        self.functions.get(index as usize).copied()
+    }
+}
+
+/// A serialized [`Context`], created by [`Context::create_snapshot`].
+///
+/// [`Context`]: crate::Context
+/// [`Context::create_snapshot`]: crate::Context::create_snapshot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    bytes: Vec<u8>,
+}
+
+impl Snapshot {
+    /// Loads a snapshot from its bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` is not a snapshot or if it was created by an incompatible
+    /// build of the engine.
+    ///
+    /// # Safety
+    ///
+    /// The bytecode of the functions in the snapshot is run without being verified, so `bytes`
+    /// must not have been crafted or tampered with. Snapshots created by
+    /// [`Context::create_snapshot`] are safe to load, and so are truncated or accidentally
+    /// corrupted ones, since the checksum rejects them. A crafted snapshot can make the VM
+    /// access registers out of bounds.
+    ///
+    /// [`Context::create_snapshot`]: crate::Context::create_snapshot
+    pub unsafe fn from_bytes(bytes: impl Into<Vec<u8>>) -> Result<Self, SnapshotError> {
+        let bytes = bytes.into();
+        check_header(&bytes, MAGIC)?;
+        Ok(Self { bytes })
+    }
+
+    /// Returns the bytes of the snapshot.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Converts the snapshot into its bytes.
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// The error returned when a snapshot cannot be created or restored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SnapshotError {
+    /// The bytes are not a snapshot.
+    NotASnapshot,
+
+    /// The snapshot was created with an unsupported version of the format.
+    UnsupportedVersion(u32),
+
+    /// The snapshot was created by an incompatible build of the engine, or by a context with
+    /// different builtins.
+    EngineMismatch,
+
+    /// The snapshot is truncated or corrupted.
+    Corrupted,
+
+    /// The context was not built with [`ContextBuilder::snapshot_creator`].
+    ///
+    /// [`ContextBuilder::snapshot_creator`]: crate::context::ContextBuilder::snapshot_creator
+    NotASnapshotCreator,
+
+    /// The context contains a value that cannot be stored in a snapshot.
+    Unsupported(String),
+
+    /// The context contains a native function that is not registered in the
+    /// [`ExternalReferences`]. Contains the name of the function.
+    UnknownNativeFunction(JsString),
+
+    /// The snapshot refers to an external reference that is not registered in the
+    /// [`ExternalReferences`] used to restore it.
+    MissingExternalReference(u32),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotASnapshot => f.write_str("the bytes are not a snapshot"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot format version {version}")
+            }
+            Self::EngineMismatch => {
+                f.write_str("the snapshot was created by an incompatible engine build")
+            }
+            Self::Corrupted => f.write_str("the snapshot is corrupted"),
+            Self::NotASnapshotCreator => {
+                f.write_str("the context was not built as a snapshot creator")
+            }
+            Self::Unsupported(what) => write!(f, "cannot snapshot {what}"),
+            Self::UnknownNativeFunction(name) => write!(
+                f,
+                "the native function `{}` is not registered as an external reference",
+                name.to_std_string_escaped()
+            ),
+            Self::MissingExternalReference(index) => {
+                write!(f, "missing external reference {index}")
+            }
+        }
+    }
+}
+
+impl Error for SnapshotError {}
+
+impl From<HeaderError> for SnapshotError {
+    fn from(err: HeaderError) -> Self {
+        match err {
+            HeaderError::Magic => Self::NotASnapshot,
+            HeaderError::Version(version) => Self::UnsupportedVersion(version),
+            HeaderError::Fingerprint => Self::EngineMismatch,
+            HeaderError::Checksum => Self::Corrupted,
+        }
+    }
+}
+
+impl From<BytecodeCacheError> for SnapshotError {
+    fn from(err: BytecodeCacheError) -> Self {
+        match err {
+            BytecodeCacheError::NotACache => Self::NotASnapshot,
+            BytecodeCacheError::UnsupportedVersion(version) => Self::UnsupportedVersion(version),
+            BytecodeCacheError::EngineMismatch => Self::EngineMismatch,
+            BytecodeCacheError::Corrupted => Self::Corrupted,
+        }
+    }
+}
+
+impl From<SnapshotError> for JsNativeError {
+    fn from(err: SnapshotError) -> Self {
+        Self::error().with_message(err.to_string())
+    }
+}
+
+impl From<SnapshotError> for JsError {
+    fn from(err: SnapshotError) -> Self {
+        JsNativeError::from(err).into()
+    }
+}
+
+/// The state kept by contexts built with [`ContextBuilder::snapshot_creator`].
+///
+/// [`ContextBuilder::snapshot_creator`]: crate::context::ContextBuilder::snapshot_creator
+pub(crate) struct SnapshotCreator {
+    external_references: ExternalReferences,
+    intrinsics: IntrinsicTable,
+    pristine: Vec<PristineState>,
+}
+
+impl SnapshotCreator {
+    /// Records the state of the builtins of a newly built `context`.
+    pub(crate) fn new(context: &Context, external_references: ExternalReferences) -> Self {
+        let intrinsics = IntrinsicTable::new(context.realm());
+        let pristine = intrinsics
+            .objects()
+            .iter()
+            .map(PristineState::new)
+            .collect();
+        Self {
+            external_references,
+            intrinsics,
+            pristine,
+        }
+    }
+}
+
+impl fmt::Debug for SnapshotCreator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SnapshotCreator")
+            .field("external_references", &self.external_references)
+            .finish_non_exhaustive()
+    }
+}
+
+pub(crate) use self::{decode::restore, encode::create};
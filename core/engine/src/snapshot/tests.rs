@@ -0,0 +1,308 @@
+use boa_parser::Source;
+
+use super::{ExternalReferences, MAGIC, Snapshot, SnapshotError};
+use crate::{
+    Context, JsNativeError, JsNativeErrorKind, JsResult, JsValue, NativeFunction, js_string,
+    object::builtins::JsArrayBuffer,
+};
+
+const SETUP: &str = r#"
+    let counter = 0;
+    const prefix = "count: ";
+    var config = { name: "boa", tags: ["js", "rust"], nested: { depth: 2 } };
+    function increment(step = 1) {
+        counter += step;
+        return prefix + counter;
+    }
+
+    function makeAccumulator() {
+        let total = 0n;
+        return (value) => (total += value);
+    }
+    const accumulate = makeAccumulator();
+    accumulate(40n);
+
+    class Point {
+        #x;
+        static #count = 0;
+        constructor(x, y) { this.#x = x; this.y = y; Point.#count++; }
+        get x() { return this.#x; }
+        #secret() { return this.#x * 2; }
+        doubled() { return this.#secret(); }
+        static count() { return Point.#count; }
+        static hasX(o) { return #x in o; }
+    }
+    class Point3D extends Point {
+        z = 7;
+        constructor(x, y) { super(x, y); }
+    }
+    const origin = new Point3D(1, 2);
+
+    const map = new Map([[1, "one"], [origin, "origin"]]);
+    const set = new Set(["a", "b"]);
+    set.delete("a");
+    const date = new Date(1234567890);
+    const error = new RangeError("out of range");
+    const boxed = [new Number(4), new String("str"), Object(Symbol.iterator), Object(5n)];
+    const unique = Symbol("unique");
+    const registered = Symbol.for("registered");
+    config[unique] = "symbol property";
+
+    const add = (a, b) => a + b;
+    const addTen = add.bind(null, 10);
+
+    Array.prototype.last = function () { return this[this.length - 1]; };
+    delete Array.prototype.at;
+    Object.freeze(config.nested);
+"#;
+
+fn creator() -> Context {
+    Context::builder()
+        .snapshot_creator(ExternalReferences::new())
+        .build()
+        .unwrap()
+}
+
+fn restore(snapshot: Snapshot, references: ExternalReferences) -> Context {
+    Context::builder()
+        .snapshot(snapshot, references)
+        .build()
+        .unwrap()
+}
+
+#[track_caller]
+fn assert_eval(context: &mut Context, source: &str, expected: impl Into<JsValue>) {
+    let result = context.eval(Source::from_bytes(source)).unwrap();
+    assert_eq!(result, expected.into(), "{source}");
+}
+
+#[test]
+fn roundtrip() {
+    let context = &mut creator();
+    context.eval(Source::from_bytes(SETUP)).unwrap();
+    let snapshot = context.create_snapshot().unwrap();
+    // SAFETY: The bytes were created by `Context::create_snapshot`.
+    let snapshot = unsafe { Snapshot::from_bytes(snapshot.into_bytes()) }.unwrap();
+    let context = &mut restore(snapshot, ExternalReferences::new());
+
+    assert_eval(context, "increment(2)", js_string!("count: 2"));
+    assert_eval(context, "counter", 2);
+    assert_eval(context, "config.tags.join()", js_string!("js,rust"));
+    assert_eval(context, "Object.isFrozen(config.nested)", true);
+    assert_eval(context, "globalThis.config === config", true);
+    assert_eval(context, "accumulate(2n)", crate::JsBigInt::from(42));
+    assert_eval(context, "origin.x + origin.y + origin.z", 10);
+    assert_eval(context, "origin.doubled()", 2);
+    assert_eval(context, "new Point3D(5, 0).doubled()", 10);
+    assert_eval(context, "Point.count()", 2);
+    assert_eval(context, "Point.hasX(origin) && !Point.hasX({})", true);
+    assert_eval(context, "origin instanceof Point", true);
+    assert_eval(
+        context,
+        "map.get(origin) + map.get(1)",
+        js_string!("originone"),
+    );
+    assert_eval(context, "[...set].join()", js_string!("b"));
+    assert_eval(context, "date.getTime()", 1_234_567_890);
+    assert_eval(context, "error instanceof RangeError", true);
+    assert_eval(context, "error.message", js_string!("out of range"));
+    assert_eval(
+        context,
+        "boxed[0] + boxed[1] + (boxed[2].valueOf() === Symbol.iterator) + boxed[3]",
+        js_string!("4strtrue5"),
+    );
+    assert_eval(context, "config[unique]", js_string!("symbol property"));
+    assert_eval(context, "Symbol.for('registered') === registered", true);
+    assert_eval(context, "addTen(5)", 15);
+    assert_eval(context, "[1, 2, 3].last()", 3);
+    assert_eval(context, "Array.prototype.at", JsValue::undefined());
+    assert_eval(
+        context,
+        "typeof Array.prototype.map",
+        js_string!("function"),
+    );
+}
+
+#[test]
+fn regexps_and_buffers() {
+    let context = &mut creator();
+    context
+        .eval(Source::from_bytes(
+            r#"
+            const pattern = /b(\d+)/gu;
+            pattern.test("ab12");
+            const buffer = new ArrayBuffer(8, { maxByteLength: 16 });
+            const bytes = new Uint8Array(buffer);
+            const words = new Uint16Array(buffer, 2, 2);
+            bytes.set([1, 2, 3, 4, 5, 6]);
+            const detached = new ArrayBuffer(4);
+            "#,
+        ))
+        .unwrap();
+    let detached = context
+        .eval(Source::from_bytes("detached"))
+        .unwrap()
+        .as_object()
+        .unwrap();
+    JsArrayBuffer::from_object(detached)
+        .unwrap()
+        .detach(&JsValue::undefined())
+        .unwrap();
+    let snapshot = context.create_snapshot().unwrap();
+    let context = &mut restore(snapshot, ExternalReferences::new());
+
+    assert_eval(
+        context,
+        "pattern.source + pattern.flags",
+        js_string!("b(\\d+)gu"),
+    );
+    assert_eval(context, "pattern.lastIndex", 4);
+    assert_eval(context, "pattern.exec('b7  b89')[1]", js_string!("89"));
+    assert_eval(context, "bytes.join()", js_string!("1,2,3,4,5,6,0,0"));
+    assert_eval(context, "words.length", 2);
+    assert_eval(context, "words.buffer === bytes.buffer", true);
+    assert_eval(context, "buffer.resize(12); bytes.length", 12);
+    assert_eval(context, "words[0] = 0xFFFF; bytes[2] + bytes[3]", 510);
+    assert_eval(context, "detached.byteLength", 0);
+    let detached = context
+        .eval(Source::from_bytes("detached"))
+        .unwrap()
+        .as_object()
+        .unwrap();
+    assert!(
+        JsArrayBuffer::from_object(detached)
+            .unwrap()
+            .data()
+            .is_none()
+    );
+}
+
+#[test]
+fn uninitialized_bindings() {
+    let context = &mut creator();
+    context
+        .eval(Source::from_bytes("function read() { return value; }"))
+        .unwrap();
+    context
+        .eval(Source::from_bytes("let value = (() => { throw 1; })();"))
+        .unwrap_err();
+    let snapshot = context.create_snapshot().unwrap();
+
+    let context = &mut restore(snapshot, ExternalReferences::new());
+    let err = context.eval(Source::from_bytes("read()")).unwrap_err();
+    assert_eq!(
+        err.as_native().map(JsNativeError::kind),
+        Some(&JsNativeErrorKind::Reference)
+    );
+}
+
+fn host_double(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let value = args
+        .first()
+        .cloned()
+        .unwrap_or_default()
+        .to_number(context)?;
+    Ok(JsValue::new(value * 2.0))
+}
+
+#[test]
+fn external_references() {
+    let references = ExternalReferences::new().function(host_double);
+    let context = &mut Context::builder()
+        .snapshot_creator(references.clone())
+        .build()
+        .unwrap();
+    context
+        .register_global_builtin_callable(
+            js_string!("double"),
+            1,
+            NativeFunction::from_fn_ptr(host_double),
+        )
+        .unwrap();
+    context
+        .eval(Source::from_bytes(
+            "const quadruple = (x) => double(double(x));",
+        ))
+        .unwrap();
+    let snapshot = context.create_snapshot().unwrap();
+
+    let restored = &mut restore(snapshot.clone(), references);
+    assert_eval(restored, "quadruple(3)", 12);
+    assert_eval(restored, "double.name", js_string!("double"));
+
+    let err = Context::builder()
+        .snapshot(snapshot, ExternalReferences::new())
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err.as_native().unwrap().message(),
+        SnapshotError::MissingExternalReference(0).to_string()
+    );
+}
+
+#[test]
+fn unknown_native_function() {
+    let context = &mut creator();
+    context
+        .register_global_builtin_callable(
+            js_string!("closure"),
+            0,
+            NativeFunction::from_copy_closure(|_, _, _| Ok(JsValue::undefined())),
+        )
+        .unwrap();
+    assert_eq!(
+        context.create_snapshot(),
+        Err(SnapshotError::UnknownNativeFunction(js_string!("closure")))
+    );
+}
+
+#[test]
+fn unsupported_objects() {
+    for source in [
+        "var p = new Proxy({}, {});",
+        "var p = Promise.resolve();",
+        "var b = new SharedArrayBuffer(8);",
+        "var a = new Int8Array(new SharedArrayBuffer(8));",
+    ] {
+        let context = &mut creator();
+        context.eval(Source::from_bytes(source)).unwrap();
+        assert!(
+            matches!(
+                context.create_snapshot(),
+                Err(SnapshotError::Unsupported(_))
+            ),
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn not_a_snapshot_creator() {
+    let context = &mut Context::default();
+    assert_eq!(
+        context.create_snapshot(),
+        Err(SnapshotError::NotASnapshotCreator)
+    );
+}
+
+#[test]
+fn invalid_bytes() {
+    // SAFETY: Invalid bytes are rejected before any bytecode is loaded.
+    assert_eq!(
+        unsafe { Snapshot::from_bytes(b"not a snapshot".to_vec()) },
+        Err(SnapshotError::NotASnapshot)
+    );
+
+    let context = &mut creator();
+    context.eval(Source::from_bytes(SETUP)).unwrap();
+    let mut bytes = context.create_snapshot().unwrap().into_bytes();
+    assert_eq!(&bytes[..MAGIC.len()], &MAGIC);
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xFF;
+    // SAFETY: The snapshot was created by `Context::create_snapshot` and then corrupted, which
+    // the checksum detects.
+    assert_eq!(
+        unsafe { Snapshot::from_bytes(bytes) },
+        Err(SnapshotError::Corrupted)
+    );
+}
@@ -3,12 +3,18 @@ use std::rc::Rc;
 use boa_ast::LinearSpan;
 use boa_gc::{Finalize, Trace};
 
+use crate::vm::source_info::SourceMapV3;
+
 struct Inner {
     source_text: boa_ast::SourceText,
+    source_map: Option<SourceMapV3>,
 }
 impl Inner {
-    fn new(source_text: boa_ast::SourceText) -> Self {
-        Self { source_text }
+    fn new(source_text: boa_ast::SourceText, source_map: Option<SourceMapV3>) -> Self {
+        Self {
+            source_text,
+            source_map,
+        }
     }
 }
 
@@ -22,7 +28,19 @@ impl SourceText {
     #[must_use]
     pub(crate) fn new(source_text: boa_ast::SourceText) -> Self {
         Self {
-            source_text: Some(Rc::new(Inner::new(source_text))),
+            source_text: Some(Rc::new(Inner::new(source_text, None))),
+        }
+    }
+
+    /// Creates a new [`SourceText`] for code that was generated from other sources, described by
+    /// `source_map`.
+    #[must_use]
+    pub(crate) fn with_source_map(
+        source_text: boa_ast::SourceText,
+        source_map: Option<SourceMapV3>,
+    ) -> Self {
+        Self {
+            source_text: Some(Rc::new(Inner::new(source_text, source_map))),
         }
     }
 
@@ -30,6 +48,13 @@ impl SourceText {
         Self { source_text: None }
     }
 
+    /// Returns the code points of the whole source text, if any.
+    #[inline]
+    pub(crate) fn code_points(&self) -> Option<&[u16]> {
+        self.inner()
+            .map(|text| text.get_code_points_from_pos(boa_ast::LinearPosition::default()))
+    }
+
     #[inline]
     fn inner(&self) -> Option<&boa_ast::SourceText> {
         self.source_text.as_ref().map(|x| &x.source_text)
@@ -38,6 +63,21 @@ impl SourceText {
     fn is_empty(&self) -> bool {
         self.source_text.is_none()
     }
+
+    /// Returns `true` if both source texts are the same, including when both are empty.
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.source_text, &other.source_text) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the source map of this source text, if any.
+    #[inline]
+    pub(crate) fn source_map(&self) -> Option<&SourceMapV3> {
+        self.source_text.as_ref()?.source_map.as_ref()
+    }
 }
 
 /// Contains pointer to source code and span of the object.
@@ -80,6 +120,12 @@ impl SpannedSourceText {
         self.source_text.clone()
     }
 
+    /// Returns the span of the source text, if any.
+    #[must_use]
+    pub(crate) const fn span(&self) -> Option<LinearSpan> {
+        self.span
+    }
+
     /// Test if the span is empty.
     #[inline]
     #[must_use]
@@ -92,6 +138,12 @@ impl SpannedSourceText {
         span_is_empty || self.source_text.is_empty()
     }
 
+    /// Returns the source map of the source text, if any.
+    #[must_use]
+    pub(crate) fn source_map(&self) -> Option<&SourceMapV3> {
+        self.source_text.source_map()
+    }
+
     /// Gets inner code points.
     #[must_use]
     pub fn to_code_points(&self) -> Option<&[u16]> {
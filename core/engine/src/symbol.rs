@@ -78,6 +78,7 @@ enum WellKnown {
     Unscopables,
     Dispose,
     AsyncDispose,
+    Metadata,
 }
 
 impl WellKnown {
@@ -98,6 +99,7 @@ impl WellKnown {
             Self::Unscopables => StaticJsStrings::SYMBOL_UNSCOPABLES,
             Self::Dispose => StaticJsStrings::SYMBOL_DISPOSE,
             Self::AsyncDispose => StaticJsStrings::SYMBOL_ASYNC_DISPOSE,
+            Self::Metadata => StaticJsStrings::SYMBOL_METADATA,
         }
     }
 
@@ -118,6 +120,7 @@ impl WellKnown {
             Self::Unscopables => StaticJsStrings::FN_SYMBOL_UNSCOPABLES,
             Self::Dispose => StaticJsStrings::FN_SYMBOL_DISPOSE,
             Self::AsyncDispose => StaticJsStrings::FN_SYMBOL_ASYNC_DISPOSE,
+            Self::Metadata => StaticJsStrings::FN_SYMBOL_METADATA,
         }
     }
 
@@ -185,6 +188,14 @@ impl JsSymbol {
         })
     }
 
+    /// Returns the well known symbol with the provided hash, if there is one.
+    pub(crate) fn well_known(hash: u64) -> Option<Self> {
+        let well_known = WellKnown::from_tag(usize::try_from(hash).ok()?)?;
+        Some(Self {
+            repr: Tagged::from_tag(well_known.hash() as usize),
+        })
+    }
+
     /// Returns the `Symbol` description.
     #[inline]
     #[must_use]
@@ -316,6 +327,8 @@ impl JsSymbol {
         (dispose, WellKnown::Dispose),
         /// Gets the static `JsSymbol` for `"Symbol.asyncDispose"`.
         (async_dispose, WellKnown::AsyncDispose),
+        /// Gets the static `JsSymbol` for `"Symbol.metadata"`.
+        (metadata, WellKnown::Metadata),
     }
 }
 
@@ -0,0 +1,205 @@
+use crate::{JsNativeErrorKind, TestAction, run_test_actions};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn method_decorator_replaces_method() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function double(method, context) {
+                return function (...args) {
+                    return method.call(this, ...args) * 2;
+                };
+            }
+            class C {
+                @double
+                value(x) { return x + 1; }
+
+                @double
+                static #secret() { return 21; }
+
+                static secret() { return C.#secret(); }
+
+                @((method) => method)
+                unchanged() {}
+            }
+        "#}),
+        TestAction::assert_eq("new C().value(1)", 4),
+        TestAction::assert_eq("C.secret()", 42),
+        TestAction::assert_eq("C.prototype.value.name", js_str!("")),
+        TestAction::assert_eq("C.prototype.unchanged.name", js_str!("unchanged")),
+    ]);
+}
+
+#[test]
+fn decorator_context() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let contexts = [];
+            function record(value, context) {
+                contexts.push(context);
+            }
+            class C {
+                @record static method() {}
+                @record get #getter() { return 1; }
+                @record field = 1;
+                @record accessor acc = 2;
+            }
+        "#}),
+        TestAction::assert_eq("contexts.length", 4),
+        TestAction::assert_eq("contexts[0].kind", js_str!("method")),
+        TestAction::assert_eq("contexts[0].static", true),
+        TestAction::assert_eq("contexts[1].kind", js_str!("getter")),
+        TestAction::assert_eq("contexts[1].name", js_str!("#getter")),
+        TestAction::assert_eq("contexts[1].private", true),
+        TestAction::assert_eq("contexts[1].access.get(new C())", 1),
+        TestAction::assert_eq("contexts[2].kind", js_str!("accessor")),
+        TestAction::assert_eq("contexts[3].kind", js_str!("field")),
+        TestAction::assert_eq("contexts[3].name", js_str!("field")),
+        TestAction::assert("contexts.every(c => c.metadata === contexts[0].metadata)"),
+    ]);
+}
+
+#[test]
+fn field_decorator_initializer() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function plusOne(value, context) {
+                return (initial) => initial + 1;
+            }
+            class C {
+                @plusOne x = 1;
+                @plusOne static y = 10;
+            }
+        "#}),
+        TestAction::assert_eq("new C().x", 2),
+        TestAction::assert_eq("C.y", 11),
+    ]);
+}
+
+#[test]
+fn auto_accessor() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class C {
+                accessor x = 1;
+                static accessor #y = 2;
+                static getY() { return C.#y; }
+                static setY(v) { C.#y = v; }
+            }
+            let c = new C();
+            let desc = Object.getOwnPropertyDescriptor(C.prototype, "x");
+        "#}),
+        TestAction::assert_eq("c.x", 1),
+        TestAction::assert_eq("c.x = 5; c.x", 5),
+        TestAction::assert_eq("Object.hasOwn(c, 'x')", false),
+        TestAction::assert_eq("typeof desc.get", js_str!("function")),
+        TestAction::assert_eq("desc.get.name", js_str!("get x")),
+        TestAction::assert_eq("C.getY()", 2),
+        TestAction::assert_eq("C.setY(3); C.getY()", 3),
+    ]);
+}
+
+#[test]
+fn accessor_decorator() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function logged({ get, set }, context) {
+                return {
+                    get() { return get.call(this) * 10; },
+                    set(v) { set.call(this, v + 1); },
+                    init(v) { return v + 100; },
+                };
+            }
+            class C {
+                @logged accessor x = 1;
+            }
+            let c = new C();
+        "#}),
+        TestAction::assert_eq("c.x", 1010),
+        TestAction::assert_eq("c.x = 2; c.x", 30),
+    ]);
+}
+
+#[test]
+fn add_initializer() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let log = [];
+            function bound(method, context) {
+                context.addInitializer(function () {
+                    this[context.name] = this[context.name].bind(this);
+                });
+            }
+            function register(value, context) {
+                context.addInitializer(function () {
+                    log.push("class " + this.name);
+                });
+            }
+            @register
+            class C {
+                name = "instance";
+                @bound getName() { return this.name; }
+            }
+            let getName = new C().getName;
+        "#}),
+        TestAction::assert_eq("getName()", js_str!("instance")),
+        TestAction::assert_eq("log.join()", js_str!("class C")),
+        TestAction::assert_native_error(
+            indoc! {r#"
+                let saved;
+                function save(value, context) { saved = context; }
+                class D { @save m() {} }
+                saved.addInitializer(() => {});
+            "#},
+            JsNativeErrorKind::Type,
+            "addInitializer: cannot add an initializer after decoration has finished",
+        ),
+    ]);
+}
+
+#[test]
+fn class_decorator_replaces_class() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function wrap(value, context) {
+                return class extends value {
+                    wrapped = context.name;
+                };
+            }
+            @wrap
+            class C {
+                static self() { return C; }
+            }
+        "#}),
+        TestAction::assert_eq("new C().wrapped", js_str!("C")),
+        TestAction::assert("C.self() === C"),
+        TestAction::assert_native_error(
+            "@(() => 1) class D {}",
+            JsNativeErrorKind::Type,
+            "class decorators must return a function or undefined",
+        ),
+    ]);
+}
+
+#[test]
+fn decorator_metadata() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function meta(key) {
+                return (value, context) => { context.metadata[key] = true; };
+            }
+            @meta("class")
+            class A {
+                @meta("method") m() {}
+            }
+            class B extends A {
+                @meta("field") f;
+            }
+        "#}),
+        TestAction::assert("A[Symbol.metadata].class && A[Symbol.metadata].method"),
+        TestAction::assert("B[Symbol.metadata].field"),
+        TestAction::assert("Object.getPrototypeOf(B[Symbol.metadata]) === A[Symbol.metadata]"),
+        TestAction::assert("B[Symbol.metadata].class"),
+    ]);
+}
@@ -6,6 +6,8 @@ use indoc::indoc;
 mod async_generator;
 mod class;
 mod control_flow;
+#[cfg(feature = "experimental")]
+mod decorators;
 mod env;
 mod function;
 mod generators;
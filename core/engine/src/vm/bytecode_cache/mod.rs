@@ -0,0 +1,1258 @@
+//! Binary serialization of compiled [`CodeBlock`]s.
+//!
+//! A bytecode cache stores everything that is needed to run a compiled [`Script`] or [`Module`]
+//! without parsing and compiling its source again: the bytecode of every function, its constants,
+//! the scopes and bindings they reference, the source text (used by `Function.prototype.toString`)
+//! and the source maps used by error backtraces. Module caches also store the import and export
+//! records of the module, which are needed to link it.
+//!
+//! A cache starts with a header that identifies the format version and the engine build that
+//! created it. Loading a cache created by a different build is rejected with
+//! [`BytecodeCacheError::EngineMismatch`], since the meaning of the bytecode can change between
+//! builds. The payload is protected by a checksum to detect truncated or corrupted caches, but
+//! the bytecode is not verified beyond that, which is why [`Script::from_bytecode_cache`] and
+//! [`Module::from_bytecode_cache`] are `unsafe`.
+//!
+//! [`Script`]: crate::Script
+//! [`Module`]: crate::Module
+//! [`Script::from_bytecode_cache`]: crate::Script::from_bytecode_cache
+//! [`Module::from_bytecode_cache`]: crate::Module::from_bytecode_cache
+
+use std::{error::Error, fmt, path::Path, rc::Rc};
+
+use boa_ast::{
+    LinearPosition, LinearSpan, Position, Span,
+    declaration::{
+        ImportAttribute as AstImportAttribute, ImportEntry, ImportName, IndirectExportEntry,
+        LocalExportEntry, ReExportImportName,
+    },
+    expression::Identifier,
+    scope::{BindingData, BindingLocator, BindingLocatorScope, Scope},
+};
+use boa_gc::Gc;
+use boa_interner::{Interner, JStrRef, Sym};
+use rustc_hash::FxHashMap;
+use thin_vec::ThinVec;
+
+use crate::{
+    JsBigInt, JsError, JsNativeError, JsResult, JsString,
+    builtins::function::ThisMode,
+    bytecompiler::ToJsString,
+    js_error,
+    module::{CompiledModule, ImportAttribute, ModuleCode, ModuleRequest},
+    spanned_source_text::{SourceText, SpannedSourceText},
+    string::JsStrVariant,
+};
+
+use super::{
+    CodeBlock, CodeBlockFlags, Constant, GlobalFunctionBinding, Handler, InlineCache, Opcode,
+    opcode::{Address, Bytecode},
+    source_info::{Entry, SourceInfo, SourceMap, SourceMapV3, SourcePath},
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The bytes every script bytecode cache starts with.
+const MAGIC: [u8; 8] = *b"BOACODE\0";
+
+/// The bytes every module bytecode cache starts with.
+const MODULE_MAGIC: [u8; 8] = *b"BOAMODL\0";
+
+/// The version of the layout of the cache. This must be bumped whenever the encoding of any of
+/// the stored structures changes.
+const FORMAT_VERSION: u32 = 1;
+
+/// Identifies the engine builds that produce compatible bytecode.
+///
+/// This covers the version of the engine, the opcodes and their operands, and the features that
+/// change the generated code.
+const ENGINE_FINGERPRINT: u64 = {
+    let mut hash = Fnv1a::new();
+    hash = hash.write(env!("CARGO_PKG_VERSION").as_bytes());
+    hash = hash.write(Opcode::SIGNATURE.as_bytes());
+    hash = hash.write(&[
+        cfg!(feature = "annex-b") as u8,
+        cfg!(feature = "experimental") as u8,
+        cfg!(feature = "trace") as u8,
+        cfg!(target_endian = "little") as u8,
+    ]);
+    hash.finish()
+};
+
+/// The size of the header: magic, format version, engine fingerprint and checksum.
+const HEADER_LEN: usize = MAGIC.len() + 4 + 8 + 8;
+
+/// The 64-bit FNV-1a hash, usable in constant contexts.
+#[derive(Clone, Copy)]
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    const fn write(mut self, bytes: &[u8]) -> Self {
+        let mut i = 0;
+        while i < bytes.len() {
+            self.0 ^= bytes[i] as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+            i += 1;
+        }
+        self
+    }
+
+    const fn finish(self) -> u64 {
+        self.0
+    }
+}
+
+/// The error returned when a bytecode cache cannot be loaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BytecodeCacheError {
+    /// The bytes are not a bytecode cache.
+    NotACache,
+
+    /// The cache was created with an unsupported version of the format.
+    UnsupportedVersion(u32),
+
+    /// The cache was created by an incompatible build of the engine.
+    EngineMismatch,
+
+    /// The cache is truncated or corrupted.
+    Corrupted,
+}
+
+impl fmt::Display for BytecodeCacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotACache => f.write_str("the bytes are not a bytecode cache"),
+            Self::UnsupportedVersion(version) => {
+                write!(f, "unsupported bytecode cache format version {version}")
+            }
+            Self::EngineMismatch => {
+                f.write_str("the bytecode cache was created by an incompatible engine build")
+            }
+            Self::Corrupted => f.write_str("the bytecode cache is corrupted"),
+        }
+    }
+}
+
+impl Error for BytecodeCacheError {}
+
+impl From<BytecodeCacheError> for JsNativeError {
+    fn from(err: BytecodeCacheError) -> Self {
+        Self::error().with_message(err.to_string())
+    }
+}
+
+impl From<BytecodeCacheError> for JsError {
+    fn from(err: BytecodeCacheError) -> Self {
+        JsNativeError::from(err).into()
+    }
+}
+
+type DecodeResult<T> = Result<T, BytecodeCacheError>;
+
+/// The compiled state of a script, as stored in a bytecode cache.
+pub(crate) struct CachedScript {
+    pub(crate) codeblock: Gc<CodeBlock>,
+    pub(crate) source_text: SourceText,
+    pub(crate) path: Option<Rc<Path>>,
+    pub(crate) annex_b_function_names: Box<[JsString]>,
+}
+
+/// Serializes a compiled script.
+///
+/// `global_scope` is the scope of the realm the script was compiled in.
+pub(crate) fn encode_script(
+    codeblock: &Gc<CodeBlock>,
+    source_text: &SourceText,
+    path: Option<&Path>,
+    annex_b_function_names: &[JsString],
+    global_scope: &Scope,
+) -> Vec<u8> {
+    let mut writer = Writer::new(global_scope);
+
+    let body = writer.section(|w| {
+        w.source(source_text);
+        w.code_block(codeblock);
+    });
+    let declarations = writer.section(|w| {
+        w.list(&codeblock.global_lexs, |w, &index| {
+            w.string(&codeblock.constant_string(index as usize));
+        });
+        let var_names = codeblock
+            .global_fns
+            .iter()
+            .map(|function| function.name_index)
+            .chain(codeblock.global_vars.iter().copied())
+            .collect::<Vec<_>>();
+        w.list(&var_names, |w, &index| {
+            w.string(&codeblock.constant_string(index as usize));
+        });
+        w.list(annex_b_function_names, Writer::string);
+        w.option(path, Writer::path);
+    });
+
+    with_header(MAGIC, &writer.finish(declarations, body))
+}
+
+/// Deserializes a compiled script, recreating its scopes inside `global_scope`.
+///
+/// # Errors
+///
+/// Besides the errors of an invalid cache, this throws a `SyntaxError` if the script declares
+/// a global lexical binding that already exists in `global_scope`, like parsing it would.
+pub(crate) fn decode_script(bytes: &[u8], global_scope: &Scope) -> JsResult<CachedScript> {
+    let payload = check_cache_header(bytes, MAGIC)?;
+    let mut reader = Reader::new(payload, global_scope.clone());
+
+    reader.strings()?;
+    let lex_names = reader.list(Reader::string)?;
+    let var_names = reader.list(Reader::string)?;
+    // Mirrors the checks done by the scope analysis of the parser.
+    if lex_names.iter().any(|name| global_scope.has_binding(name))
+        || var_names
+            .iter()
+            .any(|name| global_scope.has_lex_binding(name))
+    {
+        return Err(js_error!(SyntaxError: "duplicate lexical declaration"));
+    }
+    let annex_b_function_names = reader.list(Reader::string)?.into_boxed_slice();
+    let path = reader.option(Reader::path)?;
+    reader.tables()?;
+    let source_text = reader.source()?;
+    let codeblock = reader.code_block()?;
+
+    if !reader.is_empty() {
+        return Err(BytecodeCacheError::Corrupted.into());
+    }
+
+    Ok(CachedScript {
+        codeblock,
+        source_text,
+        path,
+        annex_b_function_names,
+    })
+}
+
+/// The compiled state of a module, as stored in a bytecode cache.
+pub(crate) struct CachedModule {
+    pub(crate) compiled: CompiledModule,
+    pub(crate) code: ModuleCode,
+}
+
+/// Serializes a compiled module and its import and export records.
+///
+/// `global_scope` is the scope of the realm the module was parsed in.
+pub(crate) fn encode_module(
+    compiled: &CompiledModule,
+    code: &ModuleCode,
+    interner: &Interner,
+    global_scope: &Scope,
+) -> Vec<u8> {
+    let mut writer = Writer::new(global_scope);
+
+    let body = writer.section(|w| {
+        w.code_block(&compiled.codeblock);
+        w.scope(&compiled.scope);
+        w.list(&compiled.functions, |w, (index, locator)| {
+            w.u32(*index);
+            w.binding_locator(locator);
+        });
+    });
+    let declarations = writer.section(|w| {
+        w.bool(code.has_tla);
+        w.option(code.path.as_deref(), Writer::path);
+        let requested_modules = code.requested_modules.iter().collect::<Vec<_>>();
+        w.list(&requested_modules, |w, request| w.module_request(request));
+        w.list(&code.import_entries, |w, entry| {
+            w.sym(entry.module_request(), interner);
+            match entry.import_name() {
+                ImportName::Namespace => w.u8(0),
+                ImportName::Name(name) => {
+                    w.u8(1);
+                    w.sym(name, interner);
+                }
+            }
+            w.sym(entry.local_name().sym(), interner);
+            w.import_attributes(entry.attributes(), interner);
+        });
+        w.list(&code.local_export_entries, |w, entry| {
+            w.sym(entry.local_name(), interner);
+            w.sym(entry.export_name(), interner);
+        });
+        w.list(&code.indirect_export_entries, |w, entry| {
+            w.sym(entry.module_request(), interner);
+            match entry.import_name() {
+                ReExportImportName::Star => w.u8(0),
+                ReExportImportName::Name(name) => {
+                    w.u8(1);
+                    w.sym(name, interner);
+                }
+            }
+            w.sym(entry.export_name(), interner);
+            w.import_attributes(entry.attributes(), interner);
+        });
+        w.list(&code.star_export_entries, Writer::module_request);
+    });
+
+    with_header(MODULE_MAGIC, &writer.finish(declarations, body))
+}
+
+/// Deserializes a compiled module, recreating its scopes inside `global_scope` and interning
+/// the names of its import and export records in `interner`.
+pub(crate) fn decode_module(
+    bytes: &[u8],
+    global_scope: &Scope,
+    interner: &mut Interner,
+) -> DecodeResult<CachedModule> {
+    let payload = check_cache_header(bytes, MODULE_MAGIC)?;
+    let mut reader = Reader::new(payload, global_scope.clone());
+
+    reader.strings()?;
+    let has_tla = reader.bool()?;
+    let path = reader.option(Reader::path)?;
+    let requested_modules = reader.list(Reader::module_request)?;
+    let import_entries = reader.list(|r| {
+        let module_request = r.sym(interner)?;
+        let import_name = match r.u8()? {
+            0 => ImportName::Namespace,
+            1 => ImportName::Name(r.sym(interner)?),
+            _ => return Err(BytecodeCacheError::Corrupted),
+        };
+        // The span of the local name is only used while parsing, so it is not stored.
+        let start = Position::new(1, 1);
+        let local_name = Identifier::new(r.sym(interner)?, Span::new(start, start));
+        let attributes = r.import_attributes(interner)?;
+        Ok(ImportEntry::new(
+            module_request,
+            import_name,
+            local_name,
+            attributes,
+        ))
+    })?;
+    let local_export_entries = reader.list(|r| {
+        let local_name = r.sym(interner)?;
+        Ok(LocalExportEntry::new(local_name, r.sym(interner)?))
+    })?;
+    let indirect_export_entries = reader.list(|r| {
+        let module_request = r.sym(interner)?;
+        let import_name = match r.u8()? {
+            0 => ReExportImportName::Star,
+            1 => ReExportImportName::Name(r.sym(interner)?),
+            _ => return Err(BytecodeCacheError::Corrupted),
+        };
+        let export_name = r.sym(interner)?;
+        let attributes = r.import_attributes(interner)?;
+        Ok(IndirectExportEntry::new(
+            module_request,
+            import_name,
+            export_name,
+            attributes,
+        ))
+    })?;
+    let star_export_entries = reader.list(Reader::module_request)?;
+    reader.tables()?;
+    let codeblock = reader.code_block()?;
+    let scope = reader.scope()?;
+    if scope.is_global() {
+        return Err(BytecodeCacheError::Corrupted);
+    }
+    let functions = reader.list(|r| {
+        let index = r.u32()?;
+        if !matches!(
+            codeblock.constants.get(index as usize),
+            Some(Constant::Function(_))
+        ) {
+            return Err(BytecodeCacheError::Corrupted);
+        }
+        Ok((index, r.binding_locator()?))
+    })?;
+
+    if !reader.is_empty() {
+        return Err(BytecodeCacheError::Corrupted);
+    }
+
+    Ok(CachedModule {
+        compiled: CompiledModule {
+            codeblock,
+            scope,
+            functions,
+        },
+        code: ModuleCode {
+            has_tla,
+            requested_modules: requested_modules.into_iter().collect(),
+            path: path.map(|path| path.to_path_buf()),
+            import_entries,
+            local_export_entries,
+            indirect_export_entries,
+            star_export_entries,
+        },
+    })
+}
+
+/// Validates the header of a bytecode cache starting with `magic`, returning its payload.
+fn check_cache_header(bytes: &[u8], magic: [u8; 8]) -> DecodeResult<&[u8]> {
+    check_header(bytes, magic).map_err(|err| match err {
+        HeaderError::Magic => BytecodeCacheError::NotACache,
+        HeaderError::Version(version) => BytecodeCacheError::UnsupportedVersion(version),
+        HeaderError::Fingerprint => BytecodeCacheError::EngineMismatch,
+        HeaderError::Checksum => BytecodeCacheError::Corrupted,
+    })
+}
+
+/// The reasons the header of a serialized payload can be rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum HeaderError {
+    /// The bytes do not start with the expected magic.
+    Magic,
+    /// The format version is not [`FORMAT_VERSION`].
+    Version(u32),
+    /// The bytes were created by an incompatible engine build.
+    Fingerprint,
+    /// The checksum of the payload does not match.
+    Checksum,
+}
+
+/// Prepends the header identifying the format and engine build to `payload`.
+pub(crate) fn with_header(magic: [u8; 8], payload: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+    bytes.extend_from_slice(&magic);
+    bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&ENGINE_FINGERPRINT.to_le_bytes());
+    bytes.extend_from_slice(&Fnv1a::new().write(payload).finish().to_le_bytes());
+    bytes.extend_from_slice(payload);
+    bytes
+}
+
+/// Validates the header written by [`with_header`], returning the payload.
+pub(crate) fn check_header(bytes: &[u8], magic: [u8; 8]) -> Result<&[u8], HeaderError> {
+    let Some((header, payload)) = bytes.split_at_checked(HEADER_LEN) else {
+        return Err(HeaderError::Magic);
+    };
+    let (found, header) = header.split_at(magic.len());
+    if found != magic {
+        return Err(HeaderError::Magic);
+    }
+    let (version, header) = header.split_at(4);
+    let version = u32::from_le_bytes(version.try_into().expect("slice has the correct length"));
+    if version != FORMAT_VERSION {
+        return Err(HeaderError::Version(version));
+    }
+    let (fingerprint, checksum) = header.split_at(8);
+    if u64::from_le_bytes(
+        fingerprint
+            .try_into()
+            .expect("slice has the correct length"),
+    ) != ENGINE_FINGERPRINT
+    {
+        return Err(HeaderError::Fingerprint);
+    }
+    if u64::from_le_bytes(checksum.try_into().expect("slice has the correct length"))
+        != Fnv1a::new().write(payload).finish()
+    {
+        return Err(HeaderError::Checksum);
+    }
+    Ok(payload)
+}
+
+/// Encodes the sections of a bytecode cache.
+///
+/// Strings, source texts, scopes and code blocks are written as indices into tables that are
+/// only encoded by [`Writer::finish`], once every section that references them is encoded.
+pub(crate) struct Writer {
+    bytes: Vec<u8>,
+    strings: FxHashMap<JsString, u32>,
+    sources: Vec<SourceText>,
+    scopes: Vec<Scope>,
+    global_scope: Scope,
+    global_bindings: FxHashMap<JsString, BindingData>,
+    used_global_bindings: Vec<BindingData>,
+    code_blocks: Vec<Gc<CodeBlock>>,
+    code_block_indices: FxHashMap<*const CodeBlock, u32>,
+}
+
+impl Writer {
+    pub(crate) fn new(global_scope: &Scope) -> Self {
+        Self {
+            bytes: Vec::new(),
+            strings: FxHashMap::default(),
+            sources: Vec::new(),
+            scopes: Vec::new(),
+            global_scope: global_scope.clone(),
+            global_bindings: global_scope
+                .data()
+                .bindings
+                .into_iter()
+                .map(|binding| (binding.name().clone(), binding))
+                .collect(),
+            used_global_bindings: Vec::new(),
+            code_blocks: Vec::new(),
+            code_block_indices: FxHashMap::default(),
+        }
+    }
+
+    /// Encodes a section with `f`, returning its bytes.
+    pub(crate) fn section(&mut self, f: impl FnOnce(&mut Self)) -> Vec<u8> {
+        let outer = std::mem::take(&mut self.bytes);
+        f(self);
+        std::mem::replace(&mut self.bytes, outer)
+    }
+
+    /// Encodes the tables referenced by the already encoded sections, returning the payload.
+    ///
+    /// `declarations` only can reference strings, and is decoded right after the string table,
+    /// before the global bindings are created by [`Reader::tables`]. `body` is decoded last.
+    pub(crate) fn finish(mut self, declarations: Vec<u8>, body: Vec<u8>) -> Vec<u8> {
+        // The tables are encoded in the reverse order of their dependencies, since encoding
+        // a table can add entries to the tables before it.
+        let code_blocks = self.section(Self::code_blocks);
+        let scopes = self.section(Self::scopes);
+        let globals = self.section(Self::global_bindings);
+        let sources = self.section(Self::sources);
+        let strings = self.section(Self::strings);
+        [
+            strings,
+            declarations,
+            sources,
+            globals,
+            scopes,
+            code_blocks,
+            body,
+        ]
+        .concat()
+    }
+
+    /// Replaces the bytes of the section being encoded, returning the previous bytes.
+    pub(crate) fn swap_bytes(&mut self, bytes: Vec<u8>) -> Vec<u8> {
+        std::mem::replace(&mut self.bytes, bytes)
+    }
+
+    pub(crate) fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    pub(crate) fn bool(&mut self, value: bool) {
+        self.u8(value.into());
+    }
+
+    pub(crate) fn u16(&mut self, value: u16) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub(crate) fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub(crate) fn u64(&mut self, value: u64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Encodes a length, which must fit in an `u32`.
+    pub(crate) fn u32_len(&mut self, len: usize) {
+        self.u32(u32::try_from(len).expect("lengths in compiled code must fit in an u32"));
+    }
+
+    pub(crate) fn bytes(&mut self, bytes: &[u8]) {
+        self.u32_len(bytes.len());
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    /// Appends bytes that were already encoded.
+    pub(crate) fn raw(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    pub(crate) fn str(&mut self, value: &str) {
+        self.bytes(value.as_bytes());
+    }
+
+    pub(crate) fn path(&mut self, path: &Path) {
+        self.str(&path.to_string_lossy());
+    }
+
+    /// Encodes a string as an index into the string table.
+    pub(crate) fn string(&mut self, string: &JsString) {
+        let next = u32::try_from(self.strings.len()).expect("too many strings");
+        let index = *self.strings.entry(string.clone()).or_insert(next);
+        self.u32(index);
+    }
+
+    /// Encodes an interned string as an index into the string table.
+    fn sym(&mut self, sym: Sym, interner: &Interner) {
+        self.string(&sym.to_js_string(interner));
+    }
+
+    fn import_attributes(&mut self, attributes: &[AstImportAttribute], interner: &Interner) {
+        self.list(attributes, |w, attribute| {
+            w.sym(attribute.key(), interner);
+            w.sym(attribute.value(), interner);
+        });
+    }
+
+    fn module_request(&mut self, request: &ModuleRequest) {
+        self.string(request.specifier());
+        self.list(request.attributes(), |w, attribute| {
+            w.string(attribute.key());
+            w.string(attribute.value());
+        });
+    }
+
+    pub(crate) fn option<T>(&mut self, value: Option<T>, f: impl FnOnce(&mut Self, T)) {
+        self.bool(value.is_some());
+        if let Some(value) = value {
+            f(self, value);
+        }
+    }
+
+    pub(crate) fn list<T>(&mut self, values: &[T], mut f: impl FnMut(&mut Self, &T)) {
+        self.u32_len(values.len());
+        for value in values {
+            f(self, value);
+        }
+    }
+
+    /// Encodes a source text as an index into the source table, where `0` is no source text.
+    pub(crate) fn source(&mut self, source: &SourceText) {
+        if source.code_points().is_none() {
+            self.u32(0);
+            return;
+        }
+        let index = if let Some(index) = self.sources.iter().position(|s| s.ptr_eq(source)) {
+            index
+        } else {
+            self.sources.push(source.clone());
+            self.sources.len() - 1
+        };
+        self.u32_len(index + 1);
+    }
+
+    /// Encodes a scope as an index into the scope table, where `0` is the global scope.
+    pub(crate) fn scope(&mut self, scope: &Scope) {
+        if scope.is_global() {
+            debug_assert!(scope.ptr_eq(&self.global_scope));
+            self.u32(0);
+            return;
+        }
+        if let Some(index) = self.scopes.iter().position(|s| s.ptr_eq(scope)) {
+            self.u32_len(index + 1);
+            return;
+        }
+
+        // Register the outer scopes first, so that scopes are always decoded after their outer
+        // scope.
+        let mut chain = Vec::new();
+        let mut current = scope;
+        while !current.is_global() && !self.scopes.iter().any(|s| s.ptr_eq(current)) {
+            chain.push(current.clone());
+            current = current
+                .outer()
+                .expect("non-global scopes have an outer scope");
+        }
+        self.scopes.extend(chain.into_iter().rev());
+        self.u32_len(self.scopes.len());
+    }
+
+    fn scopes(&mut self) {
+        // Encoding the table cannot add new scopes, since all outer scopes are already registered.
+        self.u32_len(self.scopes.len());
+        for index in 0..self.scopes.len() {
+            let scope = self.scopes[index].clone();
+            let outer = scope
+                .outer()
+                .expect("non-global scopes have an outer scope");
+            self.scope(outer);
+            self.u32(scope.unique_id());
+            let data = scope.data();
+            self.u32(data.index);
+            self.bool(data.function);
+            self.bool(data.this_escaped);
+            self.list(&data.bindings, |w, binding| {
+                w.string(binding.name());
+                w.u32(binding.index());
+                w.u8(binding.flags());
+            });
+        }
+    }
+
+    fn global_bindings(&mut self) {
+        let bindings = std::mem::take(&mut self.used_global_bindings);
+        self.list(&bindings, |w, binding| {
+            w.string(binding.name());
+            w.u8(binding.flags());
+        });
+    }
+
+    fn sources(&mut self) {
+        let sources = std::mem::take(&mut self.sources);
+        self.list(&sources, |w, source| {
+            let code_points = source
+                .code_points()
+                .expect("only source texts with code points are registered");
+            w.u32_len(code_points.len());
+            for &cu in code_points {
+                w.u16(cu);
+            }
+            w.option(source.source_map(), |w, map| map.encode(w));
+        });
+    }
+
+    fn strings(&mut self) {
+        let mut strings = vec![JsString::default(); self.strings.len()];
+        for (string, &index) in &self.strings {
+            strings[index as usize] = string.clone();
+        }
+        self.list(&strings, |w, string| match string.variant() {
+            JsStrVariant::Latin1(bytes) => {
+                w.u8(0);
+                w.bytes(bytes);
+            }
+            JsStrVariant::Utf16(units) => {
+                w.u8(1);
+                w.u32_len(units.len());
+                for &unit in units {
+                    w.u16(unit);
+                }
+            }
+        });
+    }
+
+    fn binding_locator(&mut self, locator: &BindingLocator) {
+        self.string(locator.name());
+        match locator.scope() {
+            BindingLocatorScope::GlobalObject => self.u8(0),
+            BindingLocatorScope::GlobalDeclarative => {
+                self.u8(1);
+                let binding = &self.global_bindings[locator.name()];
+                if !self
+                    .used_global_bindings
+                    .iter()
+                    .any(|used| used.name() == binding.name())
+                {
+                    self.used_global_bindings.push(binding.clone());
+                }
+            }
+            BindingLocatorScope::Stack(index) => {
+                self.u8(2);
+                self.u32(index);
+            }
+        }
+        self.u32(locator.binding_index());
+        self.u32(locator.unique_scope_id());
+    }
+
+    /// Encodes a code block as an index into the code block table.
+    pub(crate) fn code_block(&mut self, code: &Gc<CodeBlock>) {
+        let index = self.register_code_block(code);
+        self.u32(index);
+    }
+
+    fn register_code_block(&mut self, code: &Gc<CodeBlock>) -> u32 {
+        let key = std::ptr::from_ref::<CodeBlock>(code);
+        if let Some(&index) = self.code_block_indices.get(&key) {
+            return index;
+        }
+        // Register the inner functions first, so that code blocks are always decoded after the
+        // functions they contain.
+        for constant in &code.constants {
+            if let Constant::Function(inner) = constant {
+                self.register_code_block(inner);
+            }
+        }
+        let index = u32::try_from(self.code_blocks.len()).expect("too many code blocks");
+        self.code_block_indices.insert(key, index);
+        self.code_blocks.push(code.clone());
+        index
+    }
+
+    fn code_blocks(&mut self) {
+        // Encoding the table cannot add new code blocks, since all inner functions are already
+        // registered.
+        let code_blocks = std::mem::take(&mut self.code_blocks);
+        self.list(&code_blocks, |w, code| w.code_block_data(code));
+    }
+
+    fn code_block_data(&mut self, code: &CodeBlock) {
+        self.u16(code.flags.get().bits());
+        self.u32(code.length);
+        self.u32(code.parameter_length);
+        self.u32(code.register_count);
+        self.u8(match code.this_mode {
+            ThisMode::Lexical => 0,
+            ThisMode::Strict => 1,
+            ThisMode::Global => 2,
+        });
+        self.list(&code.mapped_arguments_binding_indices, |w, index| {
+            w.option(*index, Self::u32);
+        });
+        self.bytes(&code.bytecode.bytes);
+        self.list(&code.constants, |w, constant| match constant {
+            Constant::String(string) => {
+                w.u8(0);
+                w.string(string);
+            }
+            Constant::Function(code) => {
+                w.u8(1);
+                w.code_block(code);
+            }
+            Constant::BigInt(bigint) => {
+                w.u8(2);
+                w.str(&bigint.to_string_radix(16));
+            }
+            Constant::Scope(scope) => {
+                w.u8(3);
+                w.scope(scope);
+            }
+        });
+        self.list(&code.bindings, Self::binding_locator);
+        self.list(&code.handlers, |w, handler| {
+            w.u32(handler.start.as_u32());
+            w.u32(handler.end.as_u32());
+            w.u32(handler.environment_count);
+        });
+        self.list(&code.ic, |w, ic| w.string(&ic.name));
+        self.source_info(&code.source_info);
+        self.list(&code.global_lexs, |w, index| w.u32(*index));
+        self.list(&code.global_fns, |w, binding| {
+            w.u32(binding.name_index);
+            w.u32(binding.function_index);
+        });
+        self.list(&code.global_vars, |w, index| w.u32(*index));
+    }
+
+    fn source_info(&mut self, info: &SourceInfo) {
+        self.string(info.function_name());
+        let map = info.map();
+        match map.path() {
+            SourcePath::None => self.u8(0),
+            SourcePath::Eval => self.u8(1),
+            SourcePath::Json => self.u8(2),
+            SourcePath::Path(path) => {
+                self.u8(3);
+                self.path(path);
+            }
+        }
+        self.list(map.entries(), |w, entry| {
+            w.u32(entry.pc);
+            w.option(entry.position, |w, position| {
+                w.u32(position.line_number());
+                w.u32(position.column_number());
+            });
+        });
+        let text = info.text_spanned();
+        self.source(&text.source_text());
+        self.option(text.span(), |w, span| {
+            w.u64(span.start().pos() as u64);
+            w.u64(span.end().pos() as u64);
+        });
+    }
+}
+
+/// Decodes the sections of a bytecode cache.
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    strings: Vec<JsString>,
+    global_scope: Scope,
+    global_bindings: FxHashMap<JsString, u32>,
+    scopes: Vec<Scope>,
+    unique_scope_ids: FxHashMap<u32, u32>,
+    sources: Vec<SourceText>,
+    code_blocks: Vec<Gc<CodeBlock>>,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8], global_scope: Scope) -> Self {
+        Self {
+            bytes,
+            strings: Vec::new(),
+            global_scope,
+            global_bindings: FxHashMap::default(),
+            scopes: Vec::new(),
+            unique_scope_ids: FxHashMap::default(),
+            sources: Vec::new(),
+            code_blocks: Vec::new(),
+        }
+    }
+
+    /// Decodes the tables encoded by [`Writer::finish`] after the declarations section.
+    pub(crate) fn tables(&mut self) -> DecodeResult<()> {
+        self.sources = self.list(|r| {
+            let len = r.len()?;
+            let mut text = boa_ast::SourceText::with_capacity(len);
+            for _ in 0..len {
+                text.collect_code_point(u32::from(r.u16()?));
+            }
+            let source_map = r.option(SourceMapV3::decode)?;
+            Ok(SourceText::with_source_map(text, source_map))
+        })?;
+        self.global_bindings()?;
+        self.scopes()?;
+        let len = self.len()?;
+        for _ in 0..len {
+            let code = self.code_block_data()?;
+            self.code_blocks.push(Gc::new(code));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Replaces the bytes left to decode, returning the previous bytes.
+    pub(crate) fn swap_bytes(&mut self, bytes: &'a [u8]) -> &'a [u8] {
+        std::mem::replace(&mut self.bytes, bytes)
+    }
+
+    fn take(&mut self, len: usize) -> DecodeResult<&'a [u8]> {
+        let (taken, rest) = self
+            .bytes
+            .split_at_checked(len)
+            .ok_or(BytecodeCacheError::Corrupted)?;
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> DecodeResult<[u8; N]> {
+        Ok(self
+            .take(N)?
+            .try_into()
+            .expect("slice has the correct length"))
+    }
+
+    pub(crate) fn u8(&mut self) -> DecodeResult<u8> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    pub(crate) fn bool(&mut self) -> DecodeResult<bool> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(BytecodeCacheError::Corrupted),
+        }
+    }
+
+    pub(crate) fn u16(&mut self) -> DecodeResult<u16> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    pub(crate) fn u32(&mut self) -> DecodeResult<u32> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    pub(crate) fn u64(&mut self) -> DecodeResult<u64> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    /// Decodes a length, checking that the remaining bytes can hold at least that many elements.
+    pub(crate) fn len(&mut self) -> DecodeResult<usize> {
+        let len = self.u32()? as usize;
+        if len > self.bytes.len() {
+            return Err(BytecodeCacheError::Corrupted);
+        }
+        Ok(len)
+    }
+
+    pub(crate) fn bytes(&mut self) -> DecodeResult<&'a [u8]> {
+        let len = self.len()?;
+        self.take(len)
+    }
+
+    pub(crate) fn str(&mut self) -> DecodeResult<&'a str> {
+        std::str::from_utf8(self.bytes()?).map_err(|_| BytecodeCacheError::Corrupted)
+    }
+
+    pub(crate) fn path(&mut self) -> DecodeResult<Rc<Path>> {
+        Ok(Path::new(self.str()?).into())
+    }
+
+    pub(crate) fn string(&mut self) -> DecodeResult<JsString> {
+        let index = self.u32()? as usize;
+        self.strings
+            .get(index)
+            .cloned()
+            .ok_or(BytecodeCacheError::Corrupted)
+    }
+
+    /// Decodes a string from the string table, interning it in `interner`.
+    fn sym(&mut self, interner: &mut Interner) -> DecodeResult<Sym> {
+        let string = self.string()?;
+        let units = string.iter().collect::<Vec<_>>();
+        Ok(interner.get_or_intern(JStrRef::Utf16(&units)))
+    }
+
+    fn import_attributes(
+        &mut self,
+        interner: &mut Interner,
+    ) -> DecodeResult<Box<[AstImportAttribute]>> {
+        let attributes = self.list(|r| {
+            let key = r.sym(interner)?;
+            Ok(AstImportAttribute::new(key, r.sym(interner)?))
+        })?;
+        Ok(attributes.into_boxed_slice())
+    }
+
+    fn module_request(&mut self) -> DecodeResult<ModuleRequest> {
+        let specifier = self.string()?;
+        let attributes = self.list(|r| {
+            let key = r.string()?;
+            Ok(ImportAttribute::new(key, r.string()?))
+        })?;
+        Ok(ModuleRequest::new(specifier, attributes.into_boxed_slice()))
+    }
+
+    pub(crate) fn option<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> DecodeResult<T>,
+    ) -> DecodeResult<Option<T>> {
+        if self.bool()? {
+            f(self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub(crate) fn list<T>(
+        &mut self,
+        mut f: impl FnMut(&mut Self) -> DecodeResult<T>,
+    ) -> DecodeResult<Vec<T>> {
+        let len = self.len()?;
+        let mut values = Vec::with_capacity(len);
+        for _ in 0..len {
+            values.push(f(self)?);
+        }
+        Ok(values)
+    }
+
+    pub(crate) fn source(&mut self) -> DecodeResult<SourceText> {
+        match self.u32()? as usize {
+            0 => Ok(SourceText::default()),
+            index => self
+                .sources
+                .get(index - 1)
+                .cloned()
+                .ok_or(BytecodeCacheError::Corrupted),
+        }
+    }
+
+    pub(crate) fn scope(&mut self) -> DecodeResult<Scope> {
+        match self.u32()? as usize {
+            0 => Ok(self.global_scope.clone()),
+            index => self
+                .scopes
+                .get(index - 1)
+                .cloned()
+                .ok_or(BytecodeCacheError::Corrupted),
+        }
+    }
+
+    fn scopes(&mut self) -> DecodeResult<()> {
+        let len = self.len()?;
+        for _ in 0..len {
+            let outer = self.scope()?;
+            let unique_id = self.u32()?;
+            let index = self.u32()?;
+            let function = self.bool()?;
+            let this_escaped = self.bool()?;
+            let bindings = self.list(|r| {
+                let name = r.string()?;
+                let index = r.u32()?;
+                BindingData::from_parts(name, index, r.u8()?).ok_or(BytecodeCacheError::Corrupted)
+            })?;
+            let scope = Scope::from_data(
+                outer,
+                boa_ast::scope::ScopeData {
+                    index,
+                    function,
+                    this_escaped,
+                    bindings,
+                },
+            );
+            self.unique_scope_ids.insert(unique_id, scope.unique_id());
+            self.scopes.push(scope);
+        }
+        Ok(())
+    }
+
+    fn global_bindings(&mut self) -> DecodeResult<()> {
+        let len = self.len()?;
+        for _ in 0..len {
+            let name = self.string()?;
+            let binding = BindingData::from_parts(name.clone(), 0, self.u8()?)
+                .ok_or(BytecodeCacheError::Corrupted)?;
+            let index = self.global_scope.get_or_create_binding(&binding);
+            self.global_bindings.insert(name, index);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn strings(&mut self) -> DecodeResult<()> {
+        self.strings = self.list(|r| match r.u8()? {
+            0 => Ok(JsString::from(crate::string::JsStr::latin1(r.bytes()?))),
+            1 => {
+                let len = r.len()?;
+                let units = (0..len)
+                    .map(|_| r.u16())
+                    .collect::<DecodeResult<Vec<_>>>()?;
+                Ok(JsString::from(&units[..]))
+            }
+            _ => Err(BytecodeCacheError::Corrupted),
+        })?;
+        Ok(())
+    }
+
+    fn binding_locator(&mut self) -> DecodeResult<BindingLocator> {
+        let name = self.string()?;
+        let scope = match self.u8()? {
+            0 => BindingLocatorScope::GlobalObject,
+            1 => BindingLocatorScope::GlobalDeclarative,
+            2 => BindingLocatorScope::Stack(self.u32()?),
+            _ => return Err(BytecodeCacheError::Corrupted),
+        };
+        let mut binding_index = self.u32()?;
+        let mut unique_scope_id = self.u32()?;
+        if scope == BindingLocatorScope::GlobalDeclarative {
+            // The global bindings can have different indices in the realm the script is loaded in.
+            binding_index = *self
+                .global_bindings
+                .get(&name)
+                .ok_or(BytecodeCacheError::Corrupted)?;
+            unique_scope_id = self.global_scope.unique_id();
+        } else if let Some(&id) = self.unique_scope_ids.get(&unique_scope_id) {
+            unique_scope_id = id;
+        }
+        Ok(BindingLocator::new(
+            name,
+            scope,
+            binding_index,
+            unique_scope_id,
+        ))
+    }
+
+    /// Decodes a code block from the code block table.
+    ///
+    /// Code blocks can only reference the code blocks decoded before them.
+    pub(crate) fn code_block(&mut self) -> DecodeResult<Gc<CodeBlock>> {
+        let index = self.u32()? as usize;
+        self.code_blocks
+            .get(index)
+            .cloned()
+            .ok_or(BytecodeCacheError::Corrupted)
+    }
+
+    fn code_block_data(&mut self) -> DecodeResult<CodeBlock> {
+        let flags = CodeBlockFlags::from_bits(self.u16()?).ok_or(BytecodeCacheError::Corrupted)?;
+        let length = self.u32()?;
+        let parameter_length = self.u32()?;
+        let register_count = self.u32()?;
+        let this_mode = match self.u8()? {
+            0 => ThisMode::Lexical,
+            1 => ThisMode::Strict,
+            2 => ThisMode::Global,
+            _ => return Err(BytecodeCacheError::Corrupted),
+        };
+        let mapped_arguments_binding_indices = ThinVec::from(self.list(|r| r.option(Self::u32))?);
+        let bytecode = Bytecode {
+            bytes: self.bytes()?.into(),
+        };
+        let constants = ThinVec::from(self.list(|r| {
+            match r.u8()? {
+                0 => r.string().map(Constant::String),
+                1 => r.code_block().map(Constant::Function),
+                2 => JsBigInt::from_string_radix(r.str()?, 16)
+                    .map(Constant::BigInt)
+                    .ok_or(BytecodeCacheError::Corrupted),
+                3 => r.scope().map(Constant::Scope),
+                _ => Err(BytecodeCacheError::Corrupted),
+            }
+        })?);
+        let bindings = self.list(Self::binding_locator)?.into_boxed_slice();
+        let handlers = ThinVec::from(self.list(|r| {
+            Ok(Handler {
+                start: Address::new(r.u32()?),
+                end: Address::new(r.u32()?),
+                environment_count: r.u32()?,
+            })
+        })?);
+        let ic = self
+            .list(|r| r.string().map(InlineCache::new))?
+            .into_boxed_slice();
+        let source_info = self.source_info()?;
+        let global_lexs = self.list(Self::u32)?.into_boxed_slice();
+        let global_fns = self
+            .list(|r| {
+                Ok(GlobalFunctionBinding {
+                    name_index: r.u32()?,
+                    function_index: r.u32()?,
+                })
+            })?
+            .into_boxed_slice();
+        let global_vars = self.list(Self::u32)?.into_boxed_slice();
+
+        Ok(CodeBlock {
+            flags: std::cell::Cell::new(flags),
+            length,
+            parameter_length,
+            register_count,
+            this_mode,
+            mapped_arguments_binding_indices,
+            bytecode,
+            constants,
+            bindings,
+            handlers,
+            ic,
+            source_info,
+            global_lexs,
+            global_fns,
+            global_vars,
+            debug_id: CodeBlock::get_next_codeblock_id(),
+            #[cfg(feature = "trace")]
+            traced: std::cell::Cell::new(false),
+        })
+    }
+
+    fn source_info(&mut self) -> DecodeResult<SourceInfo> {
+        let function_name = self.string()?;
+        let path = match self.u8()? {
+            0 => SourcePath::None,
+            1 => SourcePath::Eval,
+            2 => SourcePath::Json,
+            3 => SourcePath::Path(self.path()?),
+            _ => return Err(BytecodeCacheError::Corrupted),
+        };
+        let entries = self.list(|r| {
+            let pc = r.u32()?;
+            let position = r.option(|r| {
+                let line = r.u32()?;
+                let column = r.u32()?;
+                if line == 0 || column == 0 {
+                    return Err(BytecodeCacheError::Corrupted);
+                }
+                Ok(Position::new(line, column))
+            })?;
+            Ok(Entry { pc, position })
+        })?;
+        let source_text = self.source()?;
+        let len = source_text.code_points().map_or(0, <[u16]>::len);
+        let span = self.option(|r| {
+            let start = usize::try_from(r.u64()?).map_err(|_| BytecodeCacheError::Corrupted)?;
+            let end = usize::try_from(r.u64()?).map_err(|_| BytecodeCacheError::Corrupted)?;
+            if start > end || end > len {
+                return Err(BytecodeCacheError::Corrupted);
+            }
+            Ok(LinearSpan::new(
+                LinearPosition::new(start),
+                LinearPosition::new(end),
+            ))
+        })?;
+
+        Ok(SourceInfo::new(
+            SourceMap::new(entries.into_boxed_slice(), path),
+            function_name,
+            SpannedSourceText::new(source_text, span),
+        ))
+    }
+}
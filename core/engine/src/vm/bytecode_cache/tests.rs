@@ -0,0 +1,283 @@
+use std::rc::Rc;
+
+use boa_parser::Source;
+
+use super::{BytecodeCacheError, HEADER_LEN, MAGIC, decode_script};
+use crate::{
+    Context, JsNativeErrorKind, JsResult, JsValue, Module, Script, js_string,
+    module::{IntoJsModule, MapModuleLoader},
+};
+
+const SOURCE: &str = r#"
+    let counter = 0;
+    const prefix = "count: ";
+
+    function makeCounter(step) {
+        return () => {
+            counter += step;
+            return prefix + counter;
+        };
+    }
+
+    class Point {
+        #x;
+        constructor(x, y) { this.#x = x; this.y = y; }
+        get x() { return this.#x; }
+        static origin() { return new Point(0, 0); }
+    }
+
+    function* range(n) { for (let i = 0; i < n; i++) yield i; }
+
+    const increment = makeCounter(2);
+    increment();
+    const values = [...range(4)].map(v => BigInt(v) * 10n ** 20n);
+    [
+        increment(),
+        new Point(3, 4).x + Point.origin().y,
+        values[3].toString(),
+        makeCounter.toString(),
+        `${typeof Point}:${[..."héllo"].length}`,
+    ].join("|")
+"#;
+
+const EXPECTED: &str = "count: 4|3|300000000000000000000|function makeCounter(step) {\n        \
+    return () => {\n            counter += step;\n            return prefix + counter;\n        \
+    };\n    }|function:5";
+
+fn compile(context: &mut Context) -> Vec<u8> {
+    let script = Script::parse(Source::from_bytes(SOURCE), None, context).unwrap();
+    script.to_bytecode_cache(context).unwrap()
+}
+
+fn load(bytes: &[u8], context: &mut Context) -> JsResult<Script> {
+    // SAFETY: The caches are created by `Script::to_bytecode_cache`, and at most corrupted by
+    // the tests to check that they are rejected.
+    unsafe { Script::from_bytecode_cache(bytes, None, context) }
+}
+
+#[track_caller]
+fn assert_cache_error(bytes: &[u8], expected: BytecodeCacheError) {
+    let context = &mut Context::default();
+    let Err(err) = decode_script(bytes, context.realm().scope()) else {
+        panic!("expected the cache to be rejected");
+    };
+    let err = err.as_native().expect("cache errors are native errors");
+    assert_eq!(err.kind(), &JsNativeErrorKind::Error);
+    assert_eq!(err.message(), expected.to_string());
+}
+
+#[test]
+fn roundtrip() {
+    let context = &mut Context::default();
+    let bytes = compile(context);
+
+    let context = &mut Context::default();
+    let script = load(&bytes, context).unwrap();
+    let result = script.evaluate(context).unwrap();
+    assert_eq!(result, JsValue::from(js_string!(EXPECTED)));
+
+    // The global lexical declarations of the script are visible to later scripts.
+    let result = context
+        .eval(Source::from_bytes("counter + prefix.length"))
+        .unwrap();
+    assert_eq!(result, JsValue::from(11));
+}
+
+#[test]
+fn matches_parsed_script() {
+    let context = &mut Context::default();
+    let script = Script::parse(Source::from_bytes(SOURCE), None, context).unwrap();
+    let expected = script.evaluate(context).unwrap();
+
+    let bytes = compile(&mut Context::default());
+    let context = &mut Context::default();
+    let script = load(&bytes, context).unwrap();
+    assert_eq!(script.evaluate(context).unwrap(), expected);
+}
+
+#[test]
+fn redeclared_global_lexical_binding() {
+    let context = &mut Context::default();
+    let bytes = compile(context);
+
+    let context = &mut Context::default();
+    context
+        .eval(Source::from_bytes("let unrelated = 1;"))
+        .unwrap();
+    let script = load(&bytes, context).unwrap();
+    script.evaluate(context).unwrap();
+
+    // Loading the script again redeclares `counter`.
+    let err = load(&bytes, context).unwrap_err();
+    assert_eq!(err.as_native().unwrap().kind(), &JsNativeErrorKind::Syntax);
+}
+
+#[test]
+fn engine_mismatch() {
+    let context = &mut Context::default();
+    let mut bytes = compile(context);
+    bytes[MAGIC.len() + 4] ^= 1;
+
+    assert_cache_error(&bytes, BytecodeCacheError::EngineMismatch);
+}
+
+#[test]
+fn unsupported_version() {
+    let context = &mut Context::default();
+    let mut bytes = compile(context);
+    bytes[MAGIC.len()..MAGIC.len() + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+
+    assert_cache_error(&bytes, BytecodeCacheError::UnsupportedVersion(u32::MAX));
+}
+
+#[test]
+fn corrupted() {
+    let context = &mut Context::default();
+    let bytes = compile(context);
+
+    let mut flipped = bytes.clone();
+    flipped[HEADER_LEN + 10] ^= 0x55;
+    assert_cache_error(&flipped, BytecodeCacheError::Corrupted);
+    assert_cache_error(&bytes[..bytes.len() - 1], BytecodeCacheError::Corrupted);
+}
+
+#[test]
+fn not_a_cache() {
+    assert_cache_error(b"", BytecodeCacheError::NotACache);
+    assert_cache_error(SOURCE.as_bytes(), BytecodeCacheError::NotACache);
+
+    let context = &mut Context::default();
+    let err = load(b"", context).unwrap_err();
+    assert_eq!(
+        err.as_native().unwrap().message(),
+        BytecodeCacheError::NotACache.to_string()
+    );
+}
+
+#[cfg(feature = "annex-b")]
+#[test]
+fn annex_b_block_function() {
+    let source = "var before = typeof f; { function f() { return 1; } } before + ':' + f()";
+    let context = &mut Context::default();
+    let script = Script::parse(Source::from_bytes(source), None, context).unwrap();
+    let bytes = script.to_bytecode_cache(context).unwrap();
+
+    let context = &mut Context::default();
+    let script = load(&bytes, context).unwrap();
+    assert_eq!(
+        script.evaluate(context).unwrap(),
+        JsValue::from(js_string!("undefined:1"))
+    );
+    assert!(
+        context
+            .global_object()
+            .has_own_property(js_string!("f"), context)
+            .unwrap()
+    );
+}
+
+const MODULE_SOURCE: &str = r#"
+    import { base, twice } from "dep";
+    import * as dep from "dep";
+    export { base as reexported } from "dep";
+    export * as namespace from "dep";
+
+    export const value = await Promise.resolve(twice(base));
+    export function describe() {
+        return `${value}:${dep.base}:${helper()}:${typeof Answer}`;
+    }
+    function helper() { return typeof describe; }
+    export default class Answer {}
+"#;
+
+const DEP_SOURCE: &str = "export const base = 21; export function twice(x) { return x * 2; }";
+
+fn module_context() -> Context {
+    let loader = Rc::new(MapModuleLoader::default());
+    let mut context = Context::builder()
+        .module_loader(loader.clone())
+        .build()
+        .unwrap();
+    let dep = Module::parse(Source::from_bytes(DEP_SOURCE), None, &mut context).unwrap();
+    loader.insert("dep", dep);
+    context
+}
+
+fn evaluate_module(module: &Module, context: &mut Context) -> JsValue {
+    let promise = module.load_link_evaluate(context);
+    context.run_jobs().unwrap();
+    assert!(
+        promise.state().as_fulfilled().is_some(),
+        "module didn't execute successfully! Promise: {:?}",
+        promise.state()
+    );
+
+    let namespace = module.namespace(context);
+    let describe = namespace.get(js_string!("describe"), context).unwrap();
+    let described = describe
+        .as_callable()
+        .unwrap()
+        .call(&JsValue::undefined(), &[], context)
+        .unwrap();
+    let reexported = namespace.get(js_string!("reexported"), context).unwrap();
+    let twice = namespace
+        .get(js_string!("namespace"), context)
+        .unwrap()
+        .as_object()
+        .unwrap()
+        .get(js_string!("twice"), context)
+        .unwrap();
+    assert_eq!(reexported, JsValue::from(21));
+    assert!(twice.is_callable());
+    described
+}
+
+#[test]
+fn module_roundtrip() {
+    let context = &mut module_context();
+    let module = Module::parse(Source::from_bytes(MODULE_SOURCE), None, context).unwrap();
+    let bytes = module.to_bytecode_cache(context).unwrap();
+    assert_eq!(
+        evaluate_module(&module, context),
+        JsValue::from(js_string!("42:21:function:function"))
+    );
+
+    // Creating the cache after evaluation must produce the same bytes.
+    assert_eq!(module.to_bytecode_cache(context).unwrap(), bytes);
+
+    let context = &mut module_context();
+    // SAFETY: the bytes were produced by `to_bytecode_cache` above.
+    let module = unsafe { Module::from_bytecode_cache(&bytes, None, context) }.unwrap();
+    assert_eq!(
+        evaluate_module(&module, context),
+        JsValue::from(js_string!("42:21:function:function"))
+    );
+}
+
+#[test]
+fn synthetic_module_cache() {
+    let context = &mut Context::default();
+    let module = Vec::<(crate::JsString, crate::NativeFunction)>::new().into_js_module(context);
+    let err = module.to_bytecode_cache(context).unwrap_err();
+    assert_eq!(err.as_native().unwrap().kind(), &JsNativeErrorKind::Type);
+}
+
+#[test]
+fn script_and_module_caches_are_distinct() {
+    let context = &mut module_context();
+    let script_bytes = compile(context);
+    // SAFETY: the bytes come from `to_bytecode_cache`, just for the wrong kind of code.
+    let err = unsafe { Module::from_bytecode_cache(&script_bytes, None, context) }.unwrap_err();
+    assert_eq!(
+        err.as_native().unwrap().message(),
+        BytecodeCacheError::NotACache.to_string()
+    );
+
+    let module = Module::parse(Source::from_bytes(MODULE_SOURCE), None, context).unwrap();
+    let module_bytes = module.to_bytecode_cache(context).unwrap();
+    let err = load(&module_bytes, context).unwrap_err();
+    assert_eq!(
+        err.as_native().unwrap().message(),
+        BytecodeCacheError::NotACache.to_string()
+    );
+}
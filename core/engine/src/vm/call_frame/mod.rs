@@ -4,8 +4,8 @@
 
 use super::ActiveRunnable;
 use crate::{
-    JsValue,
-    builtins::iterable::IteratorRecord,
+    Context, JsObject, JsValue,
+    builtins::{disposable_stack::DisposeCapability, iterable::IteratorRecord},
     bytecompiler::Register,
     environments::EnvironmentStack,
     realm::Realm,
@@ -60,6 +60,9 @@ pub struct CallFrame {
     // Iterators and their `[[Done]]` flags that must be closed when an abrupt completion is thrown.
     pub(crate) iterators: ThinVec<IteratorRecord>,
 
+    // The dispose capabilities of the active `using` and `await using` scopes.
+    pub(crate) disposal_stack: ThinVec<DisposeCapability>,
+
     // The stack of bindings being updated.
     // SAFETY: Nothing in `BindingLocator` requires tracing, so this is safe.
     #[unsafe_ignore_trace]
@@ -103,6 +106,44 @@ impl CallFrame {
             position: source_info.map().find(self.pc),
         }
     }
+
+    /// Returns the `this` value of this call frame.
+    ///
+    /// The frame must be running in `context`.
+    #[must_use]
+    pub fn this(&self, context: &Context) -> JsValue {
+        context.vm.stack.get_this(self)
+    }
+
+    /// Returns the function object of this call frame, or `None` for scripts and modules.
+    ///
+    /// The frame must be running in `context`.
+    #[must_use]
+    pub fn function(&self, context: &Context) -> Option<JsObject> {
+        context.vm.stack.get_function(self)
+    }
+
+    /// Returns the arguments passed to this call frame.
+    ///
+    /// The frame must be running in `context`.
+    #[must_use]
+    pub fn arguments<'a>(&self, context: &'a Context) -> &'a [JsValue] {
+        context.vm.stack.get_arguments(self)
+    }
+
+    /// Returns the names and values of the bindings visible from this call frame, ordered from
+    /// the innermost scope to the global one.
+    ///
+    /// An inner binding shadows the outer bindings with the same name, which come after it.
+    /// Bindings in their temporal dead zone have no value. The properties of the global object
+    /// and of the objects of `with` statements are not included.
+    ///
+    /// This is a read-only view: changing the returned list doesn't assign to the bindings.
+    #[must_use]
+    pub fn bindings(&self) -> Vec<(JsString, Option<JsValue>)> {
+        self.environments
+            .bindings(self.realm.environment(), self.realm.scope())
+    }
 }
 
 /// ---- `CallFrame` creation methods ----
@@ -148,6 +189,7 @@ impl CallFrame {
             argument_count: 0,
             rp: 0,
             iterators: ThinVec::new(),
+            disposal_stack: ThinVec::new(),
             binding_stack: ThinVec::new(),
             code_block,
             loop_iteration_count: 0,
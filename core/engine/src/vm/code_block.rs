@@ -806,6 +806,48 @@ impl CodeBlock {
             | Instruction::Await { src } => {
                 format!("src:{src}")
             }
+            Instruction::AddDisposableResource { value }
+            | Instruction::AddAsyncDisposableResource { value } => {
+                format!("value:{value}")
+            }
+            Instruction::DisposeError { error } => {
+                format!("error:{error}")
+            }
+            Instruction::DisposeResources { value, done } => {
+                format!("value:{value}, done:{done}")
+            }
+            Instruction::PopDisposeScope { has_error, error } => {
+                format!("has_error:{has_error}, error:{error}")
+            }
+            Instruction::CreateClassDecoration {
+                dst,
+                class,
+                prototype,
+                decorators,
+            } => {
+                format!("dst:{dst}, class:{class}, prototype:{prototype}, decorators:{decorators}")
+            }
+            Instruction::PushClassElementDecorators {
+                state,
+                key,
+                value,
+                decorators,
+                storage,
+                flags,
+            } => {
+                format!(
+                    "state:{state}, key:{key}, value:{value}, decorators:{decorators}, storage:{storage}, flags:{flags}"
+                )
+            }
+            Instruction::ApplyClassDecorators { dst, state } => {
+                format!("dst:{dst}, state:{state}")
+            }
+            Instruction::InitializeDecoratedStaticElement { state, index } => {
+                format!("state:{state}, index:{index}")
+            }
+            Instruction::RunClassExtraInitializers { state, class } => {
+                format!("state:{state}, class:{class}")
+            }
             Instruction::IteratorPush { iterator, next }
             | Instruction::IteratorPop { iterator, next } => {
                 format!("iterator:{iterator}, next:{next}")
@@ -873,6 +915,8 @@ impl CodeBlock {
             | Instruction::NewSpread
             | Instruction::SuperCallSpread
             | Instruction::PopPrivateEnvironment
+            | Instruction::PushDisposeScope
+            | Instruction::Debugger
             | Instruction::Generator
             | Instruction::AsyncGenerator => String::new(),
             Instruction::Reserved1
@@ -922,19 +966,7 @@ impl CodeBlock {
             | Instruction::Reserved45
             | Instruction::Reserved46
             | Instruction::Reserved47
-            | Instruction::Reserved48
-            | Instruction::Reserved49
-            | Instruction::Reserved50
-            | Instruction::Reserved51
-            | Instruction::Reserved52
-            | Instruction::Reserved53
-            | Instruction::Reserved54
-            | Instruction::Reserved55
-            | Instruction::Reserved56
-            | Instruction::Reserved57
-            | Instruction::Reserved58
-            | Instruction::Reserved59
-            | Instruction::Reserved60 => unreachable!("Reserved opcodes are unreachable"),
+            | Instruction::Reserved48 => unreachable!("Reserved opcodes are unreachable"),
         }
     }
 }
@@ -367,7 +367,19 @@ impl CodeBlock {
                 | Instruction::CheckReturn
                 | Instruction::BindThisValue { .. }
                 | Instruction::CreateMappedArgumentsObject { .. }
-                | Instruction::CreateUnmappedArgumentsObject { .. } => {
+                | Instruction::CreateUnmappedArgumentsObject { .. }
+                | Instruction::PushDisposeScope
+                | Instruction::AddDisposableResource { .. }
+                | Instruction::AddAsyncDisposableResource { .. }
+                | Instruction::DisposeError { .. }
+                | Instruction::DisposeResources { .. }
+                | Instruction::PopDisposeScope { .. }
+                | Instruction::CreateClassDecoration { .. }
+                | Instruction::PushClassElementDecorators { .. }
+                | Instruction::ApplyClassDecorators { .. }
+                | Instruction::InitializeDecoratedStaticElement { .. }
+                | Instruction::RunClassExtraInitializers { .. }
+                | Instruction::Debugger => {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
                 }
@@ -421,19 +433,7 @@ impl CodeBlock {
                 | Instruction::Reserved45
                 | Instruction::Reserved46
                 | Instruction::Reserved47
-                | Instruction::Reserved48
-                | Instruction::Reserved49
-                | Instruction::Reserved50
-                | Instruction::Reserved51
-                | Instruction::Reserved52
-                | Instruction::Reserved53
-                | Instruction::Reserved54
-                | Instruction::Reserved55
-                | Instruction::Reserved56
-                | Instruction::Reserved57
-                | Instruction::Reserved58
-                | Instruction::Reserved59
-                | Instruction::Reserved60 => unreachable!("Reserved opcodes are unreachable"),
+                | Instruction::Reserved48 => unreachable!("Reserved opcodes are unreachable"),
             }
         }
 
@@ -7,8 +7,9 @@
 use crate::{
     Context, JsError, JsExpect, JsNativeError, JsObject, JsResult, JsString, JsValue, Module,
     builtins::promise::{PromiseCapability, ResolvingFunctions},
+    context::InterruptHandle,
     environments::EnvironmentStack,
-    error::RuntimeLimitError,
+    error::{EngineError, RuntimeLimitError},
     object::JsFunction,
     realm::Realm,
     script::Script,
@@ -38,6 +39,7 @@ pub(crate) use {
 
 pub use runtime_limits::RuntimeLimits;
 pub use {
+    bytecode_cache::BytecodeCacheError,
     call_frame::{CallFrame, GeneratorResumeKind},
     code_block::CodeBlock,
     source_info::{NativeSourceInfo, SourcePath},
@@ -51,6 +53,7 @@ mod completion_record;
 mod inline_cache;
 mod runtime_limits;
 
+pub(crate) mod bytecode_cache;
 pub(crate) mod opcode;
 pub(crate) mod shadow_stack;
 pub(crate) mod source_info;
@@ -85,6 +88,12 @@ pub struct Vm {
     pub(crate) pending_exception: Option<JsError>,
     pub(crate) runtime_limits: RuntimeLimits,
 
+    /// Number of instructions executed since the last refuel.
+    pub(crate) instructions_executed: u64,
+
+    /// Handle used by other threads to interrupt the execution.
+    pub(crate) interrupt_handle: InterruptHandle,
+
     /// This is used to assign a native (rust) function as the active function,
     /// because we don't push a frame for them.
     pub(crate) native_active_function: Option<JsObject>,
@@ -346,6 +355,8 @@ impl Vm {
             return_value: JsValue::undefined(),
             pending_exception: None,
             runtime_limits: RuntimeLimits::default(),
+            instructions_executed: 0,
+            interrupt_handle: InterruptHandle::default(),
             native_active_function: None,
             host_call_depth: 0,
             shadow_stack: ShadowStack::default(),
@@ -703,15 +714,20 @@ impl Context {
     where
         F: FnOnce(&mut Context, Opcode) -> ControlFlow<CompletionRecord>,
     {
-        #[cfg(feature = "fuzz")]
-        {
-            use crate::error::EngineError;
-            if self.instructions_remaining == 0 {
-                return ControlFlow::Break(CompletionRecord::Throw(
-                    EngineError::NoInstructionsRemain.into(),
-                ));
+        if self.vm.instructions_executed >= self.vm.runtime_limits.instruction_budget() {
+            let host_hooks = self.host_hooks();
+            if !host_hooks.instruction_budget_exhausted(self) {
+                return self.handle_error(EngineError::NoInstructionsRemain.into());
+            }
+            self.vm.instructions_executed = 0;
+        }
+        self.vm.instructions_executed += 1;
+
+        if self.vm.interrupt_handle.take() {
+            let host_hooks = self.host_hooks();
+            if !host_hooks.interrupt_requested(self) {
+                return self.handle_error(EngineError::Interrupted.into());
             }
-            self.instructions_remaining -= 1;
         }
 
         #[cfg(feature = "trace")]
@@ -942,6 +958,8 @@ impl Context {
         if self.vm.runtime_limits.stack_size_limit() <= self.vm.stack.stack.len() {
             return Err(RuntimeLimitError::StackSize.into());
         }
+        // Must throw if the heap went over its limit and a collection couldn't free enough memory.
+        boa_gc::check_heap_limit(0)?;
 
         Ok(())
     }
@@ -341,7 +341,7 @@ fn parse_import_attributes(
 /// [load]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
 /// [finish]: https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
 /// [continue]: https://tc39.es/ecma262/#sec-ContinueDynamicImport
-async fn load_dyn_import(
+pub(crate) async fn load_dyn_import(
     referrer: Referrer,
     request: ModuleRequest,
     cap: PromiseCapability,
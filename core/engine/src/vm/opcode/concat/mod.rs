@@ -1,5 +1,5 @@
 use super::RegisterOperand;
-use crate::{Context, JsResult, JsString, vm::opcode::Operation};
+use crate::{Context, JsResult, JsString, builtins::String, vm::opcode::Operation};
 use thin_vec::ThinVec;
 
 /// `ConcatToString` implements the Opcode Operation for `Opcode::ConcatToString`
@@ -21,7 +21,8 @@ impl ConcatToString {
             let val = context.vm.get_register(value.into()).clone();
             strings.push(val.to_string(context)?);
         }
-        let s = JsString::concat_array(&strings.iter().map(JsString::as_str).collect::<Vec<_>>());
+        String::check_allocation(strings.iter().map(JsString::len).sum())?;
+        let s = JsString::concat_rope_array(&strings);
         context.vm.set_register(string.into(), s.into());
         Ok(())
     }
@@ -0,0 +1,22 @@
+use crate::{Context, JsResult, vm::opcode::Operation};
+
+/// `Debugger` implements the Opcode Operation for `Opcode::Debugger`
+///
+/// Operation:
+///  - Invoke the debugger hook of the host with the current call frame.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Debugger;
+
+impl Debugger {
+    #[inline(always)]
+    pub(super) fn operation((): (), context: &mut Context) -> JsResult<()> {
+        let frame = context.vm.frame().clone();
+        context.host_hooks().on_debugger_statement(&frame, context)
+    }
+}
+
+impl Operation for Debugger {
+    const NAME: &'static str = "Debugger";
+    const INSTRUCTION: &'static str = "INST - Debugger";
+    const COST: u8 = 1;
+}
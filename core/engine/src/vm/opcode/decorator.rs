@@ -0,0 +1,170 @@
+use crate::{
+    Context, JsExpect, JsResult,
+    builtins::function::decorator::{ClassDecoration, ClassElementFlags},
+    vm::opcode::{IndexOperand, Operation, RegisterOperand},
+};
+
+/// `CreateClassDecoration` implements the Opcode Operation for `Opcode::CreateClassDecoration`
+///
+/// Operation:
+///  - Create the decoration state of a class definition.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CreateClassDecoration;
+
+impl CreateClassDecoration {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (dst, class, prototype, decorators): (
+            RegisterOperand,
+            RegisterOperand,
+            RegisterOperand,
+            RegisterOperand,
+        ),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let class = context.vm.get_register(class.into()).clone();
+        let prototype = context.vm.get_register(prototype.into()).clone();
+        let decorators = context.vm.get_register(decorators.into()).clone();
+        let class = class.as_object().js_expect("class must be an object")?;
+        let prototype = prototype
+            .as_object()
+            .js_expect("class prototype must be an object")?;
+
+        let state = ClassDecoration::create(class, prototype, &decorators, context)?;
+        context.vm.set_register(dst.into(), state.into());
+        Ok(())
+    }
+}
+
+impl Operation for CreateClassDecoration {
+    const NAME: &'static str = "CreateClassDecoration";
+    const INSTRUCTION: &'static str = "INST - CreateClassDecoration";
+    const COST: u8 = 4;
+}
+
+/// `PushClassElementDecorators` implements the Opcode Operation for `Opcode::PushClassElementDecorators`
+///
+/// Operation:
+///  - Record a class element that must be decorated.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushClassElementDecorators;
+
+impl PushClassElementDecorators {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (state, key, value, decorators, storage, flags): (
+            RegisterOperand,
+            RegisterOperand,
+            RegisterOperand,
+            RegisterOperand,
+            RegisterOperand,
+            IndexOperand,
+        ),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let state = context.vm.get_register(state.into()).clone();
+        let key = context.vm.get_register(key.into()).clone();
+        let value = context.vm.get_register(value.into()).clone();
+        let decorators = context.vm.get_register(decorators.into()).clone();
+        let storage = context.vm.get_register(storage.into()).as_string();
+        let flags = ClassElementFlags::from_bits_truncate(flags.into());
+        let state = state
+            .as_object()
+            .js_expect("class decoration state must be an object")?;
+
+        ClassDecoration::push_element(&state, &key, &value, &decorators, storage, flags, context)
+    }
+}
+
+impl Operation for PushClassElementDecorators {
+    const NAME: &'static str = "PushClassElementDecorators";
+    const INSTRUCTION: &'static str = "INST - PushClassElementDecorators";
+    const COST: u8 = 6;
+}
+
+/// `ApplyClassDecorators` implements the Opcode Operation for `Opcode::ApplyClassDecorators`
+///
+/// Operation:
+///  - Apply the decorators of the elements and of the class.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ApplyClassDecorators;
+
+impl ApplyClassDecorators {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (dst, state): (RegisterOperand, RegisterOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let state = context.vm.get_register(state.into()).clone();
+        let state = state
+            .as_object()
+            .js_expect("class decoration state must be an object")?;
+
+        let class = ClassDecoration::apply(&state, context)?;
+        context.vm.set_register(dst.into(), class);
+        Ok(())
+    }
+}
+
+impl Operation for ApplyClassDecorators {
+    const NAME: &'static str = "ApplyClassDecorators";
+    const INSTRUCTION: &'static str = "INST - ApplyClassDecorators";
+    const COST: u8 = 8;
+}
+
+/// `InitializeDecoratedStaticElement` implements the Opcode Operation for `Opcode::InitializeDecoratedStaticElement`
+///
+/// Operation:
+///  - Initialize a decorated static field or a static auto-accessor on the class.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct InitializeDecoratedStaticElement;
+
+impl InitializeDecoratedStaticElement {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (state, index): (RegisterOperand, IndexOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let state = context.vm.get_register(state.into()).clone();
+        let state = state
+            .as_object()
+            .js_expect("class decoration state must be an object")?;
+
+        ClassDecoration::initialize_static_element(&state, usize::from(index), context)
+    }
+}
+
+impl Operation for InitializeDecoratedStaticElement {
+    const NAME: &'static str = "InitializeDecoratedStaticElement";
+    const INSTRUCTION: &'static str = "INST - InitializeDecoratedStaticElement";
+    const COST: u8 = 4;
+}
+
+/// `RunClassExtraInitializers` implements the Opcode Operation for `Opcode::RunClassExtraInitializers`
+///
+/// Operation:
+///  - Run the extra initializers added by the class decorators.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RunClassExtraInitializers;
+
+impl RunClassExtraInitializers {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (state, class): (RegisterOperand, RegisterOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let state = context.vm.get_register(state.into()).clone();
+        let class = context.vm.get_register(class.into()).clone();
+        let state = state
+            .as_object()
+            .js_expect("class decoration state must be an object")?;
+
+        ClassDecoration::run_class_extra_initializers(&state, &class, context)
+    }
+}
+
+impl Operation for RunClassExtraInitializers {
+    const NAME: &'static str = "RunClassExtraInitializers";
+    const INSTRUCTION: &'static str = "INST - RunClassExtraInitializers";
+    const COST: u8 = 4;
+}
@@ -0,0 +1,200 @@
+use crate::{
+    Context, JsExpect, JsResult,
+    builtins::disposable_stack::{DisposableResource, DisposeCapability, DisposeHint},
+    vm::opcode::{Operation, RegisterOperand},
+};
+
+/// `PushDisposeScope` implements the Opcode Operation for `Opcode::PushDisposeScope`
+///
+/// Operation:
+///  - Push a new dispose capability on the disposal stack.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushDisposeScope;
+
+impl PushDisposeScope {
+    #[inline(always)]
+    pub(super) fn operation((): (), context: &mut Context) {
+        context
+            .vm
+            .frame_mut()
+            .disposal_stack
+            .push(DisposeCapability::new());
+    }
+}
+
+impl Operation for PushDisposeScope {
+    const NAME: &'static str = "PushDisposeScope";
+    const INSTRUCTION: &'static str = "INST - PushDisposeScope";
+    const COST: u8 = 1;
+}
+
+/// Adds `value` to the current dispose capability, with the given hint.
+fn add_disposable_resource(
+    value: RegisterOperand,
+    hint: DisposeHint,
+    context: &mut Context,
+) -> JsResult<()> {
+    let value = context.vm.get_register(value.into()).clone();
+    let Some(resource) = DisposableResource::from_value(&value, hint, context)? else {
+        return Ok(());
+    };
+
+    context
+        .vm
+        .frame_mut()
+        .disposal_stack
+        .last_mut()
+        .js_expect("disposal stack should have at least a dispose capability")?
+        .add_resource(resource);
+
+    Ok(())
+}
+
+/// `AddDisposableResource` implements the Opcode Operation for `Opcode::AddDisposableResource`
+///
+/// Operation:
+///  - Add the value of a `using` declaration to the current dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddDisposableResource;
+
+impl AddDisposableResource {
+    #[inline(always)]
+    pub(crate) fn operation(value: RegisterOperand, context: &mut Context) -> JsResult<()> {
+        add_disposable_resource(value, DisposeHint::Sync, context)
+    }
+}
+
+impl Operation for AddDisposableResource {
+    const NAME: &'static str = "AddDisposableResource";
+    const INSTRUCTION: &'static str = "INST - AddDisposableResource";
+    const COST: u8 = 3;
+}
+
+/// `AddAsyncDisposableResource` implements the Opcode Operation for `Opcode::AddAsyncDisposableResource`
+///
+/// Operation:
+///  - Add the value of an `await using` declaration to the current dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddAsyncDisposableResource;
+
+impl AddAsyncDisposableResource {
+    #[inline(always)]
+    pub(crate) fn operation(value: RegisterOperand, context: &mut Context) -> JsResult<()> {
+        add_disposable_resource(value, DisposeHint::Async, context)
+    }
+}
+
+impl Operation for AddAsyncDisposableResource {
+    const NAME: &'static str = "AddAsyncDisposableResource";
+    const INSTRUCTION: &'static str = "INST - AddAsyncDisposableResource";
+    const COST: u8 = 3;
+}
+
+/// `DisposeError` implements the Opcode Operation for `Opcode::DisposeError`
+///
+/// Operation:
+///  - Record a thrown error in the current dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeError;
+
+impl DisposeError {
+    #[inline(always)]
+    pub(crate) fn operation(error: RegisterOperand, context: &mut Context) -> JsResult<()> {
+        let error = context.vm.get_register(error.into()).clone();
+        let mut capability = context
+            .vm
+            .frame_mut()
+            .disposal_stack
+            .pop()
+            .js_expect("disposal stack should have at least a dispose capability")?;
+
+        capability.record_error(error, context);
+
+        context.vm.frame_mut().disposal_stack.push(capability);
+        Ok(())
+    }
+}
+
+impl Operation for DisposeError {
+    const NAME: &'static str = "DisposeError";
+    const INSTRUCTION: &'static str = "INST - DisposeError";
+    const COST: u8 = 2;
+}
+
+/// `DisposeResources` implements the Opcode Operation for `Opcode::DisposeResources`
+///
+/// Operation:
+///  - Dispose the resources of the current dispose capability, until one of them needs to be awaited.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeResources;
+
+impl DisposeResources {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (value, done): (RegisterOperand, RegisterOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // The capability is taken out of the frame, since disposing resources calls into user code.
+        let mut capability = context
+            .vm
+            .frame_mut()
+            .disposal_stack
+            .pop()
+            .js_expect("disposal stack should have at least a dispose capability")?;
+
+        let result = capability.dispose_resources(context);
+
+        context.vm.frame_mut().disposal_stack.push(capability);
+
+        let result = result?;
+        context
+            .vm
+            .set_register(done.into(), result.is_none().into());
+        context
+            .vm
+            .set_register(value.into(), result.unwrap_or_default());
+        Ok(())
+    }
+}
+
+impl Operation for DisposeResources {
+    const NAME: &'static str = "DisposeResources";
+    const INSTRUCTION: &'static str = "INST - DisposeResources";
+    const COST: u8 = 6;
+}
+
+/// `PopDisposeScope` implements the Opcode Operation for `Opcode::PopDisposeScope`
+///
+/// Operation:
+///  - Pop the current dispose capability from the disposal stack, and get its recorded error.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PopDisposeScope;
+
+impl PopDisposeScope {
+    #[inline(always)]
+    pub(crate) fn operation(
+        (has_error, error): (RegisterOperand, RegisterOperand),
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let capability = context
+            .vm
+            .frame_mut()
+            .disposal_stack
+            .pop()
+            .js_expect("disposal stack should have at least a dispose capability")?;
+
+        if let Some(value) = capability.completion() {
+            context.vm.set_register(has_error.into(), true.into());
+            context.vm.set_register(error.into(), value.clone());
+        } else {
+            context.vm.set_register(has_error.into(), false.into());
+        }
+        Ok(())
+    }
+}
+
+impl Operation for PopDisposeScope {
+    const NAME: &'static str = "PopDisposeScope";
+    const INSTRUCTION: &'static str = "INST - PopDisposeScope";
+    const COST: u8 = 2;
+}
@@ -20,6 +20,9 @@ impl IncrementLoopIteration {
         }
 
         frame.loop_iteration_count = previous_iteration_count.wrapping_add(1);
+
+        // Loops are the safe points where the script can notice an exceeded heap limit.
+        boa_gc::check_heap_limit(0)?;
         Ok(())
     }
 }
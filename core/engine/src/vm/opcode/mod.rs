@@ -18,8 +18,11 @@ mod call;
 mod concat;
 mod control_flow;
 mod copy;
+mod debugger;
+mod decorator;
 mod define;
 mod delete;
+mod dispose;
 mod environment;
 mod function;
 mod generator;
@@ -55,10 +58,16 @@ pub(crate) use control_flow::*;
 #[doc(inline)]
 pub(crate) use copy::*;
 #[doc(inline)]
+pub(crate) use debugger::*;
+#[doc(inline)]
+pub(crate) use decorator::*;
+#[doc(inline)]
 pub(crate) use define::*;
 #[doc(inline)]
 pub(crate) use delete::*;
 #[doc(inline)]
+pub(crate) use dispose::*;
+#[doc(inline)]
 pub(crate) use environment::*;
 #[doc(inline)]
 pub(crate) use function::*;
@@ -372,6 +381,14 @@ macro_rules! generate_opcodes {
         }
 
         impl Opcode {
+            /// A description of every opcode and the types of its operands, used to detect
+            /// bytecode that was compiled by an incompatible engine.
+            pub(crate) const SIGNATURE: &'static str = concat!(
+                $(
+                    stringify!($Variant $({ $($FieldName: $FieldType),* })?), ";",
+                )*
+            );
+
             pub(crate) fn as_str(&self) -> &'static str {
                 match self {
                     $(Self::$Variant => $Variant::NAME),*
@@ -2139,6 +2156,110 @@ generate_opcodes! {
     ///   - Output: dst
     CreateUnmappedArgumentsObject { dst: RegisterOperand },
 
+    /// Push a new dispose capability on the disposal stack, for a block that contains
+    /// `using` or `await using` declarations.
+    ///
+    /// - Disposal Stack: **=>** `capability`
+    PushDisposeScope,
+
+    /// Add the value of a `using` declaration to the current dispose capability.
+    ///
+    /// Performs [`AddDisposableResource ( disposeCapability, V, sync-dispose )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    ///
+    /// - Registers:
+    ///   - Input: value
+    AddDisposableResource { value: RegisterOperand },
+
+    /// Add the value of an `await using` declaration to the current dispose capability.
+    ///
+    /// Performs [`AddDisposableResource ( disposeCapability, V, async-dispose )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+    ///
+    /// - Registers:
+    ///   - Input: value
+    AddAsyncDisposableResource { value: RegisterOperand },
+
+    /// Record a thrown error in the current dispose capability.
+    ///
+    /// If an error was already recorded, both errors are aggregated in a new `SuppressedError`.
+    ///
+    /// - Registers:
+    ///   - Input: error
+    DisposeError { error: RegisterOperand },
+
+    /// Dispose the resources of the current dispose capability, until one of them
+    /// needs to be awaited.
+    ///
+    /// Performs [`DisposeResources ( disposeCapability, completion )`][spec].
+    ///
+    /// If `done` is `false`, `value` must be awaited before running this instruction again.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    ///
+    /// - Registers:
+    ///   - Output: value, done
+    DisposeResources { value: RegisterOperand, done: RegisterOperand },
+
+    /// Pop the current dispose capability from the disposal stack, storing `true` in `has_error`
+    /// and the recorded error in `error` if disposing threw an error, and `false` otherwise.
+    ///
+    /// - Registers:
+    ///   - Output: has_error, error
+    /// - Disposal Stack: `capability` **=>**
+    PopDisposeScope { has_error: RegisterOperand, error: RegisterOperand },
+
+    /// Create the decoration state of a class definition, with the evaluated class decorators.
+    ///
+    /// Decorators are stored in an array as pairs of `this` values and decorator functions.
+    ///
+    /// - Registers:
+    ///   - Input: class, prototype, decorators
+    ///   - Output: dst
+    CreateClassDecoration { dst: RegisterOperand, class: RegisterOperand, prototype: RegisterOperand, decorators: RegisterOperand },
+
+    /// Record a class element that must be decorated, or an auto-accessor.
+    ///
+    /// `key` is the property key of a public element or the description of a private name, `value`
+    /// is the method or the field initializer of the element and `storage` is the description of
+    /// the private name backing an auto-accessor, or `undefined`.
+    ///
+    /// - Operands:
+    ///   - flags: `IndexOperand` (see `ClassElementFlags`)
+    /// - Registers:
+    ///   - Input: state, key, value, decorators, storage
+    PushClassElementDecorators { state: RegisterOperand, key: RegisterOperand, value: RegisterOperand, decorators: RegisterOperand, storage: RegisterOperand, flags: IndexOperand },
+
+    /// Apply the decorators of the elements and of the class, storing the decorated class in dst.
+    ///
+    /// - Registers:
+    ///   - Input: state
+    ///   - Output: dst
+    ApplyClassDecorators { dst: RegisterOperand, state: RegisterOperand },
+
+    /// Initialize a decorated static field or a static auto-accessor on the class.
+    ///
+    /// - Operands:
+    ///   - index: `IndexOperand`
+    /// - Registers:
+    ///   - Input: state
+    InitializeDecoratedStaticElement { state: RegisterOperand, index: IndexOperand },
+
+    /// Run the extra initializers added by the class decorators.
+    ///
+    /// - Registers:
+    ///   - Input: state, class
+    RunClassExtraInitializers { state: RegisterOperand, class: RegisterOperand },
+
+    /// Invoke the debugger hook of the host.
+    ///
+    /// Emitted for `debugger` statements, see [`HostHooks::on_debugger_statement`].
+    ///
+    /// [`HostHooks::on_debugger_statement`]: crate::context::HostHooks::on_debugger_statement
+    Debugger,
+
     /// Reserved [`Opcode`].
     Reserved1 => Reserved,
     /// Reserved [`Opcode`].
@@ -2235,28 +2356,4 @@ generate_opcodes! {
     Reserved47 => Reserved,
     /// Reserved [`Opcode`].
     Reserved48 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved49 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved50 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved51 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved52 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved53 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved54 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved55 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved56 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved57 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved58 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved59 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved60 => Reserved,
 }
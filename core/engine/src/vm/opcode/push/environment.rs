@@ -20,9 +20,7 @@ impl PushScope {
         let scope = context.vm.frame().code_block().constant_scope(index.into());
         let frame = context.vm.frame_mut();
         let global = frame.realm.environment();
-        frame
-            .environments
-            .push_lexical(scope.num_bindings_non_local(), global);
+        frame.environments.push_lexical(scope, global);
     }
 }
 
@@ -80,8 +78,7 @@ impl PushPrivateEnvironment {
             names.push(name);
         }
 
-        let ptr: *const _ = class.as_ref();
-        let environment = Gc::new(PrivateEnvironment::new(ptr.cast::<()>() as usize, names));
+        let environment = Gc::new(PrivateEnvironment::new(class.private_name_id(), names));
 
         class
             .downcast_mut::<OrdinaryFunction>()
@@ -12,6 +12,9 @@ pub struct RuntimeLimits {
 
     /// Max function recursion limit
     recursion: usize,
+
+    /// Max instructions executed before the budget is exhausted.
+    instruction_budget: u64,
 }
 
 impl Default for RuntimeLimits {
@@ -22,6 +25,7 @@ impl Default for RuntimeLimits {
             recursion: 512,
             backtrace_limit: 50,
             stack_size: 1024 * 10,
+            instruction_budget: u64::MAX,
         }
     }
 }
@@ -94,4 +98,37 @@ impl RuntimeLimits {
     pub fn set_recursion_limit(&mut self, value: usize) {
         self.recursion = value;
     }
+
+    /// Get the instruction budget.
+    ///
+    /// The budget is the number of instructions that a [`Context`][crate::Context] can execute
+    /// before it has to be refueled with [`Context::refuel`][crate::Context::refuel].
+    ///
+    /// The budget value [`u64::MAX`] means that there is no limit.
+    #[inline]
+    #[must_use]
+    pub const fn instruction_budget(&self) -> u64 {
+        self.instruction_budget
+    }
+
+    /// Set the instruction budget.
+    ///
+    /// Once the budget is exhausted, the [`HostHooks::instruction_budget_exhausted`] hook is
+    /// called, which can either refuel the context to continue the execution, or terminate it
+    /// with an uncatchable [`EngineError::NoInstructionsRemain`] error.
+    ///
+    /// Setting the budget to [`u64::MAX`] means that there is no limit.
+    ///
+    /// [`HostHooks::instruction_budget_exhausted`]: crate::context::HostHooks::instruction_budget_exhausted
+    /// [`EngineError::NoInstructionsRemain`]: crate::error::EngineError::NoInstructionsRemain
+    #[inline]
+    pub fn set_instruction_budget(&mut self, value: u64) {
+        self.instruction_budget = value;
+    }
+
+    /// Disable the instruction budget.
+    #[inline]
+    pub fn disable_instruction_budget(&mut self) {
+        self.instruction_budget = u64::MAX;
+    }
 }
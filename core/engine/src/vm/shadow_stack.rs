@@ -17,6 +17,34 @@ impl Backtrace {
     pub(crate) fn iter(&self) -> impl DoubleEndedIterator<Item = &ShadowEntry> {
         self.stack.iter()
     }
+
+    /// Removes the innermost entry if it belongs to a native function.
+    pub(crate) fn pop_native(&mut self) {
+        if matches!(self.stack.last(), Some(ShadowEntry::Native { .. })) {
+            self.stack.pop();
+        }
+    }
+
+    /// Keeps only the `n` innermost entries.
+    pub(crate) fn truncate(&mut self, n: usize) {
+        let excess = self.stack.len().saturating_sub(n);
+        self.stack.drain(..excess);
+    }
+
+    /// Removes the innermost call of the function with the given `source_info` and every entry
+    /// above it.
+    ///
+    /// If the function is not part of the backtrace, all the entries are removed.
+    pub(crate) fn truncate_before(&mut self, source_info: &SourceInfo) {
+        let index = self
+            .stack
+            .iter()
+            .rposition(|entry| {
+                matches!(entry, ShadowEntry::Bytecode { source_info: info, .. } if info.ptr_eq(source_info))
+            })
+            .unwrap_or_default();
+        self.stack.truncate(index);
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -50,11 +78,16 @@ impl Display for ShadowEntry {
                 }
             }
             ShadowEntry::Bytecode { pc, source_info } => {
-                let path = source_info.map().path();
-                let position = source_info.map().find(*pc);
+                let (path, position) = match source_info.original_location(*pc) {
+                    Some(location) => (location.path.into(), Some(location.position)),
+                    None => (
+                        source_info.map().path().clone(),
+                        source_info.map().find(*pc),
+                    ),
+                };
 
                 if path.is_some() || position.is_some() {
-                    write!(f, " ({}", source_info.map().path())?;
+                    write!(f, " ({path}")?;
 
                     if let Some(position) = position {
                         write!(
@@ -7,10 +7,12 @@ use std::{
 use boa_ast::Position;
 
 mod builder;
+mod source_map_v3;
 
 use boa_gc::{Finalize, Trace};
 use boa_string::JsString;
 pub(crate) use builder::SourceMapBuilder;
+pub(crate) use source_map_v3::{OriginalLocation, SourceMapV3};
 
 use crate::SpannedSourceText;
 
@@ -51,6 +53,18 @@ impl SourceInfo {
     pub(crate) fn text_spanned(&self) -> &SpannedSourceText {
         &self.inner.text_spanned
     }
+
+    /// Maps the position of the instruction at `pc` back to the original source, if the code
+    /// was generated from other sources described by a source map.
+    pub(crate) fn original_location(&self, pc: u32) -> Option<OriginalLocation> {
+        let source_map = self.text_spanned().source_map()?;
+        source_map.lookup(self.map().find(pc)?)
+    }
+
+    /// Returns `true` if both source infos belong to the same code block.
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
 }
 
 #[derive(Debug, Default, Clone)]
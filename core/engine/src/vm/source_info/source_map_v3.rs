@@ -0,0 +1,371 @@
+//! Support for [Source Map v3][spec], used to map positions in generated code (e.g. transpiled or
+//! bundled scripts) back to the original sources and names.
+//!
+//! [spec]: https://tc39.es/ecma426/
+
+use std::{path::Path, rc::Rc};
+
+use arrayvec::ArrayVec;
+use base64::{Engine, prelude::BASE64_STANDARD};
+use boa_ast::Position;
+use serde::Deserialize;
+
+use crate::{
+    JsNativeError, JsResult, JsString,
+    vm::bytecode_cache::{BytecodeCacheError, Reader, Writer},
+};
+
+/// The JSON representation of a source map.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSourceMap {
+    version: u32,
+    #[serde(default)]
+    source_root: Option<String>,
+    #[serde(default)]
+    sources: Vec<Option<String>>,
+    #[serde(default)]
+    names: Vec<String>,
+    #[serde(default)]
+    mappings: String,
+    #[serde(default)]
+    sections: Option<serde_json::Value>,
+}
+
+/// A mapping from a generated column to a position in an original source.
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    column: u32,
+    original: Option<OriginalSegment>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct OriginalSegment {
+    source: u32,
+    line: u32,
+    column: u32,
+    name: Option<u32>,
+}
+
+/// A location in an original source.
+#[derive(Debug, Clone)]
+pub(crate) struct OriginalLocation {
+    /// The path of the original source, if it is known.
+    pub(crate) path: Option<Rc<Path>>,
+
+    /// The position in the original source.
+    pub(crate) position: Position,
+
+    /// The original name of the identifier at this location, if any.
+    pub(crate) name: Option<JsString>,
+}
+
+/// A parsed [Source Map v3][spec].
+///
+/// [spec]: https://tc39.es/ecma426/
+#[derive(Debug, Clone)]
+pub(crate) struct SourceMapV3 {
+    sources: Box<[Option<Rc<Path>>]>,
+    names: Box<[JsString]>,
+    lines: Box<[Box<[Segment]>]>,
+}
+
+impl SourceMapV3 {
+    /// Parses the source map of a script or module, resolving its sources relative to the
+    /// directory of `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if `json` is not a valid source map.
+    pub(crate) fn parse(json: &str, path: Option<&Path>) -> JsResult<Self> {
+        Self::parse_inner(json, path).map_err(|reason| {
+            JsNativeError::syntax()
+                .with_message(format!("invalid source map: {reason}"))
+                .into()
+        })
+    }
+
+    fn parse_inner(json: &str, path: Option<&Path>) -> Result<Self, String> {
+        let raw: RawSourceMap = serde_json::from_str(json).map_err(|err| err.to_string())?;
+
+        if raw.version != 3 {
+            return Err(format!("unsupported version {}", raw.version));
+        }
+        if raw.sections.is_some() {
+            return Err("index maps are not supported".into());
+        }
+
+        let base = path.and_then(Path::parent);
+        let root = raw.source_root.as_deref().unwrap_or_default();
+        let sources = raw
+            .sources
+            .iter()
+            .map(|source| source.as_deref().map(|source| resolve(base, root, source)))
+            .collect::<Box<[_]>>();
+        let names = raw
+            .names
+            .iter()
+            .map(|name| JsString::from(name.as_str()))
+            .collect::<Box<[_]>>();
+        let lines = parse_mappings(&raw.mappings, sources.len(), names.len())?;
+
+        Ok(Self {
+            sources,
+            names,
+            lines,
+        })
+    }
+
+    /// Encodes this source map into a bytecode cache.
+    pub(crate) fn encode(&self, w: &mut Writer) {
+        w.list(&self.sources, |w, source| {
+            w.option(source.as_deref(), Writer::path);
+        });
+        w.list(&self.names, Writer::string);
+        w.list(&self.lines, |w, segments| {
+            w.list(segments, |w, segment| {
+                w.u32(segment.column);
+                w.option(segment.original, |w, original| {
+                    w.u32(original.source);
+                    w.u32(original.line);
+                    w.u32(original.column);
+                    w.option(original.name, Writer::u32);
+                });
+            });
+        });
+    }
+
+    /// Decodes a source map encoded by [`SourceMapV3::encode`].
+    pub(crate) fn decode(r: &mut Reader<'_>) -> Result<Self, BytecodeCacheError> {
+        let sources = r.list(|r| r.option(Reader::path))?.into_boxed_slice();
+        let names = r.list(Reader::string)?.into_boxed_slice();
+        let lines = r
+            .list(|r| {
+                r.list(|r| {
+                    let column = r.u32()?;
+                    let original = r.option(|r| {
+                        let original = OriginalSegment {
+                            source: r.u32()?,
+                            line: r.u32()?,
+                            column: r.u32()?,
+                            name: r.option(Reader::u32)?,
+                        };
+                        if original.source as usize >= sources.len()
+                            || original
+                                .name
+                                .is_some_and(|name| name as usize >= names.len())
+                        {
+                            return Err(BytecodeCacheError::Corrupted);
+                        }
+                        Ok(original)
+                    })?;
+                    Ok(Segment { column, original })
+                })
+                .map(Vec::into_boxed_slice)
+            })?
+            .into_boxed_slice();
+
+        Ok(Self {
+            sources,
+            names,
+            lines,
+        })
+    }
+
+    /// Gets the source map referenced by a `//# sourceMappingURL=data:...` comment at the end of
+    /// the given source code.
+    ///
+    /// Only inline `data:` URLs are supported; any other URL, or a map that cannot be parsed, is
+    /// ignored.
+    pub(crate) fn from_inline_comment(code_points: &[u16], path: Option<&Path>) -> Option<Self> {
+        let url = source_mapping_url(code_points)?;
+        let (metadata, data) = url.strip_prefix("data:")?.split_once(',')?;
+        let metadata = metadata.strip_suffix(";base64")?;
+        if metadata
+            .split(';')
+            .next()
+            .is_none_or(|mime| mime != "application/json")
+        {
+            return None;
+        }
+
+        let json = BASE64_STANDARD.decode(data).ok()?;
+        let json = String::from_utf8(json).ok()?;
+        Self::parse(&json, path).ok()
+    }
+
+    /// Maps a position in the generated code to its location in the original source.
+    pub(crate) fn lookup(&self, position: Position) -> Option<OriginalLocation> {
+        let line = self
+            .lines
+            .get(usize::try_from(position.line_number() - 1).ok()?)?;
+        let column = position.column_number() - 1;
+
+        let index = line.partition_point(|segment| segment.column <= column);
+        let original = line.get(index.checked_sub(1)?)?.original?;
+
+        Some(OriginalLocation {
+            path: self.sources[original.source as usize].clone(),
+            position: Position::new(
+                original.line.checked_add(1)?,
+                original.column.checked_add(1)?,
+            ),
+            name: original.name.map(|name| self.names[name as usize].clone()),
+        })
+    }
+}
+
+/// Resolves a source of a source map against its `sourceRoot` and the directory of the script.
+fn resolve(base: Option<&Path>, root: &str, source: &str) -> Rc<Path> {
+    let source = if root.is_empty() {
+        source.to_owned()
+    } else if root.ends_with('/') {
+        format!("{root}{source}")
+    } else {
+        format!("{root}/{source}")
+    };
+    let source = source.strip_prefix("file://").unwrap_or(&source);
+
+    match base {
+        Some(base) if !source.contains("://") && Path::new(source).is_relative() => {
+            base.join(source).into()
+        }
+        _ => Path::new(source).into(),
+    }
+}
+
+/// Finds the URL of the last `//# sourceMappingURL=` comment in the trailing comments of the code.
+fn source_mapping_url(code_points: &[u16]) -> Option<String> {
+    for line in code_points.rsplit(|&cu| cu == u16::from(b'\n')) {
+        let line = String::from_utf16_lossy(line);
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let comment = line.strip_prefix("//")?;
+        if let Some(url) = comment
+            .strip_prefix("# sourceMappingURL=")
+            .or_else(|| comment.strip_prefix("@ sourceMappingURL="))
+        {
+            return Some(url.trim().to_owned());
+        }
+    }
+
+    None
+}
+
+/// Decodes the `mappings` field of a source map into the segments of each generated line.
+fn parse_mappings(
+    mappings: &str,
+    sources: usize,
+    names: usize,
+) -> Result<Box<[Box<[Segment]>]>, String> {
+    let index = |value: i64, len: usize, kind: &str| {
+        u32::try_from(value)
+            .ok()
+            .filter(|&index| (index as usize) < len)
+            .ok_or_else(|| format!("{kind} index {value} out of bounds"))
+    };
+
+    // Original positions are 0-based and returned 1-based, so `u32::MAX` is out of range.
+    let position = |value: i64, kind: &str| {
+        u32::try_from(value)
+            .ok()
+            .filter(|&value| value < u32::MAX)
+            .ok_or_else(|| format!("original {kind} {value} out of range"))
+    };
+
+    let mut source = 0i64;
+    let mut line = 0i64;
+    let mut column = 0i64;
+    let mut name = 0i64;
+
+    let mut lines = Vec::new();
+    for encoded_line in mappings.split(';') {
+        let mut generated_column = 0i64;
+        let mut segments = Vec::new();
+
+        for encoded in encoded_line
+            .split(',')
+            .filter(|segment| !segment.is_empty())
+        {
+            let fields =
+                decode_vlq(encoded).ok_or_else(|| format!("invalid VLQ segment `{encoded}`"))?;
+
+            generated_column += fields[0];
+            let original = match fields.len() {
+                1 => None,
+                4 | 5 => {
+                    source += fields[1];
+                    line += fields[2];
+                    column += fields[3];
+                    let name = if let Some(delta) = fields.get(4) {
+                        name += delta;
+                        Some(index(name, names, "name")?)
+                    } else {
+                        None
+                    };
+
+                    Some(OriginalSegment {
+                        source: index(source, sources, "source")?,
+                        line: position(line, "line")?,
+                        column: position(column, "column")?,
+                        name,
+                    })
+                }
+                len => return Err(format!("segment `{encoded}` has {len} fields")),
+            };
+
+            segments.push(Segment {
+                column: u32::try_from(generated_column).map_err(|_| "negative generated column")?,
+                original,
+            });
+        }
+
+        segments.sort_by_key(|segment| segment.column);
+        lines.push(segments.into_boxed_slice());
+    }
+
+    Ok(lines.into_boxed_slice())
+}
+
+/// Decodes the base64 VLQ fields of a single segment.
+fn decode_vlq(encoded: &str) -> Option<ArrayVec<i64, 5>> {
+    let mut fields = ArrayVec::new();
+    let mut value = 0i64;
+    let mut shift = 0;
+
+    for byte in encoded.bytes() {
+        let digit = i64::from(match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        });
+
+        if shift > 31 {
+            return None;
+        }
+        value += (digit & 0b1_1111) << shift;
+
+        if digit & 0b10_0000 == 0 {
+            let magnitude = value >> 1;
+            fields
+                .try_push(if value & 1 == 1 {
+                    -magnitude
+                } else {
+                    magnitude
+                })
+                .ok()?;
+            value = 0;
+            shift = 0;
+        } else {
+            shift += 5;
+        }
+    }
+
+    (shift == 0 && !fields.is_empty()).then_some(fields)
+}
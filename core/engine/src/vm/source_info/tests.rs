@@ -1,6 +1,8 @@
-use crate::vm::source_info::find_entry;
+use std::path::Path;
 
-use super::Entry;
+use crate::{js_string, vm::source_info::find_entry};
+
+use super::{Entry, SourceMapV3};
 
 #[test]
 fn find_empty() {
@@ -171,3 +173,87 @@ fn find_with_single_entry() {
     assert_eq!(find_entry(entries, 33), None);
     assert_eq!(find_entry(entries, u32::MAX), None);
 }
+
+#[test]
+fn source_map_lookup() {
+    let source_map = SourceMapV3::parse(
+        r#"{
+            "version": 3,
+            "sourceRoot": "src/",
+            "sources": ["app.ts"],
+            "names": ["inner", "outer"],
+            "mappings": "SAASA,WACE;;SAEFC"
+        }"#,
+        None,
+    )
+    .unwrap();
+
+    assert!(source_map.lookup((1, 1).into()).is_none());
+
+    let location = source_map.lookup((1, 10).into()).unwrap();
+    assert_eq!(location.path.as_deref(), Some(Path::new("src/app.ts")));
+    assert_eq!(location.position, (1, 10).into());
+    assert_eq!(location.name, Some(js_string!("inner")));
+
+    let location = source_map.lookup((1, 30).into()).unwrap();
+    assert_eq!(location.position, (2, 12).into());
+    assert_eq!(location.name, None);
+
+    assert!(source_map.lookup((2, 1).into()).is_none());
+
+    let location = source_map.lookup((3, 10).into()).unwrap();
+    assert_eq!(location.position, (4, 10).into());
+    assert_eq!(location.name, Some(js_string!("outer")));
+
+    assert!(source_map.lookup((4, 1).into()).is_none());
+}
+
+#[test]
+fn source_map_position_out_of_range() {
+    let parse = |mappings: &str| {
+        SourceMapV3::parse(
+            &format!(r#"{{"version": 3, "sources": ["a.ts"], "mappings": "{mappings}"}}"#),
+            None,
+        )
+    };
+
+    // Original line `u32::MAX - 1`, the last one that can be returned.
+    let source_map = parse("AA8/////HA").unwrap();
+    let location = source_map.lookup((1, 1).into()).unwrap();
+    assert_eq!(location.position, (u32::MAX, 1).into());
+
+    // Original line and column `u32::MAX`.
+    assert!(parse("AA+/////HA").is_err());
+    assert!(parse("AAA+/////H").is_err());
+}
+
+#[test]
+fn source_map_inline_comment() {
+    let code = |code: &str| code.encode_utf16().collect::<Vec<_>>();
+
+    // {"version":3,"sources":["a.ts"],"mappings":"AAAA"}
+    let source_map = SourceMapV3::from_inline_comment(
+        &code(
+            "x;\n//# sourceMappingURL=data:application/json;base64,\
+             eyJ2ZXJzaW9uIjozLCJzb3VyY2VzIjpbImEudHMiXSwibWFwcGluZ3MiOiJBQUFBIn0=\n\n",
+        ),
+        Some(Path::new("dist/a.js")),
+    )
+    .unwrap();
+    let location = source_map.lookup((1, 1).into()).unwrap();
+    assert_eq!(
+        location.path.as_deref(),
+        Some(Path::new("dist").join("a.ts").as_path())
+    );
+
+    assert!(
+        SourceMapV3::from_inline_comment(&code("//# sourceMappingURL=a.js.map"), None).is_none()
+    );
+    assert!(
+        SourceMapV3::from_inline_comment(
+            &code("//# sourceMappingURL=data:application/json;base64,e30=\nx;"),
+            None
+        )
+        .is_none()
+    );
+}
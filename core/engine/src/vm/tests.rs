@@ -1,15 +1,17 @@
-use crate::error::RuntimeLimitError;
+use crate::context::HostHooks;
+use crate::error::{EngineError, RuntimeLimitError};
 use crate::vm::CallFrame;
 use crate::vm::call_frame::CallFrameLocation;
 use crate::vm::source_info::SourcePath;
 use crate::{
-    Context, JsNativeErrorKind, JsValue, NativeFunction, TestAction, js_string,
+    Context, JsNativeErrorKind, JsString, JsValue, NativeFunction, TestAction, js_string,
     property::Attribute, run_test_actions, run_test_actions_with,
 };
 use boa_ast::Position;
 use boa_macros::js_str;
 use boa_parser::Source;
 use indoc::indoc;
+use std::{cell::Cell, rc::Rc};
 
 #[test]
 fn typeof_string() {
@@ -375,6 +377,330 @@ fn recursion_runtime_limit() {
     ]);
 }
 
+#[test]
+fn instruction_budget() {
+    run_test_actions([
+        TestAction::assert_context(|context| context.instructions_remaining() == u64::MAX),
+        TestAction::inspect_context(|context| {
+            context.runtime_limits_mut().set_instruction_budget(10_000);
+        }),
+        TestAction::assert_eq("for (let i = 0; i < 100; ++i) { }", JsValue::undefined()),
+        TestAction::assert_context(|context| context.instructions_remaining() < 10_000),
+        TestAction::assert_engine_error(
+            indoc! {r#"
+                try {
+                    while (true) { }
+                } catch {
+                }
+            "#},
+            EngineError::NoInstructionsRemain,
+        ),
+        TestAction::assert_context(|context| context.instructions_remaining() == 0),
+        TestAction::assert_engine_error("1 + 1", EngineError::NoInstructionsRemain),
+        TestAction::inspect_context(Context::refuel),
+        TestAction::assert_eq("1 + 1", 2),
+    ]);
+}
+
+#[test]
+fn instruction_budget_exhausted_hook() {
+    struct Hooks {
+        calls: Cell<u32>,
+    }
+
+    impl HostHooks for Hooks {
+        fn instruction_budget_exhausted(&self, _context: &mut Context) -> bool {
+            self.calls.set(self.calls.get() + 1);
+            self.calls.get() <= 3
+        }
+    }
+
+    let hooks = Rc::new(Hooks {
+        calls: Cell::new(0),
+    });
+    let context = &mut Context::builder()
+        .host_hooks(hooks.clone())
+        .instruction_budget(1_000)
+        .build()
+        .unwrap();
+
+    let result = context.eval(Source::from_bytes(indoc! {r#"
+        var iterations = 0;
+        while (true) {
+            iterations++;
+        }
+    "#}));
+    assert_eq!(
+        result.unwrap_err().as_engine(),
+        Some(&EngineError::NoInstructionsRemain)
+    );
+    assert_eq!(hooks.calls.get(), 4);
+
+    // The context stays exhausted until it is refueled.
+    let result = context.eval(Source::from_bytes("iterations"));
+    assert_eq!(
+        result.unwrap_err().as_engine(),
+        Some(&EngineError::NoInstructionsRemain)
+    );
+
+    context.refuel();
+    let iterations = context.eval(Source::from_bytes("iterations")).unwrap();
+    assert!(iterations.as_number().unwrap() > 0.0);
+}
+
+#[test]
+fn interrupt_handle() {
+    let context = &mut Context::default();
+    let handle = context.interrupt_handle();
+
+    handle.interrupt();
+    assert!(handle.is_requested());
+    let result = context.eval(Source::from_bytes("try { 1 } catch { 2 }"));
+    assert_eq!(
+        result.unwrap_err().as_engine(),
+        Some(&EngineError::Interrupted)
+    );
+    assert!(!handle.is_requested());
+    assert_eq!(context.eval(Source::from_bytes("1")), Ok(JsValue::new(1)));
+
+    handle.interrupt();
+    handle.cancel();
+    assert_eq!(context.eval(Source::from_bytes("1")), Ok(JsValue::new(1)));
+
+    let thread_handle = handle.clone();
+    std::thread::spawn(move || thread_handle.interrupt())
+        .join()
+        .unwrap();
+    let result = context.eval(Source::from_bytes("while (true) { }"));
+    assert_eq!(
+        result.unwrap_err().as_engine(),
+        Some(&EngineError::Interrupted)
+    );
+}
+
+#[test]
+fn interrupt_requested_hook() {
+    struct Hooks {
+        calls: Cell<u32>,
+    }
+
+    impl HostHooks for Hooks {
+        fn interrupt_requested(&self, _context: &mut Context) -> bool {
+            self.calls.set(self.calls.get() + 1);
+            true
+        }
+    }
+
+    let hooks = Rc::new(Hooks {
+        calls: Cell::new(0),
+    });
+    let context = &mut Context::builder()
+        .host_hooks(hooks.clone())
+        .build()
+        .unwrap();
+
+    context.interrupt_handle().interrupt();
+    let result = context.eval(Source::from_bytes(indoc! {r#"
+        let i = 0;
+        while (i < 1000) {
+            i++;
+        }
+        i
+    "#}));
+    assert_eq!(result, Ok(JsValue::new(1000)));
+    assert_eq!(hooks.calls.get(), 1);
+}
+
+#[test]
+fn debugger_statement_hook() {
+    #[derive(Default)]
+    struct Hooks {
+        pauses: std::cell::RefCell<Vec<(JsString, JsValue, Vec<JsValue>)>>,
+    }
+
+    impl HostHooks for Hooks {
+        fn on_debugger_statement(
+            &self,
+            frame: &CallFrame,
+            context: &mut Context,
+        ) -> crate::JsResult<()> {
+            let arguments = frame.arguments(context).to_vec();
+            if arguments.first() == Some(&JsValue::new(-1)) {
+                return Err(crate::JsNativeError::error()
+                    .with_message("stopped by the debugger")
+                    .into());
+            }
+            self.pauses.borrow_mut().push((
+                frame.position().function_name,
+                frame.this(context),
+                arguments,
+            ));
+            Ok(())
+        }
+    }
+
+    let hooks = Rc::new(Hooks::default());
+    let context = &mut Context::builder()
+        .host_hooks(hooks.clone())
+        .build()
+        .unwrap();
+
+    let result = context.eval(Source::from_bytes(indoc! {r#"
+        const object = {
+            inspect(a, b) {
+                debugger;
+                return a + b;
+            }
+        };
+        let caught;
+        try {
+            object.inspect(-1);
+        } catch (e) {
+            caught = e.message;
+        }
+        debugger;
+        object.inspect(1, 2) + caught;
+    "#}));
+    assert_eq!(
+        result,
+        Ok(JsValue::from(js_string!("3stopped by the debugger")))
+    );
+
+    let pauses = hooks.pauses.borrow();
+    assert_eq!(pauses.len(), 2);
+    assert_eq!(pauses[0].0, js_string!("<main>"));
+    assert_eq!(pauses[0].2, Vec::<JsValue>::new());
+    assert_eq!(pauses[1].0, js_string!("inspect"));
+    assert!(pauses[1].1.is_object());
+    assert_eq!(pauses[1].2, vec![JsValue::new(1), JsValue::new(2)]);
+}
+
+#[test]
+fn debugger_statement_bindings() {
+    #[derive(Default)]
+    struct Hooks {
+        bindings: std::cell::RefCell<Vec<(JsString, Option<JsValue>)>>,
+    }
+
+    impl HostHooks for Hooks {
+        fn on_debugger_statement(
+            &self,
+            frame: &CallFrame,
+            _context: &mut Context,
+        ) -> crate::JsResult<()> {
+            *self.bindings.borrow_mut() = frame.bindings();
+            Ok(())
+        }
+    }
+
+    let hooks = Rc::new(Hooks::default());
+    let context = &mut Context::builder()
+        .host_hooks(hooks.clone())
+        .build()
+        .unwrap();
+
+    let result = context.eval(Source::from_bytes(indoc! {r#"
+        const global = "global";
+        function inspect(a) {
+            let local = a * 2;
+            {
+                const local = "shadow";
+                debugger;
+                let later = 0;
+            }
+            return local;
+        }
+        inspect(4);
+    "#}));
+    assert_eq!(result, Ok(JsValue::new(8)));
+
+    let bindings = hooks.bindings.borrow();
+    let values = |name: JsString| {
+        bindings
+            .iter()
+            .filter(|(binding, _)| *binding == name)
+            .map(|(_, value)| value.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        values(js_string!("local")),
+        vec![Some(js_string!("shadow").into()), Some(JsValue::new(8))]
+    );
+    assert_eq!(values(js_string!("later")), vec![None]);
+    assert_eq!(values(js_string!("a")), vec![Some(JsValue::new(4))]);
+    assert_eq!(
+        values(js_string!("global")),
+        vec![Some(js_string!("global").into())]
+    );
+    assert_eq!(
+        bindings.last().map(|(name, _)| name),
+        Some(&js_string!("global"))
+    );
+}
+
+#[test]
+fn heap_limit() {
+    run_test_actions([
+        TestAction::inspect_context(|_| {
+            let mut config = boa_gc::gc_config();
+            let statistics = boa_gc::gc_statistics();
+            config.set_heap_limit(Some(
+                statistics.bytes_allocated() + statistics.external_bytes() + 4_194_304,
+            ));
+            boa_gc::set_gc_config(config);
+        }),
+        TestAction::run(indoc! {r#"
+            function throwsRangeError(f) {
+                try {
+                    f();
+                } catch (e) {
+                    // Avoid calls, since the memory is still reachable from the unwound frames.
+                    return e.name === "RangeError";
+                }
+                return false;
+            }
+        "#}),
+        TestAction::assert("throwsRangeError(() => 'a'.repeat(2 ** 22))"),
+        TestAction::assert("throwsRangeError(() => 'a'.padEnd(2 ** 22))"),
+        TestAction::assert("throwsRangeError(() => new ArrayBuffer(2 ** 23))"),
+        TestAction::assert("throwsRangeError(() => [].join.call({ length: 2 ** 40 }))"),
+        TestAction::assert(indoc! {r#"
+            throwsRangeError(() => {
+                let s = 'a';
+                while (true) {
+                    s += s;
+                }
+            })
+        "#}),
+        TestAction::assert(indoc! {r#"
+            throwsRangeError(() => {
+                const objects = [];
+                while (true) {
+                    objects.push({});
+                }
+            })
+        "#}),
+        TestAction::assert(indoc! {r#"
+            throwsRangeError(() => {
+                const buffers = [];
+                while (true) {
+                    buffers.push(new ArrayBuffer(2 ** 21));
+                }
+            })
+        "#}),
+        TestAction::assert(indoc! {r#"
+            throwsRangeError(() => {
+                const strings = [];
+                while (true) {
+                    strings.push('a'.repeat(2 ** 20));
+                }
+            })
+        "#}),
+        TestAction::assert_eq("'a'.repeat(4)", js_string!("aaaa")),
+        TestAction::assert_eq("new ArrayBuffer(1024).byteLength", 1024),
+    ]);
+}
+
 #[test]
 fn arguments_object_constructor_valid_index() {
     run_test_actions([TestAction::assert_eq(
@@ -0,0 +1,74 @@
+//! Memory kept alive by the garbage collected heap, but allocated outside of it.
+
+use crate::{Finalize, Trace, empty_trace};
+use std::cell::Cell;
+
+// This can't live in `BoaGc`, since external memory is usually released while dropping the
+// values of the heap, when the collector state is already borrowed by the sweep.
+thread_local!(static EXTERNAL_BYTES: Cell<usize> = const { Cell::new(0) });
+
+/// Returns the number of bytes allocated outside of the collector of the current thread, but
+/// owned by values of its heap.
+///
+/// This includes the memory registered with [`ExternalMemory`], and the contents of strings
+/// when the `boa_string` feature is enabled.
+#[must_use]
+pub fn external_bytes() -> usize {
+    let bytes = EXTERNAL_BYTES.with(Cell::get);
+
+    #[cfg(feature = "boa_string")]
+    let bytes = bytes.saturating_add(boa_string::JsString::allocated_bytes());
+
+    bytes
+}
+
+/// A registration of memory that is owned by a value of the heap, but was allocated outside of
+/// the collector, such as the contents of a buffer.
+///
+/// The registered bytes count towards the heap limit of the current thread until the
+/// registration is dropped. Cloning it registers the same number of bytes again, just like
+/// cloning the memory it stands for.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ExternalMemory {
+    bytes: usize,
+}
+
+impl ExternalMemory {
+    /// Registers `bytes` bytes of external memory.
+    #[must_use]
+    pub fn new(bytes: usize) -> Self {
+        EXTERNAL_BYTES.with(|total| total.set(total.get().saturating_add(bytes)));
+        Self { bytes }
+    }
+
+    /// Gets the number of registered bytes.
+    #[inline]
+    #[must_use]
+    pub const fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Changes the number of registered bytes, for when the memory is resized or released.
+    pub fn set_bytes(&mut self, bytes: usize) {
+        *self = Self::new(bytes);
+    }
+}
+
+impl Clone for ExternalMemory {
+    fn clone(&self) -> Self {
+        Self::new(self.bytes)
+    }
+}
+
+impl Drop for ExternalMemory {
+    fn drop(&mut self) {
+        EXTERNAL_BYTES.with(|total| total.set(total.get().saturating_sub(self.bytes)));
+    }
+}
+
+impl Finalize for ExternalMemory {}
+
+// SAFETY: `ExternalMemory` doesn't contain any traceable types.
+unsafe impl Trace for ExternalMemory {
+    empty_trace!();
+}
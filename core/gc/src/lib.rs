@@ -18,7 +18,11 @@
 extern crate self as boa_gc;
 
 mod cell;
+mod external;
+mod limit;
 mod pointers;
+mod statistics;
+mod time;
 mod trace;
 
 pub(crate) mod internals;
@@ -26,22 +30,29 @@ pub(crate) mod internals;
 use internals::{EphemeronBox, ErasedEphemeronBox, ErasedWeakMapBox, WeakMapBox};
 use pointers::{NonTraceable, RawWeakMap};
 use std::{
+    any::TypeId,
     cell::{Cell, RefCell},
     mem,
     ptr::NonNull,
+    rc::Rc,
+    time::Duration,
 };
 
 pub use crate::trace::{Finalize, Trace, Tracer};
 pub use boa_macros::{Finalize, Trace};
 pub use cell::{GcRef, GcRefCell, GcRefMut};
+pub use external::{ExternalMemory, external_bytes};
 pub use internals::GcBox;
+pub use limit::HeapLimitExceeded;
 pub use pointers::{Ephemeron, Gc, GcErased, WeakGc, WeakMap};
+pub use statistics::{CollectionInfo, GcStatistics, TypeStatistics};
 
 type GcErasedPointer = NonNull<GcBox<NonTraceable>>;
 type EphemeronPointer = NonNull<dyn ErasedEphemeronBox>;
 type ErasedWeakMapBoxPointer = NonNull<dyn ErasedWeakMapBox>;
 
 thread_local!(static GC_DROPPING: Cell<bool> = const { Cell::new(false) });
+thread_local!(static HEAP_LIMIT_EXCEEDED: Cell<bool> = const { Cell::new(false) });
 thread_local!(static BOA_GC: RefCell<BoaGc> = RefCell::new( BoaGc {
     config: GcConfig::default(),
     runtime: GcRuntimeData::default(),
@@ -50,31 +61,104 @@ thread_local!(static BOA_GC: RefCell<BoaGc> = RefCell::new( BoaGc {
     weak_maps: Vec::default(),
 }));
 
-#[derive(Debug, Clone, Copy)]
-struct GcConfig {
+type CollectionCallback = Rc<dyn Fn(&CollectionInfo)>;
+
+thread_local!(static GC_CALLBACKS: RefCell<Vec<(CollectionCallbackId, CollectionCallback)>> = const { RefCell::new(Vec::new()) });
+thread_local!(static NEXT_CALLBACK_ID: Cell<u64> = const { Cell::new(0) });
+
+/// The configuration of the garbage collector of a thread.
+///
+/// A collection is triggered when the number of allocated bytes exceeds the threshold. If the
+/// heap is still more than `used_space_percentage` percent full after the collection, the
+/// threshold is raised so that this percentage is kept.
+///
+/// An optional hard heap limit can also be set. See [`GcConfig::set_heap_limit`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GcConfig {
     /// The threshold at which the garbage collector will trigger a collection.
     threshold: usize,
     /// The percentage of used space at which the garbage collector will trigger a collection.
     used_space_percentage: usize,
+    /// The maximum number of bytes the heap can use, if any.
+    heap_limit: Option<usize>,
 }
 
-// Setting the defaults to an arbitrary value currently.
-//
-// TODO: Add a configure later
 impl Default for GcConfig {
     fn default() -> Self {
         Self {
             // Start at 1MB, the nursary size for V8 is ~1-8MB and SM can be up to 16MB
             threshold: 1_048_576,
             used_space_percentage: 70,
+            heap_limit: None,
         }
     }
 }
 
+impl GcConfig {
+    /// Gets the number of allocated bytes that triggers a collection.
+    #[inline]
+    #[must_use]
+    pub const fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Sets the number of allocated bytes that triggers a collection.
+    #[inline]
+    pub const fn set_threshold(&mut self, threshold: usize) {
+        self.threshold = threshold;
+    }
+
+    /// Gets the percentage of the threshold that can still be in use after a collection before
+    /// the threshold is raised.
+    #[inline]
+    #[must_use]
+    pub const fn used_space_percentage(&self) -> usize {
+        self.used_space_percentage
+    }
+
+    /// Sets the percentage of the threshold that can still be in use after a collection before
+    /// the threshold is raised.
+    ///
+    /// The percentage is clamped to the `1..=100` range.
+    #[inline]
+    pub fn set_used_space_percentage(&mut self, percentage: usize) {
+        self.used_space_percentage = percentage.clamp(1, 100);
+    }
+
+    /// Gets the hard limit of the heap, in bytes.
+    ///
+    /// Returns `None` if the heap size is unlimited, which is the default.
+    #[inline]
+    #[must_use]
+    pub const fn heap_limit(&self) -> Option<usize> {
+        self.heap_limit
+    }
+
+    /// Sets the hard limit of the heap, in bytes.
+    ///
+    /// The limit applies to the values of the heap together with the external memory they own,
+    /// see [`external_bytes`].
+    ///
+    /// Allocating past this limit triggers a collection. If the heap is still over the limit
+    /// after it, [`heap_limit_exceeded`] returns `true` until a later collection brings the heap
+    /// back under the limit. Allocations can't fail, so it's up to the users of the collector to
+    /// check the limit with [`check_heap_limit`] at points where they can report an error,
+    /// including before large allocations that don't go through the collector.
+    ///
+    /// Passing `None` removes the limit.
+    #[inline]
+    pub const fn set_heap_limit(&mut self, limit: Option<usize>) {
+        self.heap_limit = limit;
+    }
+}
+
 #[derive(Default, Debug, Clone, Copy)]
 struct GcRuntimeData {
     collections: usize,
     bytes_allocated: usize,
+    total_pause: Duration,
+    last_pause: Duration,
+    max_pause: Duration,
 }
 
 #[derive(Debug)]
@@ -86,6 +170,16 @@ struct BoaGc {
     weak_maps: Vec<ErasedWeakMapBoxPointer>,
 }
 
+impl BoaGc {
+    /// Returns the number of bytes that count towards the heap limit, which includes the
+    /// external memory owned by the heap.
+    fn heap_size(&self) -> usize {
+        self.runtime
+            .bytes_allocated
+            .saturating_add(external_bytes())
+    }
+}
+
 impl Drop for BoaGc {
     fn drop(&mut self) {
         Collector::dump(self);
@@ -130,10 +224,10 @@ impl Allocator {
     /// Allocate a new garbage collected value to the Garbage Collector's heap.
     fn alloc_gc<T: Trace>(value: GcBox<T>) -> NonNull<GcBox<T>> {
         let element_size = size_of_val::<GcBox<T>>(&value);
-        BOA_GC.with(|st| {
+        let (ptr, collection) = BOA_GC.with(|st| {
             let mut gc = st.borrow_mut();
 
-            Self::manage_state(&mut gc);
+            let collection = Self::manage_state(&mut gc);
             // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
             let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) };
             let erased: NonNull<GcBox<NonTraceable>> = ptr.cast();
@@ -141,18 +235,24 @@ impl Allocator {
             gc.strongs.push(erased);
             gc.runtime.bytes_allocated += element_size;
 
-            ptr
-        })
+            (ptr, collection)
+        });
+
+        if let Some(info) = collection {
+            run_collection_callbacks(&info);
+        }
+
+        ptr
     }
 
     fn alloc_ephemeron<K: Trace + ?Sized, V: Trace>(
         value: EphemeronBox<K, V>,
     ) -> NonNull<EphemeronBox<K, V>> {
         let element_size = size_of_val::<EphemeronBox<K, V>>(&value);
-        BOA_GC.with(|st| {
+        let (ptr, collection) = BOA_GC.with(|st| {
             let mut gc = st.borrow_mut();
 
-            Self::manage_state(&mut gc);
+            let collection = Self::manage_state(&mut gc);
             // Safety: value cannot be a null pointer, since `Box` cannot return null pointers.
             let ptr = unsafe { NonNull::new_unchecked(Box::into_raw(Box::new(value))) };
             let erased: NonNull<dyn ErasedEphemeronBox> = ptr;
@@ -160,8 +260,14 @@ impl Allocator {
             gc.weaks.push(erased);
             gc.runtime.bytes_allocated += element_size;
 
-            ptr
-        })
+            (ptr, collection)
+        });
+
+        if let Some(info) = collection {
+            run_collection_callbacks(&info);
+        }
+
+        ptr
     }
 
     fn alloc_weak_map<K: Trace + ?Sized, V: Trace>() -> WeakMap<K, V> {
@@ -185,19 +291,30 @@ impl Allocator {
         })
     }
 
-    fn manage_state(gc: &mut BoaGc) {
-        if gc.runtime.bytes_allocated > gc.config.threshold {
-            Collector::collect(gc);
-
-            // Post collection check
-            // If the allocated bytes are still above the threshold, increase the threshold.
-            if gc.runtime.bytes_allocated
-                > gc.config.threshold / 100 * gc.config.used_space_percentage
-            {
-                gc.config.threshold =
-                    gc.runtime.bytes_allocated / gc.config.used_space_percentage * 100;
-            }
+    fn manage_state(gc: &mut BoaGc) -> Option<CollectionInfo> {
+        // Only collect once when going over the heap limit, otherwise every allocation would
+        // trigger a collection until the user of the collector notices the exceeded limit.
+        let over_heap_limit = gc
+            .config
+            .heap_limit
+            .is_some_and(|limit| gc.heap_size() > limit)
+            && !heap_limit_exceeded();
+
+        if gc.runtime.bytes_allocated <= gc.config.threshold && !over_heap_limit {
+            return None;
         }
+
+        let info = Collector::collect(gc);
+
+        // Post collection check
+        // If the allocated bytes are still above the threshold, increase the threshold.
+        if gc.runtime.bytes_allocated > gc.config.threshold / 100 * gc.config.used_space_percentage
+        {
+            gc.config.threshold =
+                gc.runtime.bytes_allocated / gc.config.used_space_percentage * 100;
+        }
+
+        Some(info)
     }
 }
 
@@ -222,7 +339,9 @@ struct Collector;
 
 impl Collector {
     /// Run a collection on the full heap.
-    fn collect(gc: &mut BoaGc) {
+    fn collect(gc: &mut BoaGc) -> CollectionInfo {
+        let start = time::Instant::now();
+        let bytes_before = gc.runtime.bytes_allocated;
         gc.runtime.collections += 1;
 
         Self::trace_non_roots(gc);
@@ -275,6 +394,24 @@ impl Collector {
         gc.strongs.shrink_to(gc.strongs.len() >> 2);
         gc.weaks.shrink_to(gc.weaks.len() >> 2);
         gc.weak_maps.shrink_to(gc.weak_maps.len() >> 2);
+
+        let pause = start.elapsed();
+        gc.runtime.total_pause += pause;
+        gc.runtime.last_pause = pause;
+        gc.runtime.max_pause = gc.runtime.max_pause.max(pause);
+
+        let exceeded = gc
+            .config
+            .heap_limit
+            .is_some_and(|limit| gc.heap_size() > limit);
+        HEAP_LIMIT_EXCEEDED.with(|flag| flag.set(exceeded));
+
+        CollectionInfo {
+            collection: gc.runtime.collections,
+            bytes_before,
+            bytes_after: gc.runtime.bytes_allocated,
+            pause,
+        }
     }
 
     fn trace_non_roots(gc: &BoaGc) {
@@ -532,13 +669,208 @@ impl Collector {
 
 /// Forcefully runs a garbage collection of all inaccessible nodes.
 pub fn force_collect() {
+    let collection = BOA_GC.with(|current| {
+        let mut gc = current.borrow_mut();
+
+        (gc.runtime.bytes_allocated > 0).then(|| Collector::collect(&mut gc))
+    });
+
+    if let Some(info) = collection {
+        run_collection_callbacks(&info);
+    }
+}
+
+/// Returns the configuration of the garbage collector of the current thread.
+#[must_use]
+pub fn gc_config() -> GcConfig {
+    BOA_GC.with(|current| current.borrow().config)
+}
+
+/// Sets the configuration of the garbage collector of the current thread.
+///
+/// The new threshold applies from the next allocation onwards.
+pub fn set_gc_config(config: GcConfig) {
     BOA_GC.with(|current| {
         let mut gc = current.borrow_mut();
+        gc.config = config;
+
+        let exceeded = heap_limit_exceeded()
+            && config
+                .heap_limit
+                .is_some_and(|limit| gc.heap_size() > limit);
+        HEAP_LIMIT_EXCEEDED.with(|flag| flag.set(exceeded));
+    });
+}
+
+/// Returns `true` if the heap of the current thread went over its heap limit, and no collection
+/// has brought it back under the limit since.
+///
+/// See [`GcConfig::set_heap_limit`].
+#[must_use]
+#[inline]
+pub fn heap_limit_exceeded() -> bool {
+    HEAP_LIMIT_EXCEEDED.with(Cell::get)
+}
+
+/// Checks that `additional` more bytes can be allocated without going over the heap limit of
+/// the current thread.
+///
+/// If the check fails at first, a collection is run before checking again. Passing `0` checks
+/// whether the heap is currently over its limit, which is cheap unless
+/// [`heap_limit_exceeded`] returns `true`.
+///
+/// The bytes are not reserved, so this can also be used for allocations that don't go through
+/// the collector, such as the contents of strings or buffers.
+///
+/// # Errors
+///
+/// Returns [`HeapLimitExceeded`] if the allocation would still go over the limit after the
+/// collection.
+pub fn check_heap_limit(additional: usize) -> Result<(), HeapLimitExceeded> {
+    if additional == 0 && !heap_limit_exceeded() {
+        return Ok(());
+    }
+
+    let (result, collection) = BOA_GC.with(|current| {
+        let mut gc = current.borrow_mut();
 
-        if gc.runtime.bytes_allocated > 0 {
-            Collector::collect(&mut gc);
+        let Some(limit) = gc.config.heap_limit else {
+            return (Ok(()), None);
+        };
+
+        let fits = |gc: &BoaGc| {
+            gc.heap_size()
+                .checked_add(additional)
+                .is_some_and(|total| total <= limit)
+        };
+
+        if !heap_limit_exceeded() && fits(&gc) {
+            return (Ok(()), None);
         }
+
+        let info = Collector::collect(&mut gc);
+
+        let result = if fits(&gc) {
+            Ok(())
+        } else {
+            Err(HeapLimitExceeded {
+                limit,
+                bytes_allocated: gc.heap_size(),
+                requested: additional,
+            })
+        };
+
+        (result, Some(info))
     });
+
+    if let Some(info) = collection {
+        run_collection_callbacks(&info);
+    }
+
+    result
+}
+
+/// Returns statistics about the garbage collector of the current thread.
+#[must_use]
+pub fn gc_statistics() -> GcStatistics {
+    BOA_GC.with(|current| {
+        let gc = current.borrow();
+
+        GcStatistics {
+            collections: gc.runtime.collections,
+            bytes_allocated: gc.runtime.bytes_allocated,
+            external_bytes: external_bytes(),
+            threshold: gc.config.threshold,
+            object_count: gc.strongs.len(),
+            ephemeron_count: gc.weaks.len(),
+            weak_map_count: gc.weak_maps.len(),
+            total_pause: gc.runtime.total_pause,
+            last_pause: gc.runtime.last_pause,
+            max_pause: gc.runtime.max_pause,
+        }
+    })
+}
+
+/// Returns statistics about the [`Gc`] values in the heap of the current thread, grouped by type
+/// and sorted by the number of bytes they use, largest first.
+///
+/// This walks the whole heap, so it is considerably more expensive than [`gc_statistics`].
+#[must_use]
+pub fn type_statistics() -> Vec<TypeStatistics> {
+    BOA_GC.with(|current| {
+        let gc = current.borrow();
+
+        let mut types = hashbrown::HashMap::<TypeId, TypeStatistics>::new();
+        for node in &gc.strongs {
+            // SAFETY: nodes are always valid outside of a collection.
+            let vtable = unsafe { node.as_ref() }.vtable;
+            let entry = types
+                .entry(vtable.type_id())
+                .or_insert_with(|| TypeStatistics {
+                    type_id: vtable.type_id(),
+                    type_name: vtable.type_name(),
+                    count: 0,
+                    bytes: 0,
+                });
+            entry.count += 1;
+            entry.bytes += vtable.size();
+        }
+
+        let mut types = types.into_values().collect::<Vec<_>>();
+        types.sort_by(|a, b| b.bytes.cmp(&a.bytes).then(a.type_name.cmp(b.type_name)));
+        types
+    })
+}
+
+/// An identifier of a callback registered with [`add_collection_callback`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CollectionCallbackId(u64);
+
+/// Registers a callback that runs on the current thread after every collection.
+///
+/// Callbacks run after the collection has finished, so they are free to allocate new
+/// [`Gc`] values.
+pub fn add_collection_callback<F>(callback: F) -> CollectionCallbackId
+where
+    F: Fn(&CollectionInfo) + 'static,
+{
+    let id = NEXT_CALLBACK_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        CollectionCallbackId(id)
+    });
+    GC_CALLBACKS.with(|callbacks| callbacks.borrow_mut().push((id, Rc::new(callback))));
+    id
+}
+
+/// Removes a callback registered with [`add_collection_callback`].
+///
+/// Returns `false` if there was no callback with the given id.
+#[allow(clippy::must_use_candidate)]
+pub fn remove_collection_callback(id: CollectionCallbackId) -> bool {
+    GC_CALLBACKS.with(|callbacks| {
+        let mut callbacks = callbacks.borrow_mut();
+        let len = callbacks.len();
+        callbacks.retain(|(callback_id, _)| *callback_id != id);
+        callbacks.len() != len
+    })
+}
+
+fn run_collection_callbacks(info: &CollectionInfo) {
+    // Clone the callbacks to allow them to register or remove callbacks themselves.
+    let Ok(callbacks) = GC_CALLBACKS.try_with(|callbacks| {
+        callbacks
+            .borrow()
+            .iter()
+            .map(|(_, callback)| callback.clone())
+            .collect::<Vec<_>>()
+    }) else {
+        return;
+    };
+
+    for callback in callbacks {
+        callback(info);
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,49 @@
+//! The hard limit of the garbage collected heap of a thread.
+
+use std::{error::Error, fmt};
+
+/// Error returned by [`check_heap_limit`][crate::check_heap_limit] when an allocation would
+/// exceed the heap limit set with [`GcConfig::set_heap_limit`][crate::GcConfig::set_heap_limit],
+/// even after running a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeapLimitExceeded {
+    pub(crate) limit: usize,
+    pub(crate) bytes_allocated: usize,
+    pub(crate) requested: usize,
+}
+
+impl HeapLimitExceeded {
+    /// Gets the configured heap limit, in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Gets the number of bytes in use after the collection, including the external memory
+    /// owned by the heap.
+    #[inline]
+    #[must_use]
+    pub const fn bytes_allocated(&self) -> usize {
+        self.bytes_allocated
+    }
+
+    /// Gets the number of bytes requested by the failed allocation.
+    #[inline]
+    #[must_use]
+    pub const fn requested(&self) -> usize {
+        self.requested
+    }
+}
+
+impl fmt::Display for HeapLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "allocating {} bytes with {} bytes in use exceeds the heap limit of {} bytes",
+            self.requested, self.bytes_allocated, self.limit
+        )
+    }
+}
+
+impl Error for HeapLimitExceeded {}
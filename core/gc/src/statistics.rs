@@ -0,0 +1,187 @@
+//! Statistics about the garbage collected heap of a thread.
+
+use std::{any::TypeId, time::Duration};
+
+/// Statistics about the garbage collector of the current thread.
+///
+/// Returned by [`gc_statistics`][crate::gc_statistics].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GcStatistics {
+    pub(crate) collections: usize,
+    pub(crate) bytes_allocated: usize,
+    pub(crate) external_bytes: usize,
+    pub(crate) threshold: usize,
+    pub(crate) object_count: usize,
+    pub(crate) ephemeron_count: usize,
+    pub(crate) weak_map_count: usize,
+    pub(crate) total_pause: Duration,
+    pub(crate) last_pause: Duration,
+    pub(crate) max_pause: Duration,
+}
+
+impl GcStatistics {
+    /// Gets the number of collections run on this thread.
+    #[inline]
+    #[must_use]
+    pub const fn collections(&self) -> usize {
+        self.collections
+    }
+
+    /// Gets the number of bytes currently allocated in the heap, including unreachable values
+    /// that haven't been collected yet.
+    #[inline]
+    #[must_use]
+    pub const fn bytes_allocated(&self) -> usize {
+        self.bytes_allocated
+    }
+
+    /// Gets the number of bytes allocated outside of the heap but owned by its values, such as
+    /// the contents of strings and buffers.
+    ///
+    /// See [`external_bytes`][crate::external_bytes].
+    #[inline]
+    #[must_use]
+    pub const fn external_bytes(&self) -> usize {
+        self.external_bytes
+    }
+
+    /// Gets the number of allocated bytes that will trigger the next collection.
+    #[inline]
+    #[must_use]
+    pub const fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// Gets the number of [`Gc`][crate::Gc] values in the heap.
+    #[inline]
+    #[must_use]
+    pub const fn object_count(&self) -> usize {
+        self.object_count
+    }
+
+    /// Gets the number of [`Ephemeron`][crate::Ephemeron] values in the heap.
+    #[inline]
+    #[must_use]
+    pub const fn ephemeron_count(&self) -> usize {
+        self.ephemeron_count
+    }
+
+    /// Gets the number of [`WeakMap`][crate::WeakMap] values in the heap.
+    #[inline]
+    #[must_use]
+    pub const fn weak_map_count(&self) -> usize {
+        self.weak_map_count
+    }
+
+    /// Gets the total time spent collecting garbage on this thread.
+    ///
+    /// Pause times are always zero on targets that don't provide a clock.
+    #[inline]
+    #[must_use]
+    pub const fn total_pause(&self) -> Duration {
+        self.total_pause
+    }
+
+    /// Gets the duration of the last collection.
+    #[inline]
+    #[must_use]
+    pub const fn last_pause(&self) -> Duration {
+        self.last_pause
+    }
+
+    /// Gets the duration of the longest collection.
+    #[inline]
+    #[must_use]
+    pub const fn max_pause(&self) -> Duration {
+        self.max_pause
+    }
+}
+
+/// Statistics about the [`Gc`][crate::Gc] values of a single type in the heap.
+///
+/// Returned by [`type_statistics`][crate::type_statistics].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeStatistics {
+    pub(crate) type_id: TypeId,
+    pub(crate) type_name: &'static str,
+    pub(crate) count: usize,
+    pub(crate) bytes: usize,
+}
+
+impl TypeStatistics {
+    /// Gets the [`TypeId`] of the type.
+    #[inline]
+    #[must_use]
+    pub const fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Gets the name of the type, as returned by [`std::any::type_name`].
+    #[inline]
+    #[must_use]
+    pub const fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Gets the number of values of the type in the heap.
+    #[inline]
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Gets the number of bytes used by the values of the type in the heap.
+    #[inline]
+    #[must_use]
+    pub const fn bytes(&self) -> usize {
+        self.bytes
+    }
+}
+
+/// Information about a finished collection, passed to the callbacks registered with
+/// [`add_collection_callback`][crate::add_collection_callback].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollectionInfo {
+    pub(crate) collection: usize,
+    pub(crate) bytes_before: usize,
+    pub(crate) bytes_after: usize,
+    pub(crate) pause: Duration,
+}
+
+impl CollectionInfo {
+    /// Gets the number of this collection, starting at `1` for the first collection of the
+    /// thread.
+    #[inline]
+    #[must_use]
+    pub const fn collection(&self) -> usize {
+        self.collection
+    }
+
+    /// Gets the number of bytes allocated before the collection.
+    #[inline]
+    #[must_use]
+    pub const fn bytes_before(&self) -> usize {
+        self.bytes_before
+    }
+
+    /// Gets the number of bytes allocated after the collection.
+    #[inline]
+    #[must_use]
+    pub const fn bytes_after(&self) -> usize {
+        self.bytes_after
+    }
+
+    /// Gets the number of bytes freed by the collection.
+    #[inline]
+    #[must_use]
+    pub const fn bytes_freed(&self) -> usize {
+        self.bytes_before.saturating_sub(self.bytes_after)
+    }
+
+    /// Gets the duration of the collection.
+    #[inline]
+    #[must_use]
+    pub const fn pause(&self) -> Duration {
+        self.pause
+    }
+}
@@ -0,0 +1,85 @@
+use super::run_test;
+use crate::{
+    ExternalMemory, Gc, GcBox, check_heap_limit, external_bytes, force_collect, gc_config,
+    gc_statistics, heap_limit_exceeded, set_gc_config,
+};
+
+#[test]
+fn heap_limit_check() {
+    run_test(|| {
+        let mut config = gc_config();
+        assert_eq!(config.heap_limit(), None);
+        assert!(check_heap_limit(usize::MAX).is_ok());
+
+        let limit = 16 * size_of::<GcBox<[u8; 64]>>();
+        config.set_heap_limit(Some(limit));
+        set_gc_config(config);
+
+        assert!(check_heap_limit(limit).is_ok());
+        let error = check_heap_limit(limit + 1).expect_err("should exceed the heap limit");
+        assert_eq!(error.limit(), limit);
+        assert_eq!(error.requested(), limit + 1);
+        assert_eq!(gc_statistics().collections(), 1);
+
+        let values = (0..8).map(|_| Gc::new([0_u8; 64])).collect::<Vec<_>>();
+        assert!(check_heap_limit(limit / 2).is_ok());
+        assert!(check_heap_limit(limit / 2 + 1).is_err());
+
+        drop(values);
+        assert!(check_heap_limit(limit).is_ok());
+    });
+}
+
+#[test]
+fn heap_limit_exceeded_by_allocations() {
+    run_test(|| {
+        let mut config = gc_config();
+        config.set_heap_limit(Some(4 * size_of::<GcBox<[u8; 64]>>()));
+        set_gc_config(config);
+
+        let mut values = Vec::new();
+        while !heap_limit_exceeded() {
+            values.push(Gc::new([0_u8; 64]));
+        }
+        assert_eq!(values.len(), 6);
+        assert!(check_heap_limit(0).is_err());
+
+        // Going over the limit again must not trigger a collection per allocation.
+        let collections = gc_statistics().collections();
+        values.push(Gc::new([0_u8; 64]));
+        assert_eq!(gc_statistics().collections(), collections);
+
+        values.clear();
+        force_collect();
+        assert!(!heap_limit_exceeded());
+        assert!(check_heap_limit(0).is_ok());
+    });
+}
+
+#[test]
+fn heap_limit_counts_external_memory() {
+    run_test(|| {
+        let mut config = gc_config();
+        config.set_heap_limit(Some(4096));
+        set_gc_config(config);
+
+        let buffers = (0..3)
+            .map(|_| Gc::new(ExternalMemory::new(1024)))
+            .collect::<Vec<_>>();
+        assert_eq!(external_bytes(), 3072);
+        assert_eq!(gc_statistics().external_bytes(), 3072);
+
+        let error = check_heap_limit(1024).expect_err("should exceed the heap limit");
+        assert!(error.bytes_allocated() > 3072);
+
+        let mut clone = ExternalMemory::clone(&buffers[0]);
+        assert_eq!(external_bytes(), 4096);
+        clone.set_bytes(0);
+        assert_eq!(external_bytes(), 3072);
+
+        // Collecting the unreachable values releases their external memory.
+        drop(buffers);
+        assert!(check_heap_limit(1024).is_ok());
+        assert_eq!(external_bytes(), 0);
+    });
+}
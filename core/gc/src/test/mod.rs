@@ -3,6 +3,8 @@ use crate::BOA_GC;
 mod allocation;
 mod cell;
 mod erased;
+mod limit;
+mod statistics;
 mod std_types;
 mod weak;
 mod weak_map;
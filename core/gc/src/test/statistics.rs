@@ -0,0 +1,107 @@
+mod miri {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::super::run_test;
+    use crate::{
+        Gc, GcBox, GcConfig, GcRefCell, add_collection_callback, force_collect, gc_config,
+        gc_statistics, remove_collection_callback, set_gc_config, type_statistics,
+    };
+
+    #[test]
+    fn gc_config_per_thread() {
+        run_test(|| {
+            let mut config = gc_config();
+            assert_eq!(config, GcConfig::default());
+
+            config.set_threshold(4096);
+            config.set_used_space_percentage(0);
+            assert_eq!(config.used_space_percentage(), 1);
+            config.set_used_space_percentage(200);
+            assert_eq!(config.used_space_percentage(), 100);
+            set_gc_config(config);
+
+            assert_eq!(gc_config().threshold(), 4096);
+            assert_eq!(gc_statistics().threshold(), 4096);
+
+            // Other threads keep their own configuration.
+            run_test(|| assert_eq!(gc_config(), GcConfig::default()));
+        });
+    }
+
+    #[test]
+    fn gc_statistics_counts() {
+        run_test(|| {
+            let numbers = (0..10_u64).map(Gc::new).collect::<Vec<_>>();
+            let cells = (0..5_u8)
+                .map(|i| Gc::new(GcRefCell::new(i)))
+                .collect::<Vec<_>>();
+
+            let statistics = gc_statistics();
+            assert_eq!(statistics.collections(), 0);
+            assert_eq!(statistics.object_count(), 15);
+            assert_eq!(
+                statistics.bytes_allocated(),
+                10 * size_of::<GcBox<u64>>() + 5 * size_of::<GcBox<GcRefCell<u8>>>()
+            );
+
+            let types = type_statistics();
+            assert_eq!(types.len(), 2);
+            let numbers_type = types
+                .iter()
+                .find(|ty| ty.type_name() == std::any::type_name::<u64>())
+                .expect("u64 should be in the heap");
+            assert_eq!(numbers_type.type_id(), std::any::TypeId::of::<u64>());
+            assert_eq!(numbers_type.count(), 10);
+            assert_eq!(numbers_type.bytes(), 10 * size_of::<GcBox<u64>>());
+
+            drop(numbers);
+            force_collect();
+
+            let statistics = gc_statistics();
+            assert_eq!(statistics.collections(), 1);
+            assert_eq!(statistics.object_count(), 5);
+            assert!(statistics.max_pause() >= statistics.last_pause());
+            assert_eq!(statistics.total_pause(), statistics.last_pause());
+            assert_eq!(type_statistics().len(), 1);
+
+            drop(cells);
+        });
+    }
+
+    #[test]
+    fn gc_collection_callbacks() {
+        run_test(|| {
+            let collections = Rc::new(RefCell::new(Vec::new()));
+
+            let id = add_collection_callback({
+                let collections = collections.clone();
+                move |info| {
+                    // Allocating inside a callback must not deadlock the collector.
+                    let _gc = Gc::new(info.collection());
+                    collections.borrow_mut().push(*info);
+                }
+            });
+
+            let gc = Gc::new(1_u32);
+            drop(gc);
+            force_collect();
+
+            {
+                let collections = collections.borrow();
+                assert_eq!(collections.len(), 1);
+                assert_eq!(collections[0].collection(), 1);
+                assert_eq!(collections[0].bytes_freed(), size_of::<GcBox<u32>>());
+                assert_eq!(
+                    collections[0].bytes_after(),
+                    collections[0].bytes_before() - size_of::<GcBox<u32>>()
+                );
+            }
+
+            assert!(remove_collection_callback(id));
+            assert!(!remove_collection_callback(id));
+
+            force_collect();
+            assert_eq!(collections.borrow().len(), 1);
+        });
+    }
+}
@@ -0,0 +1,41 @@
+//! The clock used to measure the pause times of collections.
+//!
+//! `std::time::Instant` panics on `wasm32-unknown-unknown`, so the clock of the `web-time` crate
+//! is used there when the `js` feature is enabled, and pause times are reported as zero otherwise.
+
+#[cfg(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi")),
+    feature = "js"
+))]
+pub(crate) use web_time::Instant;
+
+#[cfg(not(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi"))
+)))]
+pub(crate) use std::time::Instant;
+
+/// A clock that doesn't measure anything, for targets without a clock.
+#[cfg(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi")),
+    not(feature = "js")
+))]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Instant;
+
+#[cfg(all(
+    target_family = "wasm",
+    not(any(target_os = "emscripten", target_os = "wasi")),
+    not(feature = "js")
+))]
+impl Instant {
+    pub(crate) const fn now() -> Self {
+        Self
+    }
+
+    pub(crate) const fn elapsed(self) -> std::time::Duration {
+        std::time::Duration::ZERO
+    }
+}
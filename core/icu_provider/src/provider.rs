@@ -0,0 +1,73 @@
+//! Data provider struct definitions for the locale data that ICU4X doesn't provide.
+//!
+//! The data for these markers is exported from [CLDR][cldr] by Boa's `gen-icu4x-data` tool,
+//! and it is included in the default data provider exported by `buffer` with the `bundled` feature.
+//!
+//! [cldr]: https://github.com/unicode-org/cldr-json
+
+// Provider structs must be stable, and their derives trigger the `single_use_lifetimes` lint.
+#![allow(clippy::exhaustive_structs, single_use_lifetimes)]
+
+use icu_provider::{DataMarker, DataMarkerInfo};
+use zerovec::ZeroMap;
+
+icu_provider::data_marker!(
+    /// `CalendarDisplayNamesV1`
+    CalendarDisplayNamesV1,
+    CalendarDisplayNames<'static>
+);
+icu_provider::data_marker!(
+    /// `DateTimeFieldDisplayNamesV1`
+    DateTimeFieldDisplayNamesV1,
+    DateTimeFieldDisplayNames<'static>
+);
+
+/// The list of markers provided by Boa.
+pub const MARKERS: &[DataMarkerInfo] = &[
+    CalendarDisplayNamesV1::INFO,
+    DateTimeFieldDisplayNamesV1::INFO,
+];
+
+#[derive(
+    Debug, PartialEq, Clone, Default, yoke::Yokeable, zerofrom::ZeroFrom, serde::Deserialize,
+)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = boa_icu_provider::provider))]
+#[yoke(prove_covariance_manually)]
+/// `CalendarDisplayNames` provides mapping between a calendar and its display name.
+pub struct CalendarDisplayNames<'data> {
+    /// Mapping for a BCP 47 calendar identifier to its display name.
+    #[serde(borrow)]
+    pub names: ZeroMap<'data, str, str>,
+}
+
+icu_provider::data_struct!(
+    CalendarDisplayNames<'_>,
+    #[cfg(feature = "datagen")]
+);
+
+#[derive(
+    Debug, PartialEq, Clone, Default, yoke::Yokeable, zerofrom::ZeroFrom, serde::Deserialize,
+)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = boa_icu_provider::provider))]
+#[yoke(prove_covariance_manually)]
+/// `DateTimeFieldDisplayNames` provides mapping between a date-time field and its display name.
+///
+/// The fields are identified by their ECMA-402 codes, such as `"weekOfYear"` or `"dayPeriod"`.
+pub struct DateTimeFieldDisplayNames<'data> {
+    /// Mapping for a date-time field to its display name.
+    #[serde(borrow)]
+    pub names: ZeroMap<'data, str, str>,
+    /// Mapping for a date-time field to its short display name.
+    #[serde(borrow)]
+    pub short_names: ZeroMap<'data, str, str>,
+    /// Mapping for a date-time field to its narrow display name.
+    #[serde(borrow)]
+    pub narrow_names: ZeroMap<'data, str, str>,
+}
+
+icu_provider::data_struct!(
+    DateTimeFieldDisplayNames<'_>,
+    #[cfg(feature = "datagen")]
+);
@@ -154,5 +154,6 @@ static_syms! {
     "source",
     "using",
     "dispose",
-    "asyncDispose"
+    "asyncDispose",
+    "accessor"
 }
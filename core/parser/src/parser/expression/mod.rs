@@ -45,8 +45,8 @@ use boa_interner::{Interner, Sym};
 pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
 pub(in crate::parser) use {
     fpl_or_exp::FormalParameterListOrExpression,
-    identifiers::{BindingIdentifier, LabelIdentifier},
-    left_hand_side::LeftHandSideExpression,
+    identifiers::{BindingIdentifier, IdentifierReference, LabelIdentifier},
+    left_hand_side::{LeftHandSideExpression, arguments::Arguments},
     primary::object_initializer::{
         AsyncGeneratorMethod, AsyncMethod, GeneratorMethod, PropertyName,
     },
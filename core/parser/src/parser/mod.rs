@@ -20,7 +20,8 @@ use crate::{
     source::ReadChar,
 };
 use boa_ast::{
-    Position, StatementList,
+    Declaration, Position, StatementList, StatementListItem,
+    declaration::LexicalDeclaration,
     function::{FormalParameterList, FunctionBody},
     operations::{
         ContainsSymbol, all_private_identifiers_valid, check_labels, contains,
@@ -419,6 +420,22 @@ where
             }
         }
 
+        // It is a Syntax Error if the goal symbol of the syntactic grammar is Script and
+        // UsingDeclaration or AwaitUsingDeclaration is not contained within a Block, CaseBlock,
+        // ForStatement, ForInOfStatement, FunctionBody or ClassBody.
+        for item in body.statements() {
+            if let StatementListItem::Declaration(declaration) = item
+                && let Declaration::Lexical(
+                    LexicalDeclaration::Using(_) | LexicalDeclaration::AwaitUsing(_),
+                ) = declaration.as_ref()
+            {
+                return Err(Error::general(
+                    "using declarations are not allowed at the top level of a script",
+                    Position::new(1, 1),
+                ));
+            }
+        }
+
         if let Err(error) = check_labels(&body) {
             return Err(Error::lex(LexError::Syntax(
                 error.message(interner).into(),
@@ -0,0 +1,186 @@
+//! Decorator parsing.
+//!
+//! More information:
+//!  - [Decorators proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-decorators/#prod-DecoratorList
+
+use crate::{
+    Error,
+    lexer::TokenKind,
+    parser::{
+        AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
+        expression::{Arguments, Expression, IdentifierReference},
+    },
+    source::ReadChar,
+};
+use boa_ast::{
+    self as ast, Punctuator, Span, Spanned,
+    expression::{
+        Call, Identifier, Parenthesized,
+        access::{PrivatePropertyAccess, SimplePropertyAccess},
+    },
+    function::{Decorator, PrivateName},
+};
+use boa_interner::{Interner, Sym};
+
+/// Parses a list of decorators.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-DecoratorList
+#[derive(Debug, Clone, Copy)]
+pub(in crate::parser) struct DecoratorList {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl DecoratorList {
+    /// Creates a new `DecoratorList` parser.
+    pub(in crate::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for DecoratorList
+where
+    R: ReadChar,
+{
+    type Output = Box<[Decorator]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let mut decorators = Vec::new();
+        while let Some(token) = cursor.next_if(Punctuator::At, interner)? {
+            // Decorators are a Stage 3 proposal, so they are only parsed with the
+            // `experimental` feature.
+            if !cfg!(feature = "experimental") {
+                return Err(Error::general(
+                    "decorators require the `experimental` feature",
+                    token.span().start(),
+                ));
+            }
+            decorators.push(
+                DecoratorExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?,
+            );
+        }
+        Ok(decorators.into_boxed_slice())
+    }
+}
+
+/// Parses the expression of a single decorator, after its `@` token.
+///
+/// More information:
+///  - [Decorators proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-decorators/#prod-Decorator
+#[derive(Debug, Clone, Copy)]
+struct DecoratorExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl DecoratorExpression {
+    /// Creates a new `DecoratorExpression` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for DecoratorExpression
+where
+    R: ReadChar,
+{
+    type Output = Decorator;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        // DecoratorParenthesizedExpression : ( Expression[+In] )
+        if let Some(token) = cursor.next_if(Punctuator::OpenParen, interner)? {
+            let start = token.span().start();
+            let expression = Expression::new(true, self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?;
+            let end = cursor
+                .expect(Punctuator::CloseParen, "decorator", interner)?
+                .span()
+                .end();
+            return Ok(Decorator::new(
+                Parenthesized::new(expression, Span::new(start, end)).into(),
+            ));
+        }
+
+        // DecoratorMemberExpression :
+        //     IdentifierReference
+        //     DecoratorMemberExpression . IdentifierName
+        //     DecoratorMemberExpression . PrivateIdentifier
+        let mut expression: ast::Expression =
+            IdentifierReference::new(self.allow_yield, self.allow_await)
+                .parse(cursor, interner)?
+                .into();
+        while cursor.next_if(Punctuator::Dot, interner)?.is_some() {
+            let token = cursor.next(interner).or_abrupt()?;
+            let span = expression.span();
+            let access = match token.kind() {
+                TokenKind::IdentifierName((name, _)) => {
+                    SimplePropertyAccess::new(expression, Identifier::new(*name, token.span()))
+                        .into()
+                }
+                TokenKind::Keyword((kw, _)) => SimplePropertyAccess::new(
+                    expression,
+                    Identifier::new(kw.to_sym(), token.span()),
+                )
+                .into(),
+                TokenKind::BooleanLiteral((value, _)) => {
+                    let sym = if *value { Sym::TRUE } else { Sym::FALSE };
+                    SimplePropertyAccess::new(expression, Identifier::new(sym, token.span())).into()
+                }
+                TokenKind::NullLiteral(_) => {
+                    SimplePropertyAccess::new(expression, Identifier::new(Sym::NULL, token.span()))
+                        .into()
+                }
+                TokenKind::PrivateIdentifier(name) => PrivatePropertyAccess::new(
+                    expression,
+                    PrivateName::new(*name, token.span()),
+                    Span::new(span.start(), token.span().end()),
+                )
+                .into(),
+                _ => {
+                    return Err(Error::expected(
+                        ["identifier".to_owned()],
+                        token.to_string(interner),
+                        token.span(),
+                        "decorator",
+                    ));
+                }
+            };
+            expression = ast::Expression::PropertyAccess(access);
+        }
+
+        // DecoratorCallExpression : DecoratorMemberExpression Arguments
+        if cursor
+            .peek(0, interner)?
+            .is_some_and(|token| token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen))
+        {
+            let start = expression.span().start();
+            let (args, args_span) =
+                Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+            expression = Call::new(expression, args, Span::new(start, args_span.end())).into();
+        }
+
+        Ok(Decorator::new(expression))
+    }
+}
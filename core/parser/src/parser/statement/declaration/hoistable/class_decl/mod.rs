@@ -1,9 +1,13 @@
 #[cfg(test)]
 mod tests;
 
+mod decorator;
+
+pub(in crate::parser) use self::decorator::DecoratorList;
+
 use crate::{
     Error,
-    lexer::{Error as LexError, TokenKind, token::ContainsEscapeSequence},
+    lexer::{Error as LexError, Token, TokenKind, token::ContainsEscapeSequence},
     parser::{
         AllowAwait, AllowDefault, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
         expression::{
@@ -75,6 +79,8 @@ where
     type Output = ClassDeclarationNode;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
         let span = cursor
             .expect((Keyword::Class, false), "class declaration", interner)?
             .span();
@@ -111,6 +117,7 @@ where
             super_ref,
             constructor,
             elements.into_boxed_slice(),
+            decorators,
         ))
     }
 }
@@ -486,6 +493,38 @@ where
     }
 }
 
+impl ClassElement {
+    /// Parses the optional initializer of a class field, up to and including its trailing
+    /// semicolon.
+    ///
+    /// If `name` is given, it is used to name an anonymous function definition in the initializer.
+    fn parse_field_initializer<R, N>(
+        self,
+        name: N,
+        cursor: &mut Cursor<R>,
+        interner: &mut Interner,
+    ) -> ParseResult<Option<Expression>>
+    where
+        R: ReadChar,
+        N: Into<Option<Identifier>>,
+    {
+        if cursor.next_if(Punctuator::Assign, interner)?.is_none() {
+            cursor.expect_semicolon("expected semicolon", interner)?;
+            return Ok(None);
+        }
+        let strict = cursor.strict();
+        cursor.set_strict(true);
+        let mut rhs = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+            .parse(cursor, interner)?;
+        cursor.expect_semicolon("expected semicolon", interner)?;
+        cursor.set_strict(strict);
+        if let Some(name) = name.into() {
+            rhs.set_anonymous_function_definition_name(&name);
+        }
+        Ok(Some(rhs))
+    }
+}
+
 /// Representation of private object elements.
 #[derive(Debug, PartialEq)]
 pub(crate) enum PrivateElement {
@@ -533,9 +572,22 @@ where
     type Output = (Option<FunctionExpression>, Option<function::ClassElement>);
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let decorators_position = cursor.peek(0, interner).or_abrupt()?.span().start();
+        let strict = cursor.strict();
+        cursor.set_strict(true);
+        let decorators =
+            DecoratorList::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+        cursor.set_strict(strict);
+
         let token = cursor.peek(0, interner).or_abrupt()?;
         let r#static = match token.kind() {
             TokenKind::Punctuator(Punctuator::Semicolon) => {
+                if !decorators.is_empty() {
+                    return Err(Error::general(
+                        "decorators are not valid here",
+                        decorators_position,
+                    ));
+                }
                 cursor.advance(interner);
                 return Ok((None, None));
             }
@@ -569,6 +621,51 @@ where
             _ => false,
         };
 
+        let token = cursor.peek(0, interner).or_abrupt()?;
+        let accessor = match token.kind() {
+            // Auto-accessors are part of the decorators proposal, so without the `experimental`
+            // feature `accessor` is only a field name.
+            TokenKind::IdentifierName((Sym::ACCESSOR, ContainsEscapeSequence(contains_escape)))
+                if cfg!(feature = "experimental") =>
+            {
+                let contains_escape = *contains_escape;
+                let accessor_position = token.span().start();
+                // The buffered token before `accessor` may be a line terminator.
+                let skip_n = if cursor.peek_is_line_terminator(0, interner).or_abrupt()? {
+                    2
+                } else {
+                    1
+                };
+                match cursor
+                    .peek_no_skip_line_term(skip_n, interner)?
+                    .map(Token::kind)
+                {
+                    Some(
+                        TokenKind::IdentifierName(_)
+                        | TokenKind::StringLiteral(_)
+                        | TokenKind::NumericLiteral(_)
+                        | TokenKind::Keyword(_)
+                        | TokenKind::NullLiteral(_)
+                        | TokenKind::BooleanLiteral(_)
+                        | TokenKind::PrivateIdentifier(_)
+                        | TokenKind::Punctuator(Punctuator::OpenBracket),
+                    ) => {
+                        if contains_escape {
+                            return Err(Error::general(
+                                "keyword must not contain escaped characters",
+                                accessor_position,
+                            ));
+                        }
+                        // this "accessor" is a keyword.
+                        cursor.advance(interner);
+                        true
+                    }
+                    _ => false,
+                }
+            }
+            _ => false,
+        };
+
         let is_keyword = !matches!(
             cursor.peek(1, interner).or_abrupt()?.kind(),
             TokenKind::Punctuator(
@@ -585,7 +682,71 @@ where
 
         let position = token.span().start();
         let element = match token.kind() {
+            TokenKind::PrivateIdentifier(Sym::CONSTRUCTOR) if accessor => {
+                return Err(Error::general(
+                    "class accessor may not be named '#constructor'",
+                    position,
+                ));
+            }
+            TokenKind::PrivateIdentifier(name) if accessor => {
+                let name = *name;
+                let name_span = token.span();
+                cursor.advance(interner);
+                let function_name = interner.get_or_intern(
+                    [utf16!("#"), interner.resolve_expect(name).utf16()]
+                        .concat()
+                        .as_slice(),
+                );
+                let initializer = self.parse_field_initializer(
+                    Identifier::new(function_name, name_span),
+                    cursor,
+                    interner,
+                )?;
+                let field = PrivateFieldDefinition::new(
+                    PrivateName::new(name, name_span),
+                    initializer,
+                    true,
+                    decorators,
+                );
+                if r#static {
+                    function::ClassElement::PrivateStaticFieldDefinition(field)
+                } else {
+                    function::ClassElement::PrivateFieldDefinition(field)
+                }
+            }
+            _ if accessor => {
+                let name = PropertyName::new(self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?;
+                if let Some(name) = name.literal() {
+                    if r#static {
+                        if [Sym::CONSTRUCTOR, Sym::PROTOTYPE].contains(&name.sym()) {
+                            return Err(Error::general(
+                                "class may not have static accessors named 'constructor' or 'prototype'",
+                                position,
+                            ));
+                        }
+                    } else if name == Sym::CONSTRUCTOR {
+                        return Err(Error::general(
+                            "class may not have accessors named 'constructor'",
+                            position,
+                        ));
+                    }
+                }
+                let initializer = self.parse_field_initializer(name.literal(), cursor, interner)?;
+                let field = ClassFieldDefinition::new(name, initializer, true, decorators);
+                if r#static {
+                    function::ClassElement::StaticFieldDefinition(field)
+                } else {
+                    function::ClassElement::FieldDefinition(field)
+                }
+            }
             TokenKind::IdentifierName((Sym::CONSTRUCTOR, _)) if !r#static => {
+                if !decorators.is_empty() {
+                    return Err(Error::general(
+                        "decorators are not valid here",
+                        decorators_position,
+                    ));
+                }
                 cursor.advance(interner);
                 let strict = cursor.strict();
                 cursor.set_strict(true);
@@ -612,6 +773,12 @@ where
                 ));
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) if r#static => {
+                if !decorators.is_empty() {
+                    return Err(Error::general(
+                        "decorators are not valid here",
+                        decorators_position,
+                    ));
+                }
                 cursor.advance(interner);
                 let (statement_list, end) = if let Some(token) =
                     cursor.next_if(TokenKind::Punctuator(Punctuator::CloseBlock), interner)?
@@ -747,6 +914,7 @@ where
                     MethodDefinitionKind::Generator,
                     r#static,
                     start_linear_pos,
+                    decorators,
                 ))
             }
             TokenKind::Keyword((Keyword::Async, true)) if is_keyword => {
@@ -809,6 +977,7 @@ where
                             MethodDefinitionKind::AsyncGenerator,
                             r#static,
                             start_linear_pos,
+                            decorators,
                         ))
                     }
                     TokenKind::IdentifierName((Sym::CONSTRUCTOR, _)) if !r#static => {
@@ -855,6 +1024,7 @@ where
                             MethodDefinitionKind::Async,
                             r#static,
                             start_linear_pos,
+                            decorators,
                         ))
                     }
                 }
@@ -906,6 +1076,7 @@ where
                             MethodDefinitionKind::Get,
                             r#static,
                             start_linear_pos,
+                            decorators,
                         ))
                     }
                     TokenKind::IdentifierName((Sym::CONSTRUCTOR, _)) if !r#static => {
@@ -954,13 +1125,18 @@ where
                             MethodDefinitionKind::Get,
                             r#static,
                             start_linear_pos,
+                            decorators,
                         ))
                     }
                     _ => {
                         let span = token.span();
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field =
-                            ClassFieldDefinition::new(Identifier::new(Sym::GET, span).into(), None);
+                        let field = ClassFieldDefinition::new(
+                            Identifier::new(Sym::GET, span).into(),
+                            None,
+                            false,
+                            decorators,
+                        );
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
@@ -1009,6 +1185,7 @@ where
                             MethodDefinitionKind::Set,
                             r#static,
                             start_linear_pos,
+                            decorators,
                         ))
                     }
                     TokenKind::IdentifierName((Sym::CONSTRUCTOR, _)) if !r#static => {
@@ -1056,13 +1233,18 @@ where
                             MethodDefinitionKind::Set,
                             r#static,
                             start_linear_pos,
+                            decorators,
                         ))
                     }
                     _ => {
                         let span = token.span();
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field =
-                            ClassFieldDefinition::new(Identifier::new(Sym::SET, span).into(), None);
+                        let field = ClassFieldDefinition::new(
+                            Identifier::new(Sym::SET, span).into(),
+                            None,
+                            false,
+                            decorators,
+                        );
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
@@ -1105,6 +1287,8 @@ where
                         let field = PrivateFieldDefinition::new(
                             PrivateName::new(name, name_span),
                             Some(rhs),
+                            false,
+                            decorators,
                         );
                         if r#static {
                             function::ClassElement::PrivateStaticFieldDefinition(field)
@@ -1136,12 +1320,17 @@ where
                             MethodDefinitionKind::Ordinary,
                             r#static,
                             start_linear_pos,
+                            decorators,
                         ))
                     }
                     _ => {
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field =
-                            PrivateFieldDefinition::new(PrivateName::new(name, name_span), None);
+                        let field = PrivateFieldDefinition::new(
+                            PrivateName::new(name, name_span),
+                            None,
+                            false,
+                            decorators,
+                        );
                         if r#static {
                             function::ClassElement::PrivateStaticFieldDefinition(field)
                         } else {
@@ -1189,7 +1378,7 @@ where
                         if let Some(name) = name.literal() {
                             rhs.set_anonymous_function_definition_name(&name);
                         }
-                        let field = ClassFieldDefinition::new(name, Some(rhs));
+                        let field = ClassFieldDefinition::new(name, Some(rhs), false, decorators);
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
@@ -1228,6 +1417,7 @@ where
                             MethodDefinitionKind::Ordinary,
                             r#static,
                             start_linear_pos,
+                            decorators,
                         ))
                     }
                     _ => {
@@ -1247,7 +1437,7 @@ where
                             }
                         }
                         cursor.expect_semicolon("expected semicolon", interner)?;
-                        let field = ClassFieldDefinition::new(name, None);
+                        let field = ClassFieldDefinition::new(name, None, false, decorators);
                         if r#static {
                             function::ClassElement::StaticFieldDefinition(field)
                         } else {
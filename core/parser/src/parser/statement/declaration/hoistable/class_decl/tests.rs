@@ -1,4 +1,4 @@
-use crate::parser::tests::check_script_parser;
+use crate::parser::tests::{check_invalid_script, check_script_parser};
 use boa_ast::{
     Declaration, Expression, Span, Statement, StatementList, StatementListItem,
     declaration::{LexicalDeclaration, Variable, VariableList},
@@ -8,11 +8,16 @@ use boa_ast::{
         literal::Literal,
     },
     function::{
-        ClassDeclaration, ClassElement, ClassFieldDefinition, ClassMethodDefinition,
-        FormalParameterList, FunctionBody, FunctionExpression,
+        ClassDeclaration, ClassElement, ClassElementName, ClassFieldDefinition,
+        ClassMethodDefinition, FormalParameterList, FunctionBody, FunctionExpression,
     },
     property::MethodDefinitionKind,
 };
+#[cfg(feature = "experimental")]
+use boa_ast::{
+    expression::Parenthesized,
+    function::{Decorator, PrivateName},
+};
 use boa_interner::Interner;
 use boa_macros::utf16;
 use indoc::indoc;
@@ -22,7 +27,7 @@ fn check_async_ordinary_method() {
     let interner = &mut Interner::default();
 
     let elements = vec![ClassElement::MethodDefinition(ClassMethodDefinition::new(
-        boa_ast::function::ClassElementName::PropertyName(
+        ClassElementName::PropertyName(
             Identifier::new(
                 interner.get_or_intern_static("async", utf16!("async")),
                 Span::new((2, 5), (2, 10)),
@@ -34,6 +39,7 @@ fn check_async_ordinary_method() {
         MethodDefinitionKind::Ordinary,
         false,
         boa_ast::LinearPosition::default(),
+        Box::default(),
     ))];
 
     check_script_parser(
@@ -51,6 +57,7 @@ fn check_async_ordinary_method() {
                 None,
                 None,
                 elements.into(),
+                Box::default(),
             )
             .into(),
         )
@@ -70,6 +77,8 @@ fn check_async_field_initialization() {
         )
         .into(),
         Some(Literal::new(1, Span::new((3, 7), (3, 8))).into()),
+        false,
+        Box::default(),
     ))];
 
     check_script_parser(
@@ -88,6 +97,7 @@ fn check_async_field_initialization() {
                 None,
                 None,
                 elements.into(),
+                Box::default(),
             )))
             .into(),
         ],
@@ -106,6 +116,8 @@ fn check_async_field() {
         )
         .into(),
         None,
+        false,
+        Box::default(),
     ))];
 
     check_script_parser(
@@ -123,6 +135,7 @@ fn check_async_field() {
                 None,
                 None,
                 elements.into(),
+                Box::default(),
             )))
             .into(),
         ],
@@ -187,6 +200,7 @@ fn check_new_target_with_property_access() {
         None,
         Some(constructor),
         Box::default(),
+        Box::default(),
     );
 
     let instantiation = Expression::New(
@@ -248,6 +262,7 @@ fn check_non_reserved_keyword_as_identifier() {
                 None,
                 None,
                 vec![].into(),
+                Box::default(),
             )
             .into(),
         )
@@ -255,3 +270,143 @@ fn check_non_reserved_keyword_as_identifier() {
         interner,
     );
 }
+
+#[cfg(feature = "experimental")]
+#[test]
+fn check_decorators() {
+    let interner = &mut Interner::default();
+    let dec = interner.get_or_intern_static("dec", utf16!("dec"));
+
+    let elements = vec![
+        ClassElement::FieldDefinition(ClassFieldDefinition::new(
+            Identifier::new(
+                interner.get_or_intern_static("x", utf16!("x")),
+                Span::new((3, 19), (3, 20)),
+            )
+            .into(),
+            None,
+            true,
+            vec![Decorator::new(
+                Identifier::new(dec, Span::new((3, 6), (3, 9))).into(),
+            )]
+            .into(),
+        )),
+        ClassElement::MethodDefinition(ClassMethodDefinition::new(
+            ClassElementName::PrivateName(PrivateName::new(
+                interner.get_or_intern_static("m", utf16!("m")),
+                Span::new((4, 19), (4, 21)),
+            )),
+            FormalParameterList::default(),
+            FunctionBody::new(StatementList::default(), Span::new((4, 24), (4, 26))),
+            MethodDefinitionKind::Ordinary,
+            true,
+            boa_ast::LinearPosition::default(),
+            vec![Decorator::new(
+                Parenthesized::new(
+                    Identifier::new(dec, Span::new((4, 7), (4, 10))).into(),
+                    Span::new((4, 6), (4, 11)),
+                )
+                .into(),
+            )]
+            .into(),
+        )),
+    ];
+
+    check_script_parser(
+        indoc! {r#"
+            @dec
+            class A {
+                @dec accessor x;
+                @(dec) static #m() {}
+            }
+        "#},
+        [Declaration::ClassDeclaration(
+            ClassDeclaration::new(
+                Identifier::new(
+                    interner.get_or_intern_static("A", utf16!("A")),
+                    Span::new((2, 7), (2, 8)),
+                ),
+                None,
+                None,
+                elements.into(),
+                vec![Decorator::new(
+                    Identifier::new(dec, Span::new((1, 2), (1, 5))).into(),
+                )]
+                .into(),
+            )
+            .into(),
+        )
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_accessor_as_field_name() {
+    let interner = &mut Interner::default();
+    let accessor = interner.get_or_intern_static("accessor", utf16!("accessor"));
+
+    let elements = vec![
+        ClassElement::FieldDefinition(ClassFieldDefinition::new(
+            Identifier::new(accessor, Span::new((2, 5), (2, 13))).into(),
+            None,
+            false,
+            Box::default(),
+        )),
+        ClassElement::FieldDefinition(ClassFieldDefinition::new(
+            Identifier::new(
+                interner.get_or_intern_static("x", utf16!("x")),
+                Span::new((3, 5), (3, 6)),
+            )
+            .into(),
+            None,
+            false,
+            Box::default(),
+        )),
+    ];
+
+    check_script_parser(
+        indoc! {r#"
+            class A {
+                accessor
+                x
+            }
+        "#},
+        [Declaration::ClassDeclaration(
+            ClassDeclaration::new(
+                Identifier::new(
+                    interner.get_or_intern_static("A", utf16!("A")),
+                    Span::new((1, 7), (1, 8)),
+                ),
+                None,
+                None,
+                elements.into(),
+                Box::default(),
+            )
+            .into(),
+        )
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_invalid_decorators() {
+    check_invalid_script("class A { @dec constructor() {} }");
+    check_invalid_script("class A { @dec static {} }");
+    check_invalid_script("class A { @dec; }");
+    check_invalid_script("class A { @dec.[x] m() {} }");
+    check_invalid_script("class A { accessor constructor; }");
+    check_invalid_script("class A { static accessor prototype; }");
+    check_invalid_script("class A { accessor #x; #x; }");
+    check_invalid_script("@dec function f() {}");
+}
+
+#[cfg(not(feature = "experimental"))]
+#[test]
+fn check_decorators_require_experimental() {
+    check_invalid_script("@dec class A {}");
+    check_invalid_script("(@dec class {})");
+    check_invalid_script("class A { @dec m() {} }");
+    check_invalid_script("class A { accessor x; }");
+}
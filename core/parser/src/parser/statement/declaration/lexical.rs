@@ -193,6 +193,23 @@ pub(crate) fn allowed_token_after_let(token: Option<&Token>) -> bool {
     )
 }
 
+/// Check if the given token is valid after the `using` keyword of a `using` declaration.
+///
+/// The token must be peeked without skipping line terminators, since there cannot be a line
+/// terminator between `using` and the first binding of the declaration.
+pub(crate) fn allowed_token_after_using(token: Option<&Token>) -> bool {
+    matches!(
+        token.map(Token::kind),
+        Some(
+            TokenKind::IdentifierName(_)
+                | TokenKind::Keyword((
+                    Keyword::Yield | Keyword::Let | Keyword::Async | Keyword::Of | Keyword::Using,
+                    _
+                ))
+        )
+    )
+}
+
 /// Parses a binding list.
 ///
 /// It will return an error if a `const` or `using` declaration is being parsed and there is no
@@ -17,10 +17,11 @@ mod tests;
 pub(in crate::parser) use self::{
     export::ExportDeclaration,
     hoistable::{
-        ClassDeclaration, FunctionDeclaration, HoistableDeclaration, class_decl::ClassTail,
+        ClassDeclaration, FunctionDeclaration, HoistableDeclaration,
+        class_decl::{ClassTail, DecoratorList},
     },
     import::ImportDeclaration,
-    lexical::{LexicalDeclaration, allowed_token_after_let},
+    lexical::{LexicalDeclaration, allowed_token_after_let, allowed_token_after_using},
 };
 use crate::{
     Error,
@@ -71,7 +72,8 @@ where
         let tok_str = tok.to_string(interner);
 
         match tok_kind {
-            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _)) => {
+            TokenKind::Keyword((Keyword::Function | Keyword::Async | Keyword::Class, _))
+            | TokenKind::Punctuator(Punctuator::At) => {
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false)
                     .parse(cursor, interner)
             }
@@ -83,7 +85,12 @@ where
             TokenKind::Keyword((Keyword::Await, false)) => {
                 // Check if this is `await using`
                 // Per spec, there must be [no LineTerminator here] between `await` and `using`
-                if let Some(next_tok) = cursor.peek_no_skip_line_term(1, interner)?
+                let skip_n = if cursor.peek_is_line_terminator(0, interner).or_abrupt()? {
+                    2
+                } else {
+                    1
+                };
+                if let Some(next_tok) = cursor.peek_no_skip_line_term(skip_n, interner)?
                     && next_tok.kind() != &TokenKind::LineTerminator
                     && matches!(next_tok.kind(), TokenKind::Keyword((Keyword::Using, false)))
                 {
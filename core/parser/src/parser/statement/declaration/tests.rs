@@ -663,13 +663,13 @@ fn import_non_string_attribute_value() {
     );
 }
 
-/// Checks `using` declaration parsing.
+/// Checks `using` declaration parsing in a module.
 #[test]
 fn using_declaration() {
     let interner = &mut Interner::default();
-    check_script_parser(
+    check_module_parser(
         "using x = resource;",
-        vec![
+        vec![ModuleItem::StatementListItem(
             Declaration::Lexical(LexicalDeclaration::Using(
                 vec![Variable::from_identifier(
                     Identifier::new(
@@ -688,18 +688,18 @@ fn using_declaration() {
                 .unwrap(),
             ))
             .into(),
-        ],
+        )],
         interner,
     );
 }
 
-/// Checks `using` declaration with multiple bindings.
+/// Checks `using` declaration with multiple bindings in a module.
 #[test]
 fn using_declaration_multiple() {
     let interner = &mut Interner::default();
-    check_script_parser(
+    check_module_parser(
         "using a = res1, b = res2;",
-        vec![
+        vec![ModuleItem::StatementListItem(
             Declaration::Lexical(LexicalDeclaration::Using(
                 vec![
                     Variable::from_identifier(
@@ -733,7 +733,7 @@ fn using_declaration_multiple() {
                 .unwrap(),
             ))
             .into(),
-        ],
+        )],
         interner,
     );
 }
@@ -741,7 +741,60 @@ fn using_declaration_multiple() {
 /// Checks that `using` declaration without initializer fails.
 #[test]
 fn using_declaration_no_init() {
-    check_invalid_script("using x;");
+    check_invalid_script("{ using x; }");
+}
+
+/// Checks that `using` declarations are rejected at the top level of a script.
+#[test]
+fn using_declaration_script_top_level() {
+    check_invalid_script("using x = resource;");
+    check_invalid_script("using a = res1, b = res2;");
+}
+
+/// Checks that `using` is still a valid identifier when not followed by a binding.
+#[test]
+fn using_as_identifier() {
+    let interner = &mut Interner::default();
+    let source = Source::from_bytes(indoc! {"
+        var using = 1;
+        using;
+        using
+        x = using;
+        label: { using = using + 1; }
+        for (using of []);
+    "});
+    let scope = boa_ast::scope::Scope::new_global();
+    let result = Parser::new(source).parse_script(&scope, interner);
+    assert!(
+        result.is_ok(),
+        "Failed to parse `using` as an identifier: {:?}",
+        result.err()
+    );
+}
+
+/// Checks `using` declarations in the head of for loops.
+#[test]
+fn using_declaration_for_loops() {
+    let interner = &mut Interner::default();
+    let source = Source::from_bytes(indoc! {"
+        for (using x = resource; ; ) {}
+        for (using x of resources) {}
+        for (using of of resources) {}
+        async function f() {
+            for (await using x of resources) {}
+            for await (await using x of resources) {}
+        }
+    "});
+    let scope = boa_ast::scope::Scope::new_global();
+    let result = Parser::new(source).parse_script(&scope, interner);
+    assert!(
+        result.is_ok(),
+        "Failed to parse `using` in for loops: {:?}",
+        result.err()
+    );
+
+    check_invalid_script("for (using x in object) {}");
+    check_invalid_script("for (using x; ; ) {}");
 }
 
 /// Checks `await using` declaration parsing in async function.
@@ -804,10 +857,20 @@ fn using_no_destructuring_object() {
     check_invalid_script("using {x, y} = resource;");
 }
 
-/// Checks that destructuring patterns are rejected for `using` declarations.
+/// Checks that `using [` is not parsed as a destructuring `using` declaration.
+///
+/// Since array patterns are not allowed, `using [a, b]` is a computed member expression.
 #[test]
 fn using_no_destructuring_array() {
-    check_invalid_script("using [a, b] = resource;");
+    let interner = &mut Interner::default();
+    let source = Source::from_bytes("{ using [a, b] = resource; }");
+    let scope = boa_ast::scope::Scope::new_global();
+    let result = Parser::new(source).parse_script(&scope, interner);
+    assert!(
+        result.is_ok(),
+        "Failed to parse `using [` as a member expression: {:?}",
+        result.err()
+    );
 }
 
 /// Checks that destructuring patterns are rejected for `await using` declarations.
@@ -840,13 +903,13 @@ fn await_using_duplicate_names() {
     check_invalid_script("async function f() { await using x = r1, x = r2; }");
 }
 
-/// Checks that `using` works with valid identifiers.
+/// Checks that `using` works with valid identifiers in a module.
 #[test]
 fn using_valid_identifiers() {
     let interner = &mut Interner::default();
-    check_script_parser(
+    check_module_parser(
         "using x = resource, y = resource2;",
-        vec![
+        vec![ModuleItem::StatementListItem(
             Declaration::Lexical(LexicalDeclaration::Using(
                 vec![
                     Variable::from_identifier(
@@ -880,7 +943,7 @@ fn using_valid_identifiers() {
                 .unwrap(),
             ))
             .into(),
-        ],
+        )],
         interner,
     );
 }
@@ -9,13 +9,13 @@
 
 use crate::{
     Error,
-    lexer::{Error as LexError, TokenKind},
+    lexer::{Error as LexError, Token, TokenKind},
     parser::{
         AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
         expression::{AssignmentExpression, Expression},
         statement::{
             Statement,
-            declaration::{LexicalDeclaration, allowed_token_after_let},
+            declaration::{LexicalDeclaration, allowed_token_after_let, allowed_token_after_using},
             variable::VariableDeclarationList,
         },
     },
@@ -108,6 +108,8 @@ where
         };
 
         let mut init_is_async_of = false;
+        // The first token of the initializer can be preceded by a line terminator.
+        let skip_n = usize::from(cursor.peek_is_line_terminator(0, interner).or_abrupt()?);
         let init = match cursor.peek(0, interner).or_abrupt()?.kind().clone() {
             TokenKind::Keyword((Keyword::Var, _)) => {
                 cursor.advance(interner);
@@ -131,6 +133,31 @@ where
                     .parse(cursor, interner)?
                     .into(),
             ),
+            TokenKind::Keyword((Keyword::Using, false))
+                if is_using_declaration(cursor, skip_n, interner)? =>
+            {
+                Some(
+                    LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
+                        .parse(cursor, interner)?
+                        .into(),
+                )
+            }
+            TokenKind::Keyword((Keyword::Await, false))
+                if self.allow_await.0
+                    && matches!(
+                        cursor
+                            .peek_no_skip_line_term(skip_n + 1, interner)?
+                            .map(Token::kind),
+                        Some(TokenKind::Keyword((Keyword::Using, false)))
+                    )
+                    && is_using_declaration(cursor, skip_n + 1, interner)? =>
+            {
+                Some(
+                    LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
+                        .parse(cursor, interner)?
+                        .into(),
+                )
+            }
             TokenKind::Keyword((Keyword::Async, false)) if !r#await => {
                 if matches!(
                     cursor.peek(1, interner).or_abrupt()?.kind(),
@@ -221,7 +248,10 @@ where
                 // Checks are only applicable to lexical bindings.
                 if matches!(
                     &init,
-                    IterableLoopInitializer::Const(_) | IterableLoopInitializer::Let(_)
+                    IterableLoopInitializer::Const(_)
+                        | IterableLoopInitializer::Let(_)
+                        | IterableLoopInitializer::Using(_)
+                        | IterableLoopInitializer::AwaitUsing(_)
                 ) {
                     // It is a Syntax Error if the BoundNames of ForDeclaration contains "let".
                     // It is a Syntax Error if any element of the BoundNames of ForDeclaration also occurs in the VarDeclaredNames of Statement.
@@ -258,15 +288,21 @@ where
             (init, _) => init,
         };
 
-        if let Some(ForLoopInitializer::Lexical(initializer)) = &init
-            && let ast::declaration::LexicalDeclaration::Const(list) = initializer.declaration()
-        {
-            for decl in list.as_ref() {
-                if decl.init().is_none() {
-                    return Err(Error::general(
-                        "Expected initializer for const declaration",
-                        position,
-                    ));
+        if let Some(ForLoopInitializer::Lexical(initializer)) = &init {
+            let kind = match initializer.declaration() {
+                ast::declaration::LexicalDeclaration::Const(_) => Some("const"),
+                ast::declaration::LexicalDeclaration::Using(_) => Some("using"),
+                ast::declaration::LexicalDeclaration::AwaitUsing(_) => Some("await using"),
+                ast::declaration::LexicalDeclaration::Let(_) => None,
+            };
+            if let Some(kind) = kind {
+                for decl in initializer.declaration().variable_list().as_ref() {
+                    if decl.init().is_none() {
+                        return Err(Error::general(
+                            format!("Expected initializer for {kind} declaration"),
+                            position,
+                        ));
+                    }
                 }
             }
         }
@@ -324,6 +360,41 @@ where
     }
 }
 
+/// Checks if the `using` keyword peeked at `skip_n` starts a `using` declaration in the head of
+/// a for statement.
+///
+/// `for (using of of iterable)` declares a binding named `of`, while `for (using of iterable)`
+/// assigns to an identifier named `using`.
+fn is_using_declaration<R>(
+    cursor: &mut Cursor<R>,
+    skip_n: usize,
+    interner: &mut Interner,
+) -> ParseResult<bool>
+where
+    R: ReadChar,
+{
+    let next = cursor.peek_no_skip_line_term(skip_n + 1, interner)?;
+    let next_is_of = matches!(
+        next.map(Token::kind),
+        Some(TokenKind::Keyword((Keyword::Of, false)))
+    );
+    if !allowed_token_after_using(next) {
+        return Ok(false);
+    }
+    if !next_is_of {
+        return Ok(true);
+    }
+    Ok(matches!(
+        cursor
+            .peek_no_skip_line_term(skip_n + 2, interner)?
+            .map(Token::kind),
+        Some(
+            TokenKind::Keyword((Keyword::Of, _))
+                | TokenKind::Punctuator(Punctuator::Assign | Punctuator::Semicolon)
+        )
+    ))
+}
+
 fn initializer_to_iterable_loop_initializer(
     initializer: ForLoopInitializer,
     position: Position,
@@ -386,16 +457,30 @@ fn initializer_to_iterable_loop_initializer(
                         position,
                     )));
                     }
-                    Ok(match initializer.declaration() {
+                    match initializer.declaration() {
                         ast::declaration::LexicalDeclaration::Const(_) => {
-                            IterableLoopInitializer::Const(decl.binding().clone())
+                            Ok(IterableLoopInitializer::Const(decl.binding().clone()))
                         }
-                        ast::declaration::LexicalDeclaration::Let(_)
-                        | ast::declaration::LexicalDeclaration::Using(_)
-                        | ast::declaration::LexicalDeclaration::AwaitUsing(_) => {
-                            IterableLoopInitializer::Let(decl.binding().clone())
+                        ast::declaration::LexicalDeclaration::Let(_) => {
+                            Ok(IterableLoopInitializer::Let(decl.binding().clone()))
                         }
-                    })
+                        ast::declaration::LexicalDeclaration::Using(_)
+                        | ast::declaration::LexicalDeclaration::AwaitUsing(_)
+                            if in_loop =>
+                        {
+                            Err(Error::lex(LexError::Syntax(
+                                "a using declaration is not allowed in the head of a for-in loop"
+                                    .into(),
+                                position,
+                            )))
+                        }
+                        ast::declaration::LexicalDeclaration::Using(_) => {
+                            Ok(IterableLoopInitializer::Using(decl.binding().clone()))
+                        }
+                        ast::declaration::LexicalDeclaration::AwaitUsing(_) => {
+                            Ok(IterableLoopInitializer::AwaitUsing(decl.binding().clone()))
+                        }
+                    }
                 }
                 _ => Err(Error::lex(LexError::Syntax(
                     format!("only one variable can be declared in the head of a {loop_type} loop")
@@ -20,6 +20,7 @@ mod utf8;
 pub struct Source<'path, R> {
     pub(crate) reader: R,
     pub(crate) path: Option<&'path Path>,
+    pub(crate) source_map: Option<String>,
 }
 
 impl<'bytes> Source<'static, UTF8Input<&'bytes [u8]>> {
@@ -40,6 +41,7 @@ impl<'bytes> Source<'static, UTF8Input<&'bytes [u8]>> {
         Self {
             reader: UTF8Input::new(source.as_ref()),
             path: None,
+            source_map: None,
         }
     }
 }
@@ -62,6 +64,7 @@ impl<'input> Source<'static, UTF16Input<'input>> {
         Self {
             reader: UTF16Input::new(input),
             path: None,
+            source_map: None,
         }
     }
 }
@@ -89,6 +92,7 @@ impl<'path> Source<'path, UTF8Input<BufReader<File>>> {
         Ok(Self {
             reader: UTF8Input::new(BufReader::new(reader)),
             path: Some(source),
+            source_map: None,
         })
     }
 }
@@ -116,6 +120,7 @@ impl<'path, R: Read> Source<'path, UTF8Input<R>> {
         Self {
             reader: UTF8Input::new(reader),
             path,
+            source_map: None,
         }
     }
 }
@@ -126,6 +131,22 @@ impl<'path, R> Source<'path, R> {
         Source {
             reader: self.reader,
             path: Some(new_path),
+            source_map: self.source_map,
+        }
+    }
+
+    /// Sets the [Source Map v3][spec] of this [`Source`], used to map positions in the code back to
+    /// the original sources it was generated from.
+    ///
+    /// A source map set with this method takes precedence over any inline
+    /// `//# sourceMappingURL=data:...` comment in the code.
+    ///
+    /// [spec]: https://tc39.es/ecma426/
+    #[must_use]
+    pub fn with_source_map(self, source_map: impl Into<String>) -> Self {
+        Self {
+            source_map: Some(source_map.into()),
+            ..self
         }
     }
 
@@ -133,6 +154,11 @@ impl<'path, R> Source<'path, R> {
     pub fn path(&self) -> Option<&'path Path> {
         self.path
     }
+
+    /// Returns the source map (if any) of this source file.
+    pub fn source_map(&self) -> Option<&str> {
+        self.source_map.as_deref()
+    }
 }
 
 /// This trait is used to abstract over the different types of input readers.
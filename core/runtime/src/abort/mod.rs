@@ -1,15 +1,19 @@
 //! `AbortController` and `AbortSignal` Web API implementations.
+//!
+//! `AbortSignal` is an [`EventTarget`]: aborting a signal fires a trusted `abort` event at
+//! it, synchronously.
 
 use boa_engine::class::Class;
-use boa_engine::job::GenericJob;
-use boa_engine::object::builtins::JsFunction;
+use boa_engine::interop::JsClass;
 use boa_engine::realm::Realm;
 use boa_engine::{
     Context, Finalize, JsData, JsError, JsNativeError, JsObject, JsResult, JsValue, Trace,
     boa_class, boa_module, js_error, js_string,
 };
-use boa_gc::GcRefCell;
-use std::cell::Cell;
+use boa_gc::{Gc, GcRefCell};
+use boa_wintertc::events::{
+    self, AbortSignalData, AbortState, EventTarget, EventTargetData, JsEventTarget,
+};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -46,56 +50,103 @@ fn make_abort_error(context: &mut Context) -> JsValue {
     obj.into()
 }
 
-/// The JavaScript `AbortSignal` class.
-#[derive(Debug, Clone, JsData, Trace, Finalize)]
-pub struct JsAbortSignal {
-    #[unsafe_ignore_trace]
-    aborted: Cell<bool>,
+/// The state of an `AbortSignal`, shared by all the handles to it.
+#[derive(Debug, Trace, Finalize)]
+struct AbortSignalState {
     reason: GcRefCell<Option<JsValue>>,
-    listeners: GcRefCell<Vec<JsFunction>>,
+    /// The `AbortSignal` object of this signal, which is the target of its `abort` event.
+    object: GcRefCell<Option<JsObject>>,
     #[unsafe_ignore_trace]
     cancel_token: CancellationToken,
 }
 
+/// The JavaScript `AbortSignal` class.
+///
+/// This is a handle to the state of the signal, so clones refer to the same signal.
+#[derive(Debug, Clone, JsData, Trace, Finalize)]
+pub struct JsAbortSignal {
+    state: Gc<AbortSignalState>,
+    target: EventTarget,
+    abort: AbortState,
+}
+
 impl Default for JsAbortSignal {
     fn default() -> Self {
         Self {
-            aborted: Cell::new(false),
-            reason: GcRefCell::default(),
-            listeners: GcRefCell::default(),
-            cancel_token: CancellationToken::new(),
+            state: Gc::new(AbortSignalState {
+                reason: GcRefCell::default(),
+                object: GcRefCell::default(),
+                cancel_token: CancellationToken::new(),
+            }),
+            target: EventTarget::default(),
+            abort: AbortState::default(),
         }
     }
 }
 
+impl EventTargetData for JsAbortSignal {
+    fn event_target(&self) -> &EventTarget {
+        &self.target
+    }
+}
+
+impl AbortSignalData for JsAbortSignal {
+    fn abort_state(&self) -> &AbortState {
+        &self.abort
+    }
+}
+
 impl JsAbortSignal {
+    /// Creates a new `AbortSignal` object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `AbortSignal` class is not registered.
+    pub fn new_object(context: &mut Context) -> JsResult<JsObject> {
+        let signal = Self::default();
+        let object = Self::from_data(signal.clone(), context)?;
+        *signal.state.object.borrow_mut() = Some(object.clone());
+        Ok(object)
+    }
+
+    /// Returns a handle to the signal of the `AbortSignal` object `object`.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the signal has already been aborted.
+    /// Returns an error if `object` is not an `AbortSignal`.
+    pub fn from_object(object: &JsObject) -> JsResult<Self> {
+        object
+            .downcast_ref::<Self>()
+            .map(|signal| signal.clone())
+            .ok_or_else(|| js_error!(TypeError: "object is not an AbortSignal"))
+    }
+
+    /// Aborts this signal with `reason`, then fires an `abort` event at its object. Does
+    /// nothing if the signal is already aborted.
+    ///
+    /// `abort` listeners run synchronously, so the object of the signal must not be borrowed
+    /// while calling this; use a handle returned by [`JsAbortSignal::from_object`] instead.
+    ///
+    /// See <https://dom.spec.whatwg.org/#abortsignal-signal-abort>
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `abort` event cannot be created.
     pub fn signal_abort(&self, reason: JsValue, context: &mut Context) -> JsResult<()> {
-        if self.aborted.get() {
+        let state = &self.state;
+        if self.abort.is_aborted() {
             return Ok(());
         }
-        self.aborted.set(true);
-        *self.reason.borrow_mut() = Some(reason);
-
-        let listeners: Vec<JsFunction> = self.listeners.borrow_mut().drain(..).collect();
-
-        let realm = context.realm().clone();
-        for listener in listeners {
-            context.enqueue_job(
-                GenericJob::new(
-                    move |context| {
-                        listener.call(&JsValue::undefined(), &[], context)?;
-                        Ok(JsValue::undefined())
-                    },
-                    realm.clone(),
-                )
-                .into(),
-            );
-        }
+        *state.reason.borrow_mut() = Some(reason);
+        state.cancel_token.cancel();
 
-        self.cancel_token.cancel();
+        // Run the abort algorithms, which remove the event listeners bound to this signal.
+        self.abort.abort();
+
+        let object = state.object.borrow().clone();
+        if let Some(object) = object {
+            events::fire_event(&object, js_string!("abort"), context)?;
+        }
 
         Ok(())
     }
@@ -103,15 +154,16 @@ impl JsAbortSignal {
     /// Returns `true` if this signal has been aborted.
     #[must_use]
     pub fn is_aborted(&self) -> bool {
-        self.aborted.get()
+        self.abort.is_aborted()
     }
 
     /// Returns the abort reason.
     pub fn abort_reason(&self, context: &mut Context) -> JsValue {
-        if !self.aborted.get() {
+        if !self.is_aborted() {
             return JsValue::undefined();
         }
-        self.reason
+        self.state
+            .reason
             .borrow()
             .clone()
             .unwrap_or_else(|| make_abort_error(context))
@@ -120,7 +172,7 @@ impl JsAbortSignal {
     /// Returns the cancellation token.
     #[must_use]
     pub fn cancellation_token(&self) -> CancellationToken {
-        self.cancel_token.clone()
+        self.state.cancel_token.clone()
     }
 }
 
@@ -136,7 +188,7 @@ impl JsAbortSignal {
 
     #[boa(getter)]
     fn aborted(&self) -> bool {
-        self.aborted.get()
+        self.is_aborted()
     }
 
     #[boa(getter)]
@@ -145,38 +197,12 @@ impl JsAbortSignal {
     }
 
     fn throw_if_aborted(&self, context: &mut Context) -> JsResult<()> {
-        if self.aborted.get() {
+        if self.is_aborted() {
             Err(JsError::from_opaque(self.abort_reason(context)))
         } else {
             Ok(())
         }
     }
-
-    fn add_event_listener(
-        &self,
-        event_type: boa_engine::JsString,
-        callback: JsFunction,
-        context: &mut Context,
-    ) -> JsResult<()> {
-        if event_type.to_std_string_escaped() != "abort" {
-            return Err(js_error!(TypeError: "AbortSignal only supports the 'abort' event type"));
-        }
-        if self.aborted.get() {
-            callback.call(&JsValue::undefined(), &[], context)?;
-        } else {
-            self.listeners.borrow_mut().push(callback);
-        }
-        Ok(())
-    }
-
-    fn remove_event_listener(&self, event_type: boa_engine::JsString, callback: JsFunction) {
-        if event_type.to_std_string_escaped() != "abort" {
-            return;
-        }
-        self.listeners
-            .borrow_mut()
-            .retain(|f| !JsObject::equals(f, &callback));
-    }
 }
 
 /// The JavaScript `AbortController` class.
@@ -190,8 +216,8 @@ pub struct JsAbortController {
 impl JsAbortController {
     #[boa(constructor)]
     fn constructor(context: &mut Context) -> JsResult<Self> {
-        let signal_obj = Class::from_data(JsAbortSignal::default(), context)?;
-        Ok(Self { signal: signal_obj })
+        let signal = JsAbortSignal::new_object(context)?;
+        Ok(Self { signal })
     }
 
     #[boa(getter)]
@@ -199,12 +225,14 @@ impl JsAbortController {
         self.signal.clone()
     }
 
-    fn abort(&self, reason: Option<JsValue>, context: &mut Context) -> JsResult<()> {
+    #[allow(clippy::needless_pass_by_value)]
+    #[boa(method)]
+    fn abort(this: JsClass<Self>, reason: Option<JsValue>, context: &mut Context) -> JsResult<()> {
         let abort_reason = reason.unwrap_or_else(|| make_abort_error(context));
 
-        let Some(signal) = self.signal.downcast_ref::<JsAbortSignal>() else {
-            return Err(js_error!(TypeError: "AbortController: invalid signal object"));
-        };
+        // Abort listeners run synchronously, so neither the controller nor the signal must
+        // stay borrowed.
+        let signal = JsAbortSignal::from_object(&this.borrow().signal)?;
         signal.signal_abort(abort_reason, context)
     }
 }
@@ -216,8 +244,20 @@ pub mod js_module {
     type JsAbortSignal = super::JsAbortSignal;
 }
 
+/// Registers `AbortController` and `AbortSignal`, along with the event classes if they
+/// are not registered yet.
+///
 /// # Errors
 /// Returns an error if registration fails.
 pub fn register(realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
-    js_module::boa_register(realm, context)
+    let has_event_target = match &realm {
+        Some(realm) => realm.get_class::<JsEventTarget>().is_some(),
+        None => context.get_global_class::<JsEventTarget>().is_some(),
+    };
+    if !has_event_target {
+        events::register(realm.clone(), context)?;
+    }
+
+    js_module::boa_register(realm.clone(), context)?;
+    events::register_abort_signal_class::<JsAbortSignal>(realm, context)
 }
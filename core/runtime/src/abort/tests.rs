@@ -211,3 +211,112 @@ fn reason_abort_error_when_aborted_without_reason() {
         "#,
     )]);
 }
+
+#[test]
+fn signal_is_event_target() {
+    run_test_actions([TestAction::run(
+        r"
+        let ctrl = new AbortController();
+        if (!(ctrl.signal instanceof EventTarget)) {
+            throw new Error('signal should be an EventTarget');
+        }
+        ",
+    )]);
+}
+
+#[test]
+fn abort_event_is_dispatched_synchronously() {
+    run_test_actions([TestAction::run(
+        r#"
+        let ctrl = new AbortController();
+        let event = null;
+        ctrl.signal.addEventListener('abort', (e) => {
+            event = e;
+            if (!ctrl.signal.aborted) {
+                throw new Error('signal should be aborted before the event fires');
+            }
+        });
+        ctrl.abort();
+        if (event === null) {
+            throw new Error('abort event should be dispatched synchronously');
+        }
+        if (event.type !== "abort" || !event.isTrusted || event.target !== ctrl.signal) {
+            throw new Error('unexpected abort event');
+        }
+        "#,
+    )]);
+}
+
+#[test]
+fn remove_event_listener() {
+    run_test_actions([TestAction::run(
+        r"
+        let ctrl = new AbortController();
+        let called = false;
+        const listener = () => { called = true; };
+        ctrl.signal.addEventListener('abort', listener);
+        ctrl.signal.removeEventListener('abort', listener);
+        ctrl.abort();
+        if (called) {
+            throw new Error('removed listener should not be called');
+        }
+        ",
+    )]);
+}
+
+#[test]
+fn signal_option_removes_listener() {
+    run_test_actions([TestAction::run(
+        r"
+        let ctrl = new AbortController();
+        let target = new EventTarget();
+        let count = 0;
+        target.addEventListener('foo', () => { count += 1; }, { signal: ctrl.signal });
+        target.dispatchEvent(new Event('foo'));
+        ctrl.abort();
+        target.dispatchEvent(new Event('foo'));
+        target.addEventListener('foo', () => { count += 1; }, { signal: ctrl.signal });
+        target.dispatchEvent(new Event('foo'));
+        if (count !== 1) {
+            throw new Error('listener should only be called before abort, got: ' + count);
+        }
+        ",
+    )]);
+}
+
+#[test]
+fn signal_option_ignores_untrusted_abort_events() {
+    run_test_actions([TestAction::run(
+        r"
+        let ctrl = new AbortController();
+        let target = new EventTarget();
+        let count = 0;
+        target.addEventListener('foo', () => { count += 1; }, { signal: ctrl.signal });
+        ctrl.signal.dispatchEvent(new Event('abort'));
+        target.dispatchEvent(new Event('foo'));
+        if (count !== 1) {
+            throw new Error('a dispatched abort event should not remove the listener');
+        }
+        ",
+    )]);
+}
+
+#[test]
+fn signal_option_requires_abort_signal() {
+    run_test_actions([TestAction::run(
+        r"
+        let target = new EventTarget();
+        for (const signal of [new EventTarget(), globalThis, { aborted: false }]) {
+            let threw = false;
+            try {
+                target.addEventListener('foo', () => {}, { signal });
+            } catch (e) {
+                threw = e instanceof TypeError;
+            }
+            if (!threw) {
+                throw new Error('signal should be rejected: ' + signal);
+            }
+        }
+        ",
+    )]);
+}
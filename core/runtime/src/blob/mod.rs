@@ -0,0 +1,540 @@
+//! The `Blob` and `File` interfaces of the File API, implemented as [`JsBlob`] and
+//! [`JsFile`].
+//!
+//! Both classes share the [`Blob`] state, so the methods and accessors on `Blob.prototype`
+//! work with `File` instances too.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [W3C File API specification][spec]
+//!
+//! [spec]: https://w3c.github.io/FileAPI/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Blob
+
+use crate::stream::JsReadableStream;
+use boa_engine::class::Class;
+use boa_engine::interop::JsThis;
+use boa_engine::object::builtins::{
+    AlignedVec, JsArrayBuffer, JsDataView, JsPromise, JsTypedArray, JsUint8Array,
+};
+use boa_engine::realm::Realm;
+use boa_engine::value::{Convert, IntegerOrInfinity, TryFromJs};
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, boa_module,
+    js_error, js_string,
+};
+use cow_utils::CowUtils;
+use std::rc::Rc;
+
+#[cfg(test)]
+mod tests;
+
+/// The bytes and the MIME type of a blob.
+///
+/// See <https://w3c.github.io/FileAPI/#blob-section>
+#[derive(Debug, Clone, Default)]
+pub struct Blob {
+    bytes: Rc<[u8]>,
+    r#type: JsString,
+}
+
+impl Blob {
+    /// Creates a new blob from its bytes and MIME type.
+    ///
+    /// The type is lowercased, or ignored if it contains characters outside the printable
+    /// ASCII range.
+    #[must_use]
+    pub fn new(bytes: impl Into<Rc<[u8]>>, r#type: &str) -> Self {
+        Self {
+            bytes: bytes.into(),
+            r#type: normalize_type(r#type),
+        }
+    }
+
+    /// Returns the bytes of the blob.
+    #[must_use]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the size of the blob, in bytes.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns the MIME type of the blob, or an empty string if it is unknown.
+    #[must_use]
+    pub fn r#type(&self) -> &JsString {
+        &self.r#type
+    }
+
+    /// Returns a new blob containing the bytes in the range `start..end`, clamped to the
+    /// size of this blob.
+    #[must_use]
+    pub fn slice(&self, start: usize, end: usize, r#type: &str) -> Self {
+        let end = end.min(self.size());
+        let start = start.min(end);
+        Self::new(&self.bytes[start..end], r#type)
+    }
+}
+
+/// Parses a MIME type the way the `Blob` constructor does.
+///
+/// See step 3 of <https://w3c.github.io/FileAPI/#constructorBlob>
+fn normalize_type(r#type: &str) -> JsString {
+    if r#type.chars().all(|c| matches!(c, '\u{20}'..='\u{7E}')) {
+        JsString::from(r#type.cow_to_ascii_lowercase().as_ref())
+    } else {
+        JsString::default()
+    }
+}
+
+/// Returns the [`Blob`] state of `object`, if it is a `Blob` or a `File`.
+#[must_use]
+pub fn blob_of(object: &JsObject) -> Option<Blob> {
+    if let Some(data) = object.downcast_ref::<JsBlob>() {
+        return Some(data.blob.clone());
+    }
+    object
+        .downcast_ref::<JsFile>()
+        .map(|data| data.blob.clone())
+}
+
+/// Returns the [`Blob`] state of the `this` value, throwing a `TypeError` if it is not a
+/// `Blob` or a `File`.
+fn this_blob(this: &JsValue) -> JsResult<Blob> {
+    this.as_object()
+        .as_ref()
+        .and_then(blob_of)
+        .ok_or_else(|| js_error!(TypeError: "'this' is not a Blob"))
+}
+
+/// Returns a copy of the bytes viewed by an `ArrayBuffer`, a `TypedArray` or a `DataView`,
+/// or `None` if `value` is none of those.
+///
+/// # Errors
+/// Returns an error if the buffer is detached or the view is out of bounds.
+pub(crate) fn buffer_source_bytes(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<Option<Vec<u8>>> {
+    let (buffer, range) = if let Ok(buffer) = JsArrayBuffer::try_from_js(value, context) {
+        (buffer, None)
+    } else if let Ok(typed_array) = JsTypedArray::try_from_js(value, context) {
+        let Some(buffer) = typed_array.buffer(context)?.as_object() else {
+            return Err(js_error!(TypeError: "Invalid buffer backing TypedArray."));
+        };
+        let offset = typed_array.byte_offset(context)?;
+        let length = typed_array.byte_length(context)?;
+        (
+            JsArrayBuffer::from_object(buffer)?,
+            Some(offset..offset + length),
+        )
+    } else if let Ok(data_view) = JsDataView::try_from_js(value, context) {
+        let Some(buffer) = data_view.buffer(context)?.as_object() else {
+            return Err(js_error!(TypeError: "Invalid buffer backing DataView."));
+        };
+        let offset = usize::try_from(data_view.byte_offset(context)?)
+            .map_err(|_| js_error!(RangeError: "DataView offset exceeds addressable size."))?;
+        let length = usize::try_from(data_view.byte_length(context)?)
+            .map_err(|_| js_error!(RangeError: "DataView length exceeds addressable size."))?;
+        (
+            JsArrayBuffer::from_object(buffer)?,
+            Some(offset..offset + length),
+        )
+    } else {
+        return Ok(None);
+    };
+
+    let Some(data) = buffer.data() else {
+        return Err(js_error!(TypeError: "cannot read a detached ArrayBuffer"));
+    };
+    let bytes = match range {
+        Some(range) => data
+            .get(range)
+            .ok_or_else(|| js_error!(RangeError: "The view is out of the bounds of its buffer."))?,
+        None => &data,
+    };
+    Ok(Some(bytes.to_vec()))
+}
+
+/// Creates a new `ArrayBuffer` containing a copy of `bytes`.
+pub(crate) fn array_buffer(bytes: &[u8], context: &mut Context) -> JsResult<JsArrayBuffer> {
+    JsArrayBuffer::from_byte_block(AlignedVec::from_slice(0, bytes), context)
+}
+
+/// The `endings` member of the `Blob` and `File` options.
+///
+/// See <https://w3c.github.io/FileAPI/#enumdef-endingtype>
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Endings {
+    #[default]
+    Transparent,
+    Native,
+}
+
+impl TryFromJs for Endings {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let endings = value.to_string(context)?;
+        if endings == js_string!("transparent") {
+            Ok(Self::Transparent)
+        } else if endings == js_string!("native") {
+            Ok(Self::Native)
+        } else {
+            Err(js_error!(
+                TypeError: "'{}' is not a valid value for the endings option",
+                endings.to_std_string_escaped()
+            ))
+        }
+    }
+}
+
+/// Options for the `Blob` constructor.
+///
+/// See <https://w3c.github.io/FileAPI/#dfn-BlobPropertyBag>
+#[derive(Debug, Default, Clone, TryFromJs)]
+struct BlobPropertyBag {
+    #[boa(rename = "type")]
+    content_type: Option<Convert<String>>,
+    endings: Option<Endings>,
+}
+
+/// Options for the `File` constructor.
+///
+/// See <https://w3c.github.io/FileAPI/#dfn-FilePropertyBag>
+#[derive(Debug, Default, Clone, TryFromJs)]
+struct FilePropertyBag {
+    #[boa(rename = "type")]
+    content_type: Option<Convert<String>>,
+    endings: Option<Endings>,
+    #[boa(rename = "lastModified")]
+    last_modified: Option<Convert<f64>>,
+}
+
+/// Replaces every line break of `string` (`\r\n`, `\r` or `\n`) with `line_break`.
+///
+/// See <https://w3c.github.io/FileAPI/#convert-line-endings-to-native>
+pub(crate) fn normalize_line_breaks(string: &str, line_break: &str) -> String {
+    let mut output = String::with_capacity(string.len());
+    let mut chars = string.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                output.push_str(line_break);
+            }
+            '\n' => output.push_str(line_break),
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+/// Concatenates the parts passed to the `Blob` and `File` constructors into a byte sequence.
+///
+/// See <https://w3c.github.io/FileAPI/#process-blob-parts>
+fn process_blob_parts(
+    parts: Option<&JsValue>,
+    endings: Endings,
+    context: &mut Context,
+) -> JsResult<Vec<u8>> {
+    let Some(parts) = parts.filter(|parts| !parts.is_undefined()) else {
+        return Ok(Vec::new());
+    };
+    if !parts.is_object() {
+        return Err(js_error!(TypeError: "The blob parts must be a sequence"));
+    }
+
+    let mut bytes = Vec::new();
+    for part in Vec::<JsValue>::try_from_js(parts, context)? {
+        if let Some(blob) = part.as_object().as_ref().and_then(blob_of) {
+            bytes.extend_from_slice(blob.bytes());
+        } else if let Some(buffer) = buffer_source_bytes(&part, context)? {
+            bytes.extend_from_slice(&buffer);
+        } else {
+            let string = part.to_string(context)?.to_std_string_lossy();
+            if endings == Endings::Native {
+                let native = if cfg!(windows) { "\r\n" } else { "\n" };
+                bytes.extend_from_slice(normalize_line_breaks(&string, native).as_bytes());
+            } else {
+                bytes.extend_from_slice(string.as_bytes());
+            }
+        }
+    }
+    Ok(bytes)
+}
+
+/// Resolves a relative `start` or `end` argument of `Blob.prototype.slice`.
+fn relative_index(
+    value: Option<&JsValue>,
+    default: usize,
+    size: usize,
+    context: &mut Context,
+) -> JsResult<usize> {
+    let Some(value) = value.filter(|value| !value.is_undefined()) else {
+        return Ok(default);
+    };
+    let size = i64::try_from(size).unwrap_or(i64::MAX);
+    let index = match value.to_integer_or_infinity(context)? {
+        IntegerOrInfinity::NegativeInfinity => 0,
+        IntegerOrInfinity::Integer(index) if index < 0 => size.saturating_add(index).max(0),
+        index => index.clamp_finite(0, size),
+    };
+    Ok(usize::try_from(index).unwrap_or_default())
+}
+
+/// The JavaScript `Blob` class.
+#[derive(Debug, Clone, Default, Trace, Finalize, JsData)]
+pub struct JsBlob {
+    #[unsafe_ignore_trace]
+    blob: Blob,
+}
+
+impl JsBlob {
+    /// Creates a new `Blob` instance from its state.
+    #[must_use]
+    pub fn new(blob: Blob) -> Self {
+        Self { blob }
+    }
+
+    /// Returns the state of this blob.
+    #[must_use]
+    pub fn blob(&self) -> &Blob {
+        &self.blob
+    }
+}
+
+#[boa_class(rename = "Blob")]
+#[boa(rename_all = "camelCase")]
+impl JsBlob {
+    /// See <https://w3c.github.io/FileAPI/#constructorBlob>
+    #[boa(constructor)]
+    #[boa(length = 0)]
+    fn constructor(
+        blob_parts: Option<JsValue>,
+        options: Option<BlobPropertyBag>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let options = options.unwrap_or_default();
+        let bytes = process_blob_parts(
+            blob_parts.as_ref(),
+            options.endings.unwrap_or_default(),
+            context,
+        )?;
+        let content_type = options
+            .content_type
+            .as_ref()
+            .map_or("", |Convert(t)| t.as_str());
+        Ok(Self::new(Blob::new(bytes, content_type)))
+    }
+
+    #[boa(getter)]
+    fn size(JsThis(this): JsThis<JsValue>) -> JsResult<usize> {
+        this_blob(&this).map(|blob| blob.size())
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "type")]
+    fn r#type(JsThis(this): JsThis<JsValue>) -> JsResult<JsString> {
+        this_blob(&this).map(|blob| blob.r#type().clone())
+    }
+
+    /// See <https://w3c.github.io/FileAPI/#dfn-slice>
+    #[boa(method)]
+    #[boa(length = 0)]
+    fn slice(
+        JsThis(this): JsThis<JsValue>,
+        start: Option<JsValue>,
+        end: Option<JsValue>,
+        content_type: Option<Convert<String>>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let blob = this_blob(&this)?;
+        let size = blob.size();
+        let start = relative_index(start.as_ref(), 0, size, context)?;
+        let end = relative_index(end.as_ref(), size, size, context)?;
+        let content_type = content_type.as_ref().map_or("", |Convert(t)| t.as_str());
+        Ok(Self::new(blob.slice(start, end, content_type)))
+    }
+
+    /// See <https://w3c.github.io/FileAPI/#dom-blob-stream>
+    #[boa(method)]
+    fn stream(JsThis(this): JsThis<JsValue>, context: &mut Context) -> JsResult<JsObject> {
+        let blob = this_blob(&this)?;
+        let stream = JsReadableStream::from_bytes(blob.bytes(), context)?;
+        crate::stream::create(stream, context).map(JsObject::upcast)
+    }
+
+    /// See <https://w3c.github.io/FileAPI/#dom-blob-text>
+    #[boa(method)]
+    fn text(JsThis(this): JsThis<JsValue>, context: &mut Context) -> JsResult<JsPromise> {
+        let blob = this_blob(&this)?;
+        Ok(JsPromise::from_async_fn(
+            async move |_| {
+                let text = String::from_utf8_lossy(blob.bytes());
+                Ok(JsString::from(text).into())
+            },
+            context,
+        ))
+    }
+
+    /// See <https://w3c.github.io/FileAPI/#dom-blob-arraybuffer>
+    #[boa(method)]
+    fn array_buffer(JsThis(this): JsThis<JsValue>, context: &mut Context) -> JsResult<JsPromise> {
+        let blob = this_blob(&this)?;
+        Ok(JsPromise::from_async_fn(
+            async move |context| {
+                array_buffer(blob.bytes(), &mut context.borrow_mut()).map(Into::into)
+            },
+            context,
+        ))
+    }
+
+    /// See <https://w3c.github.io/FileAPI/#dom-blob-bytes>
+    #[boa(method)]
+    fn bytes(JsThis(this): JsThis<JsValue>, context: &mut Context) -> JsResult<JsPromise> {
+        let blob = this_blob(&this)?;
+        Ok(JsPromise::from_async_fn(
+            async move |context| {
+                JsUint8Array::from_iter(blob.bytes().iter().copied(), &mut context.borrow_mut())
+                    .map(Into::into)
+            },
+            context,
+        ))
+    }
+}
+
+/// The JavaScript `File` class, a `Blob` with a name and a modification date.
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsFile {
+    #[unsafe_ignore_trace]
+    blob: Blob,
+    name: JsString,
+    last_modified: i64,
+}
+
+impl JsFile {
+    /// Creates a new `File` instance from its contents, name and last modification date, in
+    /// milliseconds since the Unix epoch.
+    #[must_use]
+    pub fn new(blob: Blob, name: JsString, last_modified: i64) -> Self {
+        Self {
+            blob,
+            name,
+            last_modified,
+        }
+    }
+
+    /// Returns the contents of this file.
+    #[must_use]
+    pub fn blob(&self) -> &Blob {
+        &self.blob
+    }
+
+    /// Returns the name of this file.
+    #[must_use]
+    pub fn name(&self) -> &JsString {
+        &self.name
+    }
+
+    /// Creates a new `File` object named `name` holding `blob`, last modified now.
+    pub(crate) fn create(blob: Blob, name: JsString, context: &mut Context) -> JsResult<JsObject> {
+        Self::from_data(Self::new(blob, name, now(context)), context)
+    }
+}
+
+/// Returns the current time, in milliseconds since the Unix epoch.
+fn now(context: &Context) -> i64 {
+    i64::try_from(context.clock().now().millis_since_epoch()).unwrap_or(i64::MAX)
+}
+
+#[boa_class(rename = "File")]
+#[boa(rename_all = "camelCase")]
+impl JsFile {
+    /// See <https://w3c.github.io/FileAPI/#file-constructor>
+    #[boa(constructor)]
+    #[boa(length = 2)]
+    fn constructor(
+        file_bits: Option<JsValue>,
+        file_name: Option<JsValue>,
+        options: Option<FilePropertyBag>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let (Some(file_bits), Some(file_name)) = (file_bits, file_name) else {
+            return Err(js_error!(TypeError: "File constructor requires 2 arguments"));
+        };
+        let options = options.unwrap_or_default();
+        let bytes = process_blob_parts(
+            Some(&file_bits),
+            options.endings.unwrap_or_default(),
+            context,
+        )?;
+        let name = file_name.to_string(context)?;
+        let content_type = options
+            .content_type
+            .as_ref()
+            .map_or("", |Convert(t)| t.as_str());
+        let last_modified = match options.last_modified {
+            Some(Convert(last_modified)) => {
+                IntegerOrInfinity::from(last_modified).clamp_finite(i64::MIN, i64::MAX)
+            }
+            None => now(context),
+        };
+        Ok(Self::new(
+            Blob::new(bytes, content_type),
+            name,
+            last_modified,
+        ))
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "name")]
+    fn name_(&self) -> JsString {
+        self.name.clone()
+    }
+
+    #[boa(getter)]
+    fn last_modified(&self) -> i64 {
+        self.last_modified
+    }
+
+    /// Always empty, since files are never selected from a directory.
+    #[boa(getter)]
+    #[allow(clippy::unused_self)]
+    fn webkit_relative_path(&self) -> JsString {
+        JsString::default()
+    }
+}
+
+/// JavaScript module containing the `Blob` and `File` classes.
+#[boa_module]
+pub mod js_module {
+    type JsBlob = super::JsBlob;
+    type JsFile = super::JsFile;
+}
+
+/// Register the `Blob` and `File` classes into the realm/context. Pass `None` for the
+/// realm to register globally.
+///
+/// # Errors
+/// This will error if the context or realm cannot register the classes.
+pub fn register(realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+    js_module::boa_register(realm.clone(), context)?;
+
+    // `File` inherits from `Blob`.
+    let (blob, file) = match realm {
+        Some(realm) => (realm.get_class::<JsBlob>(), realm.get_class::<JsFile>()),
+        None => (
+            context.get_global_class::<JsBlob>(),
+            context.get_global_class::<JsFile>(),
+        ),
+    };
+    let (Some(blob), Some(file)) = (blob, file) else {
+        return Err(js_error!(Error: "Blob and File classes should be registered"));
+    };
+    file.prototype().set_prototype(Some(blob.prototype()));
+    file.constructor().set_prototype(Some(blob.constructor()));
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+use crate::test::{TestAction, run_test_actions};
+use boa_engine::js_str;
+
+/// Awaits the promise stored in the global `result` property.
+fn await_result() -> TestAction {
+    TestAction::inspect_context(|ctx| {
+        let result = ctx.global_object().get(js_str!("result"), ctx).unwrap();
+        result.as_promise().unwrap().await_blocking(ctx).unwrap();
+    })
+}
+
+#[test]
+fn blob_parts() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const inner = new Blob(["inner"]);
+                const bytes = new Uint8Array([0x61, 0x62, 0x63, 0x64]);
+                const blob = new Blob(
+                    ["str", inner, bytes.buffer, bytes.subarray(1, 3), new DataView(bytes.buffer, 3)],
+                    { type: "Text/Plain" },
+                );
+
+                assertEq(blob.size, 3 + 5 + 4 + 2 + 1);
+                assertEq(blob.type, "text/plain");
+                assertEq(new Blob().size, 0);
+                assertEq(new Blob([], { type: "é" }).type, "");
+                assertEq(new Blob(["a\r\nb\rc"], { endings: "native" }).size, 5);
+                assertEq(new Blob(["a\r\nb"], { endings: "transparent" }).size, 4);
+                assertThrows(() => new Blob("not a sequence"));
+                assertThrows(() => new Blob([], { endings: "invalid" }));
+
+                globalThis.result = (async () => {
+                    assertEq(await blob.text(), "strinnerabcdbcd");
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn blob_slice() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const blob = new Blob(["Hello, World!"], { type: "text/plain" });
+
+                globalThis.result = (async () => {
+                    assertEq(await blob.slice().text(), "Hello, World!");
+                    assertEq(await blob.slice(7).text(), "World!");
+                    assertEq(await blob.slice(0, 5).text(), "Hello");
+                    assertEq(await blob.slice(-6, -1).text(), "World");
+                    assertEq(await blob.slice(5, 2).text(), "");
+                    assertEq(await blob.slice(-100, 100).text(), "Hello, World!");
+                    assertEq(blob.slice().type, "");
+                    assertEq(blob.slice(0, 1, "TEXT/HTML").type, "text/html");
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn blob_readers() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const blob = new Blob(["\u{1F600}"]);
+
+                globalThis.result = (async () => {
+                    const buffer = await blob.arrayBuffer();
+                    assert(buffer instanceof ArrayBuffer);
+                    assertArrayEqual(new Uint8Array(buffer), [0xF0, 0x9F, 0x98, 0x80]);
+
+                    const bytes = await blob.bytes();
+                    assert(bytes instanceof Uint8Array);
+                    assertArrayEqual(bytes, [0xF0, 0x9F, 0x98, 0x80]);
+
+                    assertEq(await blob.text(), "\u{1F600}");
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn blob_stream() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const blob = new Blob(["abc", "def"]);
+                const stream = blob.stream();
+                assert(stream instanceof ReadableStream);
+                assert(blob.stream() !== stream);
+
+                globalThis.result = (async () => {
+                    const bytes = [];
+                    for await (const chunk of stream) {
+                        assert(chunk instanceof Uint8Array);
+                        bytes.push(...chunk);
+                    }
+                    assertEq(String.fromCharCode(...bytes), "abcdef");
+
+                    const empty = new Blob().stream().getReader();
+                    assert((await empty.read()).done);
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn file() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const file = new File(["a", "b"], "name.txt", { type: "text/plain", lastModified: 42 });
+
+                assert(file instanceof File);
+                assert(file instanceof Blob);
+                assertEq(Object.getPrototypeOf(File), Blob);
+                assertEq(file.name, "name.txt");
+                assertEq(file.lastModified, 42);
+                assertEq(file.size, 2);
+                assertEq(file.type, "text/plain");
+                assertEq(file.webkitRelativePath, "");
+                assert(new File([], "now").lastModified > 0);
+                assertThrows(() => new File(["a"]));
+
+                const slice = file.slice(1);
+                assert(!(slice instanceof File));
+
+                globalThis.result = (async () => {
+                    assertEq(await file.text(), "ab");
+                    assertEq(await new Blob([file, slice]).text(), "abb");
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn invalid_this() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const size = Object.getOwnPropertyDescriptor(Blob.prototype, "size").get;
+                assertThrows(() => size.call({}));
+                assertThrows(() => Blob.prototype.slice.call({}));
+                assertThrows(() => Blob.prototype.text.call(new FormData()));
+            "#,
+        ),
+    ]);
+}
@@ -17,6 +17,17 @@ pub trait RuntimeExtension: Debug {
     fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()>;
 }
 
+/// Register the `EventTarget` and event classes (`Event`, `CustomEvent`, `ErrorEvent`,
+/// `MessageEvent`). This also makes the global object an event target.
+#[derive(Copy, Clone, Debug)]
+pub struct EventTargetExtension;
+
+impl RuntimeExtension for EventTargetExtension {
+    fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        boa_wintertc::events::register(realm, context)
+    }
+}
+
 /// Register the Timeout/Interval functions.
 #[derive(Copy, Clone, Debug)]
 pub struct TimeoutExtension;
@@ -37,7 +48,7 @@ impl RuntimeExtension for MicrotaskExtension {
     }
 }
 
-/// Register the `TextEncoder` and `TextDecoder` classes.
+/// Register the `TextEncoder` and `TextDecoder` classes, and their stream variants.
 #[derive(Copy, Clone, Debug)]
 pub struct EncodingExtension;
 
@@ -48,6 +59,17 @@ impl RuntimeExtension for EncodingExtension {
     }
 }
 
+/// Register the `Blob`, `File` and `FormData` classes.
+#[derive(Copy, Clone, Debug)]
+pub struct FileExtension;
+
+impl RuntimeExtension for FileExtension {
+    fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        crate::blob::register(realm.clone(), context)?;
+        crate::form_data::register(realm, context)
+    }
+}
+
 /// Register the `structuredClone` function.
 #[derive(Copy, Clone, Debug)]
 pub struct StructuredCloneExtension;
@@ -122,17 +144,37 @@ impl<F: crate::fetch::Fetcher + Debug + 'static> RuntimeExtension for FetchExten
 }
 
 /// `AbortController` and `AbortSignal` extension.
-#[cfg(feature = "fetch")]
 #[derive(Copy, Clone, Debug)]
 pub struct AbortControllerExtension;
 
-#[cfg(feature = "fetch")]
 impl RuntimeExtension for AbortControllerExtension {
     fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
         crate::abort::register(realm, context)
     }
 }
 
+/// Streams extension (`ReadableStream`, `WritableStream`, `TransformStream` and their
+/// readers, writers, controllers and queuing strategies, `CompressionStream` and
+/// `DecompressionStream`).
+#[derive(Copy, Clone, Debug)]
+pub struct StreamsExtension;
+
+impl RuntimeExtension for StreamsExtension {
+    fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        crate::stream::register(realm, context)
+    }
+}
+
+/// Web Crypto extension (`crypto`, `Crypto`, `SubtleCrypto` and `CryptoKey`).
+#[derive(Copy, Clone, Debug)]
+pub struct CryptoExtension;
+
+impl RuntimeExtension for CryptoExtension {
+    fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        boa_wintertc::crypto::register(realm, context)
+    }
+}
+
 /// Register the `postMessage` JavaScript API with the specified
 /// [`crate::message::MessageSender`].
 #[derive(Debug)]
@@ -0,0 +1,328 @@
+//! The bodies of `Request` and `Response` objects.
+//!
+//! See <https://fetch.spec.whatwg.org/#bodies>
+
+use crate::blob::{self, Blob, JsBlob, blob_of};
+use crate::form_data::JsFormData;
+use crate::stream::readable::tee;
+use crate::stream::{ByteChunks, JsReadableStream, JsReadableStreamDefaultReader, create};
+use boa_engine::class::Class;
+use boa_engine::object::builtins::{JsPromise, JsUint8Array};
+use boa_engine::{
+    Context, Finalize, JsNativeError, JsObject, JsResult, JsString, JsValue, Trace, js_error,
+};
+use boa_gc::{Gc, GcRefCell};
+use http::HeaderMap;
+use http::header::CONTENT_TYPE;
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+/// Where the bytes of a body come from, until its stream is created.
+enum Source {
+    /// The whole body, in memory.
+    Bytes(Rc<Vec<u8>>),
+    /// Chunks which are read when the body is.
+    Chunks(ByteChunks),
+}
+
+impl fmt::Debug for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Self::Chunks(_) => f.write_str("Chunks"),
+        }
+    }
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct BodyInner {
+    /// The source of the body, which is `None` once its stream was created.
+    #[unsafe_ignore_trace]
+    source: Option<Source>,
+    stream: Option<JsObject<JsReadableStream>>,
+    /// Whether the body was read before its stream was created.
+    used: bool,
+}
+
+/// The body of a `Request` or a `Response`.
+///
+/// The `ReadableStream` of a body is only created when it is accessed, so bodies that are
+/// read with e.g. `text()` don't go through a stream.
+///
+/// See <https://fetch.spec.whatwg.org/#concept-body>
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct Body {
+    inner: Gc<GcRefCell<BodyInner>>,
+}
+
+/// The result of reading a whole body.
+enum ReadAll {
+    Bytes(Rc<Vec<u8>>),
+    /// A promise resolved with the bytes of the body as a `Uint8Array`.
+    Promise(JsPromise),
+}
+
+impl Body {
+    fn new(source: Option<Source>, stream: Option<JsObject<JsReadableStream>>) -> Self {
+        Self {
+            inner: Gc::new(GcRefCell::new(BodyInner {
+                source,
+                stream,
+                used: false,
+            })),
+        }
+    }
+
+    /// Creates a body made of `bytes`.
+    pub(crate) fn from_bytes(bytes: Vec<u8>) -> Self {
+        Self::new(Some(Source::Bytes(Rc::new(bytes))), None)
+    }
+
+    /// Creates a body whose bytes are read from `chunks` on demand.
+    pub(crate) fn from_chunks(chunks: ByteChunks) -> Self {
+        Self::new(Some(Source::Chunks(chunks)), None)
+    }
+
+    /// Creates a body which reads `stream`.
+    pub(crate) fn from_stream(stream: JsObject<JsReadableStream>) -> Self {
+        Self::new(None, Some(stream))
+    }
+
+    /// Returns the bytes of the body, unless it is streamed.
+    pub(crate) fn bytes(&self) -> Option<Rc<Vec<u8>>> {
+        match &self.inner.borrow().source {
+            Some(Source::Bytes(bytes)) => Some(bytes.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the body was read from.
+    ///
+    /// See <https://fetch.spec.whatwg.org/#dom-body-bodyused>
+    pub(crate) fn is_used(&self) -> bool {
+        let inner = self.inner.borrow();
+        match &inner.stream {
+            Some(stream) => stream.borrow().data().is_disturbed(),
+            None => inner.used,
+        }
+    }
+
+    /// See <https://fetch.spec.whatwg.org/#body-unusable>
+    fn is_unusable(&self) -> bool {
+        let inner = self.inner.borrow();
+        match &inner.stream {
+            Some(stream) => {
+                let stream = stream.borrow();
+                stream.data().is_disturbed() || stream.data().is_locked()
+            }
+            None => inner.used,
+        }
+    }
+
+    /// Returns the stream of the body, creating it if needed.
+    ///
+    /// See <https://fetch.spec.whatwg.org/#concept-body-stream>
+    pub(crate) fn stream(&self, context: &mut Context) -> JsResult<JsObject<JsReadableStream>> {
+        let (source, used) = {
+            let mut inner = self.inner.borrow_mut();
+            if let Some(stream) = &inner.stream {
+                return Ok(stream.clone());
+            }
+            (inner.source.take(), inner.used)
+        };
+        let stream = match source {
+            Some(Source::Chunks(chunks)) if !used => {
+                JsReadableStream::from_byte_chunks(chunks, context)?
+            }
+            Some(Source::Bytes(bytes)) if !used => JsReadableStream::from_bytes(&bytes, context)?,
+            _ => {
+                // The body was already read, so its stream is empty and disturbed.
+                let stream = JsReadableStream::from_bytes(&[], context)?;
+                stream.set_disturbed();
+                stream
+            }
+        };
+        let stream = create(stream, context)?;
+        self.inner.borrow_mut().stream = Some(stream.clone());
+        Ok(stream)
+    }
+
+    /// Clones the body, teeing its stream if it has one.
+    ///
+    /// See <https://fetch.spec.whatwg.org/#concept-body-clone>
+    pub(crate) fn clone_body(&self, context: &mut Context) -> JsResult<Self> {
+        if self.is_unusable() {
+            return Err(js_error!(TypeError: "cannot clone a body that was already used"));
+        }
+        if let Some(bytes) = self.bytes() {
+            return Ok(Self::new(Some(Source::Bytes(bytes)), None));
+        }
+        let stream = self.stream(context)?;
+        let stream = stream.borrow().data().clone();
+        let (branch1, branch2) = tee(&stream, false, context)?;
+        self.inner.borrow_mut().stream = Some(create(branch1, context)?);
+        Ok(Self::from_stream(create(branch2, context)?))
+    }
+
+    /// Starts reading the whole body, which makes it used.
+    fn read_all(&self, context: &mut Context) -> JsResult<ReadAll> {
+        if self.is_unusable() {
+            return Err(js_error!(TypeError: "the body was already used"));
+        }
+        {
+            let mut inner = self.inner.borrow_mut();
+            if let Some(Source::Bytes(bytes)) = &inner.source {
+                let bytes = bytes.clone();
+                inner.used = true;
+                return Ok(ReadAll::Bytes(bytes));
+            }
+        }
+        let stream = self.stream(context)?;
+        let stream = stream.borrow().data().clone();
+        let reader = JsReadableStreamDefaultReader::acquire(&stream, context)?;
+        Ok(ReadAll::Promise(reader.read_all_bytes(context)?))
+    }
+}
+
+/// Reads the whole of `body`, then runs `steps` with its bytes. A null body has no bytes.
+///
+/// See <https://fetch.spec.whatwg.org/#concept-body-consume-body>
+fn consume<F>(body: Option<&Body>, context: &mut Context, steps: F) -> JsResult<JsPromise>
+where
+    F: AsyncFnOnce(Rc<Vec<u8>>, &RefCell<&mut Context>) -> JsResult<JsValue> + 'static,
+{
+    let read = match body.map(|body| body.read_all(context)).transpose() {
+        Ok(read) => read,
+        Err(error) => return JsPromise::from_result(Err::<JsValue, _>(error), context),
+    };
+    Ok(JsPromise::from_async_fn(
+        async move |context| {
+            let bytes = match read {
+                None => Rc::default(),
+                Some(ReadAll::Bytes(bytes)) => bytes,
+                Some(ReadAll::Promise(promise)) => {
+                    let future = promise.into_js_future(&mut context.borrow_mut())?;
+                    let bytes = future.await?;
+                    let context = &mut context.borrow_mut();
+                    let bytes = bytes.to_object(context)?;
+                    Rc::new(JsUint8Array::from_object(bytes)?.to_vec(context)?)
+                }
+            };
+            steps(bytes, context).await
+        },
+        context,
+    ))
+}
+
+/// Extract a body and its associated MIME type from a `BodyInit` value.
+///
+/// See <https://fetch.spec.whatwg.org/#concept-bodyinit-extract>
+pub(crate) fn extract_body(
+    val: &JsValue,
+    context: &mut Context,
+) -> JsResult<(Vec<u8>, Option<String>)> {
+    if let Some(object) = val.as_object() {
+        // Blob: the type is the blob's type, if not empty.
+        if let Some(blob) = blob_of(&object) {
+            let r#type = (!blob.r#type().is_empty()).then(|| blob.r#type().to_std_string_lossy());
+            return Ok((blob.bytes().to_vec(), r#type));
+        }
+
+        // BufferSource: no type.
+        if let Some(bytes) = blob::buffer_source_bytes(val, context)? {
+            return Ok((bytes, None));
+        }
+
+        // ReadableStream: only supported by `Response`, which handles it before extracting.
+        if object.downcast_ref::<JsReadableStream>().is_some() {
+            return Err(js_error!(TypeError: "ReadableStream request bodies are not supported"));
+        }
+
+        // FormData: encoded as `multipart/form-data` with a new boundary.
+        if let Some(form_data) = object.downcast_ref::<JsFormData>() {
+            let (bytes, r#type) = form_data.to_multipart();
+            return Ok((bytes, Some(r#type)));
+        }
+
+        // URLSearchParams: the type is "application/x-www-form-urlencoded;charset=UTF-8".
+        #[cfg(feature = "url")]
+        if let Some(params) = object.downcast_ref::<crate::url::JsUrlSearchParams>() {
+            return Ok((
+                params.serialize().into_bytes(),
+                Some("application/x-www-form-urlencoded;charset=UTF-8".to_owned()),
+            ));
+        }
+    }
+
+    // USVString: the type is "text/plain;charset=UTF-8".
+    let bytes = val.to_string(context)?.to_std_string_lossy().into_bytes();
+    Ok((bytes, Some("text/plain;charset=UTF-8".to_owned())))
+}
+
+/// Returns the value of the `Content-Type` header, or an empty string if there is none.
+pub(crate) fn content_type(headers: &HeaderMap) -> String {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_owned()
+}
+
+/// Reads the body as an `ArrayBuffer`.
+pub(crate) fn array_buffer(body: Option<&Body>, context: &mut Context) -> JsResult<JsPromise> {
+    consume(body, context, async |body, context| {
+        blob::array_buffer(&body, &mut context.borrow_mut()).map(Into::into)
+    })
+}
+
+/// Reads the body as a `Blob` whose type is `content_type`.
+pub(crate) fn blob(
+    body: Option<&Body>,
+    content_type: String,
+    context: &mut Context,
+) -> JsResult<JsPromise> {
+    consume(body, context, async move |body, context| {
+        let blob = Blob::new(body.as_slice(), &content_type);
+        JsBlob::from_data(JsBlob::new(blob), &mut context.borrow_mut()).map(Into::into)
+    })
+}
+
+/// Reads the body as a `Uint8Array`.
+pub(crate) fn bytes(body: Option<&Body>, context: &mut Context) -> JsResult<JsPromise> {
+    consume(body, context, async |body, context| {
+        JsUint8Array::from_iter(body.iter().copied(), &mut context.borrow_mut()).map(Into::into)
+    })
+}
+
+/// Reads the body as a `FormData`, parsing it according to `content_type`.
+pub(crate) fn form_data(
+    body: Option<&Body>,
+    content_type: String,
+    context: &mut Context,
+) -> JsResult<JsPromise> {
+    consume(body, context, async move |body, context| {
+        let context = &mut context.borrow_mut();
+        let form_data = JsFormData::parse(&body, &content_type, context)?;
+        JsFormData::from_data(form_data, context).map(Into::into)
+    })
+}
+
+/// Reads the body as a JSON value.
+pub(crate) fn json(body: Option<&Body>, context: &mut Context) -> JsResult<JsPromise> {
+    consume(body, context, async |body, context| {
+        let json_string = String::from_utf8_lossy(body.as_ref());
+        let json = serde_json::from_str::<serde_json::Value>(&json_string)
+            .map_err(|e| JsNativeError::syntax().with_message(e.to_string()))?;
+
+        JsValue::from_json(&json, &mut context.borrow_mut())
+    })
+}
+
+/// Reads the body as a string.
+pub(crate) fn text(body: Option<&Body>, context: &mut Context) -> JsResult<JsPromise> {
+    consume(body, context, async |body, _| {
+        let body = String::from_utf8_lossy(body.as_ref());
+        Ok(JsString::from(body).into())
+    })
+}
@@ -22,7 +22,8 @@ impl Fetcher for ErrorFetcher {
     }
 }
 
-/// Implementation of `Fetcher` that uses the blocking `reqwest` library as the backend.
+/// Implementation of `StreamingFetcher` that uses the blocking `reqwest` library as the
+/// backend. The bodies of responses are read in chunks, as JavaScript reads them.
 #[cfg(feature = "reqwest-blocking")]
 #[derive(Default, Debug, Clone, Trace, Finalize, JsData)]
 pub struct BlockingReqwestFetcher {
@@ -30,15 +31,20 @@ pub struct BlockingReqwestFetcher {
     client: reqwest::blocking::Client,
 }
 
+/// The size of the chunks read from the bodies of responses.
 #[cfg(feature = "reqwest-blocking")]
-impl Fetcher for BlockingReqwestFetcher {
-    async fn fetch(
+const CHUNK_SIZE: usize = 64 * 1024;
+
+#[cfg(feature = "reqwest-blocking")]
+impl crate::fetch::StreamingFetcher for BlockingReqwestFetcher {
+    async fn fetch_streaming(
         self: Rc<Self>,
         request: JsRequest,
         signal: Option<JsObject>,
         _context: &RefCell<&mut Context>,
-    ) -> JsResult<JsResponse> {
-        use boa_engine::{JsError, JsString};
+    ) -> JsResult<http::Response<crate::stream::ByteChunks>> {
+        use boa_engine::JsError;
+        use std::io::Read;
 
         if let Some(ref sig) = signal
             && let Some(sig_ref) = sig.downcast_ref::<crate::abort::JsAbortSignal>()
@@ -61,7 +67,7 @@ impl Fetcher for BlockingReqwestFetcher {
             .build()
             .map_err(JsError::from_rust)?;
 
-        let resp = self.client.execute(req).map_err(JsError::from_rust)?;
+        let mut resp = self.client.execute(req).map_err(JsError::from_rust)?;
 
         if let Some(ref sig) = signal
             && let Some(sig_ref) = sig.downcast_ref::<crate::abort::JsAbortSignal>()
@@ -74,7 +80,6 @@ impl Fetcher for BlockingReqwestFetcher {
 
         let status = resp.status();
         let headers = resp.headers().clone();
-        let bytes = resp.bytes().map_err(JsError::from_rust)?;
         let mut builder = http::Response::builder().status(status.as_u16());
 
         for k in headers.keys() {
@@ -83,9 +88,15 @@ impl Fetcher for BlockingReqwestFetcher {
             }
         }
 
-        builder
-            .body(bytes.to_vec())
-            .map_err(JsError::from_rust)
-            .map(|request| JsResponse::basic(JsString::from(url), request))
+        // The body is read as JavaScript reads it, instead of being buffered.
+        let mut buffer = vec![0; CHUNK_SIZE];
+        let chunks = std::iter::from_fn(move || match resp.read(&mut buffer) {
+            Ok(0) => None,
+            Ok(read) => Some(Ok(buffer[..read].to_vec())),
+            Err(error) => Some(Err(JsError::from_rust(error))),
+        });
+
+        let body: crate::stream::ByteChunks = Box::pin(futures_lite::stream::iter(chunks));
+        builder.body(body).map_err(JsError::from_rust)
     }
 }
@@ -10,6 +10,7 @@
 use crate::fetch::headers::JsHeaders;
 use crate::fetch::request::{JsRequest, RequestInit};
 use crate::fetch::response::JsResponse;
+use crate::stream::ByteChunks;
 use boa_engine::class::Class;
 use boa_engine::object::FunctionObjectBuilder;
 use boa_engine::object::builtins::JsArray;
@@ -29,6 +30,7 @@ pub mod request;
 pub mod response;
 pub mod tests;
 
+mod body;
 mod fetchers;
 
 #[doc(inline)]
@@ -62,6 +64,51 @@ pub trait Fetcher: NativeObject {
     ) -> JsResult<JsResponse>;
 }
 
+/// A trait for backend implementations of an HTTP fetcher which stream the bodies of
+/// responses, so they don't need to be buffered entirely in memory.
+///
+/// Every `StreamingFetcher` is a [`Fetcher`], whose responses have a body that is read
+/// from the returned [`ByteChunks`] as JavaScript reads it.
+pub trait StreamingFetcher: NativeObject {
+    /// Resolve a string to a URL. See [`Fetcher::resolve_uri`].
+    ///
+    /// # Errors
+    /// This function should return an error if the URL cannot be handled by the fetcher.
+    fn resolve_uri(&self, uri: String, _context: &mut Context) -> JsResult<String> {
+        Ok(uri)
+    }
+
+    /// Perform the Fetch execution, taking a [`request::JsRequest`] and returning the
+    /// HTTP response, whose body is a stream of chunks.
+    ///
+    /// # Errors
+    /// Any errors returned by the HTTP implementation must conform to [`JsError`].
+    #[expect(async_fn_in_trait, reason = "all our APIs are single-threaded")]
+    async fn fetch_streaming(
+        self: Rc<Self>,
+        request: JsRequest,
+        signal: Option<JsObject>,
+        context: &RefCell<&mut Context>,
+    ) -> JsResult<http::Response<ByteChunks>>;
+}
+
+impl<T: StreamingFetcher> Fetcher for T {
+    fn resolve_uri(&self, uri: String, context: &mut Context) -> JsResult<String> {
+        StreamingFetcher::resolve_uri(self, uri, context)
+    }
+
+    async fn fetch(
+        self: Rc<Self>,
+        request: JsRequest,
+        signal: Option<JsObject>,
+        context: &RefCell<&mut Context>,
+    ) -> JsResult<JsResponse> {
+        let url = JsString::from(request.uri().to_string());
+        let response = self.fetch_streaming(request, signal, context).await?;
+        Ok(JsResponse::streaming(url, response))
+    }
+}
+
 /// A reference counted pointer to a `Fetcher` implementation. This is so we can
 /// add this to the context, but we need to be able to keep an `Rc<>` structure
 /// to make API calls.
@@ -140,13 +187,16 @@ async fn fetch_inner<T: Fetcher>(
             let Ok(request_ref) = request.try_borrow() else {
                 return Err(js_error!(TypeError: "Request object is already in use"));
             };
+            if request_ref.data().is_body_used() {
+                return Err(js_error!(TypeError: "the body of the request was already used"));
+            }
 
             request_ref.data().inner().clone()
         }
     };
 
     let mut request = if let Some(options) = options {
-        options.into_request_builder(Some(request))?
+        options.into_request_builder(Some(request), &mut context.borrow_mut())?
     } else {
         request
     };
@@ -4,13 +4,18 @@
 //!
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Request
 use super::HttpRequest;
+use super::body::{self, Body, extract_body};
 use super::headers::JsHeaders;
+use boa_engine::object::builtins::JsPromise;
 use boa_engine::value::{Convert, TryFromJs};
 use boa_engine::{
-    Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, js_error,
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, js_error,
 };
 use either::Either;
+use http::HeaderValue;
+use http::header::CONTENT_TYPE;
 use std::mem;
+use std::rc::Rc;
 
 /// A [RequestInit][mdn] object. This is a JavaScript object (not a
 /// class) that can be used as options for creating a [`JsRequest`].
@@ -35,10 +40,11 @@ impl RequestInit {
     /// body specified by JavaScript and the builder.
     ///
     /// # Errors
-    /// If the body is not a valid type, an error is returned.
+    /// If the body cannot be extracted, an error is returned.
     pub fn into_request_builder(
         mut self,
         request: Option<HttpRequest<Vec<u8>>>,
+        context: &mut Context,
     ) -> JsResult<HttpRequest<Vec<u8>>> {
         let mut builder = HttpRequest::builder();
         let mut request_body = Vec::new();
@@ -67,23 +73,30 @@ impl RequestInit {
             )?.as_str());
         }
 
-        if let Some(body) = &self.body {
-            // TODO: add more support types.
-            if let Some(body) = body.as_string() {
-                let body = body.to_std_string().map_err(
-                    |_| js_error!(TypeError: "Request constructor: body is not a valid string"),
-                )?;
-                request_body = body.into_bytes();
-            } else {
-                return Err(
-                    js_error!(TypeError: "Request constructor: body is not a supported type"),
-                );
-            }
+        let mut body_type = None;
+        if let Some(body) = self
+            .body
+            .as_ref()
+            .filter(|body| !body.is_null_or_undefined())
+        {
+            let (bytes, r#type) = extract_body(body, context)?;
+            request_body = bytes;
+            body_type = r#type;
         }
 
-        builder
+        let mut request = builder
             .body(request_body)
-            .map_err(|_| js_error!(Error: "Cannot construct request"))
+            .map_err(|_| js_error!(Error: "Cannot construct request"))?;
+
+        // If the body has a type and the headers do not contain `Content-Type`, append it.
+        if let Some(body_type) = body_type
+            && !request.headers().contains_key(CONTENT_TYPE)
+        {
+            let value = HeaderValue::try_from(body_type)
+                .map_err(|_| js_error!(TypeError: "Invalid body content type"))?;
+            request.headers_mut().insert(CONTENT_TYPE, value);
+        }
+        Ok(request)
     }
 }
 
@@ -96,9 +109,22 @@ impl RequestInit {
 pub struct JsRequest {
     #[unsafe_ignore_trace]
     inner: HttpRequest<Vec<u8>>,
+
+    /// The body as seen by JavaScript, `None` for an empty body.
+    body: Option<Body>,
 }
 
 impl JsRequest {
+    fn new(inner: HttpRequest<Vec<u8>>) -> Self {
+        let body = (!inner.body().is_empty()).then(|| Body::from_bytes(inner.body().clone()));
+        Self { inner, body }
+    }
+
+    /// Returns `true` if the body of the request was read from.
+    pub(crate) fn is_body_used(&self) -> bool {
+        self.body.as_ref().is_some_and(Body::is_used)
+    }
+
     /// Get the inner `http::Request` object. This drops the body (if any).
     pub fn into_inner(mut self) -> HttpRequest<Vec<u8>> {
         mem::replace(&mut self.inner, HttpRequest::new(Vec::new()))
@@ -114,6 +140,12 @@ impl JsRequest {
         self.inner.uri()
     }
 
+    /// Return a copy of the body.
+    #[must_use]
+    pub fn body(&self) -> Rc<Vec<u8>> {
+        Rc::new(self.inner.body().clone())
+    }
+
     /// Create a [`JsRequest`] instance from JavaScript arguments, similar to
     /// calling its constructor in JavaScript.
     ///
@@ -122,6 +154,7 @@ impl JsRequest {
     pub fn create_from_js(
         input: Either<JsString, JsRequest>,
         options: Option<RequestInit>,
+        context: &mut Context,
     ) -> JsResult<Self> {
         let request = match input {
             Either::Left(uri) => {
@@ -139,17 +172,17 @@ impl JsRequest {
         };
 
         if let Some(options) = options {
-            let inner = options.into_request_builder(Some(request))?;
-            Ok(Self { inner })
+            let inner = options.into_request_builder(Some(request), context)?;
+            Ok(Self::new(inner))
         } else {
-            Ok(Self { inner: request })
+            Ok(Self::new(request))
         }
     }
 }
 
 impl From<HttpRequest<Vec<u8>>> for JsRequest {
     fn from(inner: HttpRequest<Vec<u8>>) -> Self {
-        Self { inner }
+        Self::new(inner)
     }
 }
 
@@ -163,26 +196,90 @@ impl JsRequest {
     pub fn constructor(
         input: Either<JsString, JsObject>,
         options: Option<RequestInit>,
+        context: &mut Context,
     ) -> JsResult<Self> {
         // Need to use a match as `Either::map_right` does not have an equivalent
         // `Either::map_right_ok`.
         let input = match input {
             Either::Right(r) => {
                 if let Ok(request) = r.clone().downcast::<JsRequest>() {
-                    Either::Right(request.borrow().data().clone())
+                    let request = request.borrow().data().clone();
+                    if request.is_body_used() {
+                        return Err(
+                            js_error!(TypeError: "the body of the request was already used"),
+                        );
+                    }
+                    Either::Right(request)
                 } else {
                     return Err(js_error!(TypeError: "invalid input argument"));
                 }
             }
             Either::Left(i) => Either::Left(i),
         };
-        JsRequest::create_from_js(input, options)
+        JsRequest::create_from_js(input, options, context)
     }
 
+    /// See <https://fetch.spec.whatwg.org/#dom-request-clone>
     #[boa(rename = "clone")]
-    fn clone_request(&self) -> Self {
-        Self {
+    fn clone_request(&self, context: &mut Context) -> JsResult<Self> {
+        let body = self
+            .body
+            .as_ref()
+            .map(|body| body.clone_body(context))
+            .transpose()?;
+        Ok(Self {
             inner: self.inner.clone(),
+            body,
+        })
+    }
+
+    /// Returns the body as a `ReadableStream`, or `null` for an empty body.
+    ///
+    /// See <https://fetch.spec.whatwg.org/#dom-body-body>
+    #[boa(getter)]
+    #[boa(rename = "body")]
+    fn body_(&self, context: &mut Context) -> JsResult<JsValue> {
+        match &self.body {
+            Some(body) => Ok(body.stream(context)?.upcast().into()),
+            None => Ok(JsValue::null()),
         }
     }
+
+    /// See <https://fetch.spec.whatwg.org/#dom-body-bodyused>
+    #[boa(getter)]
+    fn body_used(&self) -> bool {
+        self.is_body_used()
+    }
+
+    /// See <https://fetch.spec.whatwg.org/#dom-body-arraybuffer>
+    fn array_buffer(&self, context: &mut Context) -> JsResult<JsPromise> {
+        body::array_buffer(self.body.as_ref(), context)
+    }
+
+    /// See <https://fetch.spec.whatwg.org/#dom-body-blob>
+    fn blob(&self, context: &mut Context) -> JsResult<JsPromise> {
+        let content_type = body::content_type(self.inner.headers());
+        body::blob(self.body.as_ref(), content_type, context)
+    }
+
+    /// See <https://fetch.spec.whatwg.org/#dom-body-bytes>
+    fn bytes(&self, context: &mut Context) -> JsResult<JsPromise> {
+        body::bytes(self.body.as_ref(), context)
+    }
+
+    /// See <https://fetch.spec.whatwg.org/#dom-body-formdata>
+    fn form_data(&self, context: &mut Context) -> JsResult<JsPromise> {
+        let content_type = body::content_type(self.inner.headers());
+        body::form_data(self.body.as_ref(), content_type, context)
+    }
+
+    /// See <https://fetch.spec.whatwg.org/#dom-body-text>
+    fn text(&self, context: &mut Context) -> JsResult<JsPromise> {
+        body::text(self.body.as_ref(), context)
+    }
+
+    /// See <https://fetch.spec.whatwg.org/#dom-body-json>
+    fn json(&self, context: &mut Context) -> JsResult<JsPromise> {
+        body::json(self.body.as_ref(), context)
+    }
 }
@@ -5,8 +5,10 @@
 //!
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Response
 
+use crate::fetch::body::{self, Body, extract_body};
 use crate::fetch::headers::JsHeaders;
-use boa_engine::object::builtins::{JsPromise, JsUint8Array};
+use crate::stream::{ByteChunks, JsReadableStream};
+use boa_engine::object::builtins::JsPromise;
 use boa_engine::value::{Convert, TryFromJs, TryIntoJs};
 use boa_engine::{
     Context, JsData, JsNativeError, JsResult, JsString, JsValue, boa_class, js_error, js_str,
@@ -139,8 +141,10 @@ pub struct JsResponse {
 
     headers: JsHeaders,
 
-    #[unsafe_ignore_trace]
-    body: Rc<Vec<u8>>,
+    /// The body of the response, `None` for a null body.
+    ///
+    /// See <https://fetch.spec.whatwg.org/#concept-response-body>
+    body: Option<Body>,
 }
 
 impl JsResponse {
@@ -151,7 +155,6 @@ impl JsResponse {
         let status = parts.status.as_u16();
         let status_text = JsString::from(parts.status.canonical_reason().unwrap_or(""));
         let headers = JsHeaders::from_http(parts.headers);
-        let body = Rc::new(body);
 
         Self {
             url,
@@ -159,7 +162,26 @@ impl JsResponse {
             status,
             status_text,
             headers,
-            body,
+            body: Some(Body::from_bytes(body)),
+        }
+    }
+
+    /// Create a new instance from an HTTP response whose body is read chunk by chunk, and
+    /// the URL that requested it.
+    #[must_use]
+    pub fn streaming(url: JsString, inner: http::Response<ByteChunks>) -> Self {
+        let (parts, body) = inner.into_parts();
+        let status = parts.status.as_u16();
+        let status_text = JsString::from(parts.status.canonical_reason().unwrap_or(""));
+        let headers = JsHeaders::from_http(parts.headers);
+
+        Self {
+            url,
+            r#type: ResponseType::Basic,
+            status,
+            status_text,
+            headers,
+            body: Some(Body::from_chunks(body)),
         }
     }
 
@@ -176,14 +198,16 @@ impl JsResponse {
             status: 0,
             status_text: JsString::default(),
             headers: JsHeaders::default(),
-            body: Rc::new(Vec::new()),
+            body: None,
         }
     }
 
-    /// Return a copy of the body.
+    /// Return the body, if it is held in memory.
+    ///
+    /// Null bodies and bodies that are streamed return `None`.
     #[must_use]
-    pub fn body(&self) -> Rc<Vec<u8>> {
-        self.body.clone()
+    pub fn body(&self) -> Option<Rc<Vec<u8>>> {
+        self.body.as_ref().and_then(Body::bytes)
     }
 }
 
@@ -208,7 +232,7 @@ pub struct JsResponseOptions {
 /// "extract a body". `None` means no body was provided.
 fn initialize_response(
     init: &JsResponseOptions,
-    body_with_type: Option<(Vec<u8>, Option<&str>)>,
+    body_with_type: Option<(Body, Option<String>)>,
 ) -> JsResult<JsResponse> {
     // Step 1: If init["status"] is not in the range 200 to 599, inclusive, throw a RangeError.
     let status = init.status.unwrap_or(200);
@@ -235,7 +259,7 @@ fn initialize_response(
     let mut headers = init.headers.clone().unwrap_or_default();
 
     // Step 6: If body is non-null, then:
-    let body = if let Some((body_bytes, body_type)) = body_with_type {
+    let body = if let Some((body, body_type)) = body_with_type {
         // Step 6.1: If response's status is a null body status, throw a TypeError.
         if is_null_body_status(status) {
             return Err(
@@ -252,13 +276,13 @@ fn initialize_response(
         {
             headers.append(
                 Convert::from("content-type".to_string()),
-                Convert::from(content_type.to_string()),
+                Convert::from(content_type),
             )?;
         }
 
-        Rc::new(body_bytes)
+        Some(body)
     } else {
-        Rc::new(Vec::new())
+        None
     };
 
     Ok(JsResponse {
@@ -271,22 +295,6 @@ fn initialize_response(
     })
 }
 
-/// Extract a body and its associated MIME type from a JS value.
-///
-/// This is a simplified implementation of the "extract a body" algorithm.
-///
-/// See <https://fetch.spec.whatwg.org/#concept-bodyinit-extract>
-fn extract_body(val: &JsValue, context: &mut Context) -> JsResult<(Vec<u8>, Option<&'static str>)> {
-    // TODO: handle other BodyInit types: Blob, ArrayBuffer, ArrayBufferView,
-    //       FormData, URLSearchParams.
-    // Currently only USVString is supported.
-    //
-    // For a USVString, the type is "text/plain;charset=UTF-8".
-    // See https://fetch.spec.whatwg.org/#concept-bodyinit-extract step 6.
-    let bytes = val.to_string(context)?.to_std_string_escaped().into_bytes();
-    Ok((bytes, Some("text/plain;charset=UTF-8")))
-}
-
 #[boa_class(rename = "Response")]
 #[boa(rename_all = "camelCase")]
 impl JsResponse {
@@ -323,7 +331,7 @@ impl JsResponse {
             status: status_code.as_u16(),
             status_text: JsString::from(status_code.canonical_reason().unwrap_or("")),
             headers: JsHeaders::from_http(headers),
-            body: Rc::new(Vec::new()),
+            body: None,
         })
     }
 
@@ -342,7 +350,10 @@ impl JsResponse {
 
         // Step 2: Let body be the result of extracting bytes.
         // The MIME type for JSON is "application/json".
-        let body_with_type = (json_bytes, Some("application/json"));
+        let body_with_type = (
+            Body::from_bytes(json_bytes),
+            Some("application/json".to_owned()),
+        );
 
         // Step 3: Let responseObject be the result of creating a Response object.
         // Step 4: Perform initialize a response given responseObject, init, and (body, "application/json").
@@ -365,10 +376,30 @@ impl JsResponse {
 
         // Step 3: Let bodyWithType be null.
         // Step 4: If body is non-null, set bodyWithType to the result of extracting body.
-        let body_with_type: Option<(Vec<u8>, Option<&'static str>)> = match body {
+        let body_with_type: Option<(Body, Option<String>)> = match body {
             None => None,
             Some(ref val) if val.is_null_or_undefined() => None,
-            Some(val) => Some(extract_body(&val, context)?),
+            Some(val) => {
+                // A `ReadableStream` is used as is, and has no type.
+                if let Some(stream) = val
+                    .as_object()
+                    .and_then(|object| object.downcast::<JsReadableStream>().ok())
+                {
+                    let unusable = {
+                        let stream = stream.borrow();
+                        stream.data().is_disturbed() || stream.data().is_locked()
+                    };
+                    if unusable {
+                        return Err(
+                            js_error!(TypeError: "Response body stream is disturbed or locked."),
+                        );
+                    }
+                    Some((Body::from_stream(stream), None))
+                } else {
+                    let (bytes, body_type) = extract_body(&val, context)?;
+                    Some((Body::from_bytes(bytes), body_type))
+                }
+            }
         };
 
         // Step 5: Perform initialize a response given this, init, and bodyWithType.
@@ -446,51 +477,71 @@ impl JsResponse {
         false
     }
 
+    /// See <https://fetch.spec.whatwg.org/#dom-response-clone>
     #[boa(rename = "clone")]
-    fn clone_response(&self) -> Self {
-        Self {
+    fn clone_response(&self, context: &mut Context) -> JsResult<Self> {
+        let body = self
+            .body
+            .as_ref()
+            .map(|body| body.clone_body(context))
+            .transpose()?;
+        Ok(Self {
             url: self.url.clone(),
             r#type: self.r#type,
             status: self.status,
             status_text: self.status_text.clone(),
             headers: self.headers.deep_clone(),
-            body: Rc::new((*self.body).clone()),
+            body,
+        })
+    }
+
+    /// Returns the body as a `ReadableStream`, or `null` for a null body.
+    ///
+    /// See <https://fetch.spec.whatwg.org/#dom-body-body>
+    #[boa(getter)]
+    #[boa(rename = "body")]
+    fn body_(&self, context: &mut Context) -> JsResult<JsValue> {
+        match &self.body {
+            Some(body) => Ok(body.stream(context)?.upcast().into()),
+            None => Ok(JsValue::null()),
         }
     }
 
-    fn bytes(&self, context: &mut Context) -> JsPromise {
-        let body = self.body.clone();
-        JsPromise::from_async_fn(
-            async move |context| {
-                JsUint8Array::from_iter(body.iter().copied(), &mut context.borrow_mut())
-                    .map(Into::into)
-            },
-            context,
-        )
+    /// See <https://fetch.spec.whatwg.org/#dom-body-bodyused>
+    #[boa(getter)]
+    fn body_used(&self) -> bool {
+        self.body.as_ref().is_some_and(Body::is_used)
+    }
+
+    /// See <https://fetch.spec.whatwg.org/#dom-body-arraybuffer>
+    fn array_buffer(&self, context: &mut Context) -> JsResult<JsPromise> {
+        body::array_buffer(self.body.as_ref(), context)
+    }
+
+    /// See <https://fetch.spec.whatwg.org/#dom-body-blob>
+    fn blob(&self, context: &mut Context) -> JsResult<JsPromise> {
+        let content_type = body::content_type(&self.headers.as_header_map().borrow());
+        body::blob(self.body.as_ref(), content_type, context)
+    }
+
+    /// See <https://fetch.spec.whatwg.org/#dom-body-bytes>
+    fn bytes(&self, context: &mut Context) -> JsResult<JsPromise> {
+        body::bytes(self.body.as_ref(), context)
+    }
+
+    /// See <https://fetch.spec.whatwg.org/#dom-body-formdata>
+    fn form_data(&self, context: &mut Context) -> JsResult<JsPromise> {
+        let content_type = body::content_type(&self.headers.as_header_map().borrow());
+        body::form_data(self.body.as_ref(), content_type, context)
     }
 
-    fn text(&self, context: &mut Context) -> JsPromise {
-        let body = self.body.clone();
-        JsPromise::from_async_fn(
-            async move |_| {
-                let body = String::from_utf8_lossy(body.as_ref());
-                Ok(JsString::from(body).into())
-            },
-            context,
-        )
+    /// See <https://fetch.spec.whatwg.org/#dom-body-text>
+    fn text(&self, context: &mut Context) -> JsResult<JsPromise> {
+        body::text(self.body.as_ref(), context)
     }
 
-    fn json(&self, context: &mut Context) -> JsPromise {
-        let body = self.body.clone();
-        JsPromise::from_async_fn(
-            async move |context| {
-                let json_string = String::from_utf8_lossy(body.as_ref());
-                let json = serde_json::from_str::<serde_json::Value>(&json_string)
-                    .map_err(|e| JsNativeError::syntax().with_message(e.to_string()))?;
-
-                JsValue::from_json(&json, &mut context.borrow_mut())
-            },
-            context,
-        )
+    /// See <https://fetch.spec.whatwg.org/#dom-body-json>
+    fn json(&self, context: &mut Context) -> JsResult<JsPromise> {
+        body::json(self.body.as_ref(), context)
     }
 }
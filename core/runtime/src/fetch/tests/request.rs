@@ -34,15 +34,18 @@ fn request_constructor() {
                     .and_then(|o| o.downcast_ref::<JsResponse>())
                     .unwrap()
                     .body()
-                    .as_ref()
+                    .unwrap()
                     .as_slice(),
                 "Hello World".as_bytes()
             );
         }),
-        TestAction::inspect_context(|_ctx| {
-            let request =
-                JsRequest::create_from_js(Either::Left(js_string!("http://example.com")), None)
-                    .unwrap();
+        TestAction::inspect_context(|ctx| {
+            let request = JsRequest::create_from_js(
+                Either::Left(js_string!("http://example.com")),
+                None,
+                ctx,
+            )
+            .unwrap();
             assert_eq!(request.uri().to_string(), "http://example.com/");
         }),
     ]);
@@ -215,3 +218,103 @@ fn request_clone_method_is_independent() {
         }),
     ]);
 }
+
+#[test]
+fn request_body_init() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            crate::fetch::register(TestFetcher::default(), None, ctx)
+                .expect("failed to register fetch");
+        }),
+        TestAction::run(
+            r#"
+                globalThis.result = (async () => {
+                    const blob = new Request("http://unit.test", {
+                        method: "POST",
+                        body: new Blob(["blob body"], { type: "text/x-blob" }),
+                    });
+                    assertEq(await blob.clone().text(), "blob body");
+                    assertEq((await blob.blob()).type, "text/x-blob");
+
+                    const buffer = new Request("http://unit.test", {
+                        method: "POST",
+                        body: new Uint8Array([1, 2, 3]),
+                    });
+                    assertArrayEqual(new Uint8Array(await buffer.clone().arrayBuffer()), [1, 2, 3]);
+                    assertArrayEqual(await buffer.bytes(), [1, 2, 3]);
+
+                    const json = new Request("http://unit.test", {
+                        method: "POST",
+                        body: '{"a":1}',
+                        headers: { "content-type": "application/json" },
+                    });
+                    assertEq((await json.clone().json()).a, 1);
+                    assertEq((await json.blob()).type, "application/json");
+
+                    const form = new FormData();
+                    form.append("key", "value");
+                    const multipart = new Request("http://unit.test", { method: "POST", body: form });
+                    assertEq((await multipart.formData()).get("key"), "value");
+
+                    const empty = new Request("http://unit.test", { body: null });
+                    assertEq(empty.body, null);
+                    assertEq(await empty.text(), "");
+
+                    const streamed = new Request("http://unit.test", { method: "POST", body: "abc" });
+                    assert(!streamed.bodyUsed);
+                    assert(streamed.body instanceof ReadableStream);
+                    assertEq(await streamed.text(), "abc");
+                    assert(streamed.bodyUsed);
+                    assertThrows(() => streamed.clone());
+                    assertThrows(() => new Request(streamed));
+
+                    let error;
+                    try {
+                        await streamed.text();
+                    } catch (e) {
+                        error = e;
+                    }
+                    assert(error instanceof TypeError);
+                    assertThrows(
+                        () => new Request("http://unit.test", { method: "POST", body: new ReadableStream() }),
+                    );
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let result = ctx.global_object().get(js_str!("result"), ctx).unwrap();
+            result.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn request_url_search_params_body() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            crate::fetch::register(TestFetcher::default(), None, ctx)
+                .expect("failed to register fetch");
+        }),
+        TestAction::run(
+            r#"
+                globalThis.result = (async () => {
+                    const params = new URLSearchParams({ a: "1 2", b: "&" });
+                    const request = new Request("http://unit.test", { method: "POST", body: params });
+                    assertEq(
+                        (await request.clone().blob()).type,
+                        "application/x-www-form-urlencoded;charset=utf-8",
+                    );
+                    assertEq(await request.clone().text(), "a=1+2&b=%26");
+                    assertEq((await request.formData()).get("b"), "&");
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let result = ctx.global_object().get(js_str!("result"), ctx).unwrap();
+            result.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
@@ -1,7 +1,12 @@
 use super::TestFetcher;
+use crate::fetch::StreamingFetcher;
+use crate::fetch::request::JsRequest;
+use crate::stream::ByteChunks;
 use crate::test::{TestAction, run_test_actions};
-use boa_engine::{Context, js_str};
+use boa_engine::{Context, Finalize, JsData, JsObject, JsResult, Trace, js_str};
 use http::{Response, Uri};
+use std::cell::RefCell;
+use std::rc::Rc;
 
 fn register(responses: &[(&'static str, Response<Vec<u8>>)], ctx: &mut Context) {
     let mut fetcher = TestFetcher::default();
@@ -354,3 +359,253 @@ fn response_clone_preserves_status() {
         }),
     ]);
 }
+
+#[test]
+fn response_body_init() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| register(&[], ctx)),
+        TestAction::run(
+            r#"
+                globalThis.response = (async () => {
+                    const bytes = new Uint8Array([104, 105, 33]);
+
+                    const fromBlob = new Response(new Blob(["blob"], { type: "text/x-blob" }));
+                    assertEq(fromBlob.headers.get("content-type"), "text/x-blob");
+                    assertEq(await fromBlob.text(), "blob");
+
+                    const fromUntypedBlob = new Response(new Blob(["blob"]));
+                    assertEq(fromUntypedBlob.headers.get("content-type"), null);
+
+                    const fromBuffer = new Response(bytes.buffer);
+                    assertEq(fromBuffer.headers.get("content-type"), null);
+                    assertEq(await fromBuffer.text(), "hi!");
+
+                    assertEq(await new Response(bytes.subarray(1)).text(), "i!");
+                    assertEq(await new Response(new DataView(bytes.buffer, 2)).text(), "!");
+
+                    const fromString = new Response("text");
+                    assertEq(fromString.headers.get("content-type"), "text/plain;charset=UTF-8");
+
+                    const explicit = new Response(new Blob(["x"], { type: "a/b" }), {
+                        headers: { "content-type": "c/d" },
+                    });
+                    assertEq(explicit.headers.get("content-type"), "c/d");
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let response = ctx.global_object().get(js_str!("response"), ctx).unwrap();
+            response.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
+
+#[test]
+fn response_body_readers() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            let mut resp = Response::new(b"a=1&b=two+words".to_vec());
+            resp.headers_mut().append(
+                "content-type",
+                "application/x-www-form-urlencoded".parse().unwrap(),
+            );
+            register(&[("http://unit.test", resp)], ctx);
+        }),
+        TestAction::run(
+            r#"
+                globalThis.response = (async () => {
+                    const response = await fetch("http://unit.test");
+                    const form = await response.clone().formData();
+                    assertEq(form.get("a"), "1");
+                    assertEq(form.get("b"), "two words");
+
+                    const blob = await response.clone().blob();
+                    assert(blob instanceof Blob);
+                    assertEq(blob.type, "application/x-www-form-urlencoded");
+                    assertEq(await blob.text(), "a=1&b=two+words");
+
+                    const buffer = await response.arrayBuffer();
+                    assert(buffer instanceof ArrayBuffer);
+                    assertEq(buffer.byteLength, 15);
+
+                    let error;
+                    try {
+                        await new Response("not a form").formData();
+                    } catch (e) {
+                        error = e;
+                    }
+                    assert(error instanceof TypeError);
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let response = ctx.global_object().get(js_str!("response"), ctx).unwrap();
+            response.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
+
+#[test]
+fn response_form_data_roundtrip() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| register(&[], ctx)),
+        TestAction::run(
+            r#"
+                globalThis.response = (async () => {
+                    const form = new FormData();
+                    form.append("field", "value\nwith newline");
+                    form.append("file", new File(["file content"], "a.txt", { type: "text/plain" }));
+
+                    const response = new Response(form);
+                    const contentType = response.headers.get("content-type");
+                    assert(contentType.startsWith("multipart/form-data; boundary="));
+
+                    const parsed = await response.formData();
+                    assertEq(parsed.get("field"), "value\r\nwith newline");
+                    const file = parsed.get("file");
+                    assert(file instanceof File);
+                    assertEq(file.name, "a.txt");
+                    assertEq(file.type, "text/plain");
+                    assertEq(await file.text(), "file content");
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let response = ctx.global_object().get(js_str!("response"), ctx).unwrap();
+            response.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
+
+#[test]
+fn response_body_stream() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            register(
+                &[("http://unit.test", Response::new(b"Hello World".to_vec()))],
+                ctx,
+            );
+        }),
+        TestAction::run(
+            r#"
+                globalThis.response = (async () => {
+                    const response = await fetch("http://unit.test");
+                    assert(!response.bodyUsed);
+                    const cloned = response.clone();
+                    const body = response.body;
+                    assert(body instanceof ReadableStream);
+                    assertEq(response.body, body);
+
+                    const reader = body.getReader();
+                    const { value } = await reader.read();
+                    assert(value instanceof Uint8Array);
+                    assertEq(String.fromCharCode(...value), "Hello World");
+                    assert((await reader.read()).done);
+                    assert(response.bodyUsed);
+                    assertEq(await cloned.text(), "Hello World");
+
+                    let error;
+                    try {
+                        await response.text();
+                    } catch (e) {
+                        error = e;
+                    }
+                    assert(error instanceof TypeError);
+                    assertThrows(() => response.clone());
+
+                    const read = new Response("text");
+                    assertEq(await read.text(), "text");
+                    assert(read.bodyUsed);
+
+                    assertEq(new Response().body, null);
+                    assertEq(Response.error().body, null);
+                    assertEq(await new Response().text(), "");
+
+                    const source = new ReadableStream({
+                        start(controller) {
+                            controller.enqueue(new Uint8Array([0x61, 0x62]));
+                            controller.enqueue(new Uint8Array([0x63]));
+                            controller.close();
+                        },
+                    });
+                    const streamed = new Response(source);
+                    assertEq(streamed.body, source);
+                    assertEq(streamed.headers.get("content-type"), null);
+                    const [left, right] = [streamed.clone(), streamed];
+                    assertEq(await left.text(), "abc");
+                    assertArrayEqual(await right.bytes(), [0x61, 0x62, 0x63]);
+                    assertThrows(() => new Response(source));
+
+                    const invalid = new Response(ReadableStream.from(["not bytes"]));
+                    error = undefined;
+                    try {
+                        await invalid.text();
+                    } catch (e) {
+                        error = e;
+                    }
+                    assert(error instanceof TypeError);
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let response = ctx.global_object().get(js_str!("response"), ctx).unwrap();
+            response.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
+
+/// A [`StreamingFetcher`] which responds to every request with the same chunks.
+#[derive(Debug, Trace, Finalize, JsData)]
+struct ChunkedFetcher(#[unsafe_ignore_trace] Vec<&'static [u8]>);
+
+impl StreamingFetcher for ChunkedFetcher {
+    async fn fetch_streaming(
+        self: Rc<Self>,
+        _request: JsRequest,
+        _signal: Option<JsObject>,
+        _context: &RefCell<&mut Context>,
+    ) -> JsResult<Response<ByteChunks>> {
+        let chunks: Vec<_> = self.0.iter().map(|chunk| Ok(chunk.to_vec())).collect();
+        let body: ByteChunks = Box::pin(futures_lite::stream::iter(chunks));
+        Ok(Response::new(body))
+    }
+}
+
+#[test]
+fn response_streaming_fetcher() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            let fetcher = ChunkedFetcher(vec![b"Hello", b"", b" World"]);
+            crate::fetch::register(fetcher, None, ctx).expect("failed to register fetch");
+        }),
+        TestAction::run(
+            r#"
+                globalThis.response = (async () => {
+                    const response = await fetch("http://unit.test");
+                    assertEq(response.status, 200);
+                    const reader = response.body.getReader();
+                    const chunks = [];
+                    for (let result = await reader.read(); !result.done; result = await reader.read()) {
+                        chunks.push(String.fromCharCode(...result.value));
+                    }
+                    assertArrayEqual(chunks, ["Hello", " World"]);
+
+                    assertEq(await (await fetch("http://unit.test")).text(), "Hello World");
+
+                    const canceled = await fetch("http://unit.test");
+                    await canceled.body.cancel();
+                    assert(canceled.bodyUsed);
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let response = ctx.global_object().get(js_str!("response"), ctx).unwrap();
+            response.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
@@ -0,0 +1,379 @@
+//! The `FormData` JavaScript class, implemented as [`JsFormData`].
+//!
+//! `FormData` objects hold a list of `(name, value)` entries, where each value is either a
+//! string or a `File`. They are mostly used as the body of `fetch` requests, which encodes
+//! them as `multipart/form-data`.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `XMLHttpRequest` specification][spec]
+//!
+//! [spec]: https://xhr.spec.whatwg.org/#interface-formdata
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/FormData
+
+use crate::blob::{Blob, JsBlob, JsFile, blob_of};
+use boa_engine::gc::GcRefCell;
+use boa_engine::interop::JsClass;
+use boa_engine::object::builtins::{JsArray, TypedJsFunction};
+use boa_engine::property::PropertyDescriptor;
+use boa_engine::realm::Realm;
+use boa_engine::value::Convert;
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsSymbol, JsValue, Trace, boa_class,
+    boa_module, js_error, js_string,
+};
+use cow_utils::CowUtils;
+
+pub(crate) mod multipart;
+
+#[cfg(test)]
+mod tests;
+
+use multipart::PartValue;
+
+/// A callback function for the `forEach` method.
+pub type ForEachCallback = TypedJsFunction<(JsValue, JsString, JsObject), ()>;
+
+/// The value of a `FormData` entry.
+///
+/// See <https://xhr.spec.whatwg.org/#formdataentryvalue>
+#[derive(Debug, Clone, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub enum FormDataEntryValue {
+    /// A string value.
+    String(JsString),
+    /// A `File` object.
+    File(JsObject),
+}
+
+impl From<FormDataEntryValue> for JsValue {
+    fn from(value: FormDataEntryValue) -> Self {
+        match value {
+            FormDataEntryValue::String(string) => string.into(),
+            FormDataEntryValue::File(file) => file.into(),
+        }
+    }
+}
+
+/// The JavaScript `FormData` class.
+#[derive(Debug, Default, Trace, Finalize, JsData)]
+pub struct JsFormData {
+    entries: GcRefCell<Vec<(JsString, FormDataEntryValue)>>,
+}
+
+impl JsFormData {
+    /// Appends a new entry.
+    pub fn append_entry(&self, name: JsString, value: FormDataEntryValue) {
+        self.entries.borrow_mut().push((name, value));
+    }
+
+    /// Returns a copy of the entries.
+    #[must_use]
+    pub fn to_entries(&self) -> Vec<(JsString, FormDataEntryValue)> {
+        self.entries.borrow().clone()
+    }
+
+    /// Encodes the entries as a `multipart/form-data` body, returning the body and its
+    /// MIME type.
+    #[must_use]
+    pub fn to_multipart(&self) -> (Vec<u8>, String) {
+        let parts = self.to_entries().into_iter().map(|(name, value)| {
+            let value = match value {
+                FormDataEntryValue::String(string) => PartValue::Text(string.to_std_string_lossy()),
+                FormDataEntryValue::File(file) => {
+                    let (filename, blob) = file_parts(&file);
+                    PartValue::File {
+                        filename: filename.to_std_string_lossy(),
+                        blob,
+                    }
+                }
+            };
+            (name.to_std_string_lossy(), value)
+        });
+
+        let boundary = multipart::generate_boundary();
+        let body = multipart::serialize(parts, &boundary);
+        (body, format!("multipart/form-data; boundary={boundary}"))
+    }
+
+    /// Parses a `multipart/form-data` or `application/x-www-form-urlencoded` body, as done
+    /// by the `formData()` method of `Request` and `Response`.
+    ///
+    /// See <https://fetch.spec.whatwg.org/#body-mixin>
+    ///
+    /// # Errors
+    /// Returns a `TypeError` if the MIME type is not one of those, or if the body is
+    /// malformed.
+    pub fn parse(body: &[u8], content_type: &str, context: &mut Context) -> JsResult<Self> {
+        let essence = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .cow_to_ascii_lowercase();
+        let form_data = Self::default();
+        match essence.as_ref() {
+            "multipart/form-data" => {
+                let parts = multipart::boundary(content_type)
+                    .and_then(|boundary| multipart::parse(body, &boundary))
+                    .ok_or_else(|| js_error!(TypeError: "Could not parse the body as FormData."))?;
+                for (name, value) in parts {
+                    let value = match value {
+                        PartValue::Text(text) => FormDataEntryValue::String(text.into()),
+                        PartValue::File { filename, blob } => FormDataEntryValue::File(
+                            JsFile::create(blob, filename.into(), context)?,
+                        ),
+                    };
+                    form_data.append_entry(name.into(), value);
+                }
+            }
+            "application/x-www-form-urlencoded" => {
+                for (name, value) in multipart::parse_urlencoded(body) {
+                    form_data.append_entry(name.into(), FormDataEntryValue::String(value.into()));
+                }
+            }
+            _ => {
+                return Err(js_error!(
+                    TypeError: "Could not parse the body as FormData: unsupported MIME type."
+                ));
+            }
+        }
+        Ok(form_data)
+    }
+}
+
+/// Returns the name and the contents of a `File` entry.
+fn file_parts(file: &JsObject) -> (JsString, Blob) {
+    let name = file
+        .downcast_ref::<JsFile>()
+        .map(|file| file.name().clone())
+        .unwrap_or_default();
+    (name, blob_of(file).unwrap_or_default())
+}
+
+/// Creates the entry for a `(name, value, filename)` triple passed to `append` or `set`.
+///
+/// See <https://xhr.spec.whatwg.org/#create-an-entry>
+fn create_entry(
+    name: JsString,
+    value: &JsValue,
+    filename: Option<&JsValue>,
+    context: &mut Context,
+) -> JsResult<(JsString, FormDataEntryValue)> {
+    let Some(object) = value.as_object() else {
+        return string_entry(name, value, filename, context);
+    };
+    let Some(blob) = blob_of(&object) else {
+        return string_entry(name, value, filename, context);
+    };
+
+    let filename = filename.map(|f| f.to_string(context)).transpose()?;
+    let value = if object.downcast_ref::<JsBlob>().is_some() {
+        // Blobs are converted to files named "blob" by default.
+        JsFile::create(blob, filename.unwrap_or(js_string!("blob")), context)?
+    } else if let Some(filename) = filename {
+        JsFile::create(blob, filename, context)?
+    } else {
+        object
+    };
+    Ok((name, FormDataEntryValue::File(value)))
+}
+
+/// Creates an entry with a string value.
+fn string_entry(
+    name: JsString,
+    value: &JsValue,
+    filename: Option<&JsValue>,
+    context: &mut Context,
+) -> JsResult<(JsString, FormDataEntryValue)> {
+    if filename.is_some() {
+        return Err(js_error!(
+            TypeError: "The value must be a Blob when a filename is provided."
+        ));
+    }
+    let value = value.to_string(context)?.to_std_string_lossy();
+    Ok((name, FormDataEntryValue::String(value.into())))
+}
+
+/// Converts a required `name` argument.
+fn name_argument(name: Option<Convert<JsString>>) -> JsResult<JsString> {
+    name.map(|Convert(ref name)| JsString::from(name.to_std_string_lossy()))
+        .ok_or_else(|| js_error!(TypeError: "The name argument is required."))
+}
+
+#[boa_class(rename = "FormData")]
+#[boa(rename_all = "camelCase")]
+impl JsFormData {
+    /// See <https://xhr.spec.whatwg.org/#dom-formdata>
+    #[boa(constructor)]
+    #[boa(length = 0)]
+    fn constructor(form: Option<JsValue>) -> JsResult<Self> {
+        if form.is_some_and(|form| !form.is_undefined()) {
+            return Err(js_error!(TypeError: "FormData does not support form elements."));
+        }
+        Ok(Self::default())
+    }
+
+    /// See <https://xhr.spec.whatwg.org/#dom-formdata-append>
+    #[boa(length = 2)]
+    fn append(
+        &self,
+        name: Option<Convert<JsString>>,
+        value: Option<JsValue>,
+        filename: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let name = name_argument(name)?;
+        let (name, value) =
+            create_entry(name, &value.unwrap_or_default(), filename.as_ref(), context)?;
+        self.append_entry(name, value);
+        Ok(())
+    }
+
+    /// See <https://xhr.spec.whatwg.org/#dom-formdata-delete>
+    fn delete(&self, name: Option<Convert<JsString>>) -> JsResult<()> {
+        let name = name_argument(name)?;
+        self.entries.borrow_mut().retain(|(n, _)| *n != name);
+        Ok(())
+    }
+
+    /// See <https://xhr.spec.whatwg.org/#dom-formdata-get>
+    fn get(&self, name: Option<Convert<JsString>>) -> JsResult<JsValue> {
+        let name = name_argument(name)?;
+        Ok(self
+            .entries
+            .borrow()
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map_or_else(JsValue::null, |(_, value)| value.clone().into()))
+    }
+
+    /// See <https://xhr.spec.whatwg.org/#dom-formdata-getall>
+    fn get_all(&self, name: Option<Convert<JsString>>, context: &mut Context) -> JsResult<JsArray> {
+        let name = name_argument(name)?;
+        let values = self
+            .entries
+            .borrow()
+            .iter()
+            .filter(|(n, _)| *n == name)
+            .map(|(_, value)| value.clone().into())
+            .collect::<Vec<JsValue>>();
+        Ok(JsArray::from_iter(values, context))
+    }
+
+    /// See <https://xhr.spec.whatwg.org/#dom-formdata-has>
+    fn has(&self, name: Option<Convert<JsString>>) -> JsResult<bool> {
+        let name = name_argument(name)?;
+        Ok(self.entries.borrow().iter().any(|(n, _)| *n == name))
+    }
+
+    /// Replaces the first entry named `name` and removes the others, or appends a new
+    /// entry if there is none.
+    ///
+    /// See <https://xhr.spec.whatwg.org/#dom-formdata-set>
+    #[boa(length = 2)]
+    fn set(
+        &self,
+        name: Option<Convert<JsString>>,
+        value: Option<JsValue>,
+        filename: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let name = name_argument(name)?;
+        let (name, value) =
+            create_entry(name, &value.unwrap_or_default(), filename.as_ref(), context)?;
+
+        let mut entries = self.entries.borrow_mut();
+        if let Some(index) = entries.iter().position(|(n, _)| *n == name) {
+            entries[index].1 = value;
+            let rest = entries.split_off(index + 1);
+            entries.extend(rest.into_iter().filter(|(n, _)| *n != name));
+        } else {
+            entries.push((name, value));
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator over the `[name, value]` pairs.
+    fn entries(&self, context: &mut Context) -> JsResult<JsValue> {
+        let entries = self
+            .to_entries()
+            .into_iter()
+            .map(|(name, value)| JsArray::from_iter([name.into(), value.into()], context).into())
+            .collect::<Vec<JsValue>>();
+        JsArray::from_iter(entries, context).values(context)
+    }
+
+    /// Returns an iterator over the names of the entries.
+    fn keys(&self, context: &mut Context) -> JsResult<JsValue> {
+        let keys = self
+            .to_entries()
+            .into_iter()
+            .map(|(name, _)| name.into())
+            .collect::<Vec<JsValue>>();
+        JsArray::from_iter(keys, context).values(context)
+    }
+
+    /// Returns an iterator over the values of the entries.
+    fn values(&self, context: &mut Context) -> JsResult<JsValue> {
+        let values = self
+            .to_entries()
+            .into_iter()
+            .map(|(_, value)| value.into())
+            .collect::<Vec<JsValue>>();
+        JsArray::from_iter(values, context).values(context)
+    }
+
+    /// Calls `callback` for each entry, with the value, the name and this object.
+    #[boa(method)]
+    #[boa(length = 1)]
+    fn for_each(
+        this: JsClass<Self>,
+        callback: ForEachCallback,
+        this_arg: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let object = this.inner().upcast();
+        let this_arg = this_arg.unwrap_or_default();
+        let entries = this.borrow().to_entries();
+        for (name, value) in entries {
+            callback.call_with_this(&this_arg, context, (value.into(), name, object.clone()))?;
+        }
+        Ok(())
+    }
+}
+
+/// JavaScript module containing the `FormData` class.
+#[boa_module]
+pub mod js_module {
+    type JsFormData = super::JsFormData;
+}
+
+/// Register the `FormData` class into the realm/context. Pass `None` for the realm to
+/// register globally.
+///
+/// # Errors
+/// This will error if the context or realm cannot register the class.
+pub fn register(realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+    js_module::boa_register(realm.clone(), context)?;
+
+    // `FormData.prototype[Symbol.iterator]` is the same function as `entries`.
+    let prototype = match realm {
+        Some(realm) => realm.get_class::<JsFormData>(),
+        None => context.get_global_class::<JsFormData>(),
+    }
+    .ok_or_else(|| js_error!(Error: "FormData class should be registered"))?
+    .prototype();
+    let entries = prototype.get(js_string!("entries"), context)?;
+    prototype.define_property_or_throw(
+        JsSymbol::iterator(),
+        PropertyDescriptor::builder()
+            .value(entries)
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+
+    Ok(())
+}
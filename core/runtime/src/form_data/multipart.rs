@@ -0,0 +1,246 @@
+//! The `multipart/form-data` encoding of form data.
+//!
+//! See <https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#multipart/form-data-encoding-algorithm>
+//! and <https://datatracker.ietf.org/doc/html/rfc7578>.
+
+use crate::blob::{Blob, normalize_line_breaks};
+use std::hash::{BuildHasher, RandomState};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The value of a part of a `multipart/form-data` body.
+#[derive(Debug, Clone)]
+pub(crate) enum PartValue {
+    /// A string value.
+    Text(String),
+    /// A file, with its name and contents.
+    File { filename: String, blob: Blob },
+}
+
+/// Generates a new boundary that is very unlikely to appear in the encoded values.
+pub(crate) fn generate_boundary() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let random = RandomState::new().hash_one(count);
+    format!("----BoaFormBoundary{random:016x}{count:x}")
+}
+
+/// Converts every line break of `value` to `\r\n`.
+fn normalize_line_endings(value: &str) -> String {
+    normalize_line_breaks(value, "\r\n")
+}
+
+/// Escapes a name or filename to be used in a `Content-Disposition` header.
+fn escape(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\n' => output.push_str("%0A"),
+            '\r' => output.push_str("%0D"),
+            '"' => output.push_str("%22"),
+            c => output.push(c),
+        }
+    }
+    output
+}
+
+/// Reverses [`escape`].
+fn unescape(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(index) = rest.find('%') {
+        output.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let (c, len) = match rest.get(..3) {
+            Some("%0A") => ('\n', 3),
+            Some("%0D") => ('\r', 3),
+            Some("%22") => ('"', 3),
+            _ => ('%', 1),
+        };
+        output.push(c);
+        rest = &rest[len..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Encodes the `(name, value)` entries as a `multipart/form-data` body delimited by
+/// `boundary`.
+pub(crate) fn serialize(
+    entries: impl IntoIterator<Item = (String, PartValue)>,
+    boundary: &str,
+) -> Vec<u8> {
+    let mut body = Vec::new();
+    for (name, value) in entries {
+        body.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+        let name = escape(&normalize_line_endings(&name));
+        match value {
+            PartValue::Text(text) => {
+                body.extend_from_slice(
+                    format!("Content-Disposition: form-data; name=\"{name}\"\r\n\r\n").as_bytes(),
+                );
+                body.extend_from_slice(normalize_line_endings(&text).as_bytes());
+            }
+            PartValue::File { filename, blob } => {
+                let content_type = if blob.r#type().is_empty() {
+                    "application/octet-stream".to_owned()
+                } else {
+                    blob.r#type().to_std_string_lossy()
+                };
+                body.extend_from_slice(
+                    format!(
+                        "Content-Disposition: form-data; name=\"{name}\"; filename=\"{}\"\r\n\
+                         Content-Type: {content_type}\r\n\r\n",
+                        escape(&filename)
+                    )
+                    .as_bytes(),
+                );
+                body.extend_from_slice(blob.bytes());
+            }
+        }
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+    body
+}
+
+/// Returns the value of the `boundary` parameter of a `multipart/form-data` MIME type.
+pub(crate) fn boundary(content_type: &str) -> Option<String> {
+    content_type.split(';').skip(1).find_map(|parameter| {
+        let (name, value) = parameter.split_once('=')?;
+        if !name.trim().eq_ignore_ascii_case("boundary") {
+            return None;
+        }
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .unwrap_or(value);
+        (!value.is_empty()).then(|| value.to_owned())
+    })
+}
+
+/// Returns the position of the first occurrence of `needle` in `haystack`.
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Returns the value of the `key` parameter of a `Content-Disposition` header.
+fn disposition_parameter(header: &str, key: &str) -> Option<String> {
+    let mut rest = header;
+    while let Some(start) = rest.find(';') {
+        rest = rest[start + 1..].trim_start();
+        let Some((name, value)) = rest.split_once('=') else {
+            break;
+        };
+        let (value, remaining) = if let Some(value) = value.strip_prefix('"') {
+            let end = value.find('"')?;
+            (&value[..end], &value[end + 1..])
+        } else {
+            let end = value.find(';').unwrap_or(value.len());
+            (value[..end].trim_end(), &value[end..])
+        };
+        if name.trim().eq_ignore_ascii_case(key) {
+            return Some(unescape(value));
+        }
+        rest = remaining;
+    }
+    None
+}
+
+/// Parses a `multipart/form-data` body delimited by `boundary` into its `(name, value)`
+/// entries.
+///
+/// Returns `None` if the body is malformed.
+pub(crate) fn parse(body: &[u8], boundary: &str) -> Option<Vec<(String, PartValue)>> {
+    let delimiter = format!("--{boundary}");
+    let delimiter = delimiter.as_bytes();
+    let part_delimiter = format!("\r\n--{boundary}");
+    let part_delimiter = part_delimiter.as_bytes();
+
+    let start = find(body, delimiter)?;
+    let mut rest = &body[start + delimiter.len()..];
+    let mut entries = Vec::new();
+    loop {
+        if rest.starts_with(b"--") {
+            return Some(entries);
+        }
+        rest = rest.strip_prefix(b"\r\n")?;
+
+        let headers_end = find(rest, b"\r\n\r\n")?;
+        let headers = std::str::from_utf8(&rest[..headers_end]).ok()?;
+        rest = &rest[headers_end + 4..];
+
+        let mut name = None;
+        let mut filename = None;
+        let mut content_type = None;
+        for header in headers.split("\r\n") {
+            let (header_name, value) = header.split_once(':')?;
+            let value = value.trim();
+            if header_name
+                .trim()
+                .eq_ignore_ascii_case("content-disposition")
+            {
+                let kind = value.split(';').next().unwrap_or_default().trim();
+                if !kind.eq_ignore_ascii_case("form-data") {
+                    return None;
+                }
+                name = disposition_parameter(value, "name");
+                filename = disposition_parameter(value, "filename");
+            } else if header_name.trim().eq_ignore_ascii_case("content-type") {
+                content_type = Some(value.to_owned());
+            }
+        }
+
+        let body_end = find(rest, part_delimiter)?;
+        let bytes = &rest[..body_end];
+        rest = &rest[body_end + part_delimiter.len()..];
+
+        let value = match filename {
+            Some(filename) => PartValue::File {
+                filename,
+                blob: Blob::new(bytes, content_type.as_deref().unwrap_or("text/plain")),
+            },
+            None => PartValue::Text(String::from_utf8_lossy(bytes).into_owned()),
+        };
+        entries.push((name?, value));
+    }
+}
+
+/// Parses an `application/x-www-form-urlencoded` body into its `(name, value)` entries.
+///
+/// See <https://url.spec.whatwg.org/#concept-urlencoded-parser>
+pub(crate) fn parse_urlencoded(body: &[u8]) -> Vec<(String, String)> {
+    fn decode(bytes: &[u8]) -> String {
+        let mut output = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'+' => output.push(b' '),
+                b'%' if i + 2 < bytes.len()
+                    && bytes[i + 1].is_ascii_hexdigit()
+                    && bytes[i + 2].is_ascii_hexdigit() =>
+                {
+                    let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or_default();
+                    output.push(u8::from_str_radix(hex, 16).unwrap_or_default());
+                    i += 2;
+                }
+                byte => output.push(byte),
+            }
+            i += 1;
+        }
+        String::from_utf8_lossy(&output).into_owned()
+    }
+
+    body.split(|&byte| byte == b'&')
+        .filter(|sequence| !sequence.is_empty())
+        .map(|sequence| {
+            let (name, value) = match sequence.iter().position(|&byte| byte == b'=') {
+                Some(index) => (&sequence[..index], &sequence[index + 1..]),
+                None => (sequence, &[][..]),
+            };
+            (decode(name), decode(value))
+        })
+        .collect()
+}
@@ -0,0 +1,140 @@
+use super::multipart::{self, PartValue};
+use crate::blob::Blob;
+use crate::test::{TestAction, run_test_actions};
+use boa_engine::js_str;
+
+#[test]
+fn entries() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const form = new FormData();
+                form.append("a", "1");
+                form.append("b", 2);
+                form.append("a", "3");
+
+                assert(form.has("a"));
+                assert(!form.has("c"));
+                assertEq(form.get("a"), "1");
+                assertEq(form.get("b"), "2");
+                assertEq(form.get("c"), null);
+                assertArrayEqual(form.getAll("a"), ["1", "3"]);
+                assertArrayEqual(form.keys(), ["a", "b", "a"]);
+                assertArrayEqual(form.values(), ["1", "2", "3"]);
+                assertArrayEqual([...form].map(([k, v]) => k + "=" + v), ["a=1", "b=2", "a=3"]);
+                assertEq(FormData.prototype[Symbol.iterator], FormData.prototype.entries);
+
+                form.set("a", "4");
+                assertArrayEqual(form.keys(), ["a", "b"]);
+                assertEq(form.get("a"), "4");
+                form.set("c", "5");
+                assertArrayEqual(form.keys(), ["a", "b", "c"]);
+
+                form.delete("b");
+                assertArrayEqual(form.keys(), ["a", "c"]);
+
+                const seen = [];
+                form.forEach(function (value, key, object) {
+                    assertEq(object, form);
+                    seen.push(this.prefix + key + value);
+                }, { prefix: "-" });
+                assertArrayEqual(seen, ["-a4", "-c5"]);
+
+                assertThrows(() => form.append("a", "b", "filename"));
+                assertThrows(() => new FormData({}));
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn file_entries() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const form = new FormData();
+                const file = new File(["content"], "file.txt");
+                form.append("blob", new Blob(["data"], { type: "text/plain" }));
+                form.append("named", new Blob(["data"]), "named.bin");
+                form.append("file", file);
+                form.append("renamed", file, "other.txt");
+
+                const blob = form.get("blob");
+                assert(blob instanceof File);
+                assertEq(blob.name, "blob");
+                assertEq(blob.type, "text/plain");
+                assertEq(form.get("named").name, "named.bin");
+                assertEq(form.get("file"), file);
+                assertNEq(form.get("renamed"), file);
+                assertEq(form.get("renamed").name, "other.txt");
+                assertEq(form.get("renamed").size, file.size);
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn multipart_roundtrip() {
+    let entries = vec![
+        (
+            "text".to_owned(),
+            PartValue::Text("line 1\nline 2".to_owned()),
+        ),
+        (
+            "quote\"name".to_owned(),
+            PartValue::File {
+                filename: "file.bin".to_owned(),
+                blob: Blob::new(&b"\r\n--not-a-boundary\r\n"[..], ""),
+            },
+        ),
+    ];
+    let body = multipart::serialize(entries, "BOUNDARY");
+    assert_eq!(
+        String::from_utf8_lossy(&body),
+        "--BOUNDARY\r\n\
+         Content-Disposition: form-data; name=\"text\"\r\n\r\n\
+         line 1\r\nline 2\r\n\
+         --BOUNDARY\r\n\
+         Content-Disposition: form-data; name=\"quote%22name\"; filename=\"file.bin\"\r\n\
+         Content-Type: application/octet-stream\r\n\r\n\
+         \r\n--not-a-boundary\r\n\r\n\
+         --BOUNDARY--\r\n"
+    );
+
+    let parsed = multipart::parse(&body, "BOUNDARY").unwrap();
+    assert_eq!(parsed.len(), 2);
+    assert!(
+        matches!(&parsed[0], (name, PartValue::Text(text)) if name == "text" && text == "line 1\r\nline 2")
+    );
+    let (name, PartValue::File { filename, blob }) = &parsed[1] else {
+        panic!("expected a file");
+    };
+    assert_eq!(name, "quote\"name");
+    assert_eq!(filename, "file.bin");
+    assert_eq!(blob.r#type(), &js_str!("application/octet-stream"));
+    assert_eq!(blob.bytes(), b"\r\n--not-a-boundary\r\n");
+
+    assert!(multipart::parse(b"--BOUNDARY\r\nbroken", "BOUNDARY").is_none());
+    assert_eq!(
+        multipart::boundary("multipart/form-data; charset=utf-8; boundary=\"abc\"").as_deref(),
+        Some("abc")
+    );
+    assert_eq!(multipart::boundary("multipart/form-data"), None);
+}
+
+#[test]
+fn urlencoded() {
+    assert_eq!(
+        multipart::parse_urlencoded(b"a=1&b=hello+world&&c&d=%E2%9C%93%2&a=%3D"),
+        [
+            ("a", "1"),
+            ("b", "hello world"),
+            ("c", ""),
+            ("d", "\u{2713}%2"),
+            ("a", "="),
+        ]
+        .map(|(name, value)| (name.to_owned(), value.to_owned()))
+    );
+}
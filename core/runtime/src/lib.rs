@@ -111,27 +111,35 @@ pub mod console;
 #[doc(inline)]
 pub use console::{Console, ConsoleState, DefaultLogger, Logger, NullLogger};
 
-#[cfg(feature = "fetch")]
 pub mod abort;
+pub mod blob;
 pub mod clone;
 pub mod extensions;
 #[cfg(feature = "fetch")]
 pub mod fetch;
+pub mod form_data;
 pub mod interval;
 pub mod message;
 pub mod microtask;
 #[cfg(feature = "process")]
 pub mod process;
 pub mod store;
+pub mod stream;
 pub mod text;
 #[cfg(feature = "url")]
 pub mod url;
 
+#[doc(inline)]
+pub use boa_wintertc::crypto;
+#[doc(inline)]
+pub use boa_wintertc::events;
+
 #[cfg(feature = "process")]
 use crate::extensions::ProcessExtension;
 use crate::extensions::{
-    Base64Extension, EncodingExtension, MicrotaskExtension, StructuredCloneExtension,
-    TimeoutExtension,
+    AbortControllerExtension, Base64Extension, CryptoExtension, EncodingExtension,
+    EventTargetExtension, FileExtension, MicrotaskExtension, StreamsExtension,
+    StructuredCloneExtension, TimeoutExtension,
 };
 pub use extensions::RuntimeExtension;
 
@@ -146,17 +154,19 @@ pub fn register(
     ctx: &mut boa_engine::Context,
 ) -> boa_engine::JsResult<()> {
     (
+        EventTargetExtension,
         Base64Extension,
         TimeoutExtension,
         EncodingExtension,
+        FileExtension,
         MicrotaskExtension,
         StructuredCloneExtension,
         #[cfg(feature = "url")]
         extensions::UrlExtension,
         #[cfg(feature = "process")]
         ProcessExtension,
-        #[cfg(feature = "fetch")]
-        extensions::AbortControllerExtension,
+        // Extension tuples hold at most 12 elements, so the last ones are nested.
+        (AbortControllerExtension, StreamsExtension, CryptoExtension),
         extensions,
     )
         .register(realm, ctx)?;
@@ -2,15 +2,41 @@
 
 use crate::message::MessageSender;
 use crate::store::JsValueStore;
+use boa_engine::class::Class;
 use boa_engine::job::NativeAsyncJob;
 use boa_engine::object::builtins::JsFunction;
 use boa_engine::value::TryIntoJs;
 use boa_engine::{
     Context, Finalize, JsData, JsError, JsResult, JsString, JsValue, Trace, js_string,
 };
+use boa_wintertc::events::{self, JsMessageEvent};
 use futures::StreamExt;
 use futures::channel::mpsc::UnboundedSender;
 
+/// Creates a channel whose messages are received by a job in the `destination` context,
+/// which calls `on_message` with each message converted to a [`JsValue`].
+fn spawn_receiver(
+    destination: &mut Context,
+    on_message: fn(JsValue, &mut Context) -> JsResult<()>,
+) -> UnboundedSender<JsValueStore> {
+    let (sender, mut receiver) = futures::channel::mpsc::unbounded::<JsValueStore>();
+
+    destination.enqueue_job(
+        NativeAsyncJob::new(async move |ctx| {
+            while let Some(store) = receiver.next().await {
+                let context = &mut ctx.borrow_mut();
+                let v = store.try_into_js(context)?;
+                on_message(v, context)?;
+            }
+
+            Ok(JsValue::undefined())
+        })
+        .into(),
+    );
+
+    sender
+}
+
 /// A [`MessageSender`] that reads the `onMessageQueue` property of the global
 /// object and calls it if it is a function. Note that this does not support
 /// event listeners, only checks the (made-up) `onMessageQueue` property. It
@@ -40,27 +66,60 @@ impl OnMessageQueueSender {
     /// array in the destination context. This type is send/sync and can be
     /// registered in any and many separate `Context` for `postMessage`.
     pub fn create(destination: &mut Context) -> Self {
-        let (sender, mut receiver) = futures::channel::mpsc::unbounded::<JsValueStore>();
+        let sender = spawn_receiver(destination, |v, context| {
+            let global = context.global_object();
+            if let Some(x) = global
+                .get(js_string!("onMessageQueue"), context)?
+                .as_callable()
+                .and_then(JsFunction::from_object)
+            {
+                x.call(&JsValue::undefined(), &[v], context)?;
+            }
+            Ok(())
+        });
+
+        Self { sender }
+    }
+}
 
-        destination.enqueue_job(
-            NativeAsyncJob::new(async move |ctx| {
-                while let Some(store) = receiver.next().await {
-                    let context = &mut ctx.borrow_mut();
-                    let v = store.try_into_js(context)?;
-                    let global = context.global_object();
-                    if let Some(x) = global
-                        .get(js_string!("onMessageQueue"), context)?
-                        .as_callable()
-                        .and_then(JsFunction::from_object)
-                    {
-                        x.call(&JsValue::undefined(), &[v], context)?;
-                    }
-                }
+/// A [`MessageSender`] that fires a `message` event, as a `MessageEvent` whose `data`
+/// is the message, at the global object of the destination context. Messages are
+/// received with `addEventListener("message", ...)`. This does not check the
+/// `targetOrigin` and accepts all messages.
+///
+/// The event classes must be registered in the destination context (see
+/// [`boa_wintertc::events::register`]).
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct MessageEventSender {
+    #[unsafe_ignore_trace]
+    sender: UnboundedSender<JsValueStore>,
+}
 
-                Ok(JsValue::undefined())
-            })
-            .into(),
-        );
+impl MessageSender for MessageEventSender {
+    fn send(&self, message: JsValueStore, _target_origin: Option<JsString>) -> JsResult<()> {
+        self.sender
+            .unbounded_send(message)
+            .map_err(JsError::from_rust)?;
+        Ok(())
+    }
+
+    fn stop(&self) -> JsResult<()> {
+        self.sender.close_channel();
+        Ok(())
+    }
+}
+
+impl MessageEventSender {
+    /// Create a `MessageEventSender` that dispatches messages as events in the
+    /// destination context. This type is send/sync and can be registered in any
+    /// and many separate `Context` for `postMessage`.
+    pub fn create(destination: &mut Context) -> Self {
+        let sender = spawn_receiver(destination, |data, context| {
+            let event = JsMessageEvent::message(data, JsString::default(), context)?;
+            let event = JsMessageEvent::from_data(event, context)?;
+            events::dispatch_event(&context.global_object(), &event, context)?;
+            Ok(())
+        });
 
         Self { sender }
     }
@@ -1,7 +1,7 @@
 //! Tests for the `postMessage` extension.
 
 use crate::message;
-use crate::message::senders::OnMessageQueueSender;
+use crate::message::senders::{MessageEventSender, OnMessageQueueSender};
 use crate::test::{TestAction, run_test_actions_with};
 use boa_engine::job::{JobExecutor, SimpleJobExecutor};
 use boa_engine::{Context, js_string};
@@ -53,6 +53,53 @@ fn basic() {
     );
 }
 
+/// Messages sent with a `MessageEventSender` are dispatched as `message` events.
+#[test]
+fn message_event() {
+    let context = &mut Context::default();
+    boa_wintertc::events::register(None, context).unwrap();
+
+    let sender = MessageEventSender::create(context);
+    message::register(sender, None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::harness(),
+            TestAction::run(
+                r#"
+                let latestEvent = null;
+                addEventListener("message", (event) => {
+                    latestEvent = event;
+                });
+
+                const message = { "hello": "world" };
+                postMessage(message);
+                assert(latestEvent === null);
+            "#,
+            ),
+            TestAction::inspect_context(move |context| {
+                drop(future::block_on(future::poll_once(
+                    context
+                        .downcast_job_executor::<SimpleJobExecutor>()
+                        .expect("")
+                        .run_jobs_async(&RefCell::new(context)),
+                )));
+            }),
+            TestAction::run(
+                r#"
+                assert(latestEvent instanceof MessageEvent);
+                assert(latestEvent.isTrusted);
+                assert(latestEvent.target === globalThis);
+                assert(latestEvent.data !== message);
+                assertEq(latestEvent.type, "message");
+                assertEq(latestEvent.data.hello, "world");
+            "#,
+            ),
+        ],
+        context,
+    );
+}
+
 #[test]
 fn shared_multi_thread() {
     let (sender, receiver) = std::sync::mpsc::channel::<OnMessageQueueSender>();
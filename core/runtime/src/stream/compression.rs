@@ -0,0 +1,339 @@
+//! The `CompressionStream` and `DecompressionStream` classes.
+//!
+//! See <https://compression.spec.whatwg.org/>
+
+use super::transform::{JsTransformStream, JsTransformStreamDefaultController, NativeTransformer};
+use crate::blob::buffer_source_bytes;
+use boa_engine::object::builtins::JsUint8Array;
+use boa_engine::value::TryFromJs;
+use boa_engine::{
+    Context, Finalize, JsData, JsError, JsObject, JsResult, JsValue, Trace, boa_class, js_error,
+    js_string,
+};
+use flate2::write::{DeflateEncoder, GzDecoder, GzEncoder, ZlibEncoder};
+use flate2::{Compression, Decompress, FlushDecompress, Status};
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::rc::Rc;
+
+/// The compression formats supported by the compression streams.
+///
+/// See <https://compression.spec.whatwg.org/#enumdef-compressionformat>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompressionFormat {
+    /// The gzip format ([RFC 1952](https://www.rfc-editor.org/rfc/rfc1952)).
+    Gzip,
+    /// The zlib format ([RFC 1950](https://www.rfc-editor.org/rfc/rfc1950)).
+    Deflate,
+    /// The raw deflate format ([RFC 1951](https://www.rfc-editor.org/rfc/rfc1951)).
+    DeflateRaw,
+}
+
+impl TryFromJs for CompressionFormat {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let format = value.to_string(context)?;
+        if format == js_string!("gzip") {
+            Ok(Self::Gzip)
+        } else if format == js_string!("deflate") {
+            Ok(Self::Deflate)
+        } else if format == js_string!("deflate-raw") {
+            Ok(Self::DeflateRaw)
+        } else {
+            Err(js_error!(
+                TypeError: "'{}' is not a supported compression format",
+                format.to_std_string_escaped()
+            ))
+        }
+    }
+}
+
+/// Returns the bytes of a chunk written to a compression stream.
+fn chunk_bytes(chunk: &JsValue, context: &mut Context) -> JsResult<Vec<u8>> {
+    buffer_source_bytes(chunk, context)?.ok_or_else(
+        || js_error!(TypeError: "The chunk must be an ArrayBuffer, TypedArray or DataView."),
+    )
+}
+
+/// Enqueues `bytes` as a `Uint8Array`, unless they are empty.
+fn enqueue_bytes(
+    bytes: Vec<u8>,
+    controller: &JsTransformStreamDefaultController,
+    context: &mut Context,
+) -> JsResult<()> {
+    if bytes.is_empty() {
+        return Ok(());
+    }
+    controller.enqueue(JsUint8Array::from_iter(bytes, context)?.into(), context)
+}
+
+/// A compressor writing its output to a buffer, which is taken after every chunk.
+#[derive(Debug)]
+enum Encoder {
+    Gzip(GzEncoder<Vec<u8>>),
+    Deflate(ZlibEncoder<Vec<u8>>),
+    DeflateRaw(DeflateEncoder<Vec<u8>>),
+}
+
+impl Encoder {
+    fn new(format: CompressionFormat) -> Self {
+        match format {
+            CompressionFormat::Gzip => {
+                Self::Gzip(GzEncoder::new(Vec::new(), Compression::default()))
+            }
+            CompressionFormat::Deflate => {
+                Self::Deflate(ZlibEncoder::new(Vec::new(), Compression::default()))
+            }
+            CompressionFormat::DeflateRaw => {
+                Self::DeflateRaw(DeflateEncoder::new(Vec::new(), Compression::default()))
+            }
+        }
+    }
+
+    /// Compresses `input`, returning the output produced so far.
+    fn write(&mut self, input: &[u8]) -> io::Result<Vec<u8>> {
+        let output = match self {
+            Self::Gzip(encoder) => {
+                encoder.write_all(input)?;
+                encoder.get_mut()
+            }
+            Self::Deflate(encoder) => {
+                encoder.write_all(input)?;
+                encoder.get_mut()
+            }
+            Self::DeflateRaw(encoder) => {
+                encoder.write_all(input)?;
+                encoder.get_mut()
+            }
+        };
+        Ok(std::mem::take(output))
+    }
+
+    /// Ends the compressed data, returning the remaining output.
+    fn finish(&mut self) -> io::Result<Vec<u8>> {
+        let output = match self {
+            Self::Gzip(encoder) => {
+                encoder.try_finish()?;
+                encoder.get_mut()
+            }
+            Self::Deflate(encoder) => {
+                encoder.try_finish()?;
+                encoder.get_mut()
+            }
+            Self::DeflateRaw(encoder) => {
+                encoder.try_finish()?;
+                encoder.get_mut()
+            }
+        };
+        Ok(std::mem::take(output))
+    }
+}
+
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Compressor {
+    #[unsafe_ignore_trace]
+    encoder: Rc<RefCell<Encoder>>,
+}
+
+impl NativeTransformer for Compressor {
+    fn transform(
+        &self,
+        chunk: JsValue,
+        controller: &JsTransformStreamDefaultController,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let input = chunk_bytes(&chunk, context)?;
+        let output = self
+            .encoder
+            .borrow_mut()
+            .write(&input)
+            .map_err(JsError::from_rust)?;
+        enqueue_bytes(output, controller, context)
+    }
+
+    fn flush(
+        &self,
+        controller: &JsTransformStreamDefaultController,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let output = self
+            .encoder
+            .borrow_mut()
+            .finish()
+            .map_err(JsError::from_rust)?;
+        enqueue_bytes(output, controller, context)
+    }
+}
+
+/// A decompressor writing its output to a buffer, which is taken after every chunk.
+///
+/// The zlib and raw deflate formats are inflated directly, to know whether the end of the
+/// compressed data was reached.
+#[derive(Debug)]
+enum Decoder {
+    Gzip(GzDecoder<Vec<u8>>),
+    Inflate { inflate: Decompress, ended: bool },
+}
+
+impl Decoder {
+    fn new(format: CompressionFormat) -> Self {
+        match format {
+            CompressionFormat::Gzip => Self::Gzip(GzDecoder::new(Vec::new())),
+            CompressionFormat::Deflate | CompressionFormat::DeflateRaw => Self::Inflate {
+                inflate: Decompress::new(format == CompressionFormat::Deflate),
+                ended: false,
+            },
+        }
+    }
+
+    /// Decompresses `input`, returning the output produced so far.
+    fn write(&mut self, mut input: &[u8]) -> JsResult<Vec<u8>> {
+        match self {
+            Self::Gzip(decoder) => {
+                decoder.write_all(input).map_err(decompression_error)?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+            Self::Inflate { inflate, ended } => {
+                let mut output = Vec::new();
+                loop {
+                    if *ended {
+                        if !input.is_empty() {
+                            return Err(js_error!(
+                                TypeError: "unexpected data after the end of the compressed data"
+                            ));
+                        }
+                        return Ok(output);
+                    }
+                    output.reserve(input.len().max(1024) * 2);
+                    let spare = output.capacity() - output.len();
+                    let (total_in, total_out) = (inflate.total_in(), inflate.total_out());
+                    let status = inflate
+                        .decompress_vec(input, &mut output, FlushDecompress::None)
+                        .map_err(decompression_error)?;
+                    let consumed = usize::try_from(inflate.total_in() - total_in)
+                        .expect("cannot consume more than the input");
+                    let produced = usize::try_from(inflate.total_out() - total_out)
+                        .expect("cannot produce more than the spare capacity");
+                    input = &input[consumed..];
+                    *ended = status == Status::StreamEnd;
+                    // A full output buffer may leave output in the decompressor.
+                    if input.is_empty() && produced < spare && !*ended {
+                        return Ok(output);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks that the compressed data is complete, returning the remaining output.
+    fn finish(&mut self) -> JsResult<Vec<u8>> {
+        match self {
+            Self::Gzip(decoder) => {
+                decoder.try_finish().map_err(decompression_error)?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+            Self::Inflate { ended: true, .. } => Ok(Vec::new()),
+            Self::Inflate { ended: false, .. } => {
+                Err(js_error!(TypeError: "the compressed data is incomplete"))
+            }
+        }
+    }
+}
+
+fn decompression_error(error: impl std::fmt::Display) -> JsError {
+    js_error!(TypeError: "invalid compressed data: {}", error)
+}
+
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Decompressor {
+    #[unsafe_ignore_trace]
+    decoder: Rc<RefCell<Decoder>>,
+}
+
+impl NativeTransformer for Decompressor {
+    fn transform(
+        &self,
+        chunk: JsValue,
+        controller: &JsTransformStreamDefaultController,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let input = chunk_bytes(&chunk, context)?;
+        let output = self.decoder.borrow_mut().write(&input)?;
+        enqueue_bytes(output, controller, context)
+    }
+
+    fn flush(
+        &self,
+        controller: &JsTransformStreamDefaultController,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let output = self.decoder.borrow_mut().finish()?;
+        enqueue_bytes(output, controller, context)
+    }
+}
+
+/// The JavaScript `CompressionStream` class, which compresses binary chunks.
+///
+/// See <https://compression.spec.whatwg.org/#compressionstream>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsCompressionStream {
+    stream: JsTransformStream,
+}
+
+#[boa_class(rename = "CompressionStream")]
+#[boa(rename_all = "camelCase")]
+impl JsCompressionStream {
+    #[boa(constructor)]
+    fn constructor(format: CompressionFormat, context: &mut Context) -> JsResult<Self> {
+        let compressor = Compressor {
+            encoder: Rc::new(RefCell::new(Encoder::new(format))),
+        };
+        Ok(Self {
+            stream: JsTransformStream::from_native(compressor, context)?,
+        })
+    }
+
+    #[boa(getter)]
+    fn readable(&self) -> JsObject {
+        self.stream.readable_stream().upcast()
+    }
+
+    #[boa(getter)]
+    fn writable(&self) -> JsObject {
+        self.stream.writable_stream().upcast()
+    }
+}
+
+/// The JavaScript `DecompressionStream` class, which decompresses binary chunks.
+///
+/// The stream errors with a `TypeError` if the data is invalid, incomplete or followed by
+/// trailing data.
+///
+/// See <https://compression.spec.whatwg.org/#decompressionstream>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsDecompressionStream {
+    stream: JsTransformStream,
+}
+
+#[boa_class(rename = "DecompressionStream")]
+#[boa(rename_all = "camelCase")]
+impl JsDecompressionStream {
+    #[boa(constructor)]
+    fn constructor(format: CompressionFormat, context: &mut Context) -> JsResult<Self> {
+        let decompressor = Decompressor {
+            decoder: Rc::new(RefCell::new(Decoder::new(format))),
+        };
+        Ok(Self {
+            stream: JsTransformStream::from_native(decompressor, context)?,
+        })
+    }
+
+    #[boa(getter)]
+    fn readable(&self) -> JsObject {
+        self.stream.readable_stream().upcast()
+    }
+
+    #[boa(getter)]
+    fn writable(&self) -> JsObject {
+        self.stream.writable_stream().upcast()
+    }
+}
@@ -0,0 +1,452 @@
+//! Boa's implementation of the WHATWG Streams standard.
+//!
+//! This contains `ReadableStream`, `WritableStream` and `TransformStream`, along with their
+//! readers, writers, controllers and the built-in queuing strategies, as well as the
+//! `CompressionStream` and `DecompressionStream` transform streams.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG Streams specification][spec]
+//!
+//! [spec]: https://streams.spec.whatwg.org/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Streams_API
+#![allow(clippy::needless_pass_by_value)]
+
+use boa_engine::builtins::promise::ResolvingFunctions;
+use boa_engine::class::Class;
+use boa_engine::object::builtins::{JsFunction, JsPromise};
+use boa_engine::realm::Realm;
+use boa_engine::value::TryFromJs;
+use boa_engine::{
+    Context, Finalize, JsArgs, JsError, JsNativeError, JsObject, JsResult, JsString, JsValue,
+    NativeFunction, Trace, boa_module, js_error, js_string,
+};
+use std::collections::VecDeque;
+
+pub mod compression;
+pub mod readable;
+pub mod strategy;
+pub mod transform;
+pub mod writable;
+
+#[cfg(test)]
+mod tests;
+
+#[doc(inline)]
+pub use compression::{JsCompressionStream, JsDecompressionStream};
+#[doc(inline)]
+pub use readable::{
+    ByteChunks, JsReadableByteStreamController, JsReadableStream, JsReadableStreamBYOBReader,
+    JsReadableStreamBYOBRequest, JsReadableStreamDefaultController, JsReadableStreamDefaultReader,
+};
+#[doc(inline)]
+pub use strategy::{JsByteLengthQueuingStrategy, JsCountQueuingStrategy};
+#[doc(inline)]
+pub use transform::{JsTransformStream, JsTransformStreamDefaultController};
+
+pub(crate) use transform::NativeTransformer;
+#[doc(inline)]
+pub use writable::{
+    JsWritableStream, JsWritableStreamDefaultController, JsWritableStreamDefaultWriter,
+};
+
+/// Creates the JavaScript object of a class from its native `data`.
+pub(crate) fn create<T: Class>(data: T, context: &mut Context) -> JsResult<JsObject<T>> {
+    T::from_data(data, context)?
+        .downcast::<T>()
+        .map_err(|_| js_error!(TypeError: "could not create a {} object", T::NAME))
+}
+
+/// Steps run with captured state and a single value, e.g. when a promise settles.
+pub(crate) type Steps<T> = fn(&T, JsValue, &mut Context) -> JsResult<JsValue>;
+
+/// Steps that do nothing, for reactions that only care about one outcome of a promise.
+#[allow(clippy::unnecessary_wraps)]
+pub(crate) fn ignore<T>(_: &T, _: JsValue, _: &mut Context) -> JsResult<JsValue> {
+    Ok(JsValue::undefined())
+}
+
+/// Creates a function that runs `steps` with `captures` and its first argument.
+pub(crate) fn steps_function<T: Trace + Clone + 'static>(
+    captures: &T,
+    steps: Steps<T>,
+    context: &mut Context,
+) -> JsFunction {
+    NativeFunction::from_copy_closure_with_captures(
+        move |_, args, captures, context| {
+            steps(captures, args.get_or_undefined(0).clone(), context)
+        },
+        captures.clone(),
+    )
+    .to_js_function(context.realm())
+}
+
+/// Returns a new promise resolved with the result of `on_fulfilled` or `on_rejected` once
+/// `promise` settles. A missing step passes the value or reason through.
+///
+/// See <https://webidl.spec.whatwg.org/#dfn-perform-steps-once-promise-is-settled>
+pub(crate) fn react<T: Trace + Clone + 'static>(
+    promise: &JsPromise,
+    captures: &T,
+    on_fulfilled: Option<Steps<T>>,
+    on_rejected: Option<Steps<T>>,
+    context: &mut Context,
+) -> JsResult<JsPromise> {
+    let on_fulfilled = on_fulfilled.map(|steps| steps_function(captures, steps, context));
+    let on_rejected = on_rejected.map(|steps| steps_function(captures, steps, context));
+    promise.then(on_fulfilled, on_rejected, context)
+}
+
+/// Runs `on_fulfilled` or `on_rejected` once `promise` settles, handling its rejection.
+///
+/// See <https://webidl.spec.whatwg.org/#upon-fulfillment>
+pub(crate) fn upon<T: Trace + Clone + 'static>(
+    promise: &JsPromise,
+    captures: &T,
+    on_fulfilled: Steps<T>,
+    on_rejected: Steps<T>,
+    context: &mut Context,
+) -> JsResult<()> {
+    react(
+        promise,
+        captures,
+        Some(on_fulfilled),
+        Some(on_rejected),
+        context,
+    )?;
+    Ok(())
+}
+
+/// Marks `promise` as handled, so its rejection is not reported as unhandled.
+///
+/// See <https://webidl.spec.whatwg.org/#mark-a-promise-as-handled>
+pub(crate) fn mark_handled(promise: &JsPromise, context: &mut Context) -> JsResult<()> {
+    upon(promise, &(), ignore, ignore, context)
+}
+
+/// Returns a promise resolved with `value`.
+pub(crate) fn resolved(value: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+    JsPromise::resolve(value, context)
+}
+
+/// Returns a promise rejected with `reason`.
+pub(crate) fn rejected(reason: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+    JsPromise::reject(JsError::from_opaque(reason), context)
+}
+
+/// Returns a promise rejected with `error`, or the error itself if it cannot be caught.
+pub(crate) fn rejected_with(error: JsError, context: &mut Context) -> JsResult<JsPromise> {
+    let reason = error.into_opaque(context)?;
+    rejected(reason, context)
+}
+
+/// Returns the JavaScript value of a `TypeError` with `message`.
+pub(crate) fn type_error(message: &str, context: &mut Context) -> JsValue {
+    JsNativeError::typ()
+        .with_message(message.to_owned())
+        .into_opaque(context)
+        .into()
+}
+
+/// A pending promise along with the functions to settle it.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct Deferred {
+    promise: JsPromise,
+    functions: ResolvingFunctions,
+}
+
+impl Deferred {
+    /// Creates a new pending promise.
+    pub(crate) fn new(context: &mut Context) -> Self {
+        let (promise, functions) = JsPromise::new_pending(context);
+        Self { promise, functions }
+    }
+
+    /// Creates a promise that is already resolved with `value`.
+    pub(crate) fn resolved(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        let deferred = Self::new(context);
+        deferred.resolve(value, context)?;
+        Ok(deferred)
+    }
+
+    /// Creates a promise that is already rejected with `reason`, and marks it as handled.
+    pub(crate) fn rejected(reason: JsValue, context: &mut Context) -> JsResult<Self> {
+        let deferred = Self::new(context);
+        deferred.reject(reason, context)?;
+        mark_handled(&deferred.promise, context)?;
+        Ok(deferred)
+    }
+
+    /// Returns the promise.
+    pub(crate) fn promise(&self) -> JsPromise {
+        self.promise.clone()
+    }
+
+    /// Returns `true` if the promise has not settled yet.
+    pub(crate) fn is_pending(&self) -> bool {
+        matches!(
+            self.promise.state(),
+            boa_engine::builtins::promise::PromiseState::Pending
+        )
+    }
+
+    /// Resolves the promise with `value`. Does nothing if it already settled.
+    pub(crate) fn resolve(&self, value: JsValue, context: &mut Context) -> JsResult<()> {
+        self.functions
+            .resolve
+            .call(&JsValue::undefined(), &[value], context)?;
+        Ok(())
+    }
+
+    /// Rejects the promise with `reason`. Does nothing if it already settled.
+    pub(crate) fn reject(&self, reason: JsValue, context: &mut Context) -> JsResult<()> {
+        self.functions
+            .reject
+            .call(&JsValue::undefined(), &[reason], context)?;
+        Ok(())
+    }
+}
+
+/// An algorithm of an underlying source, sink or transformer.
+///
+/// This is either a method of the dictionary that was given to a stream constructor, called
+/// with the dictionary as `this`, or a native function for streams created by the runtime.
+/// A missing method does nothing and returns `undefined`.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct Algorithm {
+    this: JsValue,
+    function: Option<JsObject>,
+}
+
+impl Algorithm {
+    /// An algorithm that does nothing.
+    pub(crate) fn none() -> Self {
+        Self {
+            this: JsValue::undefined(),
+            function: None,
+        }
+    }
+
+    /// An algorithm calling the `key` method of `dictionary`, if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the method is not callable.
+    pub(crate) fn method(
+        dictionary: &JsObject,
+        key: &str,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let value = dictionary.get(JsString::from(key), context)?;
+        if value.is_undefined() {
+            return Ok(Self::none());
+        }
+        let Some(function) = value.as_callable() else {
+            return Err(js_error!(TypeError: "`{}` must be a function", key));
+        };
+        Ok(Self {
+            this: dictionary.clone().into(),
+            function: Some(function),
+        })
+    }
+
+    /// An algorithm running native `steps` with `captures` and the first argument.
+    pub(crate) fn native<T: Trace + Clone + 'static>(
+        captures: &T,
+        steps: Steps<T>,
+        context: &mut Context,
+    ) -> Self {
+        Self {
+            this: JsValue::undefined(),
+            function: Some(steps_function(captures, steps, context).into()),
+        }
+    }
+
+    /// Returns `true` if this algorithm does nothing.
+    pub(crate) fn is_none(&self) -> bool {
+        self.function.is_none()
+    }
+
+    /// Calls the algorithm with `args`.
+    pub(crate) fn call(&self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        match &self.function {
+            Some(function) => function.call(&self.this, args, context),
+            None => Ok(JsValue::undefined()),
+        }
+    }
+
+    /// Calls the algorithm with `args`, returning a promise resolved with its result or
+    /// rejected with the error it threw.
+    pub(crate) fn call_promise(
+        &self,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        match self.call(args, context) {
+            Ok(value) => resolved(value, context),
+            Err(error) => rejected_with(error, context),
+        }
+    }
+}
+
+/// A queue of values with their sizes, used by the default controllers.
+///
+/// See <https://streams.spec.whatwg.org/#queue-with-sizes>
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct QueueWithSizes<T: Trace + 'static> {
+    queue: VecDeque<(T, f64)>,
+    total_size: f64,
+}
+
+impl<T: Trace + 'static> Default for QueueWithSizes<T> {
+    fn default() -> Self {
+        Self {
+            queue: VecDeque::new(),
+            total_size: 0.0,
+        }
+    }
+}
+
+impl<T: Trace + 'static> QueueWithSizes<T> {
+    /// See <https://streams.spec.whatwg.org/#enqueue-value-with-size>
+    pub(crate) fn enqueue(&mut self, value: T, size: f64) -> JsResult<()> {
+        if !size.is_finite() || size < 0.0 {
+            return Err(
+                js_error!(RangeError: "the size of a chunk must be a finite, non-negative number"),
+            );
+        }
+        self.queue.push_back((value, size));
+        self.total_size += size;
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#dequeue-value>
+    pub(crate) fn dequeue(&mut self) -> Option<T> {
+        let (value, size) = self.queue.pop_front()?;
+        // Rounding errors could make the total slightly negative.
+        self.total_size = (self.total_size - size).max(0.0);
+        Some(value)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#peek-queue-value>
+    pub(crate) fn peek(&self) -> Option<&T> {
+        self.queue.front().map(|(value, _)| value)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#reset-queue>
+    pub(crate) fn reset(&mut self) {
+        self.queue.clear();
+        self.total_size = 0.0;
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    pub(crate) fn total_size(&self) -> f64 {
+        self.total_size
+    }
+}
+
+/// A `QueuingStrategy` dictionary.
+///
+/// See <https://streams.spec.whatwg.org/#dictdef-queuingstrategy>
+#[derive(Debug, Default, Clone)]
+pub struct QueuingStrategy {
+    high_water_mark: Option<f64>,
+    size: Option<JsFunction>,
+}
+
+// The members are read with `[[Get]]`, so the accessors of `ByteLengthQueuingStrategy` and
+// `CountQueuingStrategy` instances are honoured.
+impl TryFromJs for QueuingStrategy {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if value.is_null_or_undefined() {
+            return Ok(Self::default());
+        }
+        let Some(object) = value.as_object() else {
+            return Err(js_error!(TypeError: "the queuing strategy must be an object"));
+        };
+        let high_water_mark = object.get(js_string!("highWaterMark"), context)?;
+        let high_water_mark = if high_water_mark.is_undefined() {
+            None
+        } else {
+            Some(high_water_mark.to_number(context)?)
+        };
+        let size = object
+            .get(js_string!("size"), context)?
+            .try_js_into(context)?;
+        Ok(Self {
+            high_water_mark,
+            size,
+        })
+    }
+}
+
+impl QueuingStrategy {
+    /// See <https://streams.spec.whatwg.org/#validate-and-normalize-high-water-mark>
+    pub(crate) fn high_water_mark(&self, default: f64) -> JsResult<f64> {
+        let Some(high_water_mark) = self.high_water_mark else {
+            return Ok(default);
+        };
+        if high_water_mark.is_nan() || high_water_mark < 0.0 {
+            return Err(js_error!(RangeError: "highWaterMark must be a non-negative number"));
+        }
+        Ok(high_water_mark)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#make-size-algorithm-from-size-function>
+    pub(crate) fn size_algorithm(&self) -> SizeAlgorithm {
+        SizeAlgorithm(self.size.clone())
+    }
+
+    pub(crate) fn has_size(&self) -> bool {
+        self.size.is_some()
+    }
+}
+
+/// The size algorithm of a queuing strategy, which returns `1` for every chunk by default.
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+pub(crate) struct SizeAlgorithm(Option<JsFunction>);
+
+impl SizeAlgorithm {
+    pub(crate) fn size(&self, chunk: &JsValue, context: &mut Context) -> JsResult<f64> {
+        match &self.0 {
+            Some(size) => size
+                .call(&JsValue::undefined(), std::slice::from_ref(chunk), context)?
+                .to_number(context),
+            None => Ok(1.0),
+        }
+    }
+}
+
+/// JavaScript module containing the stream classes.
+#[boa_module]
+pub mod js_module {
+    type JsByteLengthQueuingStrategy = super::JsByteLengthQueuingStrategy;
+    type JsCompressionStream = super::JsCompressionStream;
+    type JsCountQueuingStrategy = super::JsCountQueuingStrategy;
+    type JsDecompressionStream = super::JsDecompressionStream;
+    type JsReadableByteStreamController = super::JsReadableByteStreamController;
+    type JsReadableStream = super::JsReadableStream;
+    type JsReadableStreamBYOBReader = super::JsReadableStreamBYOBReader;
+    type JsReadableStreamBYOBRequest = super::JsReadableStreamBYOBRequest;
+    type JsReadableStreamDefaultController = super::JsReadableStreamDefaultController;
+    type JsReadableStreamDefaultReader = super::JsReadableStreamDefaultReader;
+    type JsTransformStream = super::JsTransformStream;
+    type JsTransformStreamDefaultController = super::JsTransformStreamDefaultController;
+    type JsWritableStream = super::JsWritableStream;
+    type JsWritableStreamDefaultController = super::JsWritableStreamDefaultController;
+    type JsWritableStreamDefaultWriter = super::JsWritableStreamDefaultWriter;
+}
+
+/// Register the stream classes in the realm, or globally if `realm` is `None`.
+///
+/// `WritableStreamDefaultController.signal` and `pipeTo` use `AbortSignal`, which must
+/// also be registered.
+///
+/// # Errors
+/// Returns an error if any of the classes fail to register.
+pub fn register(realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+    js_module::boa_register(realm, context)
+}
@@ -0,0 +1,1155 @@
+//! The `ReadableByteStreamController` and `ReadableStreamBYOBRequest` JavaScript classes.
+//!
+//! See <https://streams.spec.whatwg.org/#rbs-controller-class> and
+//! <https://streams.spec.whatwg.org/#rs-byob-request-class>
+
+use super::{
+    Controller, JsReadableStream, ReadRequest, ReadableState, enforce_range_u64,
+    illegal_constructor,
+};
+use crate::stream::{Algorithm, create, resolved, type_error, upon};
+use boa_engine::builtins::typed_array::TypedArrayKind;
+use boa_engine::gc::{Gc, GcRefCell};
+use boa_engine::interop::JsClass;
+use boa_engine::object::builtins::{JsArrayBuffer, JsDataView, JsPromise, JsTypedArray};
+use boa_engine::{
+    Context, Finalize, JsData, JsError, JsObject, JsResult, JsValue, Trace, boa_class, js_error,
+    js_string,
+};
+use std::collections::VecDeque;
+
+/// The parts of an `ArrayBufferView`.
+#[derive(Debug, Clone)]
+pub(crate) struct ViewInfo {
+    pub(crate) buffer: JsArrayBuffer,
+    pub(crate) byte_offset: usize,
+    pub(crate) byte_length: usize,
+    pub(crate) element_size: usize,
+    pub(crate) constructor: JsObject,
+    pub(crate) is_data_view: bool,
+}
+
+/// Returns the constructor and element size of a kind of typed array.
+#[allow(clippy::match_wildcard_for_single_variants)]
+fn typed_array_constructor(
+    kind: TypedArrayKind,
+    context: &mut Context,
+) -> JsResult<(JsObject, usize)> {
+    let constructors = context.intrinsics().constructors();
+    let (constructor, element_size) = match kind {
+        TypedArrayKind::Int8 => (constructors.typed_int8_array(), 1),
+        TypedArrayKind::Uint8 => (constructors.typed_uint8_array(), 1),
+        TypedArrayKind::Uint8Clamped => (constructors.typed_uint8clamped_array(), 1),
+        TypedArrayKind::Int16 => (constructors.typed_int16_array(), 2),
+        TypedArrayKind::Uint16 => (constructors.typed_uint16_array(), 2),
+        TypedArrayKind::Int32 => (constructors.typed_int32_array(), 4),
+        TypedArrayKind::Uint32 => (constructors.typed_uint32_array(), 4),
+        TypedArrayKind::BigInt64 => (constructors.typed_bigint64_array(), 8),
+        TypedArrayKind::BigUint64 => (constructors.typed_biguint64_array(), 8),
+        TypedArrayKind::Float32 => (constructors.typed_float32_array(), 4),
+        TypedArrayKind::Float64 => (constructors.typed_float64_array(), 8),
+        // `Float16Array` only exists when the engine is built with the `float16` feature.
+        #[allow(unreachable_patterns)]
+        _ => {
+            let constructor = context
+                .global_object()
+                .get(js_string!("Float16Array"), context)?
+                .as_object()
+                .ok_or_else(|| js_error!(TypeError: "unsupported typed array"))?;
+            return Ok((constructor, 2));
+        }
+    };
+    Ok((constructor.constructor(), element_size))
+}
+
+/// Returns the parts of the `ArrayBufferView` `view`.
+///
+/// # Errors
+///
+/// Returns a `TypeError` if `view` is not an `ArrayBufferView` over an `ArrayBuffer`.
+pub(crate) fn view_info(view: &JsObject, context: &mut Context) -> JsResult<ViewInfo> {
+    let (buffer, byte_offset, byte_length, element_size, constructor, is_data_view) =
+        if let Ok(array) = JsTypedArray::from_object(view.clone()) {
+            let kind = array
+                .kind()
+                .ok_or_else(|| js_error!(TypeError: "view must be an ArrayBufferView"))?;
+            let (constructor, element_size) = typed_array_constructor(kind, context)?;
+            (
+                array.buffer(context)?,
+                array.byte_offset(context)?,
+                array.byte_length(context)?,
+                element_size,
+                constructor,
+                false,
+            )
+        } else if let Ok(data_view) = JsDataView::from_object(view.clone()) {
+            let constructor = context
+                .intrinsics()
+                .constructors()
+                .data_view()
+                .constructor();
+            let to_usize = |value: u64| {
+                usize::try_from(value).map_err(|_| js_error!(RangeError: "view is too large"))
+            };
+            (
+                data_view.buffer(context)?,
+                to_usize(data_view.byte_offset(context)?)?,
+                to_usize(data_view.byte_length(context)?)?,
+                1,
+                constructor,
+                true,
+            )
+        } else {
+            return Err(js_error!(TypeError: "view must be an ArrayBufferView"));
+        };
+    let buffer = buffer
+        .as_object()
+        .and_then(|buffer| JsArrayBuffer::from_object(buffer).ok())
+        .ok_or_else(|| js_error!(TypeError: "the buffer of view must be an ArrayBuffer"))?;
+    Ok(ViewInfo {
+        buffer,
+        byte_offset,
+        byte_length,
+        element_size,
+        constructor,
+        is_data_view,
+    })
+}
+
+/// Creates a view of `length` elements over `buffer`, starting at `byte_offset`.
+fn construct_view(
+    constructor: &JsObject,
+    buffer: &JsArrayBuffer,
+    byte_offset: usize,
+    length: usize,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    constructor
+        .construct(
+            &[buffer.clone().into(), byte_offset.into(), length.into()],
+            None,
+            context,
+        )
+        .map(Into::into)
+}
+
+/// Moves the contents of `buffer` to a new `ArrayBuffer`, detaching `buffer`.
+///
+/// See <https://tc39.es/ecma262/#sec-transferarraybuffer>
+pub(crate) fn transfer(buffer: &JsArrayBuffer, context: &mut Context) -> JsResult<JsArrayBuffer> {
+    let block = buffer.detach(&JsValue::undefined())?;
+    JsArrayBuffer::from_byte_block(block, context)
+}
+
+/// Copies `length` bytes from `source` to `target`.
+fn copy_bytes(
+    target: &JsArrayBuffer,
+    target_offset: usize,
+    source: &JsArrayBuffer,
+    source_offset: usize,
+    length: usize,
+) -> JsResult<()> {
+    let bytes = source
+        .data()
+        .and_then(|data| {
+            data.get(source_offset..source_offset + length)
+                .map(<[u8]>::to_vec)
+        })
+        .ok_or_else(|| js_error!(TypeError: "cannot read from a detached buffer"))?;
+    let mut target = target
+        .data_mut()
+        .ok_or_else(|| js_error!(TypeError: "cannot write to a detached buffer"))?;
+    target
+        .get_mut(target_offset..target_offset + length)
+        .ok_or_else(|| js_error!(RangeError: "out of bounds copy"))?
+        .copy_from_slice(&bytes);
+    Ok(())
+}
+
+/// The kind of reader a pull-into descriptor was made for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+enum ReaderType {
+    Default,
+    Byob,
+    /// The reader was released while the descriptor was pending.
+    None,
+}
+
+/// A pending read into a buffer.
+///
+/// See <https://streams.spec.whatwg.org/#pull-into-descriptor>
+#[derive(Debug, Clone, Trace, Finalize)]
+struct PullIntoDescriptor {
+    buffer: JsArrayBuffer,
+    buffer_byte_length: usize,
+    byte_offset: usize,
+    byte_length: usize,
+    bytes_filled: usize,
+    minimum_fill: usize,
+    element_size: usize,
+    view_constructor: JsObject,
+    reader_type: ReaderType,
+}
+
+/// A chunk in the queue of a byte stream.
+///
+/// See <https://streams.spec.whatwg.org/#readable-byte-stream-queue-entry>
+#[derive(Debug, Clone, Trace, Finalize)]
+struct QueueEntry {
+    buffer: JsArrayBuffer,
+    byte_offset: usize,
+    byte_length: usize,
+}
+
+#[derive(Debug, Trace, Finalize)]
+#[allow(clippy::struct_excessive_bools)]
+struct ByteControllerSlots {
+    stream: JsReadableStream,
+    auto_allocate_chunk_size: Option<usize>,
+    byob_request: Option<JsObject<JsReadableStreamBYOBRequest>>,
+    cancel: Algorithm,
+    pull: Algorithm,
+    close_requested: bool,
+    pull_again: bool,
+    pulling: bool,
+    started: bool,
+    pending_pull_intos: VecDeque<PullIntoDescriptor>,
+    queue: VecDeque<QueueEntry>,
+    queue_total_size: usize,
+    strategy_hwm: f64,
+}
+
+/// The JavaScript `ReadableByteStreamController` class, which controls a readable stream of
+/// bytes.
+///
+/// See <https://streams.spec.whatwg.org/#rbs-controller-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsReadableByteStreamController {
+    slots: Gc<GcRefCell<ByteControllerSlots>>,
+}
+
+impl JsReadableByteStreamController {
+    /// Returns the controller of a `ReadableByteStreamController` object.
+    pub(crate) fn of(object: &JsObject<Self>) -> Self {
+        object.borrow().data().clone()
+    }
+
+    /// Sets up `stream` with a new byte stream controller.
+    ///
+    /// See <https://streams.spec.whatwg.org/#set-up-readable-byte-stream-controller>
+    pub(crate) fn set_up(
+        stream: &JsReadableStream,
+        start: &Algorithm,
+        pull: Algorithm,
+        cancel: Algorithm,
+        high_water_mark: f64,
+        auto_allocate_chunk_size: Option<usize>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let controller = Self {
+            slots: Gc::new(GcRefCell::new(ByteControllerSlots {
+                stream: stream.clone(),
+                auto_allocate_chunk_size,
+                byob_request: None,
+                cancel,
+                pull,
+                close_requested: false,
+                pull_again: false,
+                pulling: false,
+                started: false,
+                pending_pull_intos: VecDeque::new(),
+                queue: VecDeque::new(),
+                queue_total_size: 0,
+                strategy_hwm: high_water_mark,
+            })),
+        };
+        let object = create(controller.clone(), context)?;
+        stream.set_controller(Controller::Byte(object.clone()));
+
+        let start_result = start.call(&[object.upcast().into()], context)?;
+        let start_promise = resolved(start_result, context)?;
+        upon(
+            &start_promise,
+            &controller,
+            |controller, _, context| {
+                controller.slots.borrow_mut().started = true;
+                controller.call_pull_if_needed(context)?;
+                Ok(JsValue::undefined())
+            },
+            |controller, error, context| {
+                controller.error(error, context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        )
+    }
+
+    fn stream(&self) -> JsReadableStream {
+        self.slots.borrow().stream.clone()
+    }
+
+    pub(crate) fn has_pending_pull_intos(&self) -> bool {
+        !self.slots.borrow().pending_pull_intos.is_empty()
+    }
+
+    fn first_pending_pull_into(&self) -> Option<PullIntoDescriptor> {
+        self.slots.borrow().pending_pull_intos.front().cloned()
+    }
+
+    fn shift_pending_pull_into(&self) -> Option<PullIntoDescriptor> {
+        self.slots.borrow_mut().pending_pull_intos.pop_front()
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-call-pull-if-needed>
+    fn call_pull_if_needed(&self, context: &mut Context) -> JsResult<()> {
+        if !self.should_call_pull() {
+            return Ok(());
+        }
+        let pull = {
+            let mut slots = self.slots.borrow_mut();
+            if slots.pulling {
+                slots.pull_again = true;
+                return Ok(());
+            }
+            slots.pulling = true;
+            slots.pull.clone()
+        };
+        let object = self.stream().controller().object();
+        let pull_promise = pull.call_promise(&[object.into()], context)?;
+        upon(
+            &pull_promise,
+            self,
+            |controller, _, context| {
+                let pull_again = {
+                    let mut slots = controller.slots.borrow_mut();
+                    slots.pulling = false;
+                    std::mem::take(&mut slots.pull_again)
+                };
+                if pull_again {
+                    controller.call_pull_if_needed(context)?;
+                }
+                Ok(JsValue::undefined())
+            },
+            |controller, error, context| {
+                controller.error(error, context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        )
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-should-call-pull>
+    fn should_call_pull(&self) -> bool {
+        let stream = self.stream();
+        {
+            let slots = self.slots.borrow();
+            if stream.state() != ReadableState::Readable || slots.close_requested || !slots.started
+            {
+                return false;
+            }
+        }
+        if (stream.has_default_reader() || stream.has_byob_reader())
+            && stream.num_read_requests() > 0
+        {
+            return true;
+        }
+        self.desired_size().is_some_and(|size| size > 0.0)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-clear-algorithms>
+    fn clear_algorithms(&self) {
+        let mut slots = self.slots.borrow_mut();
+        slots.pull = Algorithm::none();
+        slots.cancel = Algorithm::none();
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-clear-pending-pull-intos>
+    fn clear_pending_pull_intos(&self) {
+        self.invalidate_byob_request();
+        self.slots.borrow_mut().pending_pull_intos.clear();
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-invalidate-byob-request>
+    fn invalidate_byob_request(&self) {
+        let Some(request) = self.slots.borrow_mut().byob_request.take() else {
+            return;
+        };
+        let request = request.borrow().data().clone();
+        let mut slots = request.slots.borrow_mut();
+        slots.controller = None;
+        slots.view = None;
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-close>
+    pub(crate) fn close(&self, context: &mut Context) -> JsResult<()> {
+        let stream = self.stream();
+        {
+            let mut slots = self.slots.borrow_mut();
+            if slots.close_requested || stream.state() != ReadableState::Readable {
+                return Ok(());
+            }
+            if slots.queue_total_size > 0 {
+                slots.close_requested = true;
+                return Ok(());
+            }
+        }
+        if let Some(first) = self.first_pending_pull_into()
+            && first.bytes_filled % first.element_size != 0
+        {
+            let error = type_error(
+                "the stream was closed with a partially filled view",
+                context,
+            );
+            self.error(error.clone(), context)?;
+            return Err(JsError::from_opaque(error));
+        }
+        self.clear_algorithms();
+        stream.close(context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-enqueue>
+    pub(crate) fn enqueue(&self, chunk: &JsObject, context: &mut Context) -> JsResult<()> {
+        let stream = self.stream();
+        if self.slots.borrow().close_requested || stream.state() != ReadableState::Readable {
+            return Ok(());
+        }
+        let info = view_info(chunk, context)?;
+        if info.buffer.data().is_none() {
+            return Err(js_error!(TypeError: "the buffer of chunk is detached"));
+        }
+        let transferred = transfer(&info.buffer, context)?;
+
+        if let Some(first) = self.first_pending_pull_into() {
+            if first.buffer.data().is_none() {
+                return Err(js_error!(TypeError: "the buffer of the BYOB request is detached"));
+            }
+            self.invalidate_byob_request();
+            let buffer = transfer(&first.buffer, context)?;
+            let reader_type = {
+                let mut slots = self.slots.borrow_mut();
+                let first = slots
+                    .pending_pull_intos
+                    .front_mut()
+                    .expect("the queue was checked to not be empty");
+                first.buffer = buffer;
+                first.reader_type
+            };
+            if reader_type == ReaderType::None {
+                self.enqueue_detached_pull_into_to_queue(context)?;
+            }
+        }
+
+        if stream.has_default_reader() {
+            self.process_read_requests_using_queue(context)?;
+            if stream.num_read_requests() == 0 {
+                self.enqueue_chunk_to_queue(transferred, info.byte_offset, info.byte_length);
+            } else {
+                if self.has_pending_pull_intos() {
+                    self.shift_pending_pull_into();
+                }
+                let view = construct_view(
+                    &context
+                        .intrinsics()
+                        .constructors()
+                        .typed_uint8_array()
+                        .constructor(),
+                    &transferred,
+                    info.byte_offset,
+                    info.byte_length,
+                    context,
+                )?;
+                stream.fulfill_read_request(view, false, context)?;
+            }
+        } else if stream.has_byob_reader() {
+            self.enqueue_chunk_to_queue(transferred, info.byte_offset, info.byte_length);
+            for descriptor in self.process_pull_into_descriptors_using_queue()? {
+                self.commit_pull_into_descriptor(descriptor, context)?;
+            }
+        } else {
+            self.enqueue_chunk_to_queue(transferred, info.byte_offset, info.byte_length);
+        }
+        self.call_pull_if_needed(context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-enqueue-chunk-to-queue>
+    fn enqueue_chunk_to_queue(
+        &self,
+        buffer: JsArrayBuffer,
+        byte_offset: usize,
+        byte_length: usize,
+    ) {
+        let mut slots = self.slots.borrow_mut();
+        slots.queue.push_back(QueueEntry {
+            buffer,
+            byte_offset,
+            byte_length,
+        });
+        slots.queue_total_size += byte_length;
+    }
+
+    /// See <https://streams.spec.whatwg.org/#abstract-opdef-readablebytestreamcontrollerenqueueclonedchunktoqueue>
+    fn enqueue_cloned_chunk_to_queue(
+        &self,
+        buffer: &JsArrayBuffer,
+        byte_offset: usize,
+        byte_length: usize,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let clone = JsArrayBuffer::new(byte_length, context).and_then(|clone| {
+            copy_bytes(&clone, 0, buffer, byte_offset, byte_length).map(|()| clone)
+        });
+        match clone {
+            Ok(clone) => {
+                self.enqueue_chunk_to_queue(clone, 0, byte_length);
+                Ok(())
+            }
+            Err(error) => {
+                let error = error.into_opaque(context)?;
+                self.error(error.clone(), context)?;
+                Err(JsError::from_opaque(error))
+            }
+        }
+    }
+
+    /// See <https://streams.spec.whatwg.org/#abstract-opdef-readablebytestreamcontrollerenqueuedetachedpullintotoqueue>
+    fn enqueue_detached_pull_into_to_queue(&self, context: &mut Context) -> JsResult<()> {
+        let Some(descriptor) = self.first_pending_pull_into() else {
+            return Ok(());
+        };
+        if descriptor.bytes_filled > 0 {
+            self.enqueue_cloned_chunk_to_queue(
+                &descriptor.buffer,
+                descriptor.byte_offset,
+                descriptor.bytes_filled,
+                context,
+            )?;
+        }
+        self.shift_pending_pull_into();
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-error>
+    pub(crate) fn error(&self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        let stream = self.stream();
+        if stream.state() != ReadableState::Readable {
+            return Ok(());
+        }
+        self.clear_pending_pull_intos();
+        {
+            let mut slots = self.slots.borrow_mut();
+            slots.queue.clear();
+            slots.queue_total_size = 0;
+        }
+        self.clear_algorithms();
+        stream.error(error, context)
+    }
+
+    /// Fills `descriptor` with as many bytes from the queue as possible. Returns `true` if
+    /// it was filled up to its minimum.
+    ///
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-fill-pull-into-descriptor-from-queue>
+    fn fill_pull_into_descriptor_from_queue(
+        &self,
+        descriptor: &mut PullIntoDescriptor,
+    ) -> JsResult<bool> {
+        let queue_total_size = self.slots.borrow().queue_total_size;
+        let max_bytes_to_copy =
+            queue_total_size.min(descriptor.byte_length - descriptor.bytes_filled);
+        let max_bytes_filled = descriptor.bytes_filled + max_bytes_to_copy;
+        let mut total_bytes_to_copy_remaining = max_bytes_to_copy;
+        let mut ready = false;
+        let remainder_bytes = max_bytes_filled % descriptor.element_size;
+        let max_aligned_bytes = max_bytes_filled - remainder_bytes;
+        if max_aligned_bytes >= descriptor.minimum_fill {
+            total_bytes_to_copy_remaining = max_aligned_bytes - descriptor.bytes_filled;
+            ready = true;
+        }
+        if total_bytes_to_copy_remaining > 0 {
+            self.invalidate_byob_request();
+        }
+
+        let mut slots = self.slots.borrow_mut();
+        while total_bytes_to_copy_remaining > 0 {
+            let head = slots
+                .queue
+                .front_mut()
+                .expect("the queue holds enough bytes to copy");
+            let bytes_to_copy = total_bytes_to_copy_remaining.min(head.byte_length);
+            let dest_start = descriptor.byte_offset + descriptor.bytes_filled;
+            copy_bytes(
+                &descriptor.buffer,
+                dest_start,
+                &head.buffer,
+                head.byte_offset,
+                bytes_to_copy,
+            )?;
+            if head.byte_length == bytes_to_copy {
+                slots.queue.pop_front();
+            } else {
+                head.byte_offset += bytes_to_copy;
+                head.byte_length -= bytes_to_copy;
+            }
+            slots.queue_total_size -= bytes_to_copy;
+            descriptor.bytes_filled += bytes_to_copy;
+            total_bytes_to_copy_remaining -= bytes_to_copy;
+        }
+        Ok(ready)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-process-pull-into-descriptors-using-queue>
+    fn process_pull_into_descriptors_using_queue(&self) -> JsResult<Vec<PullIntoDescriptor>> {
+        let mut filled = Vec::new();
+        loop {
+            if self.slots.borrow().queue_total_size == 0 {
+                break;
+            }
+            let Some(mut descriptor) = self.shift_pending_pull_into() else {
+                break;
+            };
+            if self.fill_pull_into_descriptor_from_queue(&mut descriptor)? {
+                filled.push(descriptor);
+            } else {
+                self.slots
+                    .borrow_mut()
+                    .pending_pull_intos
+                    .push_front(descriptor);
+            }
+        }
+        Ok(filled)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#abstract-opdef-readablebytestreamcontrollerprocessreadrequestsusingqueue>
+    fn process_read_requests_using_queue(&self, context: &mut Context) -> JsResult<()> {
+        let Some(reader) = self.stream().reader() else {
+            return Ok(());
+        };
+        while reader.generic().num_requests() > 0 {
+            if self.slots.borrow().queue_total_size == 0 {
+                return Ok(());
+            }
+            let request = reader
+                .generic()
+                .shift_request()
+                .expect("the reader has pending read requests");
+            self.fill_read_request_from_queue(request, context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#abstract-opdef-readablebytestreamcontrollerfillreadrequestfromqueue>
+    fn fill_read_request_from_queue(
+        &self,
+        request: ReadRequest,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let entry = {
+            let mut slots = self.slots.borrow_mut();
+            let entry = slots
+                .queue
+                .pop_front()
+                .expect("the queue of the stream is not empty");
+            slots.queue_total_size -= entry.byte_length;
+            entry
+        };
+        self.handle_queue_drain(context)?;
+        let view = construct_view(
+            &context
+                .intrinsics()
+                .constructors()
+                .typed_uint8_array()
+                .constructor(),
+            &entry.buffer,
+            entry.byte_offset,
+            entry.byte_length,
+            context,
+        )?;
+        request.chunk_steps(view, context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-handle-queue-drain>
+    fn handle_queue_drain(&self, context: &mut Context) -> JsResult<()> {
+        let close = {
+            let slots = self.slots.borrow();
+            slots.queue_total_size == 0 && slots.close_requested
+        };
+        if close {
+            self.clear_algorithms();
+            self.stream().close(context)
+        } else {
+            self.call_pull_if_needed(context)
+        }
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-commit-pull-into-descriptor>
+    fn commit_pull_into_descriptor(
+        &self,
+        descriptor: PullIntoDescriptor,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let stream = self.stream();
+        let done = stream.state() == ReadableState::Closed;
+        let view = convert_pull_into_descriptor(&descriptor, context)?;
+        stream.fulfill_read_request(view, done, context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-respond>
+    pub(crate) fn respond(&self, bytes_written: usize, context: &mut Context) -> JsResult<()> {
+        let first = self
+            .first_pending_pull_into()
+            .ok_or_else(|| js_error!(TypeError: "there is no pending BYOB request"))?;
+        if self.stream().state() == ReadableState::Closed {
+            if bytes_written != 0 {
+                return Err(
+                    js_error!(TypeError: "bytesWritten must be 0 when the stream is closed"),
+                );
+            }
+        } else {
+            if bytes_written == 0 {
+                return Err(js_error!(TypeError: "bytesWritten must be greater than 0"));
+            }
+            if first.bytes_filled + bytes_written > first.byte_length {
+                return Err(js_error!(RangeError: "bytesWritten is out of range"));
+            }
+        }
+        let buffer = transfer(&first.buffer, context)?;
+        self.set_first_buffer(buffer);
+        self.respond_internal(bytes_written, context)
+    }
+
+    fn set_first_buffer(&self, buffer: JsArrayBuffer) {
+        if let Some(first) = self.slots.borrow_mut().pending_pull_intos.front_mut() {
+            first.buffer = buffer;
+        }
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-respond-internal>
+    fn respond_internal(&self, bytes_written: usize, context: &mut Context) -> JsResult<()> {
+        self.invalidate_byob_request();
+        if self.stream().state() == ReadableState::Closed {
+            self.respond_in_closed_state(context)?;
+        } else {
+            self.respond_in_readable_state(bytes_written, context)?;
+        }
+        self.call_pull_if_needed(context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-respond-in-closed-state>
+    fn respond_in_closed_state(&self, context: &mut Context) -> JsResult<()> {
+        let first = self
+            .first_pending_pull_into()
+            .expect("respond is only called with a pending pull-into");
+        if first.reader_type == ReaderType::None {
+            self.shift_pending_pull_into();
+        }
+        let stream = self.stream();
+        if stream.has_byob_reader() {
+            while stream.num_read_requests() > 0 {
+                let Some(descriptor) = self.shift_pending_pull_into() else {
+                    break;
+                };
+                self.commit_pull_into_descriptor(descriptor, context)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-respond-in-readable-state>
+    fn respond_in_readable_state(
+        &self,
+        bytes_written: usize,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let descriptor = {
+            let mut slots = self.slots.borrow_mut();
+            let first = slots
+                .pending_pull_intos
+                .front_mut()
+                .expect("respond is only called with a pending pull-into");
+            first.bytes_filled += bytes_written;
+            first.clone()
+        };
+        if descriptor.reader_type == ReaderType::None {
+            self.enqueue_detached_pull_into_to_queue(context)?;
+            for descriptor in self.process_pull_into_descriptors_using_queue()? {
+                self.commit_pull_into_descriptor(descriptor, context)?;
+            }
+            return Ok(());
+        }
+        if descriptor.bytes_filled < descriptor.minimum_fill {
+            return Ok(());
+        }
+        let mut descriptor = self
+            .shift_pending_pull_into()
+            .expect("respond is only called with a pending pull-into");
+        let remainder_size = descriptor.bytes_filled % descriptor.element_size;
+        if remainder_size > 0 {
+            let end = descriptor.byte_offset + descriptor.bytes_filled;
+            self.enqueue_cloned_chunk_to_queue(
+                &descriptor.buffer,
+                end - remainder_size,
+                remainder_size,
+                context,
+            )?;
+        }
+        descriptor.bytes_filled -= remainder_size;
+        let filled = self.process_pull_into_descriptors_using_queue()?;
+        self.commit_pull_into_descriptor(descriptor, context)?;
+        for descriptor in filled {
+            self.commit_pull_into_descriptor(descriptor, context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-respond-with-new-view>
+    fn respond_with_new_view(&self, view: &JsObject, context: &mut Context) -> JsResult<()> {
+        let info = view_info(view, context)?;
+        if info.buffer.data().is_none() {
+            return Err(js_error!(TypeError: "the buffer of view is detached"));
+        }
+        let first = self
+            .first_pending_pull_into()
+            .ok_or_else(|| js_error!(TypeError: "there is no pending BYOB request"))?;
+        if self.stream().state() == ReadableState::Closed {
+            if info.byte_length != 0 {
+                return Err(js_error!(TypeError: "view must be empty when the stream is closed"));
+            }
+        } else if info.byte_length == 0 {
+            return Err(js_error!(TypeError: "view must not be empty"));
+        }
+        if first.byte_offset + first.bytes_filled != info.byte_offset {
+            return Err(js_error!(RangeError: "view has an invalid byteOffset"));
+        }
+        if first.buffer_byte_length != info.buffer.byte_length() {
+            return Err(js_error!(RangeError: "the buffer of view has an invalid byteLength"));
+        }
+        if first.bytes_filled + info.byte_length > first.byte_length {
+            return Err(js_error!(RangeError: "view has an invalid byteLength"));
+        }
+        let buffer = transfer(&info.buffer, context)?;
+        self.set_first_buffer(buffer);
+        self.respond_internal(info.byte_length, context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#abstract-opdef-readablebytestreamcontrollergetbyobrequest>
+    fn byob_request(&self, context: &mut Context) -> JsResult<Option<JsObject>> {
+        if let Some(request) = &self.slots.borrow().byob_request {
+            return Ok(Some(request.clone().upcast()));
+        }
+        let Some(first) = self.first_pending_pull_into() else {
+            return Ok(None);
+        };
+        let view = construct_view(
+            &context
+                .intrinsics()
+                .constructors()
+                .typed_uint8_array()
+                .constructor(),
+            &first.buffer,
+            first.byte_offset + first.bytes_filled,
+            first.byte_length - first.bytes_filled,
+            context,
+        )?;
+        let request = JsReadableStreamBYOBRequest {
+            slots: Gc::new(GcRefCell::new(ByobRequestSlots {
+                controller: Some(self.clone()),
+                view: view.as_object(),
+            })),
+        };
+        let request = create(request, context)?;
+        self.slots.borrow_mut().byob_request = Some(request.clone());
+        Ok(Some(request.upcast()))
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-get-desired-size>
+    pub(crate) fn desired_size(&self) -> Option<f64> {
+        let slots = self.slots.borrow();
+        match slots.stream.state() {
+            ReadableState::Errored => None,
+            ReadableState::Closed => Some(0.0),
+            #[allow(clippy::cast_precision_loss)]
+            ReadableState::Readable => Some(slots.strategy_hwm - slots.queue_total_size as f64),
+        }
+    }
+
+    /// See <https://streams.spec.whatwg.org/#rbs-controller-private-cancel>
+    pub(crate) fn cancel_steps(
+        &self,
+        reason: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        self.clear_pending_pull_intos();
+        let cancel = {
+            let mut slots = self.slots.borrow_mut();
+            slots.queue.clear();
+            slots.queue_total_size = 0;
+            slots.cancel.clone()
+        };
+        let result = cancel.call_promise(&[reason], context);
+        self.clear_algorithms();
+        result
+    }
+
+    /// See <https://streams.spec.whatwg.org/#rbs-controller-private-pull>
+    pub(crate) fn pull_steps(&self, request: ReadRequest, context: &mut Context) -> JsResult<()> {
+        if self.slots.borrow().queue_total_size > 0 {
+            return self.fill_read_request_from_queue(request, context);
+        }
+        let auto_allocate_chunk_size = self.slots.borrow().auto_allocate_chunk_size;
+        if let Some(size) = auto_allocate_chunk_size {
+            let buffer = match JsArrayBuffer::new(size, context) {
+                Ok(buffer) => buffer,
+                Err(error) => {
+                    let error = error.into_opaque(context)?;
+                    return request.error_steps(error, context);
+                }
+            };
+            let view_constructor = context
+                .intrinsics()
+                .constructors()
+                .typed_uint8_array()
+                .constructor();
+            self.slots
+                .borrow_mut()
+                .pending_pull_intos
+                .push_back(PullIntoDescriptor {
+                    buffer,
+                    buffer_byte_length: size,
+                    byte_offset: 0,
+                    byte_length: size,
+                    bytes_filled: 0,
+                    minimum_fill: 1,
+                    element_size: 1,
+                    view_constructor,
+                    reader_type: ReaderType::Default,
+                });
+        }
+        self.stream().add_read_request(request);
+        self.call_pull_if_needed(context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#abstract-opdef-readablebytestreamcontroller-releasesteps>
+    pub(crate) fn release_steps(&self) {
+        let mut slots = self.slots.borrow_mut();
+        if let Some(mut first) = slots.pending_pull_intos.pop_front() {
+            first.reader_type = ReaderType::None;
+            slots.pending_pull_intos.clear();
+            slots.pending_pull_intos.push_back(first);
+        }
+    }
+
+    /// Reads into the view described by `info`, filling at least `min` elements.
+    ///
+    /// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-pull-into>
+    pub(crate) fn pull_into(
+        &self,
+        info: ViewInfo,
+        min: usize,
+        request: ReadRequest,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let minimum_fill = min * info.element_size;
+        let buffer = match transfer(&info.buffer, context) {
+            Ok(buffer) => buffer,
+            Err(error) => {
+                let error = error.into_opaque(context)?;
+                return request.error_steps(error, context);
+            }
+        };
+        let mut descriptor = PullIntoDescriptor {
+            buffer_byte_length: buffer.byte_length(),
+            buffer,
+            byte_offset: info.byte_offset,
+            byte_length: info.byte_length,
+            bytes_filled: 0,
+            minimum_fill,
+            element_size: info.element_size,
+            view_constructor: info.constructor,
+            reader_type: ReaderType::Byob,
+        };
+        let stream = self.stream();
+
+        if self.has_pending_pull_intos() {
+            self.slots
+                .borrow_mut()
+                .pending_pull_intos
+                .push_back(descriptor);
+            stream.add_read_request(request);
+            return Ok(());
+        }
+        if stream.state() == ReadableState::Closed {
+            let empty = construct_view(
+                &descriptor.view_constructor,
+                &descriptor.buffer,
+                descriptor.byte_offset,
+                0,
+                context,
+            )?;
+            return request.close_steps(empty, context);
+        }
+        if self.slots.borrow().queue_total_size > 0 {
+            if self.fill_pull_into_descriptor_from_queue(&mut descriptor)? {
+                let view = convert_pull_into_descriptor(&descriptor, context)?;
+                self.handle_queue_drain(context)?;
+                return request.chunk_steps(view, context);
+            }
+            if self.slots.borrow().close_requested {
+                let error = type_error("the stream is closing", context);
+                self.error(error.clone(), context)?;
+                return request.error_steps(error, context);
+            }
+        }
+        self.slots
+            .borrow_mut()
+            .pending_pull_intos
+            .push_back(descriptor);
+        stream.add_read_request(request);
+        self.call_pull_if_needed(context)
+    }
+}
+
+/// Transfers the buffer of `descriptor` to a new view of its filled elements.
+///
+/// See <https://streams.spec.whatwg.org/#readable-byte-stream-controller-convert-pull-into-descriptor>
+fn convert_pull_into_descriptor(
+    descriptor: &PullIntoDescriptor,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let buffer = transfer(&descriptor.buffer, context)?;
+    construct_view(
+        &descriptor.view_constructor,
+        &buffer,
+        descriptor.byte_offset,
+        descriptor.bytes_filled / descriptor.element_size,
+        context,
+    )
+}
+
+#[boa_class(rename = "ReadableByteStreamController")]
+#[boa(rename_all = "camelCase")]
+impl JsReadableByteStreamController {
+    #[boa(constructor)]
+    fn constructor() -> JsResult<Self> {
+        Err(illegal_constructor())
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "byobRequest")]
+    fn byob_request_(this: JsClass<Self>, context: &mut Context) -> JsResult<Option<JsObject>> {
+        this.clone_inner().byob_request(context)
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "desiredSize")]
+    fn desired_size_(&self) -> Option<f64> {
+        self.desired_size()
+    }
+
+    #[boa(method)]
+    #[boa(rename = "close")]
+    fn close_(this: JsClass<Self>, context: &mut Context) -> JsResult<()> {
+        let controller = this.clone_inner();
+        if controller.slots.borrow().close_requested {
+            return Err(js_error!(TypeError: "the stream is already closing"));
+        }
+        if controller.stream().state() != ReadableState::Readable {
+            return Err(js_error!(TypeError: "the stream is not readable"));
+        }
+        controller.close(context)
+    }
+
+    #[boa(method)]
+    #[boa(rename = "enqueue")]
+    fn enqueue_(this: JsClass<Self>, chunk: JsValue, context: &mut Context) -> JsResult<()> {
+        let controller = this.clone_inner();
+        let Some(chunk) = chunk.as_object() else {
+            return Err(js_error!(TypeError: "chunk must be an ArrayBufferView"));
+        };
+        let info = view_info(&chunk, context)?;
+        if info.byte_length == 0 {
+            return Err(js_error!(TypeError: "chunk must have a non-zero byteLength"));
+        }
+        if info.buffer.byte_length() == 0 {
+            return Err(
+                js_error!(TypeError: "the buffer of chunk must have a non-zero byteLength"),
+            );
+        }
+        if controller.slots.borrow().close_requested {
+            return Err(js_error!(TypeError: "the stream is closing"));
+        }
+        if controller.stream().state() != ReadableState::Readable {
+            return Err(js_error!(TypeError: "the stream is not readable"));
+        }
+        controller.enqueue(&chunk, context)
+    }
+
+    #[boa(method)]
+    #[boa(rename = "error")]
+    fn error_(this: JsClass<Self>, error: Option<JsValue>, context: &mut Context) -> JsResult<()> {
+        this.clone_inner().error(error.unwrap_or_default(), context)
+    }
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct ByobRequestSlots {
+    controller: Option<JsReadableByteStreamController>,
+    view: Option<JsObject>,
+}
+
+/// The JavaScript `ReadableStreamBYOBRequest` class, which lets an underlying byte source
+/// write directly into the buffer of a pending read.
+///
+/// See <https://streams.spec.whatwg.org/#rs-byob-request-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsReadableStreamBYOBRequest {
+    slots: Gc<GcRefCell<ByobRequestSlots>>,
+}
+
+#[boa_class(rename = "ReadableStreamBYOBRequest")]
+#[boa(rename_all = "camelCase")]
+impl JsReadableStreamBYOBRequest {
+    #[boa(constructor)]
+    fn constructor() -> JsResult<Self> {
+        Err(illegal_constructor())
+    }
+
+    #[boa(getter)]
+    fn view(&self) -> Option<JsObject> {
+        self.slots.borrow().view.clone()
+    }
+
+    #[boa(method)]
+    fn respond(this: JsClass<Self>, bytes_written: JsValue, context: &mut Context) -> JsResult<()> {
+        let bytes_written = enforce_range_u64(&bytes_written, context)?;
+        let (controller, view) = {
+            let request = this.borrow();
+            let slots = request.slots.borrow();
+            (slots.controller.clone(), slots.view.clone())
+        };
+        let (Some(controller), Some(view)) = (controller, view) else {
+            return Err(js_error!(TypeError: "the BYOB request has already been responded to"));
+        };
+        if view_info(&view, context)?.buffer.data().is_none() {
+            return Err(js_error!(TypeError: "the buffer of the view is detached"));
+        }
+        let bytes_written = usize::try_from(bytes_written)
+            .map_err(|_| js_error!(RangeError: "bytesWritten is out of range"))?;
+        controller.respond(bytes_written, context)
+    }
+
+    #[boa(method)]
+    fn respond_with_new_view(
+        this: JsClass<Self>,
+        view: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let Some(view) = view.as_object() else {
+            return Err(js_error!(TypeError: "view must be an ArrayBufferView"));
+        };
+        let controller = this.borrow().slots.borrow().controller.clone();
+        let Some(controller) = controller else {
+            return Err(js_error!(TypeError: "the BYOB request has already been responded to"));
+        };
+        controller.respond_with_new_view(&view, context)
+    }
+}
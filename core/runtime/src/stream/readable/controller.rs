@@ -0,0 +1,300 @@
+//! The `ReadableStreamDefaultController` JavaScript class.
+//!
+//! See <https://streams.spec.whatwg.org/#rs-default-controller-class>
+
+use super::{Controller, JsReadableStream, ReadRequest, ReadableState, illegal_constructor};
+use crate::stream::{Algorithm, QueueWithSizes, SizeAlgorithm, create, resolved, upon};
+use boa_engine::gc::{Gc, GcRefCell};
+use boa_engine::interop::JsClass;
+use boa_engine::object::builtins::JsPromise;
+use boa_engine::{
+    Context, Finalize, JsData, JsError, JsObject, JsResult, JsValue, Trace, boa_class, js_error,
+};
+
+#[derive(Debug, Trace, Finalize)]
+#[allow(clippy::struct_excessive_bools)]
+struct DefaultControllerSlots {
+    stream: JsReadableStream,
+    queue: QueueWithSizes<JsValue>,
+    started: bool,
+    close_requested: bool,
+    pull_again: bool,
+    pulling: bool,
+    strategy_hwm: f64,
+    strategy_size: SizeAlgorithm,
+    pull: Algorithm,
+    cancel: Algorithm,
+}
+
+/// The JavaScript `ReadableStreamDefaultController` class, which controls a readable stream
+/// of arbitrary chunks.
+///
+/// See <https://streams.spec.whatwg.org/#rs-default-controller-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsReadableStreamDefaultController {
+    slots: Gc<GcRefCell<DefaultControllerSlots>>,
+}
+
+impl JsReadableStreamDefaultController {
+    /// Returns the controller of a `ReadableStreamDefaultController` object.
+    pub(crate) fn of(object: &JsObject<Self>) -> Self {
+        object.borrow().data().clone()
+    }
+
+    /// Sets up `stream` with a new default controller.
+    ///
+    /// See <https://streams.spec.whatwg.org/#set-up-readable-stream-default-controller>
+    pub(crate) fn set_up(
+        stream: &JsReadableStream,
+        start: &Algorithm,
+        pull: Algorithm,
+        cancel: Algorithm,
+        high_water_mark: f64,
+        size: SizeAlgorithm,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let controller = Self {
+            slots: Gc::new(GcRefCell::new(DefaultControllerSlots {
+                stream: stream.clone(),
+                queue: QueueWithSizes::default(),
+                started: false,
+                close_requested: false,
+                pull_again: false,
+                pulling: false,
+                strategy_hwm: high_water_mark,
+                strategy_size: size,
+                pull,
+                cancel,
+            })),
+        };
+        let object = create(controller.clone(), context)?;
+        stream.set_controller(Controller::Default(object.clone()));
+
+        let start_result = start.call(&[object.upcast().into()], context)?;
+        let start_promise = resolved(start_result, context)?;
+        upon(
+            &start_promise,
+            &controller,
+            |controller, _, context| {
+                controller.slots.borrow_mut().started = true;
+                controller.call_pull_if_needed(context)?;
+                Ok(JsValue::undefined())
+            },
+            |controller, error, context| {
+                controller.error(error, context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        )
+    }
+
+    fn stream(&self) -> JsReadableStream {
+        self.slots.borrow().stream.clone()
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-default-controller-call-pull-if-needed>
+    fn call_pull_if_needed(&self, context: &mut Context) -> JsResult<()> {
+        if !self.should_call_pull() {
+            return Ok(());
+        }
+        let pull = {
+            let mut slots = self.slots.borrow_mut();
+            if slots.pulling {
+                slots.pull_again = true;
+                return Ok(());
+            }
+            slots.pulling = true;
+            slots.pull.clone()
+        };
+        let object = self.stream().controller().object();
+        let pull_promise = pull.call_promise(&[object.into()], context)?;
+        upon(
+            &pull_promise,
+            self,
+            |controller, _, context| {
+                let pull_again = {
+                    let mut slots = controller.slots.borrow_mut();
+                    slots.pulling = false;
+                    std::mem::take(&mut slots.pull_again)
+                };
+                if pull_again {
+                    controller.call_pull_if_needed(context)?;
+                }
+                Ok(JsValue::undefined())
+            },
+            |controller, error, context| {
+                controller.error(error, context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        )
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-default-controller-should-call-pull>
+    pub(crate) fn should_call_pull(&self) -> bool {
+        if !self.can_close_or_enqueue() || !self.slots.borrow().started {
+            return false;
+        }
+        let stream = self.stream();
+        if stream.is_locked() && stream.num_read_requests() > 0 {
+            return true;
+        }
+        self.desired_size().is_some_and(|size| size > 0.0)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-default-controller-clear-algorithms>
+    fn clear_algorithms(&self) {
+        let mut slots = self.slots.borrow_mut();
+        slots.pull = Algorithm::none();
+        slots.cancel = Algorithm::none();
+        slots.strategy_size = SizeAlgorithm::default();
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-default-controller-close>
+    pub(crate) fn close(&self, context: &mut Context) -> JsResult<()> {
+        if !self.can_close_or_enqueue() {
+            return Ok(());
+        }
+        let queue_is_empty = {
+            let mut slots = self.slots.borrow_mut();
+            slots.close_requested = true;
+            slots.queue.is_empty()
+        };
+        if queue_is_empty {
+            self.clear_algorithms();
+            self.stream().close(context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-default-controller-enqueue>
+    pub(crate) fn enqueue(&self, chunk: JsValue, context: &mut Context) -> JsResult<()> {
+        if !self.can_close_or_enqueue() {
+            return Ok(());
+        }
+        let stream = self.stream();
+        if stream.is_locked() && stream.num_read_requests() > 0 {
+            stream.fulfill_read_request(chunk, false, context)?;
+        } else {
+            let size = self.slots.borrow().strategy_size.clone();
+            let result = size
+                .size(&chunk, context)
+                .and_then(|size| self.slots.borrow_mut().queue.enqueue(chunk, size));
+            if let Err(error) = result {
+                let error = error.into_opaque(context)?;
+                self.error(error.clone(), context)?;
+                return Err(JsError::from_opaque(error));
+            }
+        }
+        self.call_pull_if_needed(context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-default-controller-error>
+    pub(crate) fn error(&self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        let stream = self.stream();
+        if stream.state() != ReadableState::Readable {
+            return Ok(());
+        }
+        self.slots.borrow_mut().queue.reset();
+        self.clear_algorithms();
+        stream.error(error, context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-default-controller-get-desired-size>
+    pub(crate) fn desired_size(&self) -> Option<f64> {
+        let slots = self.slots.borrow();
+        match slots.stream.state() {
+            ReadableState::Errored => None,
+            ReadableState::Closed => Some(0.0),
+            ReadableState::Readable => Some(slots.strategy_hwm - slots.queue.total_size()),
+        }
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-default-controller-can-close-or-enqueue>
+    pub(crate) fn can_close_or_enqueue(&self) -> bool {
+        let slots = self.slots.borrow();
+        !slots.close_requested && slots.stream.state() == ReadableState::Readable
+    }
+
+    /// See <https://streams.spec.whatwg.org/#rs-default-controller-private-cancel>
+    pub(crate) fn cancel_steps(
+        &self,
+        reason: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let cancel = {
+            let mut slots = self.slots.borrow_mut();
+            slots.queue.reset();
+            slots.cancel.clone()
+        };
+        let result = cancel.call_promise(&[reason], context);
+        self.clear_algorithms();
+        result
+    }
+
+    /// See <https://streams.spec.whatwg.org/#rs-default-controller-private-pull>
+    pub(crate) fn pull_steps(&self, request: ReadRequest, context: &mut Context) -> JsResult<()> {
+        let stream = self.stream();
+        let chunk = self.slots.borrow_mut().queue.dequeue();
+        let Some(chunk) = chunk else {
+            stream.add_read_request(request);
+            return self.call_pull_if_needed(context);
+        };
+        let close = {
+            let slots = self.slots.borrow();
+            slots.close_requested && slots.queue.is_empty()
+        };
+        if close {
+            self.clear_algorithms();
+            stream.close(context)?;
+        } else {
+            self.call_pull_if_needed(context)?;
+        }
+        request.chunk_steps(chunk, context)
+    }
+}
+
+#[boa_class(rename = "ReadableStreamDefaultController")]
+#[boa(rename_all = "camelCase")]
+impl JsReadableStreamDefaultController {
+    #[boa(constructor)]
+    fn constructor() -> JsResult<Self> {
+        Err(illegal_constructor())
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "desiredSize")]
+    fn desired_size_(&self) -> Option<f64> {
+        Self::desired_size(self)
+    }
+
+    #[boa(method)]
+    #[boa(rename = "close")]
+    fn close_(this: JsClass<Self>, context: &mut Context) -> JsResult<()> {
+        let controller = this.clone_inner();
+        if !controller.can_close_or_enqueue() {
+            return Err(js_error!(TypeError: "the stream cannot be closed"));
+        }
+        Self::close(&controller, context)
+    }
+
+    #[boa(method)]
+    #[boa(rename = "enqueue")]
+    fn enqueue_(
+        this: JsClass<Self>,
+        chunk: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let controller = this.clone_inner();
+        if !controller.can_close_or_enqueue() {
+            return Err(js_error!(TypeError: "cannot enqueue a chunk into the stream"));
+        }
+        Self::enqueue(&controller, chunk.unwrap_or_default(), context)
+    }
+
+    #[boa(method)]
+    #[boa(rename = "error")]
+    fn error_(this: JsClass<Self>, error: Option<JsValue>, context: &mut Context) -> JsResult<()> {
+        Self::error(&this.clone_inner(), error.unwrap_or_default(), context)
+    }
+}
@@ -0,0 +1,1207 @@
+//! The `ReadableStream` JavaScript class, implemented as [`JsReadableStream`], and the
+//! abstract operations on readable streams.
+//!
+//! See <https://streams.spec.whatwg.org/#rs-model>
+
+use super::{
+    Algorithm, Deferred, QueuingStrategy, create, mark_handled, react, rejected, rejected_with,
+    resolved, type_error,
+};
+use crate::stream::writable::JsWritableStream;
+use boa_engine::builtins::iterable::create_iter_result_object;
+use boa_engine::gc::{Gc, GcRefCell};
+use boa_engine::interop::JsClass;
+use boa_engine::object::builtins::{JsArray, JsPromise, JsUint8Array};
+use boa_engine::object::{FunctionObjectBuilder, ObjectInitializer};
+use boa_engine::property::{Attribute, PropertyKey};
+use boa_engine::{
+    Context, Finalize, JsData, JsError, JsNativeError, JsObject, JsResult, JsSymbol, JsValue,
+    NativeFunction, Trace, boa_class, js_error, js_string,
+};
+use futures_lite::{Stream, StreamExt};
+use std::cell::{Cell, RefCell};
+use std::pin::Pin;
+use std::rc::Rc;
+
+mod byte_controller;
+mod controller;
+mod pipe;
+mod reader;
+mod tee;
+
+pub use byte_controller::{JsReadableByteStreamController, JsReadableStreamBYOBRequest};
+pub use controller::JsReadableStreamDefaultController;
+pub use reader::{JsReadableStreamBYOBReader, JsReadableStreamDefaultReader};
+
+pub(crate) use reader::{GenericReader, ReadRequest};
+pub(crate) use tee::tee;
+
+/// The state of a readable stream.
+///
+/// See <https://streams.spec.whatwg.org/#readablestream-state>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+pub(crate) enum ReadableState {
+    Readable,
+    Closed,
+    Errored,
+}
+
+/// The controller of a readable stream, which is passed to its underlying source.
+#[derive(Debug, Clone, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub(crate) enum Controller {
+    Default(JsObject<JsReadableStreamDefaultController>),
+    Byte(JsObject<JsReadableByteStreamController>),
+}
+
+impl Controller {
+    /// Returns the JavaScript object of the controller.
+    pub(crate) fn object(&self) -> JsObject {
+        match self {
+            Self::Default(object) => object.clone().upcast(),
+            Self::Byte(object) => object.clone().upcast(),
+        }
+    }
+
+    /// See <https://streams.spec.whatwg.org/#abstract-opdef-readablestreamcontroller-cancelsteps>
+    fn cancel_steps(&self, reason: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        match self {
+            Self::Default(object) => {
+                JsReadableStreamDefaultController::of(object).cancel_steps(reason, context)
+            }
+            Self::Byte(object) => {
+                JsReadableByteStreamController::of(object).cancel_steps(reason, context)
+            }
+        }
+    }
+
+    /// See <https://streams.spec.whatwg.org/#abstract-opdef-readablestreamcontroller-pullsteps>
+    fn pull_steps(&self, request: ReadRequest, context: &mut Context) -> JsResult<()> {
+        match self {
+            Self::Default(object) => {
+                JsReadableStreamDefaultController::of(object).pull_steps(request, context)
+            }
+            Self::Byte(object) => {
+                JsReadableByteStreamController::of(object).pull_steps(request, context)
+            }
+        }
+    }
+
+    /// See <https://streams.spec.whatwg.org/#abstract-opdef-readablestreamcontroller-releasesteps>
+    fn release_steps(&self) {
+        if let Self::Byte(object) = self {
+            JsReadableByteStreamController::of(object).release_steps();
+        }
+    }
+
+    /// Enqueues `chunk` without the checks done by the `enqueue()` methods.
+    pub(crate) fn enqueue(&self, chunk: JsValue, context: &mut Context) -> JsResult<()> {
+        match self {
+            Self::Default(object) => {
+                JsReadableStreamDefaultController::of(object).enqueue(chunk, context)
+            }
+            Self::Byte(object) => {
+                let Some(view) = chunk.as_object() else {
+                    return Err(js_error!(TypeError: "chunk must be an ArrayBufferView"));
+                };
+                JsReadableByteStreamController::of(object).enqueue(&view, context)
+            }
+        }
+    }
+
+    /// Closes the stream without the checks done by the `close()` methods.
+    pub(crate) fn close(&self, context: &mut Context) -> JsResult<()> {
+        match self {
+            Self::Default(object) => JsReadableStreamDefaultController::of(object).close(context),
+            Self::Byte(object) => {
+                let controller = JsReadableByteStreamController::of(object);
+                controller.close(context)?;
+                // Settle a pending BYOB read, as the underlying source won't respond to it.
+                if controller.has_pending_pull_intos() {
+                    controller.respond(0, context)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Errors the stream with `error`.
+    pub(crate) fn error(&self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        match self {
+            Self::Default(object) => {
+                JsReadableStreamDefaultController::of(object).error(error, context)
+            }
+            Self::Byte(object) => JsReadableByteStreamController::of(object).error(error, context),
+        }
+    }
+}
+
+/// The reader that locks a readable stream.
+#[derive(Debug, Clone, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub(crate) enum Reader {
+    Default(GenericReader),
+    Byob(GenericReader),
+}
+
+impl Reader {
+    pub(crate) fn generic(&self) -> &GenericReader {
+        match self {
+            Self::Default(reader) | Self::Byob(reader) => reader,
+        }
+    }
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct ReadableStreamSlots {
+    state: ReadableState,
+    stored_error: JsValue,
+    disturbed: bool,
+    reader: Option<Reader>,
+    controller: Option<Controller>,
+}
+
+/// The JavaScript `ReadableStream` class.
+///
+/// See <https://streams.spec.whatwg.org/#rs-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsReadableStream {
+    slots: Gc<GcRefCell<ReadableStreamSlots>>,
+}
+
+/// The type of the underlying source of a readable stream.
+///
+/// See <https://streams.spec.whatwg.org/#enumdef-readablestreamtype>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReadableStreamType {
+    Default,
+    Bytes,
+}
+
+impl ReadableStreamType {
+    fn from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if value.is_undefined() {
+            return Ok(Self::Default);
+        }
+        if value.to_string(context)? == js_string!("bytes") {
+            Ok(Self::Bytes)
+        } else {
+            Err(js_error!(TypeError: "the type of an underlying source must be \"bytes\""))
+        }
+    }
+}
+
+impl JsReadableStream {
+    /// Creates a readable stream with no controller.
+    ///
+    /// See <https://streams.spec.whatwg.org/#initialize-readable-stream>
+    fn initialize() -> Self {
+        Self {
+            slots: Gc::new(GcRefCell::new(ReadableStreamSlots {
+                state: ReadableState::Readable,
+                stored_error: JsValue::undefined(),
+                disturbed: false,
+                reader: None,
+                controller: None,
+            })),
+        }
+    }
+
+    /// Returns the stream of a `ReadableStream` object.
+    pub(crate) fn of(object: &JsObject) -> Option<Self> {
+        object.downcast_ref::<Self>().map(|stream| stream.clone())
+    }
+
+    /// Creates a readable stream with a default controller, whose underlying source is
+    /// made of `start`, `pull` and `cancel`.
+    ///
+    /// See <https://streams.spec.whatwg.org/#create-readable-stream>
+    pub(crate) fn create(
+        start: &Algorithm,
+        pull: Algorithm,
+        cancel: Algorithm,
+        high_water_mark: f64,
+        size: super::SizeAlgorithm,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let stream = Self::initialize();
+        JsReadableStreamDefaultController::set_up(
+            &stream,
+            start,
+            pull,
+            cancel,
+            high_water_mark,
+            size,
+            context,
+        )?;
+        Ok(stream)
+    }
+
+    /// Creates a readable byte stream whose underlying source is made of `start`, `pull`
+    /// and `cancel`.
+    ///
+    /// See <https://streams.spec.whatwg.org/#abstract-opdef-createreadablebytestream>
+    pub(crate) fn create_byte_stream(
+        start: &Algorithm,
+        pull: Algorithm,
+        cancel: Algorithm,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let stream = Self::initialize();
+        JsReadableByteStreamController::set_up(&stream, start, pull, cancel, 0.0, None, context)?;
+        Ok(stream)
+    }
+
+    /// Creates a readable byte stream which contains `bytes` and is closed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chunk cannot be enqueued.
+    pub fn from_bytes(bytes: &[u8], context: &mut Context) -> JsResult<Self> {
+        let stream = Self::create_byte_stream(
+            &Algorithm::none(),
+            Algorithm::none(),
+            Algorithm::none(),
+            context,
+        )?;
+        let controller = stream.controller();
+        if !bytes.is_empty() {
+            let chunk = JsUint8Array::from_iter(bytes.iter().copied(), context)?;
+            controller.enqueue(chunk.into(), context)?;
+        }
+        controller.close(context)?;
+        Ok(stream)
+    }
+
+    /// Creates a readable byte stream whose chunks are pulled from `chunks` when the stream
+    /// is read, so they don't need to be buffered in memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stream cannot be set up.
+    pub fn from_byte_chunks(chunks: ByteChunks, context: &mut Context) -> JsResult<Self> {
+        let source = ByteChunkSource {
+            chunks: Rc::new(RefCell::new(Some(chunks))),
+            canceled: Rc::new(Cell::new(false)),
+        };
+        let pull = Algorithm::native(&source, ByteChunkSource::pull, context);
+        let cancel = Algorithm::native(&source, ByteChunkSource::cancel, context);
+        Self::create_byte_stream(&Algorithm::none(), pull, cancel, context)
+    }
+
+    pub(crate) fn state(&self) -> ReadableState {
+        self.slots.borrow().state
+    }
+
+    pub(crate) fn stored_error(&self) -> JsValue {
+        self.slots.borrow().stored_error.clone()
+    }
+
+    pub(crate) fn controller(&self) -> Controller {
+        self.slots
+            .borrow()
+            .controller
+            .clone()
+            .expect("a readable stream must have a controller once it is set up")
+    }
+
+    pub(crate) fn set_controller(&self, controller: Controller) {
+        self.slots.borrow_mut().controller = Some(controller);
+    }
+
+    pub(crate) fn reader(&self) -> Option<Reader> {
+        self.slots.borrow().reader.clone()
+    }
+
+    pub(crate) fn set_reader(&self, reader: Option<Reader>) {
+        self.slots.borrow_mut().reader = reader;
+    }
+
+    pub(crate) fn set_disturbed(&self) {
+        self.slots.borrow_mut().disturbed = true;
+    }
+
+    /// Returns `true` if the stream has been read from or canceled.
+    ///
+    /// See <https://streams.spec.whatwg.org/#is-readable-stream-disturbed>
+    #[must_use]
+    pub fn is_disturbed(&self) -> bool {
+        self.slots.borrow().disturbed
+    }
+
+    /// Returns `true` if the stream is locked to a reader.
+    ///
+    /// See <https://streams.spec.whatwg.org/#is-readable-stream-locked>
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        self.slots.borrow().reader.is_some()
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-add-read-request>
+    pub(crate) fn add_read_request(&self, request: ReadRequest) {
+        if let Some(reader) = self.reader() {
+            reader.generic().push_request(request);
+        }
+    }
+
+    /// Returns the number of pending read requests of the stream's reader.
+    ///
+    /// See <https://streams.spec.whatwg.org/#readable-stream-get-num-read-requests>
+    pub(crate) fn num_read_requests(&self) -> usize {
+        self.reader()
+            .map_or(0, |reader| reader.generic().num_requests())
+    }
+
+    pub(crate) fn has_default_reader(&self) -> bool {
+        matches!(self.reader(), Some(Reader::Default(_)))
+    }
+
+    pub(crate) fn has_byob_reader(&self) -> bool {
+        matches!(self.reader(), Some(Reader::Byob(_)))
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-cancel>
+    pub(crate) fn cancel(&self, reason: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        self.set_disturbed();
+        match self.state() {
+            ReadableState::Closed => return resolved(JsValue::undefined(), context),
+            ReadableState::Errored => return rejected(self.stored_error(), context),
+            ReadableState::Readable => {}
+        }
+        self.close(context)?;
+        if let Some(Reader::Byob(reader)) = self.reader() {
+            for request in reader.take_requests() {
+                request.close_steps(JsValue::undefined(), context)?;
+            }
+        }
+
+        let source_cancel = self.controller().cancel_steps(reason, context)?;
+        react(
+            &source_cancel,
+            &(),
+            Some(|(), _, _| Ok(JsValue::undefined())),
+            None,
+            context,
+        )
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-close>
+    pub(crate) fn close(&self, context: &mut Context) -> JsResult<()> {
+        self.slots.borrow_mut().state = ReadableState::Closed;
+        let Some(reader) = self.reader() else {
+            return Ok(());
+        };
+        reader
+            .generic()
+            .closed()
+            .resolve(JsValue::undefined(), context)?;
+        if let Reader::Default(reader) = reader {
+            for request in reader.take_requests() {
+                request.close_steps(JsValue::undefined(), context)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-error>
+    pub(crate) fn error(&self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        {
+            let mut slots = self.slots.borrow_mut();
+            slots.state = ReadableState::Errored;
+            slots.stored_error = error.clone();
+        }
+        let Some(reader) = self.reader() else {
+            return Ok(());
+        };
+        let closed = reader.generic().closed();
+        closed.reject(error.clone(), context)?;
+        mark_handled(&closed.promise(), context)?;
+        for request in reader.generic().take_requests() {
+            request.error_steps(error.clone(), context)?;
+        }
+        Ok(())
+    }
+
+    /// Fulfills the first pending read request of the stream's reader.
+    ///
+    /// See <https://streams.spec.whatwg.org/#readable-stream-fulfill-read-request> and
+    /// <https://streams.spec.whatwg.org/#readable-stream-fulfill-read-into-request>
+    pub(crate) fn fulfill_read_request(
+        &self,
+        chunk: JsValue,
+        done: bool,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let Some(request) = self
+            .reader()
+            .and_then(|reader| reader.generic().shift_request())
+        else {
+            return Ok(());
+        };
+        if done {
+            request.close_steps(chunk, context)
+        } else {
+            request.chunk_steps(chunk, context)
+        }
+    }
+
+    /// Sets up a stream from the `underlyingSource` and `strategy` given to the constructor.
+    fn set_up_from_underlying_source(
+        &self,
+        underlying_source: Option<&JsObject>,
+        strategy: &QueuingStrategy,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // Dictionary members are read in lexicographical order.
+        let (auto_allocate_chunk_size, cancel, pull, start, r#type) = match underlying_source {
+            Some(source) => {
+                let auto_allocate_chunk_size =
+                    source.get(js_string!("autoAllocateChunkSize"), context)?;
+                let auto_allocate_chunk_size = if auto_allocate_chunk_size.is_undefined() {
+                    None
+                } else {
+                    Some(enforce_range_u64(&auto_allocate_chunk_size, context)?)
+                };
+                let cancel = Algorithm::method(source, "cancel", context)?;
+                let pull = Algorithm::method(source, "pull", context)?;
+                let start = Algorithm::method(source, "start", context)?;
+                let r#type = source.get(js_string!("type"), context)?;
+                let r#type = ReadableStreamType::from_js(&r#type, context)?;
+                (auto_allocate_chunk_size, cancel, pull, start, r#type)
+            }
+            None => (
+                None,
+                Algorithm::none(),
+                Algorithm::none(),
+                Algorithm::none(),
+                ReadableStreamType::Default,
+            ),
+        };
+
+        match r#type {
+            ReadableStreamType::Bytes => {
+                if strategy.has_size() {
+                    return Err(
+                        js_error!(RangeError: "the strategy of a byte stream cannot have a size function"),
+                    );
+                }
+                let high_water_mark = strategy.high_water_mark(0.0)?;
+                let auto_allocate_chunk_size = match auto_allocate_chunk_size {
+                    Some(0) => {
+                        return Err(
+                            js_error!(TypeError: "autoAllocateChunkSize must be greater than 0"),
+                        );
+                    }
+                    Some(size) => Some(usize::try_from(size).map_err(
+                        |_| js_error!(RangeError: "autoAllocateChunkSize is too large"),
+                    )?),
+                    None => None,
+                };
+                JsReadableByteStreamController::set_up(
+                    self,
+                    &start,
+                    pull,
+                    cancel,
+                    high_water_mark,
+                    auto_allocate_chunk_size,
+                    context,
+                )
+            }
+            ReadableStreamType::Default => {
+                let size = strategy.size_algorithm();
+                let high_water_mark = strategy.high_water_mark(1.0)?;
+                JsReadableStreamDefaultController::set_up(
+                    self,
+                    &start,
+                    pull,
+                    cancel,
+                    high_water_mark,
+                    size,
+                    context,
+                )
+            }
+        }
+    }
+}
+
+/// Converts `value` to an `unsigned long long` with the `[EnforceRange]` extended attribute.
+///
+/// See <https://webidl.spec.whatwg.org/#abstract-opdef-converttoint>
+pub(crate) fn enforce_range_u64(value: &JsValue, context: &mut Context) -> JsResult<u64> {
+    let number = value.to_number(context)?;
+    if !number.is_finite() {
+        return Err(js_error!(TypeError: "{} is not a finite number", number));
+    }
+    let number = number.trunc();
+    if !(0.0..=9_007_199_254_740_991.0).contains(&number) {
+        return Err(js_error!(TypeError: "{} is out of range", number));
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Ok(number as u64)
+}
+
+/// The `StreamPipeOptions` dictionary.
+///
+/// See <https://streams.spec.whatwg.org/#dictdef-streampipeoptions>
+#[derive(Debug, Default, Clone, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub(crate) struct PipeOptions {
+    pub(crate) prevent_abort: bool,
+    pub(crate) prevent_cancel: bool,
+    pub(crate) prevent_close: bool,
+    pub(crate) signal: Option<JsObject>,
+}
+
+impl PipeOptions {
+    fn from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        if value.is_null_or_undefined() {
+            return Ok(Self::default());
+        }
+        let Some(options) = value.as_object() else {
+            return Err(js_error!(TypeError: "pipe options must be an object"));
+        };
+        let prevent_abort = options
+            .get(js_string!("preventAbort"), context)?
+            .to_boolean();
+        let prevent_cancel = options
+            .get(js_string!("preventCancel"), context)?
+            .to_boolean();
+        let prevent_close = options
+            .get(js_string!("preventClose"), context)?
+            .to_boolean();
+        let signal = options.get(js_string!("signal"), context)?;
+        let signal = if signal.is_undefined() {
+            None
+        } else {
+            match signal.as_object() {
+                Some(signal) if signal.is::<crate::abort::JsAbortSignal>() => Some(signal),
+                _ => return Err(js_error!(TypeError: "signal must be an AbortSignal")),
+            }
+        };
+        Ok(Self {
+            prevent_abort,
+            prevent_cancel,
+            prevent_close,
+            signal,
+        })
+    }
+}
+
+/// Returns the promise of `result`, or a promise rejected with its error.
+pub(crate) fn promise_or_rejected(
+    result: JsResult<JsPromise>,
+    context: &mut Context,
+) -> JsResult<JsPromise> {
+    result.or_else(|error| rejected_with(error, context))
+}
+
+#[boa_class(rename = "ReadableStream")]
+#[boa(rename_all = "camelCase")]
+impl JsReadableStream {
+    #[boa(constructor)]
+    fn constructor(
+        underlying_source: Option<JsObject>,
+        strategy: Option<QueuingStrategy>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let stream = Self::initialize();
+        stream.set_up_from_underlying_source(
+            underlying_source.as_ref(),
+            &strategy.unwrap_or_default(),
+            context,
+        )?;
+        Ok(stream)
+    }
+
+    /// Creates a stream from an iterable or async iterable of chunks.
+    ///
+    /// See <https://streams.spec.whatwg.org/#rs-from>
+    #[boa(static)]
+    fn from(async_iterable: JsValue, context: &mut Context) -> JsResult<Self> {
+        from_iterable(&async_iterable, context)
+    }
+
+    #[boa(getter)]
+    fn locked(&self) -> bool {
+        self.is_locked()
+    }
+
+    #[boa(method)]
+    #[boa(rename = "cancel")]
+    fn cancel_(
+        this: JsClass<Self>,
+        reason: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let stream = this.clone_inner();
+        if stream.is_locked() {
+            return rejected(
+                type_error("cannot cancel a stream that is locked to a reader", context),
+                context,
+            );
+        }
+        let result = stream.cancel(reason.unwrap_or_default(), context);
+        promise_or_rejected(result, context)
+    }
+
+    #[boa(method)]
+    fn get_reader(
+        this: JsClass<Self>,
+        options: Option<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        let stream = this.clone_inner();
+        let mode = match options {
+            Some(options) => options.get(js_string!("mode"), context)?,
+            None => JsValue::undefined(),
+        };
+        if mode.is_undefined() {
+            let reader = JsReadableStreamDefaultReader::acquire(&stream, context)?;
+            return Ok(create(reader, context)?.upcast());
+        }
+        if mode.to_string(context)? != js_string!("byob") {
+            return Err(js_error!(TypeError: "the reader mode must be \"byob\""));
+        }
+        let reader = JsReadableStreamBYOBReader::acquire(&stream, context)?;
+        Ok(create(reader, context)?.upcast())
+    }
+
+    #[boa(method)]
+    fn pipe_through(
+        this: JsClass<Self>,
+        transform: JsObject,
+        options: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let stream = this.clone_inner();
+        // `ReadableWritablePair` members are read in lexicographical order.
+        let readable = transform.get(js_string!("readable"), context)?;
+        let readable_stream = readable
+            .as_object()
+            .as_ref()
+            .and_then(Self::of)
+            .ok_or_else(|| js_error!(TypeError: "readable must be a ReadableStream"))?;
+        let writable = transform
+            .get(js_string!("writable"), context)?
+            .as_object()
+            .as_ref()
+            .and_then(JsWritableStream::of)
+            .ok_or_else(|| js_error!(TypeError: "writable must be a WritableStream"))?;
+        drop(readable_stream);
+        let options = PipeOptions::from_js(&options.unwrap_or_default(), context)?;
+
+        if stream.is_locked() {
+            return Err(js_error!(TypeError: "cannot pipe a locked stream"));
+        }
+        if writable.is_locked() {
+            return Err(js_error!(TypeError: "cannot pipe to a locked stream"));
+        }
+        let promise = pipe::pipe_to(&stream, &writable, options, context)?;
+        mark_handled(&promise, context)?;
+        Ok(readable)
+    }
+
+    #[boa(method)]
+    fn pipe_to(
+        this: JsClass<Self>,
+        destination: JsValue,
+        options: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let stream = this.clone_inner();
+        let result = (|| {
+            let destination = destination
+                .as_object()
+                .as_ref()
+                .and_then(JsWritableStream::of)
+                .ok_or_else(|| js_error!(TypeError: "destination must be a WritableStream"))?;
+            let options = PipeOptions::from_js(&options.unwrap_or_default(), context)?;
+            if stream.is_locked() {
+                return Err(js_error!(TypeError: "cannot pipe a locked stream"));
+            }
+            if destination.is_locked() {
+                return Err(js_error!(TypeError: "cannot pipe to a locked stream"));
+            }
+            pipe::pipe_to(&stream, &destination, options, context)
+        })();
+        promise_or_rejected(result, context)
+    }
+
+    #[boa(method)]
+    fn tee(this: JsClass<Self>, context: &mut Context) -> JsResult<JsArray> {
+        let stream = this.clone_inner();
+        let (branch1, branch2) = tee(&stream, false, context)?;
+        let branch1 = create(branch1, context)?;
+        let branch2 = create(branch2, context)?;
+        Ok(JsArray::from_iter(
+            [branch1.upcast().into(), branch2.upcast().into()],
+            context,
+        ))
+    }
+
+    #[boa(method)]
+    fn values(
+        this: JsClass<Self>,
+        options: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        async_iterator(&this.clone_inner(), options.unwrap_or_default(), context)
+    }
+
+    #[boa(method)]
+    #[boa(symbol = "async_iterator")]
+    fn async_iterator(
+        this: JsClass<Self>,
+        options: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        async_iterator(&this.clone_inner(), options.unwrap_or_default(), context)
+    }
+}
+
+/// Gets the method `key` of `object`, treating `undefined` and `null` as absent.
+///
+/// See <https://tc39.es/ecma262/#sec-getmethod>
+fn get_method<K: Into<PropertyKey>>(
+    object: &JsObject,
+    key: K,
+    context: &mut Context,
+) -> JsResult<Option<JsObject>> {
+    let method = object.get(key, context)?;
+    if method.is_null_or_undefined() {
+        return Ok(None);
+    }
+    match method.as_callable() {
+        Some(method) => Ok(Some(method.clone())),
+        None => Err(js_error!(TypeError: "value is not a function")),
+    }
+}
+
+/// See <https://streams.spec.whatwg.org/#readable-stream-from-iterable>
+fn from_iterable(async_iterable: &JsValue, context: &mut Context) -> JsResult<JsReadableStream> {
+    let object = async_iterable.to_object(context)?;
+    let (iterator, sync) =
+        if let Some(method) = get_method(&object, JsSymbol::async_iterator(), context)? {
+            (method.call(async_iterable, &[], context)?, false)
+        } else {
+            let Some(method) = get_method(&object, JsSymbol::iterator(), context)? else {
+                return Err(js_error!(TypeError: "value is not async iterable"));
+            };
+            (method.call(async_iterable, &[], context)?, true)
+        };
+    let Some(iterator) = iterator.as_object() else {
+        return Err(js_error!(TypeError: "the iterator must be an object"));
+    };
+    let next = iterator.get(js_string!("next"), context)?;
+
+    let source = IterableSource {
+        iterator,
+        next,
+        sync,
+    };
+    let pull = Algorithm::native(&source, IterableSource::pull, context);
+    let cancel = Algorithm::native(&source, IterableSource::cancel, context);
+    JsReadableStream::create(
+        &Algorithm::none(),
+        pull,
+        cancel,
+        0.0,
+        super::SizeAlgorithm::default(),
+        context,
+    )
+}
+
+/// A stream of byte chunks produced by native code, e.g. the body of an HTTP response.
+pub type ByteChunks = Pin<Box<dyn Stream<Item = JsResult<Vec<u8>>>>>;
+
+/// The underlying source of a stream created by [`JsReadableStream::from_byte_chunks`].
+#[derive(Clone, Trace, Finalize)]
+struct ByteChunkSource {
+    /// The remaining chunks, which are taken out while a chunk is being read.
+    #[unsafe_ignore_trace]
+    chunks: Rc<RefCell<Option<ByteChunks>>>,
+    #[unsafe_ignore_trace]
+    canceled: Rc<Cell<bool>>,
+}
+
+impl ByteChunkSource {
+    fn pull(&self, controller: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        let controller = controller
+            .as_object()
+            .and_then(|controller| controller.downcast::<JsReadableByteStreamController>().ok())
+            .map(Controller::Byte)
+            .ok_or_else(
+                || js_error!(TypeError: "the controller must be a byte stream controller"),
+            )?;
+        let chunks = self.chunks.clone();
+        let canceled = self.canceled.clone();
+        let promise = JsPromise::from_async_fn(
+            async move |context| {
+                let Some(mut stream) = chunks.borrow_mut().take() else {
+                    return Ok(JsValue::undefined());
+                };
+                // Byte streams cannot enqueue empty chunks, so these are skipped.
+                let next = loop {
+                    match stream.next().await {
+                        Some(Ok(chunk)) if chunk.is_empty() => {}
+                        next => break next,
+                    }
+                };
+                if canceled.get() {
+                    return Ok(JsValue::undefined());
+                }
+                let context = &mut context.borrow_mut();
+                match next {
+                    Some(Ok(chunk)) => {
+                        *chunks.borrow_mut() = Some(stream);
+                        let chunk = JsUint8Array::from_iter(chunk, context)?;
+                        controller.enqueue(chunk.into(), context)?;
+                    }
+                    Some(Err(error)) => controller.error(error.into_opaque(context)?, context)?,
+                    None => controller.close(context)?,
+                }
+                Ok(JsValue::undefined())
+            },
+            context,
+        );
+        Ok(promise.into())
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    fn cancel(&self, _: JsValue, _: &mut Context) -> JsResult<JsValue> {
+        self.canceled.set(true);
+        self.chunks.borrow_mut().take();
+        Ok(JsValue::undefined())
+    }
+}
+
+/// The underlying source of a stream created by `ReadableStream.from()`.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct IterableSource {
+    iterator: JsObject,
+    next: JsValue,
+    /// Whether the iterator is synchronous, in which case its values are awaited.
+    sync: bool,
+}
+
+impl IterableSource {
+    fn pull(&self, controller: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        let result = (|| {
+            let Some(next) = self.next.as_callable() else {
+                return Err(js_error!(TypeError: "the iterator's next method is not callable"));
+            };
+            let next_result = next.call(&self.iterator.clone().into(), &[], context)?;
+            if !self.sync {
+                return resolved(next_result, context);
+            }
+            let Some(next_result) = next_result.as_object() else {
+                return Err(js_error!(TypeError: "the iterator result must be an object"));
+            };
+            let done = next_result.get(js_string!("done"), context)?.to_boolean();
+            let value = next_result.get(js_string!("value"), context)?;
+            if done {
+                return resolved(create_iter_result_object(value, true, context), context);
+            }
+            react(
+                &resolved(value, context)?,
+                &(),
+                Some(|(), value, context| Ok(create_iter_result_object(value, false, context))),
+                None,
+                context,
+            )
+        })();
+        let next_promise = promise_or_rejected(result, context)?;
+
+        let captures = (self.clone(), controller);
+        react(
+            &next_promise,
+            &captures,
+            Some(|(_, controller), result, context| {
+                let Some(result) = result.as_object() else {
+                    return Err(js_error!(TypeError: "the iterator result must be an object"));
+                };
+                let controller = controller
+                    .as_object()
+                    .and_then(|controller| {
+                        controller
+                            .downcast::<JsReadableStreamDefaultController>()
+                            .ok()
+                    })
+                    .ok_or_else(|| js_error!(TypeError: "invalid controller"))?;
+                let controller = JsReadableStreamDefaultController::of(&controller);
+                if result.get(js_string!("done"), context)?.to_boolean() {
+                    controller.close(context)?;
+                } else {
+                    let value = result.get(js_string!("value"), context)?;
+                    controller.enqueue(value, context)?;
+                }
+                Ok(JsValue::undefined())
+            }),
+            None,
+            context,
+        )
+        .map(Into::into)
+    }
+
+    fn cancel(&self, reason: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        let result = (|| {
+            let Some(r#return) = get_method(&self.iterator, js_string!("return"), context)? else {
+                return resolved(JsValue::undefined(), context);
+            };
+            let return_result = r#return.call(&self.iterator.clone().into(), &[reason], context)?;
+            react(
+                &resolved(return_result, context)?,
+                &(),
+                Some(|(), result, _| {
+                    if result.is_object() {
+                        Ok(JsValue::undefined())
+                    } else {
+                        Err(js_error!(TypeError: "the iterator result must be an object"))
+                    }
+                }),
+                None,
+                context,
+            )
+        })();
+        promise_or_rejected(result, context).map(Into::into)
+    }
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct AsyncIteratorSlots {
+    reader: GenericReader,
+    prevent_cancel: bool,
+    ongoing: Option<JsPromise>,
+    finished: bool,
+}
+
+/// The state of an async iterator over a readable stream.
+///
+/// See <https://streams.spec.whatwg.org/#rs-asynciterator>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+struct AsyncIterator {
+    slots: Gc<GcRefCell<AsyncIteratorSlots>>,
+}
+
+/// The `%ReadableStreamAsyncIteratorPrototype%` object of a realm.
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+struct AsyncIteratorPrototype(JsObject);
+
+/// Returns the `%ReadableStreamAsyncIteratorPrototype%` object of the current realm.
+fn async_iterator_prototype(context: &mut Context) -> JsObject {
+    let realm = context.realm().clone();
+    if let Some(prototype) = realm.host_defined().get::<AsyncIteratorPrototype>() {
+        return prototype.0.clone();
+    }
+
+    let next = FunctionObjectBuilder::new(
+        &realm,
+        NativeFunction::from_fn_ptr(|this, _, context| {
+            let iterator = AsyncIterator::this(this)?;
+            iterator.next(context).map(Into::into)
+        }),
+    )
+    .name(js_string!("next"))
+    .length(0)
+    .build();
+    let r#return = FunctionObjectBuilder::new(
+        &realm,
+        NativeFunction::from_fn_ptr(|this, args, context| {
+            let iterator = AsyncIterator::this(this)?;
+            let value = args.first().cloned().unwrap_or_default();
+            iterator.r#return(value, context).map(Into::into)
+        }),
+    )
+    .name(js_string!("return"))
+    .length(1)
+    .build();
+
+    let parent = realm
+        .intrinsics()
+        .objects()
+        .iterator_prototypes()
+        .async_iterator();
+    let prototype = ObjectInitializer::new(context)
+        .property(js_string!("next"), next, Attribute::all())
+        .property(js_string!("return"), r#return, Attribute::all())
+        .build();
+    prototype.set_prototype(Some(parent));
+    realm
+        .host_defined_mut()
+        .insert(AsyncIteratorPrototype(prototype.clone()));
+    prototype
+}
+
+/// Creates an async iterator over the chunks of `stream`.
+///
+/// See <https://streams.spec.whatwg.org/#rs-get-iterator>
+fn async_iterator(
+    stream: &JsReadableStream,
+    options: JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    let prevent_cancel = match options.as_object() {
+        Some(options) => options
+            .get(js_string!("preventCancel"), context)?
+            .to_boolean(),
+        None if options.is_null_or_undefined() => false,
+        None => return Err(js_error!(TypeError: "iterator options must be an object")),
+    };
+    let reader = JsReadableStreamDefaultReader::acquire(stream, context)?;
+    let iterator = AsyncIterator {
+        slots: Gc::new(GcRefCell::new(AsyncIteratorSlots {
+            reader: reader.generic().clone(),
+            prevent_cancel,
+            ongoing: None,
+            finished: false,
+        })),
+    };
+    let prototype = async_iterator_prototype(context);
+    Ok(JsObject::from_proto_and_data(prototype, iterator))
+}
+
+impl AsyncIterator {
+    fn this(this: &JsValue) -> JsResult<Self> {
+        this.as_object()
+            .as_ref()
+            .and_then(|object| {
+                object
+                    .downcast_ref::<Self>()
+                    .map(|iterator| iterator.clone())
+            })
+            .ok_or_else(|| js_error!(TypeError: "not a ReadableStream async iterator"))
+    }
+
+    /// Runs `steps` after the ongoing `next()` or `return()` call settles.
+    fn chain(&self, steps: super::Steps<Self>, context: &mut Context) -> JsResult<JsPromise> {
+        let ongoing = self.slots.borrow().ongoing.clone();
+        let promise = match ongoing {
+            Some(ongoing) => react(&ongoing, self, Some(steps), Some(steps), context)?,
+            None => JsPromise::from_object(
+                steps(self, JsValue::undefined(), context)?
+                    .as_object()
+                    .ok_or_else(|| js_error!(TypeError: "expected a promise"))?,
+            )?,
+        };
+        self.slots.borrow_mut().ongoing = Some(promise.clone());
+        Ok(promise)
+    }
+
+    fn next(&self, context: &mut Context) -> JsResult<JsPromise> {
+        self.chain(
+            |iterator, _, context| iterator.next_steps(context).map(Into::into),
+            context,
+        )
+    }
+
+    /// See <https://streams.spec.whatwg.org/#ref-for-dfn-get-the-next-iteration-result>
+    fn next_steps(&self, context: &mut Context) -> JsResult<JsPromise> {
+        if self.slots.borrow().finished {
+            return resolved(
+                create_iter_result_object(JsValue::undefined(), true, context),
+                context,
+            );
+        }
+        let reader = self.slots.borrow().reader.clone();
+        if reader.stream().is_none() {
+            return rejected(
+                type_error("the iterator's reader has been released", context),
+                context,
+            );
+        }
+
+        let result = Deferred::new(context);
+        let captures = (self.clone(), result.clone());
+        let request = ReadRequest::steps(
+            &captures,
+            |(_, result), chunk, context| {
+                result.resolve(create_iter_result_object(chunk, false, context), context)?;
+                Ok(JsValue::undefined())
+            },
+            |(iterator, result), _, context| {
+                iterator.finish(context)?;
+                result.resolve(
+                    create_iter_result_object(JsValue::undefined(), true, context),
+                    context,
+                )?;
+                Ok(JsValue::undefined())
+            },
+            |(iterator, result), error, context| {
+                iterator.finish(context)?;
+                result.reject(error, context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        );
+        reader.default_read(request, context)?;
+        Ok(result.promise())
+    }
+
+    /// Releases the reader once the iterator is done.
+    fn finish(&self, context: &mut Context) -> JsResult<()> {
+        let reader = {
+            let mut slots = self.slots.borrow_mut();
+            slots.finished = true;
+            slots.reader.clone()
+        };
+        if reader.stream().is_some() {
+            reader.release(context)?;
+        }
+        Ok(())
+    }
+
+    fn r#return(&self, value: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        let captures = (self.clone(), value);
+        let ongoing = self.slots.borrow().ongoing.clone();
+        let steps: super::Steps<(Self, JsValue)> = |(iterator, value), _, context| {
+            iterator
+                .return_steps(value.clone(), context)
+                .map(Into::into)
+        };
+        let promise = match ongoing {
+            Some(ongoing) => react(&ongoing, &captures, Some(steps), Some(steps), context)?,
+            None => self.return_steps(captures.1.clone(), context)?,
+        };
+        self.slots.borrow_mut().ongoing = Some(promise.clone());
+        Ok(promise)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#ref-for-asynchronous-iterator-return>
+    fn return_steps(&self, value: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        let (reader, prevent_cancel, finished) = {
+            let slots = self.slots.borrow();
+            (slots.reader.clone(), slots.prevent_cancel, slots.finished)
+        };
+        let done = create_iter_result_object(value.clone(), true, context);
+        if finished {
+            return resolved(done, context);
+        }
+        self.slots.borrow_mut().finished = true;
+
+        let result = if reader.stream().is_none() {
+            resolved(JsValue::undefined(), context)?
+        } else if prevent_cancel {
+            reader.release(context)?;
+            resolved(JsValue::undefined(), context)?
+        } else {
+            let result = reader.cancel(value, context)?;
+            reader.release(context)?;
+            result
+        };
+        react(
+            &result,
+            &done,
+            Some(|done, _, _| Ok(done.clone())),
+            None,
+            context,
+        )
+    }
+}
+
+/// Returns the `TypeError` thrown by the constructors of classes that cannot be constructed.
+pub(crate) fn illegal_constructor() -> JsError {
+    JsNativeError::typ()
+        .with_message("Illegal constructor")
+        .into()
+}
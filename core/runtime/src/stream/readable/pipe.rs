@@ -0,0 +1,431 @@
+//! Piping a readable stream to a writable stream.
+//!
+//! See <https://streams.spec.whatwg.org/#readable-stream-pipe-to>
+
+use super::{
+    GenericReader, JsReadableStream, JsReadableStreamDefaultReader, PipeOptions, ReadRequest,
+    ReadableState,
+};
+use crate::abort::JsAbortSignal;
+use crate::stream::writable::{JsWritableStream, JsWritableStreamDefaultWriter, WritableState};
+use crate::stream::{Deferred, ignore, react, resolved, steps_function, type_error, upon};
+use boa_engine::gc::{Gc, GcRefCell};
+use boa_engine::object::builtins::{JsFunction, JsPromise};
+use boa_engine::{Context, Finalize, JsResult, JsValue, Trace, js_string};
+use boa_wintertc::events::{AddEventListenerOptions, EventTargetData};
+
+/// An action to take on the streams when shutting down a pipe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+enum Action {
+    /// Abort the destination.
+    AbortDestination,
+    /// Cancel the source.
+    CancelSource,
+    /// Abort the destination and cancel the source, as allowed by the options.
+    AbortBoth,
+    /// Close the destination.
+    CloseDestination,
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct PipeSlots {
+    source: JsReadableStream,
+    destination: JsWritableStream,
+    reader: GenericReader,
+    writer: JsWritableStreamDefaultWriter,
+    options: PipeOptions,
+    shutting_down: bool,
+    current_write: JsPromise,
+    promise: Deferred,
+    abort_algorithm: Option<JsFunction>,
+}
+
+/// The state of a pipe between two streams.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Pipe {
+    slots: Gc<GcRefCell<PipeSlots>>,
+}
+
+/// Pipes `source` to `destination`, returning a promise settled once the pipe is done.
+///
+/// See <https://streams.spec.whatwg.org/#readable-stream-pipe-to>
+#[allow(clippy::too_many_lines)]
+pub(crate) fn pipe_to(
+    source: &JsReadableStream,
+    destination: &JsWritableStream,
+    options: PipeOptions,
+    context: &mut Context,
+) -> JsResult<JsPromise> {
+    let reader = JsReadableStreamDefaultReader::acquire(source, context)?;
+    let writer = JsWritableStreamDefaultWriter::acquire(destination, context)?;
+    source.set_disturbed();
+
+    let promise = Deferred::new(context);
+    let signal = options.signal.clone();
+    let pipe = Pipe {
+        slots: Gc::new(GcRefCell::new(PipeSlots {
+            source: source.clone(),
+            destination: destination.clone(),
+            reader: reader.generic().clone(),
+            writer: writer.clone(),
+            options,
+            shutting_down: false,
+            current_write: resolved(JsValue::undefined(), context)?,
+            promise: promise.clone(),
+            abort_algorithm: None,
+        })),
+    };
+
+    if let Some(signal) = signal {
+        let aborted = signal
+            .downcast_ref::<JsAbortSignal>()
+            .is_some_and(|signal| signal.is_aborted());
+        if aborted {
+            pipe.abort(context)?;
+            return Ok(promise.promise());
+        }
+        let abort_algorithm = steps_function(
+            &pipe,
+            |pipe, _, context| {
+                pipe.abort(context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        );
+        let target = signal
+            .downcast_ref::<JsAbortSignal>()
+            .map(|signal| signal.event_target().clone());
+        if let Some(target) = target {
+            target.add_event_listener(
+                js_string!("abort"),
+                abort_algorithm.clone().into(),
+                AddEventListenerOptions::default(),
+            );
+        }
+        pipe.slots.borrow_mut().abort_algorithm = Some(abort_algorithm);
+    }
+
+    // Errors must be propagated forward.
+    if source.state() == ReadableState::Errored {
+        pipe.source_errored(source.stored_error(), context)?;
+    } else {
+        upon(
+            &reader.generic().closed().promise(),
+            &pipe,
+            ignore,
+            |pipe, error, context| {
+                pipe.source_errored(error, context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        )?;
+    }
+
+    // Errors must be propagated backward.
+    if destination.state() == WritableState::Errored {
+        pipe.destination_errored(destination.stored_error(), context)?;
+    } else {
+        upon(
+            &writer.closed().promise(),
+            &pipe,
+            ignore,
+            |pipe, error, context| {
+                pipe.destination_errored(error, context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        )?;
+    }
+
+    // Closing must be propagated forward.
+    if source.state() == ReadableState::Closed {
+        pipe.source_closed(context)?;
+    } else {
+        upon(
+            &reader.generic().closed().promise(),
+            &pipe,
+            |pipe, _, context| {
+                pipe.source_closed(context)?;
+                Ok(JsValue::undefined())
+            },
+            ignore,
+            context,
+        )?;
+    }
+
+    // Closing must be propagated backward.
+    if destination.close_queued_or_in_flight() || destination.state() == WritableState::Closed {
+        let error = type_error(
+            "the destination stream closed before all data could be piped to it",
+            context,
+        );
+        if pipe.options().prevent_cancel {
+            pipe.shutdown(Some(error), context)?;
+        } else {
+            pipe.shutdown_with_action(Action::CancelSource, error.clone(), Some(error), context)?;
+        }
+    }
+
+    pipe.step(context)?;
+    Ok(promise.promise())
+}
+
+impl Pipe {
+    fn options(&self) -> PipeOptions {
+        self.slots.borrow().options.clone()
+    }
+
+    fn is_shutting_down(&self) -> bool {
+        self.slots.borrow().shutting_down
+    }
+
+    /// Waits for the destination to be ready, then reads a chunk and writes it.
+    fn step(&self, context: &mut Context) -> JsResult<()> {
+        if self.is_shutting_down() {
+            return Ok(());
+        }
+        let ready = self.slots.borrow().writer.ready();
+        upon(
+            &ready.promise(),
+            self,
+            |pipe, _, context| {
+                pipe.read(context)?;
+                Ok(JsValue::undefined())
+            },
+            ignore,
+            context,
+        )
+    }
+
+    fn read(&self, context: &mut Context) -> JsResult<()> {
+        if self.is_shutting_down() {
+            return Ok(());
+        }
+        let reader = self.slots.borrow().reader.clone();
+        let request = ReadRequest::steps(
+            self,
+            |pipe, chunk, context| {
+                let writer = pipe.slots.borrow().writer.clone();
+                let write = writer.write(chunk, context)?;
+                let current_write = react(&write, &(), None, Some(ignore), context)?;
+                pipe.slots.borrow_mut().current_write = current_write;
+                pipe.step(context)?;
+                Ok(JsValue::undefined())
+            },
+            ignore,
+            ignore,
+            context,
+        );
+        reader.default_read(request, context)
+    }
+
+    fn source_errored(&self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        if self.options().prevent_abort {
+            self.shutdown(Some(error), context)
+        } else {
+            self.shutdown_with_action(
+                Action::AbortDestination,
+                error.clone(),
+                Some(error),
+                context,
+            )
+        }
+    }
+
+    fn destination_errored(&self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        if self.options().prevent_cancel {
+            self.shutdown(Some(error), context)
+        } else {
+            self.shutdown_with_action(Action::CancelSource, error.clone(), Some(error), context)
+        }
+    }
+
+    fn source_closed(&self, context: &mut Context) -> JsResult<()> {
+        if self.options().prevent_close {
+            self.shutdown(None, context)
+        } else {
+            self.shutdown_with_action(
+                Action::CloseDestination,
+                JsValue::undefined(),
+                None,
+                context,
+            )
+        }
+    }
+
+    /// Runs the abort algorithm of the pipe's signal.
+    fn abort(&self, context: &mut Context) -> JsResult<()> {
+        let signal = self.options().signal;
+        let error = signal
+            .as_ref()
+            .and_then(|signal| {
+                signal
+                    .downcast_ref::<JsAbortSignal>()
+                    .map(|signal| signal.clone())
+            })
+            .map_or_else(JsValue::undefined, |signal| signal.abort_reason(context));
+        self.shutdown_with_action(Action::AbortBoth, error.clone(), Some(error), context)
+    }
+
+    /// Returns `true` if the pending writes must be awaited before shutting down.
+    fn must_wait_for_writes(&self) -> bool {
+        let destination = self.slots.borrow().destination.clone();
+        destination.state() == WritableState::Writable && !destination.close_queued_or_in_flight()
+    }
+
+    /// Resolves once the last write, and any writes started while waiting, have finished.
+    fn wait_for_writes_to_finish(&self, context: &mut Context) -> JsResult<JsPromise> {
+        let current_write = self.slots.borrow().current_write.clone();
+        react(
+            &current_write,
+            &(self.clone(), current_write.clone()),
+            Some(|(pipe, old_write), _, context| {
+                let current_write = pipe.slots.borrow().current_write.clone();
+                if *current_write == **old_write {
+                    Ok(JsValue::undefined())
+                } else {
+                    pipe.wait_for_writes_to_finish(context).map(Into::into)
+                }
+            }),
+            None,
+            context,
+        )
+    }
+
+    fn shutdown_with_action(
+        &self,
+        action: Action,
+        reason: JsValue,
+        original_error: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        if std::mem::replace(&mut self.slots.borrow_mut().shutting_down, true) {
+            return Ok(());
+        }
+        let captures = (self.clone(), action, reason, original_error);
+        let rest: crate::stream::Steps<(Self, Action, JsValue, Option<JsValue>)> =
+            |(pipe, action, reason, original_error), _, context| {
+                let result = pipe.perform(*action, reason.clone(), context)?;
+                upon(
+                    &result,
+                    &(pipe.clone(), original_error.clone()),
+                    |(pipe, original_error), _, context| {
+                        pipe.finalize(original_error.clone(), context)?;
+                        Ok(JsValue::undefined())
+                    },
+                    |(pipe, _), error, context| {
+                        pipe.finalize(Some(error), context)?;
+                        Ok(JsValue::undefined())
+                    },
+                    context,
+                )?;
+                Ok(JsValue::undefined())
+            };
+        if self.must_wait_for_writes() {
+            let writes = self.wait_for_writes_to_finish(context)?;
+            upon(&writes, &captures, rest, ignore, context)
+        } else {
+            rest(&captures, JsValue::undefined(), context)?;
+            Ok(())
+        }
+    }
+
+    /// Performs `action`, returning a promise settled once it is done.
+    fn perform(
+        &self,
+        action: Action,
+        reason: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let (source, destination, options) = {
+            let slots = self.slots.borrow();
+            (
+                slots.source.clone(),
+                slots.destination.clone(),
+                slots.options.clone(),
+            )
+        };
+        match action {
+            Action::AbortDestination => destination.abort(reason, context),
+            Action::CancelSource => source.cancel(reason, context),
+            Action::CloseDestination => {
+                let writer = self.slots.borrow().writer.clone();
+                writer.close_with_error_propagation(context)
+            }
+            Action::AbortBoth => {
+                let mut actions = Vec::new();
+                if !options.prevent_abort {
+                    actions.push(if destination.state() == WritableState::Writable {
+                        destination.abort(reason.clone(), context)?
+                    } else {
+                        resolved(JsValue::undefined(), context)?
+                    });
+                }
+                if !options.prevent_cancel {
+                    actions.push(if source.state() == ReadableState::Readable {
+                        source.cancel(reason, context)?
+                    } else {
+                        resolved(JsValue::undefined(), context)?
+                    });
+                }
+                let all = JsPromise::all(actions, context)?;
+                react(
+                    &all,
+                    &(),
+                    Some(|(), _, _| Ok(JsValue::undefined())),
+                    None,
+                    context,
+                )
+            }
+        }
+    }
+
+    fn shutdown(&self, error: Option<JsValue>, context: &mut Context) -> JsResult<()> {
+        if std::mem::replace(&mut self.slots.borrow_mut().shutting_down, true) {
+            return Ok(());
+        }
+        if self.must_wait_for_writes() {
+            let writes = self.wait_for_writes_to_finish(context)?;
+            upon(
+                &writes,
+                &(self.clone(), error),
+                |(pipe, error), _, context| {
+                    pipe.finalize(error.clone(), context)?;
+                    Ok(JsValue::undefined())
+                },
+                ignore,
+                context,
+            )
+        } else {
+            self.finalize(error, context)
+        }
+    }
+
+    fn finalize(&self, error: Option<JsValue>, context: &mut Context) -> JsResult<()> {
+        let (writer, reader, signal, abort_algorithm, promise) = {
+            let mut slots = self.slots.borrow_mut();
+            (
+                slots.writer.clone(),
+                slots.reader.clone(),
+                slots.options.signal.clone(),
+                slots.abort_algorithm.take(),
+                slots.promise.clone(),
+            )
+        };
+        writer.release(context)?;
+        reader.release(context)?;
+        if let (Some(signal), Some(abort_algorithm)) = (signal, abort_algorithm) {
+            let target = signal
+                .downcast_ref::<JsAbortSignal>()
+                .map(|signal| signal.event_target().clone());
+            if let Some(target) = target {
+                target.remove_event_listener(&js_string!("abort"), &abort_algorithm.into(), false);
+            }
+        }
+        match error {
+            Some(error) => promise.reject(error, context),
+            None => promise.resolve(JsValue::undefined(), context),
+        }
+    }
+}
@@ -0,0 +1,476 @@
+//! The `ReadableStreamDefaultReader` and `ReadableStreamBYOBReader` JavaScript classes.
+//!
+//! See <https://streams.spec.whatwg.org/#default-reader-class> and
+//! <https://streams.spec.whatwg.org/#byob-reader-class>
+
+use super::JsReadableByteStreamController;
+use super::byte_controller::view_info;
+use super::{JsReadableStream, ReadableState, Reader, enforce_range_u64, promise_or_rejected};
+use crate::stream::{Deferred, Steps, mark_handled, rejected, steps_function, type_error};
+use boa_engine::builtins::iterable::create_iter_result_object;
+use boa_engine::gc::{Gc, GcRefCell};
+use boa_engine::interop::JsClass;
+#[cfg(feature = "fetch")]
+use boa_engine::object::builtins::JsUint8Array;
+use boa_engine::object::builtins::{JsFunction, JsPromise};
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsValue, Trace, boa_class, js_error, js_string,
+};
+use std::collections::VecDeque;
+
+/// A pending read from a reader, settled by the stream's controller.
+///
+/// See <https://streams.spec.whatwg.org/#read-request> and
+/// <https://streams.spec.whatwg.org/#read-into-request>
+#[derive(Debug, Clone, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub(crate) enum ReadRequest {
+    /// A read whose promise is returned by `read()`.
+    Promise(Deferred),
+    /// A read made by the runtime, with its own steps.
+    Steps {
+        chunk: JsFunction,
+        close: JsFunction,
+        error: JsFunction,
+    },
+}
+
+impl ReadRequest {
+    /// Creates a read request that runs native steps with `captures`.
+    pub(crate) fn steps<T: Trace + Clone + 'static>(
+        captures: &T,
+        chunk: Steps<T>,
+        close: Steps<T>,
+        error: Steps<T>,
+        context: &mut Context,
+    ) -> Self {
+        Self::Steps {
+            chunk: steps_function(captures, chunk, context),
+            close: steps_function(captures, close, context),
+            error: steps_function(captures, error, context),
+        }
+    }
+
+    pub(crate) fn chunk_steps(self, chunk: JsValue, context: &mut Context) -> JsResult<()> {
+        match self {
+            Self::Promise(deferred) => {
+                let result = create_iter_result_object(chunk, false, context);
+                deferred.resolve(result, context)
+            }
+            Self::Steps { chunk: steps, .. } => {
+                steps.call(&JsValue::undefined(), &[chunk], context)?;
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) fn close_steps(self, chunk: JsValue, context: &mut Context) -> JsResult<()> {
+        match self {
+            Self::Promise(deferred) => {
+                let result = create_iter_result_object(chunk, true, context);
+                deferred.resolve(result, context)
+            }
+            Self::Steps { close, .. } => {
+                close.call(&JsValue::undefined(), &[chunk], context)?;
+                Ok(())
+            }
+        }
+    }
+
+    pub(crate) fn error_steps(self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        match self {
+            Self::Promise(deferred) => deferred.reject(error, context),
+            Self::Steps { error: steps, .. } => {
+                steps.call(&JsValue::undefined(), &[error], context)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct GenericReaderSlots {
+    stream: Option<JsReadableStream>,
+    closed: Deferred,
+    requests: VecDeque<ReadRequest>,
+}
+
+/// The state shared by both kinds of readers.
+///
+/// See <https://streams.spec.whatwg.org/#readablestreamgenericreader>
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct GenericReader {
+    slots: Gc<GcRefCell<GenericReaderSlots>>,
+}
+
+impl GenericReader {
+    /// Locks `stream` to a new reader.
+    ///
+    /// See <https://streams.spec.whatwg.org/#readable-stream-reader-generic-initialize>
+    fn initialize(
+        stream: &JsReadableStream,
+        kind: fn(Self) -> Reader,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let closed = match stream.state() {
+            ReadableState::Readable => Deferred::new(context),
+            ReadableState::Closed => Deferred::resolved(JsValue::undefined(), context)?,
+            ReadableState::Errored => Deferred::rejected(stream.stored_error(), context)?,
+        };
+        let reader = Self {
+            slots: Gc::new(GcRefCell::new(GenericReaderSlots {
+                stream: Some(stream.clone()),
+                closed,
+                requests: VecDeque::new(),
+            })),
+        };
+        stream.set_reader(Some(kind(reader.clone())));
+        Ok(reader)
+    }
+
+    /// Returns the stream this reader is locked to, if it was not released.
+    pub(crate) fn stream(&self) -> Option<JsReadableStream> {
+        self.slots.borrow().stream.clone()
+    }
+
+    pub(crate) fn closed(&self) -> Deferred {
+        self.slots.borrow().closed.clone()
+    }
+
+    pub(crate) fn push_request(&self, request: ReadRequest) {
+        self.slots.borrow_mut().requests.push_back(request);
+    }
+
+    pub(crate) fn shift_request(&self) -> Option<ReadRequest> {
+        self.slots.borrow_mut().requests.pop_front()
+    }
+
+    pub(crate) fn take_requests(&self) -> VecDeque<ReadRequest> {
+        std::mem::take(&mut self.slots.borrow_mut().requests)
+    }
+
+    pub(crate) fn num_requests(&self) -> usize {
+        self.slots.borrow().requests.len()
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-reader-generic-cancel>
+    pub(crate) fn cancel(&self, reason: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        let Some(stream) = self.stream() else {
+            return rejected(type_error("the reader has been released", context), context);
+        };
+        stream.cancel(reason, context)
+    }
+
+    /// Releases the lock on the stream and errors the pending reads.
+    ///
+    /// See <https://streams.spec.whatwg.org/#abstract-opdef-readablestreamdefaultreaderrelease>
+    /// and <https://streams.spec.whatwg.org/#abstract-opdef-readablestreambyobreaderrelease>
+    pub(crate) fn release(&self, context: &mut Context) -> JsResult<()> {
+        let Some(stream) = self.stream() else {
+            return Ok(());
+        };
+        let error = type_error("the reader has been released", context);
+        let closed = if stream.state() == ReadableState::Readable {
+            let closed = self.closed();
+            closed.reject(error.clone(), context)?;
+            closed
+        } else {
+            let closed = Deferred::rejected(error.clone(), context)?;
+            self.slots.borrow_mut().closed = closed.clone();
+            closed
+        };
+        mark_handled(&closed.promise(), context)?;
+        stream.controller().release_steps();
+        stream.set_reader(None);
+        self.slots.borrow_mut().stream = None;
+
+        for request in self.take_requests() {
+            request.error_steps(error.clone(), context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#readable-stream-default-reader-read>
+    pub(crate) fn default_read(&self, request: ReadRequest, context: &mut Context) -> JsResult<()> {
+        let Some(stream) = self.stream() else {
+            let error = type_error("the reader has been released", context);
+            return request.error_steps(error, context);
+        };
+        stream.set_disturbed();
+        match stream.state() {
+            ReadableState::Closed => request.close_steps(JsValue::undefined(), context),
+            ReadableState::Errored => request.error_steps(stream.stored_error(), context),
+            ReadableState::Readable => stream.controller().pull_steps(request, context),
+        }
+    }
+}
+
+/// The JavaScript `ReadableStreamDefaultReader` class.
+///
+/// See <https://streams.spec.whatwg.org/#default-reader-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsReadableStreamDefaultReader {
+    reader: GenericReader,
+}
+
+impl JsReadableStreamDefaultReader {
+    /// Locks `stream` to a new default reader.
+    ///
+    /// See <https://streams.spec.whatwg.org/#acquire-readable-stream-reader>
+    pub(crate) fn acquire(stream: &JsReadableStream, context: &mut Context) -> JsResult<Self> {
+        if stream.is_locked() {
+            return Err(js_error!(TypeError: "the stream is already locked to a reader"));
+        }
+        let reader = GenericReader::initialize(stream, Reader::Default, context)?;
+        Ok(Self { reader })
+    }
+
+    pub(crate) fn generic(&self) -> &GenericReader {
+        &self.reader
+    }
+
+    /// Reads every chunk of the stream, which must all be `Uint8Array`s, returning a promise
+    /// resolved with their concatenation as a `Uint8Array`.
+    ///
+    /// See <https://streams.spec.whatwg.org/#readablestreamdefaultreader-read-all-bytes>
+    #[cfg(feature = "fetch")]
+    pub(crate) fn read_all_bytes(&self, context: &mut Context) -> JsResult<JsPromise> {
+        let read = ReadAllBytes {
+            reader: self.reader.clone(),
+            bytes: Gc::new(GcRefCell::new(Vec::new())),
+            result: Deferred::new(context),
+        };
+        read.read_loop(context)?;
+        Ok(read.result.promise())
+    }
+}
+
+/// The state of a "read all bytes" operation.
+#[cfg(feature = "fetch")]
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ReadAllBytes {
+    reader: GenericReader,
+    bytes: Gc<GcRefCell<Vec<u8>>>,
+    result: Deferred,
+}
+
+#[cfg(feature = "fetch")]
+impl ReadAllBytes {
+    /// See <https://streams.spec.whatwg.org/#read-loop>
+    fn read_loop(&self, context: &mut Context) -> JsResult<()> {
+        let request = ReadRequest::steps(
+            self,
+            |read, chunk, context| {
+                let Some(chunk) = chunk
+                    .as_object()
+                    .and_then(|chunk| JsUint8Array::from_object(chunk).ok())
+                else {
+                    let error = type_error("the chunk is not a Uint8Array", context);
+                    read.result.reject(error, context)?;
+                    return Ok(JsValue::undefined());
+                };
+                let chunk = chunk.to_vec(context)?;
+                read.bytes.borrow_mut().extend(chunk);
+                read.read_loop(context)?;
+                Ok(JsValue::undefined())
+            },
+            |read, _, context| {
+                let bytes = std::mem::take(&mut *read.bytes.borrow_mut());
+                let bytes = JsUint8Array::from_iter(bytes, context)?;
+                read.result.resolve(bytes.into(), context)?;
+                Ok(JsValue::undefined())
+            },
+            |read, error, context| {
+                read.result.reject(error, context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        );
+        self.reader.default_read(request, context)
+    }
+}
+
+/// Returns the stream of the `stream` argument of a reader constructor.
+fn stream_argument(stream: &JsValue) -> JsResult<JsReadableStream> {
+    stream
+        .as_object()
+        .as_ref()
+        .and_then(JsReadableStream::of)
+        .ok_or_else(|| js_error!(TypeError: "argument must be a ReadableStream"))
+}
+
+#[boa_class(rename = "ReadableStreamDefaultReader")]
+#[boa(rename_all = "camelCase")]
+impl JsReadableStreamDefaultReader {
+    #[boa(constructor)]
+    fn constructor(stream: JsValue, context: &mut Context) -> JsResult<Self> {
+        Self::acquire(&stream_argument(&stream)?, context)
+    }
+
+    #[boa(getter)]
+    fn closed(&self) -> JsPromise {
+        self.reader.closed().promise()
+    }
+
+    #[boa(method)]
+    fn cancel(
+        this: JsClass<Self>,
+        reason: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let reader = this.clone_inner().reader.clone();
+        let result = reader.cancel(reason.unwrap_or_default(), context);
+        promise_or_rejected(result, context)
+    }
+
+    #[boa(method)]
+    fn read(this: JsClass<Self>, context: &mut Context) -> JsResult<JsPromise> {
+        let reader = this.clone_inner().reader.clone();
+        if reader.stream().is_none() {
+            return rejected(type_error("the reader has been released", context), context);
+        }
+        let deferred = Deferred::new(context);
+        if let Err(error) = reader.default_read(ReadRequest::Promise(deferred.clone()), context) {
+            deferred.reject(error.into_opaque(context)?, context)?;
+        }
+        Ok(deferred.promise())
+    }
+
+    #[boa(method)]
+    fn release_lock(this: JsClass<Self>, context: &mut Context) -> JsResult<()> {
+        this.clone_inner().reader.release(context)
+    }
+}
+
+/// The JavaScript `ReadableStreamBYOBReader` class, which reads bytes into buffers
+/// provided by the caller.
+///
+/// See <https://streams.spec.whatwg.org/#byob-reader-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsReadableStreamBYOBReader {
+    reader: GenericReader,
+}
+
+impl JsReadableStreamBYOBReader {
+    /// Locks the byte stream `stream` to a new BYOB reader.
+    ///
+    /// See <https://streams.spec.whatwg.org/#acquire-readable-stream-byob-reader>
+    pub(crate) fn acquire(stream: &JsReadableStream, context: &mut Context) -> JsResult<Self> {
+        if stream.is_locked() {
+            return Err(js_error!(TypeError: "the stream is already locked to a reader"));
+        }
+        if !matches!(stream.controller(), super::Controller::Byte(_)) {
+            return Err(js_error!(TypeError: "a BYOB reader can only read from a byte stream"));
+        }
+        let reader = GenericReader::initialize(stream, Reader::Byob, context)?;
+        Ok(Self { reader })
+    }
+
+    /// See <https://streams.spec.whatwg.org/#byob-reader-read>
+    fn read_into(
+        &self,
+        view: &JsValue,
+        options: Option<&JsObject>,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let Some(view) = view.as_object() else {
+            return Err(js_error!(TypeError: "view must be an ArrayBufferView"));
+        };
+        let info = view_info(&view, context)?;
+        if info.byte_length == 0 {
+            return Err(js_error!(TypeError: "view must have a non-zero byteLength"));
+        }
+        if info.buffer.data().is_none() {
+            return Err(js_error!(TypeError: "the buffer of view is detached"));
+        }
+        if info.buffer.byte_length() == 0 {
+            return Err(js_error!(TypeError: "the buffer of view must have a non-zero byteLength"));
+        }
+        let min = match options {
+            Some(options) => {
+                let min = options.get(js_string!("min"), context)?;
+                if min.is_undefined() {
+                    1
+                } else {
+                    enforce_range_u64(&min, context)?
+                }
+            }
+            None => 1,
+        };
+        if min == 0 {
+            return Err(js_error!(TypeError: "min must be greater than 0"));
+        }
+        let max = if info.is_data_view {
+            info.byte_length
+        } else {
+            info.byte_length / info.element_size
+        };
+        if min > max as u64 {
+            return Err(js_error!(RangeError: "min must not be greater than the length of view"));
+        }
+        let Some(stream) = self.reader.stream() else {
+            return Err(js_error!(TypeError: "the reader has been released"));
+        };
+
+        let deferred = Deferred::new(context);
+        let request = ReadRequest::Promise(deferred.clone());
+        stream.set_disturbed();
+        if stream.state() == ReadableState::Errored {
+            request.error_steps(stream.stored_error(), context)?;
+        } else {
+            let super::Controller::Byte(controller) = stream.controller() else {
+                return Err(js_error!(TypeError: "a BYOB reader can only read from a byte stream"));
+            };
+            #[allow(clippy::cast_possible_truncation)]
+            JsReadableByteStreamController::of(&controller).pull_into(
+                info,
+                min as usize,
+                request,
+                context,
+            )?;
+        }
+        Ok(deferred.promise())
+    }
+}
+
+#[boa_class(rename = "ReadableStreamBYOBReader")]
+#[boa(rename_all = "camelCase")]
+impl JsReadableStreamBYOBReader {
+    #[boa(constructor)]
+    fn constructor(stream: JsValue, context: &mut Context) -> JsResult<Self> {
+        Self::acquire(&stream_argument(&stream)?, context)
+    }
+
+    #[boa(getter)]
+    fn closed(&self) -> JsPromise {
+        self.reader.closed().promise()
+    }
+
+    #[boa(method)]
+    fn cancel(
+        this: JsClass<Self>,
+        reason: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let reader = this.clone_inner().reader.clone();
+        let result = reader.cancel(reason.unwrap_or_default(), context);
+        promise_or_rejected(result, context)
+    }
+
+    #[boa(method)]
+    fn read(
+        this: JsClass<Self>,
+        view: JsValue,
+        options: Option<JsObject>,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let reader = this.clone_inner();
+        let result = reader.read_into(&view, options.as_ref(), context);
+        promise_or_rejected(result, context)
+    }
+
+    #[boa(method)]
+    fn release_lock(this: JsClass<Self>, context: &mut Context) -> JsResult<()> {
+        this.clone_inner().reader.release(context)
+    }
+}
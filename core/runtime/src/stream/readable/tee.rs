@@ -0,0 +1,284 @@
+//! Teeing a readable stream into two branches.
+//!
+//! See <https://streams.spec.whatwg.org/#readable-stream-tee>
+
+use super::{
+    Controller, GenericReader, JsReadableStream, JsReadableStreamDefaultReader, ReadRequest,
+};
+use crate::stream::{Algorithm, Deferred, SizeAlgorithm, resolved, upon};
+use boa_engine::gc::{Gc, GcRefCell};
+use boa_engine::job::{Job, PromiseJob};
+use boa_engine::object::builtins::{JsArray, JsUint8Array};
+use boa_engine::{Context, Finalize, JsResult, JsValue, Trace};
+
+#[derive(Debug, Trace, Finalize)]
+#[allow(clippy::struct_excessive_bools)]
+struct TeeSlots {
+    stream: JsReadableStream,
+    reader: GenericReader,
+    reading: bool,
+    read_again: bool,
+    canceled1: bool,
+    canceled2: bool,
+    reason1: JsValue,
+    reason2: JsValue,
+    branch1: Option<JsReadableStream>,
+    branch2: Option<JsReadableStream>,
+    cancel: Deferred,
+    clone_for_branch2: bool,
+}
+
+/// The state shared by the algorithms of both branches.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Tee {
+    slots: Gc<GcRefCell<TeeSlots>>,
+}
+
+/// Splits `stream` into two branches which receive the same chunks.
+///
+/// Byte streams are teed into byte streams, and each branch receives its own copy of the
+/// chunks. For other streams, both branches receive the same chunks, unless
+/// `clone_for_branch2` is set, in which case the second branch receives structured clones.
+///
+/// See <https://streams.spec.whatwg.org/#readable-stream-tee>
+pub(crate) fn tee(
+    stream: &JsReadableStream,
+    clone_for_branch2: bool,
+    context: &mut Context,
+) -> JsResult<(JsReadableStream, JsReadableStream)> {
+    let bytes = matches!(stream.controller(), Controller::Byte(_));
+    let reader = JsReadableStreamDefaultReader::acquire(stream, context)?;
+    let tee = Tee {
+        slots: Gc::new(GcRefCell::new(TeeSlots {
+            stream: stream.clone(),
+            reader: reader.generic().clone(),
+            reading: false,
+            read_again: false,
+            canceled1: false,
+            canceled2: false,
+            reason1: JsValue::undefined(),
+            reason2: JsValue::undefined(),
+            branch1: None,
+            branch2: None,
+            cancel: Deferred::new(context),
+            clone_for_branch2: clone_for_branch2 && !bytes,
+        })),
+    };
+
+    let pull = Algorithm::native(&tee, |tee, _, context| tee.pull(context), context);
+    let cancel1 = Algorithm::native(
+        &tee,
+        |tee, reason, context| tee.cancel(reason, true, context),
+        context,
+    );
+    let cancel2 = Algorithm::native(
+        &tee,
+        |tee, reason, context| tee.cancel(reason, false, context),
+        context,
+    );
+    let (branch1, branch2) = if bytes {
+        (
+            JsReadableStream::create_byte_stream(
+                &Algorithm::none(),
+                pull.clone(),
+                cancel1,
+                context,
+            )?,
+            JsReadableStream::create_byte_stream(&Algorithm::none(), pull, cancel2, context)?,
+        )
+    } else {
+        (
+            JsReadableStream::create(
+                &Algorithm::none(),
+                pull.clone(),
+                cancel1,
+                1.0,
+                SizeAlgorithm::default(),
+                context,
+            )?,
+            JsReadableStream::create(
+                &Algorithm::none(),
+                pull,
+                cancel2,
+                1.0,
+                SizeAlgorithm::default(),
+                context,
+            )?,
+        )
+    };
+    {
+        let mut slots = tee.slots.borrow_mut();
+        slots.branch1 = Some(branch1.clone());
+        slots.branch2 = Some(branch2.clone());
+    }
+
+    upon(
+        &reader.generic().closed().promise(),
+        &tee,
+        |_, _, _| Ok(JsValue::undefined()),
+        |tee, reason, context| {
+            let (branch1, branch2) = tee.branches();
+            branch1.controller().error(reason.clone(), context)?;
+            branch2.controller().error(reason, context)?;
+            tee.resolve_cancel_if_needed(context)?;
+            Ok(JsValue::undefined())
+        },
+        context,
+    )?;
+    Ok((branch1, branch2))
+}
+
+impl Tee {
+    fn branches(&self) -> (JsReadableStream, JsReadableStream) {
+        let slots = self.slots.borrow();
+        (
+            slots
+                .branch1
+                .clone()
+                .expect("branches are created before reading"),
+            slots
+                .branch2
+                .clone()
+                .expect("branches are created before reading"),
+        )
+    }
+
+    /// Resolves the cancel promise, unless both branches were canceled.
+    fn resolve_cancel_if_needed(&self, context: &mut Context) -> JsResult<()> {
+        let (canceled1, canceled2, cancel) = {
+            let slots = self.slots.borrow();
+            (slots.canceled1, slots.canceled2, slots.cancel.clone())
+        };
+        if !canceled1 || !canceled2 {
+            cancel.resolve(JsValue::undefined(), context)?;
+        }
+        Ok(())
+    }
+
+    fn pull(&self, context: &mut Context) -> JsResult<JsValue> {
+        let reader = {
+            let mut slots = self.slots.borrow_mut();
+            if slots.reading {
+                slots.read_again = true;
+                return resolved(JsValue::undefined(), context).map(Into::into);
+            }
+            slots.reading = true;
+            slots.reader.clone()
+        };
+        let request = ReadRequest::steps(
+            self,
+            |tee, chunk, context| {
+                // Enqueueing is delayed to a microtask, so errors of the source stream are
+                // seen by the branches before the chunk.
+                let tee = tee.clone();
+                context.enqueue_job(Job::from(PromiseJob::new(move |context| {
+                    tee.chunk_steps(chunk, context)?;
+                    Ok(JsValue::undefined())
+                })));
+                Ok(JsValue::undefined())
+            },
+            |tee, _, context| {
+                let (canceled1, canceled2) = {
+                    let mut slots = tee.slots.borrow_mut();
+                    slots.reading = false;
+                    (slots.canceled1, slots.canceled2)
+                };
+                let (branch1, branch2) = tee.branches();
+                if !canceled1 {
+                    branch1.controller().close(context)?;
+                }
+                if !canceled2 {
+                    branch2.controller().close(context)?;
+                }
+                tee.resolve_cancel_if_needed(context)?;
+                Ok(JsValue::undefined())
+            },
+            |tee, _, _| {
+                tee.slots.borrow_mut().reading = false;
+                Ok(JsValue::undefined())
+            },
+            context,
+        );
+        reader.default_read(request, context)?;
+        resolved(JsValue::undefined(), context).map(Into::into)
+    }
+
+    fn chunk_steps(&self, chunk: JsValue, context: &mut Context) -> JsResult<()> {
+        let (canceled1, canceled2, clone_for_branch2, stream) = {
+            let mut slots = self.slots.borrow_mut();
+            slots.read_again = false;
+            (
+                slots.canceled1,
+                slots.canceled2,
+                slots.clone_for_branch2,
+                slots.stream.clone(),
+            )
+        };
+        let (branch1, branch2) = self.branches();
+        let bytes = matches!(branch1.controller(), Controller::Byte(_));
+
+        let chunk1 = chunk.clone();
+        let mut chunk2 = chunk;
+        if !canceled2 && clone_for_branch2 {
+            match crate::clone::js_module::structured_clone(chunk2.clone(), None, context) {
+                Ok(clone) => chunk2 = clone,
+                Err(error) => {
+                    let error = error.into_opaque(context)?;
+                    branch1.controller().error(error.clone(), context)?;
+                    branch2.controller().error(error.clone(), context)?;
+                    let result = stream.cancel(error, context)?;
+                    let cancel = self.slots.borrow().cancel.clone();
+                    cancel.resolve(result.into(), context)?;
+                    return Ok(());
+                }
+            }
+        } else if bytes
+            && !canceled1
+            && !canceled2
+            && let Some(view) = chunk2.as_object()
+        {
+            // Enqueueing into a byte stream transfers the buffer, so the second branch needs
+            // its own copy.
+            let bytes = JsUint8Array::from_object(view)?.to_vec(context)?;
+            chunk2 = JsUint8Array::from_iter(bytes, context)?.into();
+        }
+        if !canceled1 {
+            branch1.controller().enqueue(chunk1, context)?;
+        }
+        if !canceled2 {
+            branch2.controller().enqueue(chunk2, context)?;
+        }
+
+        let read_again = {
+            let mut slots = self.slots.borrow_mut();
+            slots.reading = false;
+            slots.read_again
+        };
+        if read_again {
+            self.pull(context)?;
+        }
+        Ok(())
+    }
+
+    fn cancel(&self, reason: JsValue, first: bool, context: &mut Context) -> JsResult<JsValue> {
+        let (composite, stream, cancel) = {
+            let mut slots = self.slots.borrow_mut();
+            if first {
+                slots.canceled1 = true;
+                slots.reason1 = reason;
+            } else {
+                slots.canceled2 = true;
+                slots.reason2 = reason;
+            }
+            let composite = (slots.canceled1 && slots.canceled2)
+                .then(|| [slots.reason1.clone(), slots.reason2.clone()]);
+            (composite, slots.stream.clone(), slots.cancel.clone())
+        };
+        if let Some(composite) = composite {
+            let composite = JsArray::from_iter(composite, context);
+            let result = stream.cancel(composite.into(), context)?;
+            cancel.resolve(result.into(), context)?;
+        }
+        Ok(cancel.promise().into())
+    }
+}
@@ -0,0 +1,118 @@
+//! The built-in queuing strategies, `ByteLengthQueuingStrategy` and `CountQueuingStrategy`.
+//!
+//! See <https://streams.spec.whatwg.org/#qs>
+
+use boa_engine::object::FunctionObjectBuilder;
+use boa_engine::object::builtins::JsFunction;
+use boa_engine::value::{Convert, TryFromJs};
+use boa_engine::{
+    Context, Finalize, JsArgs, JsData, JsValue, NativeFunction, Trace, boa_class, js_string,
+};
+
+/// A `QueuingStrategyInit` dictionary.
+///
+/// See <https://streams.spec.whatwg.org/#dictdef-queuingstrategyinit>
+#[derive(Debug, Clone, TryFromJs)]
+#[boa(rename_all = "camelCase")]
+pub struct QueuingStrategyInit {
+    high_water_mark: Convert<f64>,
+}
+
+/// The `size` functions of the queuing strategies of a realm, which are shared by all
+/// strategies of that realm.
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+struct SizeFunctions {
+    byte_length: JsFunction,
+    count: JsFunction,
+}
+
+/// Returns the `size` functions of the current realm, creating them if needed.
+fn size_functions(context: &mut Context) -> SizeFunctions {
+    let realm = context.realm().clone();
+    if let Some(functions) = realm.host_defined().get::<SizeFunctions>() {
+        return functions.clone();
+    }
+
+    let byte_length = FunctionObjectBuilder::new(
+        &realm,
+        NativeFunction::from_fn_ptr(|_, args, context| {
+            args.get_or_undefined(0)
+                .to_object(context)?
+                .get(js_string!("byteLength"), context)
+        }),
+    )
+    .name(js_string!("size"))
+    .length(1)
+    .build();
+    let count = FunctionObjectBuilder::new(
+        &realm,
+        NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::new(1))),
+    )
+    .name(js_string!("size"))
+    .length(0)
+    .build();
+    let functions = SizeFunctions { byte_length, count };
+    realm.host_defined_mut().insert(functions.clone());
+    functions
+}
+
+/// The JavaScript `ByteLengthQueuingStrategy` class, which measures chunks by their
+/// `byteLength`.
+///
+/// See <https://streams.spec.whatwg.org/#blqs-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsByteLengthQueuingStrategy {
+    high_water_mark: f64,
+}
+
+#[boa_class(rename = "ByteLengthQueuingStrategy")]
+#[boa(rename_all = "camelCase")]
+impl JsByteLengthQueuingStrategy {
+    #[boa(constructor)]
+    fn constructor(init: QueuingStrategyInit) -> Self {
+        Self {
+            high_water_mark: init.high_water_mark.0,
+        }
+    }
+
+    #[boa(getter)]
+    fn high_water_mark(&self) -> f64 {
+        self.high_water_mark
+    }
+
+    #[boa(getter)]
+    #[allow(clippy::unused_self)]
+    fn size(&self, context: &mut Context) -> JsFunction {
+        size_functions(context).byte_length.clone()
+    }
+}
+
+/// The JavaScript `CountQueuingStrategy` class, which counts every chunk as `1`.
+///
+/// See <https://streams.spec.whatwg.org/#cqs-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsCountQueuingStrategy {
+    high_water_mark: f64,
+}
+
+#[boa_class(rename = "CountQueuingStrategy")]
+#[boa(rename_all = "camelCase")]
+impl JsCountQueuingStrategy {
+    #[boa(constructor)]
+    fn constructor(init: QueuingStrategyInit) -> Self {
+        Self {
+            high_water_mark: init.high_water_mark.0,
+        }
+    }
+
+    #[boa(getter)]
+    fn high_water_mark(&self) -> f64 {
+        self.high_water_mark
+    }
+
+    #[boa(getter)]
+    #[allow(clippy::unused_self)]
+    fn size(&self, context: &mut Context) -> JsFunction {
+        size_functions(context).count.clone()
+    }
+}
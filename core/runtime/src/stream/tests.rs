@@ -0,0 +1,490 @@
+use crate::test::{TestAction, run_test_actions};
+use boa_engine::js_str;
+
+/// Awaits the promise stored in the global `result` property.
+fn await_result() -> TestAction {
+    TestAction::inspect_context(|ctx| {
+        let result = ctx.global_object().get(js_str!("result"), ctx).unwrap();
+        result.as_promise().unwrap().await_blocking(ctx).unwrap();
+    })
+}
+
+#[test]
+fn readable_default_reader() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                let controller;
+                const stream = new ReadableStream({
+                    start(c) {
+                        controller = c;
+                        c.enqueue("a");
+                        c.enqueue("b");
+                    },
+                });
+
+                assert(!stream.locked);
+                assertEq(controller.desiredSize, -1);
+                const reader = stream.getReader();
+                assert(stream.locked);
+                assertThrows(() => stream.getReader());
+                assertThrows(() => new ReadableStreamDefaultController());
+
+                globalThis.result = (async () => {
+                    let { value, done } = await reader.read();
+                    assertEq(value, "a");
+                    assertEq(done, false);
+                    assertEq((await reader.read()).value, "b");
+
+                    const pending = reader.read();
+                    controller.enqueue("c");
+                    assertEq((await pending).value, "c");
+
+                    controller.close();
+                    assertThrows(() => controller.enqueue("d"));
+                    ({ value, done } = await reader.read());
+                    assertEq(value, undefined);
+                    assertEq(done, true);
+                    await reader.closed;
+
+                    reader.releaseLock();
+                    assert(!stream.locked);
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn readable_underlying_source() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const log = [];
+                let pulls = 0;
+                const stream = new ReadableStream(
+                    {
+                        start() {
+                            log.push("start");
+                        },
+                        pull(controller) {
+                            pulls++;
+                            controller.enqueue(pulls);
+                        },
+                        cancel(reason) {
+                            log.push("cancel: " + reason);
+                        },
+                    },
+                    { highWaterMark: 2 },
+                );
+                assertArrayEqual(log, ["start"]);
+
+                globalThis.result = (async () => {
+                    const reader = stream.getReader();
+                    assertEq((await reader.read()).value, 1);
+                    assertEq((await reader.read()).value, 2);
+                    assert(pulls >= 2);
+
+                    await reader.cancel("done");
+                    assertArrayEqual(log, ["start", "cancel: done"]);
+                    assert((await reader.read()).done);
+
+                    const errored = new ReadableStream({
+                        start(controller) {
+                            controller.error(new TypeError("boom"));
+                        },
+                    });
+                    try {
+                        await errored.getReader().read();
+                        throw new Error("read should reject");
+                    } catch (e) {
+                        assert(e instanceof TypeError);
+                        assertEq(e.message, "boom");
+                    }
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn readable_byte_stream() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                let controller;
+                const stream = new ReadableStream({
+                    type: "bytes",
+                    start(c) {
+                        controller = c;
+                    },
+                });
+                assert(controller instanceof ReadableByteStreamController);
+                assertThrows(() => controller.enqueue("not a view"));
+
+                globalThis.result = (async () => {
+                    const reader = stream.getReader({ mode: "byob" });
+                    assert(reader instanceof ReadableStreamBYOBReader);
+
+                    const pending = reader.read(new Uint8Array(4));
+                    const request = controller.byobRequest;
+                    assertEq(request.view.byteLength, 4);
+                    request.view.set([1, 2, 3]);
+                    request.respond(3);
+                    const { value, done } = await pending;
+                    assert(!done);
+                    assert(value instanceof Uint8Array);
+                    assertArrayEqual(value, [1, 2, 3]);
+
+                    controller.enqueue(new Uint8Array([4, 5, 6, 7, 8]));
+                    const first = await reader.read(new Uint8Array(2));
+                    assertArrayEqual(first.value, [4, 5]);
+                    const second = await reader.read(new Uint16Array(1));
+                    assertArrayEqual(new Uint8Array(second.value.buffer), [6, 7]);
+                    const last = await reader.read(new Uint8Array(4), { min: 1 });
+                    assertArrayEqual(last.value, [8]);
+
+                    controller.close();
+                    const end = await reader.read(new Uint8Array(1));
+                    assert(end.done);
+                    assertEq(end.value.byteLength, 0);
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn readable_tee_and_iteration() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                globalThis.result = (async () => {
+                    const [a, b] = ReadableStream.from(["x", "y", "z"]).tee();
+                    const left = [];
+                    for await (const chunk of a) {
+                        left.push(chunk);
+                    }
+                    const right = [];
+                    for await (const chunk of b.values()) {
+                        right.push(chunk);
+                    }
+                    assertArrayEqual(left, ["x", "y", "z"]);
+                    assertArrayEqual(right, ["x", "y", "z"]);
+
+                    async function* generate() {
+                        yield 1;
+                        yield 2;
+                    }
+                    const values = [];
+                    for await (const value of ReadableStream.from(generate())) {
+                        values.push(value);
+                    }
+                    assertArrayEqual(values, [1, 2]);
+
+                    let canceled = false;
+                    const stream = new ReadableStream({
+                        pull(controller) {
+                            controller.enqueue(0);
+                        },
+                        cancel() {
+                            canceled = true;
+                        },
+                    });
+                    for await (const _ of stream) {
+                        break;
+                    }
+                    assert(canceled);
+
+                    const kept = ReadableStream.from([1, 2]);
+                    for await (const _ of kept.values({ preventCancel: true })) {
+                        break;
+                    }
+                    assert(!kept.locked);
+                    assertEq((await kept.getReader().read()).value, 2);
+
+                    assertThrows(() => ReadableStream.from(42));
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn writable_stream() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const written = [];
+                let closed = false;
+                const stream = new WritableStream(
+                    {
+                        write(chunk) {
+                            written.push(chunk);
+                        },
+                        close() {
+                            closed = true;
+                        },
+                    },
+                    new CountQueuingStrategy({ highWaterMark: 2 }),
+                );
+
+                const writer = stream.getWriter();
+                assert(stream.locked);
+                assertEq(writer.desiredSize, 2);
+                assertThrows(() => stream.getWriter());
+
+                globalThis.result = (async () => {
+                    await writer.ready;
+                    await writer.write("a");
+                    await writer.write("b");
+                    await writer.close();
+                    await writer.closed;
+                    assertArrayEqual(written, ["a", "b"]);
+                    assert(closed);
+
+                    let aborted;
+                    let signal;
+                    const abortable = new WritableStream({
+                        start(controller) {
+                            signal = controller.signal;
+                        },
+                        abort(reason) {
+                            aborted = reason;
+                        },
+                    });
+                    await abortable.abort("stop");
+                    assertEq(aborted, "stop");
+                    assert(signal.aborted);
+                    assertEq(signal.reason, "stop");
+
+                    const failing = new WritableStream({
+                        write() {
+                            throw new RangeError("nope");
+                        },
+                    });
+                    const failingWriter = failing.getWriter();
+                    try {
+                        await failingWriter.write("x");
+                        throw new Error("write should reject");
+                    } catch (e) {
+                        assert(e instanceof RangeError);
+                    }
+                    try {
+                        await failingWriter.closed;
+                        throw new Error("closed should reject");
+                    } catch (e) {
+                        assert(e instanceof RangeError);
+                    }
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn transform_and_pipe() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const upper = new TransformStream({
+                    transform(chunk, controller) {
+                        controller.enqueue(chunk.toUpperCase());
+                    },
+                    flush(controller) {
+                        controller.enqueue("!");
+                    },
+                });
+                assertEq(upper.readable, upper.readable);
+                assertEq(upper.writable, upper.writable);
+
+                globalThis.result = (async () => {
+                    const chunks = [];
+                    const sink = new WritableStream({
+                        write(chunk) {
+                            chunks.push(chunk);
+                        },
+                    });
+                    await ReadableStream.from(["a", "b"]).pipeThrough(upper).pipeTo(sink);
+                    assertArrayEqual(chunks, ["A", "B", "!"]);
+
+                    const identity = new TransformStream();
+                    const writer = identity.writable.getWriter();
+                    writer.write(1);
+                    writer.close();
+                    const values = [];
+                    for await (const value of identity.readable) {
+                        values.push(value);
+                    }
+                    assertArrayEqual(values, [1]);
+
+                    let cancelReason;
+                    const source = new ReadableStream({
+                        cancel(reason) {
+                            cancelReason = reason;
+                        },
+                    });
+                    const destination = new WritableStream({
+                        start(controller) {
+                            controller.error("broken");
+                        },
+                    });
+                    try {
+                        await source.pipeTo(destination);
+                        throw new Error("pipeTo should reject");
+                    } catch (e) {
+                        assertEq(e, "broken");
+                    }
+                    assertEq(cancelReason, "broken");
+
+                    const controller = new AbortController();
+                    const endless = new ReadableStream({
+                        pull(c) {
+                            c.enqueue(0);
+                        },
+                    });
+                    const piped = endless.pipeTo(new WritableStream(), { signal: controller.signal });
+                    controller.abort("aborted");
+                    try {
+                        await piped;
+                        throw new Error("pipeTo should reject");
+                    } catch (e) {
+                        assertEq(e, "aborted");
+                    }
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
+
+#[test]
+fn queuing_strategies() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                const bytes = new ByteLengthQueuingStrategy({ highWaterMark: 16 });
+                assertEq(bytes.highWaterMark, 16);
+                assertEq(bytes.size(new Uint8Array(5)), 5);
+                assertEq(bytes.size, new ByteLengthQueuingStrategy({ highWaterMark: 1 }).size);
+
+                const count = new CountQueuingStrategy({ highWaterMark: 3 });
+                assertEq(count.highWaterMark, 3);
+                assertEq(count.size("anything"), 1);
+                assertThrows(() => new CountQueuingStrategy());
+
+                let controller;
+                new ReadableStream(
+                    {
+                        start(c) {
+                            controller = c;
+                        },
+                    },
+                    bytes,
+                );
+                assertEq(controller.desiredSize, 16);
+                controller.enqueue(new Uint8Array(10));
+                assertEq(controller.desiredSize, 6);
+
+                assertThrows(() => new ReadableStream({}, { highWaterMark: -1 }));
+                assertThrows(() => new ReadableStream({ type: "bytes" }, { size: () => 1 }));
+            "#,
+        ),
+    ]);
+}
+
+#[test]
+fn compression_streams() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(
+            r#"
+                async function collect(readable) {
+                    const bytes = [];
+                    for await (const chunk of readable) {
+                        assert(chunk instanceof Uint8Array);
+                        bytes.push(...chunk);
+                    }
+                    return bytes;
+                }
+
+                async function transform(stream, chunks) {
+                    const writer = stream.writable.getWriter();
+                    for (const chunk of chunks) {
+                        writer.write(chunk).catch(() => {});
+                    }
+                    writer.close().catch(() => {});
+                    return collect(stream.readable);
+                }
+
+                async function rejects(promise) {
+                    try {
+                        await promise;
+                    } catch (e) {
+                        assert(e instanceof TypeError);
+                        return;
+                    }
+                    throw new Error("the promise should reject");
+                }
+
+                assertThrows(() => new CompressionStream("brotli"));
+                assertThrows(() => new DecompressionStream());
+
+                globalThis.result = (async () => {
+                    const input = new Uint8Array(20000).map((_, i) => (i * 7) % 251);
+                    for (const format of ["gzip", "deflate", "deflate-raw"]) {
+                        const compressed = await transform(new CompressionStream(format), [
+                            input.subarray(0, 10000),
+                            input.slice(10000).buffer,
+                        ]);
+                        assert(compressed.length < input.length);
+                        // Decompress one byte at a time.
+                        const decompressed = await transform(
+                            new DecompressionStream(format),
+                            compressed.map((byte) => Uint8Array.of(byte)),
+                        );
+                        assertArrayEqual(decompressed, [...input]);
+                    }
+
+                    const hello = [0x68, 0x65, 0x6C, 0x6C, 0x6F];
+                    const zlib = [0x78, 0x9C, 0xCB, 0x48, 0xCD, 0xC9, 0xC9, 0x07, 0x00, 0x06, 0x2C, 0x02, 0x15];
+                    assertArrayEqual(
+                        await transform(new DecompressionStream("deflate"), [Uint8Array.from(zlib)]),
+                        hello,
+                    );
+                    assertArrayEqual(
+                        await transform(new DecompressionStream("deflate-raw"), [Uint8Array.from(zlib.slice(2, -4))]),
+                        hello,
+                    );
+
+                    const gzip = await transform(new CompressionStream("gzip"), [Uint8Array.from(hello)]);
+                    assertArrayEqual(gzip.slice(0, 3), [0x1F, 0x8B, 0x08]);
+
+                    for (const [format, bytes] of [
+                        ["deflate", [1, 2, 3, 4]],
+                        ["deflate", zlib.slice(0, -2)],
+                        ["deflate", [...zlib, 0]],
+                        ["gzip", gzip.slice(0, -1)],
+                        ["gzip", [...gzip, 0]],
+                    ]) {
+                        await rejects(transform(new DecompressionStream(format), [Uint8Array.from(bytes)]));
+                    }
+                    await rejects(transform(new CompressionStream("gzip"), ["not a buffer"]));
+                })();
+            "#,
+        ),
+        await_result(),
+    ]);
+}
@@ -0,0 +1,680 @@
+//! The `TransformStream` JavaScript class, implemented as [`JsTransformStream`], and its
+//! controller.
+//!
+//! See <https://streams.spec.whatwg.org/#ts-model>
+
+use super::readable::{Controller, JsReadableStream, ReadableState, illegal_constructor};
+use super::writable::{JsWritableStream, WritableState};
+use super::{
+    Algorithm, Deferred, QueuingStrategy, SizeAlgorithm, create, react, rejected_with, resolved,
+    type_error,
+};
+use crate::stream::JsReadableStreamDefaultController;
+use boa_engine::gc::{Gc, GcRefCell};
+use boa_engine::interop::JsClass;
+use boa_engine::object::builtins::JsPromise;
+use boa_engine::{
+    Context, Finalize, JsData, JsError, JsObject, JsResult, JsValue, Trace, boa_class, js_error,
+    js_string,
+};
+
+/// A transformer implemented in Rust, for the transform streams created by the runtime.
+pub(crate) trait NativeTransformer: Trace + Clone + 'static {
+    /// Transforms `chunk`, enqueueing the results with `controller`.
+    fn transform(
+        &self,
+        chunk: JsValue,
+        controller: &JsTransformStreamDefaultController,
+        context: &mut Context,
+    ) -> JsResult<()>;
+
+    /// Enqueues the remaining results once the writable side is closed.
+    fn flush(
+        &self,
+        controller: &JsTransformStreamDefaultController,
+        context: &mut Context,
+    ) -> JsResult<()>;
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct TransformStreamSlots {
+    backpressure: bool,
+    backpressure_change_promise: Option<Deferred>,
+    controller: Option<JsTransformStreamDefaultController>,
+    readable: Option<JsObject<JsReadableStream>>,
+    writable: Option<JsObject<JsWritableStream>>,
+}
+
+/// The JavaScript `TransformStream` class.
+///
+/// See <https://streams.spec.whatwg.org/#ts-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsTransformStream {
+    slots: Gc<GcRefCell<TransformStreamSlots>>,
+}
+
+impl JsTransformStream {
+    /// Creates the readable and writable sides of a transform stream.
+    ///
+    /// See <https://streams.spec.whatwg.org/#initialize-transform-stream>
+    fn initialize(
+        start_promise: &JsPromise,
+        [writable_high_water_mark, readable_high_water_mark]: [f64; 2],
+        [writable_size, readable_size]: [SizeAlgorithm; 2],
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let stream = Self {
+            slots: Gc::new(GcRefCell::new(TransformStreamSlots {
+                backpressure: false,
+                backpressure_change_promise: None,
+                controller: None,
+                readable: None,
+                writable: None,
+            })),
+        };
+        let start = Algorithm::native(
+            start_promise,
+            |start_promise, _, _| Ok(start_promise.clone().into()),
+            context,
+        );
+
+        let write = Algorithm::native(
+            &stream,
+            |stream, chunk, context| stream.sink_write(chunk, context).map(Into::into),
+            context,
+        );
+        let close = Algorithm::native(
+            &stream,
+            |stream, _, context| stream.sink_close(context).map(Into::into),
+            context,
+        );
+        let abort = Algorithm::native(
+            &stream,
+            |stream, reason, context| stream.sink_abort(reason, context).map(Into::into),
+            context,
+        );
+        let writable = JsWritableStream::create(
+            &start,
+            write,
+            close,
+            abort,
+            writable_high_water_mark,
+            writable_size,
+            context,
+        )?;
+
+        let pull = Algorithm::native(
+            &stream,
+            |stream, _, context| stream.source_pull(context).map(Into::into),
+            context,
+        );
+        let cancel = Algorithm::native(
+            &stream,
+            |stream, reason, context| stream.source_cancel(reason, context).map(Into::into),
+            context,
+        );
+        let readable = JsReadableStream::create(
+            &start,
+            pull,
+            cancel,
+            readable_high_water_mark,
+            readable_size,
+            context,
+        )?;
+
+        let readable = create(readable, context)?;
+        let writable = create(writable, context)?;
+        {
+            let mut slots = stream.slots.borrow_mut();
+            slots.readable = Some(readable);
+            slots.writable = Some(writable);
+        }
+        stream.set_backpressure(true, context)?;
+        Ok(stream)
+    }
+
+    /// Creates a transform stream from the `transformer` and strategies given to the
+    /// constructor.
+    fn from_transformer(
+        transformer: Option<&JsObject>,
+        writable_strategy: &QueuingStrategy,
+        readable_strategy: &QueuingStrategy,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        // Dictionary members are read in lexicographical order.
+        let (cancel, flush, start, transform) = match transformer {
+            Some(transformer) => {
+                let cancel = Algorithm::method(transformer, "cancel", context)?;
+                let flush = Algorithm::method(transformer, "flush", context)?;
+                let readable_type = transformer.get(js_string!("readableType"), context)?;
+                let start = Algorithm::method(transformer, "start", context)?;
+                let transform = Algorithm::method(transformer, "transform", context)?;
+                let writable_type = transformer.get(js_string!("writableType"), context)?;
+                if !readable_type.is_undefined() {
+                    return Err(js_error!(RangeError: "readableType must be undefined"));
+                }
+                if !writable_type.is_undefined() {
+                    return Err(js_error!(RangeError: "writableType must be undefined"));
+                }
+                (cancel, flush, start, transform)
+            }
+            None => (
+                Algorithm::none(),
+                Algorithm::none(),
+                Algorithm::none(),
+                Algorithm::none(),
+            ),
+        };
+        let readable_high_water_mark = readable_strategy.high_water_mark(0.0)?;
+        let readable_size = readable_strategy.size_algorithm();
+        let writable_high_water_mark = writable_strategy.high_water_mark(1.0)?;
+        let writable_size = writable_strategy.size_algorithm();
+
+        let start_promise = Deferred::new(context);
+        let stream = Self::initialize(
+            &start_promise.promise(),
+            [writable_high_water_mark, readable_high_water_mark],
+            [writable_size, readable_size],
+            context,
+        )?;
+        let controller =
+            JsTransformStreamDefaultController::set_up(&stream, transform, flush, cancel, context);
+        let object = controller.object(context)?;
+        let start_result = start.call(&[object.into()], context)?;
+        start_promise.resolve(start_result, context)?;
+        Ok(stream)
+    }
+
+    /// Creates a transform stream running the algorithms of `transformer`.
+    ///
+    /// See <https://streams.spec.whatwg.org/#transformstream-set-up>
+    pub(crate) fn from_native<T: NativeTransformer>(
+        transformer: T,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let start_promise = resolved(JsValue::undefined(), context)?;
+        let stream = Self::initialize(
+            &start_promise,
+            [1.0, 0.0],
+            [SizeAlgorithm::default(), SizeAlgorithm::default()],
+            context,
+        )?;
+        let captures = (transformer, stream.clone());
+        let transform = Algorithm::native(
+            &captures,
+            |(transformer, stream), chunk, context| {
+                transformer.transform(chunk, &stream.controller(), context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        );
+        let flush = Algorithm::native(
+            &captures,
+            |(transformer, stream), _, context| {
+                transformer.flush(&stream.controller(), context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        );
+        JsTransformStreamDefaultController::set_up(
+            &stream,
+            transform,
+            flush,
+            Algorithm::none(),
+            context,
+        );
+        Ok(stream)
+    }
+
+    /// Returns the readable side of the stream.
+    pub(crate) fn readable_stream(&self) -> JsObject<JsReadableStream> {
+        self.slots
+            .borrow()
+            .readable
+            .clone()
+            .expect("the readable side is created with the stream")
+    }
+
+    /// Returns the writable side of the stream.
+    pub(crate) fn writable_stream(&self) -> JsObject<JsWritableStream> {
+        self.slots
+            .borrow()
+            .writable
+            .clone()
+            .expect("the writable side is created with the stream")
+    }
+
+    fn readable(&self) -> JsReadableStream {
+        self.slots
+            .borrow()
+            .readable
+            .as_ref()
+            .map(|readable| readable.borrow().data().clone())
+            .expect("the readable side is created with the stream")
+    }
+
+    fn writable(&self) -> JsWritableStream {
+        self.slots
+            .borrow()
+            .writable
+            .as_ref()
+            .map(|writable| writable.borrow().data().clone())
+            .expect("the writable side is created with the stream")
+    }
+
+    fn controller(&self) -> JsTransformStreamDefaultController {
+        self.slots
+            .borrow()
+            .controller
+            .clone()
+            .expect("the controller is set up with the stream")
+    }
+
+    /// Returns the default controller of the readable side.
+    fn readable_controller(&self) -> JsReadableStreamDefaultController {
+        match self.readable().controller() {
+            Controller::Default(controller) => JsReadableStreamDefaultController::of(&controller),
+            Controller::Byte(_) => {
+                unreachable!("the readable side of a transform stream is not a byte stream")
+            }
+        }
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-error>
+    fn error(&self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        self.readable_controller().error(error.clone(), context)?;
+        self.error_writable_and_unblock_write(error, context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-error-writable-and-unblock-write>
+    fn error_writable_and_unblock_write(
+        &self,
+        error: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        self.controller().clear_algorithms();
+        self.writable()
+            .controller()
+            .error_if_needed(error, context)?;
+        self.unblock_write(context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-unblock-write>
+    fn unblock_write(&self, context: &mut Context) -> JsResult<()> {
+        if self.slots.borrow().backpressure {
+            self.set_backpressure(false, context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-set-backpressure>
+    fn set_backpressure(&self, backpressure: bool, context: &mut Context) -> JsResult<()> {
+        let previous = {
+            let mut slots = self.slots.borrow_mut();
+            slots.backpressure = backpressure;
+            slots
+                .backpressure_change_promise
+                .replace(Deferred::new(context))
+        };
+        if let Some(previous) = previous {
+            previous.resolve(JsValue::undefined(), context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-default-sink-write-algorithm>
+    fn sink_write(&self, chunk: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        let (backpressure, change) = {
+            let slots = self.slots.borrow();
+            (
+                slots.backpressure,
+                slots.backpressure_change_promise.clone(),
+            )
+        };
+        let controller = self.controller();
+        if let (true, Some(change)) = (backpressure, change) {
+            return react(
+                &change.promise(),
+                &(self.clone(), controller, chunk),
+                Some(|(stream, controller, chunk), _, context| {
+                    let writable = stream.writable();
+                    if writable.state() == WritableState::Erroring {
+                        return Err(JsError::from_opaque(writable.stored_error()));
+                    }
+                    controller
+                        .perform_transform(chunk.clone(), context)
+                        .map(Into::into)
+                }),
+                None,
+                context,
+            );
+        }
+        controller.perform_transform(chunk, context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-default-sink-abort-algorithm>
+    fn sink_abort(&self, reason: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        let controller = self.controller();
+        if let Some(finish) = controller.finish_promise() {
+            return Ok(finish.promise());
+        }
+        let finish = controller.start_finishing(context);
+        let cancel = controller.slots.borrow().cancel.clone();
+        let cancel_promise = cancel.call_promise(std::slice::from_ref(&reason), context)?;
+        controller.clear_algorithms();
+        react(
+            &cancel_promise,
+            &(self.clone(), finish.clone(), reason),
+            Some(|(stream, finish, reason), _, context| {
+                let readable = stream.readable();
+                if readable.state() == ReadableState::Errored {
+                    finish.reject(readable.stored_error(), context)?;
+                } else {
+                    stream
+                        .readable_controller()
+                        .error(reason.clone(), context)?;
+                    finish.resolve(JsValue::undefined(), context)?;
+                }
+                Ok(JsValue::undefined())
+            }),
+            Some(|(stream, finish, _), error, context| {
+                stream.readable_controller().error(error.clone(), context)?;
+                finish.reject(error, context)?;
+                Ok(JsValue::undefined())
+            }),
+            context,
+        )?;
+        Ok(finish.promise())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-default-sink-close-algorithm>
+    fn sink_close(&self, context: &mut Context) -> JsResult<JsPromise> {
+        let controller = self.controller();
+        if let Some(finish) = controller.finish_promise() {
+            return Ok(finish.promise());
+        }
+        let finish = controller.start_finishing(context);
+        let flush = controller.slots.borrow().flush.clone();
+        let object = controller.object(context)?;
+        let flush_promise = flush.call_promise(&[object.into()], context)?;
+        controller.clear_algorithms();
+        react(
+            &flush_promise,
+            &(self.clone(), finish.clone()),
+            Some(|(stream, finish), _, context| {
+                let readable = stream.readable();
+                if readable.state() == ReadableState::Errored {
+                    finish.reject(readable.stored_error(), context)?;
+                } else {
+                    stream.readable_controller().close(context)?;
+                    finish.resolve(JsValue::undefined(), context)?;
+                }
+                Ok(JsValue::undefined())
+            }),
+            Some(|(stream, finish), error, context| {
+                stream.readable_controller().error(error.clone(), context)?;
+                finish.reject(error, context)?;
+                Ok(JsValue::undefined())
+            }),
+            context,
+        )?;
+        Ok(finish.promise())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-default-source-pull>
+    fn source_pull(&self, context: &mut Context) -> JsResult<JsPromise> {
+        self.set_backpressure(false, context)?;
+        let change = self
+            .slots
+            .borrow()
+            .backpressure_change_promise
+            .clone()
+            .expect("set_backpressure creates a promise");
+        Ok(change.promise())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-default-source-cancel>
+    fn source_cancel(&self, reason: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        let controller = self.controller();
+        if let Some(finish) = controller.finish_promise() {
+            return Ok(finish.promise());
+        }
+        let finish = controller.start_finishing(context);
+        let cancel = controller.slots.borrow().cancel.clone();
+        let cancel_promise = cancel.call_promise(std::slice::from_ref(&reason), context)?;
+        controller.clear_algorithms();
+        react(
+            &cancel_promise,
+            &(self.clone(), finish.clone(), reason),
+            Some(|(stream, finish, reason), _, context| {
+                let writable = stream.writable();
+                if writable.state() == WritableState::Errored {
+                    finish.reject(writable.stored_error(), context)?;
+                } else {
+                    writable
+                        .controller()
+                        .error_if_needed(reason.clone(), context)?;
+                    stream.unblock_write(context)?;
+                    finish.resolve(JsValue::undefined(), context)?;
+                }
+                Ok(JsValue::undefined())
+            }),
+            Some(|(stream, finish, _), error, context| {
+                stream
+                    .writable()
+                    .controller()
+                    .error_if_needed(error.clone(), context)?;
+                stream.unblock_write(context)?;
+                finish.reject(error, context)?;
+                Ok(JsValue::undefined())
+            }),
+            context,
+        )?;
+        Ok(finish.promise())
+    }
+}
+
+#[boa_class(rename = "TransformStream")]
+#[boa(rename_all = "camelCase")]
+impl JsTransformStream {
+    #[boa(constructor)]
+    fn constructor(
+        transformer: Option<JsObject>,
+        writable_strategy: Option<QueuingStrategy>,
+        readable_strategy: Option<QueuingStrategy>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        Self::from_transformer(
+            transformer.as_ref(),
+            &writable_strategy.unwrap_or_default(),
+            &readable_strategy.unwrap_or_default(),
+            context,
+        )
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "readable")]
+    fn readable_(&self) -> JsObject {
+        self.readable_stream().upcast()
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "writable")]
+    fn writable_(&self) -> JsObject {
+        self.writable_stream().upcast()
+    }
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct TransformControllerSlots {
+    stream: JsTransformStream,
+    object: Option<JsObject<JsTransformStreamDefaultController>>,
+    cancel: Algorithm,
+    flush: Algorithm,
+    transform: Algorithm,
+    finish_promise: Option<Deferred>,
+}
+
+/// The JavaScript `TransformStreamDefaultController` class.
+///
+/// See <https://streams.spec.whatwg.org/#ts-default-controller-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsTransformStreamDefaultController {
+    slots: Gc<GcRefCell<TransformControllerSlots>>,
+}
+
+impl JsTransformStreamDefaultController {
+    /// Sets up the controller of `stream`. A missing `transform` enqueues chunks unchanged.
+    ///
+    /// See <https://streams.spec.whatwg.org/#set-up-transform-stream-default-controller>
+    fn set_up(
+        stream: &JsTransformStream,
+        transform: Algorithm,
+        flush: Algorithm,
+        cancel: Algorithm,
+        context: &mut Context,
+    ) -> Self {
+        let controller = Self {
+            slots: Gc::new(GcRefCell::new(TransformControllerSlots {
+                stream: stream.clone(),
+                object: None,
+                cancel,
+                flush,
+                transform: Algorithm::none(),
+                finish_promise: None,
+            })),
+        };
+        let transform = if transform.is_none() {
+            Algorithm::native(
+                &controller,
+                |controller, chunk, context| {
+                    let result = match controller.enqueue(chunk, context) {
+                        Ok(()) => resolved(JsValue::undefined(), context),
+                        Err(error) => rejected_with(error, context),
+                    };
+                    result.map(Into::into)
+                },
+                context,
+            )
+        } else {
+            transform
+        };
+        controller.slots.borrow_mut().transform = transform;
+        stream.slots.borrow_mut().controller = Some(controller.clone());
+        controller
+    }
+
+    /// Returns the JavaScript object of the controller, creating it if needed.
+    fn object(&self, context: &mut Context) -> JsResult<JsObject> {
+        if let Some(object) = &self.slots.borrow().object {
+            return Ok(object.clone().upcast());
+        }
+        let object = create(self.clone(), context)?;
+        self.slots.borrow_mut().object = Some(object.clone());
+        Ok(object.upcast())
+    }
+
+    fn stream(&self) -> JsTransformStream {
+        self.slots.borrow().stream.clone()
+    }
+
+    fn finish_promise(&self) -> Option<Deferred> {
+        self.slots.borrow().finish_promise.clone()
+    }
+
+    fn start_finishing(&self, context: &mut Context) -> Deferred {
+        let finish = Deferred::new(context);
+        self.slots.borrow_mut().finish_promise = Some(finish.clone());
+        finish
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-default-controller-clear-algorithms>
+    fn clear_algorithms(&self) {
+        let mut slots = self.slots.borrow_mut();
+        slots.transform = Algorithm::none();
+        slots.flush = Algorithm::none();
+        slots.cancel = Algorithm::none();
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-default-controller-enqueue>
+    pub(crate) fn enqueue(&self, chunk: JsValue, context: &mut Context) -> JsResult<()> {
+        let stream = self.stream();
+        let readable_controller = stream.readable_controller();
+        if !readable_controller.can_close_or_enqueue() {
+            return Err(js_error!(TypeError: "the readable side cannot be enqueued to"));
+        }
+        if let Err(error) = readable_controller.enqueue(chunk, context) {
+            let error = error.into_opaque(context)?;
+            stream.error_writable_and_unblock_write(error, context)?;
+            return Err(JsError::from_opaque(stream.readable().stored_error()));
+        }
+        let backpressure = !readable_controller.should_call_pull();
+        if backpressure != stream.slots.borrow().backpressure {
+            stream.set_backpressure(true, context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-default-controller-perform-transform>
+    fn perform_transform(&self, chunk: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        let transform = self.slots.borrow().transform.clone();
+        let object = self.object(context)?;
+        let transform_promise = transform.call_promise(&[chunk, object.into()], context)?;
+        react(
+            &transform_promise,
+            &self.stream(),
+            None,
+            Some(|stream, reason, context| {
+                stream.error(reason.clone(), context)?;
+                Err(JsError::from_opaque(reason))
+            }),
+            context,
+        )
+    }
+
+    /// See <https://streams.spec.whatwg.org/#transform-stream-default-controller-terminate>
+    fn terminate(&self, context: &mut Context) -> JsResult<()> {
+        let stream = self.stream();
+        stream.readable_controller().close(context)?;
+        let error = type_error("the transform stream has been terminated", context);
+        stream.error_writable_and_unblock_write(error, context)
+    }
+}
+
+#[boa_class(rename = "TransformStreamDefaultController")]
+#[boa(rename_all = "camelCase")]
+impl JsTransformStreamDefaultController {
+    #[boa(constructor)]
+    fn constructor() -> JsResult<Self> {
+        Err(illegal_constructor())
+    }
+
+    #[boa(getter)]
+    fn desired_size(&self) -> Option<f64> {
+        self.stream().readable_controller().desired_size()
+    }
+
+    #[boa(method)]
+    #[boa(rename = "enqueue")]
+    fn enqueue_(
+        this: JsClass<Self>,
+        chunk: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        this.clone_inner()
+            .enqueue(chunk.unwrap_or_default(), context)
+    }
+
+    #[boa(method)]
+    fn error(this: JsClass<Self>, reason: Option<JsValue>, context: &mut Context) -> JsResult<()> {
+        this.clone_inner()
+            .stream()
+            .error(reason.unwrap_or_default(), context)
+    }
+
+    #[boa(method)]
+    #[boa(rename = "terminate")]
+    fn terminate_(this: JsClass<Self>, context: &mut Context) -> JsResult<()> {
+        this.clone_inner().terminate(context)
+    }
+}
@@ -0,0 +1,1051 @@
+//! The `WritableStream` JavaScript class, implemented as [`JsWritableStream`], along with its
+//! writer and controller.
+//!
+//! See <https://streams.spec.whatwg.org/#ws-model>
+
+use super::readable::{illegal_constructor, promise_or_rejected};
+use super::{
+    Algorithm, Deferred, QueueWithSizes, QueuingStrategy, SizeAlgorithm, create, mark_handled,
+    rejected, resolved, type_error, upon,
+};
+use crate::abort::JsAbortSignal;
+use boa_engine::gc::{Gc, GcRefCell};
+use boa_engine::interop::JsClass;
+use boa_engine::object::builtins::JsPromise;
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsValue, Trace, boa_class, js_error, js_string,
+};
+use std::collections::VecDeque;
+
+/// The state of a writable stream.
+///
+/// See <https://streams.spec.whatwg.org/#writablestream-state>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Trace, Finalize)]
+#[boa_gc(empty_trace)]
+pub(crate) enum WritableState {
+    Writable,
+    Closed,
+    Erroring,
+    Errored,
+}
+
+/// A pending call to `abort()`.
+///
+/// See <https://streams.spec.whatwg.org/#pending-abort-request>
+#[derive(Debug, Clone, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+struct PendingAbortRequest {
+    promise: Deferred,
+    reason: JsValue,
+    was_already_erroring: bool,
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct WritableStreamSlots {
+    state: WritableState,
+    stored_error: JsValue,
+    backpressure: bool,
+    writer: Option<JsWritableStreamDefaultWriter>,
+    controller: Option<JsObject<JsWritableStreamDefaultController>>,
+    close_request: Option<Deferred>,
+    in_flight_write_request: Option<Deferred>,
+    in_flight_close_request: Option<Deferred>,
+    pending_abort_request: Option<PendingAbortRequest>,
+    write_requests: VecDeque<Deferred>,
+}
+
+/// The JavaScript `WritableStream` class.
+///
+/// See <https://streams.spec.whatwg.org/#ws-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsWritableStream {
+    slots: Gc<GcRefCell<WritableStreamSlots>>,
+}
+
+impl JsWritableStream {
+    /// Creates a writable stream with no controller.
+    ///
+    /// See <https://streams.spec.whatwg.org/#initialize-writable-stream>
+    fn initialize() -> Self {
+        Self {
+            slots: Gc::new(GcRefCell::new(WritableStreamSlots {
+                state: WritableState::Writable,
+                stored_error: JsValue::undefined(),
+                backpressure: false,
+                writer: None,
+                controller: None,
+                close_request: None,
+                in_flight_write_request: None,
+                in_flight_close_request: None,
+                pending_abort_request: None,
+                write_requests: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// Returns the stream of a `WritableStream` object.
+    pub(crate) fn of(object: &JsObject) -> Option<Self> {
+        object.downcast_ref::<Self>().map(|stream| stream.clone())
+    }
+
+    /// Creates a writable stream whose underlying sink is made of `start`, `write`, `close`
+    /// and `abort`.
+    ///
+    /// See <https://streams.spec.whatwg.org/#create-writable-stream>
+    pub(crate) fn create(
+        start: &Algorithm,
+        write: Algorithm,
+        close: Algorithm,
+        abort: Algorithm,
+        high_water_mark: f64,
+        size: SizeAlgorithm,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let stream = Self::initialize();
+        JsWritableStreamDefaultController::set_up(
+            &stream,
+            start,
+            [write, close, abort],
+            high_water_mark,
+            size,
+            context,
+        )?;
+        Ok(stream)
+    }
+
+    pub(crate) fn state(&self) -> WritableState {
+        self.slots.borrow().state
+    }
+
+    pub(crate) fn stored_error(&self) -> JsValue {
+        self.slots.borrow().stored_error.clone()
+    }
+
+    pub(crate) fn controller(&self) -> JsWritableStreamDefaultController {
+        self.slots
+            .borrow()
+            .controller
+            .as_ref()
+            .map(|controller| controller.borrow().data().clone())
+            .expect("a writable stream must have a controller once it is set up")
+    }
+
+    fn controller_object(&self) -> JsObject {
+        self.slots
+            .borrow()
+            .controller
+            .clone()
+            .expect("a writable stream must have a controller once it is set up")
+            .upcast()
+    }
+
+    /// Returns `true` if the stream is locked to a writer.
+    ///
+    /// See <https://streams.spec.whatwg.org/#is-writable-stream-locked>
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        self.slots.borrow().writer.is_some()
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-abort>
+    pub(crate) fn abort(&self, reason: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        if matches!(self.state(), WritableState::Closed | WritableState::Errored) {
+            return resolved(JsValue::undefined(), context);
+        }
+        let signal = self.controller().signal();
+        JsAbortSignal::from_object(&signal)?.signal_abort(reason.clone(), context)?;
+
+        let state = self.state();
+        if matches!(state, WritableState::Closed | WritableState::Errored) {
+            return resolved(JsValue::undefined(), context);
+        }
+        if let Some(request) = &self.slots.borrow().pending_abort_request {
+            return Ok(request.promise.promise());
+        }
+        let was_already_erroring = state == WritableState::Erroring;
+        let reason = if was_already_erroring {
+            JsValue::undefined()
+        } else {
+            reason
+        };
+        let promise = Deferred::new(context);
+        self.slots.borrow_mut().pending_abort_request = Some(PendingAbortRequest {
+            promise: promise.clone(),
+            reason: reason.clone(),
+            was_already_erroring,
+        });
+        if !was_already_erroring {
+            self.start_erroring(reason, context)?;
+        }
+        Ok(promise.promise())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-close>
+    pub(crate) fn close(&self, context: &mut Context) -> JsResult<JsPromise> {
+        let state = self.state();
+        if matches!(state, WritableState::Closed | WritableState::Errored) {
+            return rejected(
+                type_error("the stream is closed or errored", context),
+                context,
+            );
+        }
+        let promise = Deferred::new(context);
+        let (writer, backpressure) = {
+            let mut slots = self.slots.borrow_mut();
+            slots.close_request = Some(promise.clone());
+            (slots.writer.clone(), slots.backpressure)
+        };
+        if let Some(writer) = writer
+            && backpressure
+            && state == WritableState::Writable
+        {
+            writer.ready().resolve(JsValue::undefined(), context)?;
+        }
+        self.controller().close(context)?;
+        Ok(promise.promise())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-close-queued-or-in-flight>
+    pub(crate) fn close_queued_or_in_flight(&self) -> bool {
+        let slots = self.slots.borrow();
+        slots.close_request.is_some() || slots.in_flight_close_request.is_some()
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-deal-with-rejection>
+    fn deal_with_rejection(&self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        if self.state() == WritableState::Writable {
+            return self.start_erroring(error, context);
+        }
+        self.finish_erroring(context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-start-erroring>
+    fn start_erroring(&self, reason: JsValue, context: &mut Context) -> JsResult<()> {
+        let writer = {
+            let mut slots = self.slots.borrow_mut();
+            slots.state = WritableState::Erroring;
+            slots.stored_error = reason.clone();
+            slots.writer.clone()
+        };
+        if let Some(writer) = writer {
+            writer.ensure_ready_promise_rejected(reason, context)?;
+        }
+        if !self.has_operation_marked_in_flight() && self.controller().started() {
+            self.finish_erroring(context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-finish-erroring>
+    fn finish_erroring(&self, context: &mut Context) -> JsResult<()> {
+        let (stored_error, write_requests) = {
+            let mut slots = self.slots.borrow_mut();
+            slots.state = WritableState::Errored;
+            (
+                slots.stored_error.clone(),
+                std::mem::take(&mut slots.write_requests),
+            )
+        };
+        self.controller().error_steps();
+        for request in write_requests {
+            request.reject(stored_error.clone(), context)?;
+        }
+
+        let abort_request = self.slots.borrow_mut().pending_abort_request.take();
+        let Some(abort_request) = abort_request else {
+            return self.reject_close_and_closed_promise_if_needed(context);
+        };
+        if abort_request.was_already_erroring {
+            abort_request.promise.reject(stored_error, context)?;
+            return self.reject_close_and_closed_promise_if_needed(context);
+        }
+        let promise = self
+            .controller()
+            .abort_steps(abort_request.reason, context)?;
+        upon(
+            &promise,
+            &(self.clone(), abort_request.promise),
+            |(stream, promise), _, context| {
+                promise.resolve(JsValue::undefined(), context)?;
+                stream.reject_close_and_closed_promise_if_needed(context)?;
+                Ok(JsValue::undefined())
+            },
+            |(stream, promise), reason, context| {
+                promise.reject(reason, context)?;
+                stream.reject_close_and_closed_promise_if_needed(context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        )
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-finish-in-flight-close>
+    fn finish_in_flight_close(&self, context: &mut Context) -> JsResult<()> {
+        let (close_request, abort_request, writer) = {
+            let mut slots = self.slots.borrow_mut();
+            let close_request = slots.in_flight_close_request.take();
+            let abort_request = if slots.state == WritableState::Erroring {
+                slots.stored_error = JsValue::undefined();
+                slots.pending_abort_request.take()
+            } else {
+                None
+            };
+            slots.state = WritableState::Closed;
+            (close_request, abort_request, slots.writer.clone())
+        };
+        if let Some(request) = close_request {
+            request.resolve(JsValue::undefined(), context)?;
+        }
+        if let Some(request) = abort_request {
+            request.promise.resolve(JsValue::undefined(), context)?;
+        }
+        if let Some(writer) = writer {
+            writer.closed().resolve(JsValue::undefined(), context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-finish-in-flight-close-with-error>
+    fn finish_in_flight_close_with_error(
+        &self,
+        error: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let (close_request, abort_request) = {
+            let mut slots = self.slots.borrow_mut();
+            (
+                slots.in_flight_close_request.take(),
+                slots.pending_abort_request.take(),
+            )
+        };
+        if let Some(request) = close_request {
+            request.reject(error.clone(), context)?;
+        }
+        if let Some(request) = abort_request {
+            request.promise.reject(error.clone(), context)?;
+        }
+        self.deal_with_rejection(error, context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-finish-in-flight-write>
+    fn finish_in_flight_write(&self, context: &mut Context) -> JsResult<()> {
+        let request = self.slots.borrow_mut().in_flight_write_request.take();
+        if let Some(request) = request {
+            request.resolve(JsValue::undefined(), context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-finish-in-flight-write-with-error>
+    fn finish_in_flight_write_with_error(
+        &self,
+        error: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let request = self.slots.borrow_mut().in_flight_write_request.take();
+        if let Some(request) = request {
+            request.reject(error.clone(), context)?;
+        }
+        self.deal_with_rejection(error, context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-has-operation-marked-in-flight>
+    fn has_operation_marked_in_flight(&self) -> bool {
+        let slots = self.slots.borrow();
+        slots.in_flight_write_request.is_some() || slots.in_flight_close_request.is_some()
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-reject-close-and-closed-promise-if-needed>
+    fn reject_close_and_closed_promise_if_needed(&self, context: &mut Context) -> JsResult<()> {
+        let (close_request, stored_error, writer) = {
+            let mut slots = self.slots.borrow_mut();
+            (
+                slots.close_request.take(),
+                slots.stored_error.clone(),
+                slots.writer.clone(),
+            )
+        };
+        if let Some(request) = close_request {
+            request.reject(stored_error.clone(), context)?;
+        }
+        if let Some(writer) = writer {
+            let closed = writer.closed();
+            closed.reject(stored_error, context)?;
+            mark_handled(&closed.promise(), context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-update-backpressure>
+    fn update_backpressure(&self, backpressure: bool, context: &mut Context) -> JsResult<()> {
+        let (writer, previous) = {
+            let mut slots = self.slots.borrow_mut();
+            let previous = std::mem::replace(&mut slots.backpressure, backpressure);
+            (slots.writer.clone(), previous)
+        };
+        if let Some(writer) = writer
+            && backpressure != previous
+        {
+            if backpressure {
+                writer.slots.borrow_mut().ready = Deferred::new(context);
+            } else {
+                writer.ready().resolve(JsValue::undefined(), context)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets up a stream from the `underlyingSink` and `strategy` given to the constructor.
+    fn set_up_from_underlying_sink(
+        &self,
+        underlying_sink: Option<&JsObject>,
+        strategy: &QueuingStrategy,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        // Dictionary members are read in lexicographical order.
+        let (abort, close, start, write) = match underlying_sink {
+            Some(sink) => {
+                let abort = Algorithm::method(sink, "abort", context)?;
+                let close = Algorithm::method(sink, "close", context)?;
+                let start = Algorithm::method(sink, "start", context)?;
+                if !sink.get(js_string!("type"), context)?.is_undefined() {
+                    return Err(
+                        js_error!(RangeError: "the type of an underlying sink must be undefined"),
+                    );
+                }
+                let write = Algorithm::method(sink, "write", context)?;
+                (abort, close, start, write)
+            }
+            None => (
+                Algorithm::none(),
+                Algorithm::none(),
+                Algorithm::none(),
+                Algorithm::none(),
+            ),
+        };
+        let size = strategy.size_algorithm();
+        let high_water_mark = strategy.high_water_mark(1.0)?;
+        JsWritableStreamDefaultController::set_up(
+            self,
+            &start,
+            [write, close, abort],
+            high_water_mark,
+            size,
+            context,
+        )
+    }
+}
+
+#[boa_class(rename = "WritableStream")]
+#[boa(rename_all = "camelCase")]
+impl JsWritableStream {
+    #[boa(constructor)]
+    fn constructor(
+        underlying_sink: Option<JsObject>,
+        strategy: Option<QueuingStrategy>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let stream = Self::initialize();
+        stream.set_up_from_underlying_sink(
+            underlying_sink.as_ref(),
+            &strategy.unwrap_or_default(),
+            context,
+        )?;
+        Ok(stream)
+    }
+
+    #[boa(getter)]
+    fn locked(&self) -> bool {
+        self.is_locked()
+    }
+
+    #[boa(method)]
+    #[boa(rename = "abort")]
+    fn abort_(
+        this: JsClass<Self>,
+        reason: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let stream = this.clone_inner();
+        if stream.is_locked() {
+            return rejected(
+                type_error("cannot abort a stream that is locked to a writer", context),
+                context,
+            );
+        }
+        let result = stream.abort(reason.unwrap_or_default(), context);
+        promise_or_rejected(result, context)
+    }
+
+    #[boa(method)]
+    #[boa(rename = "close")]
+    fn close_(this: JsClass<Self>, context: &mut Context) -> JsResult<JsPromise> {
+        let stream = this.clone_inner();
+        if stream.is_locked() {
+            return rejected(
+                type_error("cannot close a stream that is locked to a writer", context),
+                context,
+            );
+        }
+        if stream.close_queued_or_in_flight() {
+            return rejected(
+                type_error("the stream is already closing", context),
+                context,
+            );
+        }
+        let result = stream.close(context);
+        promise_or_rejected(result, context)
+    }
+
+    #[boa(method)]
+    fn get_writer(this: JsClass<Self>, context: &mut Context) -> JsResult<JsObject> {
+        let writer = JsWritableStreamDefaultWriter::acquire(&this.clone_inner(), context)?;
+        Ok(create(writer, context)?.upcast())
+    }
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct WriterSlots {
+    stream: Option<JsWritableStream>,
+    ready: Deferred,
+    closed: Deferred,
+}
+
+/// The JavaScript `WritableStreamDefaultWriter` class.
+///
+/// See <https://streams.spec.whatwg.org/#default-writer-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsWritableStreamDefaultWriter {
+    slots: Gc<GcRefCell<WriterSlots>>,
+}
+
+impl JsWritableStreamDefaultWriter {
+    /// Locks `stream` to a new writer.
+    ///
+    /// See <https://streams.spec.whatwg.org/#set-up-writable-stream-default-writer>
+    pub(crate) fn acquire(stream: &JsWritableStream, context: &mut Context) -> JsResult<Self> {
+        if stream.is_locked() {
+            return Err(js_error!(TypeError: "the stream is already locked to a writer"));
+        }
+        let (state, stored_error, backpressure) = {
+            let slots = stream.slots.borrow();
+            (slots.state, slots.stored_error.clone(), slots.backpressure)
+        };
+        let (ready, closed) = match state {
+            WritableState::Writable => {
+                let ready = if !stream.close_queued_or_in_flight() && backpressure {
+                    Deferred::new(context)
+                } else {
+                    Deferred::resolved(JsValue::undefined(), context)?
+                };
+                (ready, Deferred::new(context))
+            }
+            WritableState::Erroring => (
+                Deferred::rejected(stored_error, context)?,
+                Deferred::new(context),
+            ),
+            WritableState::Closed => (
+                Deferred::resolved(JsValue::undefined(), context)?,
+                Deferred::resolved(JsValue::undefined(), context)?,
+            ),
+            WritableState::Errored => (
+                Deferred::rejected(stored_error.clone(), context)?,
+                Deferred::rejected(stored_error, context)?,
+            ),
+        };
+        let writer = Self {
+            slots: Gc::new(GcRefCell::new(WriterSlots {
+                stream: Some(stream.clone()),
+                ready,
+                closed,
+            })),
+        };
+        stream.slots.borrow_mut().writer = Some(writer.clone());
+        Ok(writer)
+    }
+
+    pub(crate) fn stream(&self) -> Option<JsWritableStream> {
+        self.slots.borrow().stream.clone()
+    }
+
+    pub(crate) fn ready(&self) -> Deferred {
+        self.slots.borrow().ready.clone()
+    }
+
+    pub(crate) fn closed(&self) -> Deferred {
+        self.slots.borrow().closed.clone()
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-writer-close-with-error-propagation>
+    pub(crate) fn close_with_error_propagation(
+        &self,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let Some(stream) = self.stream() else {
+            return rejected(type_error("the writer has been released", context), context);
+        };
+        let state = stream.state();
+        if stream.close_queued_or_in_flight() || state == WritableState::Closed {
+            return resolved(JsValue::undefined(), context);
+        }
+        if state == WritableState::Errored {
+            return rejected(stream.stored_error(), context);
+        }
+        stream.close(context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-writer-ensure-closed-promise-rejected>
+    fn ensure_closed_promise_rejected(
+        &self,
+        error: JsValue,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let closed = self.closed();
+        let closed = if closed.is_pending() {
+            closed.reject(error, context)?;
+            closed
+        } else {
+            let closed = Deferred::rejected(error, context)?;
+            self.slots.borrow_mut().closed = closed.clone();
+            closed
+        };
+        mark_handled(&closed.promise(), context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-writer-ensure-ready-promise-rejected>
+    fn ensure_ready_promise_rejected(&self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        let ready = self.ready();
+        let ready = if ready.is_pending() {
+            ready.reject(error, context)?;
+            ready
+        } else {
+            let ready = Deferred::rejected(error, context)?;
+            self.slots.borrow_mut().ready = ready.clone();
+            ready
+        };
+        mark_handled(&ready.promise(), context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-writer-get-desired-size>
+    #[allow(clippy::unused_self)]
+    fn desired_size(&self, stream: &JsWritableStream) -> Option<f64> {
+        match stream.state() {
+            WritableState::Errored | WritableState::Erroring => None,
+            WritableState::Closed => Some(0.0),
+            WritableState::Writable => Some(stream.controller().desired_size()),
+        }
+    }
+
+    /// Releases the lock on the stream.
+    ///
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-writer-release>
+    pub(crate) fn release(&self, context: &mut Context) -> JsResult<()> {
+        let Some(stream) = self.stream() else {
+            return Ok(());
+        };
+        let error = type_error("the writer has been released", context);
+        self.ensure_ready_promise_rejected(error.clone(), context)?;
+        self.ensure_closed_promise_rejected(error, context)?;
+        stream.slots.borrow_mut().writer = None;
+        self.slots.borrow_mut().stream = None;
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-writer-write>
+    pub(crate) fn write(&self, chunk: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        let Some(stream) = self.stream() else {
+            return rejected(type_error("the writer has been released", context), context);
+        };
+        let controller = stream.controller();
+        let chunk_size = controller.chunk_size(&chunk, context)?;
+        if !self
+            .stream()
+            .is_some_and(|current| Gc::ptr_eq(&current.slots, &stream.slots))
+        {
+            return rejected(type_error("the writer has been released", context), context);
+        }
+        let state = stream.state();
+        if state == WritableState::Errored {
+            return rejected(stream.stored_error(), context);
+        }
+        if stream.close_queued_or_in_flight() || state == WritableState::Closed {
+            return rejected(
+                type_error(
+                    "cannot write to a stream that is closing or closed",
+                    context,
+                ),
+                context,
+            );
+        }
+        if state == WritableState::Erroring {
+            return rejected(stream.stored_error(), context);
+        }
+        let promise = Deferred::new(context);
+        stream
+            .slots
+            .borrow_mut()
+            .write_requests
+            .push_back(promise.clone());
+        controller.write(chunk, chunk_size, context)?;
+        Ok(promise.promise())
+    }
+}
+
+#[boa_class(rename = "WritableStreamDefaultWriter")]
+#[boa(rename_all = "camelCase")]
+impl JsWritableStreamDefaultWriter {
+    #[boa(constructor)]
+    fn constructor(stream: JsValue, context: &mut Context) -> JsResult<Self> {
+        let stream = stream
+            .as_object()
+            .as_ref()
+            .and_then(JsWritableStream::of)
+            .ok_or_else(|| js_error!(TypeError: "argument must be a WritableStream"))?;
+        Self::acquire(&stream, context)
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "closed")]
+    fn closed_(&self) -> JsPromise {
+        self.closed().promise()
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "desiredSize")]
+    fn desired_size_(&self) -> JsResult<Option<f64>> {
+        let Some(stream) = self.stream() else {
+            return Err(js_error!(TypeError: "the writer has been released"));
+        };
+        Ok(self.desired_size(&stream))
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "ready")]
+    fn ready_(&self) -> JsPromise {
+        self.ready().promise()
+    }
+
+    #[boa(method)]
+    fn abort(
+        this: JsClass<Self>,
+        reason: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let Some(stream) = this.clone_inner().stream() else {
+            return rejected(type_error("the writer has been released", context), context);
+        };
+        let result = stream.abort(reason.unwrap_or_default(), context);
+        promise_or_rejected(result, context)
+    }
+
+    #[boa(method)]
+    fn close(this: JsClass<Self>, context: &mut Context) -> JsResult<JsPromise> {
+        let Some(stream) = this.clone_inner().stream() else {
+            return rejected(type_error("the writer has been released", context), context);
+        };
+        if stream.close_queued_or_in_flight() {
+            return rejected(
+                type_error("the stream is already closing", context),
+                context,
+            );
+        }
+        let result = stream.close(context);
+        promise_or_rejected(result, context)
+    }
+
+    #[boa(method)]
+    fn release_lock(this: JsClass<Self>, context: &mut Context) -> JsResult<()> {
+        this.clone_inner().release(context)
+    }
+
+    #[boa(method)]
+    #[boa(rename = "write")]
+    fn write_(
+        this: JsClass<Self>,
+        chunk: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let result = this.clone_inner().write(chunk.unwrap_or_default(), context);
+        promise_or_rejected(result, context)
+    }
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct WritableControllerSlots {
+    stream: JsWritableStream,
+    /// The queue of chunks to write, where `None` is the close sentinel.
+    queue: QueueWithSizes<Option<JsValue>>,
+    started: bool,
+    strategy_hwm: f64,
+    strategy_size: SizeAlgorithm,
+    write: Algorithm,
+    close: Algorithm,
+    abort: Algorithm,
+    signal: JsObject,
+}
+
+/// The JavaScript `WritableStreamDefaultController` class.
+///
+/// See <https://streams.spec.whatwg.org/#ws-default-controller-class>
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct JsWritableStreamDefaultController {
+    slots: Gc<GcRefCell<WritableControllerSlots>>,
+}
+
+impl JsWritableStreamDefaultController {
+    /// Sets up `stream` with a new controller, whose underlying sink is made of `start`
+    /// and the `write`, `close` and `abort` algorithms.
+    ///
+    /// See <https://streams.spec.whatwg.org/#set-up-writable-stream-default-controller>
+    fn set_up(
+        stream: &JsWritableStream,
+        start: &Algorithm,
+        [write, close, abort]: [Algorithm; 3],
+        high_water_mark: f64,
+        size: SizeAlgorithm,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let signal = JsAbortSignal::new_object(context)?;
+        let controller = Self {
+            slots: Gc::new(GcRefCell::new(WritableControllerSlots {
+                stream: stream.clone(),
+                queue: QueueWithSizes::default(),
+                started: false,
+                strategy_hwm: high_water_mark,
+                strategy_size: size,
+                write,
+                close,
+                abort,
+                signal,
+            })),
+        };
+        let object = create(controller.clone(), context)?;
+        stream.slots.borrow_mut().controller = Some(object.clone());
+        stream.update_backpressure(controller.backpressure(), context)?;
+
+        let start_result = start.call(&[object.upcast().into()], context)?;
+        let start_promise = resolved(start_result, context)?;
+        upon(
+            &start_promise,
+            &controller,
+            |controller, _, context| {
+                controller.slots.borrow_mut().started = true;
+                controller.advance_queue_if_needed(context)?;
+                Ok(JsValue::undefined())
+            },
+            |controller, reason, context| {
+                let stream = {
+                    let mut slots = controller.slots.borrow_mut();
+                    slots.started = true;
+                    slots.stream.clone()
+                };
+                stream.deal_with_rejection(reason, context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        )
+    }
+
+    fn stream(&self) -> JsWritableStream {
+        self.slots.borrow().stream.clone()
+    }
+
+    fn started(&self) -> bool {
+        self.slots.borrow().started
+    }
+
+    fn signal(&self) -> JsObject {
+        self.slots.borrow().signal.clone()
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-controller-advance-queue-if-needed>
+    fn advance_queue_if_needed(&self, context: &mut Context) -> JsResult<()> {
+        let stream = self.stream();
+        if !self.started() || stream.slots.borrow().in_flight_write_request.is_some() {
+            return Ok(());
+        }
+        if stream.state() == WritableState::Erroring {
+            return stream.finish_erroring(context);
+        }
+        let value = self.slots.borrow().queue.peek().cloned();
+        match value {
+            None => Ok(()),
+            Some(None) => self.process_close(context),
+            Some(Some(chunk)) => self.process_write(chunk, context),
+        }
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-controller-clear-algorithms>
+    fn clear_algorithms(&self) {
+        let mut slots = self.slots.borrow_mut();
+        slots.write = Algorithm::none();
+        slots.close = Algorithm::none();
+        slots.abort = Algorithm::none();
+        slots.strategy_size = SizeAlgorithm::default();
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-controller-close>
+    fn close(&self, context: &mut Context) -> JsResult<()> {
+        self.slots.borrow_mut().queue.enqueue(None, 0.0)?;
+        self.advance_queue_if_needed(context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-controller-error-if-needed>
+    pub(crate) fn error_if_needed(&self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        if self.stream().state() == WritableState::Writable {
+            self.error(error, context)?;
+        }
+        Ok(())
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-controller-error>
+    pub(crate) fn error(&self, error: JsValue, context: &mut Context) -> JsResult<()> {
+        self.clear_algorithms();
+        self.stream().start_erroring(error, context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-controller-get-backpressure>
+    fn backpressure(&self) -> bool {
+        self.desired_size() <= 0.0
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-controller-get-chunk-size>
+    fn chunk_size(&self, chunk: &JsValue, context: &mut Context) -> JsResult<f64> {
+        let size = self.slots.borrow().strategy_size.clone();
+        match size.size(chunk, context) {
+            Ok(size) => Ok(size),
+            Err(error) => {
+                let error = error.into_opaque(context)?;
+                self.error_if_needed(error, context)?;
+                Ok(1.0)
+            }
+        }
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-controller-get-desired-size>
+    fn desired_size(&self) -> f64 {
+        let slots = self.slots.borrow();
+        slots.strategy_hwm - slots.queue.total_size()
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-controller-process-close>
+    fn process_close(&self, context: &mut Context) -> JsResult<()> {
+        let stream = self.stream();
+        {
+            let mut slots = stream.slots.borrow_mut();
+            slots.in_flight_close_request = slots.close_request.take();
+        }
+        let close = {
+            let mut slots = self.slots.borrow_mut();
+            slots.queue.dequeue();
+            slots.close.clone()
+        };
+        let sink_close_promise = close.call_promise(&[], context)?;
+        self.clear_algorithms();
+        upon(
+            &sink_close_promise,
+            &stream,
+            |stream, _, context| {
+                stream.finish_in_flight_close(context)?;
+                Ok(JsValue::undefined())
+            },
+            |stream, reason, context| {
+                stream.finish_in_flight_close_with_error(reason, context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        )
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-controller-process-write>
+    fn process_write(&self, chunk: JsValue, context: &mut Context) -> JsResult<()> {
+        let stream = self.stream();
+        {
+            let mut slots = stream.slots.borrow_mut();
+            slots.in_flight_write_request = slots.write_requests.pop_front();
+        }
+        let write = self.slots.borrow().write.clone();
+        let object = stream.controller_object();
+        let sink_write_promise = write.call_promise(&[chunk, object.into()], context)?;
+        upon(
+            &sink_write_promise,
+            self,
+            |controller, _, context| {
+                let stream = controller.stream();
+                stream.finish_in_flight_write(context)?;
+                controller.slots.borrow_mut().queue.dequeue();
+                if !stream.close_queued_or_in_flight() && stream.state() == WritableState::Writable
+                {
+                    stream.update_backpressure(controller.backpressure(), context)?;
+                }
+                controller.advance_queue_if_needed(context)?;
+                Ok(JsValue::undefined())
+            },
+            |controller, reason, context| {
+                let stream = controller.stream();
+                if stream.state() == WritableState::Writable {
+                    controller.clear_algorithms();
+                }
+                stream.finish_in_flight_write_with_error(reason, context)?;
+                Ok(JsValue::undefined())
+            },
+            context,
+        )
+    }
+
+    /// See <https://streams.spec.whatwg.org/#writable-stream-default-controller-write>
+    fn write(&self, chunk: JsValue, chunk_size: f64, context: &mut Context) -> JsResult<()> {
+        let result = self
+            .slots
+            .borrow_mut()
+            .queue
+            .enqueue(Some(chunk), chunk_size);
+        if let Err(error) = result {
+            let error = error.into_opaque(context)?;
+            return self.error_if_needed(error, context);
+        }
+        let stream = self.stream();
+        if !stream.close_queued_or_in_flight() && stream.state() == WritableState::Writable {
+            stream.update_backpressure(self.backpressure(), context)?;
+        }
+        self.advance_queue_if_needed(context)
+    }
+
+    /// See <https://streams.spec.whatwg.org/#ws-default-controller-private-abort>
+    fn abort_steps(&self, reason: JsValue, context: &mut Context) -> JsResult<JsPromise> {
+        let abort = self.slots.borrow().abort.clone();
+        let result = abort.call_promise(&[reason], context);
+        self.clear_algorithms();
+        result
+    }
+
+    /// See <https://streams.spec.whatwg.org/#ws-default-controller-private-error>
+    fn error_steps(&self) {
+        self.slots.borrow_mut().queue.reset();
+    }
+}
+
+#[boa_class(rename = "WritableStreamDefaultController")]
+#[boa(rename_all = "camelCase")]
+impl JsWritableStreamDefaultController {
+    #[boa(constructor)]
+    fn constructor() -> JsResult<Self> {
+        Err(illegal_constructor())
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "signal")]
+    fn signal_(&self) -> JsObject {
+        self.signal()
+    }
+
+    #[boa(method)]
+    #[boa(rename = "error")]
+    fn error_(this: JsClass<Self>, error: Option<JsValue>, context: &mut Context) -> JsResult<()> {
+        let controller = this.clone_inner();
+        if controller.stream().state() != WritableState::Writable {
+            return Ok(());
+        }
+        controller.error(error.unwrap_or_default(), context)
+    }
+}
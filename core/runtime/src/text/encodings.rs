@@ -1,3 +1,157 @@
+use super::Encoding;
+use boa_engine::{JsResult, JsString, js_error};
+
+/// Decodes `input` in one go, replacing malformed sequences with U+FFFD, or failing on
+/// them if `fatal` is set.
+///
+/// The BOM is only stripped for the Unicode encodings.
+pub(crate) fn decode(
+    encoding: Encoding,
+    input: &[u8],
+    strip_bom: bool,
+    fatal: bool,
+) -> JsResult<JsString> {
+    match encoding {
+        Encoding::Utf8 => {
+            if fatal && std::str::from_utf8(input).is_err() {
+                return Err(malformed());
+            }
+            Ok(utf8::decode(input, strip_bom))
+        }
+        Encoding::Utf16Le => {
+            if fatal {
+                check_utf16(input, u16::from_le_bytes)?;
+            }
+            Ok(utf16le::decode(input, strip_bom))
+        }
+        Encoding::Utf16Be => {
+            if fatal {
+                check_utf16(input, u16::from_be_bytes)?;
+            }
+            Ok(utf16be::decode(input.to_vec(), strip_bom))
+        }
+        #[cfg(feature = "legacy-encodings")]
+        Encoding::Legacy(encoding) => legacy::decode(
+            &mut encoding.new_decoder_without_bom_handling(),
+            input,
+            true,
+            fatal,
+        ),
+    }
+}
+
+fn malformed() -> boa_engine::JsError {
+    js_error!(TypeError: "The encoded data was not valid.")
+}
+
+#[cfg(feature = "legacy-encodings")]
+fn too_long() -> boa_engine::JsError {
+    js_error!(RangeError: "The encoded data is too long.")
+}
+
+/// Fails if `input` has an odd length or contains unpaired surrogates.
+fn check_utf16(input: &[u8], from_bytes: fn([u8; 2]) -> u16) -> JsResult<()> {
+    if !input.len().is_multiple_of(2) {
+        return Err(malformed());
+    }
+    let units = input
+        .chunks_exact(2)
+        .map(|unit| from_bytes([unit[0], unit[1]]));
+    if char::decode_utf16(units).any(|c| c.is_err()) {
+        return Err(malformed());
+    }
+    Ok(())
+}
+
+/// A decoder for a stream of chunks, which keeps the bytes of a sequence split between two
+/// chunks until it is complete.
+pub(crate) struct StreamDecoder {
+    encoding: Encoding,
+    ignore_bom: bool,
+    fatal: bool,
+    bom_seen: bool,
+    pending: Vec<u8>,
+    /// The stateful decoder of a legacy encoding, which keeps split sequences itself.
+    #[cfg(feature = "legacy-encodings")]
+    legacy: Option<encoding_rs::Decoder>,
+}
+
+impl std::fmt::Debug for StreamDecoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamDecoder")
+            .field("encoding", &self.encoding)
+            .field("ignore_bom", &self.ignore_bom)
+            .field("fatal", &self.fatal)
+            .field("bom_seen", &self.bom_seen)
+            .field("pending", &self.pending)
+            .finish_non_exhaustive()
+    }
+}
+
+impl StreamDecoder {
+    pub(crate) fn new(encoding: Encoding, ignore_bom: bool, fatal: bool) -> Self {
+        Self {
+            encoding,
+            ignore_bom,
+            fatal,
+            bom_seen: false,
+            pending: Vec::new(),
+            #[cfg(feature = "legacy-encodings")]
+            legacy: match encoding {
+                Encoding::Legacy(encoding) => Some(encoding.new_decoder_without_bom_handling()),
+                _ => None,
+            },
+        }
+    }
+
+    /// Decodes `input`, keeping back the bytes at its end which the next chunk may complete.
+    pub(crate) fn decode(&mut self, input: &[u8]) -> JsResult<JsString> {
+        #[cfg(feature = "legacy-encodings")]
+        if let Some(decoder) = &mut self.legacy {
+            return legacy::decode(decoder, input, false, self.fatal);
+        }
+        self.pending.extend_from_slice(input);
+        let complete = match self.encoding {
+            Encoding::Utf8 => utf8::complete_len(&self.pending),
+            Encoding::Utf16Le => utf16le::complete_len(&self.pending),
+            Encoding::Utf16Be => utf16be::complete_len(&self.pending),
+            // Legacy encodings are decoded by their own decoder above.
+            #[cfg(feature = "legacy-encodings")]
+            Encoding::Legacy(_) => self.pending.len(),
+        };
+        let bytes: Vec<u8> = self.pending.drain(..complete).collect();
+        self.decode_bytes(&bytes)
+    }
+
+    /// Decodes the bytes kept back at the end of the stream.
+    pub(crate) fn finish(&mut self) -> JsResult<JsString> {
+        #[cfg(feature = "legacy-encodings")]
+        if let Some(decoder) = &mut self.legacy {
+            return legacy::decode(decoder, &[], true, self.fatal);
+        }
+        let bytes = std::mem::take(&mut self.pending);
+        self.decode_bytes(&bytes)
+    }
+
+    fn decode_bytes(&mut self, bytes: &[u8]) -> JsResult<JsString> {
+        if bytes.is_empty() {
+            return Ok(JsString::default());
+        }
+        // Only the start of the stream may have a BOM.
+        let strip_bom = !self.ignore_bom && !self.bom_seen;
+        self.bom_seen = true;
+        decode(self.encoding, bytes, strip_bom, self.fatal)
+    }
+}
+
+fn utf16_complete_len(input: &[u8], from_bytes: fn([u8; 2]) -> u16) -> usize {
+    let len = input.len() - input.len() % 2;
+    if len >= 2 && (0xD800..0xDC00).contains(&from_bytes([input[len - 2], input[len - 1]])) {
+        return len - 2;
+    }
+    len
+}
+
 pub(crate) mod utf8 {
     use boa_engine::JsString;
     use boa_engine::string::CodePoint;
@@ -19,11 +173,32 @@ pub(crate) mod utf8 {
         let string = String::from_utf8_lossy(input);
         JsString::from(string.as_ref())
     }
+
+    /// Returns the length of the prefix of `input` which doesn't end with an incomplete
+    /// sequence.
+    pub(crate) fn complete_len(input: &[u8]) -> usize {
+        let mut start = 0;
+        loop {
+            match std::str::from_utf8(&input[start..]) {
+                Ok(_) => return input.len(),
+                Err(error) => match error.error_len() {
+                    Some(len) => start += error.valid_up_to() + len,
+                    None => return start + error.valid_up_to(),
+                },
+            }
+        }
+    }
 }
 
 pub(crate) mod utf16le {
     use boa_engine::{JsString, js_string};
 
+    /// Returns the length of the prefix of `input` which doesn't end with an incomplete code
+    /// unit or a lead surrogate.
+    pub(crate) fn complete_len(input: &[u8]) -> usize {
+        super::utf16_complete_len(input, u16::from_le_bytes)
+    }
+
     pub(crate) fn decode(mut input: &[u8], strip_bom: bool) -> JsString {
         if strip_bom {
             input = input.strip_prefix(&[0xFF, 0xFE]).unwrap_or(input);
@@ -50,6 +225,12 @@ pub(crate) mod utf16le {
 pub(crate) mod utf16be {
     use boa_engine::{JsString, js_string};
 
+    /// Returns the length of the prefix of `input` which doesn't end with an incomplete code
+    /// unit or a lead surrogate.
+    pub(crate) fn complete_len(input: &[u8]) -> usize {
+        super::utf16_complete_len(input, u16::from_be_bytes)
+    }
+
     pub(crate) fn decode(mut input: Vec<u8>, strip_bom: bool) -> JsString {
         if strip_bom && input.starts_with(&[0xFE, 0xFF]) {
             input.drain(..2);
@@ -79,3 +260,41 @@ pub(crate) mod utf16be {
         }
     }
 }
+
+#[cfg(feature = "legacy-encodings")]
+pub(crate) mod legacy {
+    use boa_engine::{JsResult, JsString};
+    use encoding_rs::{CoderResult, Decoder, DecoderResult};
+
+    /// Decodes `input` with the stateful `decoder`, which keeps incomplete sequences until
+    /// the next call, unless `last` is set.
+    pub(crate) fn decode(
+        decoder: &mut Decoder,
+        input: &[u8],
+        last: bool,
+        fatal: bool,
+    ) -> JsResult<JsString> {
+        let mut output = String::new();
+        if fatal {
+            let capacity = decoder
+                .max_utf8_buffer_length_without_replacement(input.len())
+                .ok_or_else(super::too_long)?;
+            output.reserve(capacity);
+            let (result, _) =
+                decoder.decode_to_string_without_replacement(input, &mut output, last);
+            match result {
+                DecoderResult::InputEmpty => {}
+                DecoderResult::Malformed(_, _) => return Err(super::malformed()),
+                DecoderResult::OutputFull => unreachable!("the output has the maximum length"),
+            }
+        } else {
+            let capacity = decoder
+                .max_utf8_buffer_length(input.len())
+                .ok_or_else(super::too_long)?;
+            output.reserve(capacity);
+            let (result, _, _) = decoder.decode_to_string(input, &mut output, last);
+            debug_assert_eq!(result, CoderResult::InputEmpty);
+        }
+        Ok(JsString::from(output.as_str()))
+    }
+}
@@ -14,10 +14,15 @@ use boa_engine::{
 mod tests;
 
 mod encodings;
+mod stream;
+
+#[doc(inline)]
+pub use stream::{TextDecoderStream, TextEncoderStream};
 
 /// Options for the [`TextDecoder`] constructor.
 #[derive(Debug, Default, Clone, Copy, TryFromJs)]
 pub struct TextDecoderOptions {
+    fatal: Option<bool>,
     #[boa(rename = "ignoreBOM")]
     ignore_bom: Option<bool>,
 }
@@ -32,6 +37,10 @@ pub enum Encoding {
     Utf16Le,
     /// UTF-16 big endian encoding.
     Utf16Be,
+    /// One of the legacy single-byte or multi-byte encodings of the WHATWG Encoding
+    /// Standard, such as `windows-1252` or `shift_jis`.
+    #[cfg(feature = "legacy-encodings")]
+    Legacy(&'static encoding_rs::Encoding),
 }
 
 const TEXT_DECODER_LABELS: &[(&str, Encoding)] = &[
@@ -56,11 +65,46 @@ const TEXT_DECODER_LABELS: &[(&str, Encoding)] = &[
 fn resolve_text_decoder_label(label: &str) -> Option<Encoding> {
     let label = label.trim_matches(['\u{0009}', '\u{000A}', '\u{000C}', '\u{000D}', '\u{0020}']);
 
-    TEXT_DECODER_LABELS
+    let encoding = TEXT_DECODER_LABELS
         .iter()
         .find_map(|(supported, encoding)| {
             label.eq_ignore_ascii_case(supported).then_some(*encoding)
-        })
+        });
+
+    // The labels of the `replacement` encoding are not supported by `TextDecoder`.
+    #[cfg(feature = "legacy-encodings")]
+    let encoding = encoding.or_else(|| {
+        encoding_rs::Encoding::for_label_no_replacement(label.as_bytes()).map(Encoding::Legacy)
+    });
+
+    encoding
+}
+
+impl Encoding {
+    /// Resolves the `label` given to a decoder constructor, defaulting to UTF-8.
+    fn from_label(label: Option<JsString>) -> JsResult<Self> {
+        let Some(label) = label else {
+            return Ok(Self::default());
+        };
+        let label = label.to_std_string_lossy();
+        resolve_text_decoder_label(&label).ok_or_else(
+            || js_error!(RangeError: "The given encoding '{}' is not supported.", label),
+        )
+    }
+
+    /// Returns the name of the encoding.
+    fn name(self) -> JsString {
+        match self {
+            Self::Utf8 => js_string!("utf-8"),
+            Self::Utf16Le => js_string!("utf-16le"),
+            Self::Utf16Be => js_string!("utf-16be"),
+            #[cfg(feature = "legacy-encodings")]
+            Self::Legacy(encoding) => {
+                use cow_utils::CowUtils;
+                JsString::from(encoding.name().cow_to_ascii_lowercase().as_ref())
+            }
+        }
+    }
 }
 
 /// The [`TextDecoder`][mdn] class represents an encoder for a specific method, that is
@@ -73,6 +117,8 @@ pub struct TextDecoder {
     encoding: Encoding,
     #[unsafe_ignore_trace]
     ignore_bom: bool,
+    #[unsafe_ignore_trace]
+    fatal: bool,
 }
 
 #[boa_class]
@@ -89,20 +135,13 @@ impl TextDecoder {
         options: Option<TextDecoderOptions>,
     ) -> JsResult<Self> {
         let ignore_bom = options.and_then(|o| o.ignore_bom).unwrap_or(false);
-
-        let encoding = match encoding {
-            Some(enc) => {
-                let label = enc.to_std_string_lossy();
-                resolve_text_decoder_label(&label).ok_or_else(
-                    || js_error!(RangeError: "The given encoding '{}' is not supported.", label),
-                )?
-            }
-            None => Encoding::default(),
-        };
+        let fatal = options.and_then(|o| o.fatal).unwrap_or(false);
+        let encoding = Encoding::from_label(encoding)?;
 
         Ok(Self {
             encoding,
             ignore_bom,
+            fatal,
         })
     }
 
@@ -113,11 +152,7 @@ impl TextDecoder {
     #[boa(getter)]
     #[must_use]
     pub fn encoding(&self) -> JsString {
-        match self.encoding {
-            Encoding::Utf8 => js_string!("utf-8"),
-            Encoding::Utf16Le => js_string!("utf-16le"),
-            Encoding::Utf16Be => js_string!("utf-16be"),
-        }
+        self.encoding.name()
     }
 
     /// The [`TextDecoder.ignoreBOM`][mdn] read-only property returns a `bool` indicating
@@ -131,6 +166,16 @@ impl TextDecoder {
         self.ignore_bom
     }
 
+    /// The [`TextDecoder.fatal`][mdn] read-only property returns a `bool` indicating whether
+    /// malformed data throws a `TypeError` instead of being replaced with U+FFFD.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/fatal
+    #[boa(getter)]
+    #[must_use]
+    pub fn fatal(&self) -> bool {
+        self.fatal
+    }
+
     /// The [`TextDecoder.decode()`][mdn] method returns a string containing text decoded from the
     /// buffer passed as a parameter.
     ///
@@ -197,14 +242,7 @@ impl TextDecoder {
             &full_data
         };
 
-        Ok(match self.encoding {
-            Encoding::Utf8 => encodings::utf8::decode(data, strip_bom),
-            Encoding::Utf16Le => encodings::utf16le::decode(data, strip_bom),
-            Encoding::Utf16Be => {
-                let owned = data.to_vec();
-                encodings::utf16be::decode(owned, strip_bom)
-            }
-        })
+        encodings::decode(self.encoding, data, strip_bom, self.fatal)
     }
 }
 
@@ -256,10 +294,16 @@ impl TextEncoder {
 #[boa_module]
 pub mod js_module {
     type TextDecoder = super::TextDecoder;
+    type TextDecoderStream = super::TextDecoderStream;
     type TextEncoder = super::TextEncoder;
+    type TextEncoderStream = super::TextEncoderStream;
 }
 
-/// Register both `TextDecoder` and `TextEncoder` classes into the realm/context.
+/// Register the `TextDecoder`, `TextEncoder`, `TextDecoderStream` and `TextEncoderStream`
+/// classes into the realm/context.
+///
+/// The stream classes create `ReadableStream` and `WritableStream` objects, so the stream
+/// classes should also be registered.
 ///
 /// # Errors
 /// This will error if the context or realm cannot register the class.
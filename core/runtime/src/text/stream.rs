@@ -0,0 +1,250 @@
+//! The `TextEncoderStream` and `TextDecoderStream` classes.
+//!
+//! See <https://encoding.spec.whatwg.org/#interface-mixin-generictransformstream>
+
+use super::encodings::{StreamDecoder, utf8};
+use super::{Encoding, TextDecoderOptions};
+use crate::blob::buffer_source_bytes;
+use crate::stream::{JsTransformStream, JsTransformStreamDefaultController, NativeTransformer};
+use boa_engine::object::builtins::JsUint8Array;
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, js_error,
+    js_string,
+};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// Encodes strings to UTF-8, keeping a lead surrogate at the end of a chunk until the next
+/// one.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct Utf8Encoder {
+    #[unsafe_ignore_trace]
+    pending_lead_surrogate: Rc<Cell<Option<u16>>>,
+}
+
+impl NativeTransformer for Utf8Encoder {
+    fn transform(
+        &self,
+        chunk: JsValue,
+        controller: &JsTransformStreamDefaultController,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let chunk = chunk.to_string(context)?;
+        let mut units: Vec<u16> = self.pending_lead_surrogate.take().into_iter().collect();
+        units.extend(chunk.iter());
+        if let Some(&last) = units.last()
+            && (0xD800..0xDC00).contains(&last)
+        {
+            self.pending_lead_surrogate.set(Some(last));
+            units.pop();
+        }
+        if units.is_empty() {
+            return Ok(());
+        }
+        let bytes = utf8::encode(&JsString::from(units.as_slice()));
+        controller.enqueue(JsUint8Array::from_iter(bytes, context)?.into(), context)
+    }
+
+    fn flush(
+        &self,
+        controller: &JsTransformStreamDefaultController,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        if self.pending_lead_surrogate.take().is_none() {
+            return Ok(());
+        }
+        let replacement = JsUint8Array::from_iter("\u{FFFD}".bytes(), context)?;
+        controller.enqueue(replacement.into(), context)
+    }
+}
+
+/// The [`TextEncoderStream`][mdn] class, a transform stream encoding strings to UTF-8.
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoderStream
+#[derive(Debug, Clone, JsData, Trace, Finalize)]
+pub struct TextEncoderStream {
+    stream: JsTransformStream,
+}
+
+#[boa_class]
+impl TextEncoderStream {
+    /// The [`TextEncoderStream()`][mdn] constructor returns a new `TextEncoderStream` object.
+    ///
+    /// # Errors
+    /// This will error if the stream cannot be created.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoderStream/TextEncoderStream
+    #[boa(constructor)]
+    pub fn constructor(context: &mut Context) -> JsResult<Self> {
+        let encoder = Utf8Encoder {
+            pending_lead_surrogate: Rc::default(),
+        };
+        Ok(Self {
+            stream: JsTransformStream::from_native(encoder, context)?,
+        })
+    }
+
+    /// The [`TextEncoderStream.encoding`][mdn] read-only property always returns `utf-8`.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoderStream/encoding
+    #[boa(getter)]
+    #[must_use]
+    fn encoding() -> JsString {
+        js_string!("utf-8")
+    }
+
+    /// The [`TextEncoderStream.readable`][mdn] read-only property returns the `ReadableStream`
+    /// of the encoded chunks.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoderStream/readable
+    #[boa(getter)]
+    #[must_use]
+    pub fn readable(&self) -> JsObject {
+        self.stream.readable_stream().upcast()
+    }
+
+    /// The [`TextEncoderStream.writable`][mdn] read-only property returns the `WritableStream`
+    /// which accepts the strings to encode.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoderStream/writable
+    #[boa(getter)]
+    #[must_use]
+    pub fn writable(&self) -> JsObject {
+        self.stream.writable_stream().upcast()
+    }
+}
+
+/// Decodes `BufferSource` chunks with a [`StreamDecoder`].
+#[derive(Debug, Clone, Trace, Finalize)]
+struct ChunkDecoder {
+    #[unsafe_ignore_trace]
+    decoder: Rc<RefCell<StreamDecoder>>,
+}
+
+impl NativeTransformer for ChunkDecoder {
+    fn transform(
+        &self,
+        chunk: JsValue,
+        controller: &JsTransformStreamDefaultController,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let bytes = buffer_source_bytes(&chunk, context)?.ok_or_else(
+            || js_error!(TypeError: "The chunk must be an ArrayBuffer, TypedArray or DataView."),
+        )?;
+        let text = self.decoder.borrow_mut().decode(&bytes)?;
+        if text.is_empty() {
+            return Ok(());
+        }
+        controller.enqueue(text.into(), context)
+    }
+
+    fn flush(
+        &self,
+        controller: &JsTransformStreamDefaultController,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let text = self.decoder.borrow_mut().finish()?;
+        if text.is_empty() {
+            return Ok(());
+        }
+        controller.enqueue(text.into(), context)
+    }
+}
+
+/// The [`TextDecoderStream`][mdn] class, a transform stream decoding binary chunks to
+/// strings.
+///
+/// Sequences split between two chunks are decoded once complete.
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoderStream
+#[derive(Debug, Clone, JsData, Trace, Finalize)]
+pub struct TextDecoderStream {
+    stream: JsTransformStream,
+    #[unsafe_ignore_trace]
+    encoding: Encoding,
+    ignore_bom: bool,
+    fatal: bool,
+}
+
+#[boa_class]
+impl TextDecoderStream {
+    /// The [`TextDecoderStream()`][mdn] constructor returns a new `TextDecoderStream` object.
+    ///
+    /// # Errors
+    /// This will return an error if the encoding or options are invalid or unsupported.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoderStream/TextDecoderStream
+    #[boa(constructor)]
+    pub fn constructor(
+        encoding: Option<JsString>,
+        options: Option<TextDecoderOptions>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let ignore_bom = options.and_then(|o| o.ignore_bom).unwrap_or(false);
+        let fatal = options.and_then(|o| o.fatal).unwrap_or(false);
+        let encoding = Encoding::from_label(encoding)?;
+        let decoder = ChunkDecoder {
+            decoder: Rc::new(RefCell::new(StreamDecoder::new(
+                encoding, ignore_bom, fatal,
+            ))),
+        };
+
+        Ok(Self {
+            stream: JsTransformStream::from_native(decoder, context)?,
+            encoding,
+            ignore_bom,
+            fatal,
+        })
+    }
+
+    /// The [`TextDecoderStream.encoding`][mdn] read-only property returns the name of the
+    /// character encoding that this decoder will use.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoderStream/encoding
+    #[boa(getter)]
+    #[must_use]
+    pub fn encoding(&self) -> JsString {
+        self.encoding.name()
+    }
+
+    /// The [`TextDecoderStream.ignoreBOM`][mdn] read-only property returns a `bool` indicating
+    /// whether the BOM (byte order mark) is ignored.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoderStream/ignoreBOM
+    #[boa(getter)]
+    #[boa(rename = "ignoreBOM")]
+    #[must_use]
+    pub fn ignore_bom(&self) -> bool {
+        self.ignore_bom
+    }
+
+    /// The [`TextDecoderStream.fatal`][mdn] read-only property returns a `bool` indicating
+    /// whether malformed data errors the stream instead of being replaced with U+FFFD.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoderStream/fatal
+    #[boa(getter)]
+    #[must_use]
+    pub fn fatal(&self) -> bool {
+        self.fatal
+    }
+
+    /// The [`TextDecoderStream.readable`][mdn] read-only property returns the `ReadableStream`
+    /// of the decoded strings.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoderStream/readable
+    #[boa(getter)]
+    #[must_use]
+    pub fn readable(&self) -> JsObject {
+        self.stream.readable_stream().upcast()
+    }
+
+    /// The [`TextDecoderStream.writable`][mdn] read-only property returns the `WritableStream`
+    /// which accepts the binary chunks to decode.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoderStream/writable
+    #[boa(getter)]
+    #[must_use]
+    pub fn writable(&self) -> JsObject {
+        self.stream.writable_stream().upcast()
+    }
+}
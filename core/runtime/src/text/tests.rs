@@ -1,4 +1,4 @@
-use crate::test::{TestAction, run_test_actions_with};
+use crate::test::{TestAction, run_test_actions, run_test_actions_with};
 use crate::text;
 use boa_engine::object::builtins::JsUint8Array;
 use boa_engine::property::Attribute;
@@ -476,3 +476,228 @@ fn decoder_handle_data_view_offset_and_length() {
         context,
     );
 }
+
+#[test]
+fn encoder_stream() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(indoc! {r#"
+            const stream = new TextEncoderStream();
+            assertEq(stream.encoding, "utf-8");
+            const writer = stream.writable.getWriter();
+            // The surrogate pair is split between two chunks.
+            writer.write("a\uD83D");
+            writer.write("\uDE00b");
+            writer.write("\uD83D");
+            writer.close();
+
+            result = (async () => {
+                const bytes = [];
+                for await (const chunk of stream.readable) {
+                    assert(chunk instanceof Uint8Array);
+                    bytes.push(...chunk);
+                }
+                assertArrayEqual(bytes, [0x61, 0xF0, 0x9F, 0x98, 0x80, 0x62, 0xEF, 0xBF, 0xBD]);
+            })();
+        "#}),
+        TestAction::inspect_context(|context| {
+            let result = context
+                .global_object()
+                .get(js_str!("result"), context)
+                .unwrap();
+            result
+                .as_promise()
+                .unwrap()
+                .await_blocking(context)
+                .unwrap();
+        }),
+    ]);
+}
+
+#[test]
+fn decoder_stream() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(indoc! {r#"
+            async function decode(chunks, ...args) {
+                const stream = new TextDecoderStream(...args);
+                const writer = stream.writable.getWriter();
+                for (const chunk of chunks) {
+                    writer.write(Uint8Array.from(chunk));
+                }
+                writer.close();
+                let text = "";
+                for await (const chunk of stream.readable) {
+                    text += chunk;
+                }
+                return text;
+            }
+
+            const stream = new TextDecoderStream("UTF-16BE", { ignoreBOM: true });
+            assertEq(stream.encoding, "utf-16be");
+            assert(stream.ignoreBOM);
+            assertThrows(() => new TextDecoderStream("unknown"));
+
+            result = (async () => {
+                // Multi-byte sequences and the BOM are split between chunks.
+                assertEq(await decode([[0xEF, 0xBB], [0xBF, 0x61, 0xE2], [0x82, 0xAC]]), "a\u20AC");
+                assertEq(await decode([[0xEF, 0xBB, 0xBF, 0x61]], "utf-8", { ignoreBOM: true }), "\uFEFFa");
+                assertEq(await decode([[0x61, 0xE2, 0x82]]), "a\uFFFD");
+                assertEq(await decode([[0xFF, 0xFE, 0x3D], [0xD8, 0x00], [0xDE]], "utf-16le"), "\uD83D\uDE00");
+                assertEq(await decode([[0x00, 0x61, 0x00]], "utf-16be"), "a\uFFFD");
+
+                const invalid = new TextDecoderStream();
+                const writer = invalid.writable.getWriter();
+                writer.write("not a buffer").catch(() => {});
+                try {
+                    await invalid.readable.getReader().read();
+                    throw new Error("read should reject");
+                } catch (e) {
+                    assert(e instanceof TypeError);
+                }
+            })();
+        "#}),
+        TestAction::inspect_context(|context| {
+            let result = context
+                .global_object()
+                .get(js_str!("result"), context)
+                .unwrap();
+            result
+                .as_promise()
+                .unwrap()
+                .await_blocking(context)
+                .unwrap();
+        }),
+    ]);
+}
+
+#[test]
+fn decoder_fatal() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(indoc! {r#"
+            assert(!new TextDecoder().fatal);
+            assert(new TextDecoder("utf-8", { fatal: true }).fatal);
+
+            const invalid = Uint8Array.of(0x61, 0xFF, 0x62);
+            assertEq(new TextDecoder().decode(invalid), "a�b");
+            assertThrows(() => new TextDecoder("utf-8", { fatal: true }).decode(invalid));
+            assertEq(new TextDecoder("utf-8", { fatal: true }).decode(Uint8Array.of(0x61)), "a");
+
+            const utf16 = new TextDecoder("utf-16le", { fatal: true });
+            assertThrows(() => utf16.decode(Uint8Array.of(0x61, 0x00, 0x62)));
+            assertThrows(() => utf16.decode(Uint8Array.of(0x3D, 0xD8, 0x61, 0x00)));
+            assertEq(utf16.decode(Uint8Array.of(0x3D, 0xD8, 0x00, 0xDE)), "😀");
+
+            const stream = new TextDecoderStream("utf-8", { fatal: true });
+            assert(stream.fatal);
+            const writer = stream.writable.getWriter();
+            writer.write(Uint8Array.of(0x61, 0xE2)).catch(() => {});
+            writer.close().catch(() => {});
+            const reader = stream.readable.getReader();
+            result = (async () => {
+                assertEq((await reader.read()).value, "a");
+                try {
+                    await reader.read();
+                    throw new Error("read should reject");
+                } catch (e) {
+                    assert(e instanceof TypeError);
+                }
+            })();
+        "#}),
+        TestAction::inspect_context(|context| {
+            let result = context
+                .global_object()
+                .get(js_str!("result"), context)
+                .unwrap();
+            result
+                .as_promise()
+                .unwrap()
+                .await_blocking(context)
+                .unwrap();
+        }),
+    ]);
+}
+
+#[cfg(feature = "legacy-encodings")]
+#[test_case("windows-1252", &[0x80, 0x61, 0xE9], "\u{20AC}a\u{E9}"; "windows 1252")]
+#[test_case("latin1", &[0x80], "\u{20AC}"; "latin1 label")]
+#[test_case("iso-8859-2", &[0xB1, 0xE6], "\u{105}\u{107}"; "iso 8859 2")]
+#[test_case("koi8-r", &[0xF0, 0xD2, 0xC9], "\u{41F}\u{440}\u{438}"; "koi8 r")]
+#[test_case("shift_jis", &[0x82, 0xA0, 0x41], "\u{3042}A"; "shift jis")]
+#[test_case("euc-jp", &[0xA4, 0xA2], "\u{3042}"; "euc jp")]
+#[test_case("gbk", &[0xC4, 0xE3, 0xBA, 0xC3], "\u{4F60}\u{597D}"; "gbk")]
+#[test_case("gb18030", &[0x81, 0x30, 0x81, 0x30], "\u{80}"; "gb18030")]
+#[test_case("big5", &[0xA4, 0xA4], "\u{4E2D}"; "big5")]
+#[test_case("euc-kr", &[0xB0, 0xA1], "\u{AC00}"; "euc kr")]
+fn decoder_legacy_encodings(label: &'static str, bytes: &'static [u8], expected: &'static str) {
+    let context = &mut Context::default();
+    text::register(None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(format!(
+                "decoded = new TextDecoder({label:?}).decode(Uint8Array.from({bytes:?}));"
+            )),
+            TestAction::inspect_context(move |context| {
+                let decoded = context
+                    .global_object()
+                    .get(js_str!("decoded"), context)
+                    .unwrap();
+                assert_eq!(decoded.as_string(), Some(JsString::from(expected)));
+            }),
+        ],
+        context,
+    );
+}
+
+#[cfg(feature = "legacy-encodings")]
+#[test]
+fn decoder_legacy_options() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(indoc! {r#"
+            assertEq(new TextDecoder("latin1").encoding, "windows-1252");
+            assertEq(new TextDecoder(" Shift_JIS ").encoding, "shift_jis");
+            assertEq(new TextDecoder("x-sjis").encoding, "shift_jis");
+            assertEq(new TextDecoder("EUC-KR").encoding, "euc-kr");
+            // The labels of the `replacement` encoding are rejected.
+            assertThrows(() => new TextDecoder("iso-2022-kr"));
+            assertThrows(() => new TextDecoder("unknown"));
+
+            // A truncated sequence is replaced, or throws if `fatal` is set.
+            const truncated = Uint8Array.of(0x41, 0x82);
+            assertEq(new TextDecoder("shift_jis").decode(truncated), "A�");
+            assertThrows(() => new TextDecoder("shift_jis", { fatal: true }).decode(truncated));
+            // The BOM is not stripped for legacy encodings.
+            assertEq(new TextDecoder("windows-1252").decode(Uint8Array.of(0xEF, 0xBB, 0xBF)), "ï»¿");
+
+            const stream = new TextDecoderStream("euc-jp");
+            assertEq(stream.encoding, "euc-jp");
+            const writer = stream.writable.getWriter();
+            // The sequences are split between chunks.
+            writer.write(Uint8Array.of(0xA4));
+            writer.write(Uint8Array.of(0xA2, 0xA4));
+            writer.write(Uint8Array.of(0xA4));
+            writer.close();
+            result = (async () => {
+                let text = "";
+                for await (const chunk of stream.readable) {
+                    text += chunk;
+                }
+                assertEq(text, "あい");
+            })();
+        "#}),
+        TestAction::inspect_context(|context| {
+            let result = context
+                .global_object()
+                .get(js_str!("result"), context)
+                .unwrap();
+            result
+                .as_promise()
+                .unwrap()
+                .await_blocking(context)
+                .unwrap();
+        }),
+    ]);
+}
@@ -0,0 +1,409 @@
+//! The `URLSearchParams` JavaScript class, implemented as [`JsUrlSearchParams`].
+//!
+//! `URLSearchParams` objects hold the `(name, value)` pairs of an
+//! `application/x-www-form-urlencoded` string. The object returned by `URL.searchParams`
+//! is bound to its URL: changing one updates the other.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `URL` specification][spec]
+//!
+//! [spec]: https://url.spec.whatwg.org/#interface-urlsearchparams
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams
+
+use super::Url;
+use boa_engine::gc::GcRefCell;
+use boa_engine::interop::JsClass;
+use boa_engine::object::builtins::{JsArray, TypedJsFunction};
+use boa_engine::property::PropertyDescriptor;
+use boa_engine::realm::Realm;
+use boa_engine::value::Convert;
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsSymbol, JsValue, Trace, boa_class,
+    boa_module, js_error, js_string,
+};
+use url::form_urlencoded;
+
+/// A callback function for the `forEach` method.
+pub type ForEachCallback = TypedJsFunction<(JsString, JsString, JsObject), ()>;
+
+/// The JavaScript `URLSearchParams` class.
+#[derive(Debug, Default, Trace, Finalize, JsData)]
+pub struct JsUrlSearchParams {
+    list: GcRefCell<Vec<(String, String)>>,
+    /// The `URL` object whose query is updated when the list changes, if any.
+    url: Option<JsObject>,
+}
+
+impl JsUrlSearchParams {
+    /// Creates the `URLSearchParams` object of the `URL` object `url`.
+    pub(super) fn with_url(list: Vec<(String, String)>, url: JsObject) -> Self {
+        Self {
+            list: GcRefCell::new(list),
+            url: Some(url),
+        }
+    }
+
+    /// Returns a copy of the `(name, value)` pairs.
+    #[must_use]
+    pub fn to_entries(&self) -> Vec<(String, String)> {
+        self.list.borrow().clone()
+    }
+
+    /// Serializes the pairs as an `application/x-www-form-urlencoded` string.
+    ///
+    /// See <https://url.spec.whatwg.org/#concept-urlencoded-serializer>
+    #[must_use]
+    pub fn serialize(&self) -> String {
+        form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.list.borrow().iter())
+            .finish()
+    }
+
+    /// Replaces the pairs, without running the update steps.
+    pub(super) fn set_list(&self, list: Vec<(String, String)>) {
+        *self.list.borrow_mut() = list;
+    }
+
+    /// Sets the query of the associated URL to the serialization of the pairs.
+    ///
+    /// See <https://url.spec.whatwg.org/#concept-urlsearchparams-update>
+    fn update(&self) {
+        let Some(mut url) = self.url.as_ref().and_then(JsObject::downcast_mut::<Url>) else {
+            return;
+        };
+        let query = self.serialize();
+        url.set_query((!query.is_empty()).then_some(query.as_str()));
+    }
+}
+
+/// Parses an `application/x-www-form-urlencoded` string into its `(name, value)` pairs.
+///
+/// See <https://url.spec.whatwg.org/#concept-urlencoded-parser>
+pub(super) fn parse(input: &str) -> Vec<(String, String)> {
+    form_urlencoded::parse(input.as_bytes())
+        .into_owned()
+        .collect()
+}
+
+/// Converts a value to a `USVString`, replacing lone surrogates.
+fn usv_string(value: &JsValue, context: &mut Context) -> JsResult<String> {
+    Ok(value.to_string(context)?.to_std_string_lossy())
+}
+
+/// Converts a required argument to a `USVString`.
+fn required_argument(argument: Option<Convert<JsString>>) -> JsResult<String> {
+    argument
+        .map(|Convert(ref argument)| argument.to_std_string_lossy())
+        .ok_or_else(|| js_error!(TypeError: "Not enough arguments."))
+}
+
+/// Collects the values produced by the iterator `method` of `value`.
+fn iterate(value: &JsValue, method: &JsObject, context: &mut Context) -> JsResult<Vec<JsValue>> {
+    let iterator = method.call(value, &[], context)?;
+    let iterator = iterator
+        .as_object()
+        .ok_or_else(|| js_error!(TypeError: "The iterator must be an object."))?;
+    let next = iterator.get(js_string!("next"), context)?;
+    let next = next
+        .as_callable()
+        .ok_or_else(|| js_error!(TypeError: "The iterator's next method must be callable."))?;
+
+    let mut values = Vec::new();
+    loop {
+        let result = next.call(&iterator.clone().into(), &[], context)?;
+        let result = result
+            .as_object()
+            .ok_or_else(|| js_error!(TypeError: "The iterator result must be an object."))?;
+        if result.get(js_string!("done"), context)?.to_boolean() {
+            return Ok(values);
+        }
+        values.push(result.get(js_string!("value"), context)?);
+    }
+}
+
+/// Returns the iterator method of `value`, if it is an iterable object.
+fn iterator_method(value: &JsValue, context: &mut Context) -> JsResult<Option<JsObject>> {
+    let Some(object) = value.as_object() else {
+        return Ok(None);
+    };
+    let method = object.get(JsSymbol::iterator(), context)?;
+    if method.is_null_or_undefined() {
+        return Ok(None);
+    }
+    method
+        .as_callable()
+        .map(Some)
+        .ok_or_else(|| js_error!(TypeError: "The Symbol.iterator property must be callable."))
+}
+
+/// Converts the `init` argument of the constructor to a list of pairs, which is either a
+/// sequence of pairs, a record or a string.
+///
+/// See <https://url.spec.whatwg.org/#dom-urlsearchparams-urlsearchparams>
+fn init_list(init: &JsValue, context: &mut Context) -> JsResult<Vec<(String, String)>> {
+    if let Some(method) = iterator_method(init, context)? {
+        let mut list = Vec::new();
+        for pair in iterate(init, &method, context)? {
+            let pair = iterator_method(&pair, context)?
+                .map(|method| iterate(&pair, &method, context))
+                .transpose()?
+                .ok_or_else(|| js_error!(TypeError: "Each pair must be an iterable object."))?;
+            let [name, value] = pair.as_slice() else {
+                return Err(js_error!(TypeError: "Each pair must have exactly two items."));
+            };
+            list.push((usv_string(name, context)?, usv_string(value, context)?));
+        }
+        return Ok(list);
+    }
+
+    if let Some(object) = init.as_object() {
+        let mut list = Vec::new();
+        for key in object.own_property_keys(context)? {
+            let value = object.get(key.clone(), context)?;
+            let name = usv_string(&key.into(), context)?;
+            list.push((name, usv_string(&value, context)?));
+        }
+        return Ok(list);
+    }
+
+    let init = usv_string(init, context)?;
+    Ok(parse(init.strip_prefix('?').unwrap_or(&init)))
+}
+
+#[boa_class(rename = "URLSearchParams")]
+#[boa(rename_all = "camelCase")]
+impl JsUrlSearchParams {
+    /// See <https://url.spec.whatwg.org/#dom-urlsearchparams-urlsearchparams>
+    #[boa(constructor)]
+    #[boa(length = 0)]
+    fn constructor(init: Option<JsValue>, context: &mut Context) -> JsResult<Self> {
+        let list = match init {
+            Some(init) => init_list(&init, context)?,
+            None => Vec::new(),
+        };
+        Ok(Self {
+            list: GcRefCell::new(list),
+            url: None,
+        })
+    }
+
+    /// See <https://url.spec.whatwg.org/#dom-urlsearchparams-size>
+    #[boa(getter)]
+    fn size(&self) -> usize {
+        self.list.borrow().len()
+    }
+
+    /// See <https://url.spec.whatwg.org/#dom-urlsearchparams-append>
+    #[boa(length = 2)]
+    fn append(
+        &self,
+        name: Option<Convert<JsString>>,
+        value: Option<Convert<JsString>>,
+    ) -> JsResult<()> {
+        let name = required_argument(name)?;
+        let value = required_argument(value)?;
+        self.list.borrow_mut().push((name, value));
+        self.update();
+        Ok(())
+    }
+
+    /// Removes the pairs named `name`, and whose value is `value` if it is given.
+    ///
+    /// See <https://url.spec.whatwg.org/#dom-urlsearchparams-delete>
+    #[boa(length = 1)]
+    fn delete(
+        &self,
+        name: Option<Convert<JsString>>,
+        value: Option<Convert<JsString>>,
+    ) -> JsResult<()> {
+        let name = required_argument(name)?;
+        let value = value.map(|Convert(ref value)| value.to_std_string_lossy());
+        self.list
+            .borrow_mut()
+            .retain(|(n, v)| *n != name || value.as_ref().is_some_and(|value| v != value));
+        self.update();
+        Ok(())
+    }
+
+    /// See <https://url.spec.whatwg.org/#dom-urlsearchparams-get>
+    fn get(&self, name: Option<Convert<JsString>>) -> JsResult<JsValue> {
+        let name = required_argument(name)?;
+        Ok(self
+            .list
+            .borrow()
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map_or_else(JsValue::null, |(_, value)| {
+                JsString::from(value.as_str()).into()
+            }))
+    }
+
+    /// See <https://url.spec.whatwg.org/#dom-urlsearchparams-getall>
+    fn get_all(&self, name: Option<Convert<JsString>>, context: &mut Context) -> JsResult<JsArray> {
+        let name = required_argument(name)?;
+        let values = self
+            .list
+            .borrow()
+            .iter()
+            .filter(|(n, _)| *n == name)
+            .map(|(_, value)| JsString::from(value.as_str()).into())
+            .collect::<Vec<JsValue>>();
+        Ok(JsArray::from_iter(values, context))
+    }
+
+    /// Returns whether there is a pair named `name`, and whose value is `value` if it is
+    /// given.
+    ///
+    /// See <https://url.spec.whatwg.org/#dom-urlsearchparams-has>
+    #[boa(length = 1)]
+    fn has(
+        &self,
+        name: Option<Convert<JsString>>,
+        value: Option<Convert<JsString>>,
+    ) -> JsResult<bool> {
+        let name = required_argument(name)?;
+        let value = value.map(|Convert(ref value)| value.to_std_string_lossy());
+        Ok(self
+            .list
+            .borrow()
+            .iter()
+            .any(|(n, v)| *n == name && value.as_ref().is_none_or(|value| v == value)))
+    }
+
+    /// Replaces the value of the first pair named `name` and removes the others, or appends
+    /// a new pair if there is none.
+    ///
+    /// See <https://url.spec.whatwg.org/#dom-urlsearchparams-set>
+    #[boa(length = 2)]
+    fn set(
+        &self,
+        name: Option<Convert<JsString>>,
+        value: Option<Convert<JsString>>,
+    ) -> JsResult<()> {
+        let name = required_argument(name)?;
+        let value = required_argument(value)?;
+
+        {
+            let mut list = self.list.borrow_mut();
+            if let Some(index) = list.iter().position(|(n, _)| *n == name) {
+                list[index].1 = value;
+                let rest = list.split_off(index + 1);
+                list.extend(rest.into_iter().filter(|(n, _)| *n != name));
+            } else {
+                list.push((name, value));
+            }
+        }
+        self.update();
+        Ok(())
+    }
+
+    /// Sorts the pairs by the UTF-16 code units of their names, keeping the relative order
+    /// of pairs with the same name.
+    ///
+    /// See <https://url.spec.whatwg.org/#dom-urlsearchparams-sort>
+    fn sort(&self) {
+        self.list
+            .borrow_mut()
+            .sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+        self.update();
+    }
+
+    /// See <https://url.spec.whatwg.org/#urlsearchparams-stringification-behavior>
+    fn to_string(&self) -> JsString {
+        JsString::from(self.serialize())
+    }
+
+    /// Returns an iterator over the `[name, value]` pairs.
+    fn entries(&self, context: &mut Context) -> JsResult<JsValue> {
+        let entries = self
+            .to_entries()
+            .into_iter()
+            .map(|(name, value)| {
+                JsArray::from_iter(
+                    [JsString::from(name).into(), JsString::from(value).into()],
+                    context,
+                )
+                .into()
+            })
+            .collect::<Vec<JsValue>>();
+        JsArray::from_iter(entries, context).values(context)
+    }
+
+    /// Returns an iterator over the names of the pairs.
+    fn keys(&self, context: &mut Context) -> JsResult<JsValue> {
+        let keys = self
+            .to_entries()
+            .into_iter()
+            .map(|(name, _)| JsString::from(name).into())
+            .collect::<Vec<JsValue>>();
+        JsArray::from_iter(keys, context).values(context)
+    }
+
+    /// Returns an iterator over the values of the pairs.
+    fn values(&self, context: &mut Context) -> JsResult<JsValue> {
+        let values = self
+            .to_entries()
+            .into_iter()
+            .map(|(_, value)| JsString::from(value).into())
+            .collect::<Vec<JsValue>>();
+        JsArray::from_iter(values, context).values(context)
+    }
+
+    /// Calls `callback` for each pair, with the value, the name and this object.
+    #[boa(method)]
+    #[boa(length = 1)]
+    fn for_each(
+        this: JsClass<Self>,
+        callback: ForEachCallback,
+        this_arg: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let object = this.inner().upcast();
+        let this_arg = this_arg.unwrap_or_default();
+        let entries = this.borrow().to_entries();
+        for (name, value) in entries {
+            callback.call_with_this(
+                &this_arg,
+                context,
+                (value.into(), name.into(), object.clone()),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// JavaScript module containing the `URLSearchParams` class.
+#[boa_module]
+pub mod js_module {
+    type JsUrlSearchParams = super::JsUrlSearchParams;
+}
+
+/// Register the `URLSearchParams` class into the realm/context. Pass `None` for the realm
+/// to register globally.
+///
+/// # Errors
+/// This will error if the context or realm cannot register the class.
+pub(super) fn register(realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+    js_module::boa_register(realm.clone(), context)?;
+
+    // `URLSearchParams.prototype[Symbol.iterator]` is the same function as `entries`.
+    let prototype = match realm {
+        Some(realm) => realm.get_class::<JsUrlSearchParams>(),
+        None => context.get_global_class::<JsUrlSearchParams>(),
+    }
+    .ok_or_else(|| js_error!(Error: "URLSearchParams class should be registered"))?
+    .prototype();
+    let entries = prototype.get(js_string!("entries"), context)?;
+    prototype.define_property_or_throw(
+        JsSymbol::iterator(),
+        PropertyDescriptor::builder()
+            .value(entries)
+            .writable(true)
+            .enumerable(false)
+            .configurable(true),
+        context,
+    )?;
+
+    Ok(())
+}
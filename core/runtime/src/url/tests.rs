@@ -111,3 +111,85 @@ fn url_static_methods() {
         ),
     ]);
 }
+
+#[test]
+fn url_search_params() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r##"
+                let params = new URLSearchParams("?a=1&b=2&a=3");
+                assert_eq(params.size, 3);
+                assert_eq(params.get("a"), "1");
+                assert_eq(params.get("c"), null);
+                assert_eq(params.getAll("a").join(), "1,3");
+                assert(params.has("b"));
+                assert(params.has("a", "3"));
+                assert(!params.has("a", "2"));
+
+                params.append("c", "x y");
+                params.set("a", "4");
+                assert_eq(params.toString(), "a=4&b=2&c=x+y");
+                params.delete("b", "5");
+                assert(params.has("b"));
+                params.delete("b");
+                assert_eq(params.toString(), "a=4&c=x+y");
+
+                params = new URLSearchParams([["z", "1"], ["y", "2"], ["z", "0"]]);
+                params.sort();
+                assert_eq(params.toString(), "y=2&z=1&z=0");
+                assert_eq([...params].map(([k, v]) => k + v).join(), "y2,z1,z0");
+                assert_eq([...params.keys()].join(), "y,z,z");
+                assert_eq([...params.values()].join(), "2,1,0");
+
+                let seen = [];
+                params.forEach(function (value, name, object) {
+                    assert_eq(object, params);
+                    assert_eq(this, seen);
+                    seen.push(name + value);
+                }, seen);
+                assert_eq(seen.join(), "y2,z1,z0");
+
+                params = new URLSearchParams({ "a b": "=&" });
+                assert_eq(params.toString(), "a+b=%3D%26");
+                assert_eq(new URLSearchParams(params).get("a b"), "=&");
+
+                let threw = false;
+                try {
+                    new URLSearchParams([["a"]]);
+                } catch (e) {
+                    threw = e instanceof TypeError;
+                }
+                assert(threw, "pairs must have two items");
+            "##,
+        ),
+    ]);
+}
+
+#[test]
+fn url_search_params_update_url() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r##"
+                const url = new URL("https://example.com/?a=1#hash");
+                const params = url.searchParams;
+                assert_eq(url.searchParams, params);
+                assert_eq(params.get("a"), "1");
+
+                params.append("b", "2 3");
+                assert_eq(url.href, "https://example.com/?a=1&b=2+3#hash");
+
+                params.delete("a");
+                params.delete("b");
+                assert_eq(url.search, "");
+                assert_eq(url.href, "https://example.com/#hash");
+
+                url.search = "?c=4";
+                assert_eq(params.get("c"), "4");
+                url.href = "https://example.org/?d=5";
+                assert_eq(params.toString(), "d=5");
+            "##,
+        ),
+    ]);
+}
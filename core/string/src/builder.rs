@@ -1,5 +1,7 @@
 use crate::r#type::{InternalStringType, Latin1, Utf16};
-use crate::{JsStr, JsStrVariant, JsString, SequenceString, alloc_overflow};
+use crate::{
+    JsStr, JsStrVariant, JsString, SequenceString, alloc_overflow, vtable::record_allocation,
+};
 use std::{
     alloc::{Layout, alloc, dealloc, realloc},
     marker::PhantomData,
@@ -358,10 +360,11 @@ impl<D: InternalStringType> JsStringBuilder<D> {
         let len = self.len();
 
         // Shrink to fit the length.
+        let layout = Self::new_layout(len);
         if len != self.capacity() {
-            let layout = Self::new_layout(self.len());
             self.allocate_inner(layout);
         }
+        record_allocation(layout.size());
 
         let inner = self.inner;
 
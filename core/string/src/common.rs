@@ -104,6 +104,7 @@ impl StaticJsStrings {
         (SYMBOL_UNSCOPABLES, "Symbol.unscopables"),
         (SYMBOL_DISPOSE, "Symbol.dispose"),
         (SYMBOL_ASYNC_DISPOSE, "Symbol.asyncDispose"),
+        (SYMBOL_METADATA, "Symbol.metadata"),
         (FN_SYMBOL_ASYNC_ITERATOR, "[Symbol.asyncIterator]"),
         (FN_SYMBOL_HAS_INSTANCE, "[Symbol.hasInstance]"),
         (FN_SYMBOL_IS_CONCAT_SPREADABLE, "[Symbol.isConcatSpreadable]"),
@@ -119,6 +120,7 @@ impl StaticJsStrings {
         (FN_SYMBOL_UNSCOPABLES, "[Symbol.unscopables]"),
         (FN_SYMBOL_DISPOSE, "[Symbol.dispose]"),
         (FN_SYMBOL_ASYNC_DISPOSE, "[Symbol.asyncDispose]"),
+        (FN_SYMBOL_METADATA, "[Symbol.metadata]"),
         // Builtins
         (ARRAY, "Array"),
         (ARRAY_BUFFER, "ArrayBuffer"),
@@ -131,9 +133,13 @@ impl StaticJsStrings {
         (BIG_INT, "BigInt"),
         (BOOLEAN, "Boolean"),
         (DATA_VIEW, "DataView"),
+        (DISPOSABLE_STACK, "DisposableStack"),
+        (ASYNC_DISPOSABLE_STACK, "AsyncDisposableStack"),
+        (SHADOW_REALM, "ShadowRealm"),
         (DATE, "Date"),
         (ERROR, "Error"),
         (AGGREGATE_ERROR, "AggregateError"),
+        (SUPPRESSED_ERROR, "SuppressedError"),
         (EVAL_ERROR, "EvalError"),
         (RANGE_ERROR, "RangeError"),
         (REFERENCE_ERROR, "ReferenceError"),
@@ -148,9 +154,12 @@ impl StaticJsStrings {
         (GENERATOR_FUNCTION, "GeneratorFunction"),
         (INTL, "Intl"),
         (COLLATOR, "Collator"),
+        (DISPLAY_NAMES, "DisplayNames"),
+        (DURATION_FORMAT, "DurationFormat"),
         (LIST_FORMAT, "ListFormat"),
         (LOCALE, "Locale"),
         (PLURAL_RULES, "PluralRules"),
+        (RELATIVE_TIME_FORMAT, "RelativeTimeFormat"),
         (SEGMENTER, "Segmenter"),
         (DATE_TIME_FORMAT, "DateTimeFormat"),
         (JSON, "JSON"),
@@ -265,6 +274,8 @@ const RAW_STATICS: &[StaticString] = &[
     StaticString::new(JsStr::latin1("[Symbol.dispose]".as_bytes())),
     StaticString::new(JsStr::latin1("Symbol.asyncDispose".as_bytes())),
     StaticString::new(JsStr::latin1("[Symbol.asyncDispose]".as_bytes())),
+    StaticString::new(JsStr::latin1("Symbol.metadata".as_bytes())),
+    StaticString::new(JsStr::latin1("[Symbol.metadata]".as_bytes())),
     StaticString::new(JsStr::latin1("get [Symbol.species]".as_bytes())),
     StaticString::new(JsStr::latin1("get [Symbol.toStringTag]".as_bytes())),
     // Well known builtins
@@ -279,9 +290,13 @@ const RAW_STATICS: &[StaticString] = &[
     StaticString::new(JsStr::latin1("BigInt".as_bytes())),
     StaticString::new(JsStr::latin1("Boolean".as_bytes())),
     StaticString::new(JsStr::latin1("DataView".as_bytes())),
+    StaticString::new(JsStr::latin1("DisposableStack".as_bytes())),
+    StaticString::new(JsStr::latin1("AsyncDisposableStack".as_bytes())),
+    StaticString::new(JsStr::latin1("ShadowRealm".as_bytes())),
     StaticString::new(JsStr::latin1("Date".as_bytes())),
     StaticString::new(JsStr::latin1("Error".as_bytes())),
     StaticString::new(JsStr::latin1("AggregateError".as_bytes())),
+    StaticString::new(JsStr::latin1("SuppressedError".as_bytes())),
     StaticString::new(JsStr::latin1("EvalError".as_bytes())),
     StaticString::new(JsStr::latin1("RangeError".as_bytes())),
     StaticString::new(JsStr::latin1("ReferenceError".as_bytes())),
@@ -296,9 +311,12 @@ const RAW_STATICS: &[StaticString] = &[
     StaticString::new(JsStr::latin1("GeneratorFunction".as_bytes())),
     StaticString::new(JsStr::latin1("Intl".as_bytes())),
     StaticString::new(JsStr::latin1("Collator".as_bytes())),
+    StaticString::new(JsStr::latin1("DisplayNames".as_bytes())),
+    StaticString::new(JsStr::latin1("DurationFormat".as_bytes())),
     StaticString::new(JsStr::latin1("ListFormat".as_bytes())),
     StaticString::new(JsStr::latin1("Locale".as_bytes())),
     StaticString::new(JsStr::latin1("PluralRules".as_bytes())),
+    StaticString::new(JsStr::latin1("RelativeTimeFormat".as_bytes())),
     StaticString::new(JsStr::latin1("Segmenter".as_bytes())),
     StaticString::new(JsStr::latin1("DateTimeFormat".as_bytes())),
     StaticString::new(JsStr::latin1("JSON".as_bytes())),
@@ -29,7 +29,7 @@ use crate::display::{JsStrDisplayEscaped, JsStrDisplayLossy, JsStringDebugInfo};
 use crate::iter::CodePointsIter;
 use crate::r#type::{Latin1, Utf16};
 pub use crate::vtable::StaticString;
-use crate::vtable::{SequenceString, SliceString};
+use crate::vtable::{RopeString, SequenceString, SliceString};
 #[doc(inline)]
 pub use crate::{
     builder::{CommonJsStringBuilder, Latin1JsStringBuilder, Utf16JsStringBuilder},
@@ -48,6 +48,15 @@ use std::{
 };
 use vtable::JsStringVTable;
 
+/// The minimum length of a string produced by [`JsString::concat_rope`] to be represented
+/// as a rope. Shorter strings are cheaper to copy than to concatenate lazily.
+const ROPE_MIN_LENGTH: usize = 13;
+
+/// The minimum length of the first string passed to [`JsString::concat_rope_array`] to append
+/// the rest of the strings to it as a rope. Shorter strings are copied with the rest, since they
+/// are unlikely to be an accumulator that keeps growing.
+const ROPE_MIN_ACCUMULATOR_LENGTH: usize = 256;
+
 fn alloc_overflow() -> ! {
     panic!("detected overflow during string allocation")
 }
@@ -114,6 +123,9 @@ pub(crate) enum JsStringKind {
 
     /// A static string that is valid for `'static` lifetime.
     Static = 3,
+
+    /// A lazy concatenation of two strings. See [`RopeString`].
+    Rope = 4,
 }
 
 /// A Latin1 or UTF-16–encoded, reference counted, immutable string.
@@ -162,6 +174,16 @@ impl<'a> IntoIterator for &'a JsString {
 }
 
 impl JsString {
+    /// Returns the number of bytes used by the heap allocated strings of the current thread.
+    ///
+    /// Slices and ropes only count through the strings they keep alive, and static strings are
+    /// never counted.
+    #[inline]
+    #[must_use]
+    pub fn allocated_bytes() -> usize {
+        vtable::allocated_bytes()
+    }
+
     /// Create an iterator over the [`JsString`].
     #[inline]
     #[must_use]
@@ -651,6 +673,24 @@ impl JsString {
             full_count = sum;
         }
 
+        // SAFETY: We just computed the length and encoding from `strings`.
+        unsafe { Self::concat_exact(full_count, latin1_encoding, strings.iter().copied()) }
+    }
+
+    /// Creates a new [`JsString`] from the concatenation of every element of `strings`, given
+    /// the length and encoding of the result.
+    ///
+    /// # Safety
+    /// The caller must ensure that the lengths of `strings` sum up to `full_count`, and that all
+    /// of them are latin1 encoded if `latin1_encoding` is `true`.
+    pub(crate) unsafe fn concat_exact<'a, I>(
+        full_count: usize,
+        latin1_encoding: bool,
+        strings: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = JsStr<'a>>,
+    {
         let (ptr, data_offset) = if latin1_encoding {
             let p = SequenceString::<Latin1>::allocate(full_count);
             (p.cast::<u8>(), size_of::<SequenceString<Latin1>>())
@@ -665,7 +705,7 @@ impl JsString {
                 let seq_ptr = ptr.as_ptr();
                 seq_ptr.add(data_offset)
             };
-            for &string in strings {
+            for string in strings {
                 // SAFETY:
                 // The sum of all `count` for each `string` equals `full_count`, and since we're
                 // iteratively writing each of them to `data`, `copy_non_overlapping` always stays
@@ -710,6 +750,50 @@ impl JsString {
         StaticJsStrings::get_string(&string.as_str()).unwrap_or(string)
     }
 
+    /// Creates a new [`JsString`] from the concatenation of `x` and `y`, without copying their
+    /// contents if the result is long enough.
+    ///
+    /// The result is a rope that is flattened the first time its contents are accessed (for
+    /// example when indexing, hashing or comparing it), which makes appending to a string
+    /// in a loop linear instead of quadratic.
+    #[inline]
+    #[must_use]
+    pub fn concat_rope(x: &JsString, y: &JsString) -> Self {
+        if x.is_empty() {
+            return y.clone();
+        }
+        if y.is_empty() {
+            return x.clone();
+        }
+        let Some(len) = x.len().checked_add(y.len()) else {
+            alloc_overflow()
+        };
+        if len < ROPE_MIN_LENGTH {
+            return Self::concat(x.as_str(), y.as_str());
+        }
+
+        let rope = Box::new(RopeString::new(x.clone(), y.clone()));
+        Self {
+            ptr: NonNull::from(Box::leak(rope)).cast(),
+        }
+    }
+
+    /// Creates a new [`JsString`] from the concatenation of every element of `strings`.
+    ///
+    /// The strings are copied into a flat string, except for a long first string, which the
+    /// rest of the strings are appended to as a rope (see [`JsString::concat_rope`]). This keeps
+    /// appending to an accumulator in a loop linear, as in ``s = `${s}, ${item}` ``.
+    #[inline]
+    #[must_use]
+    pub fn concat_rope_array(strings: &[JsString]) -> Self {
+        let (start, strings) = match strings {
+            [first, rest @ ..] if first.len() >= ROPE_MIN_ACCUMULATOR_LENGTH => (first, rest),
+            _ => (&StaticJsStrings::EMPTY_STRING, strings),
+        };
+        let rest = Self::concat_array(&strings.iter().map(Self::as_str).collect::<Vec<_>>());
+        Self::concat_rope(start, &rest)
+    }
+
     /// Creates a new [`JsString`] from `data`, without checking if the string is in the interner.
     fn from_slice_skip_interning(string: JsStr<'_>) -> Self {
         let count = string.len();
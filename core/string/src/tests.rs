@@ -2,6 +2,7 @@
 
 use std::hash::{BuildHasher, BuildHasherDefault, Hash};
 
+use crate::vtable::RopeString;
 use crate::{
     CodePoint, CommonJsStringBuilder, JsStr, JsString, JsStringKind, Latin1JsStringBuilder,
     StaticJsStrings, StaticString, Utf16JsStringBuilder,
@@ -53,6 +54,26 @@ fn refcount() {
     assert_eq!(x.refcount(), Some(1));
 }
 
+#[test]
+fn allocated_bytes() {
+    let before = JsString::allocated_bytes();
+
+    let x = JsString::from("Hello world");
+    let mut builder = Latin1JsStringBuilder::new();
+    builder.extend_from_slice(b"Hello world, again");
+    let y = builder
+        .build()
+        .expect("the builder only contains ascii characters");
+    let allocated = JsString::allocated_bytes();
+    assert!(allocated >= before + x.len() + y.len());
+
+    let z = x.clone();
+    assert_eq!(JsString::allocated_bytes(), allocated);
+
+    drop((x, y, z));
+    assert_eq!(JsString::allocated_bytes(), before);
+}
+
 #[test]
 fn static_refcount() {
     let x = StaticJsStrings::EMPTY_STRING;
@@ -529,6 +550,109 @@ fn slice() {
     assert_eq!(sliced5.slice(4, 4), StaticJsStrings::EMPTY_STRING);
 }
 
+#[test]
+fn rope() {
+    let hello = JsString::from("Hello, ");
+    let world = JsString::from("wonderful world");
+    let rope = JsString::concat_rope(&hello, &world);
+    assert_eq!(rope.kind(), JsStringKind::Rope);
+    assert_eq!(rope.len(), 22);
+
+    // SAFETY: Just verified the kind.
+    let inner: &RopeString = unsafe { rope.as_inner() };
+    assert!(!inner.is_flattened());
+    assert_eq!(rope, JsString::from("Hello, wonderful world"));
+    assert!(inner.is_flattened());
+    assert_eq!(
+        hash_value(&rope),
+        hash_value(&JsString::from("Hello, wonderful world"))
+    );
+
+    // Short results are copied directly.
+    let short = JsString::concat_rope(&hello, &JsString::from("you"));
+    assert_eq!(short.kind(), JsStringKind::Latin1Sequence);
+    assert_eq!(short, JsString::from("Hello, you"));
+    assert_eq!(
+        JsString::concat_rope(&StaticJsStrings::EMPTY_STRING, &world).kind(),
+        JsStringKind::Latin1Sequence
+    );
+
+    // Mixed encodings.
+    let utf16 = JsString::from("\u{1F600} wonderful world");
+    let rope = JsString::concat_rope(&JsString::concat_rope(&hello, &utf16), &hello);
+    assert_eq!(rope.kind(), JsStringKind::Rope);
+    assert_eq!(
+        rope,
+        JsString::from("Hello, \u{1F600} wonderful worldHello, ")
+    );
+    assert_eq!(
+        rope.to_std_string_escaped(),
+        "Hello, \u{1F600} wonderful worldHello, "
+    );
+}
+
+#[test]
+fn rope_shared_children() {
+    let base = JsString::concat_rope(&JsString::from("a long enough "), &JsString::from("string"));
+    let left = JsString::concat_rope(&base, &JsString::from("!"));
+    let right = JsString::concat_rope(&JsString::from("?"), &base);
+    let rope = JsString::concat_rope(&left, &right);
+
+    assert_eq!(
+        rope,
+        JsString::from("a long enough string!?a long enough string")
+    );
+    drop(rope);
+    assert_eq!(left, JsString::from("a long enough string!"));
+    assert_eq!(base.refcount(), Some(2));
+    drop(left);
+    assert_eq!(right, JsString::from("?a long enough string"));
+    assert_eq!(base, JsString::from("a long enough string"));
+}
+
+#[test]
+fn rope_deep() {
+    // Neither flattening nor dropping a deep rope should overflow the stack.
+    let piece = JsString::from("0123456789");
+    let mut string = JsString::default();
+    for _ in 0..1_000_000 {
+        string = JsString::concat_rope(&string, &piece);
+    }
+    assert_eq!(string.kind(), JsStringKind::Rope);
+    assert_eq!(string.len(), 10_000_000);
+    assert_eq!(string.code_unit_at(9_999_999), Some(u16::from(b'9')));
+
+    let mut string = JsString::default();
+    for _ in 0..1_000_000 {
+        string = JsString::concat_rope(&piece, &string);
+    }
+    drop(string);
+
+    let mut string = JsString::default();
+    for _ in 0..100_000 {
+        string = JsString::concat_rope_array(&[string, piece.clone()]);
+    }
+    assert_eq!(string.kind(), JsStringKind::Rope);
+    assert_eq!(string.len(), 1_000_000);
+}
+
+#[test]
+fn rope_array() {
+    // The pieces of short strings are copied into a single flat string.
+    let piece = JsString::from("0123456789");
+    let strings = vec![piece; 100];
+    let string = JsString::concat_rope_array(&strings);
+    assert_eq!(string.kind(), JsStringKind::Latin1Sequence);
+    assert_eq!(string.len(), 1000);
+    assert_eq!(string, JsString::from(&strings[..]));
+
+    // The rest of the pieces are appended to a long first string as a rope.
+    let rope = JsString::concat_rope_array(&[string.clone(), JsString::from("!"), string]);
+    assert_eq!(rope.kind(), JsStringKind::Rope);
+    assert_eq!(rope.len(), 2001);
+    assert_eq!(rope.code_unit_at(1000), Some(u16::from(b'!')));
+}
+
 #[test]
 fn split() {
     let base_str = JsString::from("Hello World");
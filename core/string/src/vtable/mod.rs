@@ -4,11 +4,14 @@ use crate::{JsStr, JsString, JsStringKind};
 use std::ptr::NonNull;
 
 mod sequence;
-pub(crate) use sequence::SequenceString;
+pub(crate) use sequence::{SequenceString, allocated_bytes, record_allocation};
 
 pub(crate) mod slice;
 pub(crate) use slice::SliceString;
 
+pub(crate) mod rope;
+pub(crate) use rope::RopeString;
+
 pub(crate) mod r#static;
 pub use r#static::StaticString;
 
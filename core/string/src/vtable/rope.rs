@@ -0,0 +1,213 @@
+//! `VTable` implementations for [`RopeString`].
+use crate::iter::CodePointsIter;
+use crate::vtable::JsStringVTable;
+use crate::{JsStr, JsString, JsStringKind};
+use std::cell::{Cell, OnceCell, UnsafeCell};
+use std::process::abort;
+use std::ptr::NonNull;
+
+/// The lazy concatenation of two strings.
+///
+/// The contents of the string are only copied into a flat string the first time they are
+/// accessed. After that, the children are released and every access goes to the flat string.
+///
+/// # Notes
+/// The children can be arbitrarily deep ropes (appending to a string in a loop creates a rope
+/// with one level per iteration), so flattening and dropping never recurse.
+#[repr(C)]
+pub(crate) struct RopeString {
+    /// Embedded `VTable` - must be the first field for vtable dispatch.
+    vtable: JsStringVTable,
+    // The left and right sides of the concatenation, or `None` once the rope is flattened.
+    //
+    // The children are only borrowed while flattening a rope that contains this one, which never
+    // flattens this rope, and only mutated when flattening or dropping this rope.
+    children: UnsafeCell<Option<(JsString, JsString)>>,
+    // The flattened string, computed on first access.
+    flat: OnceCell<JsString>,
+    // Whether all the leaves of this rope are latin1 encoded.
+    latin1: bool,
+    refcount: Cell<usize>,
+}
+
+impl RopeString {
+    /// Create a new rope string given its children.
+    ///
+    /// # Panics
+    /// Panics if the sum of the lengths overflows `usize`.
+    #[inline]
+    #[must_use]
+    pub(crate) fn new(left: JsString, right: JsString) -> Self {
+        let Some(len) = left.len().checked_add(right.len()) else {
+            crate::alloc_overflow()
+        };
+        RopeString {
+            vtable: JsStringVTable {
+                clone: rope_clone,
+                drop: rope_drop,
+                as_str: rope_as_str,
+                code_points: rope_code_points,
+                refcount: rope_refcount,
+                len,
+                kind: JsStringKind::Rope,
+            },
+            latin1: is_latin1(&left) && is_latin1(&right),
+            children: UnsafeCell::new(Some((left, right))),
+            flat: OnceCell::new(),
+            refcount: Cell::new(1),
+        }
+    }
+
+    /// Returns `true` if the contents of this rope were already copied into a flat string.
+    #[cfg(test)]
+    #[must_use]
+    pub(crate) fn is_flattened(&self) -> bool {
+        self.flat.get().is_some()
+    }
+
+    /// Returns the children of this rope, or `None` if it was already flattened.
+    #[inline]
+    fn children(&self) -> Option<&(JsString, JsString)> {
+        // SAFETY: The children are only mutated when flattening or dropping this rope, neither
+        //         of which can happen while `&self` is borrowed by a caller of this function.
+        unsafe { (*self.children.get()).as_ref() }
+    }
+
+    /// Returns the flat string, copying the contents of the leaves on first access.
+    fn flat(&self) -> &JsString {
+        if let Some(flat) = self.flat.get() {
+            return flat;
+        }
+
+        let flat = {
+            let (left, right) = self
+                .children()
+                .expect("unflattened ropes must have children");
+            let leaves = Leaves {
+                stack: vec![right, left],
+            };
+            // SAFETY: The leaves sum up to the length of the rope, and all of them are latin1
+            //         encoded if the rope is.
+            unsafe { JsString::concat_exact(self.vtable.len, self.latin1, leaves) }
+        };
+        let flat = self.flat.get_or_init(|| flat);
+
+        // SAFETY: The borrow of the children above has ended, and the rope is flattened so no one
+        //         can borrow them again.
+        let children = unsafe { (*self.children.get()).take() };
+        drop_children(children);
+
+        flat
+    }
+}
+
+/// Returns `true` if `string` is latin1 encoded, without flattening it if it is a rope.
+#[inline]
+fn is_latin1(string: &JsString) -> bool {
+    if string.kind() == JsStringKind::Rope {
+        // SAFETY: Just verified the kind.
+        let rope: &RopeString = unsafe { string.as_inner() };
+        return rope.latin1;
+    }
+    string.as_str().is_latin1()
+}
+
+/// Iterator over the leaves of a rope, in order.
+struct Leaves<'a> {
+    stack: Vec<&'a JsString>,
+}
+
+impl<'a> Iterator for Leaves<'a> {
+    type Item = JsStr<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(string) = self.stack.pop() {
+            if string.kind() == JsStringKind::Rope {
+                // SAFETY: Just verified the kind.
+                let rope: &RopeString = unsafe { string.as_inner() };
+                if let Some((left, right)) = rope.children() {
+                    self.stack.push(right);
+                    self.stack.push(left);
+                    continue;
+                }
+            }
+            // This is either a leaf or an already flattened rope.
+            return Some(string.as_str());
+        }
+        None
+    }
+}
+
+/// Drops the children of a rope without recursing into ropes that are only referenced by it.
+fn drop_children(children: Option<(JsString, JsString)>) {
+    let Some((left, right)) = children else {
+        return;
+    };
+    let mut stack = vec![left, right];
+    while let Some(string) = stack.pop() {
+        if string.kind() == JsStringKind::Rope && string.refcount() == Some(1) {
+            // SAFETY: Just verified the kind.
+            let rope: &RopeString = unsafe { string.as_inner() };
+            // SAFETY: This is the last reference to the rope, so nothing else can borrow its
+            //         children.
+            if let Some((left, right)) = unsafe { (*rope.children.get()).take() } {
+                stack.push(left);
+                stack.push(right);
+            }
+        }
+        // Dropping the string here can no longer recurse, as it is either shared or childless.
+        drop(string);
+    }
+}
+
+#[inline]
+fn rope_clone(vtable: NonNull<JsStringVTable>) -> JsString {
+    // SAFETY: This is part of the correct vtable which is validated on construction.
+    let this: &RopeString = unsafe { vtable.cast().as_ref() };
+    let Some(strong) = this.refcount.get().checked_add(1) else {
+        abort();
+    };
+    this.refcount.set(strong);
+    // SAFETY: validated the string outside this function.
+    unsafe { JsString::from_ptr(vtable) }
+}
+
+#[inline]
+fn rope_drop(vtable: NonNull<JsStringVTable>) {
+    // SAFETY: This is part of the correct vtable which is validated on construction.
+    let this: &RopeString = unsafe { vtable.cast().as_ref() };
+    let Some(new) = this.refcount.get().checked_sub(1) else {
+        abort();
+    };
+    this.refcount.set(new);
+    if new != 0 {
+        return;
+    }
+
+    // SAFETY: This is the last reference, so we can deallocate.
+    // The vtable pointer is actually pointing to a RopeString, so cast it correctly.
+    let this = unsafe { Box::from_raw(vtable.cast::<RopeString>().as_ptr()) };
+    drop_children(this.children.into_inner());
+}
+
+#[inline]
+fn rope_as_str(vtable: NonNull<JsStringVTable>) -> JsStr<'static> {
+    // SAFETY: This is part of the correct vtable which is validated on construction.
+    let this: &RopeString = unsafe { vtable.cast().as_ref() };
+    // SAFETY: The flat string is never replaced once set, so its data lives as long as the rope.
+    unsafe { this.flat().as_str().as_static() }
+}
+
+#[inline]
+fn rope_code_points(vtable: NonNull<JsStringVTable>) -> CodePointsIter<'static> {
+    CodePointsIter::new(rope_as_str(vtable))
+}
+
+/// `VTable` function for refcount, need to return an `Option<usize>`.
+#[inline]
+#[allow(clippy::unnecessary_wraps)]
+fn rope_refcount(vtable: NonNull<JsStringVTable>) -> Option<usize> {
+    // SAFETY: This is part of the correct vtable which is validated on construction.
+    let this: &RopeString = unsafe { vtable.cast().as_ref() };
+    Some(this.refcount.get())
+}
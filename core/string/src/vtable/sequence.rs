@@ -10,6 +10,26 @@ use std::process::abort;
 use std::ptr;
 use std::ptr::NonNull;
 
+thread_local! {
+    /// The number of bytes used by the live sequence strings of the current thread.
+    static ALLOCATED_BYTES: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Returns the number of bytes used by the live sequence strings of the current thread.
+pub(crate) fn allocated_bytes() -> usize {
+    ALLOCATED_BYTES.with(Cell::get)
+}
+
+/// Records the allocation of a sequence string of `size` bytes.
+pub(crate) fn record_allocation(size: usize) {
+    ALLOCATED_BYTES.with(|bytes| bytes.set(bytes.get().saturating_add(size)));
+}
+
+/// Records the deallocation of a sequence string of `size` bytes.
+fn record_deallocation(size: usize) {
+    ALLOCATED_BYTES.with(|bytes| bytes.set(bytes.get().saturating_sub(size)));
+}
+
 /// A sequential memory array of `T::Char` elements.
 ///
 /// # Notes
@@ -86,6 +106,7 @@ impl<T: InternalStringType> SequenceString<T> {
         // we should abort, since an allocation error is pretty unrecoverable for us
         // right now.
         let inner = NonNull::new(inner).ok_or(Some(layout))?;
+        record_allocation(layout.size());
 
         // SAFETY:
         // `NonNull` verified for us that the pointer returned by `alloc` is valid,
@@ -157,6 +178,8 @@ fn seq_drop<T: InternalStringType>(vtable: NonNull<JsStringVTable>) {
             .pad_to_align()
     };
 
+    record_deallocation(layout.size());
+
     // SAFETY: If refcount is 0, this is the last reference, so deallocating is safe.
     unsafe {
         dealloc(vtable.as_ptr().cast(), layout);
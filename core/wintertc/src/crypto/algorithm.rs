@@ -0,0 +1,530 @@
+//! Algorithm normalization and the cryptographic primitives behind `SubtleCrypto`.
+//!
+//! See <https://w3c.github.io/webcrypto/#algorithm-normalization-normalize-an-algorithm>
+
+use super::{buffer_source_copy, dom_exception};
+use aes_gcm::aead::{Aead, KeyInit, Nonce, Payload};
+use aes_gcm::{Aes128Gcm, Aes256Gcm, AesGcm};
+use boa_engine::{Context, JsObject, JsResult, JsString, JsValue, js_error, js_string};
+use cbc::cipher::block_padding::Pkcs7;
+use cbc::cipher::{BlockCipher, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+
+/// AES-GCM with a 192-bit key and a 96-bit nonce.
+type Aes192Gcm = AesGcm<aes::Aes192, aes_gcm::aead::consts::U12>;
+
+/// The length of the initialization vector of AES-GCM, in bytes.
+const GCM_IV_LENGTH: usize = 12;
+
+/// The length of the initialization vector of AES-CBC, in bytes.
+const CBC_IV_LENGTH: usize = 16;
+
+/// The `AlgorithmIdentifier` names recognized by this implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AlgorithmName {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+    Hmac,
+    AesGcm,
+    AesCbc,
+}
+
+impl AlgorithmName {
+    const ALL: [Self; 7] = [
+        Self::Sha1,
+        Self::Sha256,
+        Self::Sha384,
+        Self::Sha512,
+        Self::Hmac,
+        Self::AesGcm,
+        Self::AesCbc,
+    ];
+
+    /// Returns the registered name of the algorithm.
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Sha1 => "SHA-1",
+            Self::Sha256 => "SHA-256",
+            Self::Sha384 => "SHA-384",
+            Self::Sha512 => "SHA-512",
+            Self::Hmac => "HMAC",
+            Self::AesGcm => "AES-GCM",
+            Self::AesCbc => "AES-CBC",
+        }
+    }
+
+    /// Returns the usages allowed for keys of this algorithm.
+    pub(crate) const fn allowed_usages(self) -> &'static [KeyUsage] {
+        match self {
+            Self::Hmac => &[KeyUsage::Sign, KeyUsage::Verify],
+            Self::AesGcm | Self::AesCbc => &[
+                KeyUsage::Encrypt,
+                KeyUsage::Decrypt,
+                KeyUsage::WrapKey,
+                KeyUsage::UnwrapKey,
+            ],
+            Self::Sha1 | Self::Sha256 | Self::Sha384 | Self::Sha512 => &[],
+        }
+    }
+
+    /// Returns the hash function with this name, if it is one.
+    const fn hash(self) -> Option<Hash> {
+        match self {
+            Self::Sha1 => Some(Hash::Sha1),
+            Self::Sha256 => Some(Hash::Sha256),
+            Self::Sha384 => Some(Hash::Sha384),
+            Self::Sha512 => Some(Hash::Sha512),
+            Self::Hmac | Self::AesGcm | Self::AesCbc => None,
+        }
+    }
+}
+
+/// An `AlgorithmIdentifier` whose name has been resolved, along with its parameters
+/// dictionary, if it was given as an object.
+#[derive(Debug)]
+pub(crate) struct Algorithm {
+    pub(crate) name: AlgorithmName,
+    params: Option<JsObject>,
+}
+
+impl Algorithm {
+    /// Resolves the name of an `AlgorithmIdentifier`, which is either a string or a
+    /// dictionary with a `name` member.
+    ///
+    /// Names are matched ASCII case-insensitively. Unknown names throw a `NotSupportedError`.
+    pub(crate) fn normalize(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let (name, params) = if let Some(object) = value.as_object() {
+            let name = object.get(js_string!("name"), context)?;
+            if name.is_undefined() {
+                return Err(js_error!(TypeError: "the algorithm has no `name` member"));
+            }
+            (name.to_string(context)?, Some(object))
+        } else {
+            (value.to_string(context)?, None)
+        };
+
+        let name = name.to_std_string_lossy();
+        let Some(name) = AlgorithmName::ALL
+            .into_iter()
+            .find(|known| known.as_str().eq_ignore_ascii_case(&name))
+        else {
+            return Err(dom_exception(
+                "NotSupportedError",
+                format!("unrecognized algorithm name `{name}`"),
+                context,
+            ));
+        };
+
+        Ok(Self { name, params })
+    }
+
+    /// Throws a `NotSupportedError` if the algorithm isn't one of `supported`.
+    pub(crate) fn expect(
+        self,
+        operation: &str,
+        supported: &[AlgorithmName],
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        if supported.contains(&self.name) {
+            Ok(self)
+        } else {
+            Err(dom_exception(
+                "NotSupportedError",
+                format!(
+                    "the {} algorithm does not support `{operation}`",
+                    self.name.as_str()
+                ),
+                context,
+            ))
+        }
+    }
+
+    /// Reads a member of the parameters dictionary, returning `None` if it is `undefined`.
+    fn member(&self, key: JsString, context: &mut Context) -> JsResult<Option<JsValue>> {
+        let Some(params) = &self.params else {
+            return Ok(None);
+        };
+        let value = params.get(key, context)?;
+        Ok((!value.is_undefined()).then_some(value))
+    }
+
+    /// Reads a required member of the parameters dictionary.
+    fn required(&self, key: &JsString, context: &mut Context) -> JsResult<JsValue> {
+        self.member(key.clone(), context)?.ok_or_else(|| {
+            js_error!(
+                TypeError: "the {} parameters are missing the required `{}` member",
+                self.name.as_str(),
+                key.to_std_string_lossy()
+            )
+        })
+    }
+
+    /// Reads a `[EnforceRange] unsigned long` member of the parameters dictionary.
+    fn length_member(&self, key: JsString, context: &mut Context) -> JsResult<Option<u32>> {
+        let Some(value) = self.member(key, context)? else {
+            return Ok(None);
+        };
+        let number = value.to_number(context)?;
+        if !number.is_finite() || number.trunc() < 0.0 || number.trunc() > f64::from(u32::MAX) {
+            return Err(js_error!(TypeError: "the length is out of range"));
+        }
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Some(number.trunc() as u32))
+    }
+
+    /// Reads the `hash` member of the `HmacKeyGenParams` and `HmacImportParams`
+    /// dictionaries.
+    pub(crate) fn hash(&self, context: &mut Context) -> JsResult<Hash> {
+        let hash = self.required(&js_string!("hash"), context)?;
+        let hash = Self::normalize(&hash, context)?;
+        match hash.name.hash() {
+            Some(hash) => Ok(hash),
+            None => Err(dom_exception(
+                "NotSupportedError",
+                format!("{} is not a hash function", hash.name.as_str()),
+                context,
+            )),
+        }
+    }
+
+    /// Reads the optional `length` member of the HMAC key parameters, in bits.
+    pub(crate) fn hmac_length(&self, context: &mut Context) -> JsResult<Option<u32>> {
+        self.length_member(js_string!("length"), context)
+    }
+
+    /// Reads the `length` member of the `AesKeyGenParams` dictionary, in bits.
+    pub(crate) fn aes_length(&self, context: &mut Context) -> JsResult<u32> {
+        let length = self
+            .length_member(js_string!("length"), context)?
+            .ok_or_else(|| {
+                js_error!(TypeError: "the AES key parameters are missing the required `length` member")
+            })?;
+        if matches!(length, 128 | 192 | 256) {
+            Ok(length)
+        } else {
+            Err(dom_exception(
+                "OperationError",
+                "the AES key length must be 128, 192 or 256 bits",
+                context,
+            ))
+        }
+    }
+
+    /// Reads the encryption parameters of the AES algorithms.
+    pub(crate) fn cipher_params(&self, context: &mut Context) -> JsResult<CipherParams> {
+        let iv = self.required(&js_string!("iv"), context)?;
+        let iv = buffer_source_copy(&iv, context)?;
+
+        if self.name != AlgorithmName::AesGcm {
+            if iv.len() != CBC_IV_LENGTH {
+                return Err(dom_exception(
+                    "OperationError",
+                    "the AES-CBC initialization vector must be 16 bytes long",
+                    context,
+                ));
+            }
+            return Ok(CipherParams::Cbc { iv });
+        }
+
+        let additional_data = match self.member(js_string!("additionalData"), context)? {
+            Some(data) => buffer_source_copy(&data, context)?,
+            None => Vec::new(),
+        };
+        let tag_length = self
+            .length_member(js_string!("tagLength"), context)?
+            .unwrap_or(128);
+        match tag_length {
+            128 => {}
+            32 | 64 | 96 | 104 | 112 | 120 => {
+                return Err(dom_exception(
+                    "NotSupportedError",
+                    "only 128-bit AES-GCM tags are supported",
+                    context,
+                ));
+            }
+            _ => {
+                return Err(dom_exception(
+                    "OperationError",
+                    "invalid AES-GCM tag length",
+                    context,
+                ));
+            }
+        }
+        if iv.len() != GCM_IV_LENGTH {
+            return Err(dom_exception(
+                "NotSupportedError",
+                "only 96-bit AES-GCM initialization vectors are supported",
+                context,
+            ));
+        }
+        Ok(CipherParams::Gcm {
+            iv,
+            additional_data,
+        })
+    }
+}
+
+/// The hash functions usable with `digest` and HMAC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Hash {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl Hash {
+    /// Returns the registered name of the hash function.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Sha1 => AlgorithmName::Sha1.as_str(),
+            Self::Sha256 => AlgorithmName::Sha256.as_str(),
+            Self::Sha384 => AlgorithmName::Sha384.as_str(),
+            Self::Sha512 => AlgorithmName::Sha512.as_str(),
+        }
+    }
+
+    /// Returns the block size of the hash function in bits, the default HMAC key length.
+    pub(crate) const fn block_size(self) -> u32 {
+        match self {
+            Self::Sha1 | Self::Sha256 => 512,
+            Self::Sha384 | Self::Sha512 => 1024,
+        }
+    }
+
+    /// Returns the JWK `alg` value of HMAC with this hash function.
+    pub(crate) const fn jwk_hmac_alg(self) -> &'static str {
+        match self {
+            Self::Sha1 => "HS1",
+            Self::Sha256 => "HS256",
+            Self::Sha384 => "HS384",
+            Self::Sha512 => "HS512",
+        }
+    }
+
+    /// Hashes `data`.
+    pub(crate) fn digest(self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Sha1 => Sha1::digest(data).to_vec(),
+            Self::Sha256 => Sha256::digest(data).to_vec(),
+            Self::Sha384 => Sha384::digest(data).to_vec(),
+            Self::Sha512 => Sha512::digest(data).to_vec(),
+        }
+    }
+
+    /// Computes the HMAC of `data` with this hash function.
+    pub(crate) fn sign(self, key: &[u8], data: &[u8]) -> Vec<u8> {
+        fn sign<M: Mac + KeyInit>(key: &[u8], data: &[u8]) -> Vec<u8> {
+            // HMAC accepts keys of any length.
+            let Ok(mut mac) = <M as Mac>::new_from_slice(key) else {
+                return Vec::new();
+            };
+            mac.update(data);
+            mac.finalize().into_bytes().to_vec()
+        }
+
+        match self {
+            Self::Sha1 => sign::<Hmac<Sha1>>(key, data),
+            Self::Sha256 => sign::<Hmac<Sha256>>(key, data),
+            Self::Sha384 => sign::<Hmac<Sha384>>(key, data),
+            Self::Sha512 => sign::<Hmac<Sha512>>(key, data),
+        }
+    }
+
+    /// Checks, in constant time, that `signature` is the HMAC of `data`.
+    pub(crate) fn verify(self, key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+        fn verify<M: Mac + KeyInit>(key: &[u8], data: &[u8], signature: &[u8]) -> bool {
+            let Ok(mut mac) = <M as Mac>::new_from_slice(key) else {
+                return false;
+            };
+            mac.update(data);
+            mac.verify_slice(signature).is_ok()
+        }
+
+        match self {
+            Self::Sha1 => verify::<Hmac<Sha1>>(key, data, signature),
+            Self::Sha256 => verify::<Hmac<Sha256>>(key, data, signature),
+            Self::Sha384 => verify::<Hmac<Sha384>>(key, data, signature),
+            Self::Sha512 => verify::<Hmac<Sha512>>(key, data, signature),
+        }
+    }
+}
+
+/// The algorithm a `CryptoKey` can be used with.
+///
+/// See <https://w3c.github.io/webcrypto/#dom-cryptokey-algorithm>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyAlgorithm {
+    /// An HMAC key, with its hash function and its length in bits.
+    Hmac { hash: Hash, length: u32 },
+    /// An AES-GCM key and its length in bits.
+    AesGcm { length: u32 },
+    /// An AES-CBC key and its length in bits.
+    AesCbc { length: u32 },
+}
+
+impl KeyAlgorithm {
+    /// Returns the name of the algorithm.
+    pub(crate) const fn name(self) -> AlgorithmName {
+        match self {
+            Self::Hmac { .. } => AlgorithmName::Hmac,
+            Self::AesGcm { .. } => AlgorithmName::AesGcm,
+            Self::AesCbc { .. } => AlgorithmName::AesCbc,
+        }
+    }
+
+    /// Returns the JWK `alg` value of keys of this algorithm.
+    pub(crate) const fn jwk_alg(self) -> &'static str {
+        match self {
+            Self::Hmac { hash, .. } => hash.jwk_hmac_alg(),
+            Self::AesGcm { length: 128 } => "A128GCM",
+            Self::AesGcm { length: 192 } => "A192GCM",
+            Self::AesGcm { .. } => "A256GCM",
+            Self::AesCbc { length: 128 } => "A128CBC",
+            Self::AesCbc { length: 192 } => "A192CBC",
+            Self::AesCbc { .. } => "A256CBC",
+        }
+    }
+}
+
+/// The `KeyUsage` enumeration.
+///
+/// See <https://w3c.github.io/webcrypto/#dfn-KeyUsage>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeyUsage {
+    Encrypt,
+    Decrypt,
+    Sign,
+    Verify,
+    DeriveKey,
+    DeriveBits,
+    WrapKey,
+    UnwrapKey,
+}
+
+impl KeyUsage {
+    /// All usages, in the order they are reported by `CryptoKey.usages`.
+    const ALL: [Self; 8] = [
+        Self::Encrypt,
+        Self::Decrypt,
+        Self::Sign,
+        Self::Verify,
+        Self::DeriveKey,
+        Self::DeriveBits,
+        Self::WrapKey,
+        Self::UnwrapKey,
+    ];
+
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Encrypt => "encrypt",
+            Self::Decrypt => "decrypt",
+            Self::Sign => "sign",
+            Self::Verify => "verify",
+            Self::DeriveKey => "deriveKey",
+            Self::DeriveBits => "deriveBits",
+            Self::WrapKey => "wrapKey",
+            Self::UnwrapKey => "unwrapKey",
+        }
+    }
+
+    /// Parses a `KeyUsage` string, throwing a `TypeError` if it is not a valid usage.
+    pub(crate) fn from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let usage = value.to_string(context)?.to_std_string_lossy();
+        Self::ALL
+            .into_iter()
+            .find(|known| known.as_str() == usage)
+            .ok_or_else(|| js_error!(TypeError: "`{}` is not a valid key usage", usage))
+    }
+
+    /// Converts a `sequence<KeyUsage>`, removing duplicates and sorting the usages.
+    pub(crate) fn sequence_from_js(value: &JsValue, context: &mut Context) -> JsResult<Vec<Self>> {
+        let usages: Vec<JsValue> = value.try_js_into(context)?;
+        let mut parsed = Vec::with_capacity(usages.len());
+        for usage in &usages {
+            parsed.push(Self::from_js(usage, context)?);
+        }
+        Ok(Self::ALL
+            .into_iter()
+            .filter(|usage| parsed.contains(usage))
+            .collect())
+    }
+}
+
+/// The parameters of `encrypt` and `decrypt`.
+#[derive(Debug)]
+pub(crate) enum CipherParams {
+    Gcm {
+        iv: Vec<u8>,
+        additional_data: Vec<u8>,
+    },
+    Cbc {
+        iv: Vec<u8>,
+    },
+}
+
+impl CipherParams {
+    /// Encrypts or decrypts `data` with `key`, returning `None` if the operation failed.
+    pub(crate) fn apply(&self, key: &[u8], data: &[u8], encrypt: bool) -> Option<Vec<u8>> {
+        match self {
+            Self::Gcm {
+                iv,
+                additional_data,
+            } => {
+                let payload = Payload {
+                    msg: data,
+                    aad: additional_data,
+                };
+                match key.len() {
+                    16 => run_gcm::<Aes128Gcm>(key, iv, payload, encrypt),
+                    24 => run_gcm::<Aes192Gcm>(key, iv, payload, encrypt),
+                    32 => run_gcm::<Aes256Gcm>(key, iv, payload, encrypt),
+                    _ => None,
+                }
+            }
+            Self::Cbc { iv } => match key.len() {
+                16 => run_cbc::<aes::Aes128>(key, iv, data, encrypt),
+                24 => run_cbc::<aes::Aes192>(key, iv, data, encrypt),
+                32 => run_cbc::<aes::Aes256>(key, iv, data, encrypt),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Runs AES-GCM with a 96-bit nonce and a 128-bit tag appended to the ciphertext.
+fn run_gcm<C: Aead + KeyInit>(
+    key: &[u8],
+    iv: &[u8],
+    payload: Payload<'_, '_>,
+    encrypt: bool,
+) -> Option<Vec<u8>> {
+    let cipher = C::new_from_slice(key).ok()?;
+    if iv.len() != GCM_IV_LENGTH {
+        return None;
+    }
+    let nonce = Nonce::<C>::from_slice(iv);
+    if encrypt {
+        cipher.encrypt(nonce, payload).ok()
+    } else {
+        cipher.decrypt(nonce, payload).ok()
+    }
+}
+
+/// Runs AES-CBC with PKCS#7 padding.
+fn run_cbc<C>(key: &[u8], iv: &[u8], data: &[u8], encrypt: bool) -> Option<Vec<u8>>
+where
+    C: BlockCipher + BlockEncryptMut + BlockDecryptMut + KeyInit,
+{
+    if encrypt {
+        let cipher = cbc::Encryptor::<C>::new_from_slices(key, iv).ok()?;
+        Some(cipher.encrypt_padded_vec_mut::<Pkcs7>(data))
+    } else {
+        let cipher = cbc::Decryptor::<C>::new_from_slices(key, iv).ok()?;
+        cipher.decrypt_padded_vec_mut::<Pkcs7>(data).ok()
+    }
+}
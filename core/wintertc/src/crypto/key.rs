@@ -0,0 +1,135 @@
+//! The `CryptoKey` interface.
+//!
+//! Spec: <https://w3c.github.io/webcrypto/#cryptokey-interface>
+
+use super::algorithm::{KeyAlgorithm, KeyUsage};
+use boa_engine::class::Class;
+use boa_engine::object::ObjectInitializer;
+use boa_engine::object::builtins::JsArray;
+use boa_engine::property::Attribute;
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, js_error,
+    js_string,
+};
+
+/// The internal slots of a `CryptoKey`.
+#[derive(Debug, Clone)]
+pub(crate) struct KeyData {
+    pub(crate) algorithm: KeyAlgorithm,
+    pub(crate) extractable: bool,
+    pub(crate) usages: Vec<KeyUsage>,
+    pub(crate) secret: Vec<u8>,
+}
+
+impl KeyData {
+    /// Returns a copy of the key data of the `CryptoKey` object `value`.
+    pub(crate) fn from_value(value: &JsValue) -> JsResult<Self> {
+        value
+            .as_object()
+            .and_then(|object| {
+                object
+                    .downcast_ref::<JsCryptoKey>()
+                    .map(|key| key.data.clone())
+            })
+            .ok_or_else(|| js_error!(TypeError: "the key is not a CryptoKey"))
+    }
+
+    /// Returns `true` if the key can be used for `usage`.
+    pub(crate) fn allows(&self, usage: KeyUsage) -> bool {
+        self.usages.contains(&usage)
+    }
+}
+
+/// The JavaScript `CryptoKey` class.
+///
+/// Only secret keys are supported, so `type` is always `"secret"`.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct JsCryptoKey {
+    #[unsafe_ignore_trace]
+    data: KeyData,
+    algorithm: JsObject,
+    usages: JsObject,
+}
+
+impl JsCryptoKey {
+    /// Creates a new `CryptoKey` object.
+    pub(crate) fn create(data: KeyData, context: &mut Context) -> JsResult<JsObject> {
+        let algorithm = algorithm_object(data.algorithm, context);
+        let usages = JsArray::from_iter(
+            data.usages
+                .iter()
+                .map(|usage| JsString::from(usage.as_str()).into()),
+            context,
+        )
+        .into();
+
+        Self::from_data(
+            Self {
+                data,
+                algorithm,
+                usages,
+            },
+            context,
+        )
+    }
+}
+
+/// Creates the object returned by `CryptoKey.prototype.algorithm`.
+///
+/// See <https://w3c.github.io/webcrypto/#dfn-KeyAlgorithm>
+fn algorithm_object(algorithm: KeyAlgorithm, context: &mut Context) -> JsObject {
+    let name = JsString::from(algorithm.name().as_str());
+    match algorithm {
+        KeyAlgorithm::Hmac { hash, length } => {
+            let hash = ObjectInitializer::new(context)
+                .property(
+                    js_string!("name"),
+                    JsString::from(hash.name()),
+                    Attribute::all(),
+                )
+                .build();
+            ObjectInitializer::new(context)
+                .property(js_string!("name"), name, Attribute::all())
+                .property(js_string!("hash"), hash, Attribute::all())
+                .property(js_string!("length"), length, Attribute::all())
+                .build()
+        }
+        KeyAlgorithm::AesGcm { length } | KeyAlgorithm::AesCbc { length } => {
+            ObjectInitializer::new(context)
+                .property(js_string!("name"), name, Attribute::all())
+                .property(js_string!("length"), length, Attribute::all())
+                .build()
+        }
+    }
+}
+
+#[boa_class(rename = "CryptoKey")]
+#[boa(rename_all = "camelCase")]
+#[allow(clippy::unused_self)]
+impl JsCryptoKey {
+    #[boa(constructor)]
+    fn constructor() -> JsResult<Self> {
+        Err(js_error!(TypeError: "Illegal constructor"))
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "type")]
+    fn r#type(&self) -> JsString {
+        js_string!("secret")
+    }
+
+    #[boa(getter)]
+    fn extractable(&self) -> bool {
+        self.data.extractable
+    }
+
+    #[boa(getter)]
+    fn algorithm(&self) -> JsObject {
+        self.algorithm.clone()
+    }
+
+    #[boa(getter)]
+    fn usages(&self) -> JsObject {
+        self.usages.clone()
+    }
+}
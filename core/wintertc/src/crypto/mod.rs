@@ -0,0 +1,235 @@
+//! TC55 Web Crypto APIs: `Crypto`, `SubtleCrypto` and `CryptoKey`.
+//!
+//! Spec: <https://w3c.github.io/webcrypto/>
+//!
+//! # TC55 Status
+//!
+//! The `crypto` global and the `Crypto`, `SubtleCrypto` and `CryptoKey` interfaces are
+//! required in the `WinterTC` TC55 Minimum Common Web API.
+//!
+//! # Supported algorithms
+//!
+//! | Algorithm                        | Operations                                                    |
+//! |----------------------------------|---------------------------------------------------------------|
+//! | SHA-1, SHA-256, SHA-384, SHA-512 | `digest`                                                      |
+//! | HMAC                             | `generateKey`, `importKey`, `exportKey`, `sign`, `verify`     |
+//! | AES-GCM, AES-CBC                 | `generateKey`, `importKey`, `exportKey`, `encrypt`, `decrypt` |
+//!
+//! Keys can be imported from and exported to the `"raw"` and `"jwk"` formats. AES-GCM
+//! only supports 96-bit initialization vectors and 128-bit authentication tags.
+//!
+//! Errors are reported as `Error` objects whose `name` is the `DOMException` name required
+//! by the specification, such as `"OperationError"` or `"InvalidAccessError"`.
+
+use boa_engine::builtins::typed_array::TypedArrayKind;
+use boa_engine::class::Class;
+use boa_engine::object::builtins::{JsArrayBuffer, JsDataView, JsTypedArray};
+use boa_engine::property::Attribute;
+use boa_engine::realm::Realm;
+use boa_engine::value::TryFromJs;
+use boa_engine::{
+    Context, Finalize, JsData, JsError, JsNativeError, JsObject, JsResult, JsString, JsValue,
+    Trace, boa_class, boa_module, js_error, js_string,
+};
+use std::borrow::Cow;
+use std::fmt::Write;
+
+mod algorithm;
+mod key;
+mod subtle;
+
+#[cfg(test)]
+mod tests;
+
+pub use key::JsCryptoKey;
+pub use subtle::JsSubtleCrypto;
+
+/// The maximum number of bytes `getRandomValues` fills in a single call.
+const MAX_RANDOM_BYTES: usize = 65536;
+
+/// Creates an error with the given `DOMException` name.
+pub(crate) fn dom_exception(
+    name: &'static str,
+    message: impl Into<Cow<'static, str>>,
+    context: &mut Context,
+) -> JsError {
+    let error = JsNativeError::error()
+        .with_message(message)
+        .into_opaque(context);
+    error
+        .set(js_string!("name"), JsString::from(name), false, context)
+        .ok();
+    JsError::from_opaque(error.into())
+}
+
+/// Fills `bytes` with cryptographically secure random values.
+fn fill_random(bytes: &mut [u8]) -> JsResult<()> {
+    getrandom::fill(bytes).map_err(|e| js_error!(Error: "failed to generate random values: {}", e))
+}
+
+/// Returns a copy of the bytes held by a `BufferSource`, throwing a `TypeError` if `value`
+/// is not an `ArrayBuffer` or an `ArrayBufferView`.
+///
+/// See <https://webidl.spec.whatwg.org/#dfn-get-buffer-source-copy>
+pub(crate) fn buffer_source_copy(value: &JsValue, context: &mut Context) -> JsResult<Vec<u8>> {
+    let (buffer, offset, length) = if let Ok(buffer) = JsArrayBuffer::try_from_js(value, context) {
+        let length = buffer.data().map_or(0, |data| data.len());
+        (buffer, 0, length)
+    } else if let Ok(typed_array) = JsTypedArray::try_from_js(value, context) {
+        let buffer = typed_array.buffer(context)?;
+        let buffer = JsArrayBuffer::try_from_js(&buffer, context)?;
+        let offset = typed_array.byte_offset(context)?;
+        (buffer, offset, typed_array.byte_length(context)?)
+    } else if let Ok(data_view) = JsDataView::try_from_js(value, context) {
+        let buffer = data_view.buffer(context)?;
+        let buffer = JsArrayBuffer::try_from_js(&buffer, context)?;
+        let offset = usize::try_from(data_view.byte_offset(context)?)
+            .map_err(|_| js_error!(RangeError: "DataView offset exceeds addressable size"))?;
+        let length = usize::try_from(data_view.byte_length(context)?)
+            .map_err(|_| js_error!(RangeError: "DataView length exceeds addressable size"))?;
+        (buffer, offset, length)
+    } else {
+        return Err(js_error!(TypeError: "value is not an ArrayBuffer or ArrayBufferView"));
+    };
+
+    // Detached buffers are treated as empty.
+    let Some(data) = buffer.data() else {
+        return Ok(Vec::new());
+    };
+    data.get(offset..offset + length)
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| js_error!(RangeError: "the view is out of the bounds of its buffer"))
+}
+
+/// Returns `true` if `kind` is an integer typed array kind.
+fn is_integer_kind(kind: TypedArrayKind) -> bool {
+    matches!(
+        kind,
+        TypedArrayKind::Int8
+            | TypedArrayKind::Uint8
+            | TypedArrayKind::Uint8Clamped
+            | TypedArrayKind::Int16
+            | TypedArrayKind::Uint16
+            | TypedArrayKind::Int32
+            | TypedArrayKind::Uint32
+            | TypedArrayKind::BigInt64
+            | TypedArrayKind::BigUint64
+    )
+}
+
+/// The JavaScript `Crypto` class, the type of the `crypto` global.
+///
+/// See <https://w3c.github.io/webcrypto/#crypto-interface>
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct JsCrypto {
+    subtle: JsObject,
+}
+
+#[boa_class(rename = "Crypto")]
+#[boa(rename_all = "camelCase")]
+#[allow(clippy::unused_self)]
+impl JsCrypto {
+    #[boa(constructor)]
+    fn constructor() -> JsResult<Self> {
+        Err(js_error!(TypeError: "Illegal constructor"))
+    }
+
+    #[boa(getter)]
+    fn subtle(&self) -> JsObject {
+        self.subtle.clone()
+    }
+
+    /// See <https://w3c.github.io/webcrypto/#Crypto-method-getRandomValues>
+    #[boa(method)]
+    fn get_random_values(&self, array: JsValue, context: &mut Context) -> JsResult<JsValue> {
+        let typed_array = JsTypedArray::try_from_js(&array, context)
+            .map_err(|_| js_error!(TypeError: "argument is not an ArrayBufferView"))?;
+        if !typed_array.kind().is_some_and(is_integer_kind) {
+            return Err(dom_exception(
+                "TypeMismatchError",
+                "the array is not an integer-typed array",
+                context,
+            ));
+        }
+
+        let length = typed_array.byte_length(context)?;
+        if length > MAX_RANDOM_BYTES {
+            return Err(dom_exception(
+                "QuotaExceededError",
+                format!("the array has more than {MAX_RANDOM_BYTES} bytes"),
+                context,
+            ));
+        }
+        let offset = typed_array.byte_offset(context)?;
+        let buffer = typed_array.buffer(context)?;
+        let buffer = JsArrayBuffer::try_from_js(&buffer, context)?;
+
+        let mut bytes = vec![0; length];
+        fill_random(&mut bytes)?;
+        if let Some(mut data) = buffer.data_mut() {
+            data.get_mut(offset..offset + length)
+                .ok_or_else(
+                    || js_error!(RangeError: "the view is out of the bounds of its buffer"),
+                )?
+                .copy_from_slice(&bytes);
+        }
+
+        Ok(typed_array.into())
+    }
+
+    /// Returns a random version 4 UUID.
+    ///
+    /// See <https://w3c.github.io/webcrypto/#Crypto-method-randomUUID>
+    #[boa(method)]
+    #[boa(rename = "randomUUID")]
+    fn random_uuid(&self) -> JsResult<JsString> {
+        let mut bytes = [0u8; 16];
+        fill_random(&mut bytes)?;
+        bytes[6] = (bytes[6] & 0x0F) | 0x40;
+        bytes[8] = (bytes[8] & 0x3F) | 0x80;
+
+        let mut uuid = String::with_capacity(36);
+        for (i, byte) in bytes.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                uuid.push('-');
+            }
+            let _ = write!(uuid, "{byte:02x}");
+        }
+        Ok(JsString::from(uuid))
+    }
+}
+
+/// JavaScript module containing the Web Crypto classes.
+#[boa_module]
+mod js_module {
+    type JsCrypto = super::JsCrypto;
+    type JsSubtleCrypto = super::JsSubtleCrypto;
+    type JsCryptoKey = super::JsCryptoKey;
+}
+
+/// Register the Web Crypto classes (`Crypto`, `SubtleCrypto`, `CryptoKey`) and the
+/// `crypto` global into the given context.
+///
+/// # Errors
+///
+/// Returns a [`boa_engine::JsError`] if registration fails.
+pub fn register(realm: Option<Realm>, ctx: &mut Context) -> JsResult<()> {
+    js_module::boa_register(realm.clone(), ctx)?;
+
+    let realm = realm.unwrap_or_else(|| ctx.realm().clone());
+    let previous_realm = ctx.enter_realm(realm);
+    let result = register_global(ctx);
+    ctx.enter_realm(previous_realm);
+    result
+}
+
+/// Defines the `crypto` global in the current realm.
+fn register_global(ctx: &mut Context) -> JsResult<()> {
+    let subtle = JsSubtleCrypto::from_data(JsSubtleCrypto, ctx)?;
+    let crypto = JsCrypto::from_data(JsCrypto { subtle }, ctx)?;
+    ctx.register_global_property(
+        js_string!("crypto"),
+        crypto,
+        Attribute::WRITABLE | Attribute::CONFIGURABLE,
+    )
+}
@@ -0,0 +1,709 @@
+//! The `SubtleCrypto` interface.
+//!
+//! Every method normalizes its arguments synchronously and returns a rejected promise if
+//! they are invalid. The cryptographic operation itself runs as a job, like the "in
+//! parallel" steps of the specification.
+//!
+//! Spec: <https://w3c.github.io/webcrypto/#subtlecrypto-interface>
+
+use super::algorithm::{Algorithm, AlgorithmName, CipherParams, Hash, KeyAlgorithm, KeyUsage};
+use super::key::{JsCryptoKey, KeyData};
+use super::{buffer_source_copy, dom_exception, fill_random};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use boa_engine::builtins::array_buffer::AlignedVec;
+use boa_engine::object::ObjectInitializer;
+use boa_engine::object::builtins::{JsArray, JsArrayBuffer, JsPromise};
+use boa_engine::property::Attribute;
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, js_error,
+    js_string,
+};
+
+/// The algorithms that can generate and import keys.
+const KEY_ALGORITHMS: [AlgorithmName; 3] = [
+    AlgorithmName::Hmac,
+    AlgorithmName::AesGcm,
+    AlgorithmName::AesCbc,
+];
+
+/// The hash algorithms usable with `digest`.
+const DIGEST_ALGORITHMS: [AlgorithmName; 4] = [
+    AlgorithmName::Sha1,
+    AlgorithmName::Sha256,
+    AlgorithmName::Sha384,
+    AlgorithmName::Sha512,
+];
+
+/// The algorithms usable with `encrypt` and `decrypt`.
+const CIPHER_ALGORITHMS: [AlgorithmName; 2] = [AlgorithmName::AesGcm, AlgorithmName::AesCbc];
+
+/// Returns a promise settled with the result of `operation`, run as a job, or rejected
+/// with the error of `input`.
+fn promise<T: 'static>(
+    input: JsResult<T>,
+    operation: impl FnOnce(T, &mut Context) -> JsResult<JsValue> + 'static,
+    context: &mut Context,
+) -> JsResult<JsPromise> {
+    match input {
+        Ok(input) => Ok(JsPromise::from_async_fn(
+            async move |context| operation(input, &mut context.borrow_mut()),
+            context,
+        )),
+        // Engine errors, like exceeded runtime limits, are not catchable by scripts.
+        Err(error) if error.as_engine().is_some() => Err(error),
+        Err(error) => JsPromise::reject(error, context),
+    }
+}
+
+/// Creates a new `ArrayBuffer` holding `bytes`.
+fn array_buffer(bytes: &[u8], context: &mut Context) -> JsResult<JsValue> {
+    JsArrayBuffer::from_byte_block(AlignedVec::from_slice(0, bytes), context).map(Into::into)
+}
+
+/// The `KeyFormat` enumeration.
+///
+/// See <https://w3c.github.io/webcrypto/#dfn-KeyFormat>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyFormat {
+    Raw,
+    Spki,
+    Pkcs8,
+    Jwk,
+}
+
+impl KeyFormat {
+    fn from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let format = value.to_string(context)?.to_std_string_lossy();
+        match format.as_str() {
+            "raw" => Ok(Self::Raw),
+            "spki" => Ok(Self::Spki),
+            "pkcs8" => Ok(Self::Pkcs8),
+            "jwk" => Ok(Self::Jwk),
+            _ => Err(js_error!(TypeError: "`{}` is not a valid key format", format)),
+        }
+    }
+
+    /// Throws a `NotSupportedError` for the asymmetric key formats.
+    fn expect_secret(self, context: &mut Context) -> JsResult<Self> {
+        match self {
+            Self::Raw | Self::Jwk => Ok(self),
+            Self::Spki | Self::Pkcs8 => Err(dom_exception(
+                "NotSupportedError",
+                "secret keys can only use the `raw` and `jwk` formats",
+                context,
+            )),
+        }
+    }
+}
+
+/// The members of a `JsonWebKey` dictionary used by secret keys.
+///
+/// See <https://w3c.github.io/webcrypto/#JsonWebKey-dictionary>
+#[derive(Debug, Default)]
+struct Jwk {
+    kty: Option<String>,
+    k: Option<String>,
+    alg: Option<String>,
+    key_use: Option<String>,
+    key_ops: Option<Vec<String>>,
+    ext: Option<bool>,
+}
+
+impl Jwk {
+    fn from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        fn string(
+            object: &JsObject,
+            key: JsString,
+            context: &mut Context,
+        ) -> JsResult<Option<String>> {
+            let value = object.get(key, context)?;
+            if value.is_undefined() {
+                return Ok(None);
+            }
+            Ok(Some(value.to_string(context)?.to_std_string_lossy()))
+        }
+
+        let Some(object) = value.as_object() else {
+            return Err(js_error!(TypeError: "a JSON Web Key must be an object"));
+        };
+
+        let key_ops = object.get(js_string!("key_ops"), context)?;
+        let key_ops = if key_ops.is_undefined() {
+            None
+        } else {
+            let ops: Vec<JsValue> = key_ops.try_js_into(context)?;
+            let mut strings = Vec::with_capacity(ops.len());
+            for op in &ops {
+                strings.push(op.to_string(context)?.to_std_string_lossy());
+            }
+            Some(strings)
+        };
+        let ext = object.get(js_string!("ext"), context)?;
+
+        Ok(Self {
+            kty: string(&object, js_string!("kty"), context)?,
+            k: string(&object, js_string!("k"), context)?,
+            alg: string(&object, js_string!("alg"), context)?,
+            key_use: string(&object, js_string!("use"), context)?,
+            key_ops,
+            ext: (!ext.is_undefined()).then(|| ext.to_boolean()),
+        })
+    }
+
+    /// Validates the key against the import parameters and returns the decoded secret.
+    ///
+    /// See the "jwk" import steps of <https://w3c.github.io/webcrypto/#hmac-operations>
+    /// and <https://w3c.github.io/webcrypto/#aes-gcm-operations>.
+    fn secret(
+        self,
+        name: AlgorithmName,
+        extractable: bool,
+        usages: &[KeyUsage],
+        context: &mut Context,
+    ) -> JsResult<(Vec<u8>, Option<String>)> {
+        let data_error = |message: &'static str, context: &mut Context| {
+            Err(dom_exception("DataError", message, context))
+        };
+
+        if self.kty.as_deref() != Some("oct") {
+            return data_error(
+                "the `kty` member of the JSON Web Key must be `oct`",
+                context,
+            );
+        }
+        let Some(k) = self.k else {
+            return data_error("the JSON Web Key has no `k` member", context);
+        };
+        let Ok(secret) = URL_SAFE_NO_PAD.decode(k) else {
+            return data_error(
+                "the `k` member of the JSON Web Key is not base64url",
+                context,
+            );
+        };
+        let expected_use = if name == AlgorithmName::Hmac {
+            "sig"
+        } else {
+            "enc"
+        };
+        if self.key_use.is_some_and(|key_use| key_use != expected_use) {
+            return data_error("the `use` member of the JSON Web Key is invalid", context);
+        }
+        if self.key_ops.is_some_and(|ops| {
+            !usages
+                .iter()
+                .all(|usage| ops.iter().any(|op| op == usage.as_str()))
+        }) {
+            return data_error(
+                "the `key_ops` member of the JSON Web Key does not allow the requested usages",
+                context,
+            );
+        }
+        if self.ext == Some(false) && extractable {
+            return data_error("the JSON Web Key is not extractable", context);
+        }
+
+        Ok((secret, self.alg))
+    }
+}
+
+/// Converts the `keyUsages` argument and checks it against the usages allowed for `name`.
+fn key_usages(
+    value: &JsValue,
+    name: AlgorithmName,
+    context: &mut Context,
+) -> JsResult<Vec<KeyUsage>> {
+    let usages = KeyUsage::sequence_from_js(value, context)?;
+    if usages
+        .iter()
+        .any(|usage| !name.allowed_usages().contains(usage))
+    {
+        return Err(dom_exception(
+            "SyntaxError",
+            format!("invalid key usages for {}", name.as_str()),
+            context,
+        ));
+    }
+    if usages.is_empty() {
+        return Err(dom_exception(
+            "SyntaxError",
+            "secret keys must have at least one usage",
+            context,
+        ));
+    }
+    Ok(usages)
+}
+
+/// Checks that `key` can be used for `usage` with `algorithm`, throwing an
+/// `InvalidAccessError` otherwise.
+fn check_key(
+    key: &KeyData,
+    algorithm: &Algorithm,
+    usage: KeyUsage,
+    context: &mut Context,
+) -> JsResult<()> {
+    if key.algorithm.name() != algorithm.name {
+        return Err(dom_exception(
+            "InvalidAccessError",
+            format!("the key is not a {} key", algorithm.name.as_str()),
+            context,
+        ));
+    }
+    if !key.allows(usage) {
+        return Err(dom_exception(
+            "InvalidAccessError",
+            format!("the key does not allow `{}`", usage.as_str()),
+            context,
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the hash function of an HMAC key.
+fn hmac_hash(key: &KeyData) -> JsResult<Hash> {
+    match key.algorithm {
+        KeyAlgorithm::Hmac { hash, .. } => Ok(hash),
+        KeyAlgorithm::AesGcm { .. } | KeyAlgorithm::AesCbc { .. } => {
+            Err(js_error!(TypeError: "the key is not an HMAC key"))
+        }
+    }
+}
+
+/// Normalizes the arguments of `digest`.
+fn digest_input(
+    algorithm: &JsValue,
+    data: &JsValue,
+    context: &mut Context,
+) -> JsResult<(Hash, Vec<u8>)> {
+    let algorithm =
+        Algorithm::normalize(algorithm, context)?.expect("digest", &DIGEST_ALGORITHMS, context)?;
+    let data = buffer_source_copy(data, context)?;
+    match algorithm.name {
+        AlgorithmName::Sha1 => Ok((Hash::Sha1, data)),
+        AlgorithmName::Sha256 => Ok((Hash::Sha256, data)),
+        AlgorithmName::Sha384 => Ok((Hash::Sha384, data)),
+        _ => Ok((Hash::Sha512, data)),
+    }
+}
+
+/// Normalizes the arguments of `generateKey`, returning the key to generate and the
+/// number of random bytes it needs.
+fn generate_key_input(
+    algorithm: &JsValue,
+    extractable: bool,
+    usages: &JsValue,
+    context: &mut Context,
+) -> JsResult<(KeyData, usize)> {
+    let algorithm = Algorithm::normalize(algorithm, context)?.expect(
+        "generateKey",
+        &KEY_ALGORITHMS,
+        context,
+    )?;
+    let usages = key_usages(usages, algorithm.name, context)?;
+
+    let key_algorithm = match algorithm.name {
+        AlgorithmName::Hmac => {
+            let hash = algorithm.hash(context)?;
+            let length = algorithm
+                .hmac_length(context)?
+                .unwrap_or_else(|| hash.block_size());
+            if length == 0 {
+                return Err(dom_exception(
+                    "OperationError",
+                    "the HMAC key length must not be zero",
+                    context,
+                ));
+            }
+            KeyAlgorithm::Hmac { hash, length }
+        }
+        AlgorithmName::AesGcm => KeyAlgorithm::AesGcm {
+            length: algorithm.aes_length(context)?,
+        },
+        _ => KeyAlgorithm::AesCbc {
+            length: algorithm.aes_length(context)?,
+        },
+    };
+    let bytes = match key_algorithm {
+        KeyAlgorithm::Hmac { length, .. }
+        | KeyAlgorithm::AesGcm { length }
+        | KeyAlgorithm::AesCbc { length } => length.div_ceil(8) as usize,
+    };
+
+    Ok((
+        KeyData {
+            algorithm: key_algorithm,
+            extractable,
+            usages,
+            secret: Vec::new(),
+        },
+        bytes,
+    ))
+}
+
+/// Normalizes the arguments of `importKey` and imports the key data.
+fn import_key_input(
+    format: &JsValue,
+    key_data: &JsValue,
+    algorithm: &JsValue,
+    extractable: bool,
+    usages: &JsValue,
+    context: &mut Context,
+) -> JsResult<KeyData> {
+    let format = KeyFormat::from_js(format, context)?;
+    let (raw, jwk) = match format {
+        KeyFormat::Jwk => (Vec::new(), Some(Jwk::from_js(key_data, context)?)),
+        KeyFormat::Raw | KeyFormat::Spki | KeyFormat::Pkcs8 => {
+            (buffer_source_copy(key_data, context)?, None)
+        }
+    };
+    let algorithm =
+        Algorithm::normalize(algorithm, context)?.expect("importKey", &KEY_ALGORITHMS, context)?;
+    let usages = key_usages(usages, algorithm.name, context)?;
+    format.expect_secret(context)?;
+
+    let (secret, jwk_alg) = match jwk {
+        Some(jwk) => jwk.secret(algorithm.name, extractable, &usages, context)?,
+        None => (raw, None),
+    };
+
+    let key_algorithm = if algorithm.name == AlgorithmName::Hmac {
+        let hash = algorithm.hash(context)?;
+        let bits = u32::try_from(secret.len())
+            .ok()
+            .and_then(|len| len.checked_mul(8))
+            .filter(|&bits| bits > 0);
+        let Some(bits) = bits else {
+            return Err(dom_exception(
+                "DataError",
+                "the HMAC key data has an invalid length",
+                context,
+            ));
+        };
+        let length = match algorithm.hmac_length(context)? {
+            None => bits,
+            Some(length) if length <= bits && length > bits - 8 => length,
+            Some(_) => {
+                return Err(dom_exception(
+                    "DataError",
+                    "the HMAC key length does not match the key data",
+                    context,
+                ));
+            }
+        };
+        KeyAlgorithm::Hmac { hash, length }
+    } else {
+        let length = match secret.len() {
+            16 => 128,
+            24 => 192,
+            32 => 256,
+            _ => {
+                return Err(dom_exception(
+                    "DataError",
+                    "the AES key data must be 128, 192 or 256 bits long",
+                    context,
+                ));
+            }
+        };
+        if algorithm.name == AlgorithmName::AesGcm {
+            KeyAlgorithm::AesGcm { length }
+        } else {
+            KeyAlgorithm::AesCbc { length }
+        }
+    };
+
+    if jwk_alg.is_some_and(|alg| alg != key_algorithm.jwk_alg()) {
+        return Err(dom_exception(
+            "DataError",
+            "the `alg` member of the JSON Web Key does not match the algorithm",
+            context,
+        ));
+    }
+
+    Ok(KeyData {
+        algorithm: key_algorithm,
+        extractable,
+        usages,
+        secret,
+    })
+}
+
+/// Normalizes the arguments of `exportKey`.
+fn export_key_input(
+    format: &JsValue,
+    key: &JsValue,
+    context: &mut Context,
+) -> JsResult<(KeyFormat, KeyData)> {
+    let format = KeyFormat::from_js(format, context)?;
+    let key = KeyData::from_value(key)?;
+    if !key.extractable {
+        return Err(dom_exception(
+            "InvalidAccessError",
+            "the key is not extractable",
+            context,
+        ));
+    }
+    format.expect_secret(context)?;
+    Ok((format, key))
+}
+
+/// Creates the `JsonWebKey` dictionary of a secret key.
+fn export_jwk(key: &KeyData, context: &mut Context) -> JsValue {
+    let key_ops = JsArray::from_iter(
+        key.usages
+            .iter()
+            .map(|usage| JsString::from(usage.as_str()).into()),
+        context,
+    );
+    ObjectInitializer::new(context)
+        .property(js_string!("kty"), js_string!("oct"), Attribute::all())
+        .property(
+            js_string!("k"),
+            JsString::from(URL_SAFE_NO_PAD.encode(&key.secret)),
+            Attribute::all(),
+        )
+        .property(
+            js_string!("alg"),
+            JsString::from(key.algorithm.jwk_alg()),
+            Attribute::all(),
+        )
+        .property(js_string!("key_ops"), key_ops, Attribute::all())
+        .property(js_string!("ext"), key.extractable, Attribute::all())
+        .build()
+        .into()
+}
+
+/// Normalizes the arguments of `sign` and `verify`.
+fn mac_input(
+    operation: &str,
+    usage: KeyUsage,
+    algorithm: &JsValue,
+    key: &JsValue,
+    context: &mut Context,
+) -> JsResult<KeyData> {
+    let algorithm = Algorithm::normalize(algorithm, context)?.expect(
+        operation,
+        &[AlgorithmName::Hmac],
+        context,
+    )?;
+    let key = KeyData::from_value(key)?;
+    check_key(&key, &algorithm, usage, context)?;
+    Ok(key)
+}
+
+/// Normalizes the arguments of `encrypt` and `decrypt`.
+fn cipher_input(
+    operation: &str,
+    usage: KeyUsage,
+    algorithm: &JsValue,
+    key: &JsValue,
+    context: &mut Context,
+) -> JsResult<(CipherParams, KeyData)> {
+    let algorithm =
+        Algorithm::normalize(algorithm, context)?.expect(operation, &CIPHER_ALGORITHMS, context)?;
+    let params = algorithm.cipher_params(context)?;
+    let key = KeyData::from_value(key)?;
+    check_key(&key, &algorithm, usage, context)?;
+    Ok((params, key))
+}
+
+/// Runs `encrypt` or `decrypt`.
+fn cipher(
+    params: &CipherParams,
+    key: &KeyData,
+    data: &[u8],
+    encrypt: bool,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    match params.apply(&key.secret, data, encrypt) {
+        Some(result) => array_buffer(&result, context),
+        None if encrypt => Err(dom_exception(
+            "OperationError",
+            "the data could not be encrypted",
+            context,
+        )),
+        None => Err(dom_exception(
+            "OperationError",
+            "the data could not be decrypted",
+            context,
+        )),
+    }
+}
+
+/// The JavaScript `SubtleCrypto` class.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct JsSubtleCrypto;
+
+#[boa_class(rename = "SubtleCrypto")]
+#[boa(rename_all = "camelCase")]
+#[allow(clippy::unused_self)]
+impl JsSubtleCrypto {
+    #[boa(constructor)]
+    fn constructor() -> JsResult<Self> {
+        Err(js_error!(TypeError: "Illegal constructor"))
+    }
+
+    /// See <https://w3c.github.io/webcrypto/#SubtleCrypto-method-digest>
+    #[boa(method)]
+    fn digest(
+        &self,
+        algorithm: JsValue,
+        data: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        promise(
+            digest_input(&algorithm, &data, context),
+            |(hash, data), context| array_buffer(&hash.digest(&data), context),
+            context,
+        )
+    }
+
+    /// See <https://w3c.github.io/webcrypto/#SubtleCrypto-method-generateKey>
+    #[boa(method)]
+    fn generate_key(
+        &self,
+        algorithm: JsValue,
+        extractable: JsValue,
+        key_usages: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        promise(
+            generate_key_input(&algorithm, extractable.to_boolean(), &key_usages, context),
+            |(mut key, bytes), context| {
+                key.secret = vec![0; bytes];
+                fill_random(&mut key.secret)?;
+                JsCryptoKey::create(key, context).map(Into::into)
+            },
+            context,
+        )
+    }
+
+    /// See <https://w3c.github.io/webcrypto/#SubtleCrypto-method-importKey>
+    #[boa(method)]
+    fn import_key(
+        &self,
+        format: JsValue,
+        key_data: JsValue,
+        algorithm: JsValue,
+        extractable: JsValue,
+        key_usages: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        promise(
+            import_key_input(
+                &format,
+                &key_data,
+                &algorithm,
+                extractable.to_boolean(),
+                &key_usages,
+                context,
+            ),
+            |key, context| JsCryptoKey::create(key, context).map(Into::into),
+            context,
+        )
+    }
+
+    /// See <https://w3c.github.io/webcrypto/#SubtleCrypto-method-exportKey>
+    #[boa(method)]
+    fn export_key(
+        &self,
+        format: JsValue,
+        key: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        promise(
+            export_key_input(&format, &key, context),
+            |(format, key), context| match format {
+                KeyFormat::Jwk => Ok(export_jwk(&key, context)),
+                _ => array_buffer(&key.secret, context),
+            },
+            context,
+        )
+    }
+
+    /// See <https://w3c.github.io/webcrypto/#SubtleCrypto-method-sign>
+    #[boa(method)]
+    fn sign(
+        &self,
+        algorithm: JsValue,
+        key: JsValue,
+        data: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let input = buffer_source_copy(&data, context).and_then(|data| {
+            mac_input("sign", KeyUsage::Sign, &algorithm, &key, context).map(|key| (key, data))
+        });
+        promise(
+            input,
+            |(key, data), context| {
+                let hash = hmac_hash(&key)?;
+                array_buffer(&hash.sign(&key.secret, &data), context)
+            },
+            context,
+        )
+    }
+
+    /// See <https://w3c.github.io/webcrypto/#SubtleCrypto-method-verify>
+    #[boa(method)]
+    fn verify(
+        &self,
+        algorithm: JsValue,
+        key: JsValue,
+        signature: JsValue,
+        data: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let input = buffer_source_copy(&signature, context).and_then(|signature| {
+            let data = buffer_source_copy(&data, context)?;
+            let key = mac_input("verify", KeyUsage::Verify, &algorithm, &key, context)?;
+            Ok((key, signature, data))
+        });
+        promise(
+            input,
+            |(key, signature, data), _| {
+                let hash = hmac_hash(&key)?;
+                Ok(hash.verify(&key.secret, &data, &signature).into())
+            },
+            context,
+        )
+    }
+
+    /// See <https://w3c.github.io/webcrypto/#SubtleCrypto-method-encrypt>
+    #[boa(method)]
+    fn encrypt(
+        &self,
+        algorithm: JsValue,
+        key: JsValue,
+        data: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let input = buffer_source_copy(&data, context).and_then(|data| {
+            cipher_input("encrypt", KeyUsage::Encrypt, &algorithm, &key, context)
+                .map(|(params, key)| (params, key, data))
+        });
+        promise(
+            input,
+            |(params, key, data), context| cipher(&params, &key, &data, true, context),
+            context,
+        )
+    }
+
+    /// See <https://w3c.github.io/webcrypto/#SubtleCrypto-method-decrypt>
+    #[boa(method)]
+    fn decrypt(
+        &self,
+        algorithm: JsValue,
+        key: JsValue,
+        data: JsValue,
+        context: &mut Context,
+    ) -> JsResult<JsPromise> {
+        let input = buffer_source_copy(&data, context).and_then(|data| {
+            cipher_input("decrypt", KeyUsage::Decrypt, &algorithm, &key, context)
+                .map(|(params, key)| (params, key, data))
+        });
+        promise(
+            input,
+            |(params, key, data), context| cipher(&params, &key, &data, false, context),
+            context,
+        )
+    }
+}
@@ -0,0 +1,317 @@
+use boa_engine::{Context, JsValue, Source, js_string};
+
+/// Helpers shared by the asynchronous tests.
+const PRELUDE: &str = r#"
+    const hex = (buffer) =>
+        [...new Uint8Array(buffer)].map((b) => b.toString(16).padStart(2, "0")).join("");
+    const bytes = (hex) => new Uint8Array(hex.match(/../g).map((h) => parseInt(h, 16)));
+    const ascii = (s) => new Uint8Array([...s].map((c) => c.charCodeAt(0)));
+    const rejects = (promise) => promise.then(() => "fulfilled", (e) => e.name);
+"#;
+
+fn context() -> Context {
+    let mut context = Context::default();
+    super::register(None, &mut context).expect("failed to register the crypto APIs");
+    context
+}
+
+#[track_caller]
+fn assert_js(context: &mut Context, source: &str) {
+    let result = context
+        .eval(Source::from_bytes(source))
+        .unwrap_or_else(|e| panic!("Uncaught {e} in:\n{source}"));
+    assert_eq!(result, JsValue::from(true), "assertion failed:\n{source}");
+}
+
+/// Runs the body of an async function and asserts that it returns `true`.
+#[track_caller]
+fn assert_async(context: &mut Context, body: &str) {
+    let source = format!(
+        r"{PRELUDE}
+        var result;
+        (async () => {{ {body} }})().then(
+            (value) => {{ result = value; }},
+            (e) => {{ result = `${{e.name}}: ${{e.message}}`; }},
+        );
+        "
+    );
+    context
+        .eval(Source::from_bytes(&source))
+        .unwrap_or_else(|e| panic!("Uncaught {e} in:\n{body}"));
+    context.run_jobs().expect("failed to run jobs");
+    let result = context
+        .global_object()
+        .get(js_string!("result"), context)
+        .expect("failed to read the result");
+    assert_eq!(
+        result,
+        JsValue::from(true),
+        "assertion failed ({}):\n{body}",
+        result.display()
+    );
+}
+
+#[test]
+fn interfaces() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        let threw = false;
+        try { new Crypto(); } catch (e) { threw = e instanceof TypeError; }
+        threw
+            && crypto instanceof Crypto
+            && crypto.subtle instanceof SubtleCrypto
+            && crypto.subtle === crypto.subtle
+            && typeof CryptoKey === "function"
+        "#,
+    );
+}
+
+#[test]
+fn get_random_values() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        const array = new Uint32Array(16);
+        const same = crypto.getRandomValues(array) === array;
+        const view = new Uint8Array(new ArrayBuffer(32), 8, 16);
+        crypto.getRandomValues(view);
+        const outside = new Uint8Array(view.buffer);
+        let mismatch, quota, type;
+        try { crypto.getRandomValues(new Float64Array(2)); } catch (e) { mismatch = e.name; }
+        try { crypto.getRandomValues(new Uint8Array(65537)); } catch (e) { quota = e.name; }
+        try { crypto.getRandomValues([1, 2]); } catch (e) { type = e instanceof TypeError; }
+        same
+            && array.some((x) => x !== 0)
+            && outside.slice(0, 8).every((x) => x === 0)
+            && outside.slice(24).every((x) => x === 0)
+            && crypto.getRandomValues(new Uint8Array(65536)).length === 65536
+            && crypto.getRandomValues(new BigInt64Array(2)).length === 2
+            && mismatch === "TypeMismatchError"
+            && quota === "QuotaExceededError"
+            && type
+        "#,
+    );
+}
+
+#[test]
+fn random_uuid() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r"
+        const pattern = /^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$/;
+        const a = crypto.randomUUID();
+        const b = crypto.randomUUID();
+        pattern.test(a) && pattern.test(b) && a !== b
+        ",
+    );
+}
+
+#[test]
+fn digest() {
+    let context = &mut context();
+    assert_async(
+        context,
+        r#"
+        const data = ascii("abc");
+        return hex(await crypto.subtle.digest("SHA-1", data))
+                === "a9993e364706816aba3e25717850c26c9cd0d89d"
+            && hex(await crypto.subtle.digest({ name: "sha-256" }, data.buffer))
+                === "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+            && hex(await crypto.subtle.digest("SHA-384", new DataView(data.buffer)))
+                === "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded163"
+                    + "1a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"
+            && hex(await crypto.subtle.digest("SHA-512", data))
+                === "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a"
+                    + "2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+            && await rejects(crypto.subtle.digest("MD5", data)) === "NotSupportedError"
+            && await rejects(crypto.subtle.digest("HMAC", data)) === "NotSupportedError"
+            && await rejects(crypto.subtle.digest("SHA-256", "abc")) === "TypeError";
+        "#,
+    );
+}
+
+#[test]
+fn hmac_sign_verify() {
+    let context = &mut context();
+    assert_async(
+        context,
+        r#"
+        // RFC 4231, test case 2.
+        const key = await crypto.subtle.importKey(
+            "raw", ascii("Jefe"), { name: "HMAC", hash: "SHA-256" }, false, ["sign", "verify"],
+        );
+        const data = ascii("what do ya want for nothing?");
+        const signature = await crypto.subtle.sign("HMAC", key, data);
+        const tampered = new Uint8Array(signature.slice());
+        tampered[0] ^= 1;
+        return hex(signature)
+                === "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+            && await crypto.subtle.verify("HMAC", key, signature, data)
+            && !await crypto.subtle.verify("HMAC", key, tampered, data)
+            && !await crypto.subtle.verify("HMAC", key, signature.slice(1), data)
+            && key.type === "secret"
+            && !key.extractable
+            && key.algorithm.name === "HMAC"
+            && key.algorithm.hash.name === "SHA-256"
+            && key.algorithm.length === 32
+            && key.usages.join() === "sign,verify"
+            && key.usages === key.usages;
+        "#,
+    );
+}
+
+#[test]
+fn generate_key() {
+    let context = &mut context();
+    assert_async(
+        context,
+        r#"
+        const hmac = await crypto.subtle.generateKey(
+            { name: "HMAC", hash: { name: "SHA-512" } }, true, ["verify", "sign", "sign"],
+        );
+        const aes = await crypto.subtle.generateKey({ name: "AES-GCM", length: 192 }, true, ["encrypt"]);
+        return hmac instanceof CryptoKey
+            && hmac.algorithm.length === 1024
+            && hmac.usages.join() === "sign,verify"
+            && (await crypto.subtle.exportKey("raw", hmac)).byteLength === 128
+            && aes.algorithm.name === "AES-GCM"
+            && aes.algorithm.length === 192
+            && (await crypto.subtle.exportKey("raw", aes)).byteLength === 24
+            && await rejects(crypto.subtle.generateKey({ name: "AES-CBC", length: 64 }, true, ["encrypt"]))
+                === "OperationError"
+            && await rejects(crypto.subtle.generateKey({ name: "AES-CBC", length: 128 }, true, ["sign"]))
+                === "SyntaxError"
+            && await rejects(crypto.subtle.generateKey({ name: "AES-CBC", length: 128 }, true, []))
+                === "SyntaxError"
+            && await rejects(crypto.subtle.generateKey({ name: "AES-CBC", length: 128 }, true, ["bad"]))
+                === "TypeError"
+            && await rejects(crypto.subtle.generateKey({ name: "HMAC" }, true, ["sign"]))
+                === "TypeError"
+            && await rejects(crypto.subtle.generateKey("SHA-256", true, ["sign"]))
+                === "NotSupportedError";
+        "#,
+    );
+}
+
+#[test]
+fn import_export_jwk() {
+    let context = &mut context();
+    assert_async(
+        context,
+        r#"
+        const raw = bytes("000102030405060708090a0b0c0d0e0f");
+        const key = await crypto.subtle.importKey("raw", raw, "AES-CBC", true, ["encrypt", "decrypt"]);
+        const jwk = await crypto.subtle.exportKey("jwk", key);
+        const imported = await crypto.subtle.importKey("jwk", jwk, "AES-CBC", true, ["decrypt"]);
+        const hmac = await crypto.subtle.importKey(
+            "jwk",
+            { kty: "oct", k: "AAECAw", alg: "HS256", ext: true },
+            { name: "HMAC", hash: "SHA-256" },
+            true,
+            ["sign"],
+        );
+        const locked = await crypto.subtle.importKey("raw", raw, "AES-GCM", false, ["encrypt"]);
+        const importJwk = (jwk, usages = ["encrypt"]) =>
+            rejects(crypto.subtle.importKey("jwk", jwk, "AES-GCM", true, usages));
+        return jwk.kty === "oct"
+            && jwk.k === "AAECAwQFBgcICQoLDA0ODw"
+            && jwk.alg === "A128CBC"
+            && jwk.key_ops.join() === "encrypt,decrypt"
+            && jwk.ext === true
+            && hex(await crypto.subtle.exportKey("raw", imported)) === hex(raw)
+            && (await crypto.subtle.exportKey("jwk", hmac)).alg === "HS256"
+            && hmac.algorithm.length === 32
+            && await rejects(crypto.subtle.exportKey("raw", locked)) === "InvalidAccessError"
+            && await rejects(crypto.subtle.exportKey("spki", key)) === "NotSupportedError"
+            && await rejects(crypto.subtle.exportKey("pem", key)) === "TypeError"
+            && await importJwk({ kty: "RSA", k: jwk.k }) === "DataError"
+            && await importJwk({ kty: "oct", k: jwk.k, alg: "A256GCM" }) === "DataError"
+            && await importJwk({ kty: "oct", k: jwk.k, use: "sig" }) === "DataError"
+            && await importJwk({ kty: "oct", k: jwk.k, key_ops: ["decrypt"] }) === "DataError"
+            && await importJwk({ kty: "oct", k: jwk.k, ext: false }) === "DataError"
+            && await importJwk({ kty: "oct", k: "AAEC" }) === "DataError"
+            && await importJwk({ kty: "oct", k: jwk.k }, ["sign"]) === "SyntaxError"
+            && await rejects(crypto.subtle.importKey("raw", raw, "AES-GCM", true, []))
+                === "SyntaxError"
+            && await rejects(crypto.subtle.importKey(
+                "raw", raw, { name: "HMAC", hash: "SHA-1", length: 64 }, true, ["sign"],
+            )) === "DataError"
+            && await rejects(crypto.subtle.importKey(
+                "raw", new Uint8Array(), { name: "HMAC", hash: "SHA-1" }, true, ["sign"],
+            )) === "DataError";
+        "#,
+    );
+}
+
+#[test]
+fn aes_gcm() {
+    let context = &mut context();
+    assert_async(
+        context,
+        r#"
+        // The Galois/Counter Mode specification, test case 2.
+        const zeros = new Uint8Array(16);
+        const key = await crypto.subtle.importKey("raw", zeros, "AES-GCM", false, ["encrypt", "decrypt"]);
+        const algorithm = { name: "AES-GCM", iv: new Uint8Array(12) };
+        const ciphertext = await crypto.subtle.encrypt(algorithm, key, zeros);
+        const tampered = new Uint8Array(ciphertext.slice());
+        tampered[0] ^= 1;
+
+        const aad = { ...algorithm, additionalData: ascii("header") };
+        const sealed = await crypto.subtle.encrypt(aad, key, ascii("message"));
+        const opened = await crypto.subtle.decrypt(aad, key, sealed);
+
+        return hex(ciphertext)
+                === "0388dace60b6a392f328c2b971b2fe78" + "ab6e47d42cec13bdf53a67b21257bddf"
+            && hex(await crypto.subtle.decrypt(algorithm, key, ciphertext)) === hex(zeros)
+            && hex(opened) === hex(ascii("message"))
+            && await rejects(crypto.subtle.decrypt(algorithm, key, sealed)) === "OperationError"
+            && await rejects(crypto.subtle.decrypt(algorithm, key, tampered)) === "OperationError"
+            && await rejects(crypto.subtle.encrypt({ ...algorithm, tagLength: 96 }, key, zeros))
+                === "NotSupportedError"
+            && await rejects(crypto.subtle.encrypt({ ...algorithm, tagLength: 100 }, key, zeros))
+                === "OperationError"
+            && await rejects(crypto.subtle.encrypt({ name: "AES-GCM" }, key, zeros)) === "TypeError"
+            && await rejects(crypto.subtle.encrypt({ name: "AES-CBC", iv: zeros }, key, zeros))
+                === "InvalidAccessError";
+        "#,
+    );
+}
+
+#[test]
+fn aes_cbc() {
+    let context = &mut context();
+    assert_async(
+        context,
+        r#"
+        // NIST SP 800-38A, F.2.1, followed by a block of PKCS#7 padding.
+        const key = await crypto.subtle.importKey(
+            "raw", bytes("2b7e151628aed2a6abf7158809cf4f3c"), "AES-CBC", false, ["encrypt"],
+        );
+        const iv = bytes("000102030405060708090a0b0c0d0e0f");
+        const ciphertext = await crypto.subtle.encrypt(
+            { name: "AES-CBC", iv }, key, bytes("6bc1bee22e409f96e93d7e117393172a"),
+        );
+
+        const generated = await crypto.subtle.generateKey({ name: "AES-CBC", length: 256 }, false, ["encrypt", "decrypt"]);
+        const message = ascii("a message that spans more than one block");
+        const encrypted = await crypto.subtle.encrypt({ name: "AES-CBC", iv }, generated, message);
+        const decrypted = await crypto.subtle.decrypt({ name: "AES-CBC", iv }, generated, encrypted);
+
+        return ciphertext.byteLength === 32
+            && hex(ciphertext).startsWith("7649abac8119b246cee98e9b12e9197d")
+            && encrypted.byteLength === 48
+            && hex(decrypted) === hex(message)
+            && await rejects(crypto.subtle.decrypt({ name: "AES-CBC", iv }, key, ciphertext))
+                === "InvalidAccessError"
+            && await rejects(crypto.subtle.decrypt({ name: "AES-CBC", iv }, generated, message))
+                === "OperationError"
+            && await rejects(crypto.subtle.encrypt({ name: "AES-CBC", iv: iv.slice(1) }, generated, message))
+                === "OperationError";
+        "#,
+    );
+}
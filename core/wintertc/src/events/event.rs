@@ -0,0 +1,717 @@
+//! The `Event` interface and its `CustomEvent`, `ErrorEvent` and `MessageEvent` subclasses.
+//!
+//! All event classes share the [`Event`] state, so the methods and accessors on
+//! `Event.prototype` work with instances of every subclass.
+//!
+//! Spec: <https://dom.spec.whatwg.org/#interface-event>
+
+use boa_engine::gc::GcRefCell;
+use boa_engine::interop::{JsClass, JsThis};
+use boa_engine::object::IntegrityLevel;
+use boa_engine::object::builtins::JsArray;
+use boa_engine::value::TryFromJs;
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, js_error,
+    js_string,
+};
+use std::cell::Cell;
+
+/// The `eventPhase` value of an event that is not being dispatched.
+pub const NONE: u16 = 0;
+/// The `eventPhase` value of an event that is propagating through the target's ancestors.
+pub const CAPTURING_PHASE: u16 = 1;
+/// The `eventPhase` value of an event that has arrived at its target.
+pub const AT_TARGET: u16 = 2;
+/// The `eventPhase` value of an event that is propagating back up the target's ancestors.
+pub const BUBBLING_PHASE: u16 = 3;
+
+/// The internal flags of an event.
+///
+/// See <https://dom.spec.whatwg.org/#stop-propagation-flag> and the following definitions.
+#[derive(Debug, Default, Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)]
+struct EventFlags {
+    stop_propagation: bool,
+    stop_immediate_propagation: bool,
+    canceled: bool,
+    in_passive_listener: bool,
+    initialized: bool,
+    dispatch: bool,
+    trusted: bool,
+}
+
+/// Converts `value` to a `WebIDL` dictionary, returning `None` if it is `undefined` or `null`.
+fn dictionary(value: Option<&JsValue>) -> JsResult<Option<JsObject>> {
+    match value {
+        None => Ok(None),
+        Some(value) if value.is_null_or_undefined() => Ok(None),
+        Some(value) => value
+            .as_object()
+            .map(Some)
+            .ok_or_else(|| js_error!(TypeError: "event init must be an object")),
+    }
+}
+
+/// Reads a `boolean` member of a dictionary, defaulting to `false`.
+fn bool_member(dict: &JsObject, key: JsString, context: &mut Context) -> JsResult<bool> {
+    Ok(dict.get(key, context)?.to_boolean())
+}
+
+/// Reads a `DOMString` member of a dictionary, defaulting to the empty string.
+fn string_member(dict: &JsObject, key: JsString, context: &mut Context) -> JsResult<JsString> {
+    let value = dict.get(key, context)?;
+    if value.is_undefined() {
+        Ok(JsString::default())
+    } else {
+        value.to_string(context)
+    }
+}
+
+/// The `EventInit` dictionary, shared by all event constructors.
+///
+/// See <https://dom.spec.whatwg.org/#dictdef-eventinit>
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EventInit {
+    /// Whether the event goes through its target's ancestors in reverse tree order.
+    pub bubbles: bool,
+    /// Whether the event can be canceled with `preventDefault()`.
+    pub cancelable: bool,
+    /// Whether the event propagates across the shadow DOM boundary.
+    pub composed: bool,
+}
+
+impl EventInit {
+    fn from_dictionary(dict: Option<&JsObject>, context: &mut Context) -> JsResult<Self> {
+        let Some(dict) = dict else {
+            return Ok(Self::default());
+        };
+
+        Ok(Self {
+            bubbles: bool_member(dict, js_string!("bubbles"), context)?,
+            cancelable: bool_member(dict, js_string!("cancelable"), context)?,
+            composed: bool_member(dict, js_string!("composed"), context)?,
+        })
+    }
+}
+
+/// The state shared by all event objects.
+///
+/// See <https://dom.spec.whatwg.org/#concept-event>
+#[derive(Debug, Trace, Finalize)]
+#[allow(clippy::struct_field_names)]
+pub struct Event {
+    event_type: GcRefCell<JsString>,
+    target: GcRefCell<Option<JsObject>>,
+    current_target: GcRefCell<Option<JsObject>>,
+    #[unsafe_ignore_trace]
+    bubbles: Cell<bool>,
+    #[unsafe_ignore_trace]
+    cancelable: Cell<bool>,
+    #[unsafe_ignore_trace]
+    composed: bool,
+    #[unsafe_ignore_trace]
+    phase: Cell<u16>,
+    #[unsafe_ignore_trace]
+    flags: Cell<EventFlags>,
+    #[unsafe_ignore_trace]
+    time_stamp: f64,
+}
+
+impl Event {
+    /// Creates a new initialized event of type `event_type`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(event_type: JsString, init: EventInit, context: &Context) -> Self {
+        let time_stamp = context.clock().now().nanos_since_epoch() as f64 / 1_000_000.0;
+
+        Self {
+            event_type: GcRefCell::new(event_type),
+            target: GcRefCell::new(None),
+            current_target: GcRefCell::new(None),
+            bubbles: Cell::new(init.bubbles),
+            cancelable: Cell::new(init.cancelable),
+            composed: init.composed,
+            phase: Cell::new(NONE),
+            flags: Cell::new(EventFlags {
+                initialized: true,
+                ..EventFlags::default()
+            }),
+            time_stamp,
+        }
+    }
+
+    fn update_flags(&self, f: impl FnOnce(&mut EventFlags)) {
+        let mut flags = self.flags.get();
+        f(&mut flags);
+        self.flags.set(flags);
+    }
+
+    /// Returns the type of this event.
+    #[must_use]
+    pub fn event_type(&self) -> JsString {
+        self.event_type.borrow().clone()
+    }
+
+    /// Returns `true` if the `preventDefault()` method was called on a cancelable event.
+    #[must_use]
+    pub fn is_canceled(&self) -> bool {
+        self.flags.get().canceled
+    }
+
+    /// Returns `true` if this event was created by the runtime rather than by a script.
+    #[must_use]
+    pub fn is_trusted(&self) -> bool {
+        self.flags.get().trusted
+    }
+
+    /// Marks this event as created by the runtime.
+    pub fn set_trusted(&self, trusted: bool) {
+        self.update_flags(|flags| flags.trusted = trusted);
+    }
+
+    /// Returns `true` if the event can be dispatched, i.e. it was initialized and is
+    /// not currently being dispatched.
+    pub(crate) fn can_dispatch(&self) -> bool {
+        let flags = self.flags.get();
+        flags.initialized && !flags.dispatch
+    }
+
+    pub(crate) fn begin_dispatch(&self, target: &JsObject) {
+        self.update_flags(|flags| flags.dispatch = true);
+        *self.target.borrow_mut() = Some(target.clone());
+        *self.current_target.borrow_mut() = Some(target.clone());
+        self.phase.set(AT_TARGET);
+    }
+
+    pub(crate) fn end_dispatch(&self) {
+        self.phase.set(NONE);
+        *self.current_target.borrow_mut() = None;
+        self.update_flags(|flags| {
+            flags.dispatch = false;
+            flags.stop_propagation = false;
+            flags.stop_immediate_propagation = false;
+        });
+    }
+
+    pub(crate) fn set_in_passive_listener(&self, value: bool) {
+        self.update_flags(|flags| flags.in_passive_listener = value);
+    }
+
+    pub(crate) fn is_propagation_stopped(&self) -> bool {
+        self.flags.get().stop_propagation
+    }
+
+    pub(crate) fn is_immediate_propagation_stopped(&self) -> bool {
+        self.flags.get().stop_immediate_propagation
+    }
+
+    pub(crate) fn current_target(&self) -> Option<JsObject> {
+        self.current_target.borrow().clone()
+    }
+
+    /// See <https://dom.spec.whatwg.org/#set-the-canceled-flag>
+    fn set_canceled(&self) {
+        if self.cancelable.get() && !self.flags.get().in_passive_listener {
+            self.update_flags(|flags| flags.canceled = true);
+        }
+    }
+}
+
+/// Runs `f` with the [`Event`] state of `object`, if it is an event object.
+pub(crate) fn with_event<R>(object: &JsObject, f: impl FnOnce(&Event) -> R) -> Option<R> {
+    if let Some(data) = object.downcast_ref::<JsEvent>() {
+        return Some(f(&data.event));
+    }
+    if let Some(data) = object.downcast_ref::<JsCustomEvent>() {
+        return Some(f(&data.event));
+    }
+    if let Some(data) = object.downcast_ref::<JsErrorEvent>() {
+        return Some(f(&data.event));
+    }
+    if let Some(data) = object.downcast_ref::<JsMessageEvent>() {
+        return Some(f(&data.event));
+    }
+    None
+}
+
+/// Runs `f` with the [`Event`] state of the `this` value, throwing a `TypeError` if it is
+/// not an event object.
+fn with_this_event<R>(this: &JsValue, f: impl FnOnce(&Event) -> R) -> JsResult<R> {
+    this.as_object()
+        .and_then(|object| with_event(&object, f))
+        .ok_or_else(|| js_error!(TypeError: "'this' is not an Event"))
+}
+
+/// Converts a nullable object attribute to a value.
+fn nullable(object: Option<JsObject>) -> JsValue {
+    object.map_or_else(JsValue::null, JsValue::from)
+}
+
+/// Reads the required `type` argument of the event constructors.
+fn event_type_argument(event_type: Option<JsValue>, context: &mut Context) -> JsResult<JsString> {
+    event_type
+        .ok_or_else(|| js_error!(TypeError: "1 argument required, but only 0 present"))?
+        .to_string(context)
+}
+
+/// The JavaScript `Event` class.
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct JsEvent {
+    event: Event,
+}
+
+impl JsEvent {
+    /// Creates a new `Event` instance from its state.
+    #[must_use]
+    pub fn new(event: Event) -> Self {
+        Self { event }
+    }
+}
+
+#[boa_class(rename = "Event")]
+#[boa(rename_all = "camelCase")]
+impl JsEvent {
+    #[boa(constructor)]
+    #[boa(length = 1)]
+    fn constructor(
+        event_type: Option<JsValue>,
+        init: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let event_type = event_type_argument(event_type, context)?;
+        let dict = dictionary(init.as_ref())?;
+        let init = EventInit::from_dictionary(dict.as_ref(), context)?;
+        Ok(Self::new(Event::new(event_type, init, context)))
+    }
+
+    #[boa(getter)]
+    #[boa(rename = "type")]
+    fn r#type(JsThis(this): JsThis<JsValue>) -> JsResult<JsString> {
+        with_this_event(&this, Event::event_type)
+    }
+
+    #[boa(getter)]
+    fn target(JsThis(this): JsThis<JsValue>) -> JsResult<JsValue> {
+        with_this_event(&this, |event| nullable(event.target.borrow().clone()))
+    }
+
+    /// Legacy alias of `target`.
+    #[boa(getter)]
+    fn src_element(JsThis(this): JsThis<JsValue>) -> JsResult<JsValue> {
+        with_this_event(&this, |event| nullable(event.target.borrow().clone()))
+    }
+
+    #[boa(getter)]
+    fn current_target(JsThis(this): JsThis<JsValue>) -> JsResult<JsValue> {
+        with_this_event(&this, |event| nullable(event.current_target()))
+    }
+
+    /// Returns the event path. Without a node tree, this is just the current target.
+    ///
+    /// See <https://dom.spec.whatwg.org/#dom-event-composedpath>
+    #[boa(method)]
+    #[boa(length = 0)]
+    fn composed_path(JsThis(this): JsThis<JsValue>, context: &mut Context) -> JsResult<JsArray> {
+        let current_target = with_this_event(&this, Event::current_target)?;
+        Ok(JsArray::from_iter(
+            current_target.into_iter().map(JsValue::from),
+            context,
+        ))
+    }
+
+    #[boa(getter)]
+    fn event_phase(JsThis(this): JsThis<JsValue>) -> JsResult<u16> {
+        with_this_event(&this, |event| event.phase.get())
+    }
+
+    #[boa(method)]
+    #[boa(length = 0)]
+    fn stop_propagation(JsThis(this): JsThis<JsValue>) -> JsResult<()> {
+        with_this_event(&this, |event| {
+            event.update_flags(|flags| flags.stop_propagation = true);
+        })
+    }
+
+    #[boa(getter)]
+    fn cancel_bubble(JsThis(this): JsThis<JsValue>) -> JsResult<bool> {
+        with_this_event(&this, |event| event.flags.get().stop_propagation)
+    }
+
+    #[boa(setter)]
+    #[boa(rename = "cancelBubble")]
+    fn set_cancel_bubble(JsThis(this): JsThis<JsValue>, value: JsValue) -> JsResult<()> {
+        if value.to_boolean() {
+            with_this_event(&this, |event| {
+                event.update_flags(|flags| flags.stop_propagation = true);
+            })?;
+        }
+        Ok(())
+    }
+
+    #[boa(method)]
+    #[boa(length = 0)]
+    fn stop_immediate_propagation(JsThis(this): JsThis<JsValue>) -> JsResult<()> {
+        with_this_event(&this, |event| {
+            event.update_flags(|flags| {
+                flags.stop_propagation = true;
+                flags.stop_immediate_propagation = true;
+            });
+        })
+    }
+
+    #[boa(getter)]
+    fn bubbles(JsThis(this): JsThis<JsValue>) -> JsResult<bool> {
+        with_this_event(&this, |event| event.bubbles.get())
+    }
+
+    #[boa(getter)]
+    fn cancelable(JsThis(this): JsThis<JsValue>) -> JsResult<bool> {
+        with_this_event(&this, |event| event.cancelable.get())
+    }
+
+    /// Legacy inverse of `defaultPrevented`.
+    #[boa(getter)]
+    fn return_value(JsThis(this): JsThis<JsValue>) -> JsResult<bool> {
+        with_this_event(&this, |event| !event.is_canceled())
+    }
+
+    #[boa(setter)]
+    #[boa(rename = "returnValue")]
+    fn set_return_value(JsThis(this): JsThis<JsValue>, value: JsValue) -> JsResult<()> {
+        if !value.to_boolean() {
+            with_this_event(&this, Event::set_canceled)?;
+        }
+        Ok(())
+    }
+
+    #[boa(method)]
+    #[boa(length = 0)]
+    fn prevent_default(JsThis(this): JsThis<JsValue>) -> JsResult<()> {
+        with_this_event(&this, Event::set_canceled)
+    }
+
+    #[boa(getter)]
+    fn default_prevented(JsThis(this): JsThis<JsValue>) -> JsResult<bool> {
+        with_this_event(&this, Event::is_canceled)
+    }
+
+    #[boa(getter)]
+    fn composed(JsThis(this): JsThis<JsValue>) -> JsResult<bool> {
+        with_this_event(&this, |event| event.composed)
+    }
+
+    #[boa(getter)]
+    fn is_trusted(JsThis(this): JsThis<JsValue>) -> JsResult<bool> {
+        with_this_event(&this, Event::is_trusted)
+    }
+
+    #[boa(getter)]
+    fn time_stamp(JsThis(this): JsThis<JsValue>) -> JsResult<f64> {
+        with_this_event(&this, |event| event.time_stamp)
+    }
+
+    /// Legacy initializer, only effective while the event is not being dispatched.
+    ///
+    /// See <https://dom.spec.whatwg.org/#dom-event-initevent>
+    #[boa(method)]
+    #[boa(length = 1)]
+    fn init_event(
+        JsThis(this): JsThis<JsValue>,
+        event_type: Option<JsValue>,
+        bubbles: Option<JsValue>,
+        cancelable: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let event_type = event_type_argument(event_type, context)?;
+        let bubbles = bubbles.is_some_and(|b| b.to_boolean());
+        let cancelable = cancelable.is_some_and(|c| c.to_boolean());
+
+        with_this_event(&this, |event| {
+            if event.flags.get().dispatch {
+                return;
+            }
+            event.update_flags(|flags| {
+                *flags = EventFlags {
+                    initialized: true,
+                    trusted: false,
+                    ..EventFlags::default()
+                };
+            });
+            *event.target.borrow_mut() = None;
+            *event.event_type.borrow_mut() = event_type;
+            event.bubbles.set(bubbles);
+            event.cancelable.set(cancelable);
+        })
+    }
+}
+
+/// The JavaScript `CustomEvent` class, an event carrying arbitrary `detail` data.
+///
+/// See <https://dom.spec.whatwg.org/#interface-customevent>
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct JsCustomEvent {
+    event: Event,
+    detail: GcRefCell<JsValue>,
+}
+
+#[boa_class(rename = "CustomEvent")]
+#[boa(rename_all = "camelCase")]
+impl JsCustomEvent {
+    #[boa(constructor)]
+    #[boa(length = 1)]
+    fn constructor(
+        event_type: Option<JsValue>,
+        init: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let event_type = event_type_argument(event_type, context)?;
+        let dict = dictionary(init.as_ref())?;
+        let event_init = EventInit::from_dictionary(dict.as_ref(), context)?;
+        let detail = match &dict {
+            Some(dict) => dict.get(js_string!("detail"), context)?,
+            None => JsValue::null(),
+        };
+        let detail = if detail.is_undefined() {
+            JsValue::null()
+        } else {
+            detail
+        };
+
+        Ok(Self {
+            event: Event::new(event_type, event_init, context),
+            detail: GcRefCell::new(detail),
+        })
+    }
+
+    #[boa(getter)]
+    fn detail(&self) -> JsValue {
+        self.detail.borrow().clone()
+    }
+
+    /// Legacy initializer, only effective while the event is not being dispatched.
+    ///
+    /// See <https://dom.spec.whatwg.org/#dom-customevent-initcustomevent>
+    #[boa(method)]
+    #[boa(length = 1)]
+    fn init_custom_event(
+        this: JsClass<Self>,
+        event_type: Option<JsValue>,
+        bubbles: Option<JsValue>,
+        cancelable: Option<JsValue>,
+        detail: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let object = this.inner().upcast();
+        JsEvent::init_event(
+            JsThis(object.into()),
+            event_type,
+            bubbles,
+            cancelable,
+            context,
+        )?;
+
+        let data = this.borrow();
+        if !data.event.flags.get().dispatch {
+            *data.detail.borrow_mut() = detail.unwrap_or_else(JsValue::null);
+        }
+        Ok(())
+    }
+}
+
+/// The JavaScript `ErrorEvent` class, used to report uncaught script errors.
+///
+/// See <https://html.spec.whatwg.org/multipage/webappapis.html#errorevent>
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct JsErrorEvent {
+    event: Event,
+    message: JsString,
+    filename: JsString,
+    #[unsafe_ignore_trace]
+    lineno: u32,
+    #[unsafe_ignore_trace]
+    colno: u32,
+    error: JsValue,
+}
+
+#[boa_class(rename = "ErrorEvent")]
+#[boa(rename_all = "camelCase")]
+impl JsErrorEvent {
+    #[boa(constructor)]
+    #[boa(length = 1)]
+    fn constructor(
+        event_type: Option<JsValue>,
+        init: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let event_type = event_type_argument(event_type, context)?;
+        let dict = dictionary(init.as_ref())?;
+        let event_init = EventInit::from_dictionary(dict.as_ref(), context)?;
+
+        let mut this = Self {
+            event: Event::new(event_type, event_init, context),
+            message: JsString::default(),
+            filename: JsString::default(),
+            lineno: 0,
+            colno: 0,
+            error: JsValue::undefined(),
+        };
+
+        if let Some(dict) = dict {
+            // Dictionary members are read in lexicographical order.
+            let colno = dict.get(js_string!("colno"), context)?;
+            if !colno.is_undefined() {
+                this.colno = colno.to_u32(context)?;
+            }
+            this.error = dict.get(js_string!("error"), context)?;
+            this.filename = string_member(&dict, js_string!("filename"), context)?;
+            let lineno = dict.get(js_string!("lineno"), context)?;
+            if !lineno.is_undefined() {
+                this.lineno = lineno.to_u32(context)?;
+            }
+            this.message = string_member(&dict, js_string!("message"), context)?;
+        }
+
+        Ok(this)
+    }
+
+    #[boa(getter)]
+    fn message(&self) -> JsString {
+        self.message.clone()
+    }
+
+    #[boa(getter)]
+    fn filename(&self) -> JsString {
+        self.filename.clone()
+    }
+
+    #[boa(getter)]
+    fn lineno(&self) -> u32 {
+        self.lineno
+    }
+
+    #[boa(getter)]
+    fn colno(&self) -> u32 {
+        self.colno
+    }
+
+    #[boa(getter)]
+    fn error(&self) -> JsValue {
+        self.error.clone()
+    }
+}
+
+/// The JavaScript `MessageEvent` class, used to deliver messages posted with `postMessage`.
+///
+/// See <https://html.spec.whatwg.org/multipage/comms.html#messageevent>
+#[derive(Debug, Trace, Finalize, JsData)]
+pub struct JsMessageEvent {
+    event: Event,
+    data: JsValue,
+    origin: JsString,
+    last_event_id: JsString,
+    source: Option<JsObject>,
+    ports: JsObject,
+}
+
+impl JsMessageEvent {
+    /// Creates a trusted `message` event carrying `data`, as fired by the runtime when a
+    /// message is received.
+    ///
+    /// # Errors
+    /// Returns an error if the `ports` array cannot be created.
+    pub fn message(data: JsValue, origin: JsString, context: &mut Context) -> JsResult<Self> {
+        let event = Event::new(js_string!("message"), EventInit::default(), context);
+        event.set_trusted(true);
+        Ok(Self {
+            event,
+            data,
+            origin,
+            last_event_id: JsString::default(),
+            source: None,
+            ports: frozen_array(Vec::new(), context)?,
+        })
+    }
+}
+
+/// Creates a frozen array from `values`, as used by `FrozenArray<T>` attributes.
+fn frozen_array(values: Vec<JsValue>, context: &mut Context) -> JsResult<JsObject> {
+    let array: JsObject = JsArray::from_iter(values, context).into();
+    array.set_integrity_level(IntegrityLevel::Frozen, context)?;
+    Ok(array)
+}
+
+#[boa_class(rename = "MessageEvent")]
+#[boa(rename_all = "camelCase")]
+impl JsMessageEvent {
+    #[boa(constructor)]
+    #[boa(length = 1)]
+    fn constructor(
+        event_type: Option<JsValue>,
+        init: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let event_type = event_type_argument(event_type, context)?;
+        let dict = dictionary(init.as_ref())?;
+        let event_init = EventInit::from_dictionary(dict.as_ref(), context)?;
+
+        let mut data = JsValue::null();
+        let mut last_event_id = JsString::default();
+        let mut origin = JsString::default();
+        let mut ports = Vec::new();
+        let mut source = None;
+
+        if let Some(dict) = dict {
+            // Dictionary members are read in lexicographical order.
+            let value = dict.get(js_string!("data"), context)?;
+            if !value.is_undefined() {
+                data = value;
+            }
+            last_event_id = string_member(&dict, js_string!("lastEventId"), context)?;
+            origin = string_member(&dict, js_string!("origin"), context)?;
+            let value = dict.get(js_string!("ports"), context)?;
+            if !value.is_undefined() {
+                ports = Vec::<JsValue>::try_from_js(&value, context)?;
+            }
+            let value = dict.get(js_string!("source"), context)?;
+            if !value.is_null_or_undefined() {
+                source = Some(value.as_object().ok_or_else(
+                    || js_error!(TypeError: "MessageEvent source must be an object or null"),
+                )?);
+            }
+        }
+
+        Ok(Self {
+            event: Event::new(event_type, event_init, context),
+            data,
+            origin,
+            last_event_id,
+            source,
+            ports: frozen_array(ports, context)?,
+        })
+    }
+
+    #[boa(getter)]
+    fn data(&self) -> JsValue {
+        self.data.clone()
+    }
+
+    #[boa(getter)]
+    fn origin(&self) -> JsString {
+        self.origin.clone()
+    }
+
+    #[boa(getter)]
+    fn last_event_id(&self) -> JsString {
+        self.last_event_id.clone()
+    }
+
+    #[boa(getter)]
+    fn source(&self) -> JsValue {
+        nullable(self.source.clone())
+    }
+
+    #[boa(getter)]
+    fn ports(&self) -> JsObject {
+        self.ports.clone()
+    }
+}
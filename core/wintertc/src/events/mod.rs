@@ -7,23 +7,218 @@
 //! `EventTarget` and associated event interfaces are required in the `WinterTC` TC55
 //! Minimum Common Web API.
 //!
-//! # TODO
+//! # Native event targets
 //!
-//! - Implement `EventTarget`.
-//! - Implement `Event`.
-//! - Implement `CustomEvent`.
-//! - Implement `ErrorEvent`.
-//! - Implement `MessageEvent`.
+//! Native classes can become event targets by storing an [`EventTarget`] and implementing
+//! [`EventTargetData`]. Once registered with [`register_event_target_class`], their
+//! prototype inherits from `EventTarget.prototype` and the runtime can fire events at their
+//! instances with [`fire_event`] or [`dispatch_event`].
+//!
+//! In the same way, native `AbortSignal` classes store an [`AbortState`] and implement
+//! [`AbortSignalData`]. Once registered with [`register_abort_signal_class`], their instances
+//! are accepted as the `signal` option of `addEventListener`.
+//!
+//! Registering these APIs also makes the global object an event target, as it is in
+//! worker scopes.
+
+use boa_engine::class::Class;
+use boa_engine::context::intrinsics::StandardConstructor;
+use boa_engine::property::PropertyDescriptor;
+use boa_engine::realm::Realm;
+use boa_engine::{Context, JsObject, JsResult, JsString, boa_module, js_error, js_string};
+
+mod event;
+mod target;
+
+#[cfg(test)]
+mod tests;
+
+pub use event::{
+    AT_TARGET, BUBBLING_PHASE, CAPTURING_PHASE, Event, EventInit, JsCustomEvent, JsErrorEvent,
+    JsEvent, JsMessageEvent, NONE,
+};
+pub use target::{
+    AbortState, AddEventListenerOptions, EventTarget, JsEventTarget, abort_state_of,
+    event_target_of,
+};
+
+/// A native class whose instances are event targets.
+pub trait EventTargetData: Class {
+    /// Returns the event listener list of this instance.
+    fn event_target(&self) -> &EventTarget;
+}
+
+/// A native `AbortSignal` class, whose instances are event targets that can remove event
+/// listeners when aborted.
+pub trait AbortSignalData: EventTargetData {
+    /// Returns the aborted flag and the abort algorithms of this instance.
+    fn abort_state(&self) -> &AbortState;
+}
+
+fn downcast_abort_state<T: AbortSignalData>(object: &JsObject) -> Option<AbortState> {
+    object
+        .downcast_ref::<T>()
+        .map(|data| data.abort_state().clone())
+}
+
+fn downcast_event_target<T: EventTargetData>(object: &JsObject) -> Option<EventTarget> {
+    object
+        .downcast_ref::<T>()
+        .map(|data| data.event_target().clone())
+}
+
+/// Returns the class `T` as registered in `realm`, or in the current realm if `None`.
+fn get_class<T: Class>(realm: Option<&Realm>, context: &Context) -> JsResult<StandardConstructor> {
+    match realm {
+        Some(realm) => realm.get_class::<T>(),
+        None => context.get_global_class::<T>(),
+    }
+    .ok_or_else(|| js_error!(Error: "{} class should be registered", T::NAME))
+}
+
+/// Makes the prototype and constructor of the class `T` inherit from those of `P`.
+fn inherit<T: Class, P: Class>(realm: Option<&Realm>, context: &Context) -> JsResult<()> {
+    let child = get_class::<T>(realm, context)?;
+    let parent = get_class::<P>(realm, context)?;
+    child.prototype().set_prototype(Some(parent.prototype()));
+    child
+        .constructor()
+        .set_prototype(Some(parent.constructor()));
+    Ok(())
+}
+
+/// Makes the already registered native class `T` an `EventTarget` subclass.
+///
+/// `EventTarget` must already be registered in the realm (see [`register`]).
+///
+/// # Errors
+///
+/// Returns a [`boa_engine::JsError`] if `T` or `EventTarget` are not registered.
+pub fn register_event_target_class<T: EventTargetData>(
+    realm: Option<Realm>,
+    context: &mut Context,
+) -> JsResult<()> {
+    inherit::<T, JsEventTarget>(realm.as_ref(), context)?;
+
+    let realm = realm.unwrap_or_else(|| context.realm().clone());
+    target::register_accessor(&realm, downcast_event_target::<T>)
+}
+
+/// Makes the already registered native class `T` an `EventTarget` subclass whose instances
+/// are accepted as the `signal` option of `addEventListener`.
+///
+/// `EventTarget` must already be registered in the realm (see [`register`]).
+///
+/// # Errors
+///
+/// Returns a [`boa_engine::JsError`] if `T` or `EventTarget` are not registered.
+pub fn register_abort_signal_class<T: AbortSignalData>(
+    realm: Option<Realm>,
+    context: &mut Context,
+) -> JsResult<()> {
+    let realm = realm.unwrap_or_else(|| context.realm().clone());
+    register_event_target_class::<T>(Some(realm.clone()), context)?;
+    target::register_signal_accessor(&realm, downcast_abort_state::<T>)
+}
+
+/// Dispatches an already created event object at `target`, keeping the event's
+/// `isTrusted` flag.
+///
+/// Returns `false` if the event was canceled.
+///
+/// # Errors
+///
+/// Returns a [`boa_engine::JsError`] if `target` is not an event target or `event` is not
+/// a dispatchable event.
+pub fn dispatch_event(
+    target: &JsObject,
+    event: &JsObject,
+    context: &mut Context,
+) -> JsResult<bool> {
+    let event_target = event_target_of(target, context)
+        .ok_or_else(|| js_error!(TypeError: "the target is not an EventTarget"))?;
+    event_target.dispatch(target, event, context)
+}
+
+/// Fires a trusted `Event` named `event_type` at `target`.
+///
+/// Returns `false` if the event was canceled.
+///
+/// See <https://dom.spec.whatwg.org/#concept-event-fire>
+///
+/// # Errors
+///
+/// Returns a [`boa_engine::JsError`] if `target` is not an event target, or if the
+/// `Event` class is not registered.
+pub fn fire_event(
+    target: &JsObject,
+    event_type: JsString,
+    context: &mut Context,
+) -> JsResult<bool> {
+    let event = Event::new(event_type, EventInit::default(), context);
+    event.set_trusted(true);
+    let event = JsEvent::from_data(JsEvent::new(event), context)?;
+    dispatch_event(target, &event, context)
+}
+
+/// JavaScript module containing the event classes.
+#[boa_module]
+mod js_module {
+    type JsEventTarget = super::JsEventTarget;
+    type JsEvent = super::JsEvent;
+    type JsCustomEvent = super::JsCustomEvent;
+    type JsErrorEvent = super::JsErrorEvent;
+    type JsMessageEvent = super::JsMessageEvent;
+}
 
 /// Register event globals (`EventTarget`, `Event`, `CustomEvent`, `ErrorEvent`,
-/// `MessageEvent`) into the given context.
+/// `MessageEvent`) into the given context, and make the global object an event target.
+///
+/// Like the global scope of workers, the global object of the realm is made to inherit from
+/// `EventTarget.prototype`: its `[[Prototype]]` is replaced, so any prototype previously set
+/// by the embedder is no longer in its prototype chain. Embedders that customize the global
+/// object's prototype should do so after calling this function, and keep
+/// `EventTarget.prototype` in the chain for `addEventListener` and `dispatchEvent` to be
+/// available on the global object.
 ///
 /// # Errors
 ///
 /// Returns a [`boa_engine::JsError`] if registration fails.
-pub fn register(
-    _realm: Option<boa_engine::realm::Realm>,
-    _ctx: &mut boa_engine::Context,
-) -> boa_engine::JsResult<()> {
+pub fn register(realm: Option<Realm>, ctx: &mut Context) -> JsResult<()> {
+    js_module::boa_register(realm.clone(), ctx)?;
+
+    inherit::<JsCustomEvent, JsEvent>(realm.as_ref(), ctx)?;
+    inherit::<JsErrorEvent, JsEvent>(realm.as_ref(), ctx)?;
+    inherit::<JsMessageEvent, JsEvent>(realm.as_ref(), ctx)?;
+
+    // The phase constants are exposed on both the constructor and the prototype.
+    let event = get_class::<JsEvent>(realm.as_ref(), ctx)?;
+    for object in [event.constructor(), event.prototype()] {
+        for (name, value) in [
+            (js_string!("NONE"), NONE),
+            (js_string!("CAPTURING_PHASE"), CAPTURING_PHASE),
+            (js_string!("AT_TARGET"), AT_TARGET),
+            (js_string!("BUBBLING_PHASE"), BUBBLING_PHASE),
+        ] {
+            object.define_property_or_throw(
+                name,
+                PropertyDescriptor::builder()
+                    .value(value)
+                    .writable(false)
+                    .enumerable(true)
+                    .configurable(false),
+                ctx,
+            )?;
+        }
+    }
+
+    let realm = realm.unwrap_or_else(|| ctx.realm().clone());
+    let event_target = get_class::<JsEventTarget>(Some(&realm), ctx)?;
+    let previous_realm = ctx.enter_realm(realm.clone());
+    let global_object = ctx.global_object();
+    ctx.enter_realm(previous_realm);
+    global_object.set_prototype(Some(event_target.prototype()));
+    target::init_registry(&realm);
+
     Ok(())
 }
@@ -0,0 +1,628 @@
+//! The `EventTarget` interface and the event dispatch algorithm.
+//!
+//! Spec: <https://dom.spec.whatwg.org/#interface-eventtarget>
+
+use super::event::{Event, with_event};
+use crate::crypto::dom_exception;
+use boa_engine::gc::{Gc, GcRefCell};
+use boa_engine::interop::JsThis;
+use boa_engine::job::GenericJob;
+use boa_engine::realm::Realm;
+use boa_engine::{
+    Context, Finalize, JsData, JsError, JsObject, JsResult, JsString, JsValue, Trace, boa_class,
+    js_error, js_string,
+};
+use std::cell::Cell;
+
+/// A registered event listener.
+///
+/// See <https://dom.spec.whatwg.org/#concept-event-listener>
+#[derive(Debug, Trace, Finalize)]
+struct EventListener {
+    event_type: JsString,
+    callback: JsObject,
+    #[unsafe_ignore_trace]
+    capture: bool,
+    #[unsafe_ignore_trace]
+    passive: bool,
+    #[unsafe_ignore_trace]
+    once: bool,
+    #[unsafe_ignore_trace]
+    removed: Cell<bool>,
+    signal: Option<AbortState>,
+}
+
+impl EventListener {
+    fn matches(&self, event_type: &JsString, callback: &JsObject, capture: bool) -> bool {
+        self.event_type == *event_type
+            && JsObject::equals(&self.callback, callback)
+            && self.capture == capture
+    }
+}
+
+/// Options accepted by `addEventListener`.
+///
+/// See <https://dom.spec.whatwg.org/#dictdef-addeventlisteneroptions>
+#[derive(Debug, Default, Clone, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+pub struct AddEventListenerOptions {
+    /// Whether the listener is invoked during the capturing phase.
+    #[unsafe_ignore_trace]
+    pub capture: bool,
+    /// Whether the listener is removed after its first invocation.
+    #[unsafe_ignore_trace]
+    pub once: bool,
+    /// Whether calls to `preventDefault()` from the listener are ignored.
+    #[unsafe_ignore_trace]
+    pub passive: bool,
+    /// The state of an abort signal that removes the listener once aborted.
+    pub signal: Option<AbortState>,
+}
+
+impl AddEventListenerOptions {
+    /// Converts the `options` argument of `addEventListener`, which is either a boolean
+    /// (the `capture` flag) or an `AddEventListenerOptions` dictionary.
+    fn from_js(options: Option<&JsValue>, context: &mut Context) -> JsResult<Self> {
+        let Some(options) = options else {
+            return Ok(Self::default());
+        };
+        let Some(dict) = options.as_object() else {
+            return Ok(Self {
+                capture: options.to_boolean(),
+                ..Self::default()
+            });
+        };
+
+        let capture = dict.get(js_string!("capture"), context)?.to_boolean();
+        let once = dict.get(js_string!("once"), context)?.to_boolean();
+        let passive = dict.get(js_string!("passive"), context)?.to_boolean();
+        let signal = dict.get(js_string!("signal"), context)?;
+        let signal = if signal.is_undefined() {
+            None
+        } else {
+            let signal = signal
+                .as_object()
+                .and_then(|signal| abort_state_of(&signal, context))
+                .ok_or_else(|| js_error!(TypeError: "'signal' member is not an AbortSignal"))?;
+            Some(signal)
+        };
+
+        Ok(Self {
+            capture,
+            once,
+            passive,
+            signal,
+        })
+    }
+}
+
+/// Reads the `capture` flag from the `options` argument of `removeEventListener`.
+fn capture_from_options(options: Option<&JsValue>, context: &mut Context) -> JsResult<bool> {
+    match options {
+        None => Ok(false),
+        Some(options) => match options.as_object() {
+            Some(dict) => Ok(dict.get(js_string!("capture"), context)?.to_boolean()),
+            None => Ok(options.to_boolean()),
+        },
+    }
+}
+
+/// The aborted flag and the abort algorithms of an `AbortSignal`.
+///
+/// This is a handle to shared state: cloning it returns a handle to the same signal. Native
+/// `AbortSignal` classes store one of these and are registered with
+/// [`register_abort_signal_class`][super::register_abort_signal_class], which makes
+/// `addEventListener` accept their instances as the `signal` option.
+///
+/// See <https://dom.spec.whatwg.org/#interface-AbortSignal>
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct AbortState {
+    inner: Gc<AbortStateInner>,
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct AbortStateInner {
+    #[unsafe_ignore_trace]
+    aborted: Cell<bool>,
+    /// The listeners to remove when the signal is aborted, along with their targets.
+    algorithms: GcRefCell<Vec<(EventTarget, Gc<EventListener>)>>,
+}
+
+impl Default for AbortState {
+    fn default() -> Self {
+        Self {
+            inner: Gc::new(AbortStateInner {
+                aborted: Cell::new(false),
+                algorithms: GcRefCell::new(Vec::new()),
+            }),
+        }
+    }
+}
+
+impl AbortState {
+    /// Returns `true` if the signal has been aborted.
+    #[must_use]
+    pub fn is_aborted(&self) -> bool {
+        self.inner.aborted.get()
+    }
+
+    /// Marks the signal as aborted and runs its abort algorithms, which removes the event
+    /// listeners bound to it.
+    ///
+    /// Does nothing if the signal was already aborted. Firing the `abort` event at the signal
+    /// is left to the caller.
+    ///
+    /// See <https://dom.spec.whatwg.org/#abortsignal-signal-abort>
+    pub fn abort(&self) {
+        if self.inner.aborted.replace(true) {
+            return;
+        }
+
+        let algorithms = std::mem::take(&mut *self.inner.algorithms.borrow_mut());
+        for (target, listener) in algorithms {
+            target.remove_listener(&listener);
+        }
+    }
+
+    fn add_algorithm(&self, target: EventTarget, listener: Gc<EventListener>) {
+        self.inner.algorithms.borrow_mut().push((target, listener));
+    }
+
+    fn remove_algorithm(&self, listener: &Gc<EventListener>) {
+        self.inner
+            .algorithms
+            .borrow_mut()
+            .retain(|(_, l)| !Gc::ptr_eq(l, listener));
+    }
+}
+
+/// The event listener list of an event target.
+///
+/// This is a handle to a shared list: cloning it returns a handle to the same list. Native
+/// classes that want to be event targets store one of these and are registered with
+/// [`register_event_target_class`][super::register_event_target_class], which makes the
+/// `EventTarget.prototype` methods work on their instances.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct EventTarget {
+    listeners: Gc<GcRefCell<Vec<Gc<EventListener>>>>,
+}
+
+impl Default for EventTarget {
+    fn default() -> Self {
+        Self {
+            listeners: Gc::new(GcRefCell::new(Vec::new())),
+        }
+    }
+}
+
+impl EventTarget {
+    /// Adds an event listener to this target.
+    ///
+    /// See <https://dom.spec.whatwg.org/#add-an-event-listener>
+    ///
+    pub fn add_event_listener(
+        &self,
+        event_type: JsString,
+        callback: JsObject,
+        options: AddEventListenerOptions,
+    ) {
+        // 1. If listener's signal is not null and is aborted, then return.
+        if options.signal.as_ref().is_some_and(AbortState::is_aborted) {
+            return;
+        }
+
+        // 3. If eventTarget's event listener list does not contain an event listener whose
+        //    type is listener's type, callback is listener's callback, and capture is
+        //    listener's capture, then append listener to eventTarget's event listener list.
+        if self
+            .listeners
+            .borrow()
+            .iter()
+            .any(|l| l.matches(&event_type, &callback, options.capture))
+        {
+            return;
+        }
+
+        let listener = Gc::new(EventListener {
+            event_type,
+            callback,
+            capture: options.capture,
+            passive: options.passive,
+            once: options.once,
+            removed: Cell::new(false),
+            signal: options.signal,
+        });
+        self.listeners.borrow_mut().push(listener.clone());
+
+        // 4. If listener's signal is not null, then add the following abort steps to it:
+        //    remove an event listener with eventTarget and listener.
+        if let Some(signal) = &listener.signal {
+            signal.add_algorithm(self.clone(), listener.clone());
+        }
+    }
+
+    /// Removes the event listener matching `event_type`, `callback` and `capture`, if any.
+    ///
+    /// See <https://dom.spec.whatwg.org/#remove-an-event-listener>
+    pub fn remove_event_listener(&self, event_type: &JsString, callback: &JsObject, capture: bool) {
+        let listener = self
+            .listeners
+            .borrow()
+            .iter()
+            .find(|l| l.matches(event_type, callback, capture))
+            .cloned();
+        if let Some(listener) = listener {
+            self.remove_listener(&listener);
+        }
+    }
+
+    fn remove_listener(&self, listener: &Gc<EventListener>) {
+        listener.removed.set(true);
+        self.listeners
+            .borrow_mut()
+            .retain(|l| !Gc::ptr_eq(l, listener));
+
+        // The abort steps of a removed listener have nothing left to do.
+        if let Some(signal) = &listener.signal {
+            signal.remove_algorithm(listener);
+        }
+    }
+
+    /// Returns `true` if this target has at least one listener for `event_type`.
+    #[must_use]
+    pub fn has_listeners(&self, event_type: &JsString) -> bool {
+        self.listeners
+            .borrow()
+            .iter()
+            .any(|l| l.event_type == *event_type)
+    }
+
+    /// Dispatches `event` to the listeners of this target. `target` is the object this
+    /// target belongs to, used as the event's `target` and `currentTarget`.
+    ///
+    /// Returns `false` if the event was canceled by one of the listeners. Exceptions thrown
+    /// by listeners do not interrupt the dispatch; they are reported as uncaught errors by
+    /// enqueuing a job that rethrows them.
+    ///
+    /// See <https://dom.spec.whatwg.org/#concept-event-dispatch>
+    ///
+    /// # Errors
+    /// Returns a `TypeError` if `event` is not an event object, and an `InvalidStateError`
+    /// if it is already being dispatched or was not initialized.
+    pub fn dispatch(
+        &self,
+        target: &JsObject,
+        event: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<bool> {
+        // 1. Set event's dispatch flag.
+        check_dispatchable(event, context)?;
+        with_event(event, |e| e.begin_dispatch(target));
+
+        // Without a node tree, the event path only contains the target itself, so the
+        // event is invoked at-target, first for capturing listeners then for the others.
+        let listeners = self.listeners.borrow().clone();
+        for capture in [true, false] {
+            if self.inner_invoke(event, &listeners, capture, context) {
+                break;
+            }
+        }
+
+        // Unset event's dispatch, stop propagation and stop immediate propagation flags.
+        Ok(with_event(event, |e| {
+            e.end_dispatch();
+            !e.is_canceled()
+        })
+        .unwrap_or(true))
+    }
+
+    /// Invokes the listeners in `listeners` for the given phase, returning `true` if the
+    /// event's propagation was stopped.
+    ///
+    /// See <https://dom.spec.whatwg.org/#concept-event-listener-inner-invoke>
+    fn inner_invoke(
+        &self,
+        event: &JsObject,
+        listeners: &[Gc<EventListener>],
+        capture: bool,
+        context: &mut Context,
+    ) -> bool {
+        let Some((event_type, current_target, stopped)) = with_event(event, |e| {
+            (
+                e.event_type(),
+                e.current_target(),
+                e.is_propagation_stopped(),
+            )
+        }) else {
+            return false;
+        };
+
+        // If event's stop propagation flag is set, then return.
+        if stopped {
+            return true;
+        }
+        let this = current_target.map_or_else(JsValue::undefined, JsValue::from);
+
+        for listener in listeners {
+            if listener.removed.get()
+                || listener.event_type != event_type
+                || listener.capture != capture
+            {
+                continue;
+            }
+
+            if listener.once {
+                self.remove_listener(listener);
+            }
+
+            if listener.passive {
+                with_event(event, |e| e.set_in_passive_listener(true));
+            }
+
+            if let Err(err) = call_listener(&listener.callback, &this, event, context) {
+                report_exception(err, context);
+            }
+
+            if listener.passive {
+                with_event(event, |e| e.set_in_passive_listener(false));
+            }
+
+            if with_event(event, Event::is_immediate_propagation_stopped).unwrap_or(false) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// Throws if `event` is not an event object, or if it cannot be dispatched because it is
+/// already being dispatched or was not initialized.
+fn check_dispatchable(event: &JsObject, context: &mut Context) -> JsResult<()> {
+    let can_dispatch = with_event(event, Event::can_dispatch)
+        .ok_or_else(|| js_error!(TypeError: "parameter 1 is not of type 'Event'"))?;
+    if can_dispatch {
+        Ok(())
+    } else {
+        Err(dom_exception(
+            "InvalidStateError",
+            "the event is already being dispatched or was not initialized",
+            context,
+        ))
+    }
+}
+
+/// Calls an event listener callback, which is either a function or an object with a
+/// `handleEvent` method.
+///
+/// See <https://webidl.spec.whatwg.org/#call-a-user-objects-operation>
+fn call_listener(
+    callback: &JsObject,
+    this: &JsValue,
+    event: &JsObject,
+    context: &mut Context,
+) -> JsResult<()> {
+    let args = [event.clone().into()];
+    if callback.is_callable() {
+        callback.call(this, &args, context)?;
+    } else {
+        let handle_event = callback.get(js_string!("handleEvent"), context)?;
+        let handle_event = handle_event
+            .as_callable()
+            .ok_or_else(|| js_error!(TypeError: "'handleEvent' property is not callable"))?;
+        handle_event.call(&callback.clone().into(), &args, context)?;
+    }
+    Ok(())
+}
+
+/// Reports an exception thrown by an event listener without interrupting the dispatch,
+/// by rethrowing it from a job.
+fn report_exception(err: JsError, context: &mut Context) {
+    let realm = context.realm().clone();
+    context.enqueue_job(GenericJob::new(move |_| Err(err), realm).into());
+}
+
+/// A function returning the [`EventTarget`] embedded in a native object of a specific type.
+type EventTargetAccessor = fn(&JsObject) -> Option<EventTarget>;
+
+/// A function returning the [`AbortState`] embedded in a native object of a specific type.
+type AbortStateAccessor = fn(&JsObject) -> Option<AbortState>;
+
+/// The per-realm registry of event target types, along with the event target of the
+/// realm's global object.
+#[derive(Debug, Default, Trace, Finalize, JsData)]
+pub(crate) struct EventTargetRegistry {
+    global: EventTarget,
+    #[unsafe_ignore_trace]
+    accessors: Vec<EventTargetAccessor>,
+    #[unsafe_ignore_trace]
+    signal_accessors: Vec<AbortStateAccessor>,
+}
+
+impl EventTargetRegistry {
+    pub(crate) fn register(&mut self, accessor: EventTargetAccessor) {
+        if !self
+            .accessors
+            .iter()
+            .any(|a| std::ptr::fn_addr_eq(*a, accessor))
+        {
+            self.accessors.push(accessor);
+        }
+    }
+
+    pub(crate) fn register_signal(&mut self, accessor: AbortStateAccessor) {
+        if !self
+            .signal_accessors
+            .iter()
+            .any(|a| std::ptr::fn_addr_eq(*a, accessor))
+        {
+            self.signal_accessors.push(accessor);
+        }
+    }
+}
+
+/// Creates the registry of `realm`, which makes its global object an event target.
+pub(crate) fn init_registry(realm: &Realm) {
+    let mut host_defined = realm.host_defined_mut();
+    if !host_defined.has::<EventTargetRegistry>() {
+        host_defined.insert_default::<EventTargetRegistry>();
+    }
+}
+
+/// Registers a new event target type in the registry of `realm`.
+///
+/// Returns an error if the registry was not created by [`init_registry`].
+pub(crate) fn register_accessor(realm: &Realm, accessor: EventTargetAccessor) -> JsResult<()> {
+    let mut host_defined = realm.host_defined_mut();
+    let registry = host_defined
+        .get_mut::<EventTargetRegistry>()
+        .ok_or_else(|| js_error!(Error: "EventTarget class should be registered"))?;
+    registry.register(accessor);
+    Ok(())
+}
+
+/// Registers a new abort signal type in the registry of `realm`.
+///
+/// Returns an error if the registry was not created by [`init_registry`].
+pub(crate) fn register_signal_accessor(
+    realm: &Realm,
+    accessor: AbortStateAccessor,
+) -> JsResult<()> {
+    let mut host_defined = realm.host_defined_mut();
+    let registry = host_defined
+        .get_mut::<EventTargetRegistry>()
+        .ok_or_else(|| js_error!(Error: "EventTarget class should be registered"))?;
+    registry.register_signal(accessor);
+    Ok(())
+}
+
+/// Returns the [`AbortState`] of `object`, if it is an abort signal in the current realm.
+#[must_use]
+pub fn abort_state_of(object: &JsObject, context: &Context) -> Option<AbortState> {
+    let realm = context.realm().clone();
+    let host_defined = realm.host_defined();
+    let registry = host_defined.get::<EventTargetRegistry>()?;
+    registry
+        .signal_accessors
+        .iter()
+        .find_map(|accessor| accessor(object))
+}
+
+/// Returns the [`EventTarget`] of `object`, if it is an event target in the current realm.
+#[must_use]
+pub fn event_target_of(object: &JsObject, context: &Context) -> Option<EventTarget> {
+    if let Some(target) = object.downcast_ref::<JsEventTarget>() {
+        return Some(target.target.clone());
+    }
+
+    let realm = context.realm().clone();
+    let host_defined = realm.host_defined();
+    let registry = host_defined.get::<EventTargetRegistry>()?;
+    if JsObject::equals(object, &context.global_object()) {
+        return Some(registry.global.clone());
+    }
+    registry
+        .accessors
+        .iter()
+        .find_map(|accessor| accessor(object))
+}
+
+/// Resolves the `this` value of the `EventTarget.prototype` methods. As with the global
+/// scope of workers, an `undefined` or `null` receiver refers to the global object.
+fn this_target(this: &JsValue, context: &mut Context) -> JsResult<(JsObject, EventTarget)> {
+    let object = if this.is_null_or_undefined() {
+        context.global_object()
+    } else {
+        this.as_object()
+            .ok_or_else(|| js_error!(TypeError: "'this' is not an EventTarget"))?
+    };
+
+    let target = event_target_of(&object, context)
+        .ok_or_else(|| js_error!(TypeError: "'this' is not an EventTarget"))?;
+    Ok((object, target))
+}
+
+/// The JavaScript `EventTarget` class.
+#[derive(Debug, Clone, Default, Trace, Finalize, JsData)]
+pub struct JsEventTarget {
+    target: EventTarget,
+}
+
+#[boa_class(rename = "EventTarget")]
+#[boa(rename_all = "camelCase")]
+impl JsEventTarget {
+    #[boa(constructor)]
+    #[boa(length = 0)]
+    fn constructor() -> Self {
+        Self::default()
+    }
+
+    /// See <https://dom.spec.whatwg.org/#dom-eventtarget-addeventlistener>
+    #[boa(method)]
+    #[boa(length = 2)]
+    fn add_event_listener(
+        JsThis(this): JsThis<JsValue>,
+        event_type: JsValue,
+        callback: JsValue,
+        options: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let (_, target) = this_target(&this, context)?;
+        let event_type = event_type.to_string(context)?;
+        let options = AddEventListenerOptions::from_js(options.as_ref(), context)?;
+
+        // 2. If listener's callback is null, then return.
+        if callback.is_null_or_undefined() {
+            return Ok(());
+        }
+        let callback = callback
+            .as_object()
+            .ok_or_else(|| js_error!(TypeError: "parameter 2 is not of type 'Object'"))?;
+
+        target.add_event_listener(event_type, callback, options);
+        Ok(())
+    }
+
+    /// See <https://dom.spec.whatwg.org/#dom-eventtarget-removeeventlistener>
+    #[boa(method)]
+    #[boa(length = 2)]
+    fn remove_event_listener(
+        JsThis(this): JsThis<JsValue>,
+        event_type: JsValue,
+        callback: JsValue,
+        options: Option<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let (_, target) = this_target(&this, context)?;
+        let event_type = event_type.to_string(context)?;
+        let capture = capture_from_options(options.as_ref(), context)?;
+
+        if let Some(callback) = callback.as_object() {
+            target.remove_event_listener(&event_type, &callback, capture);
+        }
+        Ok(())
+    }
+
+    /// See <https://dom.spec.whatwg.org/#dom-eventtarget-dispatchevent>
+    #[boa(method)]
+    #[boa(length = 1)]
+    fn dispatch_event(
+        JsThis(this): JsThis<JsValue>,
+        event: JsValue,
+        context: &mut Context,
+    ) -> JsResult<bool> {
+        let (object, target) = this_target(&this, context)?;
+        let event = event
+            .as_object()
+            .ok_or_else(|| js_error!(TypeError: "parameter 1 is not of type 'Event'"))?;
+
+        // 1. If event's dispatch flag is set, or if its initialized flag is not set, then
+        //    throw an "InvalidStateError" DOMException.
+        check_dispatchable(&event, context)?;
+
+        // 2. Initialize event's isTrusted attribute to false.
+        with_event(&event, |e| e.set_trusted(false));
+
+        target.dispatch(&object, &event, context)
+    }
+}
@@ -0,0 +1,292 @@
+use boa_engine::{Context, JsValue, Source, js_string};
+
+fn context() -> Context {
+    let mut context = Context::default();
+    super::register(None, &mut context).expect("failed to register event APIs");
+    context
+}
+
+#[track_caller]
+fn assert_js(context: &mut Context, source: &str) {
+    let result = context
+        .eval(Source::from_bytes(source))
+        .unwrap_or_else(|e| panic!("Uncaught {e} in:\n{source}"));
+    assert_eq!(result, JsValue::from(true), "assertion failed:\n{source}");
+}
+
+#[test]
+fn event_properties() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        const e = new Event("foo", { bubbles: true, cancelable: true });
+        e.type === "foo"
+            && e.bubbles
+            && e.cancelable
+            && !e.composed
+            && !e.isTrusted
+            && e.target === null
+            && e.currentTarget === null
+            && e.eventPhase === Event.NONE
+            && Event.AT_TARGET === 2
+            && e.AT_TARGET === 2
+            && typeof e.timeStamp === "number"
+        "#,
+    );
+}
+
+#[test]
+fn event_requires_type() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r"
+        let threw = false;
+        try { new Event(); } catch (e) { threw = e instanceof TypeError; }
+        threw
+        ",
+    );
+}
+
+#[test]
+fn dispatch_calls_listeners_in_order() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        const target = new EventTarget();
+        const calls = [];
+        target.addEventListener("foo", (e) => calls.push("bubble:" + e.eventPhase));
+        target.addEventListener("foo", (e) => calls.push("capture"), true);
+        const handler = { handleEvent(e) { calls.push(this === handler); } };
+        target.addEventListener("foo", handler);
+        const event = new Event("foo");
+        target.dispatchEvent(event);
+        calls.join() === "capture,bubble:2,true"
+            && event.target === target
+            && event.currentTarget === null
+            && event.eventPhase === 0
+        "#,
+    );
+}
+
+#[test]
+fn duplicate_listeners_are_ignored() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        const target = new EventTarget();
+        let count = 0;
+        const listener = () => count++;
+        target.addEventListener("foo", listener);
+        target.addEventListener("foo", listener);
+        target.addEventListener("foo", listener, { capture: true });
+        target.dispatchEvent(new Event("foo"));
+        count === 2
+        "#,
+    );
+}
+
+#[test]
+fn remove_event_listener() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        const target = new EventTarget();
+        let count = 0;
+        const listener = () => count++;
+        target.addEventListener("foo", listener, true);
+        target.removeEventListener("foo", listener);
+        target.dispatchEvent(new Event("foo"));
+        const notRemoved = count === 1;
+        target.removeEventListener("foo", listener, { capture: true });
+        target.dispatchEvent(new Event("foo"));
+        notRemoved && count === 1
+        "#,
+    );
+}
+
+#[test]
+fn once_listener() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        const target = new EventTarget();
+        let count = 0;
+        target.addEventListener("foo", () => count++, { once: true });
+        target.dispatchEvent(new Event("foo"));
+        target.dispatchEvent(new Event("foo"));
+        count === 1
+        "#,
+    );
+}
+
+#[test]
+fn prevent_default_and_passive() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        const target = new EventTarget();
+        target.addEventListener("passive", (e) => e.preventDefault(), { passive: true });
+        target.addEventListener("active", (e) => e.preventDefault());
+        const passive = new Event("passive", { cancelable: true });
+        const active = new Event("active", { cancelable: true });
+        const notCancelable = new Event("active");
+        target.dispatchEvent(passive)
+            && !passive.defaultPrevented
+            && !target.dispatchEvent(active)
+            && active.defaultPrevented
+            && target.dispatchEvent(notCancelable)
+        "#,
+    );
+}
+
+#[test]
+fn stop_immediate_propagation() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        const target = new EventTarget();
+        let count = 0;
+        target.addEventListener("foo", (e) => { count++; e.stopImmediatePropagation(); });
+        target.addEventListener("foo", () => count++);
+        target.dispatchEvent(new Event("foo"));
+        count === 1
+        "#,
+    );
+}
+
+#[test]
+fn redispatch_during_dispatch_throws() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        const target = new EventTarget();
+        let name;
+        target.addEventListener("foo", (e) => {
+            try { target.dispatchEvent(e); } catch (err) { name = err.name; }
+        });
+        target.dispatchEvent(new Event("foo"));
+        name === "InvalidStateError"
+        "#,
+    );
+}
+
+#[test]
+fn listener_exceptions_are_reported() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        const target = new EventTarget();
+        let called = false;
+        target.addEventListener("foo", () => { throw new Error("boom"); });
+        target.addEventListener("foo", () => { called = true; });
+        target.dispatchEvent(new Event("foo")) && called
+        "#,
+    );
+
+    let err = context
+        .run_jobs()
+        .expect_err("the listener error should be reported");
+    let err = err.as_opaque().expect("the listener threw an opaque error");
+    let message = err
+        .as_object()
+        .expect("the error is an object")
+        .get(js_string!("message"), context)
+        .expect("reading the message should not throw");
+    assert_eq!(message, JsValue::from(js_string!("boom")));
+}
+
+#[test]
+fn subclassing() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        class Emitter extends EventTarget {}
+        const emitter = new Emitter();
+        let detail;
+        emitter.addEventListener("custom", (e) => { detail = e.detail; });
+        const event = new CustomEvent("custom", { detail: 42 });
+        emitter.dispatchEvent(event);
+        detail === 42
+            && emitter instanceof EventTarget
+            && event instanceof Event
+            && new CustomEvent("x").detail === null
+        "#,
+    );
+}
+
+#[test]
+fn error_and_message_events() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        const error = new Error("oops");
+        const errorEvent = new ErrorEvent("error", {
+            message: "oops", filename: "a.js", lineno: 3, colno: 7, error,
+        });
+        const messageEvent = new MessageEvent("message", { data: { a: 1 }, origin: "o" });
+        errorEvent instanceof Event
+            && errorEvent.type === "error"
+            && errorEvent.message === "oops"
+            && errorEvent.filename === "a.js"
+            && errorEvent.lineno === 3
+            && errorEvent.colno === 7
+            && errorEvent.error === error
+            && messageEvent.data.a === 1
+            && messageEvent.origin === "o"
+            && messageEvent.lastEventId === ""
+            && messageEvent.source === null
+            && Array.isArray(messageEvent.ports)
+            && Object.isFrozen(messageEvent.ports)
+        "#,
+    );
+}
+
+#[test]
+fn global_object_is_event_target() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        let received = false;
+        addEventListener("ping", () => { received = true; });
+        dispatchEvent(new Event("ping"));
+        received
+            && globalThis instanceof EventTarget
+            && Object.getPrototypeOf(globalThis) === EventTarget.prototype
+        "#,
+    );
+}
+
+#[test]
+fn fire_event_is_trusted() {
+    let context = &mut context();
+    assert_js(
+        context,
+        r#"
+        var trusted;
+        var target = new EventTarget();
+        target.addEventListener("native", (e) => { trusted = e.isTrusted; });
+        true
+        "#,
+    );
+
+    let target = context
+        .global_object()
+        .get(js_string!("target"), context)
+        .expect("target should exist");
+    let target = target.as_object().expect("target should be an object");
+    assert!(super::fire_event(&target, js_string!("native"), context).expect("fire_event failed"));
+    assert_js(context, "trusted === true");
+}
@@ -39,6 +39,7 @@ pub mod abort;
 pub mod base64;
 pub mod clone;
 pub mod console;
+pub mod crypto;
 pub mod encoding;
 pub mod events;
 #[cfg(feature = "fetch")]
@@ -68,7 +69,9 @@ pub fn register(
     microtask::register(realm.clone(), ctx)?;
     clone::register(realm.clone(), ctx)?;
     base64::register(realm.clone(), ctx)?;
+    events::register(realm.clone(), ctx)?;
     abort::register(realm.clone(), ctx)?;
+    crypto::register(realm.clone(), ctx)?;
     #[cfg(feature = "url")]
     url::register(realm.clone(), ctx)?;
     #[cfg(feature = "fetch")]
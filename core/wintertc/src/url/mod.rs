@@ -8,8 +8,7 @@
 //!
 //! # TODO
 //!
-//! - Migrate `URL` from `boa_runtime::url`.
-//! - Implement `URLSearchParams`.
+//! - Migrate `URL` and `URLSearchParams` from `boa_runtime::url`.
 
 /// Register `URL` and `URLSearchParams` into the given context.
 ///
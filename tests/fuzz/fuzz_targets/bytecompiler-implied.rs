@@ -11,7 +11,7 @@ use std::io::Cursor;
 fn do_fuzz(original: FuzzSource) -> Corpus {
     let mut ctx = Context::builder()
         .interner(original.interner)
-        .instructions_remaining(0)
+        .instruction_budget(0)
         .build()
         .unwrap();
     if let Ok(parsed) = Script::parse(
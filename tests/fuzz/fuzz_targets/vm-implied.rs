@@ -11,7 +11,7 @@ use std::io::Cursor;
 fn do_fuzz(original: FuzzSource) -> JsResult<JsValue> {
     let mut ctx = Context::builder()
         .interner(original.interner)
-        .instructions_remaining(1 << 16)
+        .instruction_budget(1 << 16)
         .build()
         .unwrap();
     ctx.eval(Source::from_reader(Cursor::new(&original.source), None))
@@ -0,0 +1,217 @@
+//! A data provider for the display names that ICU4X doesn't export, read directly from the CLDR
+//! JSON release used by [`SourceDataProvider`].
+
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Cursor, Read};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use boa_icu_provider::provider::{
+    CalendarDisplayNames, CalendarDisplayNamesV1, DateTimeFieldDisplayNames,
+    DateTimeFieldDisplayNamesV1,
+};
+use icu_provider::prelude::*;
+use icu_provider_source::SourceDataProvider;
+use serde_json::Value;
+use zip::ZipArchive;
+
+/// The date-time fields of ECMA-402, and the name of the corresponding CLDR field.
+const DATE_TIME_FIELDS: [(&str, &str); 12] = [
+    ("era", "era"),
+    ("year", "year"),
+    ("quarter", "quarter"),
+    ("month", "month"),
+    ("weekOfYear", "week"),
+    ("weekday", "weekday"),
+    ("day", "day"),
+    ("dayPeriod", "dayperiod"),
+    ("hour", "hour"),
+    ("minute", "minute"),
+    ("second", "second"),
+    ("timeZoneName", "zone"),
+];
+
+/// A provider of the data for the markers in [`boa_icu_provider::provider`].
+pub(crate) struct DisplayNamesProvider {
+    archive: Mutex<ZipArchive<Cursor<Vec<u8>>>>,
+    /// Maps the CLDR calendar types to their BCP 47 identifier, for the types that differ.
+    calendar_aliases: HashMap<String, String>,
+}
+
+impl DisplayNamesProvider {
+    /// Creates a new provider, downloading the CLDR JSON data if it isn't in the download
+    /// cache of [`SourceDataProvider`].
+    pub(crate) fn try_new() -> Result<Self, Box<dyn Error>> {
+        let tag = SourceDataProvider::TESTED_CLDR_TAG;
+        let url = format!(
+            "https://github.com/unicode-org/cldr-json/releases/download/{tag}/cldr-{tag}-json-full.zip"
+        );
+        let path = std::env::var_os("ICU4X_SOURCE_CACHE")
+            .map_or_else(
+                || std::env::temp_dir().join("icu4x-source-cache"),
+                PathBuf::from,
+            )
+            .join(url.trim_start_matches("https://"));
+
+        if !path.exists() {
+            log::info!("Downloading {url}");
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let mut response = ureq::get(&url).call()?.into_body().into_reader();
+            std::io::copy(&mut response, &mut BufWriter::new(File::create(&path)?))?;
+        }
+
+        let mut provider = Self {
+            archive: Mutex::new(ZipArchive::new(Cursor::new(std::fs::read(&path)?))?),
+            calendar_aliases: HashMap::new(),
+        };
+
+        let calendars = provider.read("cldr-bcp47/bcp47/calendar.json")?;
+        if let Some(calendars) = calendars["keyword"]["u"]["ca"].as_object() {
+            provider.calendar_aliases = calendars
+                .iter()
+                .filter(|(_, calendar)| calendar["_deprecated"] != "true")
+                .filter_map(|(id, calendar)| {
+                    Some((calendar["_alias"].as_str()?.to_owned(), id.clone()))
+                })
+                .collect();
+        }
+
+        Ok(provider)
+    }
+
+    /// Reads and parses a JSON file of the CLDR data.
+    fn read(&self, path: &str) -> Result<Value, DataError> {
+        let mut archive = self.archive.lock().expect("poisoned lock");
+        let mut file = archive.by_name(path).map_err(|e| {
+            DataError::custom("CLDR JSON file")
+                .with_display_context(&e)
+                .with_display_context(&path)
+        })?;
+        let mut json = String::new();
+        file.read_to_string(&mut json)
+            .map_err(|e| DataError::from(e).with_display_context(&path))?;
+
+        serde_json::from_str(&json)
+            .map_err(|e| DataError::custom("CLDR JSON").with_display_context(&e))
+    }
+
+    /// Reads the data of `file` in the locale of `req` from the CLDR `package`.
+    fn read_locale<M: DataMarker>(
+        &self,
+        package: &str,
+        file: &str,
+        req: DataRequest<'_>,
+    ) -> Result<Value, DataError> {
+        let locale = req.id.locale.to_string();
+        if !self.locales(package, file).contains(&locale) {
+            return Err(DataErrorKind::IdentifierNotFound.with_req(M::INFO, req));
+        }
+        let mut json = self.read(&format!("{package}/main/{locale}/{file}"))?;
+        Ok(json["main"][&locale].take())
+    }
+
+    /// Lists the locales that have the data of `file` in the CLDR `package`.
+    fn locales(&self, package: &str, file: &str) -> BTreeSet<String> {
+        let prefix = format!("{package}/main/");
+        let suffix = format!("/{file}");
+        self.archive
+            .lock()
+            .expect("poisoned lock")
+            .file_names()
+            .filter_map(|name| name.strip_prefix(&prefix)?.strip_suffix(&suffix))
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// Gets the identifiers of the locales that have the data of `file` in the CLDR `package`.
+    fn locale_ids(&self, package: &str, file: &str) -> BTreeSet<DataIdentifierCow<'static>> {
+        self.locales(package, file)
+            .iter()
+            .filter_map(|locale| locale.parse::<DataLocale>().ok())
+            .map(DataIdentifierCow::from_locale)
+            .collect()
+    }
+}
+
+impl DataProvider<CalendarDisplayNamesV1> for DisplayNamesProvider {
+    fn load(
+        &self,
+        req: DataRequest<'_>,
+    ) -> Result<DataResponse<CalendarDisplayNamesV1>, DataError> {
+        let data = self.read_locale::<CalendarDisplayNamesV1>(
+            "cldr-localenames-full",
+            "localeDisplayNames.json",
+            req,
+        )?;
+
+        let names = data["localeDisplayNames"]["types"]["calendar"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(calendar, _)| !calendar.contains("-alt-"))
+            .filter_map(|(calendar, name)| {
+                let calendar = self.calendar_aliases.get(calendar).unwrap_or(calendar);
+                Some((calendar.as_str(), name.as_str()?))
+            })
+            .collect();
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: DataPayload::from_owned(CalendarDisplayNames { names }),
+        })
+    }
+}
+
+impl IterableDataProvider<CalendarDisplayNamesV1> for DisplayNamesProvider {
+    fn iter_ids(&self) -> Result<BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        Ok(self.locale_ids("cldr-localenames-full", "localeDisplayNames.json"))
+    }
+}
+
+impl DataProvider<DateTimeFieldDisplayNamesV1> for DisplayNamesProvider {
+    fn load(
+        &self,
+        req: DataRequest<'_>,
+    ) -> Result<DataResponse<DateTimeFieldDisplayNamesV1>, DataError> {
+        let data = self.read_locale::<DateTimeFieldDisplayNamesV1>(
+            "cldr-dates-full",
+            "dateFields.json",
+            req,
+        )?;
+        let fields = &data["dates"]["fields"];
+
+        let names_with_suffix = |suffix: &str| {
+            DATE_TIME_FIELDS
+                .iter()
+                .filter_map(|(code, field)| {
+                    let name = fields[format!("{field}{suffix}")]["displayName"].as_str()?;
+                    Some((*code, name))
+                })
+                .collect()
+        };
+
+        Ok(DataResponse {
+            metadata: DataResponseMetadata::default(),
+            payload: DataPayload::from_owned(DateTimeFieldDisplayNames {
+                names: names_with_suffix(""),
+                short_names: names_with_suffix("-short"),
+                narrow_names: names_with_suffix("-narrow"),
+            }),
+        })
+    }
+}
+
+impl IterableDataProvider<DateTimeFieldDisplayNamesV1> for DisplayNamesProvider {
+    fn iter_ids(&self) -> Result<BTreeSet<DataIdentifierCow<'_>>, DataError> {
+        Ok(self.locale_ids("cldr-dates-full", "dateFields.json"))
+    }
+}
+
+icu_provider::export::make_exportable_provider!(
+    DisplayNamesProvider,
+    [CalendarDisplayNamesV1, DateTimeFieldDisplayNamesV1,]
+);
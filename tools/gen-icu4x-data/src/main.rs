@@ -1,8 +1,13 @@
 #![allow(missing_docs, rustdoc::missing_crate_level_docs)]
 
+extern crate alloc;
+
+mod display_names;
+
 use std::path::Path;
 use std::{error::Error, fs::File};
 
+use icu_provider::export::ExportableProvider;
 use icu_provider_export::blob_exporter::BlobExporter;
 use icu_provider_export::prelude::*;
 use icu_provider_source::{CoverageLevel, SourceDataProvider};
@@ -14,11 +19,13 @@ const EXPORT_PATH: &str = "core/icu_provider/data";
 ///
 /// This must be kept in sync with the list of implemented services for `Intl`.
 const SERVICES: &[(&str, &[DataMarkerInfo])] = &[
+    ("icu_calendar", icu_calendar::provider::MARKERS),
     ("icu_casemap", icu_casemap::provider::MARKERS),
     ("icu_collator", icu_collator::provider::MARKERS),
     ("icu_datetime", icu_datetime::provider::MARKERS),
     ("icu_time", icu_time::provider::MARKERS),
     ("icu_decimal", icu_decimal::provider::MARKERS),
+    ("icu_experimental", icu_experimental::provider::MARKERS),
     ("icu_list", icu_list::provider::MARKERS),
     ("icu_locale", icu_locale::provider::MARKERS),
     ("icu_normalizer", icu_normalizer::provider::MARKERS),
@@ -29,7 +36,7 @@ const SERVICES: &[(&str, &[DataMarkerInfo])] = &[
 fn export_for_service(
     service: &str,
     markers: &[DataMarkerInfo],
-    provider: &SourceDataProvider,
+    provider: &impl ExportableProvider,
     driver: ExportDriver,
 ) -> Result<(), Box<dyn Error>> {
     log::info!("Generating ICU4X data for service `{service}` with markers: {markers:#?}");
@@ -82,5 +89,13 @@ fn main() -> Result<(), Box<dyn Error>> {
         export_for_service(service, keys, provider, driver.clone())?;
     }
 
+    // Data for the markers of `boa_icu_provider`, which ICU4X doesn't export.
+    export_for_service(
+        "boa",
+        boa_icu_provider::provider::MARKERS,
+        &display_names::DisplayNamesProvider::try_new()?,
+        driver,
+    )?;
+
     Ok(())
 }